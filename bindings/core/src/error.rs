@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use packable::error::UnexpectedEOF;
+use packable::error::{UnexpectedEOF, UnpackError};
 use serde::{ser::SerializeMap, Serialize, Serializer};
 
 pub use super::{method::AccountMethod, response::Response};
@@ -29,7 +29,35 @@ pub enum Error {
     SerdeJson(#[from] serde_json::error::Error),
     /// Unpack errors.
     #[error("{0}")]
-    Unpack(#[from] packable::error::UnpackError<iota_sdk::types::block::Error, UnexpectedEOF>),
+    Unpack(#[from] UnpackError<iota_sdk::types::block::Error, UnexpectedEOF>),
+    /// Method not permitted by the method handler.
+    #[error("method `{0}` is not permitted")]
+    MethodNotPermitted(String),
+}
+
+/// Stable code of an error, so that binding users can distinguish errors without parsing their messages.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// The parameters of the method are invalid or missing.
+    InvalidParameter,
+    /// A requested item, like an account, a transaction or an output, doesn't exist.
+    NotFound,
+    /// The available funds aren't enough for the transaction.
+    InsufficientFunds,
+    /// No healthy node could be reached.
+    NodeUnreachable,
+    /// A node answered the request with an error.
+    NodeError,
+    /// The secret manager failed, e.g. a locked Stronghold or a rejected Ledger Nano prompt.
+    SecretManager,
+    /// Reading or writing the wallet storage failed.
+    Storage,
+    /// The method is not permitted by the method handler.
+    MethodNotPermitted,
+    /// Any other error.
+    Internal,
 }
 
 #[cfg(feature = "stronghold")]
@@ -46,23 +74,476 @@ impl From<iota_sdk::client::node_api::mqtt::Error> for Error {
     }
 }
 
-// Serialize type with Display error.
+impl Error {
+    /// Returns the stable [`ErrorCode`] of the error, so that binding users can branch on it.
+    pub fn code(&self) -> ErrorCode {
+        use iota_sdk::wallet::Error as WalletError;
+
+        match self {
+            Self::Block(_) | Self::PrefixHex(_) | Self::SerdeJson(_) | Self::Unpack(_) => ErrorCode::InvalidParameter,
+            Self::Client(error) => client_error_code(error),
+            Self::Wallet(error) => match error {
+                WalletError::Client(error) => client_error_code(error),
+                WalletError::AccountNotFound(_)
+                | WalletError::AddressNotFoundInAccount(_)
+                | WalletError::AliasNotFoundInUnspentOutputs(_)
+                | WalletError::ContactNotFound(_)
+                | WalletError::KeyIndexNotFoundInAccount(_)
+                | WalletError::NftNotFoundInUnspentOutputs
+                | WalletError::TransactionNotFound(_) => ErrorCode::NotFound,
+                WalletError::InsufficientFunds { .. } => ErrorCode::InsufficientFunds,
+                WalletError::AccountAliasAlreadyExists(_)
+                | WalletError::Block(_)
+                | WalletError::CustomInput(_)
+                | WalletError::InvalidCoinType { .. }
+                | WalletError::InvalidMnemonic(_)
+                | WalletError::InvalidOutputKind(_)
+                | WalletError::Json(_)
+                | WalletError::MissingParameter(_) => ErrorCode::InvalidParameter,
+                WalletError::Storage(_) | WalletError::StorageIsEncrypted => ErrorCode::Storage,
+                _ => ErrorCode::Internal,
+            },
+            Self::MethodNotPermitted(_) => ErrorCode::MethodNotPermitted,
+        }
+    }
+
+    /// Returns the detailed kind of the error, made of its category and the name of the innermost error variant,
+    /// e.g. `wallet.insufficientFunds` or `node.responseError`.
+    pub fn kind(&self) -> String {
+        let (category, kind) = self.category_and_kind();
+        format!("{category}.{kind}")
+    }
+
+    /// Returns machine readable data of the error, like amounts, output IDs or node URLs, if the error has any.
+    pub fn data(&self) -> Option<serde_json::Value> {
+        use iota_sdk::wallet::Error as WalletError;
+
+        match self {
+            Self::Client(error) => client_error_data(error),
+            Self::Wallet(error) => match error {
+                WalletError::Client(error) => client_error_data(error),
+                WalletError::AccountNotFound(account) => Some(serde_json::json!({ "account": account })),
+                WalletError::AddressNotFoundInAccount(address) => Some(serde_json::json!({ "address": address })),
+                WalletError::ContactNotFound(name) => Some(serde_json::json!({ "name": name })),
+                WalletError::KeyIndexNotFoundInAccount(key_index) => Some(serde_json::json!({ "keyIndex": key_index })),
+                WalletError::ConsolidationRequired {
+                    output_count,
+                    output_count_max,
+                } => Some(serde_json::json!({
+                    "outputCount": output_count,
+                    "outputCountMax": output_count_max,
+                })),
+                WalletError::InsufficientFunds { available, required } => Some(serde_json::json!({
+                    "available": available.to_string(),
+                    "required": required.to_string(),
+                })),
+                WalletError::TransactionNotFound(transaction_id)
+                | WalletError::TransactionNotRebuildable(transaction_id, _) => {
+                    Some(serde_json::json!({ "transactionId": transaction_id }))
+                }
+                _ => None,
+            },
+            Self::MethodNotPermitted(method) => Some(serde_json::json!({ "method": method })),
+            _ => None,
+        }
+    }
+
+    // The name of the variant, independent of its `Debug` representation.
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::Block(_) => "block",
+            Self::Client(_) => "client",
+            Self::Wallet(_) => "wallet",
+            Self::PrefixHex(_) => "prefixHex",
+            Self::SerdeJson(_) => "serdeJson",
+            Self::Unpack(_) => "unpack",
+            Self::MethodNotPermitted(_) => "methodNotPermitted",
+        }
+    }
+
+    fn category_and_kind(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Block(error) => ("block", block_error_kind(error)),
+            Self::Client(error) => client_error_category_and_kind(error),
+            Self::Wallet(iota_sdk::wallet::Error::Client(error)) => client_error_category_and_kind(error),
+            Self::Wallet(iota_sdk::wallet::Error::Block(error)) => ("block", block_error_kind(error)),
+            Self::Wallet(error) => ("wallet", wallet_error_kind(error)),
+            Self::PrefixHex(_) => ("prefixHex", "prefixHex"),
+            Self::SerdeJson(_) => ("serdeJson", "serdeJson"),
+            Self::Unpack(UnpackError::Packable(error)) => ("unpack", block_error_kind(error)),
+            Self::Unpack(UnpackError::Unpacker(_)) => ("unpack", "unexpectedEof"),
+            Self::MethodNotPermitted(_) => ("bindings", "methodNotPermitted"),
+        }
+    }
+}
+
+fn client_error_code(error: &iota_sdk::client::Error) -> ErrorCode {
+    use iota_sdk::client::{
+        api::input_selection::Error as InputSelectionError, node_api::error::Error as NodeError, Error as ClientError,
+    };
+
+    match error {
+        ClientError::HealthyNodePoolEmpty
+        | ClientError::QuorumPoolSizeError { .. }
+        | ClientError::Node(NodeError::Reqwest(_)) => ErrorCode::NodeUnreachable,
+        ClientError::Node(NodeError::NotFound(_)) => ErrorCode::NotFound,
+        ClientError::Node(_) => ErrorCode::NodeError,
+        ClientError::InputSelection(
+            InputSelectionError::InsufficientAmount { .. } | InputSelectionError::InsufficientNativeTokenAmount { .. },
+        ) => ErrorCode::InsufficientFunds,
+        ClientError::Bech32HrpMismatch { .. }
+        | ClientError::Block(_)
+        | ClientError::InvalidAmount(_)
+        | ClientError::InvalidMnemonic(_)
+        | ClientError::InvalidNativeTokenMetadata { .. }
+        | ClientError::Json(_)
+        | ClientError::MissingBip32Chain
+        | ClientError::MissingParameter(_)
+        | ClientError::PrefixHex(_)
+        | ClientError::ProtocolParametersMismatch { .. }
+        | ClientError::TaggedData(_)
+        | ClientError::Unpack(_)
+        | ClientError::UnsupportedClientConfigVersion(_)
+        | ClientError::UnsupportedQueryParameter(_)
+        | ClientError::Url(_)
+        | ClientError::UrlAuth(_)
+        | ClientError::UrlValidation(_)
+        | ClientError::ZeroAmount(_) => ErrorCode::InvalidParameter,
+        ClientError::PlaceholderSecretManager
+        | ClientError::SecretManagerMismatch
+        | ClientError::CustomSecretManagerConfig
+        | ClientError::SignatureUnlockCount { .. } => ErrorCode::SecretManager,
+        #[cfg(feature = "ledger_nano")]
+        ClientError::Ledger(_) => ErrorCode::SecretManager,
+        #[cfg(feature = "stronghold")]
+        ClientError::Stronghold(_) => ErrorCode::SecretManager,
+        _ => ErrorCode::Internal,
+    }
+}
+
+fn client_error_category_and_kind(error: &iota_sdk::client::Error) -> (&'static str, &'static str) {
+    use iota_sdk::client::{api::input_selection::Error as InputSelectionError, Error as ClientError};
+
+    match error {
+        ClientError::Block(error) | ClientError::InputSelection(InputSelectionError::Block(error)) => {
+            ("block", block_error_kind(error))
+        }
+        ClientError::Node(error) => ("node", node_error_kind(error)),
+        ClientError::InputSelection(error) => ("inputSelection", input_selection_error_kind(error)),
+        _ => ("client", client_error_kind(error)),
+    }
+}
+
+fn client_error_data(error: &iota_sdk::client::Error) -> Option<serde_json::Value> {
+    use iota_sdk::client::{
+        api::input_selection::Error as InputSelectionError, node_api::error::Error as NodeError, Error as ClientError,
+    };
+
+    match error {
+        ClientError::Bech32HrpMismatch { provided, expected } => Some(serde_json::json!({
+            "provided": provided,
+            "expected": expected,
+        })),
+        ClientError::Node(NodeError::ResponseError { code, url, .. }) => Some(serde_json::json!({
+            "statusCode": code,
+            "url": url,
+        })),
+        ClientError::ProtocolParametersMismatch { url } => Some(serde_json::json!({ "url": url })),
+        ClientError::InputSelection(error) => match error {
+            InputSelectionError::InsufficientAmount { found, required } => Some(serde_json::json!({
+                "found": found.to_string(),
+                "required": required.to_string(),
+            })),
+            InputSelectionError::InsufficientNativeTokenAmount {
+                token_id,
+                found,
+                required,
+            } => Some(serde_json::json!({
+                "tokenId": token_id,
+                "found": found,
+                "required": required,
+            })),
+            InputSelectionError::RequiredInputIsForbidden(output_id)
+            | InputSelectionError::RequiredInputIsNotAvailable(output_id) => {
+                Some(serde_json::json!({ "outputId": output_id }))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// The kinds are spelled out instead of being derived from the `Debug` representation of the errors, so they stay the
+// same if it changes. Variants without a kind, e.g. added to the SDK later, are `other`.
+
+fn wallet_error_kind(error: &iota_sdk::wallet::Error) -> &'static str {
+    use iota_sdk::wallet::Error as WalletError;
+
+    match error {
+        WalletError::AccountAliasAlreadyExists(_) => "accountAliasAlreadyExists",
+        WalletError::AccountNotFound(_) => "accountNotFound",
+        WalletError::AddressNotFoundInAccount(_) => "addressNotFoundInAccount",
+        WalletError::AliasNotFoundInUnspentOutputs(_) => "aliasNotFoundInUnspentOutputs",
+        WalletError::AliasStateControllerNotInAccount(_) => "aliasStateControllerNotInAccount",
+        WalletError::Backup(_) => "backup",
+        WalletError::Block(_) => "block",
+        WalletError::BurningOrMeltingFailed(_) => "burningOrMeltingFailed",
+        WalletError::Client(_) => "client",
+        WalletError::ConsolidationRequired { .. } => "consolidationRequired",
+        WalletError::ContactNotFound(_) => "contactNotFound",
+        WalletError::Crypto(_) => "crypto",
+        WalletError::CustomInput(_) => "customInput",
+        WalletError::FailedToGetRemainder => "failedToGetRemainder",
+        WalletError::InsufficientFunds { .. } => "insufficientFunds",
+        WalletError::InvalidCoinType { .. } => "invalidCoinType",
+        WalletError::InvalidMnemonic(_) => "invalidMnemonic",
+        WalletError::InvalidOutputKind(_) => "invalidOutputKind",
+        WalletError::Io(_) => "io",
+        WalletError::Json(_) => "json",
+        WalletError::KeyIndexNotFoundInAccount(_) => "keyIndexNotFoundInAccount",
+        WalletError::Migration(_) => "migration",
+        WalletError::MintingFailed(_) => "mintingFailed",
+        WalletError::MissingParameter(_) => "missingParameter",
+        WalletError::NftNotFoundInUnspentOutputs => "nftNotFoundInUnspentOutputs",
+        WalletError::NoOutputsToConsolidate { .. } => "noOutputsToConsolidate",
+        WalletError::Other(_) => "other",
+        WalletError::PartiallySent { .. } => "partiallySent",
+        #[cfg(feature = "participation")]
+        WalletError::Participation(_) => "participation",
+        WalletError::Storage(_) => "storage",
+        WalletError::StorageIsEncrypted => "storageIsEncrypted",
+        WalletError::TaskJoin(_) => "taskJoin",
+        WalletError::TransactionNotFound(_) => "transactionNotFound",
+        WalletError::TransactionNotRebuildable(_, _) => "transactionNotRebuildable",
+        WalletError::UnsafeExpiration { .. } => "unsafeExpiration",
+        #[cfg(feature = "participation")]
+        WalletError::Voting(_) => "voting",
+        #[cfg(feature = "participation")]
+        WalletError::InvalidVotingPower => "invalidVotingPower",
+        #[allow(unreachable_patterns)]
+        _ => "other",
+    }
+}
+
+fn client_error_kind(error: &iota_sdk::client::Error) -> &'static str {
+    use iota_sdk::client::Error as ClientError;
+
+    match error {
+        ClientError::ApiTypes(_) => "apiTypes",
+        ClientError::Bech32HrpMismatch { .. } => "bech32HrpMismatch",
+        ClientError::Blake2b256(_) => "blake2b256",
+        ClientError::Block(_) => "block",
+        ClientError::ConsolidationRequired(_) => "consolidationRequired",
+        ClientError::Crypto(_) => "crypto",
+        ClientError::CustomSecretManagerConfig => "customSecretManagerConfig",
+        ClientError::DryRun => "dryRun",
+        ClientError::InputAddressNotFound { .. } => "inputAddressNotFound",
+        ClientError::InvalidAmount(_) => "invalidAmount",
+        ClientError::InvalidInclusion { .. } => "invalidInclusion",
+        ClientError::InvalidMnemonic(_) => "invalidMnemonic",
+        ClientError::InvalidNativeTokenMetadata { .. } => "invalidNativeTokenMetadata",
+        ClientError::InvalidPowNonce { .. } => "invalidPowNonce",
+        ClientError::InvalidRegularTransactionEssenceLength { .. } => "invalidRegularTransactionEssenceLength",
+        ClientError::InvalidSnapshot(_) => "invalidSnapshot",
+        ClientError::InvalidTransactionPayloadLength { .. } => "invalidTransactionPayloadLength",
+        ClientError::Io(_) => "io",
+        ClientError::Json(_) => "json",
+        ClientError::MissingParameter(_) => "missingParameter",
+        ClientError::Node(_) => "node",
+        ClientError::NoNeedPromoteOrReattach(_) => "noNeedPromoteOrReattach",
+        ClientError::NoOutput(_) => "noOutput",
+        ClientError::NodeResponseInvalid { .. } => "nodeResponseInvalid",
+        ClientError::PlaceholderSecretManager => "placeholderSecretManager",
+        ClientError::PowCancelled => "powCancelled",
+        ClientError::PoisonError => "poisonError",
+        ClientError::PrefixHex(_) => "prefixHex",
+        ClientError::ProtocolParametersMismatch { .. } => "protocolParametersMismatch",
+        ClientError::QuorumPoolSizeError { .. } => "quorumPoolSizeError",
+        ClientError::QuorumThresholdError { .. } => "quorumThresholdError",
+        ClientError::SecretManagerMismatch => "secretManagerMismatch",
+        ClientError::HealthyNodePoolEmpty => "healthyNodePoolEmpty",
+        ClientError::TaggedData(_) => "taggedData",
+        ClientError::TangleInclusion(_) => "tangleInclusion",
+        #[cfg(not(target_family = "wasm"))]
+        ClientError::TaskJoin(_) => "taskJoin",
+        ClientError::TimeNotSynced { .. } => "timeNotSynced",
+        ClientError::TransactionSemantic(_) => "transactionSemantic",
+        ClientError::UnsupportedQueryParameter(_) => "unsupportedQueryParameter",
+        ClientError::UnsupportedClientConfigVersion(_) => "unsupportedClientConfigVersion",
+        ClientError::Unpack(_) => "unpack",
+        ClientError::UrlAuth(_) => "urlAuth",
+        ClientError::Url(_) => "url",
+        ClientError::UrlValidation(_) => "urlValidation",
+        ClientError::InputSelection(_) => "inputSelection",
+        ClientError::MissingBip32Chain => "missingBip32Chain",
+        ClientError::SignatureUnlockCount { .. } => "signatureUnlockCount",
+        ClientError::EssenceHashMismatch { .. } => "essenceHashMismatch",
+        ClientError::UnexpectedSigner(_) => "unexpectedSigner",
+        ClientError::MissingSignatures(_) => "missingSignatures",
+        ClientError::ZeroAmount(_) => "zeroAmount",
+        #[cfg(feature = "participation")]
+        ClientError::Participation(_) => "participation",
+        #[cfg(feature = "ledger_nano")]
+        ClientError::Ledger(_) => "ledger",
+        #[cfg(feature = "mqtt")]
+        ClientError::Mqtt(_) => "mqtt",
+        #[cfg(feature = "stronghold")]
+        ClientError::Stronghold(_) => "stronghold",
+        #[allow(unreachable_patterns)]
+        _ => "other",
+    }
+}
+
+fn node_error_kind(error: &iota_sdk::client::node_api::error::Error) -> &'static str {
+    use iota_sdk::client::node_api::error::Error as NodeError;
+
+    match error {
+        NodeError::NotFound(_) => "notFound",
+        NodeError::Reqwest(_) => "reqwest",
+        NodeError::ResponseError { .. } => "responseError",
+        NodeError::UnavailablePow => "unavailablePow",
+        NodeError::NotSupported(_) => "notSupported",
+    }
+}
+
+fn input_selection_error_kind(error: &iota_sdk::client::api::input_selection::Error) -> &'static str {
+    use iota_sdk::client::api::input_selection::Error as InputSelectionError;
+
+    match error {
+        InputSelectionError::Block(_) => "block",
+        InputSelectionError::BurnAndTransition(_) => "burnAndTransition",
+        InputSelectionError::InsufficientAmount { .. } => "insufficientAmount",
+        InputSelectionError::InsufficientNativeTokenAmount { .. } => "insufficientNativeTokenAmount",
+        InputSelectionError::InvalidInputCount(_) => "invalidInputCount",
+        InputSelectionError::InvalidOutputCount(_) => "invalidOutputCount",
+        InputSelectionError::MissingInputWithEd25519Address => "missingInputWithEd25519Address",
+        InputSelectionError::NoAvailableInputsProvided => "noAvailableInputsProvided",
+        InputSelectionError::RequiredInputIsForbidden(_) => "requiredInputIsForbidden",
+        InputSelectionError::RequiredInputIsNotAvailable(_) => "requiredInputIsNotAvailable",
+        InputSelectionError::UnfulfillableRequirement(_) => "unfulfillableRequirement",
+    }
+}
+
+fn block_error_kind(error: &iota_sdk::types::block::Error) -> &'static str {
+    use iota_sdk::types::block::Error as BlockError;
+
+    match error {
+        BlockError::AddressCodecAlreadySet => "addressCodecAlreadySet",
+        BlockError::ConsumedAmountOverflow => "consumedAmountOverflow",
+        BlockError::ConsumedNativeTokensAmountOverflow => "consumedNativeTokensAmountOverflow",
+        BlockError::CreatedAmountOverflow => "createdAmountOverflow",
+        BlockError::CreatedNativeTokensAmountOverflow => "createdNativeTokensAmountOverflow",
+        BlockError::Crypto(_) => "crypto",
+        BlockError::DuplicateSignatureUnlock(_) => "duplicateSignatureUnlock",
+        BlockError::DuplicateUtxo(_) => "duplicateUtxo",
+        BlockError::ExpirationUnlockConditionZero => "expirationUnlockConditionZero",
+        BlockError::FeaturesNotUniqueSorted => "featuresNotUniqueSorted",
+        BlockError::InputUnlockCountMismatch { .. } => "inputUnlockCountMismatch",
+        BlockError::InvalidAddress => "invalidAddress",
+        BlockError::InvalidAddressKind(_) => "invalidAddressKind",
+        BlockError::InvalidAliasIndex(_) => "invalidAliasIndex",
+        BlockError::InvalidStorageDepositAmount(_) => "invalidStorageDepositAmount",
+        BlockError::InsufficientStorageDepositAmount { .. } => "insufficientStorageDepositAmount",
+        BlockError::StorageDepositReturnExceedsOutputAmount { .. } => "storageDepositReturnExceedsOutputAmount",
+        BlockError::InsufficientStorageDepositReturnAmount { .. } => "insufficientStorageDepositReturnAmount",
+        BlockError::InvalidBinaryParametersLength(_) => "invalidBinaryParametersLength",
+        BlockError::InvalidEssenceKind(_) => "invalidEssenceKind",
+        BlockError::InvalidFeatureCount(_) => "invalidFeatureCount",
+        BlockError::InvalidFeatureKind(_) => "invalidFeatureKind",
+        BlockError::InvalidFoundryOutputSupply { .. } => "invalidFoundryOutputSupply",
+        BlockError::Hex(_) => "hex",
+        BlockError::InvalidInputKind(_) => "invalidInputKind",
+        BlockError::InvalidInputCount(_) => "invalidInputCount",
+        BlockError::InvalidInputOutputIndex(_) => "invalidInputOutputIndex",
+        BlockError::InvalidBech32Hrp(_) => "invalidBech32Hrp",
+        BlockError::InvalidBlockLength(_) => "invalidBlockLength",
+        BlockError::InvalidStateMetadataLength(_) => "invalidStateMetadataLength",
+        BlockError::InvalidMetadataFeatureLength(_) => "invalidMetadataFeatureLength",
+        BlockError::InvalidMilestoneMetadataLength(_) => "invalidMilestoneMetadataLength",
+        BlockError::InvalidMilestoneOptionCount(_) => "invalidMilestoneOptionCount",
+        BlockError::InvalidMilestoneOptionKind(_) => "invalidMilestoneOptionKind",
+        BlockError::InvalidMigratedFundsEntryAmount(_) => "invalidMigratedFundsEntryAmount",
+        BlockError::InvalidNativeTokenCount(_) => "invalidNativeTokenCount",
+        BlockError::InvalidNetworkName(_) => "invalidNetworkName",
+        BlockError::InvalidNftIndex(_) => "invalidNftIndex",
+        BlockError::InvalidOutputAmount(_) => "invalidOutputAmount",
+        BlockError::InvalidOutputCount(_) => "invalidOutputCount",
+        BlockError::InvalidOutputKind(_) => "invalidOutputKind",
+        BlockError::InvalidParentCount(_) => "invalidParentCount",
+        BlockError::InvalidPayloadKind(_) => "invalidPayloadKind",
+        BlockError::InvalidPayloadLength { .. } => "invalidPayloadLength",
+        BlockError::InvalidReceiptFundsCount(_) => "invalidReceiptFundsCount",
+        BlockError::InvalidReceiptFundsSum(_) => "invalidReceiptFundsSum",
+        BlockError::InvalidReferenceIndex(_) => "invalidReferenceIndex",
+        BlockError::InvalidSignature => "invalidSignature",
+        BlockError::InvalidSignatureKind(_) => "invalidSignatureKind",
+        BlockError::InvalidStringPrefix(_) => "invalidStringPrefix",
+        BlockError::InvalidTaggedDataLength(_) => "invalidTaggedDataLength",
+        BlockError::InvalidTagFeatureLength(_) => "invalidTagFeatureLength",
+        BlockError::InvalidTagLength(_) => "invalidTagLength",
+        BlockError::InvalidTailTransactionHash => "invalidTailTransactionHash",
+        BlockError::InvalidTokenSchemeKind(_) => "invalidTokenSchemeKind",
+        BlockError::InvalidTransactionAmountSum(_) => "invalidTransactionAmountSum",
+        BlockError::InvalidTransactionNativeTokensCount(_) => "invalidTransactionNativeTokensCount",
+        BlockError::InvalidTreasuryOutputAmount(_) => "invalidTreasuryOutputAmount",
+        BlockError::InvalidUnlockCount(_) => "invalidUnlockCount",
+        BlockError::InvalidUnlockKind(_) => "invalidUnlockKind",
+        BlockError::InvalidUnlockReference(_) => "invalidUnlockReference",
+        BlockError::InvalidUnlockAlias(_) => "invalidUnlockAlias",
+        BlockError::InvalidUnlockNft(_) => "invalidUnlockNft",
+        BlockError::InvalidUnlockConditionCount(_) => "invalidUnlockConditionCount",
+        BlockError::InvalidUnlockConditionKind(_) => "invalidUnlockConditionKind",
+        BlockError::InvalidFoundryZeroSerialNumber => "invalidFoundryZeroSerialNumber",
+        BlockError::MilestoneInvalidSignatureCount(_) => "milestoneInvalidSignatureCount",
+        BlockError::MilestonePublicKeysSignaturesCountMismatch { .. } => "milestonePublicKeysSignaturesCountMismatch",
+        BlockError::MilestoneOptionsNotUniqueSorted => "milestoneOptionsNotUniqueSorted",
+        BlockError::MilestoneSignaturesNotUniqueSorted => "milestoneSignaturesNotUniqueSorted",
+        BlockError::MissingAddressUnlockCondition => "missingAddressUnlockCondition",
+        BlockError::MissingGovernorUnlockCondition => "missingGovernorUnlockCondition",
+        BlockError::MissingStateControllerUnlockCondition => "missingStateControllerUnlockCondition",
+        BlockError::NativeTokensNotUniqueSorted => "nativeTokensNotUniqueSorted",
+        BlockError::NativeTokensNullAmount => "nativeTokensNullAmount",
+        BlockError::NativeTokensOverflow => "nativeTokensOverflow",
+        BlockError::NetworkIdMismatch { .. } => "networkIdMismatch",
+        BlockError::NonZeroStateIndexOrFoundryCounter => "nonZeroStateIndexOrFoundryCounter",
+        BlockError::ParentsNotUniqueSorted => "parentsNotUniqueSorted",
+        BlockError::ProtocolVersionMismatch { .. } => "protocolVersionMismatch",
+        BlockError::NonceNotFound => "nonceNotFound",
+        BlockError::ReceiptFundsNotUniqueSorted => "receiptFundsNotUniqueSorted",
+        BlockError::RemainingBytesAfterBlock => "remainingBytesAfterBlock",
+        BlockError::SelfControlledAliasOutput(_) => "selfControlledAliasOutput",
+        BlockError::SelfDepositNft(_) => "selfDepositNft",
+        BlockError::SignaturePublicKeyMismatch { .. } => "signaturePublicKeyMismatch",
+        BlockError::StorageDepositReturnOverflow => "storageDepositReturnOverflow",
+        BlockError::TailTransactionHashNotUnique { .. } => "tailTransactionHashNotUnique",
+        BlockError::TimelockUnlockConditionZero => "timelockUnlockConditionZero",
+        BlockError::UnallowedFeature { .. } => "unallowedFeature",
+        BlockError::UnallowedUnlockCondition { .. } => "unallowedUnlockCondition",
+        BlockError::UnlockConditionsNotUniqueSorted => "unlockConditionsNotUniqueSorted",
+        BlockError::UnsupportedOutputKind(_) => "unsupportedOutputKind",
+        BlockError::DuplicateOutputChain(_) => "duplicateOutputChain",
+        BlockError::InvalidField(_) => "invalidField",
+    }
+}
+
+// Serialize type with Display error, and the code, kind and data of the error so that it can be handled
+// programmatically. The message is also kept as `error` for backwards compatibility.
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut seq = serializer.serialize_map(Some(2))?;
-        let mut kind_dbg = format!("{self:?}");
-        // Convert first char to lowercase
-        if let Some(r) = kind_dbg.get_mut(0..1) {
-            r.make_ascii_lowercase();
+        let data = self.data();
+        let message = self.to_string();
+        let mut seq = serializer.serialize_map(Some(if data.is_some() { 6 } else { 5 }))?;
+        seq.serialize_entry("type", self.type_name())?;
+        seq.serialize_entry("code", &self.code())?;
+        seq.serialize_entry("kind", &self.kind())?;
+        seq.serialize_entry("message", &message)?;
+        seq.serialize_entry("error", &message)?;
+        if let Some(data) = data {
+            seq.serialize_entry("data", &data)?;
         }
-        // Split by whitespace for struct variants and split by `(` for tuple variants
-        // Safe to unwrap because kind_dbg is never an empty string
-        let kind = kind_dbg.split([' ', '(']).next().unwrap();
-        seq.serialize_entry("type", &kind)?;
-        seq.serialize_entry("error", &self.to_string())?;
         seq.end()
     }
 }
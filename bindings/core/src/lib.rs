@@ -8,6 +8,7 @@ pub mod message_interface_old;
 mod method;
 mod method_handler;
 mod panic;
+mod permissions;
 mod response;
 
 use std::fmt::{Formatter, Result as FmtResult};
@@ -15,6 +16,8 @@ use std::fmt::{Formatter, Result as FmtResult};
 use derivative::Derivative;
 use fern_logger::{logger_init, LoggerConfig, LoggerOutputConfigBuilder};
 pub use iota_sdk;
+#[cfg(feature = "storage")]
+use iota_sdk::wallet::storage::{StorageKind, StorageOptions};
 use iota_sdk::{
     client::secret::{SecretManager, SecretManagerDto},
     wallet::{ClientOptions, Wallet},
@@ -26,10 +29,14 @@ pub use self::method_handler::listen_mqtt;
 #[cfg(not(target_family = "wasm"))]
 pub use self::method_handler::CallMethod;
 pub use self::{
-    error::{Error, Result},
+    error::{Error, ErrorCode, Result},
     method::{AccountMethod, ClientMethod, SecretManagerMethod, UtilsMethod, WalletMethod},
-    method_handler::{call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method},
-    response::Response,
+    method_handler::{
+        call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method,
+        call_wallet_method_with_permissions,
+    },
+    permissions::MethodPermissions,
+    response::{AccountSyncResult, Response},
 };
 
 pub fn init_logger(config: String) -> std::result::Result<(), fern_logger::Error> {
@@ -43,10 +50,15 @@ pub fn init_logger(config: String) -> std::result::Result<(), fern_logger::Error
 #[serde(rename_all = "camelCase")]
 pub struct WalletOptions {
     pub storage_path: Option<String>,
+    /// The kind of storage, defaults to RocksDB or to memory for WebAssembly.
+    #[cfg(feature = "storage")]
+    pub storage_kind: Option<StorageKind>,
     pub client_options: Option<ClientOptions>,
     pub coin_type: Option<u32>,
     #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
     pub secret_manager: Option<SecretManagerDto>,
+    #[serde(default)]
+    pub permissions: MethodPermissions,
 }
 
 impl WalletOptions {
@@ -55,6 +67,12 @@ impl WalletOptions {
         self
     }
 
+    #[cfg(feature = "storage")]
+    pub fn with_storage_kind(mut self, storage_kind: impl Into<Option<StorageKind>>) -> Self {
+        self.storage_kind = storage_kind.into();
+        self
+    }
+
     pub fn with_client_options(mut self, client_options: impl Into<Option<ClientOptions>>) -> Self {
         self.client_options = client_options.into();
         self
@@ -70,6 +88,11 @@ impl WalletOptions {
         self
     }
 
+    pub fn with_permissions(mut self, permissions: MethodPermissions) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
     pub async fn build(self) -> iota_sdk::wallet::Result<Wallet> {
         log::debug!("wallet options: {self:?}");
         let mut builder = Wallet::builder()
@@ -77,8 +100,12 @@ impl WalletOptions {
             .with_coin_type(self.coin_type);
 
         #[cfg(feature = "storage")]
-        if let Some(storage_path) = &self.storage_path {
-            builder = builder.with_storage_path(storage_path);
+        if self.storage_path.is_some() || self.storage_kind.is_some() {
+            let storage_path = self
+                .storage_path
+                .map_or_else(|| StorageOptions::default().path().to_path_buf(), Into::into);
+            builder =
+                builder.with_storage_options(StorageOptions::new(storage_path, self.storage_kind.unwrap_or_default()));
         }
 
         if let Some(secret_manager) = self.secret_manager {
@@ -18,9 +18,8 @@ use iota_sdk::{
     client::{
         api::{PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto},
         constants::SHIMMER_TESTNET_BECH32_HRP,
-        request_funds_from_faucet,
         secret::SecretManage,
-        utils, Client, NodeInfoWrapper,
+        utils, NodeInfoWrapper,
     },
     types::{
         block::{
@@ -282,7 +281,7 @@ impl WalletMessageHandler {
                 convert_async_panics(|| async {
                     match url {
                         Some(url) => {
-                            let node_info = Client::get_node_info(&url, auth).await?;
+                            let node_info = self.wallet.client().get_node_info_from(&url, auth).await?;
                             Ok(Response::NodeInfo(NodeInfoWrapper { node_info, url }))
                         }
                         None => Ok(self.wallet.client().get_info().await.map(Response::NodeInfo)?),
@@ -987,7 +986,9 @@ impl WalletMessageHandler {
             }
             AccountMethod::RequestFundsFromFaucet { url, address } => {
                 convert_async_panics(|| async {
-                    Ok(Response::Faucet(request_funds_from_faucet(&url, &address).await?))
+                    Ok(Response::Faucet(
+                        account.client().request_funds_from_faucet(&url, &address).await?,
+                    ))
                 })
                 .await
             }
@@ -13,14 +13,18 @@ use iota_sdk::{
         secret::GenerateAddressOptions,
     },
     types::block::{
-        address::Bech32Address,
-        output::{dto::OutputDto, OutputId, TokenId},
+        output::{
+            dto::{OutputDto, TokenSchemeDto},
+            feature::dto::FeatureDto,
+            unlock_condition::dto::UnlockConditionDto,
+            AliasId, NativeToken, NftId, OutputId, TokenId,
+        },
         payload::transaction::TransactionId,
     },
     wallet::{
         account::{
-            ConsolidationParams, CreateAliasParams, CreateNativeTokenParams, FilterOptions, MintNftParams,
-            OutputParams, OutputsToClaim, SyncOptions, TransactionOptionsDto,
+            ConsolidationParams, ConsolidationPolicy, CreateAliasParams, CreateNativeTokenParams, FilterOptions,
+            MintNftParams, OutputParams, OutputsToClaim, SyncOptions, TransactionOptionsDto,
         },
         SendNativeTokensParams, SendNftParams, SendParams,
     },
@@ -40,6 +44,60 @@ pub enum AccountMethod {
     /// Expected response:
     /// [`AddressesWithUnspentOutputs`](crate::Response::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs,
+    /// Build an AliasOutput with the rent structure and token supply of the account's client.
+    /// Expected response: [`Output`](crate::Response::Output)
+    #[allow(missing_docs)]
+    #[serde(rename_all = "camelCase")]
+    BuildAliasOutput {
+        // If not provided, minimum storage deposit will be used
+        amount: Option<String>,
+        native_tokens: Option<Vec<NativeToken>>,
+        alias_id: AliasId,
+        state_index: Option<u32>,
+        state_metadata: Option<String>,
+        foundry_counter: Option<u32>,
+        unlock_conditions: Vec<UnlockConditionDto>,
+        features: Option<Vec<FeatureDto>>,
+        immutable_features: Option<Vec<FeatureDto>>,
+    },
+    /// Build a BasicOutput with the rent structure and token supply of the account's client.
+    /// Expected response: [`Output`](crate::Response::Output)
+    #[allow(missing_docs)]
+    #[serde(rename_all = "camelCase")]
+    BuildBasicOutput {
+        // If not provided, minimum storage deposit will be used
+        amount: Option<String>,
+        native_tokens: Option<Vec<NativeToken>>,
+        unlock_conditions: Vec<UnlockConditionDto>,
+        features: Option<Vec<FeatureDto>>,
+    },
+    /// Build a FoundryOutput with the rent structure and token supply of the account's client.
+    /// Expected response: [`Output`](crate::Response::Output)
+    #[allow(missing_docs)]
+    #[serde(rename_all = "camelCase")]
+    BuildFoundryOutput {
+        // If not provided, minimum storage deposit will be used
+        amount: Option<String>,
+        native_tokens: Option<Vec<NativeToken>>,
+        serial_number: u32,
+        token_scheme: TokenSchemeDto,
+        unlock_conditions: Vec<UnlockConditionDto>,
+        features: Option<Vec<FeatureDto>>,
+        immutable_features: Option<Vec<FeatureDto>>,
+    },
+    /// Build an NftOutput with the rent structure and token supply of the account's client.
+    /// Expected response: [`Output`](crate::Response::Output)
+    #[allow(missing_docs)]
+    #[serde(rename_all = "camelCase")]
+    BuildNftOutput {
+        // If not provided, minimum storage deposit will be used
+        amount: Option<String>,
+        native_tokens: Option<Vec<NativeToken>>,
+        nft_id: NftId,
+        unlock_conditions: Vec<UnlockConditionDto>,
+        features: Option<Vec<FeatureDto>>,
+        immutable_features: Option<Vec<FeatureDto>>,
+    },
     /// Get outputs with additional unlock conditions
     /// Expected response: [`OutputIds`](crate::Response::OutputIds)
     #[serde(rename_all = "camelCase")]
@@ -48,12 +106,27 @@ pub enum AccountMethod {
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     ClaimOutputs { output_ids_to_claim: Vec<OutputId> },
+    /// Claim all outputs which don't require additional funds of the account to be claimed.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    ClaimProfitableOutputs,
     /// Removes a previously registered participation event from local storage.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[serde(rename_all = "camelCase")]
     DeregisterParticipationEvent { event_id: ParticipationEventId },
+    /// Build a transaction with input selection and validation, without signing or sending it.
+    /// Expected response: [`DryRunTransaction`](crate::Response::DryRunTransaction)
+    DryRunTransaction {
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Estimate the storage deposit, the required input amount and the remainder of sending base coins.
+    /// Expected response: [`SendEstimate`](crate::Response::SendEstimate)
+    EstimateSend {
+        params: Vec<SendParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Generate new Ed25519 addresses.
     /// Expected response: [`GeneratedEd25519Addresses`](crate::Response::GeneratedEd25519Addresses)
     GenerateEd25519Addresses {
@@ -113,6 +186,11 @@ pub enum AccountMethod {
     /// Expected response: [`Transaction`](crate::Response::Transaction)
     #[serde(rename_all = "camelCase")]
     GetTransaction { transaction_id: TransactionId },
+    /// Get the [`TransactionReceipt`](iota_sdk::wallet::account::types::TransactionReceipt) of a transaction stored in
+    /// the account
+    /// Expected response: [`TransactionReceipt`](crate::Response::TransactionReceipt)
+    #[serde(rename_all = "camelCase")]
+    GetTransactionReceipt { transaction_id: TransactionId },
     /// Get the account's total voting power (voting or NOT voting).
     /// Expected response: [`VotingPower`](crate::Response::VotingPower)
     #[cfg(feature = "participation")]
@@ -122,6 +200,12 @@ pub enum AccountMethod {
     /// Expected response:
     /// [`Transactions`](crate::Response::Transactions)
     IncomingTransactions,
+    /// Mint NFTs in as many transactions as needed, waiting for each transaction before sending the next one.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    MintNftsInBatches {
+        params: Vec<MintNftParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Returns all outputs of the account
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
@@ -234,6 +318,15 @@ pub enum AccountMethod {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare to update the state metadata of an alias with a state transition.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareUpdateAliasState {
+        alias_id: AliasId,
+        #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+        state_metadata: Vec<u8>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Vote for a participation event.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     #[cfg(feature = "participation")]
@@ -252,6 +345,9 @@ pub enum AccountMethod {
     RegisterParticipationEvents {
         options: ParticipationEventRegistrationOptions,
     },
+    /// Remove a metadata key of the account.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    RemoveMetadata { key: String },
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
     /// included (referenced by a milestone). Returns the included block id.
     /// Expected response: [`BlockId`](crate::Response::BlockId)
@@ -269,7 +365,9 @@ pub enum AccountMethod {
     Send {
         #[serde(with = "iota_sdk::utils::serde::string")]
         amount: u64,
-        address: Bech32Address,
+        /// Bech32 encoded address, or the name of a contact of the wallet. Errors with the invalid address if
+        /// it's neither.
+        address: String,
         options: Option<TransactionOptionsDto>,
     },
     /// Send base coins to multiple addresses, or with additional parameters.
@@ -278,6 +376,12 @@ pub enum AccountMethod {
         params: Vec<SendParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send base coins to multiple addresses, or with additional parameters, and get the receipt of the transaction.
+    /// Expected response: [`TransactionReceipt`](crate::Response::TransactionReceipt)
+    SendWithReceipt {
+        params: Vec<SendParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Send outputs in a transaction.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     SendOutputs {
@@ -287,10 +391,16 @@ pub enum AccountMethod {
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetAlias { alias: String },
+    /// Set the policy that consolidates the basic outputs automatically while syncing, `None` stops it.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetConsolidationPolicy { policy: Option<ConsolidationPolicy> },
     /// Set the fallback SyncOptions for account syncing.
     /// If storage is enabled, will persist during restarts.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetDefaultSyncOptions { options: SyncOptions },
+    /// Set a metadata key of the account to the value.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetMetadata { key: String, value: String },
     /// Validate the transaction, sign it, submit it to a node and store it in the account.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -323,4 +433,11 @@ pub enum AccountMethod {
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
     UnspentOutputs { filter_options: Option<FilterOptions> },
+    /// Display the public address with the key index on the Ledger Nano device and check that the device derived the
+    /// same address.
+    /// Expected response: [`Bool`](crate::Response::Bool)
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    #[serde(rename_all = "camelCase")]
+    VerifyAddressOnDevice { key_index: u32 },
 }
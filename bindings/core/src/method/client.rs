@@ -20,7 +20,7 @@ use iota_sdk::{
             dto::{OutputDto, TokenSchemeDto},
             feature::dto::FeatureDto,
             unlock_condition::dto::UnlockConditionDto,
-            AliasId, FoundryId, NativeToken, NftId, OutputId,
+            AliasId, FoundryId, NativeToken, NftId, OutputId, TokenId,
         },
         payload::{dto::PayloadDto, milestone::MilestoneId, transaction::TransactionId},
         BlockDto, BlockId,
@@ -111,6 +111,8 @@ pub enum ClientMethod {
     GetNode,
     /// Gets the network related information such as network_id and min_pow_score
     GetNetworkInfo,
+    /// Exports the configuration of the client, which can be used to create a client with the same settings
+    ExportConfig,
     /// Gets the network id of the node we're connecting to.
     GetNetworkId,
     /// Returns the bech32_hrp
@@ -125,6 +127,9 @@ pub enum ClientMethod {
     GetLocalPow,
     /// Get fallback to local proof of work timeout
     GetFallbackToLocalPow,
+    /// Stops the running local proof of work, the blocks fail with a PoW cancelled error.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    CancelPow,
     /// Returns the unhealthy nodes.
     #[cfg(not(target_family = "wasm"))]
     UnhealthyNodes,
@@ -146,6 +151,15 @@ pub enum ClientMethod {
         /// Options
         options: Option<BuildBlockOptions>,
     },
+    /// Build a transaction with input selection and validation, without signing it, doing PoW or posting a block
+    #[serde(rename_all = "camelCase")]
+    DryRunTransaction {
+        /// Secret manager
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        secret_manager: Option<SecretManagerDto>,
+        /// Options
+        options: Option<BuildBlockOptions>,
+    },
     /// Sign a transaction
     #[serde(rename_all = "camelCase")]
     SignTransaction {
@@ -192,6 +206,12 @@ pub enum ClientMethod {
         /// Block
         block_bytes: Vec<u8>,
     },
+    /// Post already serialized block bytes as they are, without validating them and without local PoW fallback
+    #[serde(rename_all = "camelCase")]
+    PostBlockBytes {
+        /// Block
+        block_bytes: Vec<u8>,
+    },
     /// Get block
     #[serde(rename_all = "camelCase")]
     GetBlock {
@@ -375,6 +395,12 @@ pub enum ClientMethod {
         /// Addresses generation options
         generate_addresses_options: GenerateAddressesOptions,
     },
+    /// Resolves the foundry of a native token and decodes its IRC-30 metadata
+    #[serde(rename_all = "camelCase")]
+    GetNativeTokenMetadata {
+        /// Token ID
+        token_id: TokenId,
+    },
     /// Function to find inputs from addresses for a provided amount (useful for offline signing)
     FindInputs {
         /// Addresses
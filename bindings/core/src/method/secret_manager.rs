@@ -61,6 +61,17 @@ pub enum SecretManagerMethod {
         /// Prepared transaction data
         prepared_transaction_data: PreparedTransactionDataDto,
     },
+    /// Change the Stronghold password to another one and re-encrypt the snapshot file with it.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    #[serde(rename_all = "camelCase")]
+    ChangeStrongholdPassword {
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        current_password: String,
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        new_password: String,
+    },
     /// Store a mnemonic in the Stronghold vault
     #[cfg(feature = "stronghold")]
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
@@ -1,6 +1,9 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "stronghold")]
+use std::path::PathBuf;
+
 use derivative::Derivative;
 use iota_sdk::types::block::{
     address::{Bech32Address, Hrp},
@@ -156,4 +159,29 @@ pub enum UtilsMethod {
     /// Creates a UTXOInput from outputId.
     #[serde(rename_all = "camelCase")]
     OutputIdToUtxoInput { output_id: OutputId },
+    /// Verifies a Stronghold snapshot without loading it into a wallet.
+    /// Expected response: [`StrongholdSnapshotVerification`](crate::Response::StrongholdSnapshotVerification)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    VerifyStrongholdSnapshot {
+        /// The snapshot path.
+        path: PathBuf,
+        /// Stronghold file password.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
+    /// Writes the readable parts of a Stronghold snapshot to a new path, dropping corrupted records.
+    /// Expected response: [`StrongholdSnapshotVerification`](crate::Response::StrongholdSnapshotVerification)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    #[serde(rename_all = "camelCase")]
+    RepairStrongholdSnapshot {
+        /// The snapshot path.
+        path: PathBuf,
+        /// Stronghold file password.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+        /// The path to write the repaired snapshot to.
+        recovered_path: PathBuf,
+    },
 }
@@ -1,12 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(feature = "stronghold")]
+#[cfg(any(feature = "stronghold", not(target_family = "wasm")))]
 use std::path::PathBuf;
 
 use derivative::Derivative;
 #[cfg(feature = "events")]
 use iota_sdk::wallet::events::types::{WalletEvent, WalletEventType};
+#[cfg(feature = "storage")]
+use iota_sdk::wallet::Contact;
 use iota_sdk::{
     client::{node_manager::node::NodeAuth, secret::GenerateAddressOptions},
     types::block::address::Hrp,
@@ -52,11 +54,33 @@ pub enum WalletMethod {
     /// Read accounts.
     /// Expected response: [`Accounts`](crate::Response::Accounts)
     GetAccounts,
+    /// Change the alias of an account, which can't be used by another account.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    RenameAccount {
+        account_id: AccountIdentifier,
+        alias: String,
+    },
     /// Get historic chrysalis data.
     /// Expected response: [`ChrysalisData`](crate::Response::ChrysalisData)
     #[cfg(feature = "storage")]
     #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
     GetChrysalisData,
+    /// Add a contact to the address book, replacing the contact with the same name.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    AddContact { contact: Contact },
+    /// Read the contacts of the address book.
+    /// Expected response: [`Contacts`](crate::Response::Contacts)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    ListContacts,
+    /// Remove a contact from the address book.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    RemoveContact { name: String },
     /// Consume an account method.
     /// Returns [`Response`](crate::Response)
     #[serde(rename_all = "camelCase")]
@@ -77,6 +101,17 @@ pub enum WalletMethod {
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         password: String,
     },
+    /// Backup the accounts, client options and coin type to a file encrypted with the password, which doesn't contain
+    /// the secrets of the secret manager.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(not(target_family = "wasm"))]
+    BackupToFile {
+        /// The backup destination.
+        destination: PathBuf,
+        /// The password the file is encrypted with.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
     /// Change the Stronghold password to another one and also re-encrypt the values in the loaded snapshot with it.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "stronghold")]
@@ -110,6 +145,9 @@ pub enum WalletMethod {
         /// The number of addresses to search for, after the last address with unspent outputs, in
         /// each account.
         address_gap_limit: u32,
+        /// The number of internal addresses to search for, after the last internal address with unspent outputs, in
+        /// each account. Uses `address_gap_limit` if not set.
+        address_gap_limit_internal: Option<u32>,
         /// Optional parameter to specify the sync options. The `address_start_index` and `force_syncing`
         /// fields will be overwritten to skip existing addresses.
         sync_options: Option<SyncOptions>,
@@ -141,6 +179,17 @@ pub enum WalletMethod {
         /// accounts will be restored.
         ignore_if_bech32_mismatch: Option<Hrp>,
     },
+    /// Restore the accounts, client options and coin type of a file written with
+    /// [`BackupToFile`](WalletMethod::BackupToFile). Returns an error if accounts were already created.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(not(target_family = "wasm"))]
+    RestoreBackupFromFile {
+        /// The path to the backup file.
+        source: PathBuf,
+        /// The password the file is encrypted with.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
     /// Removes the latest account (account with the largest account index).
     /// Expected response: [`Ok`](crate::Response::Ok)
     RemoveLatestAccount,
@@ -200,6 +249,16 @@ pub enum WalletMethod {
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::Response::Ok)
     StopBackgroundSync,
+    /// Sync the accounts with at most `concurrency` accounts syncing at the same time, an account that failed to
+    /// sync doesn't prevent the others from being synced.
+    /// Expected response: [`AccountSyncResults`](crate::Response::AccountSyncResults)
+    #[serde(rename_all = "camelCase")]
+    SyncAll {
+        /// Sync options
+        options: Option<SyncOptions>,
+        /// The maximum amount of accounts that sync at the same time, 8 by default
+        concurrency: Option<usize>,
+    },
     /// Emits an event for testing if the event system is working
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "events")]
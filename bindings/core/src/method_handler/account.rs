@@ -6,7 +6,8 @@ use std::str::FromStr;
 
 use iota_sdk::{
     client::api::{
-        PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto,
+        DryRunTransactionDto, PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData,
+        SignedTransactionDataDto,
     },
     types::{
         block::output::{dto::OutputDto, Output},
@@ -17,7 +18,11 @@ use iota_sdk::{
     },
 };
 
-use crate::{method::AccountMethod, Response, Result};
+use super::client::call_client_method_internal;
+use crate::{
+    method::{AccountMethod, ClientMethod},
+    Response, Result,
+};
 
 pub(crate) async fn call_account_method_internal(account: &Account, method: AccountMethod) -> Result<Response> {
     let response = match method {
@@ -29,6 +34,94 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let addresses = account.addresses_with_unspent_outputs().await?;
             Response::AddressesWithUnspentOutputs(addresses)
         }
+        AccountMethod::BuildAliasOutput {
+            amount,
+            native_tokens,
+            alias_id,
+            state_index,
+            state_metadata,
+            foundry_counter,
+            unlock_conditions,
+            features,
+            immutable_features,
+        } => {
+            call_client_method_internal(
+                account.client(),
+                ClientMethod::BuildAliasOutput {
+                    amount,
+                    native_tokens,
+                    alias_id,
+                    state_index,
+                    state_metadata,
+                    foundry_counter,
+                    unlock_conditions,
+                    features,
+                    immutable_features,
+                },
+            )
+            .await?
+        }
+        AccountMethod::BuildBasicOutput {
+            amount,
+            native_tokens,
+            unlock_conditions,
+            features,
+        } => {
+            call_client_method_internal(
+                account.client(),
+                ClientMethod::BuildBasicOutput {
+                    amount,
+                    native_tokens,
+                    unlock_conditions,
+                    features,
+                },
+            )
+            .await?
+        }
+        AccountMethod::BuildFoundryOutput {
+            amount,
+            native_tokens,
+            serial_number,
+            token_scheme,
+            unlock_conditions,
+            features,
+            immutable_features,
+        } => {
+            call_client_method_internal(
+                account.client(),
+                ClientMethod::BuildFoundryOutput {
+                    amount,
+                    native_tokens,
+                    serial_number,
+                    token_scheme,
+                    unlock_conditions,
+                    features,
+                    immutable_features,
+                },
+            )
+            .await?
+        }
+        AccountMethod::BuildNftOutput {
+            amount,
+            native_tokens,
+            nft_id,
+            unlock_conditions,
+            features,
+            immutable_features,
+        } => {
+            call_client_method_internal(
+                account.client(),
+                ClientMethod::BuildNftOutput {
+                    amount,
+                    native_tokens,
+                    nft_id,
+                    unlock_conditions,
+                    features,
+                    immutable_features,
+                },
+            )
+            .await?
+        }
         AccountMethod::ClaimableOutputs { outputs_to_claim } => {
             let output_ids = account.claimable_outputs(outputs_to_claim).await?;
             Response::OutputIds(output_ids)
@@ -37,11 +130,34 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transaction = account.claim_outputs(output_ids_to_claim.to_vec()).await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::ClaimProfitableOutputs => {
+            let transactions = account.claim_profitable_outputs().await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
         #[cfg(feature = "participation")]
         AccountMethod::DeregisterParticipationEvent { event_id } => {
             account.deregister_participation_event(&event_id).await?;
             Response::Ok
         }
+        AccountMethod::DryRunTransaction { outputs, options } => {
+            let token_supply = account.client().get_token_supply().await?;
+            let dry_run = account
+                .dry_run_transaction(
+                    outputs
+                        .into_iter()
+                        .map(|o| Ok(Output::try_from_dto_with_params(o, token_supply)?))
+                        .collect::<Result<Vec<Output>>>()?,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::DryRunTransaction(DryRunTransactionDto::from(&dry_run))
+        }
+        AccountMethod::EstimateSend { params, options } => {
+            let estimate = account
+                .estimate_send(params, options.map(TransactionOptions::try_from_dto).transpose()?)
+                .await?;
+            Response::SendEstimate(estimate)
+        }
         AccountMethod::GenerateEd25519Addresses { amount, options } => {
             let address = account.generate_ed25519_addresses(amount, options).await?;
             Response::GeneratedAccountAddresses(address)
@@ -92,6 +208,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transaction = account.get_transaction(&transaction_id).await;
             Response::Transaction(transaction.as_ref().map(TransactionDto::from).map(Box::new))
         }
+        AccountMethod::GetTransactionReceipt { transaction_id } => {
+            let receipt = account.get_transaction_receipt(&transaction_id).await?;
+            Response::TransactionReceipt(receipt)
+        }
         #[cfg(feature = "participation")]
         AccountMethod::GetVotingPower => {
             let voting_power = account.get_voting_power().await?;
@@ -101,6 +221,12 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transactions = account.incoming_transactions().await;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
         }
+        AccountMethod::MintNftsInBatches { params, options } => {
+            let transactions = account
+                .mint_nfts_in_batches(params, options.map(TransactionOptions::try_from_dto).transpose()?)
+                .await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
         AccountMethod::Outputs { filter_options } => {
             let outputs = account.outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
@@ -237,6 +363,20 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::PrepareUpdateAliasState {
+            alias_id,
+            state_metadata,
+            options,
+        } => {
+            let data = account
+                .prepare_update_alias_state(
+                    alias_id,
+                    state_metadata,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         #[cfg(feature = "participation")]
         AccountMethod::PrepareVote { event_id, answers } => {
             let data = account.prepare_vote(event_id, answers).await?;
@@ -247,6 +387,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let events = account.register_participation_events(&options).await?;
             Response::ParticipationEvents(events)
         }
+        AccountMethod::RemoveMetadata { key } => {
+            account.remove_metadata(&key).await?;
+            Response::Ok
+        }
         AccountMethod::RetryTransactionUntilIncluded {
             transaction_id,
             interval,
@@ -262,13 +406,19 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             address,
             options,
         } => {
-            let transaction = account
-                .send(
-                    amount,
-                    address,
-                    options.map(TransactionOptions::try_from_dto).transpose()?,
-                )
-                .await?;
+            let options = options.map(TransactionOptions::try_from_dto).transpose()?;
+            // Anything that isn't a bech32 address is looked up in the contacts
+            #[cfg(feature = "storage")]
+            let transaction = match iota_sdk::types::block::address::Bech32Address::try_from_str(&address) {
+                Ok(address) => account.send(amount, address, options).await?,
+                Err(e) => match account.send_to_contact(amount, &address, options).await {
+                    // Neither a valid bech32 address nor the name of a contact
+                    Err(iota_sdk::wallet::Error::ContactNotFound(_)) => return Err(e.into()),
+                    transaction => transaction?,
+                },
+            };
+            #[cfg(not(feature = "storage"))]
+            let transaction = account.send(amount, address, options).await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
         AccountMethod::SendWithParams { params, options } => {
@@ -277,6 +427,12 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SendWithReceipt { params, options } => {
+            let receipt = account
+                .send_with_receipt(params, options.map(TransactionOptions::try_from_dto).transpose()?)
+                .await?;
+            Response::TransactionReceipt(Some(receipt))
+        }
         AccountMethod::SendOutputs { outputs, options } => {
             let token_supply = account.client().get_token_supply().await?;
             let transaction = account
@@ -294,10 +450,25 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             account.set_alias(&alias).await?;
             Response::Ok
         }
+        AccountMethod::SetConsolidationPolicy { policy } => {
+            match policy {
+                Some(policy) => {
+                    account
+                        .set_consolidation_policy(policy.output_threshold, policy.unlock_conditions_allowed)
+                        .await?
+                }
+                None => account.clear_consolidation_policy().await?,
+            }
+            Response::Ok
+        }
         AccountMethod::SetDefaultSyncOptions { options } => {
             account.set_default_sync_options(options).await?;
             Response::Ok
         }
+        AccountMethod::SetMetadata { key, value } => {
+            account.set_metadata(key, value).await?;
+            Response::Ok
+        }
         AccountMethod::SignAndSubmitTransaction {
             prepared_transaction_data,
         } => {
@@ -341,6 +512,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let outputs = account.unspent_outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
         }
+        #[cfg(feature = "ledger_nano")]
+        AccountMethod::VerifyAddressOnDevice { key_index } => {
+            Response::Bool(account.verify_address_on_device(key_index).await?)
+        }
     };
     Ok(response)
 }
@@ -18,7 +18,7 @@ use crate::{
     },
     panic::{convert_async_panics, convert_panics},
     response::Response,
-    UtilsMethod,
+    MethodPermissions, UtilsMethod,
 };
 
 pub trait CallMethod {
@@ -57,8 +57,18 @@ pub async fn call_client_method(client: &Client, method: ClientMethod) -> Respon
 
 /// Call a wallet method.
 pub async fn call_wallet_method(wallet: &Wallet, method: WalletMethod) -> Response {
+    call_wallet_method_with_permissions(wallet, method, &MethodPermissions::default()).await
+}
+
+/// Call a wallet method, if it and the account method it may call are permitted.
+pub async fn call_wallet_method_with_permissions(
+    wallet: &Wallet,
+    method: WalletMethod,
+    permissions: &MethodPermissions,
+) -> Response {
     log::debug!("Wallet method: {method:?}");
-    let result = convert_async_panics(|| async { call_wallet_method_internal(wallet, method).await }).await;
+    let result =
+        convert_async_panics(|| async { call_wallet_method_internal(wallet, method, permissions).await }).await;
 
     let response = result.unwrap_or_else(Response::Error);
 
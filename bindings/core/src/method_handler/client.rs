@@ -5,8 +5,8 @@
 use iota_sdk::client::mqtt::{MqttPayload, Topic};
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionData, PreparedTransactionDataDto},
-        request_funds_from_faucet, Client,
+        api::{DryRunTransactionDto, PreparedTransactionData, PreparedTransactionDataDto},
+        Client,
     },
     types::{
         api::core::response::OutputWithMetadataResponse,
@@ -14,7 +14,7 @@ use iota_sdk::{
             input::dto::UtxoInputDto,
             output::{
                 dto::{OutputBuilderAmountDto, OutputDto},
-                AliasOutput, BasicOutput, FoundryOutput, NftOutput, Output, Rent,
+                AliasOutput, BasicOutput, FoundryOutput, NftOutput, Output,
             },
             payload::{dto::MilestonePayloadDto, Payload},
             Block, BlockDto,
@@ -198,6 +198,7 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         }
         ClientMethod::GetNode => Response::Node(client.get_node().await?),
         ClientMethod::GetNetworkInfo => Response::NetworkInfo(client.get_network_info().await?),
+        ClientMethod::ExportConfig => Response::ClientConfig(client.export_config().await),
         ClientMethod::GetNetworkId => Response::NetworkId(client.get_network_id().await?),
         ClientMethod::GetBech32Hrp => Response::Bech32Hrp(client.get_bech32_hrp().await?),
         ClientMethod::GetMinPowScore => Response::MinPowScore(client.get_min_pow_score().await?),
@@ -205,6 +206,10 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetProtocolParameters => Response::ProtocolParameters(client.get_protocol_parameters().await?),
         ClientMethod::GetLocalPow => Response::Bool(client.get_local_pow().await),
         ClientMethod::GetFallbackToLocalPow => Response::Bool(client.get_fallback_to_local_pow().await),
+        ClientMethod::CancelPow => {
+            client.cancel_pow().await;
+            Response::Ok
+        }
         ClientMethod::PrepareTransaction {
             secret_manager,
             options,
@@ -228,6 +233,27 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                 &block_builder.prepare_transaction().await?,
             ))
         }
+        ClientMethod::DryRunTransaction {
+            secret_manager,
+            options,
+        } => {
+            let mut block_builder = client.build_block();
+
+            let secret_manager = match secret_manager {
+                Some(secret_manager) => Some(secret_manager.try_into()?),
+                None => None,
+            };
+
+            if let Some(secret_manager) = &secret_manager {
+                block_builder = block_builder.with_secret_manager(secret_manager);
+            }
+
+            if let Some(options) = options {
+                block_builder = block_builder.set_options(options).await?;
+            }
+
+            Response::DryRunTransaction(DryRunTransactionDto::from(&block_builder.dry_run().await?))
+        }
         ClientMethod::SignTransaction {
             secret_manager,
             prepared_transaction_data,
@@ -262,7 +288,7 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         #[cfg(not(target_family = "wasm"))]
         ClientMethod::UnhealthyNodes => Response::UnhealthyNodes(client.unhealthy_nodes().await.into_iter().collect()),
         ClientMethod::GetHealth { url } => Response::Bool(client.get_health(&url).await?),
-        ClientMethod::GetNodeInfo { url, auth } => Response::NodeInfo(Client::get_node_info(&url, auth).await?),
+        ClientMethod::GetNodeInfo { url, auth } => Response::NodeInfo(client.get_node_info_from(&url, auth).await?),
         ClientMethod::GetInfo => Response::Info(client.get_info().await?),
         ClientMethod::GetPeers => Response::Peers(client.get_peers().await?),
         ClientMethod::GetTips => Response::Tips(client.get_tips().await?),
@@ -274,6 +300,7 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                 )?)
                 .await?,
         ),
+        ClientMethod::PostBlockBytes { block_bytes } => Response::BlockId(client.post_block_bytes(&block_bytes).await?),
         ClientMethod::PostBlock { block } => Response::BlockId(
             client
                 .post_block(&Block::try_from_dto_with_params(
@@ -393,6 +420,9 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                     .await?,
             )
         }
+        ClientMethod::GetNativeTokenMetadata { token_id } => {
+            Response::NativeTokenMetadata(client.get_native_token_metadata(token_id).await?)
+        }
         ClientMethod::FindInputs { addresses, amount } => Response::Inputs(
             client
                 .find_inputs(addresses, amount)
@@ -431,14 +461,12 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         }
         ClientMethod::MinimumRequiredStorageDeposit { output } => {
             let output = Output::try_from_dto_with_params(output, client.get_token_supply().await?)?;
-            let rent_structure = client.get_rent_structure().await?;
-
-            let minimum_storage_deposit = output.rent_cost(&rent_structure);
+            let minimum_storage_deposit = client.estimate_storage_deposit(&output).await?;
 
             Response::MinimumRequiredStorageDeposit(minimum_storage_deposit.to_string())
         }
         ClientMethod::RequestFundsFromFaucet { url, address } => {
-            Response::Faucet(request_funds_from_faucet(&url, &address).await?)
+            Response::Faucet(client.request_funds_from_faucet(&url, &address).await?)
         }
         ClientMethod::CallPluginRoute {
             base_plugin_path,
@@ -71,6 +71,18 @@ pub(crate) async fn call_secret_manager_method_internal(
             }
         }
         #[cfg(feature = "stronghold")]
+        SecretManagerMethod::ChangeStrongholdPassword {
+            current_password,
+            new_password,
+        } => {
+            if let SecretManager::Stronghold(secret_manager) = &*secret_manager {
+                secret_manager.rotate_password(current_password, new_password).await?;
+                Response::Ok
+            } else {
+                return Err(iota_sdk::client::Error::SecretManagerMismatch.into());
+            }
+        }
+        #[cfg(feature = "stronghold")]
         SecretManagerMethod::StoreMnemonic { mnemonic } => {
             let mnemonic = crypto::keys::bip39::Mnemonic::from(mnemonic);
             if let SecretManager::Stronghold(secret_manager) = &*secret_manager {
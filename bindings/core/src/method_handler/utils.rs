@@ -109,6 +109,18 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             Response::Bool(public_key.verify_keccak256(&signature, &message))
         }
         UtilsMethod::OutputIdToUtxoInput { output_id } => Response::Input((&UtxoInput::from(output_id)).into()),
+        #[cfg(feature = "stronghold")]
+        UtilsMethod::VerifyStrongholdSnapshot { path, password } => Response::StrongholdSnapshotVerification(
+            iota_sdk::client::stronghold::StrongholdAdapter::verify_snapshot(path, password.into())?,
+        ),
+        #[cfg(feature = "stronghold")]
+        UtilsMethod::RepairStrongholdSnapshot {
+            path,
+            password,
+            recovered_path,
+        } => Response::StrongholdSnapshotVerification(
+            iota_sdk::client::stronghold::StrongholdAdapter::repair_snapshot(path, password.into(), recovered_path)?,
+        ),
     };
     Ok(response)
 }
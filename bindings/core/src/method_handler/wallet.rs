@@ -5,14 +5,24 @@ use std::time::Duration;
 
 use iota_sdk::{
     types::block::address::ToBech32Ext,
-    wallet::{account::AccountDetailsDto, Wallet},
+    wallet::{account::AccountDetailsDto, RecoverAccountsOptions, Wallet},
 };
 
 use super::account::call_account_method_internal;
-use crate::{method::WalletMethod, response::Response, Result};
+use crate::{
+    method::WalletMethod,
+    response::{AccountSyncResult, Response},
+    MethodPermissions, Result,
+};
 
 /// Call a wallet method.
-pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletMethod) -> Result<Response> {
+pub(crate) async fn call_wallet_method_internal(
+    wallet: &Wallet,
+    method: WalletMethod,
+    permissions: &MethodPermissions,
+) -> Result<Response> {
+    permissions.check_method(&method)?;
+
     let response = match method {
         WalletMethod::CreateAccount {
             alias,
@@ -46,6 +56,10 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             let account = account.details().await;
             Response::Account(AccountDetailsDto::from(&*account))
         }
+        WalletMethod::RenameAccount { account_id, alias } => {
+            wallet.rename_account(account_id, alias).await?;
+            Response::Ok
+        }
         WalletMethod::GetAccountIndexes => {
             let accounts = wallet.get_accounts().await?;
             let mut account_indexes = Vec::with_capacity(accounts.len());
@@ -64,7 +78,20 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             Response::Accounts(account_dtos)
         }
         WalletMethod::GetChrysalisData => Response::ChrysalisData(wallet.get_chrysalis_data().await?),
+        #[cfg(feature = "storage")]
+        WalletMethod::AddContact { contact } => {
+            wallet.add_contact(contact).await?;
+            Response::Ok
+        }
+        #[cfg(feature = "storage")]
+        WalletMethod::ListContacts => Response::Contacts(wallet.list_contacts().await?),
+        #[cfg(feature = "storage")]
+        WalletMethod::RemoveContact { name } => {
+            wallet.remove_contact(&name).await?;
+            Response::Ok
+        }
         WalletMethod::CallAccountMethod { account_id, method } => {
+            permissions.check_method(&method)?;
             let account = wallet.get_account(account_id).await?;
             call_account_method_internal(&account, method).await?
         }
@@ -73,6 +100,11 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.backup(destination, password).await?;
             Response::Ok
         }
+        #[cfg(not(target_family = "wasm"))]
+        WalletMethod::BackupToFile { destination, password } => {
+            wallet.backup_to_file(destination, password).await?;
+            Response::Ok
+        }
         #[cfg(feature = "stronghold")]
         WalletMethod::ChangeStrongholdPassword {
             current_password,
@@ -97,14 +129,21 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             account_start_index,
             account_gap_limit,
             address_gap_limit,
+            address_gap_limit_internal,
             sync_options,
         } => {
-            let accounts = wallet
-                .recover_accounts(account_start_index, account_gap_limit, address_gap_limit, sync_options)
+            let recovered_accounts = wallet
+                .recover_accounts_with_options(RecoverAccountsOptions {
+                    account_start_index,
+                    account_gap_limit,
+                    address_gap_limit,
+                    address_gap_limit_internal,
+                    sync_options,
+                })
                 .await?;
-            let mut account_dtos = Vec::with_capacity(accounts.len());
-            for account in accounts {
-                let account = account.details().await;
+            let mut account_dtos = Vec::with_capacity(recovered_accounts.len());
+            for recovered_account in recovered_accounts {
+                let account = recovered_account.account.details().await;
                 account_dtos.push(AccountDetailsDto::from(&*account));
             }
             Response::Accounts(account_dtos)
@@ -130,6 +169,11 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
                 .await?;
             Response::Ok
         }
+        #[cfg(not(target_family = "wasm"))]
+        WalletMethod::RestoreBackupFromFile { source, password } => {
+            wallet.restore_backup_from_file(source, password).await?;
+            Response::Ok
+        }
         WalletMethod::SetClientOptions { client_options } => {
             wallet.set_client_options(*client_options).await?;
             Response::Ok
@@ -186,6 +230,14 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.stop_background_syncing().await?;
             Response::Ok
         }
+        WalletMethod::SyncAll { options, concurrency } => Response::AccountSyncResults(
+            wallet
+                .sync_all(options, concurrency)
+                .await?
+                .into_iter()
+                .map(|(account_index, result)| AccountSyncResult::new(account_index, result))
+                .collect(),
+        ),
         #[cfg(feature = "events")]
         WalletMethod::EmitTestEvent { event } => {
             wallet.emit_test_event(event.clone()).await;
@@ -0,0 +1,74 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// Restricts the methods a method handler is allowed to call.
+///
+/// Methods are identified by their serialized name, e.g. `storeMnemonic` or `signTransactionEssence`. For methods that
+/// wrap another method, like `callAccountMethod`, both the outer and the inner method must be permitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodPermissions {
+    /// If set, only these methods can be called.
+    #[serde(default)]
+    pub allowed_methods: Option<HashSet<String>>,
+    /// These methods can never be called.
+    #[serde(default)]
+    pub denied_methods: HashSet<String>,
+}
+
+impl MethodPermissions {
+    /// Only allows calling the given methods.
+    pub fn with_allowed_methods(mut self, methods: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.allowed_methods = Some(methods.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Denies calling the given methods.
+    pub fn with_denied_methods(mut self, methods: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.denied_methods = methods.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns whether the method with the given name is permitted.
+    pub fn is_permitted(&self, method_name: &str) -> bool {
+        !self.denied_methods.contains(method_name)
+            && self
+                .allowed_methods
+                .as_ref()
+                .map_or(true, |allowed| allowed.contains(method_name))
+    }
+
+    /// Checks that a method, and any method it wraps, is permitted.
+    pub fn check<M: Serialize>(&self, method: &M) -> Result<()> {
+        self.check_inner(method, true)
+    }
+
+    /// Checks that a method is permitted, without checking the methods it wraps, which are checked when they're called.
+    pub(crate) fn check_method<M: Serialize>(&self, method: &M) -> Result<()> {
+        self.check_inner(method, false)
+    }
+
+    fn check_inner<M: Serialize>(&self, method: &M, wrapped: bool) -> Result<()> {
+        if self.allowed_methods.is_none() && self.denied_methods.is_empty() {
+            return Ok(());
+        }
+
+        let mut value = serde_json::to_value(method)?;
+        loop {
+            let name = value["name"].as_str().unwrap_or_default();
+            if !self.is_permitted(name) {
+                return Err(Error::MethodNotPermitted(name.to_string()));
+            }
+            value = match value["data"].get_mut("method") {
+                Some(inner) if wrapped && inner.get("name").is_some() => inner.take(),
+                _ => return Ok(()),
+            };
+        }
+    }
+}
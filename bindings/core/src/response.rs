@@ -8,11 +8,15 @@ use std::collections::HashSet;
 use derivative::Derivative;
 #[cfg(feature = "ledger_nano")]
 use iota_sdk::client::secret::LedgerNanoStatus;
+#[cfg(feature = "stronghold")]
+use iota_sdk::client::stronghold::SnapshotVerification;
+#[cfg(feature = "storage")]
+use iota_sdk::wallet::Contact;
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+        api::{DryRunTransactionDto, NativeTokenMetadata, PreparedTransactionDataDto, SignedTransactionDataDto},
         node_manager::node::Node,
-        NetworkInfo, NodeInfoWrapper,
+        ClientConfig, NetworkInfo, NodeInfoWrapper,
     },
     types::{
         api::{
@@ -37,9 +41,14 @@ use iota_sdk::{
             BlockDto, BlockId,
         },
     },
-    wallet::account::{
-        types::{AccountAddress, AddressWithUnspentOutputs, Balance, OutputDataDto, TransactionDto},
-        AccountDetailsDto, PreparedCreateNativeTokenTransactionDto,
+    wallet::{
+        account::{
+            types::{
+                AccountAddress, AddressWithUnspentOutputs, Balance, OutputDataDto, TransactionDto, TransactionReceipt,
+            },
+            AccountDetailsDto, PreparedCreateNativeTokenTransactionDto,
+        },
+        SendEstimate,
     },
 };
 use serde::Serialize;
@@ -70,6 +79,9 @@ pub enum Response {
     /// - [`GetNetworkInfo`](crate::method::ClientMethod::GetNetworkInfo)
     NetworkInfo(NetworkInfo),
     /// Response for:
+    /// - [`ExportConfig`](crate::method::ClientMethod::ExportConfig)
+    ClientConfig(ClientConfig),
+    /// Response for:
     /// - [`GetNetworkId`](crate::method::ClientMethod::GetNetworkId)
     NetworkId(u64),
     /// Response for:
@@ -91,6 +103,10 @@ pub enum Response {
     /// - [`SignTransaction`](crate::method::ClientMethod::SignTransaction)
     SignedTransaction(TransactionPayloadDto),
     /// Response for:
+    /// - [`DryRunTransaction`](crate::method::ClientMethod::DryRunTransaction)
+    /// - [`DryRunTransaction`](crate::method::AccountMethod::DryRunTransaction)
+    DryRunTransaction(DryRunTransactionDto),
+    /// Response for:
     /// - [`SignatureUnlock`](crate::method::SecretManagerMethod::SignatureUnlock)
     SignatureUnlock(UnlockDto),
     /// Response for:
@@ -179,6 +195,9 @@ pub enum Response {
     /// - [`ConsolidateFunds`](crate::method::ClientMethod::ConsolidateFunds)
     ConsolidatedFunds(Bech32Address),
     /// Response for:
+    /// - [`GetNativeTokenMetadata`](crate::method::ClientMethod::GetNativeTokenMetadata)
+    NativeTokenMetadata(NativeTokenMetadata),
+    /// Response for:
     /// - [`FindInputs`](crate::method::ClientMethod::FindInputs)
     Inputs(Vec<UtxoInputDto>),
     /// Response for:
@@ -236,6 +255,10 @@ pub enum Response {
     /// - [`BuildBasicOutput`](crate::method::ClientMethod::BuildBasicOutput)
     /// - [`BuildFoundryOutput`](crate::method::ClientMethod::BuildFoundryOutput)
     /// - [`BuildNftOutput`](crate::method::ClientMethod::BuildNftOutput)
+    /// - [`BuildAliasOutput`](crate::method::AccountMethod::BuildAliasOutput)
+    /// - [`BuildBasicOutput`](crate::method::AccountMethod::BuildBasicOutput)
+    /// - [`BuildFoundryOutput`](crate::method::AccountMethod::BuildFoundryOutput)
+    /// - [`BuildNftOutput`](crate::method::AccountMethod::BuildNftOutput)
     /// - [`GetFoundryOutput`](crate::method::AccountMethod::GetFoundryOutput)
     /// - [`PrepareOutput`](crate::method::AccountMethod::PrepareOutput)
     Output(OutputDto),
@@ -256,9 +279,16 @@ pub enum Response {
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerNanoStatus(LedgerNanoStatus),
     /// Response for:
+    /// - [`VerifyStrongholdSnapshot`](crate::method::UtilsMethod::VerifyStrongholdSnapshot)
+    /// - [`RepairStrongholdSnapshot`](crate::method::UtilsMethod::RepairStrongholdSnapshot)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    StrongholdSnapshotVerification(SnapshotVerification),
+    /// Response for:
     /// - [`BlockId`](crate::method::UtilsMethod::BlockId)
     /// - [`PostBlock`](crate::method::ClientMethod::PostBlock)
     /// - [`PostBlockRaw`](crate::method::ClientMethod::PostBlockRaw)
+    /// - [`PostBlockBytes`](crate::method::ClientMethod::PostBlockBytes)
     /// - [`RetryTransactionUntilIncluded`](crate::method::AccountMethod::RetryTransactionUntilIncluded)
     BlockId(BlockId),
     /// Response for:
@@ -268,17 +298,25 @@ pub enum Response {
     /// - [`IsAddressValid`](crate::method::UtilsMethod::IsAddressValid)
     /// - [`VerifyEd25519Signature`](crate::method::UtilsMethod::VerifyEd25519Signature)
     /// - [`VerifySecp256k1EcdsaSignature`](crate::method::UtilsMethod::VerifySecp256k1EcdsaSignature)
+    /// - [`VerifyAddressOnDevice`](crate::method::AccountMethod::VerifyAddressOnDevice)
     Bool(bool),
     /// Response for:
+    /// - [`AddContact`](crate::method::WalletMethod::AddContact),
     /// - [`Backup`](crate::method::WalletMethod::Backup),
+    /// - [`CancelPow`](crate::method::ClientMethod::CancelPow),
     /// - [`ClearListeners`](crate::method::WalletMethod::ClearListeners)
     /// - [`ClearStrongholdPassword`](crate::method::WalletMethod::ClearStrongholdPassword),
     /// - [`DeregisterParticipationEvent`](crate::method::AccountMethod::DeregisterParticipationEvent),
     /// - [`EmitTestEvent`](crate::method::WalletMethod::EmitTestEvent),
+    /// - [`RemoveContact`](crate::method::WalletMethod::RemoveContact),
+    /// - [`RemoveMetadata`](crate::method::AccountMethod::RemoveMetadata),
+    /// - [`RenameAccount`](crate::method::WalletMethod::RenameAccount),
     /// - [`RestoreBackup`](crate::method::WalletMethod::RestoreBackup),
     /// - [`SetAlias`](crate::method::AccountMethod::SetAlias),
     /// - [`SetClientOptions`](crate::method::WalletMethod::SetClientOptions),
+    /// - [`SetConsolidationPolicy`](crate::method::AccountMethod::SetConsolidationPolicy),
     /// - [`SetDefaultSyncOptions`](crate::method::AccountMethod::SetDefaultSyncOptions),
+    /// - [`SetMetadata`](crate::method::AccountMethod::SetMetadata),
     /// - [`SetStrongholdPassword`](crate::method::WalletMethod::SetStrongholdPassword),
     /// - [`SetStrongholdPasswordClearInterval`](crate::method::WalletMethod::SetStrongholdPasswordClearInterval),
     /// - [`StartBackgroundSync`](crate::method::WalletMethod::StartBackgroundSync),
@@ -302,6 +340,9 @@ pub enum Response {
     /// - [`GetAccounts`](crate::method::WalletMethod::GetAccounts)
     Accounts(Vec<AccountDetailsDto>),
     /// Response for:
+    /// - [`SyncAll`](crate::method::WalletMethod::SyncAll)
+    AccountSyncResults(Vec<AccountSyncResult>),
+    /// Response for:
     /// - [`Addresses`](crate::method::AccountMethod::Addresses)
     Addresses(Vec<AccountAddress>),
     /// Response for:
@@ -311,6 +352,11 @@ pub enum Response {
     /// - [`GetChrysalisData`](crate::method::WalletMethod::GetChrysalisData)
     ChrysalisData(Option<HashMap<String, String>>),
     /// Response for:
+    /// - [`ListContacts`](crate::method::WalletMethod::ListContacts)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    Contacts(Vec<Contact>),
+    /// Response for:
     /// - [`MinimumRequiredStorageDeposit`](crate::method::ClientMethod::MinimumRequiredStorageDeposit)
     /// - [`ComputeStorageDeposit`](crate::method::UtilsMethod::ComputeStorageDeposit)
     MinimumRequiredStorageDeposit(String),
@@ -344,11 +390,20 @@ pub enum Response {
     /// - [`PrepareCreateNativeToken`](crate::method::AccountMethod::PrepareCreateNativeToken),
     PreparedCreateNativeTokenTransaction(PreparedCreateNativeTokenTransactionDto),
     /// Response for:
+    /// - [`EstimateSend`](crate::method::AccountMethod::EstimateSend)
+    SendEstimate(SendEstimate),
+    /// Response for:
     /// - [`GetIncomingTransaction`](crate::method::AccountMethod::GetIncomingTransaction)
     /// - [`GetTransaction`](crate::method::AccountMethod::GetTransaction),
     Transaction(Option<Box<TransactionDto>>),
     /// Response for:
+    /// - [`GetTransactionReceipt`](crate::method::AccountMethod::GetTransactionReceipt)
+    /// - [`SendWithReceipt`](crate::method::AccountMethod::SendWithReceipt)
+    TransactionReceipt(Option<TransactionReceipt>),
+    /// Response for:
+    /// - [`ClaimProfitableOutputs`](crate::method::AccountMethod::ClaimProfitableOutputs)
     /// - [`IncomingTransactions`](crate::method::AccountMethod::IncomingTransactions)
+    /// - [`MintNftsInBatches`](crate::method::AccountMethod::MintNftsInBatches)
     /// - [`PendingTransactions`](crate::method::AccountMethod::PendingTransactions),
     /// - [`Transactions`](crate::method::AccountMethod::Transactions),
     Transactions(Vec<TransactionDto>),
@@ -402,3 +457,34 @@ pub enum Response {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     AccountParticipationOverview(AccountParticipationOverview),
 }
+
+/// The result of syncing an account with [`SyncAll`](crate::method::WalletMethod::SyncAll).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSyncResult {
+    /// The index of the account.
+    pub account_index: u32,
+    /// The balance of the account, if it synced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<Balance>,
+    /// The error, if the account failed to sync.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+impl AccountSyncResult {
+    pub(crate) fn new(account_index: u32, result: iota_sdk::wallet::Result<Balance>) -> Self {
+        match result {
+            Ok(balance) => Self {
+                account_index,
+                balance: Some(balance),
+                error: None,
+            },
+            Err(error) => Self {
+                account_index,
+                balance: None,
+                error: Some(error.into()),
+            },
+        }
+    }
+}
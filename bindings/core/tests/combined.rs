@@ -7,7 +7,11 @@ use iota_sdk::{
     client::{constants::SHIMMER_COIN_TYPE, secret::SecretManagerDto, ClientBuilder},
     wallet::account::types::AccountIdentifier,
 };
-use iota_sdk_bindings_core::{AccountMethod, CallMethod, ClientMethod, Response, Result, WalletMethod, WalletOptions};
+#[cfg(feature = "storage")]
+use iota_sdk::{types::block::address::Bech32Address, wallet::Contact};
+use iota_sdk_bindings_core::{
+    AccountMethod, CallMethod, ClientMethod, Error, Response, Result, WalletMethod, WalletOptions,
+};
 
 #[tokio::test]
 async fn create_account() -> Result<()> {
@@ -175,6 +179,120 @@ async fn verify_accounts() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn sync_all() -> Result<()> {
+    let storage_path = "test-storage/sync_all";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let wallet = WalletOptions::default()
+        .with_storage_path(storage_path.to_string())
+        .with_client_options(ClientBuilder::new().from_json(client_options).unwrap())
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_secret_manager(serde_json::from_str::<SecretManagerDto>(secret_manager).unwrap())
+        .build()
+        .await?;
+
+    for _ in 0..2 {
+        wallet.create_account().finish().await?;
+    }
+
+    let method = serde_json::from_str::<WalletMethod>(r#"{"name":"syncAll","data":{"concurrency":1}}"#)?;
+    let response = wallet.call_method(method).await;
+
+    match response {
+        Response::AccountSyncResults(results) => {
+            assert_eq!(
+                results.iter().map(|result| result.account_index).collect::<Vec<_>>(),
+                [0, 1]
+            );
+            for result in &results {
+                // Every account either synced or failed on its own, e.g. without a node
+                assert!(result.balance.is_some() != result.error.is_some());
+
+                let json = serde_json::to_value(result)?;
+                assert_eq!(json["accountIndex"], result.account_index);
+                assert_eq!(json.get("balance").is_some(), result.balance.is_some());
+                assert_eq!(json.get("error").is_some(), result.error.is_some());
+            }
+        }
+        _ => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn send_to_address_or_contact() -> Result<()> {
+    let storage_path = "test-storage/send_to_address_or_contact";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let wallet = WalletOptions::default()
+        .with_storage_path(storage_path.to_string())
+        .with_client_options(ClientBuilder::new().from_json(client_options).unwrap())
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_secret_manager(serde_json::from_str::<SecretManagerDto>(secret_manager).unwrap())
+        .build()
+        .await?;
+    wallet.create_account().finish().await?;
+    wallet
+        .add_contact(Contact::new(
+            "alice",
+            Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")?,
+        ))
+        .await?;
+
+    let send = |address: &str| WalletMethod::CallAccountMethod {
+        account_id: AccountIdentifier::Index(0),
+        method: AccountMethod::Send {
+            amount: 1_000_000,
+            address: address.to_string(),
+            options: None,
+        },
+    };
+
+    // Neither a valid bech32 address nor a contact
+    for address in ["bob", "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zz"] {
+        match wallet.call_method(send(address)).await {
+            Response::Error(Error::Block(_)) => {}
+            response => panic!("unexpected response {response:?}"),
+        }
+    }
+
+    // A contact is resolved to its address, the send then fails for another reason, e.g. without funds or a node
+    match wallet.call_method(send("alice")).await {
+        Response::Error(Error::Block(_)) => panic!("the contact wasn't resolved"),
+        Response::Error(_) => {}
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
 #[tokio::test]
 async fn client_from_wallet() -> Result<()> {
     let storage_path = "test-storage/client_from_wallet";
@@ -231,3 +349,22 @@ async fn client_from_wallet() -> Result<()> {
     std::fs::remove_dir_all(storage_path).ok();
     Ok(())
 }
+
+#[tokio::test]
+async fn post_block_raw_validates_block() -> Result<()> {
+    let client = ClientBuilder::new().finish().await?;
+
+    // The bytes are unpacked before the block is posted, so invalid bytes are rejected without a node
+    let response = client
+        .call_method(ClientMethod::PostBlockRaw {
+            block_bytes: vec![2, 1, 2, 3],
+        })
+        .await;
+
+    match response {
+        Response::Error(Error::Unpack(_)) => {}
+        _ => panic!("unexpected response {response:?}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{constants::SHIMMER_COIN_TYPE, secret::SecretManagerDto, ClientBuilder},
+    wallet::account::types::AccountIdentifier,
+};
+use iota_sdk_bindings_core::{
+    call_wallet_method_with_permissions, AccountMethod, Error, MethodPermissions, Response, Result, WalletMethod,
+    WalletOptions,
+};
+
+#[test]
+fn method_permissions() {
+    let get_balance = WalletMethod::CallAccountMethod {
+        account_id: AccountIdentifier::Index(0),
+        method: AccountMethod::GetBalance,
+    };
+
+    let permissions = MethodPermissions::default();
+    assert!(permissions.check(&WalletMethod::GetAccounts).is_ok());
+    assert!(permissions.check(&get_balance).is_ok());
+
+    let permissions = MethodPermissions::default().with_denied_methods(["getBalance"]);
+    assert!(permissions.check(&WalletMethod::GetAccounts).is_ok());
+    assert!(matches!(
+        permissions.check(&get_balance),
+        Err(Error::MethodNotPermitted(name)) if name == "getBalance"
+    ));
+
+    let permissions = MethodPermissions::default().with_allowed_methods(["callAccountMethod", "getBalance"]);
+    assert!(permissions.check(&get_balance).is_ok());
+    assert!(matches!(
+        permissions.check(&WalletMethod::GetAccounts),
+        Err(Error::MethodNotPermitted(name)) if name == "getAccounts"
+    ));
+}
+
+#[tokio::test]
+async fn account_method_permissions() -> Result<()> {
+    let storage_path = "test-storage/account_method_permissions";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+
+    let wallet = WalletOptions::default()
+        .with_storage_path(storage_path.to_string())
+        .with_client_options(ClientBuilder::new())
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_secret_manager(serde_json::from_str::<SecretManagerDto>(secret_manager).unwrap())
+        .build()
+        .await?;
+    wallet.create_account().finish().await?;
+
+    let unspent_outputs = || WalletMethod::CallAccountMethod {
+        account_id: AccountIdentifier::Index(0),
+        method: AccountMethod::UnspentOutputs { filter_options: None },
+    };
+
+    let permissions = MethodPermissions::default().with_allowed_methods(["callAccountMethod", "unspentOutputs"]);
+    let response = call_wallet_method_with_permissions(&wallet, unspent_outputs(), &permissions).await;
+    assert!(matches!(response, Response::OutputsData(_)), "{response:?}");
+
+    // The account method is checked even if the wallet method wrapping it is permitted
+    let permissions = MethodPermissions::default().with_denied_methods(["unspentOutputs"]);
+    let response = call_wallet_method_with_permissions(&wallet, unspent_outputs(), &permissions).await;
+    assert!(
+        matches!(&response, Response::Error(Error::MethodNotPermitted(name)) if name == "unspentOutputs"),
+        "{response:?}"
+    );
+
+    let permissions = MethodPermissions::default().with_allowed_methods(["callAccountMethod"]);
+    let response = call_wallet_method_with_permissions(&wallet, unspent_outputs(), &permissions).await;
+    assert!(
+        matches!(&response, Response::Error(Error::MethodNotPermitted(name)) if name == "unspentOutputs"),
+        "{response:?}"
+    );
+
+    let permissions = MethodPermissions::default().with_denied_methods(["callAccountMethod"]);
+    let response = call_wallet_method_with_permissions(&wallet, unspent_outputs(), &permissions).await;
+    assert!(
+        matches!(&response, Response::Error(Error::MethodNotPermitted(name)) if name == "callAccountMethod"),
+        "{response:?}"
+    );
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
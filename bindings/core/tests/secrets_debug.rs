@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "ledger_nano")]
+use iota_sdk::client::secret::types::LedgerNanoDto;
 use iota_sdk::client::secret::SecretManagerDto;
 use iota_sdk_bindings_core::{ClientMethod, Response, UtilsMethod, WalletOptions};
 
@@ -18,7 +20,7 @@ fn method_interface_secrets_debug() {
     #[cfg(feature = "ledger_nano")]
     {
         let client_method = ClientMethod::BuildAndPostBlock {
-            secret_manager: Some(SecretManagerDto::LedgerNano(false)),
+            secret_manager: Some(SecretManagerDto::LedgerNano(LedgerNanoDto::default())),
             options: None,
         };
         assert_eq!(
@@ -44,8 +46,14 @@ fn method_interface_secrets_debug() {
     assert_eq!(format!("{:?}", response), "GeneratedMnemonic(<omitted>)");
 
     let wallet_options = WalletOptions::default().with_secret_manager(SecretManagerDto::Placeholder);
+    #[cfg(not(feature = "storage"))]
     assert_eq!(
         format!("{:?}", wallet_options),
-        "WalletOptions { storage_path: None, client_options: None, coin_type: None, secret_manager: Some(<omitted>) }"
+        "WalletOptions { storage_path: None, client_options: None, coin_type: None, secret_manager: Some(<omitted>), permissions: MethodPermissions { allowed_methods: None, denied_methods: {} } }"
+    );
+    #[cfg(feature = "storage")]
+    assert_eq!(
+        format!("{:?}", wallet_options),
+        "WalletOptions { storage_path: None, storage_kind: None, client_options: None, coin_type: None, secret_manager: Some(<omitted>), permissions: MethodPermissions { allowed_methods: None, denied_methods: {} } }"
     );
 }
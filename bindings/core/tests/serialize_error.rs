@@ -1,19 +1,98 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::{client::Error as ClientError, wallet::Error as WalletError};
-use iota_sdk_bindings_core::Error;
+use iota_sdk::{
+    client::{
+        api::input_selection::Error as InputSelectionError, node_api::error::Error as NodeError, Error as ClientError,
+    },
+    types::block::{payload::transaction::TransactionId, Error as BlockError},
+    wallet::Error as WalletError,
+};
+use iota_sdk_bindings_core::{Error, ErrorCode};
+use packable::error::{UnexpectedEOF, UnpackError};
 
 #[test]
 fn custom_error_serialization() {
     let error = Error::Client(ClientError::HealthyNodePoolEmpty);
     assert_eq!(
         serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"client\",\"error\":\"no healthy node available\"}"
+        "{\"type\":\"client\",\"code\":\"nodeUnreachable\",\"kind\":\"client.healthyNodePoolEmpty\",\"message\":\"no healthy node available\",\"error\":\"no healthy node available\"}"
     );
     let error = Error::Wallet(WalletError::AccountNotFound("Alice".to_string()));
     assert_eq!(
         serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"wallet\",\"error\":\"account Alice not found\"}"
+        "{\"type\":\"wallet\",\"code\":\"notFound\",\"kind\":\"wallet.accountNotFound\",\"message\":\"account Alice not found\",\"error\":\"account Alice not found\",\"data\":{\"account\":\"Alice\"}}"
     );
 }
+
+#[test]
+fn error_codes_and_data() {
+    let error = Error::Wallet(WalletError::InsufficientFunds {
+        available: 1,
+        required: 2,
+    });
+    assert_eq!(error.code(), ErrorCode::InsufficientFunds);
+    assert_eq!(error.kind(), "wallet.insufficientFunds");
+    assert_eq!(
+        error.data().unwrap(),
+        serde_json::json!({ "available": "1", "required": "2" })
+    );
+
+    // Nested errors use the code and kind of the innermost error
+    let error = Error::Wallet(WalletError::Client(Box::new(ClientError::Node(
+        NodeError::ResponseError {
+            code: 500,
+            text: "internal error".to_string(),
+            url: "http://localhost:14265/api/core/v2/info".to_string(),
+        },
+    ))));
+    assert_eq!(error.code(), ErrorCode::NodeError);
+    assert_eq!(error.kind(), "node.responseError");
+    assert_eq!(
+        error.data().unwrap(),
+        serde_json::json!({ "statusCode": 500, "url": "http://localhost:14265/api/core/v2/info" })
+    );
+
+    let error = Error::MethodNotPermitted("storeMnemonic".to_string());
+    assert_eq!(error.code(), ErrorCode::MethodNotPermitted);
+    assert_eq!(error.kind(), "bindings.methodNotPermitted");
+
+    let error = Error::Client(ClientError::Node(NodeError::NotFound("output".to_string())));
+    assert_eq!(error.code(), ErrorCode::NotFound);
+    assert_eq!(error.kind(), "node.notFound");
+}
+
+#[test]
+fn error_kinds() {
+    let error = Error::Block(BlockError::InvalidAddress);
+    assert_eq!(error.code(), ErrorCode::InvalidParameter);
+    assert_eq!(error.kind(), "block.invalidAddress");
+
+    let error = Error::Wallet(WalletError::Block(Box::new(BlockError::InvalidPayloadLength {
+        expected: 1,
+        actual: 2,
+    })));
+    assert_eq!(error.kind(), "block.invalidPayloadLength");
+
+    let error = Error::Client(ClientError::InputSelection(
+        InputSelectionError::NoAvailableInputsProvided,
+    ));
+    assert_eq!(error.kind(), "inputSelection.noAvailableInputsProvided");
+
+    let error = Error::Client(ClientError::InputSelection(InputSelectionError::Block(
+        BlockError::NativeTokensOverflow,
+    )));
+    assert_eq!(error.kind(), "block.nativeTokensOverflow");
+
+    let error = Error::Wallet(WalletError::TransactionNotRebuildable(
+        TransactionId::null(),
+        "no inputs",
+    ));
+    assert_eq!(error.kind(), "wallet.transactionNotRebuildable");
+
+    let error = Error::Unpack(UnpackError::Packable(BlockError::InvalidSignature));
+    assert_eq!(error.kind(), "unpack.invalidSignature");
+    let error = Error::Unpack(UnpackError::Unpacker(UnexpectedEOF));
+    assert_eq!(error.kind(), "unpack.unexpectedEof");
+    assert_eq!(serde_json::to_value(&error).unwrap()["type"], "unpack");
+}
@@ -0,0 +1,6 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+fn main() {
+    napi_build::setup();
+}
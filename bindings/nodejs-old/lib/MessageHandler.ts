@@ -1,12 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-import {
-    sendMessageAsync,
-    messageHandlerNew,
-    listenWallet,
-    destroy,
-} from './bindings';
+import { NativeMessageHandler } from './bindings';
 import type {
     WalletEventType,
     AccountManagerOptions,
@@ -18,7 +13,7 @@ import { Event } from '../types';
 
 // The MessageHandler class interacts with messages with the rust bindings.
 export class MessageHandler {
-    messageHandler: any;
+    messageHandler: InstanceType<typeof NativeMessageHandler>;
 
     constructor(options?: AccountManagerOptions) {
         const messageOptions = {
@@ -28,21 +23,22 @@ export class MessageHandler {
             secretManager: options?.secretManager,
         };
 
-        this.messageHandler = messageHandlerNew(JSON.stringify(messageOptions));
+        this.messageHandler = new NativeMessageHandler(
+            JSON.stringify(messageOptions),
+        );
     }
 
     async sendMessage(message: __Message__): Promise<string> {
-        return sendMessageAsync(
-            JSON.stringify(message),
-            this.messageHandler,
-        ).catch((error: Error) => {
-            try {
-                error = JSON.parse(error.toString()).payload;
-            } catch (e) {
-                console.error(e);
-            }
-            return Promise.reject(error);
-        });
+        return this.messageHandler
+            .sendMessage(JSON.stringify(message))
+            .catch((error: Error) => {
+                try {
+                    error = JSON.parse(error.message).payload;
+                } catch (e) {
+                    console.error(e);
+                }
+                return Promise.reject(error);
+            });
     }
 
     async callAccountMethod(
@@ -62,17 +58,16 @@ export class MessageHandler {
         eventTypes: WalletEventType[],
         callback: (error: Error, result: Event) => void,
     ): Promise<void> {
-        return listenWallet(
+        this.messageHandler.listen(
             eventTypes,
             function (err: any, data: string) {
                 const parsed = JSON.parse(data);
                 callback(err, new Event(parsed.accountIndex, parsed.event));
             },
-            this.messageHandler,
         );
     }
 
     async destroy(): Promise<void> {
-        return destroy(this.messageHandler);
+        return this.messageHandler.destroy();
     }
 }
@@ -2,10 +2,10 @@ const { resolve } = require('path');
 const { spawnSync } = require('child_process');
 const moveArtifact = require('./move-artifact');
 
-// Passing "--prepack 'npm run build:neon'" causes problems on Windows, so this is a workaround
+// Passing "--prepack 'npm run build:native'" causes problems on Windows, so this is a workaround
 const npm = process.platform === 'win32' ? 'npm.cmd' : 'npm';
 
-const { status } = spawnSync(npm, ['run', 'build:neon'], {
+const { status } = spawnSync(npm, ['run', 'build:native'], {
     stdio: 'inherit',
     cwd: resolve(__dirname, '../'),
 });
@@ -0,0 +1,28 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Writes the JSON schemas of the Rust DTOs into `types/schemas`, `npm run build:dto-types` generates the TypeScript
+//! types in `types/generated` from them, so the types can't drift from the Rust DTOs.
+//!
+//! Only DTOs whose generated type matches the hand-written one are generated, e.g. `Burn` stays hand-written because
+//! its `nativeTokens` are a `Map`.
+
+use std::path::Path;
+
+use iota_sdk_bindings_core::iota_sdk::wallet::account::ConsolidationParams;
+use schemars::{schema_for, JsonSchema};
+
+fn write_schema<T: JsonSchema>(dir: &Path) -> std::io::Result<()> {
+    let schema = serde_json::to_string_pretty(&schema_for!(T))?;
+
+    std::fs::write(dir.join(format!("{}.json", T::schema_name())), schema + "\n")
+}
+
+fn main() -> std::io::Result<()> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("types/schemas");
+    std::fs::create_dir_all(&dir)?;
+
+    write_schema::<ConsolidationParams>(&dir)?;
+
+    Ok(())
+}
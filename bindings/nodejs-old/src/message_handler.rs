@@ -6,43 +6,37 @@ use std::sync::Arc;
 use iota_sdk_bindings_core::{
     iota_sdk::{
         client::stronghold::StrongholdAdapter,
-        wallet::{
-            events::types::{Event, WalletEventType},
-            Result,
-        },
+        wallet::{events::types::WalletEventType, Result as WalletResult},
     },
     message_interface_old::{
         create_message_handler, init_logger as init_logger_rust, Message, Response, WalletMessageHandler,
     },
     WalletOptions,
 };
-use neon::prelude::*;
+use napi::{
+    threadsafe_function::{ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode},
+    Error, JsFunction, Result,
+};
+use napi_derive::napi;
 use tokio::sync::RwLock;
 
-// Wrapper so we can destroy the MessageHandler
-pub type MessageHandlerWrapperInner = Arc<RwLock<Option<MessageHandler>>>;
-// Wrapper because we can't impl Finalize on MessageHandlerWrapperInner
-pub struct MessageHandlerWrapper(pub MessageHandlerWrapperInner);
-impl Finalize for MessageHandlerWrapper {}
+// The error thrown to JavaScript, with the serialized error response as message
+fn response_error(response: &Response) -> Error {
+    Error::from_reason(serde_json::to_string(response).expect("the response is generated manually, so unwrap is safe."))
+}
 
-pub struct MessageHandler {
-    channel: Channel,
+struct MessageHandlerInner {
     wallet_message_handler: WalletMessageHandler,
 }
 
-type JsCallback = Root<JsFunction<JsObject>>;
-
-impl MessageHandler {
-    fn new(channel: Channel, options: String) -> Result<Self> {
+impl MessageHandlerInner {
+    fn new(options: String) -> WalletResult<Self> {
         let manager_options = serde_json::from_str::<WalletOptions>(&options)?;
 
         let wallet_message_handler =
             crate::RUNTIME.block_on(async move { create_message_handler(Some(manager_options)).await })?;
 
-        Ok(Self {
-            channel,
-            wallet_message_handler,
-        })
+        Ok(Self { wallet_message_handler })
     }
 
     async fn send_message(&self, serialized_message: String) -> (String, bool) {
@@ -74,150 +68,112 @@ impl MessageHandler {
     }
 }
 
-impl Finalize for MessageHandler {}
-
-fn call_event_callback(channel: &neon::event::Channel, event_data: Event, callback: Arc<JsCallback>) {
-    channel.send(move |mut cx| {
-        let cb = (*callback).to_inner(&mut cx);
-        let this = cx.undefined();
-        let args = [
-            cx.undefined().upcast::<JsValue>(),
-            cx.string(serde_json::to_string(&event_data).unwrap())
-                .upcast::<JsValue>(),
-        ];
-
-        cb.call(&mut cx, this, args)?;
-
-        Ok(())
-    });
+/// Handles the messages sent to the wallet.
+#[napi]
+pub struct MessageHandler {
+    // Wrapped so the message handler can be destroyed while requests are still referencing it
+    inner: Arc<RwLock<Option<MessageHandlerInner>>>,
 }
 
-pub fn init_logger(mut cx: FunctionContext) -> JsResult<JsUndefined> {
-    let config = cx.argument::<JsString>(0)?.value(&mut cx);
-    init_logger_rust(config).expect("failed to init logger");
-    Ok(cx.undefined())
-}
+#[napi]
+impl MessageHandler {
+    /// Creates the wallet from the serialized options.
+    #[napi(constructor)]
+    pub fn new(options: String) -> Result<Self> {
+        let inner = MessageHandlerInner::new(options).map_err(|e| response_error(&Response::Error(e)))?;
 
-pub fn message_handler_new(mut cx: FunctionContext) -> JsResult<JsBox<MessageHandlerWrapper>> {
-    let options = cx.argument::<JsString>(0)?;
-    let options = options.value(&mut cx);
-    let channel = cx.channel();
-    let message_handler = MessageHandler::new(channel, options).or_else(|e| {
-        cx.throw_error(
-            serde_json::to_string(&Response::Error(e)).expect("the response is generated manually, so unwrap is safe."),
-        )
-    })?;
-
-    Ok(cx.boxed(MessageHandlerWrapper(Arc::new(RwLock::new(Some(message_handler))))))
-}
+        Ok(Self {
+            inner: Arc::new(RwLock::new(Some(inner))),
+        })
+    }
 
-pub fn send_message(mut cx: FunctionContext) -> JsResult<JsUndefined> {
-    let message = cx.argument::<JsString>(0)?;
-    let message = message.value(&mut cx);
-    let message_handler = Arc::clone(&cx.argument::<JsBox<MessageHandlerWrapper>>(1)?.0);
-    let callback = cx.argument::<JsFunction>(2)?.root(&mut cx);
-
-    crate::RUNTIME.spawn(async move {
-        if let Some(message_handler) = &*message_handler.read().await {
-            let (response, is_error) = message_handler.send_message(message).await;
-            message_handler.channel.send(move |mut cx| {
-                let cb = callback.into_inner(&mut cx);
-                let this = cx.undefined();
-
-                let args = [
-                    if is_error {
-                        cx.string(response.clone()).upcast::<JsValue>()
-                    } else {
-                        cx.undefined().upcast::<JsValue>()
-                    },
-                    cx.string(response).upcast::<JsValue>(),
-                ];
-
-                cb.call(&mut cx, this, args)?;
-
-                Ok(())
-            });
+    /// Sends the serialized message and resolves with the serialized response, or rejects with the serialized error
+    /// response.
+    #[napi]
+    pub async fn send_message(&self, message: String) -> Result<String> {
+        let inner = Arc::clone(&self.inner);
+        let (response, is_error) = crate::RUNTIME
+            .spawn(async move {
+                match &*inner.read().await {
+                    Some(message_handler) => Some(message_handler.send_message(message).await),
+                    None => None,
+                }
+            })
+            .await
+            .map_err(|e| Error::from_reason(e.to_string()))?
+            .ok_or_else(|| Error::from_reason("Message handler got destroyed"))?;
+
+        if is_error {
+            Err(Error::from_reason(response))
         } else {
-            panic!("Message handler got destroyed")
+            Ok(response)
         }
-    });
+    }
 
-    Ok(cx.undefined())
-}
+    /// Calls the callback with the serialized events of the given types.
+    #[napi(ts_args_type = "eventTypes: number[], callback: (error: Error | null, event: string) => void")]
+    pub fn listen(&self, event_types: Vec<u8>, callback: JsFunction) -> Result<()> {
+        let event_types = event_types
+            .into_iter()
+            .map(|event_type| WalletEventType::try_from(event_type).map_err(Error::from_reason))
+            .collect::<Result<Vec<_>>>()?;
+        let callback: ThreadsafeFunction<String, ErrorStrategy::CalleeHandled> =
+            callback.create_threadsafe_function(0, |cx: ThreadSafeCallContext<String>| Ok(vec![cx.value]))?;
+        let inner = Arc::clone(&self.inner);
+
+        crate::RUNTIME.spawn(async move {
+            if let Some(message_handler) = &*inner.read().await {
+                message_handler
+                    .wallet_message_handler
+                    .listen(event_types, move |event| {
+                        let event = serde_json::to_string(event).map_err(|e| Error::from_reason(e.to_string()));
+                        callback.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+                    })
+                    .await;
+            } else {
+                panic!("Message handler got destroyed")
+            }
+        });
 
-pub fn listen(mut cx: FunctionContext) -> JsResult<JsUndefined> {
-    let js_arr_handle: Handle<JsArray> = cx.argument(0)?;
-    let vec: Vec<Handle<JsValue>> = js_arr_handle.to_vec(&mut cx)?;
-    let mut event_types = Vec::new();
-    for event_string in vec {
-        let event_type = event_string.downcast_or_throw::<JsNumber, FunctionContext>(&mut cx)?;
-        let wallet_event_type =
-            WalletEventType::try_from(event_type.value(&mut cx) as u8).or_else(|e| cx.throw_error(e))?;
-        event_types.push(wallet_event_type);
+        Ok(())
     }
 
-    let callback = Arc::new(cx.argument::<JsFunction>(1)?.root(&mut cx));
-    let message_handler = Arc::clone(&cx.argument::<JsBox<MessageHandlerWrapper>>(2)?.0);
-
-    crate::RUNTIME.spawn(async move {
-        if let Some(message_handler) = &*message_handler.read().await {
-            let channel = message_handler.channel.clone();
-            message_handler
-                .wallet_message_handler
-                .listen(event_types, move |event_data| {
-                    call_event_callback(&channel, event_data.clone(), callback.clone())
-                })
-                .await;
-        } else {
-            panic!("Message handler got destroyed")
-        }
-    });
-
-    Ok(cx.undefined())
+    /// Destroys the wallet, the message handler can't be used afterwards.
+    #[napi]
+    pub async fn destroy(&self) -> Result<()> {
+        let inner = Arc::clone(&self.inner);
+        crate::RUNTIME
+            .spawn(async move {
+                *inner.write().await = None;
+            })
+            .await
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
 }
 
-pub fn destroy(mut cx: FunctionContext) -> JsResult<JsPromise> {
-    let message_handler = Arc::clone(&cx.argument::<JsBox<MessageHandlerWrapper>>(0)?.0);
-    let channel = cx.channel();
-    let (deferred, promise) = cx.promise();
-    crate::RUNTIME.spawn(async move {
-        *message_handler.write().await = None;
-        deferred.settle_with(&channel, move |mut cx| Ok(cx.undefined()));
-    });
-    Ok(promise)
+/// Initializes the logger with the serialized logger config.
+#[napi]
+pub fn init_logger(config: String) -> Result<()> {
+    init_logger_rust(config).map_err(|e| Error::from_reason(e.to_string()))
 }
 
-pub fn migrate_stronghold_snapshot_v2_to_v3(mut cx: FunctionContext) -> JsResult<JsUndefined> {
-    let current_path = cx.argument::<JsString>(0)?.value(&mut cx);
-    let current_password = cx.argument::<JsString>(1)?.value(&mut cx).into();
-    let salt = cx.argument::<JsString>(2)?.value(&mut cx);
-    let rounds = cx.argument::<JsNumber>(3)?.value(&mut cx);
-    let new_path = cx
-        .argument_opt(4)
-        .map(|opt| opt.downcast_or_throw::<JsString, _>(&mut cx))
-        .transpose()?
-        .map(|opt| opt.value(&mut cx));
-    let new_password = cx
-        .argument_opt(5)
-        .map(|opt| opt.downcast_or_throw::<JsString, _>(&mut cx))
-        .transpose()?
-        .map(|opt| opt.value(&mut cx))
-        .map(Into::into);
-
+/// Migrates a Stronghold snapshot from version 2 to version 3.
+#[napi]
+pub fn migrate_stronghold_snapshot_v2_to_v3(
+    current_path: String,
+    current_password: String,
+    salt: String,
+    rounds: u32,
+    new_path: Option<String>,
+    new_password: Option<String>,
+) -> Result<()> {
     StrongholdAdapter::migrate_snapshot_v2_to_v3(
         &current_path,
-        current_password,
+        current_password.into(),
         salt,
-        rounds as u32,
+        rounds,
         new_path.as_ref(),
-        new_password,
+        new_password.map(Into::into),
     )
-    .or_else(|e| {
-        cx.throw_error(
-            serde_json::to_string(&Response::Error(e.into()))
-                .expect("the response is generated manually, so unwrap is safe."),
-        )
-    })?;
-
-    Ok(cx.undefined())
+    .map_err(|e| response_error(&Response::Error(e.into())))
 }
@@ -1,12 +1,5 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-/** Parameters for consolidation */
-export interface ConsolidationParams {
-    // Ignores the output_threshold if set to `true`.
-    force: boolean;
-    // Consolidates if the output number is >= the output_threshold.
-    outputThreshold?: number;
-    // Address to which the consolidated output should be sent.
-    targetAddress?: string;
-}
+// Generated from the Rust DTO, see `npm run build:dto-types`
+export type { ConsolidationParams } from './generated/ConsolidationParams';
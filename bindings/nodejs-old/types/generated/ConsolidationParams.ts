@@ -0,0 +1,25 @@
+/* eslint-disable */
+/**
+ * This file was automatically generated by json-schema-to-typescript.
+ * DO NOT MODIFY IT BY HAND. Instead, modify the source JSONSchema file,
+ * and run json-schema-to-typescript to regenerate this file.
+ */
+
+export interface ConsolidationParams {
+    /**
+     * Ignores the output_threshold if set to `true`.
+     */
+    force: boolean;
+    /**
+     * Consolidates if the output number is >= the output_threshold.
+     */
+    outputThreshold?: number | null;
+    /**
+     * Address to which the consolidated output should be sent.
+     */
+    targetAddress?: string | null;
+    /**
+     * Also consolidates outputs with other unlock conditions than the address unlock condition, like an expired expiration or a timelock in the past.
+     */
+    unlockConditionsAllowed?: boolean;
+}
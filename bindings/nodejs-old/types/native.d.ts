@@ -0,0 +1,23 @@
+/* tslint:disable */
+/* eslint-disable */
+
+/* auto-generated by NAPI-RS */
+
+/** Initializes the logger with the serialized logger config. */
+export function initLogger(config: string): void
+/** Migrates a Stronghold snapshot from version 2 to version 3. */
+export function migrateStrongholdSnapshotV2ToV3(currentPath: string, currentPassword: string, salt: string, rounds: number, newPath?: string | undefined | null, newPassword?: string | undefined | null): void
+/** Handles the messages sent to the wallet. */
+export class MessageHandler {
+  /** Creates the wallet from the serialized options. */
+  constructor(options: string)
+  /**
+   * Sends the serialized message and resolves with the serialized response, or rejects with the serialized error
+   * response.
+   */
+  sendMessage(message: string): Promise<string>
+  /** Calls the callback with the serialized events of the given types. */
+  listen(eventTypes: number[], callback: (error: Error | null, event: string) => void): void
+  /** Destroys the wallet, the message handler can't be used afterwards. */
+  destroy(): Promise<void>
+}
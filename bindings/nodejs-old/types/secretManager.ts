@@ -2,8 +2,18 @@ import { HexEncodedString } from '@iota/types';
 
 /** Secret manager that uses a Ledger Nano hardware wallet or Speculos simulator. */
 export interface LedgerNanoSecretManager {
-    /** boolean indicates whether it's a simulator or not. */
-    ledgerNano: boolean;
+    /** boolean indicates whether it's a simulator or not, or the Ledger Nano options. */
+    ledgerNano: boolean | LedgerNanoOptions;
+}
+
+/** Options of a Ledger Nano secret manager. */
+export interface LedgerNanoOptions {
+    /** Whether it's a simulator or not. */
+    isSimulator: boolean;
+    /** Whether transactions that can't be shown on the device may be signed blindly. */
+    allowBlindSigning?: boolean;
+    /** Whether addresses are cached per account, so they can be generated while the device is disconnected. */
+    cacheAddresses?: boolean;
 }
 
 /** Secret manager that uses a mnemonic. */
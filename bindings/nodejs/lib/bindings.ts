@@ -19,6 +19,8 @@ const {
     callClientMethod,
     createClient,
     destroyClient,
+    resolvePowRequest,
+    reportPowProgress,
     listenMqtt,
     callWalletMethod,
     createWallet,
@@ -75,6 +77,7 @@ const listenWalletAsync = (
     eventTypes: WalletEventType[],
     callback: (error: Error, event: Event) => void,
     handler: WalletMethodHandler,
+    accountIndexes?: number[],
 ): Promise<void> => {
     listenWallet(
         eventTypes,
@@ -83,6 +86,7 @@ const listenWalletAsync = (
             callback(err, new Event(parsed.accountIndex, parsed.event));
         },
         handler,
+        accountIndexes,
     );
     return Promise.resolve();
 };
@@ -105,6 +109,8 @@ export {
     initLogger,
     createClient,
     destroyClient,
+    resolvePowRequest,
+    reportPowProgress,
     createSecretManager,
     createWallet,
     callClientMethodAsync,
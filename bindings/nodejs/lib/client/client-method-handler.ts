@@ -6,8 +6,14 @@ import {
     createClient,
     listenMqtt,
     destroyClient,
+    resolvePowRequest,
+    reportPowProgress,
 } from '../bindings';
-import type { IClientOptions, __ClientMethods__ } from '../types/client';
+import type {
+    IClientOptions,
+    PowProvider,
+    __ClientMethods__,
+} from '../types/client';
 
 /**
  * The MethodHandler which sends the commands to the Rust side.
@@ -17,11 +23,24 @@ export class ClientMethodHandler {
 
     /**
      * @param options client options or a client method handler.
+     * @param powProvider A provider of the nonces for the local PoW, used instead of the built-in miner.
+     * @param powProgress Receives the hash rate of the local PoW in hashes per second.
      */
-    constructor(options: IClientOptions | ClientMethodHandler) {
+    constructor(
+        options: IClientOptions | ClientMethodHandler,
+        powProvider?: PowProvider,
+        powProgress?: (hashRate: number) => void,
+    ) {
         // The rust client object is not extensible
         if (Object.isExtensible(options)) {
-            this.methodHandler = createClient(JSON.stringify(options));
+            this.methodHandler = createClient(
+                JSON.stringify(options),
+                powProvider ? powProviderCallback(powProvider) : undefined,
+                powProgress
+                    ? (_error: Error, data: string) =>
+                          powProgress(JSON.parse(data).hashRate)
+                    : undefined,
+            );
         } else {
             this.methodHandler = options as ClientMethodHandler;
         }
@@ -57,3 +76,37 @@ export class ClientMethodHandler {
         return listenMqtt(topics, callback, this.methodHandler);
     }
 }
+
+/**
+ * Answers the PoW requests of the Rust side with the nonces of the PoW provider.
+ *
+ * @param powProvider The PoW provider.
+ * @returns The callback for the PoW events of the Rust side.
+ */
+function powProviderCallback(
+    powProvider: PowProvider,
+): (error: Error, data: string) => void {
+    const cancelledRequests = new Set<number>();
+
+    return (_error: Error, data: string) => {
+        const event = JSON.parse(data);
+
+        if (event.type === 'powCancelled') {
+            cancelledRequests.add(event.requestId);
+        } else if (event.type === 'powRequest') {
+            const requestId: number = event.requestId;
+            powProvider(
+                event.blockBytes,
+                event.minPowScore,
+                () => cancelledRequests.has(requestId),
+                (hashRate) => reportPowProgress(requestId, hashRate),
+            )
+                .then((nonce) =>
+                    resolvePowRequest(requestId, nonce?.toString()),
+                )
+                // Resolve the request without a nonce, so the PoW gets restarted
+                .catch(() => resolvePowRequest(requestId))
+                .finally(() => cancelledRequests.delete(requestId));
+        }
+    };
+}
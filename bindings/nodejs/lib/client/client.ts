@@ -4,10 +4,12 @@
 import { ClientMethodHandler } from './client-method-handler';
 import {
     IClientOptions,
+    IClientConfig,
     IGenerateAddressesOptions,
     IBuildBlockOptions,
     QueryParameter,
     PreparedTransactionData,
+    DryRunTransaction,
     INetworkInfo,
     INode,
     IAuth,
@@ -18,6 +20,8 @@ import {
     FoundryQueryParameter,
     NftQueryParameter,
     AliasQueryParameter,
+    NativeTokenMetadata,
+    PowProvider,
 } from '../types/client';
 import type { INodeInfoWrapper } from '../types/client/nodeInfo';
 import {
@@ -63,9 +67,20 @@ export class Client {
 
     /**
      * @param options client options or a client method handler.
-     */
-    constructor(options: IClientOptions | ClientMethodHandler) {
-        this.methodHandler = new ClientMethodHandler(options);
+     * @param powProvider A provider of the nonces for the local PoW, used instead of the built-in miner.
+     * @param powProgress Receives the hash rate of the local PoW in hashes per second, about once per second while the
+     * built-in miner is mining, or whenever the PoW provider reports it.
+     */
+    constructor(
+        options: IClientOptions | ClientMethodHandler,
+        powProvider?: PowProvider,
+        powProgress?: (hashRate: number) => void,
+    ) {
+        this.methodHandler = new ClientMethodHandler(
+            options,
+            powProvider,
+            powProgress,
+        );
     }
 
     async destroy() {
@@ -94,6 +109,18 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Export the configuration of the client, which can be passed to `new Client()` to create a client with the
+     * same settings.
+     */
+    async exportConfig(): Promise<IClientConfig> {
+        const response = await this.methodHandler.callMethod({
+            name: 'exportConfig',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Fetch basic output IDs based on the given query parameters.
      */
@@ -242,6 +269,25 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Resolve the foundry of a native token and decode its IRC-30 metadata.
+     *
+     * @param tokenId The ID of the native token.
+     * @returns The metadata of the native token.
+     */
+    async getNativeTokenMetadata(
+        tokenId: string,
+    ): Promise<NativeTokenMetadata> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getNativeTokenMetadata',
+            data: {
+                tokenId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Find inputs from addresses for a given amount (useful for offline signing).
      *
@@ -290,6 +336,40 @@ export class Client {
         return plainToInstance(PreparedTransactionData, parsed.payload);
     }
 
+    /**
+     * Build a transaction with input selection, output validation and storage deposit checks,
+     * without signing it, doing PoW or posting a block.
+     *
+     * @param secretManager One of the supported secret managers.
+     * @param options Options to build a block.
+     * @returns The would-be transaction and a summary of its consumed and created outputs.
+     */
+    async dryRunTransaction(
+        secretManager?: SecretManagerType,
+        options?: IBuildBlockOptions,
+    ): Promise<DryRunTransaction> {
+        const response = await this.methodHandler.callMethod({
+            name: 'dryRunTransaction',
+            data: {
+                secretManager,
+                options,
+            },
+        });
+
+        const payload = JSON.parse(response).payload;
+        return {
+            preparedTransactionData: plainToInstance(
+                PreparedTransactionData,
+                payload.preparedTransactionData,
+            ),
+            summary: {
+                ...payload.summary,
+                consumedAmount: BigInt(payload.summary.consumedAmount),
+                createdAmount: BigInt(payload.summary.createdAmount),
+            },
+        };
+    }
+
     /**
      * Sign a transaction.
      *
@@ -513,6 +593,24 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Post already serialized block bytes as they are, e.g. from `getBlockRaw()`, without validating them and without
+     * local PoW fallback.
+     *
+     * @param blockBytes The serialized block.
+     * @returns The ID of the posted block.
+     */
+    async postBlockBytes(blockBytes: Uint8Array): Promise<BlockId> {
+        const response = await this.methodHandler.callMethod({
+            name: 'postBlockBytes',
+            data: {
+                blockBytes: Array.from(blockBytes),
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get block as raw bytes.
      *
@@ -1063,6 +1161,15 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Stop the running local proof of work, the blocks fail with a PoW cancelled error.
+     */
+    async cancelPow(): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'cancelPow',
+        });
+    }
+
     /**
      * Build a basic output.
      *
@@ -71,6 +71,27 @@ export class SecretManager {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Change the Stronghold password and re-encrypt the snapshot file with it.
+     *
+     * @param currentPassword The current password.
+     * @param newPassword The new password.
+     */
+    async changeStrongholdPassword(
+        currentPassword: string,
+        newPassword: string,
+    ): Promise<void> {
+        const response = await this.methodHandler.callMethod({
+            name: 'changeStrongholdPassword',
+            data: {
+                currentPassword,
+                newPassword,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Store a mnemonic in the Stronghold vault.
      *
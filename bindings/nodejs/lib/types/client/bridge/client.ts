@@ -70,6 +70,10 @@ export interface __GetNetworkInfoMethod__ {
     name: 'getNetworkInfo';
 }
 
+export interface __ExportConfigMethod__ {
+    name: 'exportConfig';
+}
+
 export interface __GetBlockMethod__ {
     name: 'getBlock';
     data: {
@@ -84,6 +88,13 @@ export interface __GetBlockMetadataMethod__ {
     };
 }
 
+export interface __GetNativeTokenMetadataMethod__ {
+    name: 'getNativeTokenMetadata';
+    data: {
+        tokenId: string;
+    };
+}
+
 export interface __FindInputsMethod__ {
     name: 'findInputs';
     data: {
@@ -100,6 +111,14 @@ export interface __PrepareTransactionMethod__ {
     };
 }
 
+export interface __DryRunTransactionMethod__ {
+    name: 'dryRunTransaction';
+    data: {
+        secretManager?: SecretManagerType;
+        options?: IBuildBlockOptions;
+    };
+}
+
 export interface __SignTransactionMethod__ {
     name: 'signTransaction';
     data: {
@@ -182,6 +201,13 @@ export interface __PostBlockRawMethod__ {
     };
 }
 
+export interface __PostBlockBytesMethod__ {
+    name: 'postBlockBytes';
+    data: {
+        blockBytes: number[];
+    };
+}
+
 export interface __GetBlockRawMethod__ {
     name: 'getBlockRaw';
     data: {
@@ -389,6 +415,10 @@ export interface __UnhealthyNodesMethod__ {
     name: 'unhealthyNodes';
 }
 
+export interface __CancelPowMethod__ {
+    name: 'cancelPow';
+}
+
 export interface __BuildBasicOutputMethod__ {
     name: 'buildBasicOutput';
     data: BasicOutputBuilderParams;
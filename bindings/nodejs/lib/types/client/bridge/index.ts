@@ -10,10 +10,13 @@ import type {
     __BuildAndPostBlockMethod__,
     __GetTipsMethod__,
     __GetNetworkInfoMethod__,
+    __ExportConfigMethod__,
     __GetBlockMethod__,
     __GetBlockMetadataMethod__,
+    __GetNativeTokenMetadataMethod__,
     __FindInputsMethod__,
     __PrepareTransactionMethod__,
+    __DryRunTransactionMethod__,
     __SignTransactionMethod__,
     __PostBlockPayloadMethod__,
     __GetNodeMethod__,
@@ -28,6 +31,7 @@ import type {
     __GetNodeInfoMethod__,
     __GetPeersMethod__,
     __PostBlockRawMethod__,
+    __PostBlockBytesMethod__,
     __GetBlockRawMethod__,
     __GetReceiptsMethod__,
     __GetReceiptsMigratedAtMethod__,
@@ -54,6 +58,7 @@ import type {
     __PromoteMethod__,
     __PromoteUncheckedMethod__,
     __UnhealthyNodesMethod__,
+    __CancelPowMethod__,
     __GetMilestoneByIdMethod__,
     __GetUtxoChangesByIdMethod__,
     __GetMilestoneByIndexMethod__,
@@ -78,10 +83,13 @@ export type __ClientMethods__ =
     | __BuildAndPostBlockMethod__
     | __GetTipsMethod__
     | __GetNetworkInfoMethod__
+    | __ExportConfigMethod__
     | __GetBlockMethod__
     | __GetBlockMetadataMethod__
+    | __GetNativeTokenMetadataMethod__
     | __FindInputsMethod__
     | __PrepareTransactionMethod__
+    | __DryRunTransactionMethod__
     | __SignTransactionMethod__
     | __SignatureUnlockMethod__
     | __PostBlockPayloadMethod__
@@ -97,6 +105,7 @@ export type __ClientMethods__ =
     | __GetNodeInfoMethod__
     | __GetPeersMethod__
     | __PostBlockRawMethod__
+    | __PostBlockBytesMethod__
     | __GetBlockRawMethod__
     | __GetMilestoneByIdMethod__
     | __GetUtxoChangesByIdMethod__
@@ -127,6 +136,7 @@ export type __ClientMethods__ =
     | __PromoteMethod__
     | __PromoteUncheckedMethod__
     | __UnhealthyNodesMethod__
+    | __CancelPowMethod__
     | __BuildBasicOutputMethod__
     | __BuildAliasOutputMethod__
     | __BuildFoundryOutputMethod__
@@ -21,6 +21,10 @@ export interface IBuildBlockOptions {
     inputs?: UTXOInput[];
     /** An input range. */
     inputRange?: IRange;
+    /** Output IDs that must be used as inputs, additional inputs are selected if needed. */
+    requiredInputs?: string[];
+    /** Output IDs that must not be used as inputs. */
+    forbiddenInputs?: string[];
     /** Bech32 encoded output address and amount. */
     output?: IClientBlockBuilderOutputAddress;
     /** Hex encoded output address and amount. */
@@ -37,6 +41,8 @@ export interface IBuildBlockOptions {
     parents?: string[];
     /** Parameters for explicit burning of aliases, nfts, foundries and native tokens. */
     burn?: Burn;
+    /** Only build the transaction, posting the block fails. */
+    dryRun?: boolean;
 }
 
 /** Address with base coin amount. */
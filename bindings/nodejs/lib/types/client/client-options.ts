@@ -1,6 +1,12 @@
 // Copyright 2021-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
-import type { IMqttBrokerOptions, INetworkInfo, INode } from './network';
+import type { INodeInfoProtocol } from '../models/info';
+import type {
+    IMqttBrokerOptions,
+    INetworkInfo,
+    INode,
+    NetworkProfile,
+} from './network';
 
 /** Options for the client builder */
 export interface IClientOptions {
@@ -24,20 +30,78 @@ export interface IClientOptions {
     minQuorumSize?: number;
     /** % of nodes that have to return the same response so it gets accepted */
     quorumThreshold?: number;
+    /** Amount of nodes a block is posted to at the same time if local PoW is used */
+    broadcastNodeCount?: number;
     /** Data related to the used network */
     networkInfo?: INetworkInfo;
+    /** Protocol parameters to use the client without nodes, they are verified against the ones of the nodes if
+     * `pinnedProtocolParameters` is set */
+    protocolParameters?: INodeInfoProtocol;
+    /** If the protocol parameters are verified against the ones of the nodes instead of being replaced by them */
+    pinnedProtocolParameters?: boolean;
     /** Options for the MQTT broker */
     brokerOptions?: IMqttBrokerOptions;
     /** Timeout for API requests */
     apiTimeout?: IDuration;
     /** Timeout when sending a block that requires remote proof of work */
     remotePowTimeout?: IDuration;
+    /** Time in which posting a block for the same transaction again returns the block that was already posted, zero
+     * disables it */
+    blockIdempotencyTtl?: IDuration;
     /** The amount of threads to be used for proof of work */
     powWorkerCount?: number;
     /** Whether the PoW should be done locally or remotely. */
     localPow?: boolean;
     /** The maximum parallel API requests. */
     maxParallelApiRequests?: number;
+    /** The User-Agent header for requests, no User-Agent header is sent if empty */
+    userAgent?: string;
+    /** If the client name and version headers should be sent with every request */
+    identificationHeaders?: boolean;
+    /** How failed requests to nodes are retried, disabled by default */
+    retryPolicy?: IRetryPolicy;
+    /** How connections to nodes are kept open and reused */
+    connectionPool?: IConnectionPoolOptions;
+    /** HTTP or SOCKS5 proxy all requests to nodes are sent through, e.g. `socks5h://127.0.0.1:9050` for Tor */
+    proxy?: string;
+    /** The network the client is used for. Its public nodes are used if no node is set, nodes and addresses of other networks are rejected */
+    networkProfile?: NetworkProfile;
+    /** Whether the blocks and outputs returned by nodes are validated locally, to protect against buggy or malicious nodes */
+    validateResponses?: boolean;
+}
+
+/** How often and when a failed request to a node is repeated, with an exponential backoff */
+export interface IRetryPolicy {
+    /** The amount of times a failed request is repeated, 0 disables retries */
+    maxRetries?: number;
+    /** The time to wait before the first retry, 500 milliseconds by default */
+    initialBackoff?: IDuration;
+    /** The longest time to wait between retries, 10 seconds by default */
+    maxBackoff?: IDuration;
+    /** The HTTP status codes of responses that are retried, 429, 502, 503 and 504 by default */
+    retryOnStatus?: number[];
+    /** Whether requests that failed to connect to the node or timed out are retried, true by default */
+    retryOnConnectionError?: boolean;
+    /** Policies for routes starting with the given path, e.g. `api/core/v2/blocks` */
+    routeOverrides?: { [path: string]: IRetryPolicy };
+}
+
+/** How connections to nodes are kept open and reused, ignored on wasm */
+export interface IConnectionPoolOptions {
+    /** The maximum amount of idle connections that are kept open per node, 32 by default */
+    maxIdlePerHost?: number;
+    /** How long an idle connection is kept open, 90 seconds by default */
+    idleTimeout?: IDuration;
+    /** The interval of TCP keep-alive probes, 60 seconds by default, none are sent if null */
+    tcpKeepalive?: IDuration | null;
+    /** The interval of HTTP/2 pings that keep idle connections alive, 30 seconds by default, none are sent if null */
+    http2KeepAliveInterval?: IDuration | null;
+}
+
+/** Versioned client options exported from a client, which can be used to create a client with the same settings */
+export interface IClientConfig extends IClientOptions {
+    /** The version of the configuration format */
+    version: number;
 }
 
 /** Time duration */
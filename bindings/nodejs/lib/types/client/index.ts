@@ -7,8 +7,10 @@ export * from './client-options';
 export * from './constants';
 export * from './generate-addresses-options';
 export * from './ledger-nano-status';
+export * from './native-token-metadata';
 export * from './network';
 export * from './nodeInfo';
+export * from './pow-provider';
 export * from './prepared-transaction-data';
 export * from './query-parameters';
 export * from './range';
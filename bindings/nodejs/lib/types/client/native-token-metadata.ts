@@ -0,0 +1,20 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/** The IRC-30 metadata of a native token, stored in a metadata feature of its foundry */
+export interface NativeTokenMetadata {
+    /** The human-readable name of the native token. */
+    name: string;
+    /** The human-readable description of the native token. */
+    description?: string;
+    /** The symbol or ticker of the native token. */
+    symbol: string;
+    /** The number of decimals the token uses. */
+    decimals: number;
+    /** The URL pointing to more resources about the native token. */
+    url?: string;
+    /** The URL pointing to an image resource of the native token logo. */
+    logoUrl?: string;
+    /** The SVG image of the native token logo. */
+    logo?: string;
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import { INodeInfoProtocol } from '../models/info';
+import type { IDuration } from './client-options';
 
 /**
  * Network types.
@@ -13,6 +14,20 @@ export enum Network {
     Testnet,
 }
 
+/**
+ * Presets of the well-known networks, with their bech32 HRP, coin type, protocol parameters and public nodes.
+ */
+export enum NetworkProfile {
+    /** The IOTA mainnet. */
+    Mainnet = 'mainnet',
+    /** The Shimmer mainnet. */
+    Shimmer = 'shimmer',
+    /** The public Shimmer testnet. */
+    Testnet = 'testnet',
+    /** A private network, e.g. a private tangle running on the local machine. */
+    Private = 'private',
+}
+
 /**
  * Basic Auth or JWT.
  */
@@ -37,6 +52,28 @@ export interface IMqttBrokerOptions {
     port?: number;
     /** Sets the maximum number of reconnection attempts. 0 is unlimited. */
     maxReconnectionAttempts?: number;
+    /** Sets the path of the MQTT over WebSocket endpoint, `/api/mqtt/v1` by default. */
+    wsPath?: string;
+    /** Sets the port of the MQTT over WebSocket endpoint. Defaults to the port of the node URL. */
+    wsPort?: number;
+    /** Sets whether TLS should be used. Defaults to using it for `https` node URLs. */
+    useTls?: boolean;
+    /** Sets the TLS options, to use custom certificate authorities or client authentication. */
+    tls?: IMqttBrokerTlsOptions;
+    /** Sets the delay before the first reconnection attempt, doubled with every failed attempt. 1 second by default. */
+    reconnectionDelay?: IDuration;
+    /** Sets the maximum delay between reconnection attempts. 60 seconds by default. */
+    maxReconnectionDelay?: IDuration;
+}
+
+/** The TLS options used to connect to a MQTT broker. */
+export interface IMqttBrokerTlsOptions {
+    /** The PEM encoded certificate authorities the broker certificate is verified against. */
+    caCertificates: string;
+    /** The PEM encoded client certificate, for brokers requiring client authentication. */
+    clientCertificate?: string;
+    /** The PEM encoded private key of the client certificate. */
+    clientKey?: string;
 }
 
 /**
@@ -65,4 +102,8 @@ export interface INetworkInfo {
     fallbackToLocalPow: boolean;
     /** Tips request interval during PoW in seconds */
     tipsInterval: number;
+    /** If the protocol parameters were set by the user, they are then verified against the ones of the nodes */
+    pinnedProtocolParameters?: boolean;
+    /** The network the client is used for, nodes and addresses of other networks are rejected */
+    networkProfile?: NetworkProfile;
 }
@@ -0,0 +1,22 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { HexEncodedString } from '../../utils';
+
+/**
+ * A provider of the nonces for the local PoW, to use e.g. GPU or FPGA miners or an external PoW service instead of
+ * the built-in miner.
+ *
+ * @param blockBytes The serialized block without its nonce.
+ * @param minPowScore The minimum PoW score the nonce needs to reach.
+ * @param isCancelled Returns whether the PoW got cancelled, to restart it with new tips. Providers should check it
+ * regularly and stop working once it returns true.
+ * @param reportProgress Reports the current hash rate in hashes per second to the PoW progress callback of the client.
+ * @returns The found nonce, or undefined if the PoW got cancelled.
+ */
+export type PowProvider = (
+    blockBytes: HexEncodedString,
+    minPowScore: number,
+    isCancelled: () => boolean,
+    reportProgress: (hashRate: number) => void,
+) => Promise<bigint | undefined>;
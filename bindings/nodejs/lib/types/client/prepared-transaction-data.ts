@@ -3,7 +3,7 @@
 
 import { Type } from 'class-transformer';
 import { Address, AddressDiscriminator } from '../block/address';
-import { Output, OutputDiscriminator } from '../block/output/output';
+import { Output, OutputDiscriminator, OutputId } from '../block/output/output';
 import {
     TransactionEssence,
     TransactionEssenceDiscriminator,
@@ -32,6 +32,30 @@ export class PreparedTransactionData {
     remainder?: Remainder;
 }
 
+/**
+ * Summary of the outputs consumed and created by a prepared transaction.
+ */
+export interface TransactionSummary {
+    /** The IDs of the consumed outputs. */
+    consumedOutputIds: OutputId[];
+    /** The base coin amount of the consumed outputs. */
+    consumedAmount: bigint;
+    /** The base coin amount of the created outputs, including the remainder. */
+    createdAmount: bigint;
+    /** The index of the remainder output in the outputs of the essence, if there is one. */
+    remainderIndex?: number;
+}
+
+/**
+ * A transaction built with input selection and validation, but neither signed nor sent.
+ */
+export interface DryRunTransaction {
+    /** The would-be transaction. */
+    preparedTransactionData: PreparedTransactionData;
+    /** Summary of the consumed and created outputs. */
+    summary: TransactionSummary;
+}
+
 /**
  * Data for transaction inputs for signing and ordering of unlock blocks.
  */
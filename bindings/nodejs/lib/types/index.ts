@@ -16,3 +16,48 @@ export interface Response<T> {
     type: string;
     payload: T;
 }
+
+/**
+ * Stable code of an error, to distinguish errors without parsing their messages
+ */
+export enum ErrorCode {
+    /** The parameters of the method are invalid or missing */
+    InvalidParameter = 'invalidParameter',
+    /** A requested item, like an account, a transaction or an output, doesn't exist */
+    NotFound = 'notFound',
+    /** The available funds aren't enough for the transaction */
+    InsufficientFunds = 'insufficientFunds',
+    /** No healthy node could be reached */
+    NodeUnreachable = 'nodeUnreachable',
+    /** A node answered the request with an error */
+    NodeError = 'nodeError',
+    /** The secret manager failed, e.g. a locked Stronghold or a rejected Ledger Nano prompt */
+    SecretManager = 'secretManager',
+    /** Reading or writing the wallet storage failed */
+    Storage = 'storage',
+    /** The method is not permitted by the method handler */
+    MethodNotPermitted = 'methodNotPermitted',
+    /** Any other error */
+    Internal = 'internal',
+}
+
+/**
+ * Payload of an error response from the message interface
+ */
+export interface IErrorPayload {
+    /** The category of the error, e.g. `client` or `wallet` */
+    type: string;
+    /** Stable code of the error */
+    code: ErrorCode;
+    /** The detailed kind of the error, made of the category and the kind of the innermost error, e.g. `node.responseError` */
+    kind: string;
+    /** The error message */
+    message: string;
+    /**
+     * The error message
+     * @deprecated Use `message` instead.
+     */
+    error: string;
+    /** Machine readable data of the error, like amounts, output IDs or node URLs */
+    data?: { [key: string]: any };
+}
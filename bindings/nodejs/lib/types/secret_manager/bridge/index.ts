@@ -3,6 +3,7 @@ import type {
     __GenerateEvmAddressesMethod__,
     __GetLedgerNanoStatusMethod__,
     __SignTransactionMethod__,
+    __ChangeStrongholdPasswordMethod__,
     __StoreMnemonicMethod__,
     __SignatureUnlockMethod__,
     __SignEd25519Method__,
@@ -15,6 +16,7 @@ export type __SecretManagerMethods__ =
     | __GetLedgerNanoStatusMethod__
     | __SignTransactionMethod__
     | __SignatureUnlockMethod__
+    | __ChangeStrongholdPasswordMethod__
     | __StoreMnemonicMethod__
     | __SignEd25519Method__
     | __SignSecp256k1EcdsaMethod__;
@@ -35,6 +35,14 @@ export interface __SignatureUnlockMethod__ {
     };
 }
 
+export interface __ChangeStrongholdPasswordMethod__ {
+    name: 'changeStrongholdPassword';
+    data: {
+        currentPassword: string;
+        newPassword: string;
+    };
+}
+
 export interface __StoreMnemonicMethod__ {
     name: 'storeMnemonic';
     data: {
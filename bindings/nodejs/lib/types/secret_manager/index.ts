@@ -3,3 +3,4 @@
 
 export * from './bridge';
 export * from './secret-manager';
+export * from './snapshot-verification';
@@ -0,0 +1,12 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/** The result of verifying a Stronghold snapshot. */
+export interface SnapshotVerification {
+    /** Whether the vault contains a seed record, i.e. a mnemonic has been stored. */
+    hasMnemonic: boolean;
+    /** Keys of the store records that could be decrypted. */
+    validStoreRecords: string[];
+    /** Keys of the store records that couldn't be decrypted. */
+    corruptedStoreRecords: string[];
+}
@@ -24,6 +24,8 @@ import type {
     __VerifyMnemonicMethod__,
     __FaucetMethod__,
     __OutputIdToUtxoInput__,
+    __VerifyStrongholdSnapshotMethod__,
+    __RepairStrongholdSnapshotMethod__,
 } from './utils';
 
 export type __UtilsMethods__ =
@@ -51,4 +53,6 @@ export type __UtilsMethods__ =
     | __VerifySecp256k1EcdsaSignatureMethod__
     | __VerifyMnemonicMethod__
     | __FaucetMethod__
-    | __OutputIdToUtxoInput__;
+    | __OutputIdToUtxoInput__
+    | __VerifyStrongholdSnapshotMethod__
+    | __RepairStrongholdSnapshotMethod__;
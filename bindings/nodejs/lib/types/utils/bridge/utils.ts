@@ -195,3 +195,20 @@ export interface __OutputIdToUtxoInput__ {
         outputId: string;
     };
 }
+
+export interface __VerifyStrongholdSnapshotMethod__ {
+    name: 'verifyStrongholdSnapshot';
+    data: {
+        path: string;
+        password: string;
+    };
+}
+
+export interface __RepairStrongholdSnapshotMethod__ {
+    name: 'repairStrongholdSnapshot';
+    data: {
+        path: string;
+        password: string;
+        recoveredPath: string;
+    };
+}
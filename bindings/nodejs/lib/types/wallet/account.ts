@@ -7,6 +7,7 @@ import type { OutputData } from './output';
 import type { Transaction } from './transaction';
 import { CoinType } from '../../client';
 import { HexEncodedString } from '../utils';
+import type { IErrorPayload } from '../index';
 
 /**
  * Account identifier
@@ -69,6 +70,16 @@ export interface NativeTokenBalance {
     available: bigint;
 }
 
+/** The result of syncing an account with `Wallet::syncAll()` */
+export interface AccountSyncResult {
+    /** The index of the account */
+    accountIndex: number;
+    /** The balance of the account, if it synced */
+    balance?: Balance;
+    /** The error, if the account failed to sync */
+    error?: IErrorPayload;
+}
+
 /** Sync options for an account */
 export interface SyncOptions {
     /**
@@ -76,6 +87,11 @@ export interface SyncOptions {
      * then `address_start_index` will be ignored
      */
     addresses?: string[];
+    /**
+     * Indexes of the accounts to sync when syncing the wallet, all accounts are synced if empty.
+     * If addresses are provided, only the accounts containing them are synced.
+     */
+    accountIndexes?: number[];
     /**
      * Address index from which to start syncing addresses. 0 by default, using a higher index will be faster because
      * addresses with a lower index will be skipped, but could result in a wrong balance for that reason
@@ -107,6 +123,8 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /** Request the participation status of the voting outputs and cache it for spent ones, so the participations are still known after the outputs got pruned. Default: false. */
+    syncParticipation?: boolean;
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
@@ -149,6 +167,8 @@ export interface AccountMeta {
     coinType: CoinType;
     /** The alias name of the account. */
     alias: string;
+    /** User-defined key-value metadata of the account. */
+    metadata: { [key: string]: string };
     /** All public addresses. */
     publicAddresses: AccountAddress[];
     /** All internal addresses. */
@@ -33,6 +33,16 @@ export interface SendParams {
     expiration?: number;
 }
 
+/** The costs of sending base coins, estimated before anything is signed or submitted. */
+export interface SendEstimate {
+    /** Minimum storage deposit required by the outputs to the recipients. */
+    storageDeposit: bigint;
+    /** Amount of the inputs that are consumed by the transaction. */
+    requiredInputAmount: bigint;
+    /** Amount that is sent back to the account with the remainder output. */
+    remainder: bigint;
+}
+
 /** Address with unspent outputs */
 export interface AddressWithUnspentOutputs {
     /** The Bech32 address. */
@@ -9,12 +9,14 @@ import type {
     GenerateAddressOptions,
 } from '../address';
 import type {
-    BuildAliasOutputData,
-    BuildBasicOutputData,
-    BuildFoundryOutputData,
-    BuildNftOutputData,
-} from '../build-output-data';
-import type { Burn, INode, PreparedTransactionData } from '../../client';
+    AliasOutputBuilderParams,
+    BasicOutputBuilderParams,
+    Burn,
+    FoundryOutputBuilderParams,
+    INode,
+    NftOutputBuilderParams,
+    PreparedTransactionData,
+} from '../../client';
 import type { OutputParams } from '../output-params';
 import type { OutputsToClaim } from '../output';
 import type { SignedTransactionEssence } from '../signed-transaction-essence';
@@ -29,27 +31,30 @@ import type {
     ParticipationEventRegistrationOptions,
     ParticipationEventType,
 } from '../participation';
-import type { ConsolidationParams } from '../consolidation-params';
-import { HexEncodedAmount, Output } from '../../';
+import type {
+    ConsolidationParams,
+    ConsolidationPolicy,
+} from '../consolidation-params';
+import { HexEncodedAmount, HexEncodedString, Output } from '../../';
 
 export type __BuildAliasOutputMethod__ = {
     name: 'buildAliasOutput';
-    data: BuildAliasOutputData;
+    data: AliasOutputBuilderParams;
 };
 
 export type __BuildBasicOutputMethod__ = {
     name: 'buildBasicOutput';
-    data: BuildBasicOutputData;
+    data: BasicOutputBuilderParams;
 };
 
 export type __BuildFoundryOutputMethod__ = {
     name: 'buildFoundryOutput';
-    data: BuildFoundryOutputData;
+    data: FoundryOutputBuilderParams;
 };
 
 export type __BuildNftOutputMethod__ = {
     name: 'buildNftOutput';
-    data: BuildNftOutputData;
+    data: NftOutputBuilderParams;
 };
 
 export type __PrepareBurnMethod__ = {
@@ -67,6 +72,10 @@ export type __ClaimOutputsMethod__ = {
     };
 };
 
+export type __ClaimProfitableOutputsMethod__ = {
+    name: 'claimProfitableOutputs';
+};
+
 export type __PrepareConsolidateOutputsMethod__ = {
     name: 'prepareConsolidateOutputs';
     data: {
@@ -98,6 +107,22 @@ export type __DeregisterParticipationEventMethod__ = {
     };
 };
 
+export type __DryRunTransactionMethod__ = {
+    name: 'dryRunTransaction';
+    data: {
+        outputs: Output[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __EstimateSendMethod__ = {
+    name: 'estimateSend';
+    data: {
+        params: SendParams[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __GenerateEd25519AddressesMethod__ = {
     name: 'generateEd25519Addresses';
     data: {
@@ -145,6 +170,13 @@ export type __GetTransactionMethod__ = {
     };
 };
 
+export type __GetTransactionReceiptMethod__ = {
+    name: 'getTransactionReceipt';
+    data: {
+        transactionId: string;
+    };
+};
+
 export type __AddressesMethod__ = {
     name: 'addresses';
 };
@@ -179,6 +211,13 @@ export type __UnspentOutputsMethod__ = {
     };
 };
 
+export type __VerifyAddressOnDeviceMethod__ = {
+    name: 'verifyAddressOnDevice';
+    data: {
+        keyIndex: number;
+    };
+};
+
 export type __PrepareMintNativeTokenMethod__ = {
     name: 'prepareMintNativeToken';
     data: {
@@ -196,6 +235,14 @@ export type __PrepareCreateNativeTokenMethod__ = {
     };
 };
 
+export type __MintNftsInBatchesMethod__ = {
+    name: 'mintNftsInBatches';
+    data: {
+        params: MintNftParams[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __PrepareMintNftsMethod__ = {
     name: 'prepareMintNfts';
     data: {
@@ -228,6 +275,15 @@ export type __PrepareTransactionMethod__ = {
     };
 };
 
+export type __PrepareUpdateAliasStateMethod__ = {
+    name: 'prepareUpdateAliasState';
+    data: {
+        aliasId: string;
+        stateMetadata: HexEncodedString;
+        options?: TransactionOptions;
+    };
+};
+
 export type __RegisterParticipationEventsMethod__ = {
     name: 'registerParticipationEvents';
     data: {
@@ -235,6 +291,13 @@ export type __RegisterParticipationEventsMethod__ = {
     };
 };
 
+export type __RemoveMetadataMethod__ = {
+    name: 'removeMetadata';
+    data: {
+        key: string;
+    };
+};
+
 export type __RetryTransactionUntilIncludedMethod__ = {
     name: 'retryTransactionUntilIncluded';
     data: {
@@ -261,6 +324,14 @@ export type __SendWithParamsMethod__ = {
     };
 };
 
+export type __SendWithReceiptMethod__ = {
+    name: 'sendWithReceipt';
+    data: {
+        params: SendParams[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __PrepareSendNativeTokensMethod__ = {
     name: 'prepareSendNativeTokens';
     data: {
@@ -292,6 +363,21 @@ export type __SetAliasMethod__ = {
     };
 };
 
+export type __SetMetadataMethod__ = {
+    name: 'setMetadata';
+    data: {
+        key: string;
+        value: string;
+    };
+};
+
+export type __SetConsolidationPolicyMethod__ = {
+    name: 'setConsolidationPolicy';
+    data: {
+        policy?: ConsolidationPolicy;
+    };
+};
+
 export type __SetDefaultSyncOptionsMethod__ = {
     name: 'setDefaultSyncOptions';
     data: {
@@ -6,15 +6,19 @@ import type {
     __BuildNftOutputMethod__,
     __PrepareBurnMethod__,
     __ClaimOutputsMethod__,
+    __ClaimProfitableOutputsMethod__,
     __PrepareConsolidateOutputsMethod__,
     __PrepareCreateAliasOutputMethod__,
     __DeregisterParticipationEventMethod__,
+    __DryRunTransactionMethod__,
+    __EstimateSendMethod__,
     __GenerateEd25519AddressesMethod__,
     __GetBalanceMethod__,
     __GetOutputMethod__,
     __GetFoundryOutputMethod__,
     __ClaimableOutputsMethod__,
     __GetTransactionMethod__,
+    __GetTransactionReceiptMethod__,
     __AddressesMethod__,
     __AddressesWithUnspentOutputsMethod__,
     __OutputsMethod__,
@@ -22,22 +26,29 @@ import type {
     __IncomingTransactionsMethod__,
     __TransactionsMethod__,
     __UnspentOutputsMethod__,
+    __VerifyAddressOnDeviceMethod__,
     __PrepareCreateNativeTokenMethod__,
     __PrepareMeltNativeTokenMethod__,
     __PrepareMintNativeTokenMethod__,
+    __MintNftsInBatchesMethod__,
     __PrepareMintNftsMethod__,
     __PrepareOutputMethod__,
     __PrepareSendMethod__,
     __PrepareTransactionMethod__,
+    __PrepareUpdateAliasStateMethod__,
     __RegisterParticipationEventsMethod__,
+    __RemoveMetadataMethod__,
     __RetryTransactionUntilIncludedMethod__,
     __SendMethod__,
     __SendWithParamsMethod__,
+    __SendWithReceiptMethod__,
     __PrepareSendNativeTokensMethod__,
     __PrepareSendNftMethod__,
     __SendOutputsMethod__,
     __SetAliasMethod__,
+    __SetConsolidationPolicyMethod__,
     __SetDefaultSyncOptionsMethod__,
+    __SetMetadataMethod__,
     __SignTransactionEssenceMethod__,
     __SignAndSubmitTransactionMethod__,
     __SubmitAndStoreTransactionMethod__,
@@ -54,7 +65,9 @@ import type {
     __PrepareStopParticipatingMethod__,
 } from './account';
 import type {
+    __AddContactMethod__,
     __BackupMethod__,
+    __BackupToFileMethod__,
     __ChangeStrongholdPasswordMethod__,
     __ClearStrongholdPasswordMethod__,
     __ClearListenersMethod__,
@@ -68,15 +81,20 @@ import type {
     __GetLedgerNanoStatusMethod__,
     __GenerateEd25519AddressMethod__,
     __IsStrongholdPasswordAvailableMethod__,
+    __ListContactsMethod__,
     __RecoverAccountsMethod__,
+    __RemoveContactMethod__,
     __RemoveLatestAccountMethod__,
+    __RenameAccountMethod__,
     __RestoreBackupMethod__,
+    __RestoreBackupFromFileMethod__,
     __SetClientOptionsMethod__,
     __SetStrongholdPasswordClearIntervalMethod__,
     __SetStrongholdPasswordMethod__,
     __StartBackgroundSyncMethod__,
     __StopBackgroundSyncMethod__,
     __StoreMnemonicMethod__,
+    __SyncAllMethod__,
     __UpdateNodeAuthMethod__,
 } from './wallet';
 
@@ -87,9 +105,12 @@ export type __AccountMethod__ =
     | __BuildNftOutputMethod__
     | __PrepareBurnMethod__
     | __ClaimOutputsMethod__
+    | __ClaimProfitableOutputsMethod__
     | __PrepareConsolidateOutputsMethod__
     | __PrepareCreateAliasOutputMethod__
     | __DeregisterParticipationEventMethod__
+    | __DryRunTransactionMethod__
+    | __EstimateSendMethod__
     | __GenerateEd25519AddressesMethod__
     | __GetBalanceMethod__
     | __GetOutputMethod__
@@ -101,6 +122,7 @@ export type __AccountMethod__ =
     | __GetParticipationEventsMethod__
     | __GetParticipationEventStatusMethod__
     | __GetTransactionMethod__
+    | __GetTransactionReceiptMethod__
     | __AddressesMethod__
     | __AddressesWithUnspentOutputsMethod__
     | __OutputsMethod__
@@ -108,22 +130,29 @@ export type __AccountMethod__ =
     | __IncomingTransactionsMethod__
     | __TransactionsMethod__
     | __UnspentOutputsMethod__
+    | __VerifyAddressOnDeviceMethod__
     | __PrepareCreateNativeTokenMethod__
     | __PrepareMeltNativeTokenMethod__
     | __PrepareMintNativeTokenMethod__
+    | __MintNftsInBatchesMethod__
     | __PrepareMintNftsMethod__
     | __PrepareOutputMethod__
     | __PrepareSendMethod__
     | __PrepareTransactionMethod__
+    | __PrepareUpdateAliasStateMethod__
     | __RegisterParticipationEventsMethod__
+    | __RemoveMetadataMethod__
     | __RetryTransactionUntilIncludedMethod__
     | __SendMethod__
     | __SendWithParamsMethod__
+    | __SendWithReceiptMethod__
     | __PrepareSendNativeTokensMethod__
     | __PrepareSendNftMethod__
     | __SendOutputsMethod__
     | __SetAliasMethod__
+    | __SetConsolidationPolicyMethod__
     | __SetDefaultSyncOptionsMethod__
+    | __SetMetadataMethod__
     | __SignTransactionEssenceMethod__
     | __SignAndSubmitTransactionMethod__
     | __SubmitAndStoreTransactionMethod__
@@ -143,7 +172,9 @@ export type __CallAccountMethodMethod__ = {
 };
 
 export type __Method__ =
+    | __AddContactMethod__
     | __BackupMethod__
+    | __BackupToFileMethod__
     | __CallAccountMethodMethod__
     | __ChangeStrongholdPasswordMethod__
     | __ClearListenersMethod__
@@ -158,13 +189,18 @@ export type __Method__ =
     | __GetLedgerNanoStatusMethod__
     | __GenerateEd25519AddressMethod__
     | __IsStrongholdPasswordAvailableMethod__
+    | __ListContactsMethod__
     | __RecoverAccountsMethod__
+    | __RemoveContactMethod__
     | __RemoveLatestAccountMethod__
+    | __RenameAccountMethod__
     | __RestoreBackupMethod__
+    | __RestoreBackupFromFileMethod__
     | __SetClientOptionsMethod__
     | __SetStrongholdPasswordClearIntervalMethod__
     | __SetStrongholdPasswordMethod__
     | __StartBackgroundSyncMethod__
     | __StopBackgroundSyncMethod__
     | __StoreMnemonicMethod__
+    | __SyncAllMethod__
     | __UpdateNodeAuthMethod__;
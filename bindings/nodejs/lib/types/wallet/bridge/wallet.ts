@@ -2,6 +2,12 @@ import type { AccountId, CreateAccountPayload, SyncOptions } from '../account';
 import type { GenerateAddressOptions } from '../address';
 import type { WalletEventType, WalletEvent } from '../event';
 import type { IAuth, IClientOptions } from '../../client';
+import type { Contact } from '../wallet';
+
+export type __AddContactMethod__ = {
+    name: 'addContact';
+    data: { contact: Contact };
+};
 
 export type __BackupMethod__ = {
     name: 'backup';
@@ -11,6 +17,14 @@ export type __BackupMethod__ = {
     };
 };
 
+export type __BackupToFileMethod__ = {
+    name: 'backupToFile';
+    data: {
+        destination: string;
+        password: string;
+    };
+};
+
 export type __ChangeStrongholdPasswordMethod__ = {
     name: 'changeStrongholdPassword';
     data: {
@@ -77,20 +91,35 @@ export type __IsStrongholdPasswordAvailableMethod__ = {
     name: 'isStrongholdPasswordAvailable';
 };
 
+export type __ListContactsMethod__ = {
+    name: 'listContacts';
+};
+
 export type __RecoverAccountsMethod__ = {
     name: 'recoverAccounts';
     data: {
         accountStartIndex: number;
         accountGapLimit: number;
         addressGapLimit: number;
+        addressGapLimitInternal?: number;
         syncOptions?: SyncOptions;
     };
 };
 
+export type __RemoveContactMethod__ = {
+    name: 'removeContact';
+    data: { name: string };
+};
+
 export type __RemoveLatestAccountMethod__ = {
     name: 'removeLatestAccount';
 };
 
+export type __RenameAccountMethod__ = {
+    name: 'renameAccount';
+    data: { accountId: AccountId; alias: string };
+};
+
 export type __RestoreBackupMethod__ = {
     name: 'restoreBackup';
     data: {
@@ -101,6 +130,14 @@ export type __RestoreBackupMethod__ = {
     };
 };
 
+export type __RestoreBackupFromFileMethod__ = {
+    name: 'restoreBackupFromFile';
+    data: {
+        source: string;
+        password: string;
+    };
+};
+
 export type __SetClientOptionsMethod__ = {
     name: 'setClientOptions';
     data: { clientOptions: IClientOptions };
@@ -128,6 +165,14 @@ export type __StopBackgroundSyncMethod__ = {
     name: 'stopBackgroundSync';
 };
 
+export type __SyncAllMethod__ = {
+    name: 'syncAll';
+    data: {
+        options?: SyncOptions;
+        concurrency?: number;
+    };
+};
+
 export type __StoreMnemonicMethod__ = {
     name: 'storeMnemonic';
     data: { mnemonic: string };
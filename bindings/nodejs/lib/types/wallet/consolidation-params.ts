@@ -9,4 +9,14 @@ export interface ConsolidationParams {
     outputThreshold?: number;
     /** Address to which the consolidated output should be sent. */
     targetAddress?: string;
+    /** Also consolidates outputs with other unlock conditions than the address unlock condition, `true` by default. */
+    unlockConditionsAllowed?: boolean;
+}
+
+/** Defines when the basic outputs of an account are consolidated automatically while syncing */
+export interface ConsolidationPolicy {
+    /** Consolidates once the number of spendable basic outputs is >= the output threshold. */
+    outputThreshold: number;
+    /** Also consolidates outputs with other unlock conditions than the address unlock condition. */
+    unlockConditionsAllowed: boolean;
 }
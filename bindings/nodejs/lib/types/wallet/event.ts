@@ -51,6 +51,20 @@ enum WalletEventType {
     TransactionInclusion = 4,
     /** A progress update while submitting a transaction. */
     TransactionProgress = 5,
+    /** The address that can unlock an output changed because its expiration expired. */
+    LockedAddressChanged = 6,
+    /** The current receive address of an account was rotated. */
+    AddressRotated = 7,
+    /** A conflicting transaction was rebuilt with other inputs and sent again. */
+    TransactionRebuilt = 8,
+    /** The alias of an account was changed. */
+    AccountRenamed = 9,
+    /** The address displayed by a Nano Ledger doesn't match the address of the account. */
+    LedgerAddressMismatch = 10,
+    /** A progress update while syncing an account. */
+    SyncProgress = 11,
+    /** Basic outputs were consolidated automatically, or the attempt failed. */
+    AutoConsolidation = 12,
 }
 
 /**
@@ -91,6 +105,27 @@ class LedgerAddressGenerationWalletEvent extends WalletEvent {
     }
 }
 
+/**
+ * A 'ledger address mismatch' wallet event.
+ */
+class LedgerAddressMismatchWalletEvent extends WalletEvent {
+    keyIndex: number;
+    expected: string;
+    displayed: string;
+
+    /**
+     * @param keyIndex The key index of the address.
+     * @param expected The address of the account.
+     * @param displayed The address displayed by the device.
+     */
+    constructor(keyIndex: number, expected: string, displayed: string) {
+        super(WalletEventType.LedgerAddressMismatch);
+        this.keyIndex = keyIndex;
+        this.expected = expected;
+        this.displayed = displayed;
+    }
+}
+
 /**
  * A 'new output' wallet event.
  */
@@ -137,15 +172,155 @@ class SpentOutputWalletEvent extends WalletEvent {
 class TransactionInclusionWalletEvent extends WalletEvent {
     transactionId: TransactionId;
     inclusionState: InclusionState;
+    blockId?: string;
 
     /**
      * @param transactionId The transaction ID.
      * @param inclusionState The inclusion state of the transaction.
+     * @param blockId The block that included the transaction.
      */
-    constructor(transactionId: TransactionId, inclusionState: InclusionState) {
+    constructor(
+        transactionId: TransactionId,
+        inclusionState: InclusionState,
+        blockId?: string,
+    ) {
         super(WalletEventType.TransactionInclusion);
         this.transactionId = transactionId;
         this.inclusionState = inclusionState;
+        this.blockId = blockId;
+    }
+}
+
+/**
+ * A 'locked address changed' wallet event.
+ */
+class LockedAddressChangedWalletEvent extends WalletEvent {
+    outputId: string;
+    previousAddress: string;
+    address: string;
+
+    /**
+     * @param outputId The output ID.
+     * @param previousAddress The address that could unlock the output before, its nominal recipient.
+     * @param address The address that can unlock the output now, the return address of the expiration.
+     */
+    constructor(outputId: string, previousAddress: string, address: string) {
+        super(WalletEventType.LockedAddressChanged);
+        this.outputId = outputId;
+        this.previousAddress = previousAddress;
+        this.address = address;
+    }
+}
+
+/**
+ * An 'address rotated' wallet event.
+ */
+class AddressRotatedWalletEvent extends WalletEvent {
+    previousAddress?: string;
+    address: string;
+
+    /**
+     * @param address The new receive address.
+     * @param previousAddress The previous receive address, if there was one.
+     */
+    constructor(address: string, previousAddress?: string) {
+        super(WalletEventType.AddressRotated);
+        this.address = address;
+        this.previousAddress = previousAddress;
+    }
+}
+
+/**
+ * A 'transaction rebuilt' wallet event.
+ */
+class TransactionRebuiltWalletEvent extends WalletEvent {
+    previousTransactionId: string;
+    transactionId: string;
+
+    /**
+     * @param previousTransactionId The ID of the conflicting transaction.
+     * @param transactionId The ID of the transaction that replaces it.
+     */
+    constructor(previousTransactionId: string, transactionId: string) {
+        super(WalletEventType.TransactionRebuilt);
+        this.previousTransactionId = previousTransactionId;
+        this.transactionId = transactionId;
+    }
+}
+
+/**
+ * An 'account renamed' wallet event.
+ */
+class AccountRenamedWalletEvent extends WalletEvent {
+    previousAlias: string;
+    alias: string;
+
+    /**
+     * @param previousAlias The previous alias of the account.
+     * @param alias The new alias of the account.
+     */
+    constructor(previousAlias: string, alias: string) {
+        super(WalletEventType.AccountRenamed);
+        this.previousAlias = previousAlias;
+        this.alias = alias;
+    }
+}
+
+/**
+ * The stages of an account sync, in the order they're run.
+ */
+enum SyncStage {
+    /** Requesting the output IDs of the addresses, the items are addresses. */
+    AddressDiscovery = 'addressDiscovery',
+    /** Requesting the outputs and their metadata, the items are outputs. */
+    OutputFetching = 'outputFetching',
+    /** Requesting the transactions that created the new outputs, the items are transactions. */
+    TransactionResolution = 'transactionResolution',
+}
+
+/**
+ * A 'sync progress' wallet event.
+ */
+class SyncProgressWalletEvent extends WalletEvent {
+    stage: SyncStage;
+    processed: number;
+    total: number;
+
+    /**
+     * @param stage The stage of the sync.
+     * @param processed The number of items of the stage that were processed so far.
+     * @param total The number of items of the stage.
+     */
+    constructor(stage: SyncStage, processed: number, total: number) {
+        super(WalletEventType.SyncProgress);
+        this.stage = stage;
+        this.processed = processed;
+        this.total = total;
+    }
+}
+
+/**
+ * An 'auto consolidation' wallet event.
+ */
+class AutoConsolidationWalletEvent extends WalletEvent {
+    consolidatedOutputs: number;
+    transactionId?: string;
+    error?: string;
+
+    /**
+     * @param consolidatedOutputs The number of outputs that were consolidated, or failed to be consolidated.
+     * @param transactionId The ID of the consolidation transaction, if it was sent.
+     * @param error Why the consolidation failed.
+     */
+    constructor(
+        consolidatedOutputs: number,
+        transactionId?: string,
+        error?: string,
+    ) {
+        super(WalletEventType.AutoConsolidation);
+        this.consolidatedOutputs = consolidatedOutputs;
+        this.transactionId = transactionId;
+        this.error = error;
     }
 }
 
@@ -295,10 +470,18 @@ export {
     WalletEvent,
     ConsolidationRequiredWalletEvent,
     LedgerAddressGenerationWalletEvent,
+    LedgerAddressMismatchWalletEvent,
     NewOutputWalletEvent,
     SpentOutputWalletEvent,
     TransactionInclusionWalletEvent,
     TransactionProgressWalletEvent,
+    LockedAddressChangedWalletEvent,
+    AddressRotatedWalletEvent,
+    TransactionRebuiltWalletEvent,
+    AccountRenamedWalletEvent,
+    SyncStage,
+    SyncProgressWalletEvent,
+    AutoConsolidationWalletEvent,
     TransactionProgress,
     SelectingInputsProgress,
     GeneratingRemainderDepositAddressProgress,
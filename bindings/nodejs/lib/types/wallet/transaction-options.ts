@@ -19,12 +19,24 @@ export interface TransactionOptions {
     customInputs?: string[];
     /** Inputs that must be used for the transaction. */
     mandatoryInputs?: string[];
+    /** Outputs that must not be used as inputs, e.g. outputs of a dust attack. */
+    forbiddenInputs?: string[];
     /** Specifies what needs to be burned during input selection. */
     burn?: Burn;
     /** Optional note, that is only stored locally. */
     note?: string;
     /** Whether to allow sending a micro amount. */
     allowMicroAmount: boolean;
+    /**
+     * Time in seconds that the expiration of conditional payments needs to be later than, to leave time for the
+     * transaction to get confirmed. Default is one minute.
+     */
+    expirationSlack?: number;
+    /**
+     * Only prepare the transaction, to preview it: its inputs aren't locked, no events are emitted and no remainder
+     * address is generated. Signing or sending it fails.
+     */
+    dryRun?: boolean;
 }
 
 /** The possible remainder value strategies. */
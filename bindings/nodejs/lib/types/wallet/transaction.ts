@@ -4,6 +4,8 @@
 import { Type } from 'class-transformer';
 import { TransactionPayload } from '../block/payload/transaction';
 import { OutputResponse } from '../models/api';
+import { HexEncodedAmount } from '../utils';
+import { TransactionOptions } from './transaction-options';
 
 /** Possible InclusionStates of transactions sent with the wallet */
 export enum InclusionState {
@@ -24,6 +26,8 @@ export class Transaction {
     payload!: TransactionPayload;
     /** The block id in which the transaction payload was included */
     blockId?: string;
+    /** The ids of all blocks the transaction was sent in, starting with the first attachment */
+    attachments?: string[];
     /** The inclusion state of the transaction */
     inclusionState!: InclusionState;
     /** The creation time */
@@ -42,4 +46,50 @@ export class Transaction {
      */
     @Type(() => OutputResponse)
     inputs!: OutputResponse[];
+    /** How the transaction was built, if it was sent by the wallet, to rebuild it if it's conflicting */
+    buildData?: TransactionBuildData;
+}
+
+/** How a transaction sent by the wallet was built */
+export interface TransactionBuildData {
+    /** The options the transaction was built with */
+    options: TransactionOptions;
+    /** The index of the remainder output, if the transaction has one */
+    remainderIndex?: number;
+}
+
+/** How a transaction changed the balance of a native token, only one of the amounts isn't zero */
+export interface NativeTokenChange {
+    /** The ID of the native token */
+    tokenId: string;
+    /** The amount the balance increased by */
+    increase: HexEncodedAmount;
+    /** The amount the balance decreased by */
+    decrease: HexEncodedAmount;
+}
+
+/** How a transaction changed the balance of an address */
+export interface AddressBalanceChange {
+    /** The address, the state controller for alias outputs and the alias for foundry outputs */
+    address: string;
+    /** The change of the base coin balance */
+    baseCoinChange: string;
+    /** The changes of the native token balances, unchanged tokens are left out */
+    nativeTokenChanges: NativeTokenChange[];
+}
+
+/** The effects of a transaction: the outputs it consumed and created and how it changed the balances of the addresses owning them */
+export interface TransactionReceipt {
+    /** The ID of the transaction */
+    transactionId: string;
+    /** The block the transaction was sent in, if it was sent by the account */
+    blockId?: string;
+    /** The outputs the transaction consumed */
+    consumedOutputs: string[];
+    /** The outputs the transaction created */
+    createdOutputs: string[];
+    /** The balance changes of the addresses owning the consumed or created outputs, sorted by address */
+    addressChanges: AddressBalanceChange[];
+    /** The net changes of the native tokens over all addresses, which aren't zero if tokens were minted, melted or burned */
+    nativeTokenChanges: NativeTokenChange[];
 }
@@ -3,12 +3,40 @@ import { SecretManagerType } from '../secret_manager/secret-manager';
 
 /** Options for the Wallet builder. */
 export interface WalletOptions {
-    /** The path to the wallet database. */
+    /** The path to the wallet database, or the prefix of the keys for the browser local storage. */
     storagePath?: string;
+    /** The kind of storage, defaults to `Rocksdb`, or to `Memory` for the WebAssembly bindings. */
+    storageKind?: StorageKind;
     /** The node client options. */
     clientOptions?: IClientOptions;
     /** The type of coin stored with the wallet. */
     coinType?: CoinType;
     /** The secret manager to use. */
     secretManager?: SecretManagerType;
+    /** Restricts the methods that can be called on the wallet and the client and secret manager obtained from it. */
+    permissions?: MethodPermissions;
+}
+
+/**
+ * The kind of storage of a wallet: `Rocksdb` stores it in a database, `Memory` only keeps it while the wallet exists and
+ * `Wasm` stores it in the local storage of the browser.
+ */
+export type StorageKind = 'Rocksdb' | 'Memory' | 'Wasm';
+
+/** Restricts the methods a method handler is allowed to call, by method name. */
+export interface MethodPermissions {
+    /** If set, only these methods can be called. */
+    allowedMethods?: string[];
+    /** These methods can never be called. */
+    deniedMethods?: string[];
+}
+
+/** A named address of the address book of the wallet. */
+export interface Contact {
+    /** The name of the contact, unique in the address book. */
+    name: string;
+    /** The bech32 encoded address of the contact. */
+    address: string;
+    /** An optional note about the contact. */
+    note?: string;
 }
@@ -16,6 +16,7 @@ import {
     Output,
     IRent,
     OutputId,
+    SnapshotVerification,
 } from '../types';
 import { AliasId, BlockId, FoundryId, NftId, TokenId } from '../types/block/id';
 
@@ -397,4 +398,39 @@ export class Utils {
             data: { mnemonic },
         });
     }
+
+    /**
+     * Verify a Stronghold snapshot without loading it into a wallet.
+     *
+     * @param path The path of the snapshot.
+     * @param password The password of the snapshot.
+     */
+    static verifyStrongholdSnapshot(
+        path: string,
+        password: string,
+    ): SnapshotVerification {
+        return callUtilsMethod({
+            name: 'verifyStrongholdSnapshot',
+            data: { path, password },
+        });
+    }
+
+    /**
+     * Write the readable parts of a Stronghold snapshot to a new path, dropping corrupted records.
+     * Returns the verification of the original snapshot.
+     *
+     * @param path The path of the snapshot.
+     * @param password The password of the snapshot.
+     * @param recoveredPath The path to write the repaired snapshot to.
+     */
+    static repairStrongholdSnapshot(
+        path: string,
+        password: string,
+        recoveredPath: string,
+    ): SnapshotVerification {
+        return callUtilsMethod({
+            name: 'repairStrongholdSnapshot',
+            data: { path, password, recoveredPath },
+        });
+    }
 }
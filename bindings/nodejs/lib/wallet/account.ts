@@ -9,6 +9,7 @@ import {
     AccountMeta,
     AccountAddress,
     SendParams,
+    SendEstimate,
     SendNativeTokensParams,
     SendNftParams,
     AddressWithUnspentOutputs,
@@ -22,6 +23,7 @@ import {
     OutputsToClaim,
     Transaction,
     TransactionOptions,
+    TransactionReceipt,
     ParticipationOverview,
     ParticipationEventId,
     ParticipationEventStatus,
@@ -29,16 +31,22 @@ import {
     ParticipationEventWithNodes,
     ParticipationEventRegistrationOptions,
     ParticipationEventMap,
-    BuildAliasOutputData,
-    BuildBasicOutputData,
-    BuildFoundryOutputData,
-    BuildNftOutputData,
     SignedTransactionEssence,
     PreparedTransaction,
     PreparedCreateNativeTokenTransactionData,
     ConsolidationParams,
+    ConsolidationPolicy,
 } from '../types/wallet';
-import { INode, Burn, PreparedTransactionData } from '../client';
+import {
+    INode,
+    Burn,
+    PreparedTransactionData,
+    DryRunTransaction,
+    AliasOutputBuilderParams,
+    BasicOutputBuilderParams,
+    FoundryOutputBuilderParams,
+    NftOutputBuilderParams,
+} from '../client';
 import {
     AliasOutput,
     NftOutput,
@@ -47,6 +55,7 @@ import {
     FoundryOutput,
     Response,
     PreparedCreateNativeTokenTransaction,
+    HexEncodedString,
 } from '../types';
 import { plainToInstance } from 'class-transformer';
 import { bigIntToHex, hexToBigInt } from '../types/utils/hex-encoding';
@@ -66,54 +75,94 @@ export class Account {
         this.methodHandler = methodHandler;
     }
 
-    /** @deprecated use Client::buildAliasOutput() instead. */
-    async buildAliasOutput(data: BuildAliasOutputData): Promise<AliasOutput> {
+    /**
+     * Build an alias output with the rent structure and token supply of the node the account is connected to.
+     *
+     * @param params An instance of `AliasOutputBuilderParams`.
+     */
+    async buildAliasOutput(
+        params: AliasOutputBuilderParams,
+    ): Promise<AliasOutput> {
+        if (params.amount && typeof params.amount === 'bigint') {
+            params.amount = params.amount.toString(10);
+        }
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
                 name: 'buildAliasOutput',
-                data,
+                data: params,
             },
         );
-        return JSON.parse(response).payload;
+
+        const parsed = JSON.parse(response) as Response<AliasOutput>;
+        return plainToInstance(AliasOutput, parsed.payload);
     }
 
-    /** @deprecated use Client::buildBasicOutput() instead. */
-    async buildBasicOutput(data: BuildBasicOutputData): Promise<BasicOutput> {
+    /**
+     * Build a basic output with the rent structure and token supply of the node the account is connected to.
+     *
+     * @param params An instance of `BasicOutputBuilderParams`.
+     */
+    async buildBasicOutput(
+        params: BasicOutputBuilderParams,
+    ): Promise<BasicOutput> {
+        if (params.amount && typeof params.amount === 'bigint') {
+            params.amount = params.amount.toString(10);
+        }
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
                 name: 'buildBasicOutput',
-                data,
+                data: params,
             },
         );
-        return Output.parse(JSON.parse(response).payload) as BasicOutput;
+
+        const parsed = JSON.parse(response) as Response<BasicOutput>;
+        return plainToInstance(BasicOutput, parsed.payload);
     }
 
-    /** @deprecated use Client::buildFoundryOutput() instead. */
+    /**
+     * Build a foundry output with the rent structure and token supply of the node the account is connected to.
+     *
+     * @param params An instance of `FoundryOutputBuilderParams`.
+     */
     async buildFoundryOutput(
-        data: BuildFoundryOutputData,
+        params: FoundryOutputBuilderParams,
     ): Promise<FoundryOutput> {
+        if (params.amount && typeof params.amount === 'bigint') {
+            params.amount = params.amount.toString(10);
+        }
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
                 name: 'buildFoundryOutput',
-                data,
+                data: params,
             },
         );
-        return Output.parse(JSON.parse(response).payload) as FoundryOutput;
+
+        const parsed = JSON.parse(response) as Response<FoundryOutput>;
+        return plainToInstance(FoundryOutput, parsed.payload);
     }
 
-    /** @deprecated use Client::buildNftOutput() instead. */
-    async buildNftOutput(data: BuildNftOutputData): Promise<NftOutput> {
+    /**
+     * Build an NFT output with the rent structure and token supply of the node the account is connected to.
+     *
+     * @param params An instance of `NftOutputBuilderParams`.
+     */
+    async buildNftOutput(params: NftOutputBuilderParams): Promise<NftOutput> {
+        if (params.amount && typeof params.amount === 'bigint') {
+            params.amount = params.amount.toString(10);
+        }
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
                 name: 'buildNftOutput',
-                data,
+                data: params,
             },
         );
-        return Output.parse(JSON.parse(response).payload) as NftOutput;
+
+        const parsed = JSON.parse(response) as Response<NftOutput>;
+        return plainToInstance(NftOutput, parsed.payload);
     }
 
     /**
@@ -233,6 +282,23 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Claim all outputs which don't require additional funds of the account to be claimed,
+     * in as many transactions as needed.
+     *
+     * @returns The sent claiming transactions.
+     */
+    async claimProfitableOutputs(): Promise<Transaction[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'claimProfitableOutputs',
+            },
+        );
+        const parsed = JSON.parse(response) as Response<Transaction[]>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
     /**
      * Consolidate basic outputs with only an `AddressUnlockCondition` from an account
      * by sending them to an own address again if the output amount is greater or
@@ -346,6 +412,41 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Estimate the costs of sending base coins without signing or submitting anything.
+     *
+     * @param params Address with amounts to send.
+     * @param options The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The storage deposit, the required input amount and the remainder.
+     */
+    async estimateSend(
+        params: SendParams[],
+        options?: TransactionOptions,
+    ): Promise<SendEstimate> {
+        for (let i = 0; i < params.length; i++) {
+            if (typeof params[i].amount === 'bigint') {
+                params[i].amount = params[i].amount.toString(10);
+            }
+        }
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'estimateSend',
+                data: {
+                    params,
+                    options,
+                },
+            },
+        );
+        const payload = JSON.parse(response).payload;
+        return {
+            storageDeposit: BigInt(payload.storageDeposit),
+            requiredInputAmount: BigInt(payload.requiredInputAmount),
+            remainder: BigInt(payload.remainder),
+        };
+    }
+
     /**
      * Destroy an alias output.
      *
@@ -450,14 +551,14 @@ export class Account {
             },
         );
         const payload = JSON.parse(response).payload;
-        return this.adjustBalancePayload(payload);
+        return Account.adjustBalancePayload(payload);
     }
 
     /**
      * Converts hex encoded or decimal strings of amounts to `bigint`
      * for the balance payload.
      */
-    private adjustBalancePayload(payload: any): Balance {
+    static adjustBalancePayload(payload: any): Balance {
         for (let i = 0; i < payload.nativeTokens.length; i++) {
             payload.nativeTokens[i].total = hexToBigInt(
                 payload.nativeTokens[i].total,
@@ -639,6 +740,31 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Get the receipt of a transaction stored in the account, with the outputs it consumed and created and the
+     * balance changes of the addresses owning them.
+     *
+     * @param transactionId The ID of the transaction to get the receipt of.
+     * @returns The transaction receipt, `undefined` if the transaction isn't stored in the account.
+     */
+    async getTransactionReceipt(
+        transactionId: string,
+    ): Promise<TransactionReceipt | undefined> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getTransactionReceipt',
+                data: {
+                    transactionId,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<
+            TransactionReceipt | undefined
+        >;
+        return parsed.payload ?? undefined;
+    }
+
     /**
      * Get the transaction with inputs of an incoming transaction stored in the account
      * List might not be complete, if the node pruned the data already
@@ -777,6 +903,24 @@ export class Account {
         return plainToInstance(OutputData, parsed.payload);
     }
 
+    /**
+     * Display the public address with the key index on the Ledger Nano device, so the user can verify it.
+     * A `LedgerAddressMismatch` event is emitted if the device derived another address.
+     *
+     * @param keyIndex The key index of the address.
+     * @returns Whether the device derived the same address.
+     */
+    async verifyAddressOnDevice(keyIndex: number): Promise<boolean> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'verifyAddressOnDevice',
+                data: { keyIndex },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get the accounts metadata.
      *
@@ -866,6 +1010,34 @@ export class Account {
         );
     }
 
+    /**
+     * Mint NFTs in as many transactions as needed, for collections that don't
+     * fit in a single transaction. Each transaction is waited for before the
+     * next one is sent.
+     *
+     * @param params The options for minting nfts.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The sent transactions.
+     */
+    async mintNftsInBatches(
+        params: MintNftParams[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'mintNftsInBatches',
+                data: {
+                    params,
+                    options: transactionOptions,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<Transaction[]>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
     /**
      * Mint NFTs.
      *
@@ -1001,6 +1173,78 @@ export class Account {
         );
     }
 
+    /**
+     * Build a transaction with input selection, output validation and storage deposit checks,
+     * without signing or sending it and without keeping its inputs locked.
+     *
+     * @param outputs Outputs to use in the transaction.
+     * @param options The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The would-be transaction and a summary of its consumed and created outputs.
+     */
+    async dryRunTransaction(
+        outputs: Output[],
+        options?: TransactionOptions,
+    ): Promise<DryRunTransaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'dryRunTransaction',
+                data: {
+                    outputs,
+                    options,
+                },
+            },
+        );
+        const payload = JSON.parse(response).payload;
+        return {
+            preparedTransactionData: plainToInstance(
+                PreparedTransactionData,
+                payload.preparedTransactionData,
+            ),
+            summary: {
+                ...payload.summary,
+                consumedAmount: BigInt(payload.summary.consumedAmount),
+                createdAmount: BigInt(payload.summary.createdAmount),
+            },
+        };
+    }
+
+    /**
+     * Prepare to update the state metadata of an alias with a state
+     * transition, keeping all other fields and incrementing the state index.
+     *
+     * @param aliasId The AliasId.
+     * @param stateMetadata The new hex encoded state metadata.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The prepared transaction.
+     */
+    async prepareUpdateAliasState(
+        aliasId: string,
+        stateMetadata: HexEncodedString,
+        transactionOptions?: TransactionOptions,
+    ): Promise<PreparedTransaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'prepareUpdateAliasState',
+                data: {
+                    aliasId,
+                    stateMetadata,
+                    options: transactionOptions,
+                },
+            },
+        );
+        const parsed = JSON.parse(
+            response,
+        ) as Response<PreparedTransactionData>;
+        return new PreparedTransaction(
+            plainToInstance(PreparedTransactionData, parsed.payload),
+            this,
+        );
+    }
+
     /**
      * Register participation events.
      *
@@ -1049,7 +1293,7 @@ export class Account {
      * Send base coins to an address.
      *
      * @param amount Amount of coins.
-     * @param address Receiving address.
+     * @param address Receiving address, or the name of a contact of the wallet.
      * @param transactionOptions The options to define a `RemainderValueStrategy`
      * or custom inputs.
      * @returns The sent transaction.
@@ -1108,6 +1352,38 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Send base coins to multiple addresses or with additional parameters and get the receipt of the transaction.
+     *
+     * @param params Addresses with amounts.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The receipt of the sent transaction, with the outputs it consumed and created and the balance changes
+     * of the addresses owning them.
+     */
+    async sendWithReceipt(
+        params: SendParams[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<TransactionReceipt> {
+        for (let i = 0; i < params.length; i++) {
+            if (typeof params[i].amount === 'bigint') {
+                params[i].amount = params[i].amount.toString(10);
+            }
+        }
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'sendWithReceipt',
+                data: {
+                    params,
+                    options: transactionOptions,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<TransactionReceipt>;
+        return parsed.payload;
+    }
+
     /**
      * Send native tokens.
      *
@@ -1211,6 +1487,53 @@ export class Account {
         });
     }
 
+    /**
+     * Set a metadata key of the account to the value.
+     *
+     * @param key The metadata key.
+     * @param value The value to set.
+     */
+    async setMetadata(key: string, value: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setMetadata',
+            data: {
+                key,
+                value,
+            },
+        });
+        this.meta.metadata[key] = value;
+    }
+
+    /**
+     * Remove a metadata key of the account.
+     *
+     * @param key The metadata key to remove.
+     */
+    async removeMetadata(key: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'removeMetadata',
+            data: {
+                key,
+            },
+        });
+        delete this.meta.metadata[key];
+    }
+
+    /**
+     * Set the policy that consolidates the basic outputs automatically while syncing.
+     * Every consolidation is reported with an `AutoConsolidationWalletEvent`.
+     *
+     * @param policy The consolidation policy, no policy stops the automatic consolidation.
+     */
+    async setConsolidationPolicy(policy?: ConsolidationPolicy): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setConsolidationPolicy',
+            data: {
+                policy,
+            },
+        });
+    }
+
     /**
      * Set the fallback SyncOptions for account syncing.
      * If storage is enabled, will persist during restarts.
@@ -1313,7 +1636,7 @@ export class Account {
             },
         );
         const payload = JSON.parse(response).payload;
-        return this.adjustBalancePayload(payload);
+        return Account.adjustBalancePayload(payload);
     }
 
     /**
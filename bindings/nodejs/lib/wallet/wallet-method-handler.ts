@@ -92,12 +92,19 @@ export class WalletMethodHandler {
      *
      * @param eventTypes The wallet event types to listen for.
      * @param callback The callback function to call when an event is received.
+     * @param accountIndexes The accounts to receive events of, all accounts if not set.
      */
     async listen(
         eventTypes: WalletEventType[],
         callback: (error: Error, event: Event) => void,
+        accountIndexes?: number[],
     ): Promise<void> {
-        return listenWalletAsync(eventTypes, callback, this.methodHandler);
+        return listenWalletAsync(
+            eventTypes,
+            callback,
+            this.methodHandler,
+            accountIndexes,
+        );
     }
 
     async destroy(): Promise<void> {
@@ -11,8 +11,10 @@ import type {
     WalletEventType,
     GenerateAddressOptions,
     SyncOptions,
+    AccountSyncResult,
     WalletEvent,
     Event,
+    Contact,
 } from '../types/wallet';
 import { IAuth, IClientOptions, LedgerNanoStatus } from '../types/client';
 import { Client } from '../client';
@@ -42,6 +44,20 @@ export class Wallet {
         });
     }
 
+    /**
+     * Backup the accounts, client options and coin type to a file encrypted with the password.
+     * The secrets of the secret manager aren't part of the backup, so the mnemonic has to be backed up separately.
+     */
+    async backupToFile(destination: string, password: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'backupToFile',
+            data: {
+                destination,
+                password,
+            },
+        });
+    }
+
     /**
      * Change the Stronghold password.
      */
@@ -159,6 +175,37 @@ export class Wallet {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Add a contact to the address book, replacing the contact with the same name.
+     */
+    async addContact(contact: Contact): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'addContact',
+            data: { contact },
+        });
+    }
+
+    /**
+     * List the contacts of the address book.
+     */
+    async listContacts(): Promise<Contact[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'listContacts',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Remove a contact from the address book.
+     */
+    async removeContact(name: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'removeContact',
+            data: { name },
+        });
+    }
+
     /**
      * Get secret manager.
      */
@@ -209,12 +256,15 @@ export class Wallet {
 
     /**
      * Listen to wallet events with a callback. An empty array will listen to all possible events.
+     *
+     * @param accountIndexes Only events of these accounts are passed to the callback, events of all accounts if not set.
      */
     async listen(
         eventTypes: WalletEventType[],
         callback: (error: Error, event: Event) => void,
+        accountIndexes?: number[],
     ): Promise<void> {
-        return this.methodHandler.listen(eventTypes, callback);
+        return this.methodHandler.listen(eventTypes, callback, accountIndexes);
     }
 
     /**
@@ -230,12 +280,15 @@ export class Wallet {
 
     /**
      * Find accounts with unspent outputs.
+     *
+     * @param addressGapLimitInternal The gap limit for internal addresses, `addressGapLimit` is used if not set.
      */
     async recoverAccounts(
         accountStartIndex: number,
         accountGapLimit: number,
         addressGapLimit: number,
         syncOptions: SyncOptions,
+        addressGapLimitInternal?: number,
     ): Promise<Account[]> {
         const response = await this.methodHandler.callMethod({
             name: 'recoverAccounts',
@@ -243,6 +296,7 @@ export class Wallet {
                 accountStartIndex,
                 accountGapLimit,
                 addressGapLimit,
+                addressGapLimitInternal,
                 syncOptions,
             },
         });
@@ -263,6 +317,19 @@ export class Wallet {
         });
     }
 
+    /**
+     * Change the alias of an account, which can't be used by another account.
+     *
+     * @param accountId The index or alias of the account.
+     * @param alias The new alias of the account.
+     */
+    async renameAccount(accountId: AccountId, alias: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'renameAccount',
+            data: { accountId, alias },
+        });
+    }
+
     /**
      * Restore a backup from a Stronghold file
      * Replaces client_options, coin_type, secret_manager and accounts. Returns an error if accounts were already created
@@ -290,6 +357,20 @@ export class Wallet {
         });
     }
 
+    /**
+     * Restore the accounts, client options and coin type of a file written with `backupToFile`.
+     * Backups of older versions are migrated. Returns an error if accounts were already created.
+     */
+    async restoreBackupFromFile(source: string, password: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'restoreBackupFromFile',
+            data: {
+                source,
+                password,
+            },
+        });
+    }
+
     /**
      * Set ClientOptions.
      */
@@ -347,6 +428,34 @@ export class Wallet {
         });
     }
 
+    /**
+     * Sync the accounts with at most `concurrency` accounts syncing at the same time. An account that failed to sync
+     * doesn't prevent the others from being synced.
+     *
+     * @param options Optional synchronization options.
+     * @param concurrency The maximum amount of accounts that sync at the same time, 8 by default.
+     * @returns The balance or the error of every synced account.
+     */
+    async syncAll(
+        options?: SyncOptions,
+        concurrency?: number,
+    ): Promise<AccountSyncResult[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'syncAll',
+            data: {
+                options,
+                concurrency,
+            },
+        });
+        const results: AccountSyncResult[] = JSON.parse(response).payload;
+        for (const result of results) {
+            if (result.balance) {
+                result.balance = Account.adjustBalancePayload(result.balance);
+            }
+        }
+        return results;
+    }
+
     /**
      * Store a mnemonic in the Stronghold snapshot.
      */
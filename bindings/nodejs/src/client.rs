@@ -1,18 +1,98 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
 
+use async_trait::async_trait;
 use iota_sdk_bindings_core::{
     call_client_method as rust_call_client_method,
-    iota_sdk::client::{mqtt::Topic, Client, ClientBuilder},
-    listen_mqtt as rust_listen_mqtt, ClientMethod, Response, Result,
+    iota_sdk::client::{
+        api::pow::{PowCancel, PowProgress, PowProvider},
+        mqtt::Topic,
+        Client, ClientBuilder,
+    },
+    listen_mqtt as rust_listen_mqtt, ClientMethod, MethodPermissions, Response, Result,
 };
 use neon::prelude::*;
-use tokio::sync::RwLock;
+use once_cell::sync::Lazy;
+use tokio::sync::{oneshot, RwLock};
 
 type JsCallback = Root<JsFunction<JsObject>>;
 
+// The PoW requests sent to the JS side, by their ID, waiting for the nonce, with the reporter of their progress
+static POW_REQUESTS: Lazy<Mutex<HashMap<u32, (oneshot::Sender<Option<u64>>, PowProgress)>>> =
+    Lazy::new(Default::default);
+static NEXT_POW_REQUEST_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Computes the nonces for local PoW with a PoW provider function of the JS side.
+///
+/// Every PoW is sent as `powRequest` event to the JS callback, which answers with `resolvePowRequest()` and can report
+/// its hash rate with `reportPowProgress()`. A `powCancelled` event is sent if the PoW got cancelled to restart it with
+/// new tips.
+struct JsPowProvider {
+    channel: Channel,
+    callback: Arc<JsCallback>,
+}
+
+impl std::fmt::Debug for JsPowProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsPowProvider").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl PowProvider for JsPowProvider {
+    async fn nonce(
+        &self,
+        block_bytes: &[u8],
+        min_pow_score: u32,
+        cancel: PowCancel,
+        progress: PowProgress,
+    ) -> iota_sdk_bindings_core::iota_sdk::client::Result<Option<u64>> {
+        let request_id = NEXT_POW_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+        let (sender, mut receiver) = oneshot::channel();
+        POW_REQUESTS.lock().unwrap().insert(request_id, (sender, progress));
+
+        let block_bytes = block_bytes.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        call_event_callback(
+            &self.channel,
+            serde_json::json!({
+                "type": "powRequest",
+                "requestId": request_id,
+                "blockBytes": format!("0x{block_bytes}"),
+                "minPowScore": min_pow_score,
+            })
+            .to_string(),
+            self.callback.clone(),
+        );
+
+        loop {
+            tokio::select! {
+                // The sender is dropped without a nonce if the request got resolved without one
+                nonce = &mut receiver => return Ok(nonce.ok().flatten()),
+                _ = tokio::time::sleep(Duration::from_millis(100)) => {
+                    if cancel.is_cancelled() {
+                        POW_REQUESTS.lock().unwrap().remove(&request_id);
+                        call_event_callback(
+                            &self.channel,
+                            serde_json::json!({ "type": "powCancelled", "requestId": request_id }).to_string(),
+                            self.callback.clone(),
+                        );
+                        return Ok(None);
+                    }
+                }
+            }
+        }
+    }
+}
+
 // Wrapper so we can destroy the ClientMethodHandler
 pub type ClientMethodHandlerWrapperInner = Arc<RwLock<Option<ClientMethodHandler>>>;
 // Wrapper because we can't impl Finalize on ClientMethodHandlerWrapperInner
@@ -20,26 +100,61 @@ pub struct ClientMethodHandlerWrapper(pub ClientMethodHandlerWrapperInner);
 pub struct ClientMethodHandler {
     channel: Channel,
     client: Client,
+    permissions: MethodPermissions,
 }
 
 impl Finalize for ClientMethodHandlerWrapper {}
 
 impl ClientMethodHandler {
-    pub fn new(channel: Channel, options: String) -> Result<Self> {
+    pub fn new(
+        channel: Channel,
+        options: String,
+        pow_provider: Option<JsCallback>,
+        pow_progress: Option<JsCallback>,
+    ) -> Result<Self> {
         let runtime = tokio::runtime::Runtime::new().expect("error initializing client");
-        let client = runtime.block_on(ClientBuilder::new().from_json(&options)?.finish())?;
+        let mut client_builder = ClientBuilder::new().from_json(&options)?;
+        if let Some(pow_provider) = pow_provider {
+            client_builder = client_builder.with_pow_provider(JsPowProvider {
+                channel: channel.clone(),
+                callback: Arc::new(pow_provider),
+            });
+        }
+        if let Some(pow_progress) = pow_progress {
+            let channel = channel.clone();
+            let callback = Arc::new(pow_progress);
+            client_builder = client_builder.with_pow_progress_callback(move |hash_rate| {
+                call_event_callback(
+                    &channel,
+                    serde_json::json!({ "type": "powProgress", "hashRate": hash_rate }).to_string(),
+                    callback.clone(),
+                )
+            });
+        }
+        let client = runtime.block_on(client_builder.finish())?;
 
-        Ok(Self { channel, client })
+        Ok(Self {
+            channel,
+            client,
+            permissions: MethodPermissions::default(),
+        })
     }
 
-    pub(crate) fn new_with_client(channel: Channel, client: Client) -> Self {
-        Self { channel, client }
+    pub(crate) fn new_with_client(channel: Channel, client: Client, permissions: MethodPermissions) -> Self {
+        Self {
+            channel,
+            client,
+            permissions,
+        }
     }
 
     async fn call_method(&self, serialized_method: String) -> (String, bool) {
         match serde_json::from_str::<ClientMethod>(&serialized_method) {
             Ok(method) => {
-                let res = rust_call_client_method(&self.client, method).await;
+                let res = match self.permissions.check(&method) {
+                    Ok(()) => rust_call_client_method(&self.client, method).await,
+                    Err(e) => Response::Error(e),
+                };
                 let mut is_err = matches!(res, Response::Error(_) | Response::Panic(_));
 
                 let msg = match serde_json::to_string(&res) {
@@ -63,8 +178,20 @@ impl ClientMethodHandler {
 pub fn create_client(mut cx: FunctionContext) -> JsResult<JsBox<ClientMethodHandlerWrapper>> {
     let options = cx.argument::<JsString>(0)?;
     let options = options.value(&mut cx);
+    let pow_provider = match cx.argument_opt(1) {
+        Some(pow_provider) if pow_provider.is_a::<JsFunction, _>(&mut cx) => {
+            Some(pow_provider.downcast_or_throw::<JsFunction, _>(&mut cx)?.root(&mut cx))
+        }
+        _ => None,
+    };
+    let pow_progress = match cx.argument_opt(2) {
+        Some(pow_progress) if pow_progress.is_a::<JsFunction, _>(&mut cx) => {
+            Some(pow_progress.downcast_or_throw::<JsFunction, _>(&mut cx)?.root(&mut cx))
+        }
+        _ => None,
+    };
     let channel = cx.channel();
-    let method_handler = ClientMethodHandler::new(channel, options)
+    let method_handler = ClientMethodHandler::new(channel, options, pow_provider, pow_progress)
         .or_else(|e| cx.throw_error(serde_json::to_string(&Response::Error(e)).expect("json to string error")))?;
     Ok(cx.boxed(ClientMethodHandlerWrapper(Arc::new(RwLock::new(Some(method_handler))))))
 }
@@ -114,6 +241,36 @@ pub fn call_client_method(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
+/// Resolves a PoW request of the [`JsPowProvider`] with the found nonce, or without one if none was found.
+pub fn resolve_pow_request(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let request_id = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let nonce = match cx.argument_opt(1) {
+        Some(nonce) if nonce.is_a::<JsString, _>(&mut cx) => {
+            let nonce = nonce.downcast_or_throw::<JsString, _>(&mut cx)?.value(&mut cx);
+            Some(nonce.parse::<u64>().or_else(|e| cx.throw_error(e.to_string()))?)
+        }
+        _ => None,
+    };
+
+    if let Some((sender, _)) = POW_REQUESTS.lock().unwrap().remove(&request_id) {
+        sender.send(nonce).ok();
+    }
+
+    Ok(cx.undefined())
+}
+
+/// Reports the hash rate of a running PoW request of the [`JsPowProvider`] to the PoW progress callback of the client.
+pub fn report_pow_progress(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let request_id = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let hash_rate = cx.argument::<JsNumber>(1)?.value(&mut cx);
+
+    if let Some((_, progress)) = POW_REQUESTS.lock().unwrap().get(&request_id) {
+        progress.report(hash_rate);
+    }
+
+    Ok(cx.undefined())
+}
+
 // MQTT
 pub fn listen_mqtt(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let js_arr_handle: Handle<JsArray> = cx.argument(0)?;
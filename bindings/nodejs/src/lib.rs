@@ -47,6 +47,8 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("callClientMethod", client::call_client_method)?;
     cx.export_function("createClient", client::create_client)?;
     cx.export_function("destroyClient", client::destroy_client)?;
+    cx.export_function("resolvePowRequest", client::resolve_pow_request)?;
+    cx.export_function("reportPowProgress", client::report_pow_progress)?;
 
     // MQTT
     cx.export_function("listenMqtt", client::listen_mqtt)?;
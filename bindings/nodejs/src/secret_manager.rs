@@ -9,7 +9,7 @@ use iota_sdk_bindings_core::{
         secret::{SecretManager, SecretManagerDto},
         stronghold::StrongholdAdapter,
     },
-    Response, Result, SecretManagerMethod,
+    MethodPermissions, Response, Result, SecretManagerMethod,
 };
 use neon::prelude::*;
 use tokio::sync::RwLock;
@@ -17,6 +17,7 @@ use tokio::sync::RwLock;
 pub struct SecretManagerMethodHandler {
     channel: Channel,
     secret_manager: Arc<RwLock<SecretManager>>,
+    permissions: MethodPermissions,
 }
 
 impl Finalize for SecretManagerMethodHandler {}
@@ -29,20 +30,29 @@ impl SecretManagerMethodHandler {
         Ok(Arc::new(Self {
             channel,
             secret_manager: Arc::new(RwLock::new(secret_manager)),
+            permissions: MethodPermissions::default(),
         }))
     }
 
-    pub fn new_with_secret_manager(channel: Channel, secret_manager: Arc<RwLock<SecretManager>>) -> Arc<Self> {
+    pub fn new_with_secret_manager(
+        channel: Channel,
+        secret_manager: Arc<RwLock<SecretManager>>,
+        permissions: MethodPermissions,
+    ) -> Arc<Self> {
         Arc::new(Self {
             channel,
             secret_manager,
+            permissions,
         })
     }
 
     async fn call_method(&self, method: String) -> (String, bool) {
         match serde_json::from_str::<SecretManagerMethod>(&method) {
             Ok(method) => {
-                let res = rust_call_secret_manager_method(&self.secret_manager, method).await;
+                let res = match self.permissions.check(&method) {
+                    Ok(()) => rust_call_secret_manager_method(&self.secret_manager, method).await,
+                    Err(e) => Response::Error(e),
+                };
                 let mut is_err = matches!(res, Response::Error(_) | Response::Panic(_));
 
                 let msg = match serde_json::to_string(&res) {
@@ -4,13 +4,13 @@
 use std::sync::Arc;
 
 use iota_sdk_bindings_core::{
-    call_wallet_method as rust_call_wallet_method,
+    call_wallet_method_with_permissions as rust_call_wallet_method,
     iota_sdk::wallet::{
         events::types::{Event, WalletEventType},
         migration::migrate_db_chrysalis_to_stardust as rust_migrate_db_chrysalis_to_stardust,
         Wallet,
     },
-    Response, Result, WalletMethod, WalletOptions,
+    MethodPermissions, Response, Result, WalletMethod, WalletOptions,
 };
 use neon::prelude::*;
 use tokio::sync::RwLock;
@@ -29,6 +29,7 @@ impl Finalize for WalletMethodHandlerWrapper {}
 pub struct WalletMethodHandler {
     channel: Channel,
     wallet: Wallet,
+    permissions: MethodPermissions,
 }
 
 type JsCallback = Root<JsFunction<JsObject>>;
@@ -36,16 +37,21 @@ type JsCallback = Root<JsFunction<JsObject>>;
 impl WalletMethodHandler {
     fn new(channel: Channel, options: String) -> Result<Self> {
         let wallet_options = serde_json::from_str::<WalletOptions>(&options)?;
+        let permissions = wallet_options.permissions.clone();
 
         let wallet = crate::RUNTIME.block_on(async move { wallet_options.build().await })?;
 
-        Ok(Self { channel, wallet })
+        Ok(Self {
+            channel,
+            wallet,
+            permissions,
+        })
     }
 
     async fn call_method(&self, method: String) -> (String, bool) {
         match serde_json::from_str::<WalletMethod>(&method) {
             Ok(method) => {
-                let res = rust_call_wallet_method(&self.wallet, method).await;
+                let res = rust_call_wallet_method(&self.wallet, method, &self.permissions).await;
                 let mut is_err = matches!(res, Response::Error(_) | Response::Panic(_));
 
                 let msg = match serde_json::to_string(&res) {
@@ -145,12 +151,24 @@ pub fn listen_wallet(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let callback = Arc::new(cx.argument::<JsFunction>(1)?.root(&mut cx));
     let method_handler = Arc::clone(&cx.argument::<JsBox<WalletMethodHandlerWrapper>>(2)?.0);
 
+    // Events of other accounts are filtered out before they're serialized for the callback
+    let mut account_indexes = Vec::new();
+    if let Some(js_arr_handle) = cx.argument_opt(3) {
+        if !js_arr_handle.is_a::<JsUndefined, _>(&mut cx) {
+            let js_arr_handle = js_arr_handle.downcast_or_throw::<JsArray, FunctionContext>(&mut cx)?;
+            for account_index in js_arr_handle.to_vec(&mut cx)? {
+                let account_index = account_index.downcast_or_throw::<JsNumber, FunctionContext>(&mut cx)?;
+                account_indexes.push(account_index.value(&mut cx) as u32);
+            }
+        }
+    }
+
     crate::RUNTIME.spawn(async move {
         if let Some(method_handler) = &*method_handler.read().await {
             let channel = method_handler.channel.clone();
             method_handler
                 .wallet
-                .listen(event_types, move |event_data| {
+                .listen_to_accounts(account_indexes, event_types, move |event_data| {
                     call_event_callback(&channel, event_data.clone(), callback.clone())
                 })
                 .await;
@@ -180,8 +198,11 @@ pub fn get_client(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let (deferred, promise) = cx.promise();
     crate::RUNTIME.spawn(async move {
         if let Some(method_handler) = &*method_handler.read().await {
-            let client_method_handler =
-                ClientMethodHandler::new_with_client(channel.clone(), method_handler.wallet.client().clone());
+            let client_method_handler = ClientMethodHandler::new_with_client(
+                channel.clone(),
+                method_handler.wallet.client().clone(),
+                method_handler.permissions.clone(),
+            );
             deferred.settle_with(&channel, move |mut cx| {
                 Ok(cx.boxed(ClientMethodHandlerWrapper(Arc::new(RwLock::new(Some(
                     client_method_handler,
@@ -210,6 +231,7 @@ pub fn get_secret_manager(mut cx: FunctionContext) -> JsResult<JsPromise> {
             let secret_manager_method_handler = SecretManagerMethodHandler::new_with_secret_manager(
                 channel.clone(),
                 method_handler.wallet.get_secret_manager().clone(),
+                method_handler.permissions.clone(),
             );
             deferred.settle_with(&channel, move |mut cx| Ok(cx.boxed(secret_manager_method_handler)));
         } else {
@@ -0,0 +1,15 @@
+import asyncio
+
+from iota_sdk import AsyncClient
+
+
+async def main():
+    # Create an AsyncClient instance
+    client = AsyncClient(nodes=['https://api.testnet.shimmer.network'])
+
+    # Request the node info and the tips concurrently
+    node_info, tips = await asyncio.gather(client.get_info(), client.get_tips())
+    print(f'{node_info}')
+    print(f'Tips: {tips}')
+
+asyncio.run(main())
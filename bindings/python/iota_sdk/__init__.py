@@ -2,7 +2,9 @@
 # SPDX-License-Identifier: Apache-2.0
 
 from .iota_sdk import *
+from .error import ErrorCode, IotaSdkError
 from .client.client import Client, NodeIndexerAPI, ClientError
+from .client.async_client import AsyncClient
 from .client._high_level_api import GenerateAddressesOptions, GenerateAddressOptions
 from .utils import Utils
 from .wallet.wallet import Wallet, Account
@@ -30,6 +32,7 @@ from .types.output_id import *
 from .types.output_params import *
 from .types.payload import *
 from .types.send_params import *
+from .types.stronghold import *
 from .types.token_scheme import *
 from .types.transaction import *
 from .types.transaction_data import *
@@ -38,3 +41,4 @@ from .types.unlock import *
 from .types.unlock_condition import *
 from .types.utxo_changes import *
 from .types.consolidation_params import *
+from .types.method_permissions import *
@@ -4,6 +4,7 @@
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager
 from iota_sdk.types.block import Block
 from iota_sdk.types.common import HexStr
+from iota_sdk.types.native_token import NativeTokenMetadata
 from iota_sdk.types.output import OutputWithMetadata
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.common import CoinType
@@ -186,6 +187,21 @@ class HighLevelAPI():
             'generateAddressesOptions': generate_addresses_options.as_dict(),
         })
 
+    def get_native_token_metadata(
+            self, token_id: HexStr) -> NativeTokenMetadata:
+        """Resolve the foundry of a native token and decode its IRC-30 metadata.
+
+        Args:
+            token_id: The ID of the native token.
+
+        Returns:
+            The metadata of the native token.
+        """
+        metadata = self._call_method('getNativeTokenMetadata', {
+            'tokenId': token_id
+        })
+        return from_dict(NativeTokenMetadata, metadata)
+
     def find_inputs(self, addresses: List[str], amount: int):
         """Function to find inputs from addresses for a provided amount(useful for offline signing).
 
@@ -97,6 +97,16 @@ class NodeCoreAPI():
             'blockBytes': block_bytes
         })
 
+    def post_block_bytes(self, block_bytes: List[int]) -> HexStr:
+        """Post already serialized block bytes as they are, without validating them and without local PoW fallback.
+
+        Returns:
+            The corresponding block id of the block.
+        """
+        return self._call_method('postBlockBytes', {
+            'blockBytes': block_bytes
+        })
+
     def get_output(
             self, output_id: Union[OutputId, HexStr]) -> OutputWithMetadata:
         """Get the output corresponding to the given output id.
@@ -0,0 +1,170 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from iota_sdk import call_client_method_async
+from iota_sdk.client.client import Client, ClientError
+from iota_sdk.client._node_indexer_api import NodeIndexerAPI
+from iota_sdk.types.block import Block, BlockMetadata
+from iota_sdk.types.common import HexStr
+from iota_sdk.types.node_info import NodeInfo, NodeInfoWrapper
+from iota_sdk.types.output import OutputWithMetadata, OutputMetadata
+from iota_sdk.types.output_id import OutputId
+from json import dumps, loads
+from typing import List, Optional, Union
+from dacite import from_dict
+
+
+class AsyncClient():
+    """An IOTA client whose node calls are coroutines, to be awaited in an asyncio event loop. The requests are sent
+    by the Rust runtime without blocking the event loop, so many of them can run concurrently without a thread pool.
+
+    Methods that aren't available here can be called with `call_method()`, or on the synchronous `client`.
+
+    Attributes:
+        client: The synchronous client sharing the same inner client.
+    """
+
+    def __init__(self, client: Optional[Client] = None, **kwargs):
+        """Initialize the asynchronous IOTA Client.
+
+        **Arguments**
+        client :
+            An existing client to share, otherwise a new one is created with the keyword arguments of `Client`.
+        """
+        self.client = client if client is not None else Client(**kwargs)
+
+    async def call_method(self, name, data=None):
+        """Dumps json string and awaits `call_client_method_async()`
+        """
+        message = {
+            'name': name
+        }
+        if data:
+            message['data'] = data
+        message = dumps(message)
+
+        # Send message to the Rust library
+        response = await call_client_method_async(self.client.handle, message)
+
+        json_response = loads(response)
+
+        if "type" in json_response:
+            if json_response["type"] == "error":
+                raise ClientError(json_response['payload'])
+
+        if "payload" in json_response:
+            return json_response['payload']
+        else:
+            return response
+
+    async def get_health(self, url: str):
+        """ Get node health.
+
+        Args:
+            url: The node's url.
+        """
+        return await self.call_method('getHealth', {
+            'url': url
+        })
+
+    async def get_node_info(self, url: str, auth=None) -> NodeInfo:
+        """Get node info.
+
+        Args:
+            url: The node's url.
+            auth: A JWT or username/password authentication object.
+        """
+        return from_dict(NodeInfo, await self.call_method('getNodeInfo', {
+            'url': url,
+            'auth': auth
+        }))
+
+    async def get_info(self) -> NodeInfoWrapper:
+        """Return node information together with the url of the used node.
+        """
+        return from_dict(NodeInfoWrapper, await self.call_method('getInfo'))
+
+    async def get_tips(self) -> List[HexStr]:
+        """Request tips from the node.
+        """
+        return await self.call_method('getTips')
+
+    async def post_block(self, block: Block) -> HexStr:
+        """Post a block.
+
+        Args:
+            block: The block to post.
+
+        Returns:
+            The block id of the posted block.
+        """
+        return await self.call_method('postBlock', {
+            'block': block.__dict__
+        })
+
+    async def get_block_data(self, block_id: HexStr) -> Block:
+        """Get the block corresponding to the given block id.
+        """
+        return Block.from_dict(await self.call_method('getBlock', {
+            'blockId': block_id
+        }))
+
+    async def get_block_metadata(self, block_id: HexStr) -> BlockMetadata:
+        """Get the block metadata corresponding to the given block id.
+        """
+        return BlockMetadata.from_dict(await self.call_method('getBlockMetadata', {
+            'blockId': block_id
+        }))
+
+    async def get_output(
+            self, output_id: Union[OutputId, HexStr]) -> OutputWithMetadata:
+        """Get the output corresponding to the given output id.
+
+        Returns:
+            The output itself with its metadata.
+        """
+        output_id_str = output_id.output_id if isinstance(
+            output_id, OutputId) else output_id
+        return from_dict(OutputWithMetadata, await self.call_method('getOutput', {
+            'outputId': output_id_str
+        }))
+
+    async def get_output_metadata(
+            self, output_id: Union[OutputId, HexStr]) -> OutputMetadata:
+        """Get the output metadata corresponding to the given output id.
+
+        Returns:
+            The output metadata.
+        """
+        output_id_str = output_id.output_id if isinstance(
+            output_id, OutputId) else output_id
+        return from_dict(OutputMetadata, await self.call_method('getOutputMetadata', {
+            'outputId': output_id_str
+        }))
+
+    async def get_outputs(
+            self, output_ids: List[OutputId]) -> List[OutputWithMetadata]:
+        """Fetch OutputWithMetadata from provided OutputIds (requests are sent in parallel).
+
+        Args:
+            output_ids: A list of output ids.
+
+        Returns:
+            A list of corresponding `OutputWithMetadata` objects.
+        """
+        outputs = await self.call_method('getOutputs', {
+            'outputIds': list(map(lambda o: o.output_id, output_ids))
+        })
+        return [from_dict(OutputWithMetadata, o) for o in outputs]
+
+    async def basic_output_ids(
+            self, query_parameters: NodeIndexerAPI.QueryParameters) -> NodeIndexerAPI.OutputIdsResponse:
+        """Fetch basic output IDs from the given query parameters.
+
+        Returns:
+            The corresponding output IDs of the basic outputs.
+        """
+        response = await self.call_method('basicOutputIds', {
+            'queryParameters': query_parameters.as_dict(),
+        })
+        return NodeIndexerAPI.OutputIdsResponse(response)
@@ -3,21 +3,24 @@
 
 import iota_sdk
 from iota_sdk import call_client_method, listen_mqtt
+from iota_sdk.error import IotaSdkError
 from iota_sdk.client._node_core_api import NodeCoreAPI
 from iota_sdk.client._node_indexer_api import NodeIndexerAPI
 from iota_sdk.client._high_level_api import HighLevelAPI
 from iota_sdk.client._utils import ClientUtils
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager
 from iota_sdk.types.block import Block
+from iota_sdk.types.burn import Burn
+from iota_sdk.types.client_options import ConnectionPoolOptions, RetryPolicy
 from iota_sdk.types.common import HexStr, Node, AddressAndAmount
 from iota_sdk.types.feature import Feature
 from iota_sdk.types.native_token import NativeToken
-from iota_sdk.types.network_info import NetworkInfo
+from iota_sdk.types.network_info import NetworkInfo, NetworkProfile
 from iota_sdk.types.output import AliasOutput, BasicOutput, FoundryOutput, NftOutput, output_from_dict
 from iota_sdk.types.payload import Payload, TransactionPayload
 from iota_sdk.types.token_scheme import SimpleTokenScheme
 from iota_sdk.types.unlock_condition import UnlockCondition
-from iota_sdk.types.transaction_data import PreparedTransactionData
+from iota_sdk.types.transaction_data import DryRunTransaction, PreparedTransactionData
 from json import dumps, loads
 import humps
 from datetime import timedelta
@@ -25,7 +28,7 @@ from typing import Any, Dict, List, Optional, Union
 from dacite import from_dict
 
 
-class ClientError(Exception):
+class ClientError(IotaSdkError):
     """Represents a client error."""
     pass
 
@@ -47,11 +50,19 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         api_timeout: Optional[timedelta] = None,
         node_sync_interval: Optional[timedelta] = None,
         remote_pow_timeout: Optional[timedelta] = None,
+        block_idempotency_ttl: Optional[timedelta] = None,
         tips_interval: Optional[int] = None,
         quorum: Optional[bool] = None,
         min_quorum_size: Optional[int] = None,
         quorum_threshold: Optional[int] = None,
+        broadcast_node_count: Optional[int] = None,
         user_agent: Optional[str] = None,
+        identification_headers: Optional[bool] = None,
+        retry_policy: Optional[RetryPolicy] = None,
+        connection_pool: Optional[ConnectionPoolOptions] = None,
+        proxy: Optional[str] = None,
+        network_profile: Optional[NetworkProfile] = None,
+        validate_responses: Optional[bool] = None,
         local_pow: Optional[bool] = None,
         fallback_to_local_pow: Optional[bool] = None,
         pow_worker_count: Optional[int] = None,
@@ -76,6 +87,8 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             Interval in which nodes will be checked for their sync status and the [NetworkInfo](crate::NetworkInfo) gets updated.
         remote_pow_timeout :
             Timeout when sending a block that requires remote proof of work.
+        block_idempotency_ttl :
+            Time in which posting a block for the same transaction again returns the block that was already posted, zero disables it.
         tips_interval :
             Tips request interval during PoW in seconds.
         quorum :
@@ -84,8 +97,22 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             Minimum amount of nodes required for request when quorum is enabled.
         quorum_threshold :
             % of nodes that have to return the same response so it gets accepted.
+        broadcast_node_count :
+            Amount of nodes a block is posted to at the same time if local PoW is used.
         user_agent :
-            The User-Agent header for requests.
+            The User-Agent header for requests, no User-Agent header is sent if empty.
+        identification_headers :
+            If the client name and version headers should be sent with every request.
+        retry_policy :
+            How failed requests to nodes are retried, disabled by default.
+        connection_pool :
+            How connections to nodes are kept open and reused.
+        proxy :
+            HTTP or SOCKS5 proxy all requests to nodes are sent through, e.g. `socks5h://127.0.0.1:9050` for Tor.
+        network_profile :
+            The network the client is used for. Its public nodes are used if no node is set, nodes and addresses of other networks are rejected.
+        validate_responses :
+            Whether the blocks and outputs returned by nodes are validated locally, to protect against buggy or malicious nodes.
         local_pow :
             Local proof of work.
         fallback_to_local_pow :
@@ -129,8 +156,17 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         if 'remote_pow_timeout' in client_config:
             client_config['remote_pow_timeout'] = {'secs': int(client_config['remote_pow_timeout'].total_seconds(
             )), 'nanos': get_remaining_nano_seconds(client_config['remote_pow_timeout'])}
+        if 'block_idempotency_ttl' in client_config:
+            client_config['block_idempotency_ttl'] = {'secs': int(client_config['block_idempotency_ttl'].total_seconds(
+            )), 'nanos': get_remaining_nano_seconds(client_config['block_idempotency_ttl'])}
 
         client_config = humps.camelize(client_config)
+
+        # Added after camelizing, so the paths of the route overrides aren't changed
+        if 'retryPolicy' in client_config:
+            client_config['retryPolicy'] = client_config['retryPolicy'].as_dict()
+        if 'connectionPool' in client_config:
+            client_config['connectionPool'] = client_config['connectionPool'].as_dict()
         client_config_str = dumps(client_config)
 
         # Create the message handler
@@ -369,8 +405,11 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
                              input_range_start: Optional[int] = None,
                              input_range_end: Optional[int] = None,
                              inputs: Optional[List[Dict[str, Any]]] = None,
+                             required_inputs: Optional[List[HexStr]] = None,
+                             forbidden_inputs: Optional[List[HexStr]] = None,
                              output: Optional[AddressAndAmount] = None,
                              outputs: Optional[List[Any]] = None,
+                             burn: Optional[Burn] = None,
                              tag: Optional[HexStr] = None) -> List[Union[HexStr, Block]]:
         """Build and post a block.
 
@@ -383,8 +422,11 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         input_range_start : Start of the input range.
         input_range_end : End of the input range.
         inputs : Inputs to use.
+        required_inputs : Output IDs that must be used as inputs, additional inputs are selected if needed.
+        forbidden_inputs : Output IDs that must not be used as inputs.
         output : Address and amount to send to.
         outputs : Outputs to use.
+        burn : Aliases, NFTs, foundries and native tokens to burn explicitly.
         tag : Hex encoded tag.
 
         Returns:
@@ -404,6 +446,9 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         if 'outputs' in options:
             options['outputs'] = [v.as_dict() for v in options['outputs']]
 
+        if 'burn' in options:
+            options['burn'] = options.pop('burn').as_dict()
+
         if 'coin_type' in options:
             options['coin_type'] = int(options.pop('coin_type'))
 
@@ -435,6 +480,17 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         """
         return from_dict(NetworkInfo, self._call_method('getNetworkInfo'))
 
+    def export_config(self) -> Dict[str, Any]:
+        """Exports the configuration of the client, which can be stored and passed to `Client.from_config()`.
+        """
+        return self._call_method('exportConfig')
+
+    @classmethod
+    def from_config(cls, config: Dict[str, Any]):
+        """Creates a client from a configuration exported with `export_config()`.
+        """
+        return cls(client_handle=iota_sdk.create_client(dumps(config)))
+
     def get_network_id(self) -> int:
         """Gets the network id of the node we're connecting to.
         """
@@ -470,6 +526,11 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         """
         return self._call_method('unhealthyNodes')
 
+    def cancel_pow(self):
+        """Stops the running local proof of work, the blocks fail with a PoW cancelled error.
+        """
+        return self._call_method('cancelPow')
+
     def prepare_transaction(self,
                             secret_manager: Optional[Union[LedgerNanoSecretManager, MnemonicSecretManager,
                                                      SeedSecretManager, StrongholdSecretManager]] = None,
@@ -485,6 +546,21 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             'options': options
         }))
 
+    def dry_run_transaction(self,
+                            secret_manager: Optional[Union[LedgerNanoSecretManager, MnemonicSecretManager,
+                                                     SeedSecretManager, StrongholdSecretManager]] = None,
+                            options=None) -> DryRunTransaction:
+        """Build a transaction with input selection and validation, without signing it, doing PoW or posting a block.
+
+        Args:
+            secret_manager: One of the supported secret managers.
+            options: the transaction options.
+        """
+        return from_dict(DryRunTransaction, self._call_method('dryRunTransaction', {
+            'secretManager': secret_manager,
+            'options': options
+        }))
+
     def sign_transaction(self, secret_manager: Union[LedgerNanoSecretManager, MnemonicSecretManager,
                                                      SeedSecretManager, StrongholdSecretManager], prepared_transaction_data: PreparedTransactionData) -> TransactionPayload:
         """Sign a transaction.
@@ -0,0 +1,59 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from enum import Enum
+from typing import Any, Dict, Optional
+
+
+class ErrorCode(str, Enum):
+    """Stable code of an error, to distinguish errors without parsing their messages.
+
+    Attributes:
+        InvalidParameter: The parameters of the method are invalid or missing.
+        NotFound: A requested item, like an account, a transaction or an output, doesn't exist.
+        InsufficientFunds: The available funds aren't enough for the transaction.
+        NodeUnreachable: No healthy node could be reached.
+        NodeError: A node answered the request with an error.
+        SecretManager: The secret manager failed, e.g. a locked Stronghold or a rejected Ledger Nano prompt.
+        Storage: Reading or writing the wallet storage failed.
+        MethodNotPermitted: The method is not permitted by the method handler.
+        Internal: Any other error.
+    """
+    InvalidParameter = 'invalidParameter'
+    NotFound = 'notFound'
+    InsufficientFunds = 'insufficientFunds'
+    NodeUnreachable = 'nodeUnreachable'
+    NodeError = 'nodeError'
+    SecretManager = 'secretManager'
+    Storage = 'storage'
+    MethodNotPermitted = 'methodNotPermitted'
+    Internal = 'internal'
+
+
+class IotaSdkError(Exception):
+    """Base class of the errors returned by the Rust library.
+
+    Attributes:
+        type: The category of the error, e.g. `client` or `wallet`.
+        code: Stable code of the error.
+        kind: The detailed kind of the error, made of the category and the kind of the innermost error, e.g. `node.responseError`.
+        message: The error message.
+        data: Machine readable data of the error, like amounts, output IDs or node URLs.
+    """
+
+    def __init__(self, payload: Any):
+        super().__init__(payload)
+        self.type: Optional[str] = None
+        self.code: Optional[ErrorCode] = None
+        self.kind: Optional[str] = None
+        self.message: str = str(payload)
+        self.data: Optional[Dict[str, Any]] = None
+        # Panics only have a message
+        if isinstance(payload, dict):
+            self.type = payload.get('type')
+            code = payload.get('code')
+            # Unknown codes of newer versions are kept as None
+            self.code = ErrorCode(code) if code in {c.value for c in ErrorCode} else None
+            self.kind = payload.get('kind')
+            self.message = payload.get('message', payload.get('error', self.message))
+            self.data = payload.get('data')
@@ -2,6 +2,7 @@
 # SPDX-License-Identifier: Apache-2.0
 
 from iota_sdk import create_secret_manager, call_secret_manager_method
+from iota_sdk.error import IotaSdkError
 from iota_sdk.types.common import HexStr
 from iota_sdk.types.signature import Ed25519Signature, Bip44
 from iota_sdk.types.transaction_data import PreparedTransactionData
@@ -16,14 +17,20 @@ class LedgerNanoSecretManager(dict):
     """Secret manager that uses a Ledger Nano hardware wallet or a Speculos simulator.
     """
 
-    def __init__(self, is_simulator):
+    def __init__(self, is_simulator, allow_blind_signing=False, cache_addresses=False):
         """Initialize a Ledger Nano secret manager.
 
         Args:
             is_simulator: Whether this is a simulated Ledger Nano device.
+            allow_blind_signing: Whether transactions that can't be shown on the device may be signed blindly.
+            cache_addresses: Whether addresses are cached per account, so they can be generated while the device is disconnected.
         """
 
-        dict.__init__(self, ledgerNano=is_simulator)
+        dict.__init__(self, ledgerNano={
+            'isSimulator': is_simulator,
+            'allowBlindSigning': allow_blind_signing,
+            'cacheAddresses': cache_addresses,
+        })
 
 
 class MnemonicSecretManager(dict):
@@ -75,7 +82,7 @@ class StrongholdSecretManager(dict):
             dict.__init__(self, password=password, snapshotPath=snapshot_path)
 
 
-class SecretManagerError(Exception):
+class SecretManagerError(IotaSdkError):
     """Secret manager error.
     """
     pass
@@ -223,6 +230,18 @@ class SecretManager():
         """
         return self._call_method('getLedgerNanoStatus')
 
+    def change_stronghold_password(self, current_password: str, new_password: str):
+        """Change the Stronghold password and re-encrypt the snapshot file with it.
+
+        Args:
+            current_password: The current password.
+            new_password: The new password.
+        """
+        return self._call_method('changeStrongholdPassword', {
+            'currentPassword': current_password,
+            'newPassword': new_password
+        })
+
     def store_mnemonic(self, mnemonic: str):
         """Store a mnemonic.
 
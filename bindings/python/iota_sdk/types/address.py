@@ -4,6 +4,7 @@
 from iota_sdk.types.common import HexStr
 from dataclasses import dataclass, field
 from enum import IntEnum
+from typing import Optional
 
 
 class AddressType(IntEnum):
@@ -83,3 +84,20 @@ class AddressWithUnspentOutputs():
     keyIndex: int
     internal: bool
     outputIds: bool
+
+
+@dataclass
+class Contact():
+    """A named address of the address book of the wallet.
+
+    Attributes:
+        name: The name of the contact, unique in the address book.
+        address: The bech32 encoded address of the contact.
+        note: An optional note about the contact.
+    """
+    name: str
+    address: str
+    note: Optional[str] = None
+
+    def as_dict(self):
+        return {k: v for k, v in self.__dict__.items() if v is not None}
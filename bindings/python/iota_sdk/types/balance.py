@@ -3,7 +3,7 @@
 
 from __future__ import annotations
 from dataclasses import dataclass
-from typing import List, Optional
+from typing import Any, Dict, List, Optional
 from iota_sdk.types.common import HexStr
 
 
@@ -81,3 +81,17 @@ class Balance:
                                   for nt in config['nativeTokens']]
 
         return config
+
+
+@dataclass
+class AccountSyncResult:
+    """The result of syncing an account with `Wallet::sync_all()`.
+
+    Attributes:
+        accountIndex: The index of the account.
+        balance: The balance of the account, if it synced.
+        error: The error, if the account failed to sync.
+    """
+    accountIndex: int
+    balance: Optional[Balance] = None
+    error: Optional[Dict[str, Any]] = None
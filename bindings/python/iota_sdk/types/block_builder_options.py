@@ -22,6 +22,8 @@ class BlockBuilderOptions:
     initialAddressIndex: Optional[int] = None
     inputs: Optional[List[UtxoInput]] = None
     inputRange: Optional[Range] = None
+    requiredInputs: Optional[List[HexStr]] = None
+    forbiddenInputs: Optional[List[HexStr]] = None
     output: Optional[AddressAndAmount] = None
     outputHex: Optional[List[Dict[str, Any]]] = None
     outputs: Optional[List[Output]] = None
@@ -30,3 +32,4 @@ class BlockBuilderOptions:
     data: Optional[HexStr] = None
     parents: Optional[List[HexStr]] = None
     burn: Optional[Burn] = None
+    dryRun: Optional[bool] = None
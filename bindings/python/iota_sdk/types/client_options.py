@@ -3,7 +3,7 @@
 
 from __future__ import annotations
 from dataclasses import dataclass
-from typing import List, Optional
+from typing import Dict, List, Optional
 from iota_sdk.types.node_info import NodeInfoProtocol
 
 
@@ -15,6 +15,23 @@ class Duration:
     nanos: int
 
 
+@dataclass
+class MqttBrokerTlsOptions:
+    """The TLS options used to connect to a MQTT broker.
+
+        Attributes:
+        caCertificates (str):
+            The PEM encoded certificate authorities the broker certificate is verified against.
+        clientCertificate (str):
+            The PEM encoded client certificate, for brokers requiring client authentication.
+        clientKey (str):
+            The PEM encoded private key of the client certificate.
+    """
+    caCertificates: str
+    clientCertificate: Optional[str] = None
+    clientKey: Optional[str] = None
+
+
 @dataclass
 class MqttBrokerOptions:
     """Options for the MQTT broker.
@@ -30,15 +47,100 @@ class MqttBrokerOptions:
             Sets the port used for the MQTT operations.
         maxReconnectionAttempts (int):
             Sets the maximum number of reconnection attempts. 0 is unlimited.
+        wsPath (str):
+            Sets the path of the MQTT over WebSocket endpoint, `/api/mqtt/v1` by default.
+        wsPort (int):
+            Sets the port of the MQTT over WebSocket endpoint. Defaults to the port of the node URL.
+        useTls (bool):
+            Sets whether TLS should be used. Defaults to using it for `https` node URLs.
+        tls (MqttBrokerTlsOptions):
+            Sets the TLS options, to use custom certificate authorities or client authentication.
+        reconnectionDelay (Duration):
+            Sets the delay before the first reconnection attempt, doubled with every failed attempt. 1 second by default.
+        maxReconnectionDelay (Duration):
+            Sets the maximum delay between reconnection attempts. 60 seconds by default.
     """
     automaticDisconnect: Optional[bool] = None
     timeout: Optional[Duration] = None
     useWs: Optional[bool] = None
     port: Optional[int] = None
     maxReconnectionAttempts: Optional[int] = None
+    wsPath: Optional[str] = None
+    wsPort: Optional[int] = None
+    useTls: Optional[bool] = None
+    tls: Optional[MqttBrokerTlsOptions] = None
+    reconnectionDelay: Optional[Duration] = None
+    maxReconnectionDelay: Optional[Duration] = None
+
+    def as_dict(self):
+        config = {k: v for k, v in self.__dict__.items() if v is not None}
+        if 'tls' in config:
+            config['tls'] = {k: v for k, v in config['tls'].__dict__.items() if v is not None}
+        return config
+
+
+@dataclass
+class RetryPolicy:
+    """How often and when a failed request to a node is repeated, with an exponential backoff.
+
+        Attributes:
+        maxRetries (int):
+            The amount of times a failed request is repeated, 0 disables retries.
+        initialBackoff (Duration):
+            The time to wait before the first retry, 500 milliseconds by default.
+        maxBackoff (Duration):
+            The longest time to wait between retries, 10 seconds by default.
+        retryOnStatus (List[int]):
+            The HTTP status codes of responses that are retried, 429, 502, 503 and 504 by default.
+        retryOnConnectionError (bool):
+            Whether requests that failed to connect to the node or timed out are retried, true by default.
+        routeOverrides (Dict[str, RetryPolicy]):
+            Policies for routes starting with the given path, e.g. `api/core/v2/blocks`.
+    """
+    maxRetries: Optional[int] = None
+    initialBackoff: Optional[Duration] = None
+    maxBackoff: Optional[Duration] = None
+    retryOnStatus: Optional[List[int]] = None
+    retryOnConnectionError: Optional[bool] = None
+    routeOverrides: Optional[Dict[str, RetryPolicy]] = None
+
+    def as_dict(self):
+        config = {k: v for k, v in self.__dict__.items() if v is not None}
+        for key in ['initialBackoff', 'maxBackoff']:
+            if key in config:
+                config[key] = config[key].__dict__
+        if 'routeOverrides' in config:
+            config['routeOverrides'] = {
+                path: policy.as_dict() for path,
+                policy in config['routeOverrides'].items()}
+        return config
+
+
+@dataclass
+class ConnectionPoolOptions:
+    """How connections to nodes are kept open and reused.
+
+        Attributes:
+        maxIdlePerHost (int):
+            The maximum amount of idle connections that are kept open per node, 32 by default.
+        idleTimeout (Duration):
+            How long an idle connection is kept open, 90 seconds by default.
+        tcpKeepalive (Duration):
+            The interval of TCP keep-alive probes, 60 seconds by default.
+        http2KeepAliveInterval (Duration):
+            The interval of HTTP/2 pings that keep idle connections alive, 30 seconds by default.
+    """
+    maxIdlePerHost: Optional[int] = None
+    idleTimeout: Optional[Duration] = None
+    tcpKeepalive: Optional[Duration] = None
+    http2KeepAliveInterval: Optional[Duration] = None
 
     def as_dict(self):
-        return {k: v for k, v in self.__dict__.items() if v is not None}
+        config = {k: v for k, v in self.__dict__.items() if v is not None}
+        for key in ['idleTimeout', 'tcpKeepalive', 'http2KeepAliveInterval']:
+            if key in config:
+                config[key] = config[key].__dict__
+        return config
 
 
 @dataclass
@@ -66,8 +168,16 @@ class ClientOptions:
             Minimum amount of nodes required for request when quorum is enabled.
         quorumThreshold (int):
             % of nodes that have to return the same response so it gets accepted.
+        broadcastNodeCount (int):
+            Amount of nodes a block is posted to at the same time if local PoW is used.
         userAgent (str):
             The User-Agent header for requests.
+        retryPolicy (RetryPolicy):
+            How failed requests to nodes are retried, disabled by default.
+        connectionPool (ConnectionPoolOptions):
+            How connections to nodes are kept open and reused.
+        proxy (str):
+            HTTP or SOCKS5 proxy all requests to nodes are sent through, e.g. `socks5h://127.0.0.1:9050` for Tor.
         brokerOptions (MqttBrokerOptions):
             Options for the MQTT broker.
         protocolParameters (NodeInfoProtocol):
@@ -82,6 +192,8 @@ class ClientOptions:
             Timeout for API requests.
         remotePowTimeout (Duration):
             Timeout when sending a block that requires remote proof of work.
+        blockIdempotencyTtl (Duration):
+            Time in which posting a block for the same transaction again returns the block that was already posted, zero disables it.
         powWorkerCount (int):
             The amount of threads to be used for proof of work.
         maxParallelApiRequests (int):
@@ -96,7 +208,11 @@ class ClientOptions:
     quorum: Optional[bool] = None
     minQuorumSize: Optional[int] = None
     quorumThreshold: Optional[int] = None
+    broadcastNodeCount: Optional[int] = None
     userAgent: Optional[str] = None
+    retryPolicy: Optional[RetryPolicy] = None
+    connectionPool: Optional[ConnectionPoolOptions] = None
+    proxy: Optional[str] = None
     brokerOptions: Optional[MqttBrokerOptions] = None
     protocolParameters: Optional[NodeInfoProtocol] = None
     localPow: Optional[bool] = None
@@ -104,6 +220,7 @@ class ClientOptions:
     tipsInterval: Optional[int] = None
     apiTimeout: Optional[Duration] = None
     remotePowTimeout: Optional[Duration] = None
+    blockIdempotencyTtl: Optional[Duration] = None
     powWorkerCount: Optional[int] = None
     maxParallelApiRequests: Optional[int] = None
 
@@ -112,5 +229,9 @@ class ClientOptions:
 
         if 'brokerOptions' in config:
             config['brokerOptions'] = config['brokerOptions'].as_dict()
+        if 'retryPolicy' in config:
+            config['retryPolicy'] = config['retryPolicy'].as_dict()
+        if 'connectionPool' in config:
+            config['connectionPool'] = config['connectionPool'].as_dict()
 
         return config
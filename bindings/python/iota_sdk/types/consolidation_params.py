@@ -16,8 +16,26 @@ class ConsolidationParams:
             Consolidates if the output number is >= the output_threshold.
         targetAddress (Optional[str]):
             Address to which the consolidated output should be sent.
+        unlockConditionsAllowed (bool):
+            Also consolidates outputs with other unlock conditions than the address unlock condition.
     """
 
     force: bool
     outputThreshold: Optional[int] = None
     targetAddress: Optional[str] = None
+    unlockConditionsAllowed: bool = True
+
+
+@dataclass
+class ConsolidationPolicy:
+    """Defines when the basic outputs of an account are consolidated automatically while syncing.
+
+        Attributes:
+        outputThreshold (int):
+            Consolidates once the number of spendable basic outputs is >= the output_threshold.
+        unlockConditionsAllowed (bool):
+            Also consolidates outputs with other unlock conditions than the address unlock condition.
+    """
+
+    outputThreshold: int
+    unlockConditionsAllowed: bool
@@ -14,6 +14,13 @@ class WalletEventType(IntEnum):
         SpentOutput (3): An output was spent.
         TransactionInclusion (4): A transaction was included into the ledger.
         TransactionProgress (5): A progress update while submitting a transaction.
+        LockedAddressChanged (6): The address that can unlock an output changed because its expiration expired.
+        AddressRotated (7): The current receive address of an account was rotated.
+        TransactionRebuilt (8): A conflicting transaction was rebuilt with other inputs and sent again.
+        AccountRenamed (9): The alias of an account was changed.
+        LedgerAddressMismatch (10): The address displayed by a Nano Ledger doesn't match the address of the account.
+        SyncProgress (11): A progress update while syncing an account.
+        AutoConsolidation (12): Basic outputs were consolidated automatically, or the attempt failed.
     """
     ConsolidationRequired = 0,
     LedgerAddressGeneration = 1,
@@ -21,3 +28,10 @@ class WalletEventType(IntEnum):
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    LockedAddressChanged = 6,
+    AddressRotated = 7,
+    TransactionRebuilt = 8,
+    AccountRenamed = 9,
+    LedgerAddressMismatch = 10,
+    SyncProgress = 11,
+    AutoConsolidation = 12,
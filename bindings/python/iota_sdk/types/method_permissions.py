@@ -0,0 +1,20 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from dataclasses import dataclass, field
+from typing import List, Optional
+
+
+@dataclass
+class MethodPermissions:
+    """Restricts the methods a wallet is allowed to call, by method name, e.g. `storeMnemonic` or `getBalance`.
+
+    Attributes:
+        allowedMethods: If set, only these methods can be called.
+        deniedMethods: These methods can never be called.
+    """
+    allowedMethods: Optional[List[str]] = None
+    deniedMethods: List[str] = field(default_factory=list)
+
+    def as_dict(self):
+        return {k: v for k, v in self.__dict__.items() if v is not None}
@@ -3,6 +3,7 @@
 
 from iota_sdk.types.common import HexStr
 from dataclasses import dataclass
+from typing import Optional
 
 
 @dataclass
@@ -15,3 +16,25 @@ class NativeToken():
     """
     id: HexStr
     amount: HexStr
+
+
+@dataclass
+class NativeTokenMetadata():
+    """The IRC-30 metadata of a native token, stored in a metadata feature of its foundry.
+
+    Attributes:
+        name: The human-readable name of the native token.
+        symbol: The symbol or ticker of the native token.
+        decimals: The number of decimals the token uses.
+        description: The human-readable description of the native token.
+        url: The URL pointing to more resources about the native token.
+        logoUrl: The URL pointing to an image resource of the native token logo.
+        logo: The SVG image of the native token logo.
+    """
+    name: str
+    symbol: str
+    decimals: int
+    description: Optional[str] = None
+    url: Optional[str] = None
+    logoUrl: Optional[str] = None
+    logo: Optional[str] = None
@@ -3,6 +3,7 @@
 
 from __future__ import annotations
 from dataclasses import dataclass
+from enum import Enum
 from iota_sdk.types.node_info import NodeInfoProtocol
 
 
@@ -14,3 +15,18 @@ class NetworkInfo:
     protocolParameters: NodeInfoProtocol
     localPow: bool
     tipsInterval: int
+
+
+class NetworkProfile(str, Enum):
+    """Presets of the well-known networks, with their bech32 HRP, coin type, protocol parameters and public nodes.
+
+    Attributes:
+        Mainnet: The IOTA mainnet.
+        Shimmer: The Shimmer mainnet.
+        Testnet: The public Shimmer testnet.
+        Private: A private network, e.g. a private tangle running on the local machine.
+    """
+    Mainnet = 'mainnet'
+    Shimmer = 'shimmer'
+    Testnet = 'testnet'
+    Private = 'private'
@@ -31,6 +31,20 @@ class SendParams():
         return config
 
 
+@dataclass
+class SendEstimate():
+    """The costs of sending base coins, estimated before anything is signed or submitted.
+
+    Attributes:
+        storageDeposit: The minimum storage deposit required by the outputs to the recipients.
+        requiredInputAmount: The amount of the inputs that are consumed by the transaction.
+        remainder: The amount that is sent back to the account with the remainder output.
+    """
+    storageDeposit: str
+    requiredInputAmount: str
+    remainder: str
+
+
 @dataclass
 class SendNativeTokensParams():
     """Parameters for sending native tokens
@@ -0,0 +1,20 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import List
+
+
+@dataclass
+class SnapshotVerification:
+    """The result of verifying a Stronghold snapshot.
+
+    Attributes:
+        hasMnemonic: Whether the vault contains a seed record, i.e. a mnemonic has been stored.
+        validStoreRecords: Keys of the store records that could be decrypted.
+        corruptedStoreRecords: Keys of the store records that couldn't be decrypted.
+    """
+    hasMnemonic: bool
+    validStoreRecords: List[str]
+    corruptedStoreRecords: List[str]
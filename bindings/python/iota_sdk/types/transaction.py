@@ -39,6 +39,8 @@ class Transaction:
         inputs: The inputs of the transaction.
         note: A note attached to the transaction.
         blockId: The ID of the block that holds the transaction.
+        attachments: The IDs of all blocks the transaction was sent in, starting with the first attachment.
+        buildData: How the transaction was built, if it was sent by the wallet, to rebuild it if it's conflicting.
     """
     payload: TransactionPayload
     inclusionState: InclusionState
@@ -49,6 +51,8 @@ class Transaction:
     inputs = List[OutputWithMetadata]
     note: Optional[str] = None
     blockId: Optional[HexStr] = None
+    attachments: Optional[List[HexStr]] = None
+    buildData: Optional[Dict] = None
 
     @classmethod
     def from_dict(cls, dict: Dict) -> Transaction:
@@ -57,3 +61,51 @@ class Transaction:
         for k, v in dict.items():
             setattr(obj, k, v)
         return obj
+
+
+@dataclass
+class NativeTokenChange:
+    """How a transaction changed the balance of a native token, only one of the amounts isn't zero.
+
+    Attributes:
+        tokenId: The ID of the native token.
+        increase: The amount the balance increased by.
+        decrease: The amount the balance decreased by.
+    """
+    tokenId: HexStr
+    increase: HexStr
+    decrease: HexStr
+
+
+@dataclass
+class AddressBalanceChange:
+    """How a transaction changed the balance of an address.
+
+    Attributes:
+        address: The address, the state controller for alias outputs and the alias for foundry outputs.
+        baseCoinChange: The change of the base coin balance.
+        nativeTokenChanges: The changes of the native token balances, unchanged tokens are left out.
+    """
+    address: str
+    baseCoinChange: str
+    nativeTokenChanges: List[NativeTokenChange]
+
+
+@dataclass
+class TransactionReceipt:
+    """The effects of a transaction: the outputs it consumed and created and how it changed the balances of the addresses owning them.
+
+    Attributes:
+        transactionId: The ID of the transaction.
+        consumedOutputs: The outputs the transaction consumed.
+        createdOutputs: The outputs the transaction created.
+        addressChanges: The balance changes of the addresses owning the consumed or created outputs, sorted by address.
+        nativeTokenChanges: The net changes of the native tokens over all addresses, which aren't zero if tokens were minted, melted or burned.
+        blockId: The block the transaction was sent in, if it was sent by the account.
+    """
+    transactionId: HexStr
+    consumedOutputs: List[HexStr]
+    createdOutputs: List[HexStr]
+    addressChanges: List[AddressBalanceChange]
+    nativeTokenChanges: List[NativeTokenChange]
+    blockId: Optional[HexStr] = None
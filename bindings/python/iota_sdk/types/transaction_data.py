@@ -5,6 +5,7 @@ from __future__ import annotations
 from dataclasses import dataclass, asdict
 from typing import Optional, List, Union
 from iota_sdk.types.address import Ed25519Address, AliasAddress, NFTAddress
+from iota_sdk.types.common import HexStr
 from iota_sdk.types.output import BasicOutput, AliasOutput, FoundryOutput, NftOutput, OutputMetadata
 from iota_sdk.types.payload import RegularTransactionEssence, TransactionPayload
 from iota_sdk.types.signature import Bip44
@@ -85,6 +86,34 @@ class PreparedTransactionData:
         return config
 
 
+@dataclass
+class TransactionSummary:
+    """Summary of the outputs consumed and created by a prepared transaction.
+
+    Attributes:
+        consumedOutputIds: The IDs of the consumed outputs.
+        consumedAmount: The base coin amount of the consumed outputs.
+        createdAmount: The base coin amount of the created outputs, including the remainder.
+        remainderIndex: The index of the remainder output in the outputs of the essence, if there is one.
+    """
+    consumedOutputIds: List[HexStr]
+    consumedAmount: str
+    createdAmount: str
+    remainderIndex: Optional[int] = None
+
+
+@dataclass
+class DryRunTransaction:
+    """A transaction built with input selection and validation, but neither signed nor sent.
+
+    Attributes:
+        preparedTransactionData: The would-be transaction.
+        summary: Summary of the consumed and created outputs.
+    """
+    preparedTransactionData: PreparedTransactionData
+    summary: TransactionSummary
+
+
 @dataclass
 class SignedTransactionData:
     """Helper class for offline signing.
@@ -54,27 +54,36 @@ class TransactionOptions():
         tagged_data_payload: An optional tagged data payload.
         custom_inputs: If custom inputs are provided only those are used. If also other additional inputs should be used, `mandatory_inputs` should be used instead.
         mandatory_inputs: Inputs that must be used for the transaction.
+        forbidden_inputs: Outputs that must not be used as inputs, e.g. outputs of a dust attack.
         burn: Specifies what needs to be burned during input selection.
         note: A string attached to the transaction.
         allow_micro_amount: Whether to allow sending a micro amount.
+        expiration_slack: Time in seconds that the expiration of conditional payments needs to be later than.
+        dry_run: Only prepare the transaction, without locking its inputs, emitting events or generating a remainder address. Signing or sending it fails.
     """
 
     def __init__(self, remainder_value_strategy: Optional[Union[RemainderValueStrategy, RemainderValueStrategyCustomAddress]] = None,
                  tagged_data_payload: Optional[TaggedDataPayload] = None,
                  custom_inputs: Optional[List[OutputId]] = None,
                  mandatory_inputs: Optional[List[OutputId]] = None,
+                 forbidden_inputs: Optional[List[OutputId]] = None,
                  burn: Optional[Burn] = None,
                  note: Optional[str] = None,
-                 allow_micro_amount: Optional[bool] = None):
+                 allow_micro_amount: Optional[bool] = None,
+                 expiration_slack: Optional[int] = None,
+                 dry_run: Optional[bool] = None):
         """Initialize transaction options.
         """
         self.remainder_value_strategy = remainder_value_strategy
         self.tagged_data_payload = tagged_data_payload
         self.custom_inputs = custom_inputs
         self.mandatory_inputs = mandatory_inputs
+        self.forbidden_inputs = forbidden_inputs
         self.burn = burn
         self.note = note
         self.allow_micro_amount = allow_micro_amount
+        self.expiration_slack = expiration_slack
+        self.dry_run = dry_run
 
     def as_dict(self):
         return dict(self.__dict__)
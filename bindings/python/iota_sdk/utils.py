@@ -3,11 +3,13 @@
 
 from __future__ import annotations
 from iota_sdk import call_utils_method
+from iota_sdk.error import IotaSdkError
 from iota_sdk.types.signature import Ed25519Signature
 from iota_sdk.types.address import Address, AddressType, Ed25519Address, AliasAddress, NFTAddress
 from iota_sdk.types.common import HexStr
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.output import Output
+from iota_sdk.types.stronghold import SnapshotVerification
 from json import dumps, loads
 from typing import TYPE_CHECKING, List
 from dacite import from_dict
@@ -205,8 +207,30 @@ class Utils():
             'message': message,
         })
 
+    @staticmethod
+    def verify_stronghold_snapshot(
+            path: str, password: str) -> SnapshotVerification:
+        """Verify a Stronghold snapshot without loading it into a wallet.
+        """
+        return from_dict(SnapshotVerification, _call_method('verifyStrongholdSnapshot', {
+            'path': path,
+            'password': password,
+        }))
+
+    @staticmethod
+    def repair_stronghold_snapshot(
+            path: str, password: str, recovered_path: str) -> SnapshotVerification:
+        """Write the readable parts of a Stronghold snapshot to `recovered_path`, dropping corrupted records.
+        Returns the verification of the original snapshot.
+        """
+        return from_dict(SnapshotVerification, _call_method('repairStrongholdSnapshot', {
+            'path': path,
+            'password': password,
+            'recoveredPath': recovered_path,
+        }))
+
 
-class UtilsError(Exception):
+class UtilsError(IotaSdkError):
     """A utils error."""
     pass
 
@@ -14,11 +14,11 @@ from iota_sdk.types.output_data import OutputData
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.output import BasicOutput, NftOutput, Output, output_from_dict
 from iota_sdk.types.output_params import OutputParams
-from iota_sdk.types.transaction_data import PreparedTransactionData, SignedTransactionData
-from iota_sdk.types.send_params import CreateAliasOutputParams, CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams
-from iota_sdk.types.transaction import Transaction
+from iota_sdk.types.transaction_data import DryRunTransaction, PreparedTransactionData, SignedTransactionData
+from iota_sdk.types.send_params import CreateAliasOutputParams, CreateNativeTokenParams, MintNftParams, SendEstimate, SendNativeTokensParams, SendNftParams, SendParams
+from iota_sdk.types.transaction import Transaction, TransactionReceipt
 from iota_sdk.types.transaction_options import TransactionOptions
-from iota_sdk.types.consolidation_params import ConsolidationParams
+from iota_sdk.types.consolidation_params import ConsolidationParams, ConsolidationPolicy
 from typing import List, Optional, Union
 from dacite import from_dict
 from dataclasses import dataclass
@@ -208,6 +208,19 @@ class Account:
             }
         ))
 
+    def get_transaction_receipt(self, transaction_id: HexStr) -> Optional[TransactionReceipt]:
+        """Get the receipt of a transaction stored in the account, with the outputs it consumed and created and the
+        balance changes of the addresses owning them.
+        """
+        receipt = self._call_account_method(
+            'getTransactionReceipt', {
+                'transactionId': transaction_id
+            }
+        )
+        if receipt is None:
+            return None
+        return from_dict(TransactionReceipt, receipt)
+
     def addresses(self) -> List[AccountAddress]:
         """List addresses.
         """
@@ -248,6 +261,19 @@ class Account:
         )
         return [from_dict(OutputData, o) for o in outputs]
 
+    def verify_address_on_device(self, key_index: int) -> bool:
+        """Displays the public address with the key index on the Ledger Nano device, so the user can verify it.
+        A `LedgerAddressMismatch` event is emitted if the device derived another address.
+
+        Returns:
+            Whether the device derived the same address.
+        """
+        return self._call_account_method(
+            'verifyAddressOnDevice', {
+                'keyIndex': key_index
+            }
+        )
+
     def incoming_transactions(self) -> List[Transaction]:
         """Returns all incoming transactions of the account.
         """
@@ -326,6 +352,18 @@ class Account:
         )
         return PreparedTransaction(self, prepared)
 
+    def mint_nfts_in_batches(self, params: List[MintNftParams],
+                             options: Optional[TransactionOptions] = None) -> List[Transaction]:
+        """Mint NFTs in as many transactions as needed, waiting for each transaction before sending the next one.
+        """
+        transactions = self._call_account_method(
+            'mintNftsInBatches', {
+                'params': params,
+                'options': options
+            }
+        )
+        return [Transaction.from_dict(tx) for tx in transactions]
+
     def get_balance(self) -> Balance:
         """Get account balance information.
         """
@@ -361,6 +399,18 @@ class Account:
         )
         return PreparedTransaction(self, prepared)
 
+    def estimate_send(self, params: List[SendParams],
+                      options: Optional[TransactionOptions] = None) -> SendEstimate:
+        """Estimate the storage deposit, the required input amount and the remainder of sending base coins,
+        without signing or submitting anything.
+        """
+        return from_dict(SendEstimate, self._call_account_method(
+            'estimateSend', {
+                'params': params,
+                'options': options
+            }
+        ))
+
     def prepare_transaction(
             self, outputs: List[Output], options: Optional[TransactionOptions] = None) -> PreparedTransaction:
         """Prepare transaction.
@@ -373,6 +423,34 @@ class Account:
         )
         return PreparedTransaction(self, prepared)
 
+    def dry_run_transaction(
+            self, outputs: List[Output], options: Optional[TransactionOptions] = None) -> DryRunTransaction:
+        """Build a transaction with input selection and validation, without signing or sending it and without
+        keeping its inputs locked.
+        """
+        return from_dict(DryRunTransaction, self._call_account_method(
+            'dryRunTransaction', {
+                'outputs': outputs,
+                'options': options
+            }
+        ))
+
+    def prepare_update_alias_state(self,
+                                   alias_id: HexStr,
+                                   state_metadata: HexStr,
+                                   options: Optional[TransactionOptions] = None) -> PreparedTransaction:
+        """Update the state metadata of an alias with a state transition, keeping all other fields and incrementing
+        the state index.
+        """
+        prepared = self._call_account_method(
+            'prepareUpdateAliasState', {
+                'aliasId': alias_id,
+                'stateMetadata': state_metadata,
+                'options': options
+            }
+        )
+        return PreparedTransaction(self, prepared)
+
     def retry_transaction_until_included(
             self, transaction_id: HexStr, interval=None, max_attempts=None) -> HexStr:
         """Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
@@ -399,7 +477,7 @@ class Account:
 
     def send(self, amount: str, address: str,
              options: Optional[TransactionOptions] = None) -> Transaction:
-        """Send base coins.
+        """Send base coins to a bech32 address, or to the address of a contact of the wallet by its name.
         """
         return Transaction.from_dict(self._call_account_method(
             'send', {
@@ -420,6 +498,18 @@ class Account:
             }
         ))
 
+    def send_with_receipt(
+            self, params: List[SendParams], options: Optional[TransactionOptions] = None) -> TransactionReceipt:
+        """Send base coins to multiple addresses or with additional parameters and get the receipt of the transaction,
+        with the outputs it consumed and created and the balance changes of the addresses owning them.
+        """
+        return from_dict(TransactionReceipt, self._call_account_method(
+            'sendWithReceipt', {
+                'params': params,
+                'options': options
+            }
+        ))
+
     def prepare_send_native_tokens(
             self, params: List[SendNativeTokensParams], options: Optional[TransactionOptions] = None) -> PreparedTransaction:
         """Send native tokens.
@@ -453,6 +543,35 @@ class Account:
             }
         )
 
+    def set_metadata(self, key: str, value: str):
+        """Set a metadata key of the account to the value.
+        """
+        return self._call_account_method(
+            'setMetadata', {
+                'key': key,
+                'value': value
+            }
+        )
+
+    def remove_metadata(self, key: str):
+        """Remove a metadata key of the account.
+        """
+        return self._call_account_method(
+            'removeMetadata', {
+                'key': key
+            }
+        )
+
+    def set_consolidation_policy(self, policy: Optional[ConsolidationPolicy] = None):
+        """Set the policy that consolidates the basic outputs automatically while syncing, no policy stops it.
+        Every consolidation is reported with an AutoConsolidation event.
+        """
+        return self._call_account_method(
+            'setConsolidationPolicy', {
+                'policy': policy
+            }
+        )
+
     def set_default_sync_options(self, options: SyncOptions):
         """Set the fallback SyncOptions for account syncing.
         If storage is enabled, will persist during restarts.
@@ -503,6 +622,14 @@ class Account:
             }
         ))
 
+    def claim_profitable_outputs(self) -> List[Transaction]:
+        """Claim all outputs which don't require additional funds of the account to be claimed.
+        """
+        transactions = self._call_account_method(
+            'claimProfitableOutputs'
+        )
+        return [Transaction.from_dict(tx) for tx in transactions]
+
     def send_outputs(
             self, outputs: List[Output], options: Optional[TransactionOptions] = None) -> Transaction:
         """Send outputs in a transaction.
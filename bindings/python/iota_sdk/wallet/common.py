@@ -2,6 +2,7 @@
 # SPDX-License-Identifier: Apache-2.0
 
 from iota_sdk import call_wallet_method
+from iota_sdk.error import IotaSdkError
 import humps
 import json
 from json import dumps, JSONEncoder
@@ -65,6 +66,6 @@ def _call_method_routine(func):
     return wrapper
 
 
-class WalletError(Exception):
+class WalletError(IotaSdkError):
     """A wallet error."""
     pass
@@ -100,6 +100,12 @@ class SyncOptions():
         This will overwrite the `account`, `alias` and `nft` options.
     sync_native_token_foundries :
         Sync native token foundries, so their metadata can be returned in the balance.
+    sync_participation :
+        Request the participation status of the voting outputs and cache it for spent ones,
+        so the participations are still known after the outputs got pruned.
+    account_indexes :
+        Indexes of the accounts to sync when syncing the wallet, all accounts are synced if empty.
+        If addresses are provided, only the accounts containing them are synced.
     """
 
     def __init__(self,
@@ -113,7 +119,9 @@ class SyncOptions():
                  alias: Optional[AliasSyncOptions] = None,
                  nft: Optional[NftSyncOptions] = None,
                  sync_only_most_basic_outputs: Optional[bool] = None,
-                 sync_native_token_foundries: Optional[bool] = None):
+                 sync_native_token_foundries: Optional[bool] = None,
+                 sync_participation: Optional[bool] = None,
+                 account_indexes: Optional[List[int]] = None):
         """Initialize `Self`.
         """
         self.addresses = addresses
@@ -127,6 +135,8 @@ class SyncOptions():
         self.nft = nft
         self.syncOnlyMostBasicOutputs = sync_only_most_basic_outputs
         self.syncNativeTokenFoundries = sync_native_token_foundries
+        self.syncParticipation = sync_participation
+        self.accountIndexes = account_indexes
 
     def as_dict(self):
         return dict(self.__dict__)
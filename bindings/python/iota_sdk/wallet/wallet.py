@@ -4,11 +4,14 @@
 from iota_sdk import destroy_wallet, create_wallet, listen_wallet, get_client_from_wallet, get_secret_manager_from_wallet, Client
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager, SecretManager
 from iota_sdk.types.client_options import ClientOptions
-from iota_sdk.types.address import AccountAddress
+from iota_sdk.types.address import AccountAddress, Contact
+from iota_sdk.types.balance import AccountSyncResult
+from iota_sdk.types.method_permissions import MethodPermissions
 from iota_sdk.wallet.account import Account, _call_method_routine
 from iota_sdk.wallet.sync_options import SyncOptions
 from json import dumps
 from typing import Any, Dict, List, Optional, Union
+from dacite import from_dict
 
 
 class Wallet():
@@ -19,8 +22,13 @@ class Wallet():
     """
 
     def __init__(self, storage_path: Optional[str] = None, client_options: Optional[Dict[str, Any]] = None, coin_type: Optional[int] = None,
-                 secret_manager: Optional[Union[LedgerNanoSecretManager, MnemonicSecretManager, SeedSecretManager, StrongholdSecretManager]] = None):
+                 secret_manager: Optional[Union[LedgerNanoSecretManager, MnemonicSecretManager, SeedSecretManager, StrongholdSecretManager]] = None,
+                 permissions: Optional[MethodPermissions] = None):
         """Initialize `self`.
+
+        Args:
+            permissions: Restricts the wallet and account methods that can be called, enforced in Rust. The client and
+                secret manager taken from the wallet are restricted the same way.
         """
 
         # Setup the options
@@ -31,6 +39,8 @@ class Wallet():
             options['coinType'] = coin_type
         if secret_manager:
             options['secretManager'] = secret_manager
+        if permissions:
+            options['permissions'] = permissions.as_dict()
 
         options_str: str = dumps(options)
 
@@ -120,13 +130,24 @@ class Wallet():
             }
         )
 
-    def change_stronghold_password(self, password: str):
+    def backup_to_file(self, destination: str, password: str):
+        """Backup the accounts, client options and coin type to a file encrypted with the password.
+        The secrets of the secret manager aren't part of the backup, so the mnemonic has to be backed up separately.
+        """
+        return self._call_method(
+            'backupToFile', {
+                'destination': destination,
+                'password': password
+            }
+        )
+
+    def change_stronghold_password(self, current_password: str, new_password: str):
         """Change stronghold password.
         """
         return self._call_method(
             'changeStrongholdPassword', {
-                'currentPassword': password,
-                'newPassword': password
+                'currentPassword': current_password,
+                'newPassword': new_password
             }
         )
 
@@ -145,14 +166,17 @@ class Wallet():
         )
 
     def recover_accounts(self, account_start_index: int, account_gap_limit: int,
-                         address_gap_limit: int, sync_options: Optional[SyncOptions] = None):
+                         address_gap_limit: int, sync_options: Optional[SyncOptions] = None,
+                         address_gap_limit_internal: Optional[int] = None):
         """Recover accounts.
+        The gap limit for internal addresses is `address_gap_limit` if `address_gap_limit_internal` isn't set.
         """
         return self._call_method(
             'recoverAccounts', {
                 'accountStartIndex': account_start_index,
                 'accountGapLimit': account_gap_limit,
                 'addressGapLimit': address_gap_limit,
+                'addressGapLimitInternal': address_gap_limit_internal,
                 'syncOptions': sync_options
             }
         )
@@ -164,6 +188,16 @@ class Wallet():
             'removeLatestAccount'
         )
 
+    def rename_account(self, account_id: Union[str, int], alias: str):
+        """Change the alias of an account, which can't be used by another account.
+        """
+        return self._call_method(
+            'renameAccount', {
+                'accountId': account_id,
+                'alias': alias
+            }
+        )
+
     def restore_backup(self, source: str, password: str):
         """Restore a backup from a Stronghold file.
         Replaces `client_options`, `coin_type`, `secret_manager` and accounts.
@@ -178,6 +212,43 @@ class Wallet():
             }
         )
 
+    def restore_backup_from_file(self, source: str, password: str):
+        """Restore the accounts, client options and coin type of a file written with `backup_to_file`.
+        Backups of older versions are migrated. Returns an error if accounts were already created.
+        """
+        return self._call_method(
+            'restoreBackupFromFile', {
+                'source': source,
+                'password': password
+            }
+        )
+
+    def add_contact(self, contact: Contact):
+        """Add a contact to the address book, replacing the contact with the same name.
+        """
+        return self._call_method(
+            'addContact', {
+                'contact': contact.as_dict()
+            }
+        )
+
+    def list_contacts(self) -> List[Contact]:
+        """List the contacts of the address book.
+        """
+        contacts = self._call_method(
+            'listContacts'
+        )
+        return [Contact(**contact) for contact in contacts]
+
+    def remove_contact(self, name: str):
+        """Remove a contact from the address book.
+        """
+        return self._call_method(
+            'removeContact', {
+                'name': name
+            }
+        )
+
     def set_client_options(self, client_options):
         """Update the client options for all accounts.
         """
@@ -250,12 +321,28 @@ class Wallet():
             'stopBackgroundSync',
         )
 
-    def listen(self, handler, events: Optional[List[int]] = None):
+    def sync_all(self, options: Optional[SyncOptions] = None,
+                 concurrency: Optional[int] = None) -> List[AccountSyncResult]:
+        """Sync all accounts, with at most `concurrency` accounts synced at the same time (8 by default).
+        The parallel requests to the node are split between the accounts syncing at the same time.
+        The result of an account contains its balance, or the error if it failed to sync.
+        """
+        results = self._call_method(
+            'syncAll', {
+                'options': options,
+                'concurrency': concurrency,
+            }
+        )
+        return [from_dict(AccountSyncResult, result) for result in results]
+
+    def listen(self, handler, events: Optional[List[int]] = None,
+               account_indexes: Optional[List[int]] = None):
         """Listen to wallet events, empty array or None will listen to all events.
         The default value for events is None.
+        Only events of the `account_indexes` are passed to the handler, events of all accounts if None.
         """
         events_array = [] if events is None else events
-        listen_wallet(self.handle, events_array, handler)
+        listen_wallet(self.handle, events_array, handler, account_indexes)
 
     def clear_listeners(self, events: Optional[List[int]] = None):
         """Remove wallet event listeners, empty array or None will remove all listeners.
@@ -7,15 +7,16 @@ use iota_sdk_bindings_core::{
         mqtt::{Error as MqttError, Topic},
         Client as RustClient, ClientBuilder,
     },
-    listen_mqtt as rust_listen_mqtt, ClientMethod,
+    listen_mqtt as rust_listen_mqtt, ClientMethod, MethodPermissions, Response,
 };
 use pyo3::{prelude::*, types::PyTuple};
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 #[pyclass]
 pub struct Client {
     pub client: RustClient,
+    pub permissions: MethodPermissions,
 }
 
 /// Create client for python-side usage.
@@ -29,17 +30,39 @@ pub fn create_client(options: Option<String>) -> Result<Client> {
         })
     })?;
 
-    Ok(Client { client })
+    Ok(Client {
+        client,
+        permissions: MethodPermissions::default(),
+    })
 }
 
 #[pyfunction]
 pub fn call_client_method(client: &Client, method: String) -> Result<String> {
     let method = serde_json::from_str::<ClientMethod>(&method)?;
-    let response = crate::block_on(async { rust_call_client_method(&client.client, method).await });
+    let response = match client.permissions.check(&method) {
+        Ok(()) => crate::block_on(async { rust_call_client_method(&client.client, method).await }),
+        Err(e) => Response::Error(e),
+    };
 
     Ok(serde_json::to_string(&response)?)
 }
 
+/// Calls a client method without blocking the thread, returns an awaitable that resolves to the JSON-encoded response.
+#[pyfunction]
+pub fn call_client_method_async<'py>(py: Python<'py>, client: &Client, method: String) -> PyResult<&'py PyAny> {
+    let permissions = client.permissions.clone();
+    let client = client.client.clone();
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let method = serde_json::from_str::<ClientMethod>(&method).map_err(Error::from)?;
+        let response = match permissions.check(&method) {
+            Ok(()) => rust_call_client_method(&client, method).await,
+            Err(e) => Response::Error(e),
+        };
+
+        Ok(serde_json::to_string(&response).map_err(Error::from)?)
+    })
+}
+
 #[pyfunction]
 pub fn listen_mqtt(client: &Client, topics: Vec<String>, handler: PyObject) -> Result<()> {
     let topics = topics
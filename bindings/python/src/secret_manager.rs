@@ -6,7 +6,7 @@ use std::sync::Arc;
 use iota_sdk_bindings_core::{
     call_secret_manager_method as rust_call_secret_manager_method,
     iota_sdk::client::secret::{SecretManager as RustSecretManager, SecretManagerDto},
-    SecretManagerMethod,
+    MethodPermissions, Response, SecretManagerMethod,
 };
 use pyo3::prelude::*;
 use tokio::sync::RwLock;
@@ -16,6 +16,7 @@ use crate::error::Result;
 #[pyclass]
 pub struct SecretManager {
     pub secret_manager: Arc<RwLock<RustSecretManager>>,
+    pub permissions: MethodPermissions,
 }
 
 /// Create secret_manager for python-side usage.
@@ -25,14 +26,19 @@ pub fn create_secret_manager(options: String) -> Result<SecretManager> {
     let secret_manager = RustSecretManager::try_from(secret_manager_dto)?;
     Ok(SecretManager {
         secret_manager: Arc::new(RwLock::new(secret_manager)),
+        permissions: MethodPermissions::default(),
     })
 }
 
 #[pyfunction]
 pub fn call_secret_manager_method(secret_manager: &SecretManager, method: String) -> Result<String> {
     let method = serde_json::from_str::<SecretManagerMethod>(&method)?;
-    let response =
-        crate::block_on(async { rust_call_secret_manager_method(&secret_manager.secret_manager, method).await });
+    let response = match secret_manager.permissions.check(&method) {
+        Ok(()) => {
+            crate::block_on(async { rust_call_secret_manager_method(&secret_manager.secret_manager, method).await })
+        }
+        Err(e) => Response::Error(e),
+    };
 
     Ok(serde_json::to_string(&response)?)
 }
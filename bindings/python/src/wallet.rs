@@ -4,9 +4,9 @@
 use std::sync::Arc;
 
 use iota_sdk_bindings_core::{
-    call_wallet_method as rust_call_wallet_method,
+    call_wallet_method_with_permissions as rust_call_wallet_method,
     iota_sdk::wallet::{events::types::WalletEventType, Wallet as RustWallet},
-    Response, WalletMethod, WalletOptions,
+    MethodPermissions, Response, WalletMethod, WalletOptions,
 };
 use pyo3::{prelude::*, types::PyTuple};
 use tokio::sync::RwLock;
@@ -20,6 +20,7 @@ use crate::{
 #[pyclass]
 pub struct Wallet {
     pub wallet: Arc<RwLock<Option<RustWallet>>>,
+    pub permissions: MethodPermissions,
 }
 
 /// Destroys the wallet instance.
@@ -35,10 +36,12 @@ pub fn destroy_wallet(wallet: &Wallet) -> PyResult<()> {
 #[pyfunction]
 pub fn create_wallet(options: String) -> Result<Wallet> {
     let wallet_options = serde_json::from_str::<WalletOptions>(&options)?;
+    let permissions = wallet_options.permissions.clone();
     let wallet = crate::block_on(async { wallet_options.build().await })?;
 
     Ok(Wallet {
         wallet: Arc::new(RwLock::new(Some(wallet))),
+        permissions,
     })
 }
 
@@ -48,7 +51,7 @@ pub fn call_wallet_method(wallet: &Wallet, method: String) -> Result<String> {
     let method = serde_json::from_str::<WalletMethod>(&method)?;
     let response = crate::block_on(async {
         match wallet.wallet.read().await.as_ref() {
-            Some(wallet) => rust_call_wallet_method(wallet, method).await,
+            Some(rust_wallet) => rust_call_wallet_method(rust_wallet, method, &wallet.permissions).await,
             None => Response::Panic("wallet got destroyed".into()),
         }
     });
@@ -56,9 +59,9 @@ pub fn call_wallet_method(wallet: &Wallet, method: String) -> Result<String> {
     Ok(serde_json::to_string(&response)?)
 }
 
-/// Listen to wallet events.
+/// Listen to wallet events, of the given accounts or of all accounts if empty.
 #[pyfunction]
-pub fn listen_wallet(wallet: &Wallet, events: Vec<u8>, handler: PyObject) {
+pub fn listen_wallet(wallet: &Wallet, events: Vec<u8>, handler: PyObject, account_indexes: Option<Vec<u32>>) {
     let mut rust_events = Vec::with_capacity(events.len());
 
     for event in events {
@@ -78,7 +81,7 @@ pub fn listen_wallet(wallet: &Wallet, events: Vec<u8>, handler: PyObject) {
             .await
             .as_ref()
             .expect("wallet got destroyed")
-            .listen(rust_events, move |event| {
+            .listen_to_accounts(account_indexes.unwrap_or_default(), rust_events, move |event| {
                 let event_string = serde_json::to_string(&event).expect("json to string error");
                 Python::with_gil(|py| {
                     let args = PyTuple::new(py, &[event_string]);
@@ -108,7 +111,11 @@ pub fn get_client_from_wallet(wallet: &Wallet) -> Result<Client> {
             })
     })?;
 
-    Ok(Client { client })
+    // The client is restricted like the wallet it's taken from
+    Ok(Client {
+        client,
+        permissions: wallet.permissions.clone(),
+    })
 }
 
 /// Get the secret manager from the wallet.
@@ -130,5 +137,8 @@ pub fn get_secret_manager_from_wallet(wallet: &Wallet) -> Result<SecretManager>
             })
     })?;
 
-    Ok(SecretManager { secret_manager })
+    Ok(SecretManager {
+        secret_manager,
+        permissions: wallet.permissions.clone(),
+    })
 }
@@ -0,0 +1,50 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from iota_sdk import AsyncClient, Client
+from iota_sdk.client.client import ClientError
+import asyncio
+import unittest
+
+
+class TestAsyncClient(unittest.TestCase):
+    def test_shared_client(self):
+        client = Client(local_pow=False)
+        async_client = AsyncClient(client)
+
+        # Both clients use the same inner client
+        assert async_client.client is client
+        assert asyncio.run(async_client.call_method(
+            'getLocalPow')) == client.get_local_pow()
+
+    def test_client_from_kwargs(self):
+        async_client = AsyncClient(local_pow=False)
+
+        assert not async_client.client.get_local_pow()
+        assert not asyncio.run(async_client.call_method('getLocalPow'))
+
+    def test_concurrent_calls(self):
+        async_client = AsyncClient()
+
+        async def gather():
+            return await asyncio.gather(
+                async_client.call_method('getLocalPow'),
+                async_client.call_method('unhealthyNodes'),
+            )
+
+        local_pow, unhealthy_nodes = asyncio.run(gather())
+        assert local_pow == async_client.client.get_local_pow()
+        assert unhealthy_nodes == []
+
+    def test_error_response(self):
+        async_client = AsyncClient()
+
+        # There is no node to request the info from
+        with self.assertRaises(ClientError):
+            asyncio.run(async_client.get_info())
+
+    def test_invalid_method(self):
+        async_client = AsyncClient()
+
+        with self.assertRaises(ValueError):
+            asyncio.run(async_client.call_method('notAClientMethod'))
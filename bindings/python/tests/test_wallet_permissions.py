@@ -0,0 +1,33 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from iota_sdk import Wallet, MnemonicSecretManager, CoinType, ClientOptions, ClientError, MethodPermissions, SecretManagerError
+import shutil
+import unittest
+
+
+class WalletPermissions(unittest.TestCase):
+    def test_permissions_of_derived_handles(self):
+        db_path = './test_wallet_permissions'
+        shutil.rmtree(db_path, ignore_errors=True)
+
+        client_options = ClientOptions(nodes=[])
+
+        secret_manager = MnemonicSecretManager(
+            "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast")
+
+        wallet = Wallet(db_path, client_options, CoinType.IOTA, secret_manager,
+                        permissions=MethodPermissions(deniedMethods=['storeMnemonic', 'getLocalPow']))
+
+        # The client and secret manager taken from the wallet are restricted like the wallet
+        with self.assertRaises(SecretManagerError):
+            wallet.get_secret_manager().store_mnemonic(
+                "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast")
+        with self.assertRaises(ClientError):
+            wallet.get_client().get_local_pow()
+
+        # Other methods can still be called
+        assert wallet.get_client().unhealthy_nodes() == []
+
+        wallet.destroy()
+        shutil.rmtree(db_path, ignore_errors=True)
@@ -4,7 +4,7 @@
 use iota_sdk_bindings_core::{
     call_client_method,
     iota_sdk::client::{Client, ClientBuilder},
-    ClientMethod, Response,
+    ClientMethod, MethodPermissions, Response,
 };
 use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
 use wasm_bindgen_futures::future_to_promise;
@@ -15,6 +15,7 @@ use crate::{ArrayString, PromiseString};
 #[wasm_bindgen(js_name = ClientMethodHandler)]
 pub struct ClientMethodHandler {
     pub(crate) client: Client,
+    pub(crate) permissions: MethodPermissions,
 }
 
 /// Creates a method handler with the given client options.
@@ -34,7 +35,10 @@ pub fn create_client(clientOptions: String) -> Result<ClientMethodHandler, JsVal
             .map_err(|err| err.to_string())
     })?;
 
-    Ok(ClientMethodHandler { client })
+    Ok(ClientMethodHandler {
+        client,
+        permissions: MethodPermissions::default(),
+    })
 }
 
 /// Necessary for compatibility with the node.js bindings.
@@ -50,11 +54,15 @@ pub async fn destroy_client(_client_method_handler: &ClientMethodHandler) -> Res
 #[allow(non_snake_case)]
 pub fn call_client_method_async(method: String, methodHandler: &ClientMethodHandler) -> Result<PromiseString, JsValue> {
     let client: Client = methodHandler.client.clone();
+    let permissions = methodHandler.permissions.clone();
 
     let promise: js_sys::Promise = future_to_promise(async move {
         let method: ClientMethod = serde_json::from_str(&method).map_err(|err| err.to_string())?;
 
-        let response = call_client_method(&client, method).await;
+        let response = match permissions.check(&method) {
+            Ok(()) => call_client_method(&client, method).await,
+            Err(e) => Response::Error(e),
+        };
         let ser = JsValue::from(serde_json::to_string(&response).map_err(|err| err.to_string())?);
         match response {
             Response::Error(_) | Response::Panic(_) => Err(ser),
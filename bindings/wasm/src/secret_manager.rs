@@ -6,7 +6,7 @@ use std::sync::Arc;
 use iota_sdk_bindings_core::{
     call_secret_manager_method,
     iota_sdk::client::secret::{SecretManager, SecretManagerDto},
-    Response, SecretManagerMethod,
+    MethodPermissions, Response, SecretManagerMethod,
 };
 use tokio::sync::RwLock;
 use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
@@ -18,6 +18,7 @@ use crate::PromiseString;
 #[wasm_bindgen(js_name = SecretManagerMethodHandler)]
 pub struct SecretManagerMethodHandler {
     pub(crate) secret_manager: Arc<RwLock<SecretManager>>,
+    pub(crate) permissions: MethodPermissions,
 }
 
 /// Creates a method handler with the given secret_manager options.
@@ -29,6 +30,7 @@ pub fn create_secret_manager(options: String) -> Result<SecretManagerMethodHandl
 
     Ok(SecretManagerMethodHandler {
         secret_manager: Arc::new(RwLock::new(secret_manager)),
+        permissions: MethodPermissions::default(),
     })
 }
 
@@ -42,10 +44,14 @@ pub fn call_secret_manager_method_async(
     methodHandler: &SecretManagerMethodHandler,
 ) -> Result<PromiseString, JsValue> {
     let secret_manager = methodHandler.secret_manager.clone();
+    let permissions = methodHandler.permissions.clone();
     let promise: js_sys::Promise = future_to_promise(async move {
         let method: SecretManagerMethod = serde_json::from_str(&method).map_err(|err| err.to_string())?;
 
-        let response = call_secret_manager_method(&secret_manager, method).await;
+        let response = match permissions.check(&method) {
+            Ok(()) => call_secret_manager_method(&secret_manager, method).await,
+            Err(e) => Response::Error(e),
+        };
         let ser = JsValue::from(serde_json::to_string(&response).map_err(|err| err.to_string())?);
         match response {
             Response::Error(_) | Response::Panic(_) => Err(ser),
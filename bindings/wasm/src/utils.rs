@@ -16,3 +16,16 @@ pub fn call_utils_method(method: String) -> Result<JsValue, JsValue> {
         serde_json::to_string(&response).map_err(|err| err.to_string())?,
     ))
 }
+
+/// Derives the randomness of the SDK, like the entropy of generated mnemonics, from the given seed, so that browser
+/// test suites can produce reproducible results. Passing `undefined` uses the operating system randomness again.
+/// Only available if the bindings are built with the `seeded_rng` feature, never use it with real funds.
+#[cfg(feature = "seeded_rng")]
+#[wasm_bindgen(js_name = setRandomSeed)]
+pub fn set_random_seed(seed: Option<u64>) -> Result<(), JsValue> {
+    match seed {
+        Some(seed) => iota_sdk_bindings_core::iota_sdk::client::utils::set_rng_seed(seed),
+        None => iota_sdk_bindings_core::iota_sdk::client::utils::clear_rng_seed(),
+    }
+    .map_err(|err| err.to_string().into())
+}
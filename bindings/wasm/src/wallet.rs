@@ -4,12 +4,12 @@
 use std::sync::Arc;
 
 use iota_sdk_bindings_core::{
-    call_wallet_method,
+    call_wallet_method_with_permissions,
     iota_sdk::wallet::{
         events::types::{Event, WalletEventType},
         Wallet,
     },
-    Response, WalletMethod, WalletOptions,
+    MethodPermissions, Response, WalletMethod, WalletOptions,
 };
 use tokio::sync::{
     mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
@@ -23,6 +23,7 @@ use crate::{client::ClientMethodHandler, secret_manager::SecretManagerMethodHand
 #[wasm_bindgen(js_name = WalletMethodHandler)]
 pub struct WalletMethodHandler {
     wallet: Arc<Mutex<Option<Wallet>>>,
+    permissions: MethodPermissions,
 }
 
 /// Creates a method handler with the given options.
@@ -30,6 +31,7 @@ pub struct WalletMethodHandler {
 #[allow(non_snake_case)]
 pub fn create_wallet(options: String) -> Result<WalletMethodHandler, JsValue> {
     let wallet_options = serde_json::from_str::<WalletOptions>(&options).map_err(|e| e.to_string())?;
+    let permissions = wallet_options.permissions.clone();
 
     let wallet_method_handler = tokio::runtime::Builder::new_current_thread()
         .build()
@@ -39,6 +41,7 @@ pub fn create_wallet(options: String) -> Result<WalletMethodHandler, JsValue> {
 
     Ok(WalletMethodHandler {
         wallet: Arc::new(Mutex::new(Some(wallet_method_handler))),
+        permissions,
     })
 }
 
@@ -58,7 +61,10 @@ pub async fn get_client(method_handler: &WalletMethodHandler) -> Result<ClientMe
         .client()
         .clone();
 
-    Ok(ClientMethodHandler { client })
+    Ok(ClientMethodHandler {
+        client,
+        permissions: method_handler.permissions.clone(),
+    })
 }
 
 #[wasm_bindgen(js_name = getSecretManagerFromWallet)]
@@ -71,7 +77,10 @@ pub async fn get_secret_manager(method_handler: &WalletMethodHandler) -> Result<
         .get_secret_manager()
         .clone();
 
-    Ok(SecretManagerMethodHandler { secret_manager })
+    Ok(SecretManagerMethodHandler {
+        secret_manager,
+        permissions: method_handler.permissions.clone(),
+    })
 }
 
 /// Handles a method, returns the response as a JSON-encoded string.
@@ -82,7 +91,12 @@ pub async fn call_wallet_method_async(method: String, method_handler: &WalletMet
     let wallet = method_handler.wallet.lock().await;
     let method: WalletMethod = serde_json::from_str(&method).map_err(|err| err.to_string())?;
 
-    let response = call_wallet_method(wallet.as_ref().expect("wallet got destroyed"), method).await;
+    let response = call_wallet_method_with_permissions(
+        wallet.as_ref().expect("wallet got destroyed"),
+        method,
+        &method_handler.permissions,
+    )
+    .await;
     match response {
         Response::Error(e) => Err(e.to_string().into()),
         Response::Panic(p) => Err(p.into()),
@@ -98,11 +112,13 @@ pub async fn call_wallet_method_async(method: String, method_handler: &WalletMet
 /// * `vec`: An array of strings that represent the event types you want to listen to.
 /// * `callback`: A JavaScript function that will be called when a wallet event occurs.
 /// * `method_handler`: This is the same method handler that we used in the previous section.
+/// * `account_indexes`: The accounts to listen to, all accounts if not set.
 #[wasm_bindgen(js_name = listenWalletAsync)]
 pub async fn listen_wallet(
     vec: js_sys::Array,
     callback: js_sys::Function,
     method_handler: &WalletMethodHandler,
+    account_indexes: Option<Vec<u32>>,
 ) -> Result<JsValue, JsValue> {
     let mut event_types = Vec::with_capacity(vec.length() as _);
     for event_type in vec.keys() {
@@ -120,7 +136,7 @@ pub async fn listen_wallet(
         .await
         .as_ref()
         .expect("wallet not initialised")
-        .listen(event_types, move |wallet_event| {
+        .listen_to_accounts(account_indexes.unwrap_or_default(), event_types, move |wallet_event| {
             tx.send(wallet_event.clone()).unwrap();
         })
         .await;
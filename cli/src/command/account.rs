@@ -5,7 +5,6 @@ use std::str::FromStr;
 
 use clap::{Parser, Subcommand};
 use iota_sdk::{
-    client::request_funds_from_faucet,
     types::{
         api::plugins::participation::types::ParticipationEventId,
         block::{
@@ -512,7 +511,10 @@ pub async fn faucet_command(
         .as_deref()
         .unwrap_or("https://faucet.testnet.shimmer.network/api/enqueue");
 
-    println_log_info!("{}", request_funds_from_faucet(faucet_url, &address).await?);
+    println_log_info!(
+        "{}",
+        account.client().request_funds_from_faucet(faucet_url, &address).await?
+    );
 
     Ok(())
 }
@@ -0,0 +1,209 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! In this example we will implement a secret manager that doesn't hold any keys itself, but asks a remote signing
+//! service, e.g. in front of an HSM or a KMS, for public keys and signatures over an authenticated HTTP API. It's then
+//! used like any other secret manager to send a transaction.
+//!
+//! The signing service is expected to offer the following endpoints, authenticated with a bearer token:
+//! * `POST /public-key` with the chain of the key, `{ "chain": [coinType, account, change, addressIndex] }`, returns
+//!   its public key, `{ "publicKey": "0x..." }`
+//! * `POST /sign` with a chain and a message, `{ "chain": [...], "message": "0x..." }`, returns the signature of the
+//!   message, `{ "publicKey": "0x...", "signature": "0x..." }`
+//!
+//! Rename `.env.example` to `.env` first, then run the command:
+//! ```sh
+//! cargo run --release --example remote_signer [AMOUNT]
+//! ```
+
+use std::ops::Range;
+
+use async_trait::async_trait;
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip44::Bip44,
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+use iota_sdk::{
+    client::{
+        api::{GetAddressesOptions, PreparedTransactionData},
+        secret::{
+            default_sign_transaction, default_sign_transaction_essence, CustomSecretManager, GenerateAddressOptions,
+            SecretManage, SecretManager,
+        },
+        Client, Error, Result,
+    },
+    types::block::{
+        address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
+    },
+};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PublicKeyResponse {
+    public_key: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignResponse {
+    public_key: String,
+    signature: String,
+}
+
+/// A secret manager signing with the keys of a remote signing service.
+struct RemoteSigner {
+    http_client: reqwest::Client,
+    url: String,
+    token: String,
+}
+
+impl RemoteSigner {
+    fn new(url: impl Into<String>, token: impl Into<String>) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            url: url.into(),
+            token: token.into(),
+        }
+    }
+
+    async fn post<T: serde::de::DeserializeOwned>(&self, path: &str, body: serde_json::Value) -> Result<T> {
+        let response = self
+            .http_client
+            .post(format!("{}/{path}", self.url))
+            .bearer_auth(&self.token)
+            .json(&body)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| Error::Node(e.into()))?;
+
+        response.json().await.map_err(|e| Error::Node(e.into()))
+    }
+}
+
+fn chain_json(chain: Bip44) -> serde_json::Value {
+    json!([chain.coin_type, chain.account, chain.change, chain.address_index])
+}
+
+#[async_trait]
+impl SecretManage for RemoteSigner {
+    type Error = Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>> {
+        let internal = options.into().map(|o| o.internal).unwrap_or_default();
+        let mut addresses = Vec::new();
+
+        for address_index in address_indexes {
+            let chain = Bip44::new(coin_type)
+                .with_account(account_index)
+                .with_change(internal as _)
+                .with_address_index(address_index);
+            let response: PublicKeyResponse = self.post("public-key", json!({ "chain": chain_json(chain) })).await?;
+            let public_key: [u8; 32] = prefix_hex::decode(response.public_key)?;
+
+            // Hash the public key to get the address
+            addresses.push(Ed25519Address::new(Blake2b256::digest(public_key).into()));
+        }
+
+        Ok(addresses)
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>> {
+        // The signing service only holds Ed25519 keys
+        Err(Error::SecretManagerMismatch)
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature> {
+        let response: SignResponse = self
+            .post(
+                "sign",
+                json!({ "chain": chain_json(chain), "message": prefix_hex::encode(msg) }),
+            )
+            .await?;
+
+        Ok(Ed25519Signature::try_from_bytes(
+            prefix_hex::decode(response.public_key)?,
+            prefix_hex::decode(response.signature)?,
+        )?)
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _msg: &[u8],
+        _chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature)> {
+        Err(Error::SecretManagerMismatch)
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks> {
+        default_sign_transaction_essence(self, prepared_transaction_data, time).await
+    }
+
+    async fn sign_transaction(&self, prepared_transaction_data: PreparedTransactionData) -> Result<TransactionPayload> {
+        default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // This example uses secrets in environment variables for simplicity which should not be done in production.
+    dotenvy::dotenv().ok();
+
+    let amount = std::env::args()
+        .nth(1)
+        .map(|s| s.parse::<u64>().unwrap())
+        .unwrap_or(1_000_000u64);
+
+    // Create a node client.
+    let client = Client::builder()
+        .with_node(&std::env::var("NODE_URL").unwrap())?
+        .finish()
+        .await?;
+
+    let secret_manager = SecretManager::Custom(CustomSecretManager::new(RemoteSigner::new(
+        std::env::var("REMOTE_SIGNER_URL").unwrap(),
+        std::env::var("REMOTE_SIGNER_TOKEN").unwrap(),
+    )));
+
+    // Get the first address of the remote signer
+    let first_address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(0..1))
+        .await?[0];
+    println!("1st address: {first_address}");
+
+    // Send the amount to the first address, the remote signer signs the transaction
+    let block = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_output(first_address, amount)
+        .await?
+        .finish()
+        .await?;
+
+    println!(
+        "Transaction sent: {}/block/{}",
+        std::env::var("EXPLORER_URL").unwrap(),
+        block.id()
+    );
+
+    Ok(())
+}
@@ -18,16 +18,13 @@ use iota_sdk::{
     types::block::{
         address::{Bech32Address, NftAddress},
         output::NftId,
-        payload::transaction::TransactionId,
     },
-    wallet::{Account, MintNftParams, Result},
+    wallet::{MintNftParams, Result},
     Wallet,
 };
 
 // The NFT collection size
 const NFT_COLLECTION_SIZE: usize = 150;
-// Mint NFTs in chunks since the transaction size is limited
-const NUM_NFTS_MINTED_PER_TRANSACTION: usize = 50;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -66,18 +63,15 @@ async fn main() -> Result<()> {
         })
         .collect::<Vec<_>>();
 
-    for (index, nft_mint_params) in nft_mint_params.chunks(NUM_NFTS_MINTED_PER_TRANSACTION).enumerate() {
+    // The NFTs are minted in as many transactions as needed, since the transaction size is limited
+    println!("Minting {NFT_COLLECTION_SIZE} NFTs...");
+    let transactions = account.mint_nfts_in_batches(nft_mint_params, None).await?;
+    for transaction in transactions {
         println!(
-            "Minting {} NFTs... ({}/{})",
-            nft_mint_params.len(),
-            index * NUM_NFTS_MINTED_PER_TRANSACTION + nft_mint_params.len(),
-            NFT_COLLECTION_SIZE
+            "Transaction sent: {}/transaction/{}",
+            std::env::var("EXPLORER_URL").unwrap(),
+            transaction.transaction_id
         );
-        let transaction = account.mint_nfts(nft_mint_params.to_vec(), None).await?;
-        wait_for_inclusion(&transaction.transaction_id, &account).await?;
-
-        // Sync so the new outputs are available again for new transactions
-        account.sync(None).await?;
     }
 
     // After the NFTs are minted, the issuer nft can be sent to the so called "null address"
@@ -105,21 +99,3 @@ fn get_immutable_metadata(index: usize, issuer_nft_id: NftId) -> String {
     }}"#
     )).unwrap().to_string()
 }
-
-async fn wait_for_inclusion(transaction_id: &TransactionId, account: &Account) -> Result<()> {
-    println!(
-        "Transaction sent: {}/transaction/{}",
-        std::env::var("EXPLORER_URL").unwrap(),
-        transaction_id
-    );
-    // Wait for transaction to get included
-    let block_id = account
-        .retry_transaction_until_included(transaction_id, None, None)
-        .await?;
-    println!(
-        "Block included: {}/block/{}",
-        std::env::var("EXPLORER_URL").unwrap(),
-        block_id
-    );
-    Ok(())
-}
@@ -76,9 +76,10 @@ impl<'a> ClientBlockBuilder<'a> {
         let mut available_inputs = self.get_utxo_chains_inputs(self.outputs.iter()).await?;
 
         let required_inputs_for_sender_or_issuer = self.get_inputs_for_sender_and_issuer(&available_inputs).await?;
-        let required_inputs_for_sender_or_issuer_ids = required_inputs_for_sender_or_issuer
+        let required_inputs_ids = required_inputs_for_sender_or_issuer
             .iter()
             .map(|input| *input.output_id())
+            .chain(self.required_inputs.iter().copied())
             .collect::<HashSet<_>>();
 
         available_inputs.extend(required_inputs_for_sender_or_issuer);
@@ -108,13 +109,18 @@ impl<'a> ClientBlockBuilder<'a> {
             available_input_addresses.clone(),
             protocol_parameters.clone(),
         )
-        .required_inputs(required_inputs_for_sender_or_issuer_ids.clone())
+        .required_inputs(required_inputs_ids.clone())
+        .forbidden_inputs(self.forbidden_inputs.clone())
         .timestamp(current_time);
 
         if let Some(address) = self.custom_remainder_address {
             input_selection = input_selection.remainder_address(address);
         }
 
+        if let Some(burn) = &self.burn {
+            input_selection = input_selection.burn(burn.clone());
+        }
+
         if let Ok(selected_transaction_data) = input_selection.select() {
             return Ok(selected_transaction_data);
         }
@@ -194,13 +200,18 @@ impl<'a> ClientBlockBuilder<'a> {
                         available_input_addresses.clone(),
                         protocol_parameters.clone(),
                     )
-                    .required_inputs(required_inputs_for_sender_or_issuer_ids.clone())
+                    .required_inputs(required_inputs_ids.clone())
+                    .forbidden_inputs(self.forbidden_inputs.clone())
                     .timestamp(current_time);
 
                     if let Some(address) = self.custom_remainder_address {
                         input_selection = input_selection.remainder_address(address);
                     }
 
+                    if let Some(burn) = &self.burn {
+                        input_selection = input_selection.burn(burn.clone());
+                    }
+
                     let selected_transaction_data = match input_selection.select() {
                         Ok(r) => r,
                         // for these errors, just try again in the next round with more addresses which might have more
@@ -217,6 +228,11 @@ impl<'a> ClientBlockBuilder<'a> {
                             cached_error.replace(Error::from(err));
                             continue;
                         }
+                        // The required input could be on an address that wasn't searched yet
+                        Err(err @ InputSelectionError::RequiredInputIsNotAvailable(_)) => {
+                            cached_error.replace(Error::from(err));
+                            continue;
+                        }
                         // Not enough balance for a remainder.
                         Err(InputSelectionError::Block(block_error)) => match block_error {
                             crate::types::block::Error::InvalidStorageDepositAmount { .. } => {
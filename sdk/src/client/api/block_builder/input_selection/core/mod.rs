@@ -5,6 +5,7 @@ pub(crate) mod burn;
 pub(crate) mod error;
 pub(crate) mod remainder;
 pub(crate) mod requirement;
+pub(crate) mod strategy;
 pub(crate) mod transition;
 
 use core::ops::Deref;
@@ -17,6 +18,7 @@ pub use self::{
     burn::{Burn, BurnDto},
     error::Error,
     requirement::Requirement,
+    strategy::{BranchAndBound, InputSelectionStrategy, LargestFirst, MinimalFragmentation},
 };
 use crate::{
     client::{api::types::RemainderData, secret::types::InputSigningData},
@@ -46,6 +48,7 @@ pub struct InputSelection {
     timestamp: u32,
     requirements: Vec<Requirement>,
     automatically_transitioned: HashMap<ChainId, Option<AliasTransition>>,
+    strategy: Option<Box<dyn InputSelectionStrategy>>,
 }
 
 /// Result of the input selection algorithm.
@@ -165,7 +168,9 @@ impl InputSelection {
         addresses: impl IntoIterator<Item = Address>,
         protocol_parameters: ProtocolParameters,
     ) -> Self {
-        let available_inputs = available_inputs.into();
+        let mut available_inputs: Vec<InputSigningData> = available_inputs.into();
+        // Inputs that are equally good are always selected in the same order, independent of where they come from
+        available_inputs.sort_unstable_by_key(|input| *input.output_id());
         let mut addresses = HashSet::from_iter(addresses);
 
         addresses.extend(available_inputs.iter().filter_map(|input| match &input.output {
@@ -191,18 +196,19 @@ impl InputSelection {
             timestamp: unix_timestamp_now().as_secs() as u32,
             requirements: Vec::new(),
             automatically_transitioned: HashMap::new(),
+            strategy: None,
         }
     }
 
     /// Sets the required inputs of an [`InputSelection`].
-    pub fn required_inputs(mut self, inputs: impl Into<HashSet<OutputId>>) -> Self {
-        self.required_inputs = inputs.into();
+    pub fn required_inputs(mut self, inputs: impl IntoIterator<Item = OutputId>) -> Self {
+        self.required_inputs = inputs.into_iter().collect();
         self
     }
 
     /// Sets the forbidden inputs of an [`InputSelection`].
-    pub fn forbidden_inputs(mut self, inputs: HashSet<OutputId>) -> Self {
-        self.forbidden_inputs = inputs;
+    pub fn forbidden_inputs(mut self, inputs: impl IntoIterator<Item = OutputId>) -> Self {
+        self.forbidden_inputs = inputs.into_iter().collect();
         self
     }
 
@@ -218,6 +224,12 @@ impl InputSelection {
         self
     }
 
+    /// Sets the strategy of an [`InputSelection`] to order the inputs used to fulfill the amount requirement.
+    pub fn strategy(mut self, strategy: impl InputSelectionStrategy + 'static) -> Self {
+        self.strategy = Some(Box::new(strategy));
+        self
+    }
+
     /// Sets the timestamp of an [`InputSelection`].
     pub fn timestamp(mut self, timestamp: u32) -> Self {
         self.timestamp = timestamp;
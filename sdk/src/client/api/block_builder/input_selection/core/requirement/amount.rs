@@ -299,10 +299,15 @@ impl InputSelection {
 
         // Try to select outputs first with ordering from low to high amount, if that fails, try reversed.
 
-        log::debug!("Ordering inputs from low to high amount");
-        // Sort inputs per amount, low to high.
-        self.available_inputs
-            .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+        if let Some(strategy) = &self.strategy {
+            log::debug!("Ordering inputs with {strategy:?}");
+            strategy.order_inputs(&mut self.available_inputs, amount_selection.missing_amount());
+        } else {
+            log::debug!("Ordering inputs from low to high amount");
+            // Sort inputs per amount, low to high.
+            self.available_inputs
+                .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+        }
 
         if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
             return Ok(r);
@@ -0,0 +1,163 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+
+use crate::{client::secret::types::InputSigningData, types::block::output::Output};
+
+/// Decides in which order the available inputs are used to fulfill the amount requirement of an
+/// [`InputSelection`](super::InputSelection).
+///
+/// Inputs are selected in the returned order until the amount of the outputs, the storage deposit of a potential
+/// remainder and storage deposits that need to be returned are covered, so a strategy doesn't need to care about these.
+/// Inputs without native tokens and storage deposit return unlock conditions are still preferred over the others.
+pub trait InputSelectionStrategy: Debug + Send + Sync {
+    /// Orders the available inputs, `missing_amount` is the amount that is missing before any input is selected.
+    fn order_inputs(&self, inputs: &mut [InputSigningData], missing_amount: u64);
+}
+
+/// Uses the inputs with the largest amount first, which results in the lowest amount of inputs.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct LargestFirst;
+
+impl InputSelectionStrategy for LargestFirst {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], _missing_amount: u64) {
+        inputs.sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+    }
+}
+
+/// Uses the inputs with the smallest amount first, which consolidates small outputs and keeps the amount of outputs
+/// of the addresses low. This is the order used if no strategy is set.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct MinimalFragmentation;
+
+impl InputSelectionStrategy for MinimalFragmentation {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], _missing_amount: u64) {
+        inputs.sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+    }
+}
+
+/// Searches for a set of plain basic inputs that exactly matches the missing amount, so that no remainder output needs
+/// to be created. If no such set is found within `max_tries` steps, it falls back to [`LargestFirst`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BranchAndBound {
+    /// The maximum amount of steps of the search.
+    pub max_tries: usize,
+}
+
+impl BranchAndBound {
+    /// The default maximum amount of steps of the search.
+    pub const DEFAULT_MAX_TRIES: usize = 100_000;
+}
+
+impl Default for BranchAndBound {
+    fn default() -> Self {
+        Self {
+            max_tries: Self::DEFAULT_MAX_TRIES,
+        }
+    }
+}
+
+impl InputSelectionStrategy for BranchAndBound {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], missing_amount: u64) {
+        LargestFirst.order_inputs(inputs, missing_amount);
+
+        if missing_amount == 0 {
+            return;
+        }
+
+        // Only inputs that don't need a remainder for native tokens or a storage deposit return can match exactly
+        let candidates = inputs
+            .iter()
+            .enumerate()
+            .filter(|(_, input)| match &input.output {
+                Output::Basic(basic) => {
+                    basic.native_tokens().is_empty() && basic.unlock_conditions().storage_deposit_return().is_none()
+                }
+                _ => false,
+            })
+            .map(|(index, input)| (index, input.output.amount()))
+            .collect::<Vec<_>>();
+
+        if let Some(selection) = find_exact_match(&candidates, missing_amount, self.max_tries) {
+            log::debug!(
+                "Found an exact match for {missing_amount} with {} inputs",
+                selection.len()
+            );
+            // Move the matching inputs to the front, keeping the largest first order for the others
+            let mut position = 0;
+            for index in selection {
+                inputs[position..=index].rotate_right(1);
+                position += 1;
+            }
+        }
+    }
+}
+
+// Depth first search over the candidates sorted by amount from high to low, returns the indexes of the selected
+// candidates in ascending order.
+fn find_exact_match(candidates: &[(usize, u64)], target: u64, max_tries: usize) -> Option<Vec<usize>> {
+    // The sum of all candidates from a position on, to stop early if the target can't be reached anymore
+    let mut remaining_sums = vec![0; candidates.len() + 1];
+    for position in (0..candidates.len()).rev() {
+        remaining_sums[position] = remaining_sums[position + 1] + candidates[position].1;
+    }
+
+    let mut search = ExactMatchSearch {
+        candidates,
+        remaining_sums,
+        selection: Vec::new(),
+        tries_left: max_tries,
+    };
+
+    search.search(0, target).then_some(search.selection)
+}
+
+struct ExactMatchSearch<'a> {
+    candidates: &'a [(usize, u64)],
+    remaining_sums: Vec<u64>,
+    selection: Vec<usize>,
+    tries_left: usize,
+}
+
+impl ExactMatchSearch<'_> {
+    fn search(&mut self, position: usize, missing: u64) -> bool {
+        if missing == 0 {
+            return true;
+        }
+        if self.tries_left == 0 || position == self.candidates.len() || self.remaining_sums[position] < missing {
+            return false;
+        }
+        self.tries_left -= 1;
+
+        let (index, amount) = self.candidates[position];
+        if amount <= missing {
+            self.selection.push(index);
+            if self.search(position + 1, missing - amount) {
+                return true;
+            }
+            self.selection.pop();
+        }
+
+        self.search(position + 1, missing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match() {
+        let candidates = [(0, 50), (1, 30), (2, 20), (4, 7), (5, 3)];
+
+        assert_eq!(find_exact_match(&candidates, 50, 100), Some(vec![0]));
+        assert_eq!(find_exact_match(&candidates, 53, 100), Some(vec![0, 5]));
+        assert_eq!(find_exact_match(&candidates, 57, 100), Some(vec![0, 4]));
+        assert_eq!(find_exact_match(&candidates, 110, 100), Some(vec![0, 1, 2, 4, 5]));
+        assert_eq!(find_exact_match(&candidates, 111, 100), None);
+        assert_eq!(find_exact_match(&candidates, 49, 100), None);
+        // The search is bounded
+        assert_eq!(find_exact_match(&candidates, 10, 2), None);
+    }
+}
@@ -119,6 +119,7 @@ impl<'a> ClientBlockBuilder<'a> {
             protocol_parameters.clone(),
         )
         .required_inputs(required_inputs)
+        .forbidden_inputs(self.forbidden_inputs.clone())
         .timestamp(current_time);
 
         if let Some(address) = self.custom_remainder_address {
@@ -10,4 +10,7 @@ mod sender_issuer;
 mod utxo_chains;
 
 pub(crate) use self::core::is_alias_transition;
-pub use self::core::{Burn, BurnDto, Error, InputSelection, Requirement, Selected};
+pub use self::core::{
+    BranchAndBound, Burn, BurnDto, Error, InputSelection, InputSelectionStrategy, LargestFirst, MinimalFragmentation,
+    Requirement, Selected,
+};
@@ -158,6 +158,36 @@ impl<'a> ClientBlockBuilder<'a> {
             }
         }
 
+        // Chains to burn have no output, so get their inputs from the burn
+        if let Some(burn) = &self.burn {
+            for alias_id in burn.aliases() {
+                let output_id = client.alias_output_id(*alias_id).await?;
+                let input = client.get_output(&output_id).await?;
+                if let Output::Alias(alias_input) = input.output() {
+                    // An alias is destroyed with a governance transition
+                    utxo_chains.push((*alias_input.governor_address(), input));
+                }
+            }
+            for nft_id in burn.nfts() {
+                let output_id = client.nft_output_id(*nft_id).await?;
+                let input = client.get_output(&output_id).await?;
+                if let Output::Nft(nft_input) = input.output() {
+                    let unlock_address = nft_input
+                        .unlock_conditions()
+                        .locked_address(nft_input.address(), current_time);
+
+                    utxo_chains.push((*unlock_address, input));
+                }
+            }
+            for foundry_id in burn.foundries() {
+                let output_id = client.foundry_output_id(*foundry_id).await?;
+                let input = client.get_output(&output_id).await?;
+                if let Output::Foundry(foundry_input) = input.output() {
+                    utxo_chains.push((Address::Alias(*foundry_input.alias_address()), input));
+                }
+            }
+        }
+
         // Get recursively owned alias or nft outputs
         get_alias_and_nft_outputs_recursively(self.client, &mut utxo_chains).await?;
 
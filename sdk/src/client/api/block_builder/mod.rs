@@ -5,24 +5,26 @@ pub mod input_selection;
 pub mod pow;
 pub mod transaction;
 
-use std::ops::Range;
+use std::{collections::HashSet, ops::Range};
 
 use packable::bounded::TryIntoBoundedU16Error;
 use serde::{Deserialize, Serialize};
 
-use self::input_selection::BurnDto;
 pub use self::transaction::verify_semantic;
+use self::{input_selection::BurnDto, pow::PowCancel};
 use crate::{
     client::{
-        api::block_builder::input_selection::Burn, constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Error,
-        Result,
+        api::{block_builder::input_selection::Burn, DryRunTransaction},
+        constants::SHIMMER_COIN_TYPE,
+        secret::SecretManager,
+        Client, Error, Result,
     },
     types::{
         block::{
             address::{Address, Bech32Address, Ed25519Address},
             input::{dto::UtxoInputDto, UtxoInput, INPUT_COUNT_MAX},
             output::{
-                dto::OutputDto, unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output,
+                dto::OutputDto, unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, OutputId,
                 OUTPUT_COUNT_RANGE,
             },
             parent::Parents,
@@ -43,12 +45,16 @@ pub struct ClientBlockBuilder<'a> {
     initial_address_index: u32,
     inputs: Option<Vec<UtxoInput>>,
     input_range: Range<u32>,
+    required_inputs: HashSet<OutputId>,
+    forbidden_inputs: HashSet<OutputId>,
     outputs: Vec<Output>,
     custom_remainder_address: Option<Address>,
     tag: Option<Vec<u8>>,
     data: Option<Vec<u8>>,
     parents: Option<Parents>,
     burn: Option<Burn>,
+    pow_cancel: Option<PowCancel>,
+    dry_run: bool,
 }
 
 /// Block output address
@@ -76,6 +82,10 @@ pub struct ClientBlockBuilderOptions {
     pub inputs: Option<Vec<UtxoInputDto>>,
     /// Input range
     pub input_range: Option<Range<u32>>,
+    /// Outputs that must be used as inputs
+    pub required_inputs: Option<Vec<OutputId>>,
+    /// Outputs that must not be used as inputs
+    pub forbidden_inputs: Option<Vec<OutputId>>,
     /// Bech32 encoded output address and amount
     pub output: Option<ClientBlockBuilderOutputAddress>,
     /// Hex encoded output address and amount
@@ -92,6 +102,8 @@ pub struct ClientBlockBuilderOptions {
     pub parents: Option<Vec<BlockId>>,
     /// Explicit burning of aliases, nfts, foundries and native tokens
     pub burn: Option<BurnDto>,
+    /// Only build the transaction, without signing or sending it
+    pub dry_run: Option<bool>,
 }
 
 impl<'a> ClientBlockBuilder<'a> {
@@ -105,12 +117,16 @@ impl<'a> ClientBlockBuilder<'a> {
             initial_address_index: 0,
             inputs: None,
             input_range: 0..100,
+            required_inputs: HashSet::new(),
+            forbidden_inputs: HashSet::new(),
             outputs: Vec::new(),
             custom_remainder_address: None,
             tag: None,
             data: None,
             parents: None,
             burn: None,
+            pow_cancel: None,
+            dry_run: false,
         }
     }
 
@@ -161,6 +177,19 @@ impl<'a> ClientBlockBuilder<'a> {
         self
     }
 
+    /// Set outputs that must be used as inputs with automatic input selection, which selects additional inputs if
+    /// they're not enough. They're only found if they're basic outputs on the searched addresses.
+    pub fn with_required_inputs(mut self, output_ids: impl IntoIterator<Item = OutputId>) -> Self {
+        self.required_inputs = output_ids.into_iter().collect();
+        self
+    }
+
+    /// Set outputs that must not be used as inputs, e.g. outputs of a dust attack.
+    pub fn with_forbidden_inputs(mut self, output_ids: impl IntoIterator<Item = OutputId>) -> Self {
+        self.forbidden_inputs = output_ids.into_iter().collect();
+        self
+    }
+
     /// Set a transfer to the builder
     pub async fn with_output(
         mut self,
@@ -168,7 +197,7 @@ impl<'a> ClientBlockBuilder<'a> {
         amount: u64,
     ) -> Result<ClientBlockBuilder<'a>> {
         let address = address.convert()?;
-        self.client.bech32_hrp_matches(address.hrp()).await?;
+        self.client.validate_recipient(&address, None, amount, false).await?;
 
         let output = BasicOutputBuilder::new_with_amount(amount)
             .add_unlock_condition(AddressUnlockCondition::new(address))
@@ -231,6 +260,20 @@ impl<'a> ClientBlockBuilder<'a> {
         Ok(self)
     }
 
+    /// Sets a token to stop the local PoW of the block with [`Error::PowCancelled`], instead of the one cancelled by
+    /// [`ClientInner::cancel_pow()`](crate::client::ClientInner::cancel_pow).
+    pub fn with_pow_cancel(mut self, pow_cancel: impl Into<Option<PowCancel>>) -> Self {
+        self.pow_cancel = pow_cancel.into();
+        self
+    }
+
+    /// Marks the block as a dry run: the transaction can then only be previewed with [`ClientBlockBuilder::dry_run()`]
+    /// and [`ClientBlockBuilder::finish()`] fails with [`Error::DryRun`] instead of signing and posting it.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
     /// Set multiple options from client block builder options type
     /// Useful for bindings
     pub async fn set_options(mut self, options: ClientBlockBuilderOptions) -> Result<ClientBlockBuilder<'a>> {
@@ -256,6 +299,14 @@ impl<'a> ClientBlockBuilder<'a> {
             self = self.with_input_range(input_range);
         }
 
+        if let Some(required_inputs) = options.required_inputs {
+            self = self.with_required_inputs(required_inputs);
+        }
+
+        if let Some(forbidden_inputs) = options.forbidden_inputs {
+            self = self.with_forbidden_inputs(forbidden_inputs);
+        }
+
         if let Some(output) = options.output {
             self = self
                 .with_output(
@@ -310,19 +361,41 @@ impl<'a> ClientBlockBuilder<'a> {
             self = self.with_burn(Burn::from(burn));
         }
 
+        if let Some(dry_run) = options.dry_run {
+            self = self.with_dry_run(dry_run);
+        }
+
         Ok(self)
     }
 
+    /// Builds the transaction like [`ClientBlockBuilder::finish()`], with input selection, output validation and
+    /// storage deposit checks, but without signing it, doing PoW or posting a block. Nodes are only requested to find
+    /// the inputs. The builder is kept, so the previewed transaction can be sent with it afterwards.
+    pub async fn dry_run(&self) -> Result<DryRunTransaction> {
+        if self.outputs.is_empty() && self.burn.is_none() {
+            return Err(Error::MissingParameter("output"));
+        }
+        if self.secret_manager.is_none() && self.inputs.is_none() {
+            return Err(Error::MissingParameter("seed"));
+        }
+
+        Ok(self.prepare_transaction().await?.into())
+    }
+
     /// Consume the builder, post the block to the node and get the API result
     pub async fn finish(self) -> Result<Block> {
+        if self.dry_run {
+            return Err(Error::DryRun);
+        }
         // tagged_data payload requires an tagged_data tag
         if self.data.is_some() && self.tag.is_none() {
             return Err(Error::MissingParameter("tag"));
         }
-        if self.inputs.is_some() && self.outputs.is_empty() {
+        if self.inputs.is_some() && self.outputs.is_empty() && self.burn.is_none() {
             return Err(Error::MissingParameter("output"));
         }
-        if !self.outputs.is_empty() {
+        // A burn without outputs still needs a transaction
+        if !self.outputs.is_empty() || self.burn.is_some() {
             if self.secret_manager.is_none() && self.inputs.is_none() {
                 return Err(Error::MissingParameter("seed"));
             }
@@ -360,7 +433,14 @@ impl<'a> ClientBlockBuilder<'a> {
     pub async fn finish_block(self, payload: Option<Payload>) -> Result<Block> {
         // Do not replace parents with the latest tips if they are set explicitly,
         // necessary for block promotion.
-        let final_block = self.client.finish_block_builder(self.parents, payload).await?;
+        let final_block = match self.pow_cancel {
+            Some(pow_cancel) => {
+                self.client
+                    .finish_block_builder_with_cancel(self.parents, payload, pow_cancel)
+                    .await?
+            }
+            None => self.client.finish_block_builder(self.parents, payload).await?,
+        };
 
         let block_id = self.client.post_block_raw(&final_block).await?;
         // Get block if we use remote PoW, because the node will change parents and nonce
@@ -3,21 +3,137 @@
 
 //! PoW functions.
 
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use futures::future::Either;
+use packable::PackableExt;
+
 #[cfg(not(target_family = "wasm"))]
-use crate::pow::miner::{Miner, MinerBuilder, MinerCancel};
+use crate::pow::miner::{Miner, MinerBuilder, MinerCancel, MinerProgressCallback};
 #[cfg(target_family = "wasm")]
 use crate::pow::wasm_miner::{SingleThreadedMiner, SingleThreadedMinerBuilder};
 use crate::{
     client::{ClientInner, Error, Result},
+    pow::score::PowScorer,
     types::block::{parent::Parents, payload::Payload, Block, BlockBuilder, Error as BlockError},
 };
 
+/// A provider of the nonces for the local PoW, to use e.g. GPU or FPGA miners or an external PoW service instead of the
+/// built-in miner.
+#[async_trait]
+pub trait PowProvider: core::fmt::Debug + Send + Sync {
+    /// Returns a nonce reaching the `min_pow_score` for the serialized block without its nonce, or `None` if the PoW
+    /// got cancelled before a nonce was found. The PoW is cancelled when the tips interval elapsed, to restart it with
+    /// new tips, so providers should regularly check the `cancel` token. The hash rate can be reported with
+    /// `progress`. Blocks with a nonce that doesn't reach the `min_pow_score` fail with [`Error::InvalidPowNonce`].
+    async fn nonce(
+        &self,
+        block_bytes: &[u8],
+        min_pow_score: u32,
+        cancel: PowCancel,
+        progress: PowProgress,
+    ) -> Result<Option<u64>>;
+}
+
+/// A token to cancel the PoW of a [`PowProvider`].
+#[derive(Clone, Debug, Default)]
+pub struct PowCancel(Arc<AtomicBool>);
+
+impl PowCancel {
+    /// Creates a new token that isn't cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the PoW.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether the PoW was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Reports the hash rate of a [`PowProvider`] to the PoW progress callback of the client, if it has one.
+#[derive(Clone, Default)]
+pub struct PowProgress(Option<Arc<dyn Fn(f64) + Send + Sync>>);
+
+impl PowProgress {
+    /// Reports the current hash rate in hashes per second.
+    pub fn report(&self, hash_rate: f64) {
+        if let Some(callback) = &self.0 {
+            callback(hash_rate);
+        }
+    }
+}
+
+impl core::fmt::Debug for PowProgress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PowProgress").field(&self.0.is_some()).finish()
+    }
+}
+
+/// A shareable [`PowProvider`], providers are equal if they are the same instance.
+#[derive(Clone, Debug)]
+pub(crate) struct SharedPowProvider(pub(crate) Arc<dyn PowProvider>);
+
+impl PartialEq for SharedPowProvider {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SharedPowProvider {}
+
+/// A shareable callback receiving the hash rate of the local PoW, callbacks are equal if they are the same instance.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Clone)]
+pub(crate) struct SharedPowProgressCallback(pub(crate) MinerProgressCallback);
+
+#[cfg(not(target_family = "wasm"))]
+impl core::fmt::Debug for SharedPowProgressCallback {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SharedPowProgressCallback")
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl PartialEq for SharedPowProgressCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Eq for SharedPowProgressCallback {}
+
 impl ClientInner {
     /// Finishes the block with local PoW if needed.
     /// Without local PoW, it will finish the block with a 0 nonce.
     pub async fn finish_block_builder(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+        self.finish_block_builder_with_cancel(parents, payload, self.pow_cancel().await)
+            .await
+    }
+
+    /// Like [`ClientInner::finish_block_builder()`], but stops the local PoW with [`Error::PowCancelled`] once the
+    /// `cancel` token is cancelled.
+    pub async fn finish_block_builder_with_cancel(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        cancel: PowCancel,
+    ) -> Result<Block> {
         if self.get_local_pow().await {
-            self.finish_pow(parents, payload).await
+            self.finish_pow_with_cancel(parents, payload, cancel).await
         } else {
             // Finish block without doing PoW.
             let parents = match parents {
@@ -29,36 +145,147 @@ impl ClientInner {
         }
     }
 
-    /// Calls the appropriate PoW function depending whether the compilation is for wasm or not.
+    /// Calls the PoW provider of the client if there is one, otherwise the appropriate PoW function depending whether
+    /// the compilation is for wasm or not.
     pub async fn finish_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+        self.finish_pow_with_cancel(parents, payload, self.pow_cancel().await)
+            .await
+    }
+
+    /// Stops the running local PoW of all blocks that are finished without an own cancel token, e.g. by
+    /// [`ClientInner::post_block()`] falling back to local PoW, with [`Error::PowCancelled`]. The PoW of blocks that
+    /// are finished afterwards isn't affected.
+    pub async fn cancel_pow(&self) {
+        let mut pow_cancel = self.pow_cancel.write().await;
+        pow_cancel.cancel();
+        *pow_cancel = PowCancel::new();
+    }
+
+    /// Returns the token cancelled by [`ClientInner::cancel_pow()`].
+    pub(crate) async fn pow_cancel(&self) -> PowCancel {
+        self.pow_cancel.read().await.clone()
+    }
+
+    /// Like [`ClientInner::finish_pow()`], but stops the PoW with [`Error::PowCancelled`] once the `cancel` token is
+    /// cancelled, e.g. because the user aborted it or a remote PoW returned a block first.
+    pub async fn finish_pow_with_cancel(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        cancel: PowCancel,
+    ) -> Result<Block> {
+        if let Some(pow_provider) = &self.pow_provider {
+            return self
+                .finish_provider_pow(&*pow_provider.0, parents, payload, &cancel)
+                .await;
+        }
+
         #[cfg(not(target_family = "wasm"))]
-        let block = self.finish_multi_threaded_pow(parents, payload).await?;
+        let block = self.finish_multi_threaded_pow(parents, payload, cancel).await?;
         #[cfg(target_family = "wasm")]
-        let block = self.finish_single_threaded_pow(parents, payload).await?;
+        let block = self.finish_single_threaded_pow(parents, payload, &cancel).await?;
 
         Ok(block)
     }
 
-    /// Performs multi-threaded proof-of-work.
+    /// Performs proof-of-work with a [`PowProvider`].
+    ///
+    /// Cancels the PoW and fetches new tips after each tips interval elapses if no parents are provided.
+    async fn finish_provider_pow(
+        &self,
+        pow_provider: &dyn PowProvider,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        cancel: &PowCancel,
+    ) -> Result<Block> {
+        let min_pow_score = self.get_min_pow_score().await?;
+        let tips_interval = self.get_tips_interval().await;
+        #[cfg(not(target_family = "wasm"))]
+        let progress = PowProgress(self.pow_progress_callback.as_ref().map(|callback| callback.0.clone()));
+        #[cfg(target_family = "wasm")]
+        let progress = PowProgress::default();
+
+        loop {
+            if cancel.is_cancelled() {
+                return Err(Error::PowCancelled);
+            }
+            let parents = match &parents {
+                Some(parents) => parents.clone(),
+                None => Parents::from_vec(self.get_tips().await?)?,
+            };
+            let block_bytes = BlockBuilder::new(parents.clone())
+                .with_payload(payload.clone())
+                .finish()?
+                .pack_to_vec();
+            let block_bytes = &block_bytes[..block_bytes.len() - core::mem::size_of::<u64>()];
+
+            let provider_cancel = PowCancel::new();
+            let nonce = pow_provider.nonce(block_bytes, min_pow_score, provider_cancel.clone(), progress.clone());
+            let timeout = pow_timeout_future(tips_interval, cancel);
+            futures::pin_mut!(nonce, timeout);
+            let nonce = match futures::future::select(nonce, timeout).await {
+                Either::Left((nonce, _)) => nonce?,
+                // Give the provider the chance to stop instead of dropping it in the middle of the PoW
+                Either::Right((_, nonce)) => {
+                    provider_cancel.cancel();
+                    nonce.await?
+                }
+            };
+
+            if let Some(nonce) = nonce {
+                let block = BlockBuilder::new(parents)
+                    .with_payload(payload)
+                    .with_nonce(nonce)
+                    .finish()?;
+                // Nodes would reject the block, so a wrong nonce is an error of the provider
+                let score = PowScorer::new().score(&block.pack_to_vec());
+                if score < min_pow_score as f64 {
+                    return Err(Error::InvalidPowNonce {
+                        nonce,
+                        score,
+                        min_pow_score,
+                    });
+                }
+
+                return Ok(block);
+            }
+        }
+    }
+
+    /// Performs multi-threaded proof-of-work, the `pow_worker_count` workers search separate ranges of nonces in
+    /// parallel.
     ///
     /// Always fetches new tips after each tips interval elapses if no parents are provided.
     #[cfg(not(target_family = "wasm"))]
-    async fn finish_multi_threaded_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+    async fn finish_multi_threaded_pow(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        cancel: PowCancel,
+    ) -> Result<Block> {
         let pow_worker_count = *self.pow_worker_count.read().await;
+        let progress_callback = self.pow_progress_callback.as_ref().map(|callback| callback.0.clone());
         let min_pow_score = self.get_min_pow_score().await?;
         let tips_interval = self.get_tips_interval().await;
 
         loop {
-            let cancel = MinerCancel::new();
+            if cancel.is_cancelled() {
+                return Err(Error::PowCancelled);
+            }
+            let miner_cancel = MinerCancel::new();
+            let miner_cancel_2 = miner_cancel.clone();
             let cancel_2 = cancel.clone();
+            let progress_callback_ = progress_callback.clone();
             let payload_ = payload.clone();
             let parents = match &parents {
                 Some(parents) => parents.clone(),
                 None => Parents::from_vec(self.get_tips().await?)?,
             };
-            let time_thread = std::thread::spawn(move || Ok(pow_timeout(tips_interval, cancel)));
+            let time_thread = std::thread::spawn(move || Ok(pow_timeout(tips_interval, miner_cancel, cancel_2)));
             let pow_thread = std::thread::spawn(move || {
-                let mut client_miner = MinerBuilder::new().with_cancel(cancel_2);
+                let mut client_miner = MinerBuilder::new()
+                    .with_cancel(miner_cancel_2)
+                    .with_progress_callback(progress_callback_);
                 if let Some(worker_count) = pow_worker_count {
                     client_miner = client_miner.with_num_workers(worker_count);
                 }
@@ -86,11 +313,20 @@ impl ClientInner {
     ///
     /// Fetches new tips after each tips interval elapses if no parents are provided.
     #[cfg(target_family = "wasm")]
-    async fn finish_single_threaded_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+    async fn finish_single_threaded_pow(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        cancel: &PowCancel,
+    ) -> Result<Block> {
         let min_pow_score: u32 = self.get_min_pow_score().await?;
         let tips_interval: u64 = self.get_tips_interval().await;
 
         loop {
+            // The single-threaded miner can only be stopped between the tips intervals
+            if cancel.is_cancelled() {
+                return Err(Error::PowCancelled);
+            }
             let parents = match &parents {
                 Some(parents) => parents.clone(),
                 None => Parents::from_vec(self.get_tips().await?)?,
@@ -126,12 +362,38 @@ fn do_pow(
         .finish_nonce(|bytes| miner.nonce(bytes, min_pow_score))?)
 }
 
-// PoW timeout, if we reach this we will restart the PoW with new tips, so the final block will never be lazy.
+// How often the PoW timeouts check if the PoW got cancelled.
+const POW_CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+// PoW timeout of a PoW provider, if we reach this we will restart the PoW with new tips. Ends early if the PoW gets
+// cancelled.
+async fn pow_timeout_future(after_seconds: u64, cancel: &PowCancel) {
+    let timeout = instant::Instant::now() + Duration::from_secs(after_seconds);
+
+    while !cancel.is_cancelled() && instant::Instant::now() < timeout {
+        #[cfg(not(target_family = "wasm"))]
+        tokio::time::sleep(POW_CANCEL_CHECK_INTERVAL).await;
+        #[cfg(target_family = "wasm")]
+        gloo_timers::future::TimeoutFuture::new(POW_CANCEL_CHECK_INTERVAL.as_millis() as u32).await;
+    }
+}
+
+// PoW timeout, if we reach this we will restart the PoW with new tips, so the final block will never be lazy. Stops the
+// miner early if the PoW gets cancelled and ends once the miner found a nonce.
 #[cfg(not(target_family = "wasm"))]
-fn pow_timeout(after_seconds: u64, cancel: MinerCancel) -> Option<Block> {
-    std::thread::sleep(std::time::Duration::from_secs(after_seconds));
+fn pow_timeout(after_seconds: u64, miner_cancel: MinerCancel, cancel: PowCancel) -> Option<Block> {
+    let timeout = std::time::Instant::now() + Duration::from_secs(after_seconds);
 
-    cancel.trigger();
+    loop {
+        std::thread::sleep(POW_CANCEL_CHECK_INTERVAL);
 
-    None
+        // The miner cancels itself when it found a nonce
+        if miner_cancel.is_cancelled() {
+            return None;
+        }
+        if cancel.is_cancelled() || std::time::Instant::now() >= timeout {
+            miner_cancel.trigger();
+            return None;
+        }
+    }
 }
@@ -0,0 +1,223 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers to find and sweep the funds of Chrysalis era seeds and mnemonics
+
+use std::{collections::BTreeMap, ops::Range};
+
+use super::{GetAddressesOptions, PreparedTransactionData, ADDRESS_GAP_RANGE};
+use crate::{
+    client::{
+        constants::IOTA_COIN_TYPE, node_api::indexer::query_parameters::QueryParameter, secret::SecretManager, Client,
+        Result,
+    },
+    types::block::{
+        address::Bech32Address,
+        input::{UtxoInput, INPUT_COUNT_MAX},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokensBuilder, OutputId},
+        ConvertTo,
+    },
+};
+
+/// Options to scan the addresses of a Chrysalis era seed or mnemonic for funds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChrysalisScanOptions {
+    /// The coin types to scan, Chrysalis wallets used the IOTA coin type.
+    pub coin_types: Vec<u32>,
+    /// The account indexes to scan.
+    pub account_indexes: Range<u32>,
+    /// The address indexes to scan, for public and internal addresses.
+    pub address_range: Range<u32>,
+}
+
+impl Default for ChrysalisScanOptions {
+    fn default() -> Self {
+        Self {
+            coin_types: vec![IOTA_COIN_TYPE],
+            account_indexes: 0..1,
+            address_range: 0..ADDRESS_GAP_RANGE,
+        }
+    }
+}
+
+impl ChrysalisScanOptions {
+    /// Set the coin types to scan.
+    pub fn with_coin_types(mut self, coin_types: impl Into<Vec<u32>>) -> Self {
+        self.coin_types = coin_types.into();
+        self
+    }
+
+    /// Set the account indexes to scan.
+    pub fn with_account_indexes(mut self, account_indexes: Range<u32>) -> Self {
+        self.account_indexes = account_indexes;
+        self
+    }
+
+    /// Set the address indexes to scan.
+    pub fn with_address_range(mut self, address_range: Range<u32>) -> Self {
+        self.address_range = address_range;
+        self
+    }
+}
+
+/// The funds found on an address of a Chrysalis era seed or mnemonic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChrysalisAddressFunds {
+    /// The address.
+    pub address: Bech32Address,
+    /// The coin type the address was derived with.
+    pub coin_type: u32,
+    /// The account index the address was derived with.
+    pub account_index: u32,
+    /// Whether the address is an internal address.
+    pub internal: bool,
+    /// The address index.
+    pub address_index: u32,
+    /// The outputs that can be unlocked by the address without further unlock conditions.
+    pub output_ids: Vec<OutputId>,
+    /// The amount of the outputs.
+    pub amount: u64,
+}
+
+impl Client {
+    /// Derives the addresses of a Chrysalis era seed or mnemonic and returns the ones holding funds. The funds of
+    /// Chrysalis addresses were carried over to the Stardust ledger, so the client has to be connected to a node of
+    /// that network, which also needs to have the indexer plugin enabled.
+    pub async fn find_chrysalis_funds(
+        &self,
+        secret_manager: &SecretManager,
+        options: ChrysalisScanOptions,
+    ) -> Result<Vec<ChrysalisAddressFunds>> {
+        log::debug!("[find_chrysalis_funds]");
+        let bech32_hrp = self.get_bech32_hrp().await?;
+        let mut funds = Vec::new();
+
+        for coin_type in &options.coin_types {
+            for account_index in options.account_indexes.clone() {
+                let address_options = GetAddressesOptions::default()
+                    .with_coin_type(*coin_type)
+                    .with_account_index(account_index)
+                    .with_range(options.address_range.clone())
+                    .with_bech32_hrp(bech32_hrp);
+                let public_addresses = secret_manager
+                    .generate_ed25519_addresses(address_options.clone())
+                    .await?;
+                let internal_addresses = secret_manager
+                    .generate_ed25519_addresses(address_options.internal())
+                    .await?;
+
+                let addresses = public_addresses
+                    .into_iter()
+                    .map(|address| (address, false))
+                    .chain(internal_addresses.into_iter().map(|address| (address, true)));
+                for (position, (address, internal)) in addresses.enumerate() {
+                    // Outputs that can be unlocked by this address without further unlock conditions
+                    let output_ids = self
+                        .basic_output_ids([
+                            QueryParameter::Address(address),
+                            QueryParameter::HasExpiration(false),
+                            QueryParameter::HasTimelock(false),
+                            QueryParameter::HasStorageDepositReturn(false),
+                        ])
+                        .await?
+                        .items;
+                    if output_ids.is_empty() {
+                        continue;
+                    }
+
+                    let amount = self
+                        .get_outputs(&output_ids)
+                        .await?
+                        .iter()
+                        .map(|output_with_metadata| output_with_metadata.output().amount())
+                        .sum();
+                    let address_index = options.address_range.start + (position % options.address_range.len()) as u32;
+                    log::debug!("[find_chrysalis_funds] found {amount} on {address} with index {address_index}");
+
+                    funds.push(ChrysalisAddressFunds {
+                        address,
+                        coin_type: *coin_type,
+                        account_index,
+                        internal,
+                        address_index,
+                        output_ids,
+                        amount,
+                    });
+                }
+            }
+        }
+
+        Ok(funds)
+    }
+
+    /// Prepares the transactions that sweep the funds found with [`Client::find_chrysalis_funds()`] to a Stardust
+    /// address, e.g. of a new wallet. A transaction is prepared for every coin type and account, and for every
+    /// [`INPUT_COUNT_MAX`] outputs. The prepared transactions can be signed with
+    /// [`ClientBlockBuilder::sign_transaction()`](super::ClientBlockBuilder::sign_transaction) and sent one after the
+    /// other.
+    pub async fn prepare_chrysalis_sweep(
+        &self,
+        secret_manager: &SecretManager,
+        funds: &[ChrysalisAddressFunds],
+        address: impl ConvertTo<Bech32Address>,
+    ) -> Result<Vec<PreparedTransactionData>> {
+        log::debug!("[prepare_chrysalis_sweep]");
+        let address = address.convert()?;
+        self.bech32_hrp_matches(address.hrp()).await?;
+        let token_supply = self.get_token_supply().await?;
+
+        // The funds of an account can be swept together, as the inputs are searched in the same derivation path
+        let mut funds_per_account = BTreeMap::<(u32, u32), Vec<&ChrysalisAddressFunds>>::new();
+        for address_funds in funds {
+            funds_per_account
+                .entry((address_funds.coin_type, address_funds.account_index))
+                .or_default()
+                .push(address_funds);
+        }
+
+        let mut prepared_transactions = Vec::new();
+        for ((coin_type, account_index), account_funds) in funds_per_account {
+            // Safe to unwrap, every account has at least one address with funds
+            let first_address_index = account_funds.iter().map(|f| f.address_index).min().unwrap();
+            let last_address_index = account_funds.iter().map(|f| f.address_index).max().unwrap();
+            let output_ids = account_funds
+                .iter()
+                .flat_map(|address_funds| address_funds.output_ids.iter().copied())
+                .collect::<Vec<_>>();
+
+            for chunk in output_ids.chunks(INPUT_COUNT_MAX.into()) {
+                let mut block_builder = self
+                    .build_block()
+                    .with_secret_manager(secret_manager)
+                    .with_coin_type(coin_type)
+                    .with_account_index(account_index)
+                    .with_input_range(first_address_index..last_address_index + 1);
+                let mut total_amount = 0;
+                let mut total_native_tokens = NativeTokensBuilder::new();
+
+                for output_with_metadata in self.get_outputs(chunk).await? {
+                    block_builder =
+                        block_builder.with_input(UtxoInput::from(*output_with_metadata.metadata().output_id()))?;
+                    if let Some(native_tokens) = output_with_metadata.output().native_tokens() {
+                        total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                    }
+                    total_amount += output_with_metadata.output().amount();
+                }
+
+                let sweep_output = BasicOutputBuilder::new_with_amount(total_amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .with_native_tokens(total_native_tokens.finish()?)
+                    .finish_output(token_supply)?;
+
+                prepared_transactions.push(
+                    block_builder
+                        .with_outputs([sweep_output])?
+                        .prepare_transaction()
+                        .await?,
+                );
+            }
+        }
+
+        Ok(prepared_transactions)
+    }
+}
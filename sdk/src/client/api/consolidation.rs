@@ -20,6 +20,8 @@ impl Client {
         options: GetAddressesOptions,
     ) -> Result<Bech32Address> {
         let token_supply = self.get_token_supply().await?;
+        let coin_type = options.coin_type;
+        let account_index = options.account_index;
         let mut last_transfer_index = options.range.start;
         // use the start index as offset
         let offset = last_transfer_index;
@@ -64,7 +66,11 @@ impl Client {
                 self.bech32_hrp_matches(consolidation_address.hrp()).await?;
 
                 for chunk in outputs_chunks {
-                    let mut block_builder = self.build_block().with_secret_manager(secret_manager);
+                    let mut block_builder = self
+                        .build_block()
+                        .with_secret_manager(secret_manager)
+                        .with_coin_type(coin_type)
+                        .with_account_index(account_index);
                     let mut total_amount = 0;
                     let mut total_native_tokens = NativeTokensBuilder::new();
 
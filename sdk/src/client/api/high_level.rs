@@ -3,7 +3,7 @@
 
 use std::collections::HashSet;
 
-use futures::{StreamExt, TryStreamExt};
+use futures::{Stream, StreamExt, TryStreamExt};
 
 use crate::{
     client::{
@@ -12,7 +12,7 @@ use crate::{
             DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL, DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT, FIVE_MINUTES_IN_SECONDS,
         },
         error::{Error, Result},
-        node_api::indexer::query_parameters::QueryParameter,
+        node_api::{error::Error as NodeApiError, indexer::query_parameters::QueryParameter},
         Client,
     },
     types::{
@@ -20,18 +20,30 @@ use crate::{
         block::{
             address::Bech32Address,
             input::{Input, UtxoInput, INPUT_COUNT_MAX},
-            output::OutputWithMetadata,
+            output::{
+                unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, OutputId, OutputWithMetadata,
+            },
             parent::Parents,
             payload::{
-                transaction::{TransactionEssence, TransactionId},
+                transaction::{TransactionEssence, TransactionId, TransactionPayload},
                 Payload,
             },
+            semantic::{semantic_validation, ConflictReason, ValidationContext},
             Block, BlockId,
         },
     },
     utils::unix_timestamp_now,
 };
 
+/// Blocks returned by [`Client::find_blocks_skip_missing()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FoundBlocks {
+    /// The blocks that were found, in the order their responses arrived.
+    pub blocks: Vec<Block>,
+    /// The IDs of the blocks that weren't found.
+    pub missing: Vec<BlockId>,
+}
+
 impl Client {
     /// Get the inputs of a transaction for the given transaction id.
     pub async fn inputs_from_transaction_id(&self, transaction_id: &TransactionId) -> Result<Vec<OutputWithMetadata>> {
@@ -69,6 +81,99 @@ impl Client {
         futures::future::try_join_all(block_ids.iter().map(|block_id| self.get_block(block_id))).await
     }
 
+    /// Returns a stream of blocks for the provided block IDs, yielded as soon as their responses arrive, so not
+    /// necessarily in the order of the provided IDs. At most `concurrency` requests are in flight at the same time and
+    /// duplicated block IDs are only requested once.
+    pub fn find_blocks_stream<'a>(
+        &'a self,
+        block_ids: impl IntoIterator<Item = BlockId> + 'a,
+        concurrency: usize,
+    ) -> impl Stream<Item = (BlockId, Result<Block>)> + 'a {
+        let mut seen = HashSet::new();
+
+        futures::stream::iter(block_ids.into_iter().filter(move |block_id| seen.insert(*block_id)))
+            .map(move |block_id| async move { (block_id, self.get_block(&block_id).await) })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Find all blocks by provided block IDs with bounded concurrency, skipping the blocks that the node doesn't know
+    /// instead of failing. The IDs of the skipped blocks are reported in [`FoundBlocks::missing`], any other error is
+    /// returned.
+    pub async fn find_blocks_skip_missing(
+        &self,
+        block_ids: impl IntoIterator<Item = BlockId>,
+        concurrency: usize,
+    ) -> Result<FoundBlocks> {
+        let mut found_blocks = FoundBlocks::default();
+        let mut stream = Box::pin(self.find_blocks_stream(block_ids, concurrency));
+
+        while let Some((block_id, res)) = stream.next().await {
+            match res {
+                Ok(block) => found_blocks.blocks.push(block),
+                Err(Error::Node(NodeApiError::NotFound(_))) => found_blocks.missing.push(block_id),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(found_blocks)
+    }
+
+    /// Semantically validates a transaction payload against its inputs, as currently known by the node, and the
+    /// timestamp of the latest milestone. This allows rejecting an invalid transaction locally with the precise
+    /// [`ConflictReason`] instead of relying on the node.
+    pub async fn validate_transaction_payload(&self, transaction_payload: &TransactionPayload) -> Result<()> {
+        let TransactionEssence::Regular(essence) = transaction_payload.essence();
+        let mut inputs = Vec::with_capacity(essence.inputs().len());
+
+        for input in essence.inputs().iter() {
+            let Input::Utxo(input) = input else {
+                return Err(Error::TransactionSemantic(ConflictReason::SemanticValidationFailed));
+            };
+
+            match self.get_output(input.output_id()).await {
+                Ok(output_with_metadata) => {
+                    if output_with_metadata.metadata().is_spent() {
+                        return Err(Error::TransactionSemantic(ConflictReason::InputUtxoAlreadySpent));
+                    }
+                    inputs.push((*input.output_id(), output_with_metadata.into_output()));
+                }
+                Err(Error::Node(NodeApiError::NotFound(_))) => {
+                    return Err(Error::TransactionSemantic(ConflictReason::InputUtxoNotFound));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let milestone_timestamp = self.get_latest_milestone_timestamp().await?;
+
+        let inputs = inputs
+            .iter()
+            .map(|(output_id, output)| (output_id, output))
+            .collect::<Vec<(&OutputId, &Output)>>();
+        let context = ValidationContext::new(
+            &transaction_payload.id(),
+            essence,
+            inputs.iter().map(|(output_id, output)| (*output_id, *output)),
+            transaction_payload.unlocks(),
+            milestone_timestamp,
+        );
+
+        match semantic_validation(context, inputs.as_slice(), transaction_payload.unlocks())? {
+            ConflictReason::None => Ok(()),
+            conflict => Err(Error::TransactionSemantic(conflict)),
+        }
+    }
+
+    /// Posts a block after semantically validating its transaction payload, if any, with
+    /// [`Client::validate_transaction_payload()`].
+    pub async fn post_block_validated(&self, block: &Block) -> Result<BlockId> {
+        if let Some(Payload::Transaction(transaction_payload)) = block.payload() {
+            self.validate_transaction_payload(transaction_payload).await?;
+        }
+
+        self.post_block(block).await
+    }
+
     /// Retries (promotes or reattaches) a block for provided block id. Block should only be
     /// retried only if they are valid and haven't been confirmed for a while.
     pub async fn retry(&self, block_id: &BlockId) -> Result<(BlockId, Block)> {
@@ -166,8 +271,19 @@ impl Client {
     }
 
     /// Function to find inputs from addresses for a provided amount (useful for offline signing), ignoring outputs with
-    /// additional unlock conditions
+    /// additional unlock conditions. Inputs are only selected so that a remainder, if needed, can cover its storage
+    /// deposit. For input selection that is also aware of native tokens and other output types, use
+    /// [`InputSelection`](crate::client::api::input_selection::InputSelection).
     pub async fn find_inputs(&self, addresses: Vec<Bech32Address>, amount: u64) -> Result<Vec<UtxoInput>> {
+        // The minimum amount of a remainder output to the first address
+        let minimum_remainder_amount = match addresses.first() {
+            Some(address) => BasicOutputBuilder::new_with_minimum_storage_deposit(self.get_rent_structure().await?)
+                .add_unlock_condition(AddressUnlockCondition::new(*address))
+                .finish_output(self.get_token_supply().await?)?
+                .amount(),
+            None => 0,
+        };
+
         // Get outputs from node and select inputs
         let available_outputs = futures::stream::iter(addresses)
             .then(|address| {
@@ -209,7 +325,7 @@ impl Client {
             .enumerate()
         {
             // Break if we have enough funds and don't create dust for the remainder
-            if total_already_spent == amount || total_already_spent >= amount {
+            if total_already_spent == amount || total_already_spent >= amount + minimum_remainder_amount {
                 break;
             }
             selected_inputs.push(output_wrapper.0);
@@ -222,6 +338,12 @@ impl Client {
                 required: amount,
             })?;
         }
+        if total_already_spent != amount && total_already_spent < amount + minimum_remainder_amount {
+            return Err(InputSelectionError::InsufficientAmount {
+                found: total_already_spent,
+                required: amount + minimum_remainder_amount,
+            })?;
+        }
 
         Ok(selected_inputs)
     }
@@ -244,7 +366,7 @@ impl Client {
         let reattach_block = self.finish_block_builder(None, block.payload().cloned()).await?;
 
         // Post the modified
-        let block_id = self.post_block_raw(&reattach_block).await?;
+        let block_id = self.post_reattachment(&reattach_block).await?;
         // Get block if we use remote Pow, because the node will change parents and nonce
         let block = if self.get_local_pow().await {
             reattach_block
@@ -285,6 +407,22 @@ impl Client {
         Ok((block_id, block))
     }
 
+    /// Returns the timestamp of the latest milestone, as cached by the node syncing or requested from the node, falling
+    /// back to the local time if the node doesn't know any milestone yet.
+    pub async fn get_latest_milestone_timestamp(&self) -> Result<u32> {
+        Ok(match self.get_network_info().await?.latest_milestone_timestamp {
+            Some(timestamp) => timestamp,
+            None => self
+                .get_info()
+                .await?
+                .node_info
+                .status
+                .latest_milestone
+                .timestamp
+                .unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
+        })
+    }
+
     /// Returns the local time checked with the timestamp of the latest milestone, if the difference is larger than 5
     /// minutes an error is returned to prevent locking outputs by accident for a wrong time.
     pub async fn get_time_checked(&self) -> Result<u32> {
@@ -0,0 +1,316 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verifying the inclusion of blocks with Merkle proofs against milestones signed by the coordinator
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, Error, Result},
+    types::{
+        api::core::response::LedgerInclusionState,
+        block::{
+            payload::{
+                milestone::{dto::MilestonePayloadDto, MerkleRoot, MilestonePayload},
+                Payload,
+            },
+            Block, BlockDto, BlockId,
+        },
+        TryFromDto,
+    },
+};
+
+// Domain separation of the leaves and nodes of a Merkle tree, as specified in TIP-4
+const LEAF_HASH_PREFIX: u8 = 0;
+const NODE_HASH_PREFIX: u8 = 1;
+
+/// A public key of the coordinator with the milestone indexes it's applicable to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneKeyRange {
+    /// The hex encoded Ed25519 public key.
+    pub public_key: String,
+    /// The first milestone index the key is applicable to.
+    pub start_index: u32,
+    /// The last milestone index the key is applicable to, 0 if it's applicable to all following milestones.
+    pub end_index: u32,
+}
+
+impl MilestoneKeyRange {
+    /// Creates a new [`MilestoneKeyRange`].
+    pub fn new(public_key: impl Into<String>, start_index: u32, end_index: u32) -> Self {
+        Self {
+            public_key: public_key.into(),
+            start_index,
+            end_index,
+        }
+    }
+
+    /// Returns whether the key is applicable to the milestone with the given index.
+    pub fn is_applicable(&self, milestone_index: u32) -> bool {
+        self.start_index <= milestone_index && (self.end_index == 0 || milestone_index <= self.end_index)
+    }
+}
+
+/// A Merkle audit path from a block ID to the root of the Merkle tree of the blocks referenced by a milestone.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MerkleAuditPath {
+    /// An inner node of the tree.
+    Node {
+        /// The left subtree.
+        #[serde(rename = "l")]
+        left: Box<Self>,
+        /// The right subtree.
+        #[serde(rename = "r")]
+        right: Box<Self>,
+    },
+    /// The leaf of the proven block ID.
+    Value {
+        /// The block ID.
+        value: BlockId,
+    },
+    /// The hex encoded hash of a subtree that isn't on the path.
+    Hash {
+        /// The hash.
+        #[serde(rename = "h")]
+        hash: String,
+    },
+}
+
+impl MerkleAuditPath {
+    /// Computes the Merkle root of the audit path.
+    pub fn root(&self) -> Result<MerkleRoot> {
+        Ok(match self {
+            Self::Node { left, right } => MerkleRoot::new(
+                Blake2b256::new()
+                    .chain_update([NODE_HASH_PREFIX])
+                    .chain_update(*left.root()?)
+                    .chain_update(*right.root()?)
+                    .finalize()
+                    .into(),
+            ),
+            Self::Value { value } => MerkleRoot::new(
+                Blake2b256::new()
+                    .chain_update([LEAF_HASH_PREFIX])
+                    .chain_update(value)
+                    .finalize()
+                    .into(),
+            ),
+            Self::Hash { hash } => MerkleRoot::new(prefix_hex::decode(hash)?),
+        })
+    }
+
+    /// Returns whether the audit path proves the given block ID.
+    pub fn contains(&self, block_id: &BlockId) -> bool {
+        match self {
+            Self::Node { left, right } => left.contains(block_id) || right.contains(block_id),
+            Self::Value { value } => value == block_id,
+            Self::Hash { .. } => false,
+        }
+    }
+}
+
+/// A proof of inclusion, as returned by the proof of inclusion plugin of a node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InclusionProofResponse {
+    /// The milestone referencing the block.
+    pub milestone: MilestonePayloadDto,
+    /// The block.
+    pub block: BlockDto,
+    /// The audit path from the block ID to the inclusion Merkle root of the milestone.
+    pub proof: MerkleAuditPath,
+}
+
+/// Verifies that the block has the given ID and that the audit path proves it against the inclusion Merkle root of the
+/// milestone, which has to be signed by at least `min_threshold` of the applicable coordinator keys.
+fn verify_inclusion_proof(
+    block_id: &BlockId,
+    block: &Block,
+    milestone: &MilestonePayload,
+    proof: &MerkleAuditPath,
+    key_ranges: &[MilestoneKeyRange],
+    min_threshold: usize,
+) -> core::result::Result<(), String> {
+    if &block.id() != block_id {
+        return Err(format!("the node returned block {}", block.id()));
+    }
+    if !proof.contains(block_id) {
+        return Err("the proof doesn't contain the block".to_string());
+    }
+
+    let milestone_index = milestone.essence().index().0;
+    let root = proof.root().map_err(|error| format!("invalid proof: {error}"))?;
+    if &root != milestone.essence().inclusion_merkle_root() {
+        return Err(format!(
+            "the proof doesn't match the inclusion Merkle root of milestone {milestone_index}"
+        ));
+    }
+
+    milestone
+        .validate(&applicable_public_keys(key_ranges, milestone_index), min_threshold)
+        .map_err(|error| format!("milestone {milestone_index} is invalid: {error:?}"))
+}
+
+/// Returns the public keys applicable to the milestone with the given index, hex encoded without prefix.
+fn applicable_public_keys(key_ranges: &[MilestoneKeyRange], milestone_index: u32) -> Vec<String> {
+    key_ranges
+        .iter()
+        .filter(|key_range| key_range.is_applicable(milestone_index))
+        .map(|key_range| key_range.public_key.trim_start_matches("0x").to_lowercase())
+        .collect()
+}
+
+impl Client {
+    /// Gets a Merkle proof that a block is referenced by a milestone from the proof of inclusion plugin of the node.
+    /// GET /api/poi/v1/create/{blockId}
+    pub async fn get_inclusion_proof(&self, block_id: &BlockId) -> Result<InclusionProofResponse> {
+        let path = &format!("api/poi/v1/create/{block_id}");
+
+        self.get_request(path, None, false, false).await
+    }
+
+    /// Verifies that a block is referenced by a milestone signed by at least `min_threshold` of the coordinator keys
+    /// applicable to its index. The keys are supplied by the caller, e.g. from the node configuration of the network,
+    /// and the block is proven with a Merkle audit path against the inclusion Merkle root of the milestone, so neither
+    /// the milestone nor the proof have to be trusted. Requires the proof of inclusion plugin on the node.
+    ///
+    /// Whether the transaction of the block was applied or conflicting isn't part of the proof, so the ledger
+    /// inclusion state is still reported by the node: the block has to be included in the ledger if it has a
+    /// transaction. Returns the verified milestone.
+    pub async fn verify_inclusion(
+        &self,
+        block_id: &BlockId,
+        key_ranges: &[MilestoneKeyRange],
+        min_threshold: usize,
+    ) -> Result<MilestonePayload> {
+        log::debug!("[verify_inclusion]");
+        let invalid_inclusion = |reason: String| Error::InvalidInclusion {
+            block_id: block_id.to_string(),
+            reason,
+        };
+
+        let proof = self.get_inclusion_proof(block_id).await?;
+        let protocol_parameters = self.get_protocol_parameters().await?;
+        let milestone = MilestonePayload::try_from_dto_with_params(proof.milestone, &protocol_parameters)?;
+        let block = Block::try_from_dto_with_params(proof.block, &protocol_parameters)?;
+
+        verify_inclusion_proof(block_id, &block, &milestone, &proof.proof, key_ranges, min_threshold)
+            .map_err(invalid_inclusion)?;
+
+        let metadata = self.get_block_metadata(block_id).await?;
+        let expected_state = match block.payload() {
+            Some(Payload::Transaction(_)) => LedgerInclusionState::Included,
+            _ => LedgerInclusionState::NoTransaction,
+        };
+        if metadata.ledger_inclusion_state != Some(expected_state) {
+            return Err(invalid_inclusion(format!(
+                "the ledger inclusion state is {:?} instead of {expected_state:?}",
+                metadata.ledger_inclusion_state
+            )));
+        }
+
+        Ok(milestone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto::signatures::ed25519::SecretKey;
+
+    use super::*;
+    use crate::types::block::{
+        payload::milestone::{MilestoneEssence, MilestoneIndex, MilestoneOptions},
+        rand::{
+            block::rand_block_id,
+            milestone::{rand_merkle_root, rand_milestone_id},
+            parents::rand_parents,
+        },
+        signature::{Ed25519Signature, Signature},
+        BlockBuilder, PROTOCOL_VERSION,
+    };
+
+    fn signed_milestone(secret_key: &SecretKey, inclusion_merkle_root: MerkleRoot) -> MilestonePayload {
+        let essence = MilestoneEssence::new(
+            MilestoneIndex(10),
+            0,
+            PROTOCOL_VERSION,
+            rand_milestone_id(),
+            rand_parents(),
+            inclusion_merkle_root,
+            rand_merkle_root(),
+            Vec::new(),
+            MilestoneOptions::from_vec(Vec::new()).unwrap(),
+        )
+        .unwrap();
+        let signature = Ed25519Signature::new(secret_key.public_key(), secret_key.sign(&essence.hash()));
+
+        MilestonePayload::new(essence, [Signature::from(signature)]).unwrap()
+    }
+
+    #[test]
+    fn inclusion_proof() {
+        let secret_key = SecretKey::from_bytes(&[1; 32]);
+        let key_ranges = [MilestoneKeyRange::new(
+            prefix_hex::encode(secret_key.public_key().to_bytes()),
+            0,
+            0,
+        )];
+        let block = BlockBuilder::new(rand_parents()).with_nonce(0).finish().unwrap();
+        let block_id = block.id();
+
+        let proof = MerkleAuditPath::Node {
+            left: Box::new(MerkleAuditPath::Hash {
+                hash: rand_merkle_root().to_string(),
+            }),
+            right: Box::new(MerkleAuditPath::Value { value: block_id }),
+        };
+        let milestone = signed_milestone(&secret_key, proof.root().unwrap());
+        assert!(verify_inclusion_proof(&block_id, &block, &milestone, &proof, &key_ranges, 1).is_ok());
+
+        // A block that isn't the requested one
+        assert!(verify_inclusion_proof(&rand_block_id(), &block, &milestone, &proof, &key_ranges, 1).is_err());
+
+        // A forged proof of another block, or of a root the milestone doesn't commit to
+        let forged_proof = MerkleAuditPath::Node {
+            left: Box::new(MerkleAuditPath::Hash {
+                hash: rand_merkle_root().to_string(),
+            }),
+            right: Box::new(MerkleAuditPath::Value { value: rand_block_id() }),
+        };
+        assert!(verify_inclusion_proof(&block_id, &block, &milestone, &forged_proof, &key_ranges, 1).is_err());
+        let forged_milestone = signed_milestone(&secret_key, forged_proof.root().unwrap());
+        assert!(verify_inclusion_proof(&block_id, &block, &forged_milestone, &proof, &key_ranges, 1).is_err());
+
+        // A milestone signed by a key that isn't a coordinator key
+        let forged_milestone = signed_milestone(&SecretKey::from_bytes(&[2; 32]), proof.root().unwrap());
+        assert!(verify_inclusion_proof(&block_id, &block, &forged_milestone, &proof, &key_ranges, 1).is_err());
+    }
+
+    #[test]
+    fn audit_path_serde() {
+        let proof = serde_json::from_str::<MerkleAuditPath>(
+            r#"{"l":{"h":"0x0000000000000000000000000000000000000000000000000000000000000000"},"r":{"value":"0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(proof, MerkleAuditPath::Node { .. }));
+        assert_eq!(
+            serde_json::from_value::<MerkleAuditPath>(serde_json::to_value(&proof).unwrap()).unwrap(),
+            proof
+        );
+    }
+
+    #[test]
+    fn applicable_keys() {
+        let key_ranges = [
+            MilestoneKeyRange::new("0xAA", 0, 10),
+            MilestoneKeyRange::new("bb", 5, 0),
+            MilestoneKeyRange::new("0xcc", 11, 20),
+        ];
+
+        assert_eq!(applicable_public_keys(&key_ranges, 0), ["aa"]);
+        assert_eq!(applicable_public_keys(&key_ranges, 10), ["aa", "bb"]);
+        assert_eq!(applicable_public_keys(&key_ranges, 21), ["bb"]);
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Traversing the blocks confirmed by a milestone
+
+use std::collections::HashSet;
+
+use futures::Stream;
+
+use crate::{
+    client::{Client, Result},
+    types::block::{Block, BlockId},
+};
+
+// The state of [`Client::get_milestone_cone()`]
+struct MilestoneCone {
+    milestone_index: u32,
+    // Blocks to visit, with the block once its parents were pushed after it
+    stack: Vec<(BlockId, Option<Block>)>,
+    visited: HashSet<BlockId>,
+}
+
+impl Client {
+    /// Returns a stream of the blocks confirmed by the milestone with the given index, in white-flag order: the past
+    /// cone of the milestone is walked depth-first from its parents, parents in their order, and every block follows
+    /// its parents. Blocks that were already confirmed by an earlier milestone end the walk, so the stream covers the
+    /// blocks that were newly confirmed by this milestone. Every block and its metadata are requested while the stream
+    /// is polled.
+    pub fn get_milestone_cone(&self, milestone_index: u32) -> impl Stream<Item = Result<Block>> + '_ {
+        futures::stream::try_unfold(None, move |cone: Option<MilestoneCone>| async move {
+            let mut cone = match cone {
+                Some(cone) => cone,
+                None => {
+                    let milestone = self.get_milestone_by_index(milestone_index).await?;
+                    MilestoneCone {
+                        milestone_index,
+                        stack: milestone
+                            .essence()
+                            .parents()
+                            .iter()
+                            .rev()
+                            .map(|parent| (*parent, None))
+                            .collect(),
+                        visited: HashSet::new(),
+                    }
+                }
+            };
+
+            while let Some((block_id, block)) = cone.stack.pop() {
+                // All parents were returned already, so the block is next
+                if let Some(block) = block {
+                    return Ok(Some((block, Some(cone))));
+                }
+                if !cone.visited.insert(block_id) {
+                    continue;
+                }
+
+                let metadata = self.get_block_metadata(&block_id).await?;
+                if metadata.referenced_by_milestone_index != Some(cone.milestone_index) {
+                    continue;
+                }
+
+                let block = self.get_block(&block_id).await?;
+                let parents = block
+                    .parents()
+                    .iter()
+                    .rev()
+                    .filter(|parent| !cone.visited.contains(*parent))
+                    .map(|parent| (*parent, None))
+                    .collect::<Vec<_>>();
+                cone.stack.push((block_id, Some(block)));
+                cone.stack.extend(parents);
+            }
+
+            Ok(None)
+        })
+    }
+}
@@ -5,10 +5,32 @@
 
 mod address;
 mod block_builder;
+mod chrysalis_migration;
 mod consolidation;
 mod high_level;
+mod inclusion;
+mod milestone_cone;
+mod multisig;
+mod native_token_metadata;
+mod offline_signing;
+mod receipt_audit;
+#[cfg(not(target_family = "wasm"))]
+mod tagged_data_listener;
 mod types;
 
-pub use self::{address::*, block_builder::*, types::*};
+#[cfg(not(target_family = "wasm"))]
+pub use self::tagged_data_listener::{TaggedDataEvent, TaggedDataListener, TaggedDataStatus};
+pub use self::{
+    address::*,
+    block_builder::*,
+    chrysalis_migration::{ChrysalisAddressFunds, ChrysalisScanOptions},
+    high_level::FoundBlocks,
+    inclusion::{InclusionProofResponse, MerkleAuditPath, MilestoneKeyRange},
+    multisig::{MultisigCoordinator, PartialSignature, PartialSignatureDto},
+    native_token_metadata::{NativeTokenMetadata, IRC_30_STANDARD},
+    offline_signing::AddressWithChain,
+    receipt_audit::{ReceiptAudit, ReceiptAuditIssue, ReceiptAuditReport},
+    types::*,
+};
 
 const ADDRESS_GAP_RANGE: u32 = 20;
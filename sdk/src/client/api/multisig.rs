@@ -0,0 +1,246 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Collecting the Ed25519 signatures of several parties for transactions with inputs of several addresses
+
+use std::collections::HashMap;
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip44::Bip44,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{verify_semantic, PreparedTransactionData, SignedTransactionData};
+use crate::{
+    client::{
+        api::transaction::validate_transaction_payload_length,
+        secret::{input_unlocks, InputUnlock, SecretManage},
+        Error, Result,
+    },
+    types::block::{
+        address::Ed25519Address,
+        payload::TransactionPayload,
+        semantic::ConflictReason,
+        signature::{dto::Ed25519SignatureDto, Ed25519Signature, Signature},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
+    },
+};
+
+/// A signature of the essence hash of a transaction by one of the addresses that have to sign it. Every party signs
+/// with its own secret manager, possibly on another machine, and sends its partial signatures to the
+/// [`MultisigCoordinator`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartialSignature {
+    /// The signed essence hash.
+    pub essence_hash: [u8; 32],
+    /// The signature.
+    pub signature: Ed25519Signature,
+}
+
+impl PartialSignature {
+    /// Signs the essence hash of the prepared transaction with the secret manager, once for every chain. The parties
+    /// should check [`PreparedTransactionData::summary()`] before signing.
+    pub async fn sign<S: SecretManage + ?Sized>(
+        secret_manager: &S,
+        prepared_transaction_data: &PreparedTransactionData,
+        chains: &[Bip44],
+    ) -> Result<Vec<Self>>
+    where
+        Error: From<S::Error>,
+    {
+        let essence_hash = prepared_transaction_data.essence_hash();
+        let mut signatures = Vec::with_capacity(chains.len());
+
+        for chain in chains {
+            signatures.push(Self {
+                essence_hash,
+                signature: secret_manager.sign_ed25519(&essence_hash, *chain).await?,
+            });
+        }
+
+        Ok(signatures)
+    }
+
+    /// Returns the address of the public key of the signature.
+    pub fn signer(&self) -> Ed25519Address {
+        Ed25519Address::new(Blake2b256::digest(self.signature.public_key()).into())
+    }
+}
+
+/// PartialSignature Dto
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialSignatureDto {
+    /// The signed essence hash.
+    pub essence_hash: String,
+    /// The signature.
+    pub signature: Ed25519SignatureDto,
+}
+
+impl From<&PartialSignature> for PartialSignatureDto {
+    fn from(value: &PartialSignature) -> Self {
+        Self {
+            essence_hash: prefix_hex::encode(value.essence_hash),
+            signature: Ed25519SignatureDto::from(&value.signature),
+        }
+    }
+}
+
+impl TryFrom<PartialSignatureDto> for PartialSignature {
+    type Error = crate::types::block::Error;
+
+    fn try_from(value: PartialSignatureDto) -> core::result::Result<Self, Self::Error> {
+        Ok(Self {
+            essence_hash: prefix_hex::decode(&value.essence_hash)
+                .map_err(|_| crate::types::block::Error::InvalidField("essenceHash"))?,
+            signature: Ed25519Signature::try_from(value.signature)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PartialSignature {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> core::result::Result<Self, D::Error> {
+        PartialSignatureDto::deserialize(d).and_then(|dto| Self::try_from(dto).map_err(serde::de::Error::custom))
+    }
+}
+
+impl Serialize for PartialSignature {
+    fn serialize<S>(&self, s: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        PartialSignatureDto::from(self).serialize(s)
+    }
+}
+
+/// Collects the partial signatures of a transaction whose inputs are owned by several Ed25519 addresses, and
+/// assembles its unlocks once every address signed.
+///
+/// Every party gets the [`PreparedTransactionData`] of the transaction, checks it and signs it with
+/// [`PartialSignature::sign()`]. A partial signature is only accepted if its public key hashes to one of the addresses
+/// that have to sign and it's valid for the essence hash.
+#[derive(Clone, Debug)]
+pub struct MultisigCoordinator {
+    prepared_transaction_data: PreparedTransactionData,
+    time: u32,
+    input_unlocks: Vec<InputUnlock>,
+    signatures: HashMap<Ed25519Address, Ed25519Signature>,
+}
+
+impl MultisigCoordinator {
+    /// Creates a coordinator for the prepared transaction. The addresses that have to sign are the ones required to
+    /// unlock the inputs at `time`, which is also the time the transaction is verified with when it's finished.
+    pub fn new(prepared_transaction_data: PreparedTransactionData, time: u32) -> Result<Self> {
+        let input_unlocks = input_unlocks(&prepared_transaction_data, time)?;
+
+        Ok(Self {
+            prepared_transaction_data,
+            time,
+            input_unlocks,
+            signatures: HashMap::new(),
+        })
+    }
+
+    /// Returns the prepared transaction, to send it to the parties that have to sign it.
+    pub fn prepared_transaction_data(&self) -> &PreparedTransactionData {
+        &self.prepared_transaction_data
+    }
+
+    /// Returns the essence hash that has to be signed.
+    pub fn essence_hash(&self) -> [u8; 32] {
+        self.prepared_transaction_data.essence_hash()
+    }
+
+    /// Returns the addresses that have to sign the transaction, in the order of their signature unlocks.
+    pub fn signers(&self) -> impl Iterator<Item = &Ed25519Address> + '_ {
+        self.input_unlocks.iter().filter_map(|unlock| match unlock {
+            InputUnlock::Signature(address) => Some(address),
+            InputUnlock::Unlock(_) => None,
+        })
+    }
+
+    /// Returns the addresses that have to sign the transaction but didn't yet.
+    pub fn missing_signers(&self) -> impl Iterator<Item = &Ed25519Address> + '_ {
+        self.signers().filter(|address| !self.signatures.contains_key(address))
+    }
+
+    /// Returns whether every address that has to sign the transaction did.
+    pub fn is_complete(&self) -> bool {
+        self.missing_signers().next().is_none()
+    }
+
+    /// Validates the partial signature and adds it. A signature of an address that already signed replaces the
+    /// previous one.
+    pub fn add_signature(&mut self, partial_signature: PartialSignature) -> Result<()> {
+        let essence_hash = self.essence_hash();
+        if partial_signature.essence_hash != essence_hash {
+            return Err(Error::EssenceHashMismatch {
+                expected: prefix_hex::encode(essence_hash),
+                found: prefix_hex::encode(partial_signature.essence_hash),
+            });
+        }
+
+        let signer = partial_signature.signer();
+        if !self.signers().any(|address| address == &signer) {
+            return Err(Error::UnexpectedSigner(signer.to_string()));
+        }
+        partial_signature.signature.is_valid(&essence_hash, &signer)?;
+
+        self.signatures.insert(signer, partial_signature.signature);
+
+        Ok(())
+    }
+
+    /// Validates the partial signatures and adds them, stopping at the first invalid one.
+    pub fn add_signatures(&mut self, partial_signatures: impl IntoIterator<Item = PartialSignature>) -> Result<()> {
+        for partial_signature in partial_signatures {
+            self.add_signature(partial_signature)?;
+        }
+
+        Ok(())
+    }
+
+    /// Assembles the unlocks of the transaction, fails if signatures are missing.
+    pub fn unlocks(&self) -> Result<Unlocks> {
+        let missing_signers = self.missing_signers().map(ToString::to_string).collect::<Vec<_>>();
+        if !missing_signers.is_empty() {
+            return Err(Error::MissingSignatures(missing_signers));
+        }
+
+        let unlocks = self
+            .input_unlocks
+            .iter()
+            .map(|unlock| match unlock {
+                InputUnlock::Signature(address) => {
+                    Unlock::Signature(SignatureUnlock::new(Signature::from(self.signatures[address].clone())))
+                }
+                InputUnlock::Unlock(unlock) => unlock.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Unlocks::new(unlocks)?)
+    }
+
+    /// Assembles the unlocks and checks that the signed transaction is semantically valid. It can then be submitted
+    /// with [`Client::submit_transaction()`](crate::client::Client::submit_transaction()).
+    pub fn finish(self) -> Result<SignedTransactionData> {
+        let unlocks = self.unlocks()?;
+        let PreparedTransactionData {
+            essence, inputs_data, ..
+        } = self.prepared_transaction_data;
+        let transaction_payload = TransactionPayload::new(essence, unlocks)?;
+
+        validate_transaction_payload_length(&transaction_payload)?;
+
+        let conflict = verify_semantic(&inputs_data, &transaction_payload, self.time)?;
+        if conflict != ConflictReason::None {
+            return Err(Error::TransactionSemantic(conflict));
+        }
+
+        Ok(SignedTransactionData {
+            transaction_payload,
+            inputs_data,
+        })
+    }
+}
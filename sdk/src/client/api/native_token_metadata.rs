@@ -0,0 +1,123 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolving the IRC-30 metadata of native tokens
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, Error, Result},
+    types::block::output::{feature::MetadataFeature, FoundryId, Output, TokenId},
+};
+
+/// The IRC-30 standard of native token metadata.
+pub const IRC_30_STANDARD: &str = "IRC30";
+
+/// The metadata of a native token following the IRC-30 standard, which is stored in a metadata feature of its
+/// foundry. See <https://github.com/iotaledger/tips/blob/main/tips/TIP-0030/tip-0030.md>.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTokenMetadata {
+    /// The human-readable name of the native token.
+    pub name: String,
+    /// The human-readable description of the native token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The symbol or ticker of the native token.
+    pub symbol: String,
+    /// The number of decimals the token uses, divide the token amount by `10^decimals` to get its user
+    /// representation.
+    pub decimals: u32,
+    /// The URL pointing to more resources about the native token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The URL pointing to an image resource of the native token logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The SVG image of the native token logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Irc30Metadata {
+    standard: String,
+    #[serde(flatten)]
+    metadata: NativeTokenMetadata,
+}
+
+impl TryFrom<&MetadataFeature> for NativeTokenMetadata {
+    type Error = serde_json::Error;
+
+    fn try_from(metadata_feature: &MetadataFeature) -> std::result::Result<Self, Self::Error> {
+        let Irc30Metadata { standard, metadata } = serde_json::from_slice(metadata_feature.data())?;
+
+        if standard != IRC_30_STANDARD {
+            return Err(serde::de::Error::custom(format!(
+                "unsupported standard {standard}, expected {IRC_30_STANDARD}"
+            )));
+        }
+
+        Ok(metadata)
+    }
+}
+
+impl Client {
+    /// Resolves the foundry of a native token and decodes the IRC-30 metadata of its immutable metadata feature, or of
+    /// its mutable metadata feature if it has no immutable one. Requires a node with the indexer plugin enabled.
+    pub async fn get_native_token_metadata(&self, token_id: TokenId) -> Result<NativeTokenMetadata> {
+        log::debug!("[get_native_token_metadata]");
+        let output_id = self.foundry_output_id(FoundryId::from(token_id)).await?;
+        let output = self.get_output(&output_id).await?.into_output();
+
+        let Output::Foundry(foundry) = output else {
+            return Err(Error::NoOutput(format!("foundry of native token {token_id}")));
+        };
+        let metadata_feature = foundry
+            .immutable_features()
+            .metadata()
+            .or_else(|| foundry.features().metadata())
+            .ok_or_else(|| Error::InvalidNativeTokenMetadata {
+                token_id: token_id.to_string(),
+                reason: "the foundry has no metadata feature".to_string(),
+            })?;
+
+        NativeTokenMetadata::try_from(metadata_feature).map_err(|error| Error::InvalidNativeTokenMetadata {
+            token_id: token_id.to_string(),
+            reason: error.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FOUNDRY_METADATA: &str = r#"{"standard":"IRC30","name":"NativeToken","description":"A native token","symbol":"NT","decimals":6,"logoUrl":"https://my.website/nativeToken.png"}"#;
+
+    #[test]
+    fn decode_irc_30_metadata() {
+        let metadata_feature = MetadataFeature::new(FOUNDRY_METADATA.as_bytes().to_vec()).unwrap();
+
+        assert_eq!(
+            NativeTokenMetadata::try_from(&metadata_feature).unwrap(),
+            NativeTokenMetadata {
+                name: "NativeToken".to_string(),
+                description: Some("A native token".to_string()),
+                symbol: "NT".to_string(),
+                decimals: 6,
+                url: None,
+                logo_url: Some("https://my.website/nativeToken.png".to_string()),
+                logo: None,
+            }
+        );
+    }
+
+    #[test]
+    fn reject_other_standards() {
+        let metadata_feature =
+            MetadataFeature::new(FOUNDRY_METADATA.replace("IRC30", "IRC27").as_bytes().to_vec()).unwrap();
+
+        assert!(NativeTokenMetadata::try_from(&metadata_feature).is_err());
+    }
+}
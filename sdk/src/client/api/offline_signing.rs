@@ -0,0 +1,119 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Preparing transactions on an online machine, signing them on an air-gapped machine and submitting them online
+
+use crypto::keys::bip44::Bip44;
+use serde::{Deserialize, Serialize};
+
+use super::{verify_semantic, PreparedTransactionData, SignedTransactionData};
+use crate::{
+    client::{secret::SecretManage, Client, Error, Result},
+    types::block::{address::Bech32Address, output::Output, payload::Payload, semantic::ConflictReason, Block},
+};
+
+/// An address with the BIP44 chain it was generated with. The addresses of an offline secret manager are exported
+/// like this, so that the online machine can prepare transactions that the offline machine can sign.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressWithChain {
+    /// The address.
+    pub address: Bech32Address,
+    /// The BIP44 chain of the address.
+    #[serde(with = "crate::utils::serde::bip44::Bip44Def")]
+    pub chain: Bip44,
+}
+
+impl AddressWithChain {
+    /// Creates a new [`AddressWithChain`].
+    pub fn new(address: Bech32Address, chain: Bip44) -> Self {
+        Self { address, chain }
+    }
+}
+
+impl Client {
+    /// Prepares a transaction that creates the outputs with inputs of the addresses, on the online machine. The
+    /// chains of the addresses are set on the inputs, so the offline machine can sign the returned transaction with
+    /// [`Client::sign_transaction()`]. [`PreparedTransactionData`] can be serialized to JSON to transfer it.
+    pub async fn prepare_transaction(
+        &self,
+        addresses: &[AddressWithChain],
+        outputs: impl Into<Vec<Output>> + Send,
+    ) -> Result<PreparedTransactionData> {
+        log::debug!("[prepare_transaction]");
+        let outputs = outputs.into();
+        let amount = outputs.iter().map(Output::amount).sum();
+
+        let inputs = self
+            .find_inputs(addresses.iter().map(|address| address.address).collect(), amount)
+            .await?;
+        let mut block_builder = self.build_block();
+        for input in inputs {
+            block_builder = block_builder.with_input(input)?;
+        }
+        let mut prepared_transaction_data = block_builder.with_outputs(outputs)?.prepare_transaction().await?;
+
+        // Without a secret manager the inputs get the chain of the first address, so replace it with the chain of the
+        // address that owns the input
+        let current_time = self.get_time_checked().await?;
+        for input in &mut prepared_transaction_data.inputs_data {
+            let (required_address, _) =
+                input
+                    .output
+                    .required_and_unlocked_address(current_time, input.output_metadata.output_id(), None)?;
+            input.chain = addresses
+                .iter()
+                .find(|address| address.address.inner() == &required_address)
+                .map(|address| address.chain);
+        }
+        if let Some(remainder) = &mut prepared_transaction_data.remainder {
+            remainder.chain = addresses
+                .iter()
+                .find(|address| address.address.inner() == &remainder.address)
+                .map(|address| address.chain);
+        }
+
+        Ok(prepared_transaction_data)
+    }
+
+    /// Signs a transaction prepared with [`Client::prepare_transaction()`], on the offline machine. No requests are
+    /// sent, so the client doesn't need to have any nodes. [`SignedTransactionData`] can be serialized to JSON to
+    /// transfer it back to the online machine.
+    pub async fn sign_transaction<S: SecretManage>(
+        &self,
+        secret_manager: &S,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<SignedTransactionData>
+    where
+        Error: From<S::Error>,
+    {
+        log::debug!("[sign_transaction]");
+        let inputs_data = prepared_transaction_data.inputs_data.clone();
+        let transaction_payload = secret_manager.sign_transaction(prepared_transaction_data).await?;
+
+        Ok(SignedTransactionData {
+            transaction_payload,
+            inputs_data,
+        })
+    }
+
+    /// Verifies a transaction signed with [`Client::sign_transaction()`] and submits it in a block, on the online
+    /// machine.
+    pub async fn submit_transaction(&self, signed_transaction_data: SignedTransactionData) -> Result<Block> {
+        log::debug!("[submit_transaction]");
+        let current_time = self.get_time_checked().await?;
+
+        let conflict = verify_semantic(
+            &signed_transaction_data.inputs_data,
+            &signed_transaction_data.transaction_payload,
+            current_time,
+        )?;
+        if conflict != ConflictReason::None {
+            return Err(Error::TransactionSemantic(conflict));
+        }
+
+        self.build_block()
+            .finish_block(Some(Payload::from(signed_transaction_data.transaction_payload)))
+            .await
+    }
+}
@@ -0,0 +1,362 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Auditing the receipts of the legacy migration against the treasury
+
+use std::ops::RangeBounds;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{node_api::error::Error as NodeApiError, Client, Error, Result},
+    types::{
+        block::payload::milestone::{MilestoneId, ReceiptMilestoneOption},
+        TryFromDto,
+    },
+};
+
+/// A receipt checked by [`Client::audit_receipts()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptAudit {
+    /// The index of the milestone that contains the receipt.
+    pub milestone_index: u32,
+    /// The index of the legacy milestone in which the funds were migrated.
+    pub migrated_at: u32,
+    /// Whether the receipt is the final one for the legacy milestone.
+    pub last: bool,
+    /// The number of migrated funds entries.
+    pub funds_count: usize,
+    /// The sum of the migrated funds.
+    pub migrated_amount: u64,
+    /// The ID of the milestone whose treasury output is spent by the receipt.
+    pub treasury_input_milestone_id: MilestoneId,
+    /// The amount of the treasury output created by the receipt.
+    pub treasury_output_amount: u64,
+}
+
+/// An inconsistency found by [`Client::audit_receipts()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ReceiptAuditIssue {
+    /// The treasury input of a receipt doesn't spend the treasury output of the previous receipt.
+    #[serde(rename_all = "camelCase")]
+    TreasuryInputMismatch {
+        milestone_index: u32,
+        expected: MilestoneId,
+        found: MilestoneId,
+    },
+    /// The treasury output of a receipt isn't the previous treasury output minus the migrated funds.
+    #[serde(rename_all = "camelCase")]
+    TreasuryAmountMismatch {
+        milestone_index: u32,
+        expected: u64,
+        found: u64,
+    },
+    /// The treasury output of the latest receipt isn't the current treasury output.
+    #[serde(rename_all = "camelCase")]
+    CurrentTreasuryMismatch { milestone_id: MilestoneId, amount: u64 },
+}
+
+/// The report of [`Client::audit_receipts()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptAuditReport {
+    /// The audited receipts, ordered by milestone index.
+    pub receipts: Vec<ReceiptAudit>,
+    /// The sum of the funds migrated by the audited receipts.
+    pub total_migrated: u64,
+    /// The amount of the current treasury output.
+    pub treasury_amount: u64,
+    /// The inconsistencies that were found.
+    pub issues: Vec<ReceiptAuditIssue>,
+    /// The indexes of the milestones of audited receipts that are pruned on the node.
+    pub pruned_milestones: Vec<u32>,
+}
+
+impl ReceiptAuditReport {
+    /// Returns whether no inconsistencies were found.
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Client {
+    /// Audits the receipts in the milestones of the range: sums the migrated funds and checks that every receipt
+    /// spends the treasury output created by the previous receipt, that the new treasury output is reduced by the
+    /// migrated funds, and, if the range contains the latest receipt, that its treasury output is the current one.
+    /// The receipt before the range is used to check the first one in the range.
+    /// Milestones that are pruned on the node are listed in the report, the treasury input of the receipt after them
+    /// can't be checked.
+    pub async fn audit_receipts(&self, range: impl RangeBounds<u32> + Send) -> Result<ReceiptAuditReport> {
+        let protocol_parameters = self.get_protocol_parameters().await?;
+
+        let mut receipts = self.get_receipts().await?;
+        receipts.sort_unstable_by_key(|receipt| receipt.milestone_index);
+
+        let treasury = self.get_treasury().await?;
+        let treasury_amount = treasury
+            .amount
+            .parse()
+            .map_err(|_| Error::InvalidAmount(treasury.amount.clone()))?;
+
+        let receipts_count = receipts.len();
+        // The receipts are sorted, so the ones in the range are contiguous
+        let Some(first) = receipts
+            .iter()
+            .position(|receipt| range.contains(&receipt.milestone_index))
+        else {
+            return Ok(ReceiptAuditReport {
+                treasury_amount,
+                ..Default::default()
+            });
+        };
+
+        // Start with the receipt right before the range, to check the first one in the range against it
+        let mut audited = Vec::new();
+        for (position, response) in receipts.into_iter().enumerate().skip(first.saturating_sub(1)) {
+            let in_range = range.contains(&response.milestone_index);
+            if !in_range && position > first {
+                break;
+            }
+
+            let receipt = ReceiptMilestoneOption::try_from_dto_with_params(response.receipt, &protocol_parameters)?;
+            let audit = ReceiptAudit {
+                milestone_index: response.milestone_index,
+                migrated_at: *receipt.migrated_at(),
+                last: receipt.last(),
+                funds_count: receipt.funds().len(),
+                migrated_amount: receipt.amount(),
+                treasury_input_milestone_id: *receipt.transaction().input().milestone_id(),
+                treasury_output_amount: receipt.transaction().output().amount(),
+            };
+            let milestone_id = match self.get_milestone_by_index(audit.milestone_index).await {
+                Ok(milestone) => Some(milestone.id()),
+                Err(Error::Node(NodeApiError::NotFound(_))) => None,
+                Err(e) => return Err(e),
+            };
+
+            audited.push(AuditedReceipt {
+                audit,
+                milestone_id,
+                in_range,
+                latest: position + 1 == receipts_count,
+            });
+        }
+
+        Ok(audit(audited, treasury.milestone_id, treasury_amount))
+    }
+}
+
+/// A receipt with the ID of its milestone, if the milestone isn't pruned on the node.
+struct AuditedReceipt {
+    audit: ReceiptAudit,
+    milestone_id: Option<MilestoneId>,
+    in_range: bool,
+    latest: bool,
+}
+
+/// Checks the receipts, ordered by milestone index, against the previous receipt and the current treasury output.
+fn audit(
+    receipts: Vec<AuditedReceipt>,
+    treasury_milestone_id: MilestoneId,
+    treasury_amount: u64,
+) -> ReceiptAuditReport {
+    let mut report = ReceiptAuditReport {
+        treasury_amount,
+        ..Default::default()
+    };
+    let mut previous: Option<AuditedReceipt> = None;
+
+    for receipt in receipts {
+        let AuditedReceipt {
+            audit,
+            milestone_id,
+            in_range,
+            latest,
+        } = &receipt;
+
+        if *in_range {
+            if milestone_id.is_none() {
+                report.pruned_milestones.push(audit.milestone_index);
+            }
+
+            if let Some(previous) = &previous {
+                // The treasury input can only be checked if the milestone of the previous receipt isn't pruned
+                if let Some(previous_milestone_id) = previous.milestone_id {
+                    if audit.treasury_input_milestone_id != previous_milestone_id {
+                        report.issues.push(ReceiptAuditIssue::TreasuryInputMismatch {
+                            milestone_index: audit.milestone_index,
+                            expected: previous_milestone_id,
+                            found: audit.treasury_input_milestone_id,
+                        });
+                    }
+                }
+                let expected = previous.audit.treasury_output_amount.checked_sub(audit.migrated_amount);
+                if expected != Some(audit.treasury_output_amount) {
+                    report.issues.push(ReceiptAuditIssue::TreasuryAmountMismatch {
+                        milestone_index: audit.milestone_index,
+                        expected: expected.unwrap_or_default(),
+                        found: audit.treasury_output_amount,
+                    });
+                }
+            }
+
+            // The latest receipt created the current treasury output
+            if *latest
+                && (milestone_id.map_or(false, |milestone_id| milestone_id != treasury_milestone_id)
+                    || audit.treasury_output_amount != treasury_amount)
+            {
+                report.issues.push(ReceiptAuditIssue::CurrentTreasuryMismatch {
+                    milestone_id: milestone_id.unwrap_or(treasury_milestone_id),
+                    amount: audit.treasury_output_amount,
+                });
+            }
+
+            report.total_migrated += audit.migrated_amount;
+            report.receipts.push(audit.clone());
+        }
+
+        previous = Some(receipt);
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn milestone_id(byte: u8) -> MilestoneId {
+        MilestoneId::new([byte; 32])
+    }
+
+    fn receipt(
+        milestone_index: u32,
+        milestone_id: Option<MilestoneId>,
+        treasury_input_milestone_id: MilestoneId,
+        migrated_amount: u64,
+        treasury_output_amount: u64,
+    ) -> AuditedReceipt {
+        AuditedReceipt {
+            audit: ReceiptAudit {
+                milestone_index,
+                migrated_at: milestone_index,
+                last: true,
+                funds_count: 1,
+                migrated_amount,
+                treasury_input_milestone_id,
+                treasury_output_amount,
+            },
+            milestone_id,
+            in_range: true,
+            latest: false,
+        }
+    }
+
+    #[test]
+    fn consistent_receipts() {
+        let mut before = receipt(1, Some(milestone_id(1)), milestone_id(0), 100, 1_000);
+        before.in_range = false;
+        let mut latest = receipt(3, Some(milestone_id(3)), milestone_id(2), 200, 700);
+        latest.latest = true;
+
+        let report = audit(
+            vec![
+                before,
+                receipt(2, Some(milestone_id(2)), milestone_id(1), 100, 900),
+                latest,
+            ],
+            milestone_id(3),
+            700,
+        );
+
+        assert!(report.is_consistent());
+        assert_eq!(
+            report
+                .receipts
+                .iter()
+                .map(|receipt| receipt.milestone_index)
+                .collect::<Vec<_>>(),
+            [2, 3]
+        );
+        assert_eq!(report.total_migrated, 300);
+        assert_eq!(report.treasury_amount, 700);
+        assert!(report.pruned_milestones.is_empty());
+    }
+
+    #[test]
+    fn inconsistent_receipts() {
+        let mut latest = receipt(3, Some(milestone_id(3)), milestone_id(1), 200, 600);
+        latest.latest = true;
+
+        let report = audit(
+            vec![receipt(2, Some(milestone_id(2)), milestone_id(1), 100, 900), latest],
+            milestone_id(4),
+            500,
+        );
+
+        assert_eq!(
+            report.issues,
+            [
+                ReceiptAuditIssue::TreasuryInputMismatch {
+                    milestone_index: 3,
+                    expected: milestone_id(2),
+                    found: milestone_id(1),
+                },
+                ReceiptAuditIssue::TreasuryAmountMismatch {
+                    milestone_index: 3,
+                    expected: 700,
+                    found: 600,
+                },
+                ReceiptAuditIssue::CurrentTreasuryMismatch {
+                    milestone_id: milestone_id(3),
+                    amount: 600,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn pruned_milestones() {
+        let mut before = receipt(1, None, milestone_id(0), 100, 1_000);
+        before.in_range = false;
+        let mut latest = receipt(3, None, milestone_id(2), 200, 700);
+        latest.latest = true;
+
+        // The treasury inputs after pruned milestones and the ID of the current treasury milestone can't be checked
+        let report = audit(
+            vec![before, receipt(2, None, milestone_id(9), 100, 900), latest],
+            milestone_id(3),
+            700,
+        );
+
+        assert!(report.is_consistent());
+        assert_eq!(report.pruned_milestones, [2, 3]);
+        assert_eq!(report.total_migrated, 300);
+
+        // The amounts are still checked
+        let mut latest = receipt(3, None, milestone_id(2), 200, 600);
+        latest.latest = true;
+        let report = audit(
+            vec![receipt(2, None, milestone_id(1), 100, 900), latest],
+            milestone_id(3),
+            700,
+        );
+
+        assert_eq!(
+            report.issues,
+            [
+                ReceiptAuditIssue::TreasuryAmountMismatch {
+                    milestone_index: 3,
+                    expected: 700,
+                    found: 600,
+                },
+                ReceiptAuditIssue::CurrentTreasuryMismatch {
+                    milestone_id: milestone_id(3),
+                    amount: 600,
+                },
+            ]
+        );
+    }
+}
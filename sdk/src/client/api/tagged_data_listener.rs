@@ -0,0 +1,451 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Listening to the tagged data payloads of new blocks with a given tag
+
+use std::{sync::Arc, time::Duration};
+
+#[cfg(feature = "mqtt")]
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use tokio::task::JoinHandle;
+
+#[cfg(feature = "mqtt")]
+use crate::client::{
+    constants::{DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL, DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT},
+    node_api::mqtt::{MqttPayload, Topic, TopicEvent, TopicHandler},
+};
+use crate::{
+    client::{Client, Result},
+    types::{
+        api::core::response::{BlockMetadataResponse, LedgerInclusionState},
+        block::{
+            payload::{transaction::TransactionEssence, Payload, TaggedDataPayload},
+            Block, BlockId,
+        },
+    },
+};
+
+/// Interval in which the confirmed milestones are requested if the blocks can't be received over MQTT.
+const TAGGED_DATA_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+type TaggedDataHandler = Arc<dyn Fn(&TaggedDataEvent) + Send + Sync>;
+
+/// The confirmation status of a block received by [`Client::listen_tagged_data()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TaggedDataStatus {
+    /// The block isn't referenced by a milestone yet.
+    Pending,
+    /// The block was referenced by the milestone and its transaction, if any, was applied to the ledger.
+    Included {
+        /// The index of the milestone that referenced the block.
+        milestone_index: u32,
+    },
+    /// The block was referenced by the milestone but its transaction was conflicting.
+    Conflicting {
+        /// The index of the milestone that referenced the block.
+        milestone_index: u32,
+    },
+}
+
+impl From<&BlockMetadataResponse> for TaggedDataStatus {
+    fn from(metadata: &BlockMetadataResponse) -> Self {
+        match (metadata.referenced_by_milestone_index, metadata.ledger_inclusion_state) {
+            (Some(milestone_index), Some(LedgerInclusionState::Conflicting)) => Self::Conflicting { milestone_index },
+            (Some(milestone_index), _) => Self::Included { milestone_index },
+            (None, _) => Self::Pending,
+        }
+    }
+}
+
+/// The decoded tagged data payload of a block received by [`Client::listen_tagged_data()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TaggedDataEvent {
+    /// The ID of the block.
+    pub block_id: BlockId,
+    /// The tag of the tagged data payload.
+    pub tag: Vec<u8>,
+    /// The data of the tagged data payload.
+    pub data: Vec<u8>,
+    /// The confirmation status of the block.
+    pub status: TaggedDataStatus,
+}
+
+impl TaggedDataEvent {
+    // Returns an event for the tagged data payload of the block, directly in the block or in its transaction, if the
+    // payload has the tag
+    fn from_block(block: &Block, tag: &[u8], status: TaggedDataStatus) -> Option<Self> {
+        let tagged_data = tagged_data_payload(block)?;
+
+        (tagged_data.tag() == tag).then(|| Self {
+            block_id: block.id(),
+            tag: tagged_data.tag().to_vec(),
+            data: tagged_data.data().to_vec(),
+            status,
+        })
+    }
+
+    /// Returns the data as UTF-8 string, if it's valid UTF-8.
+    pub fn data_utf8(&self) -> Option<&str> {
+        core::str::from_utf8(&self.data).ok()
+    }
+
+    /// Deserializes the data from JSON.
+    pub fn data_json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.data)?)
+    }
+}
+
+fn tagged_data_payload(block: &Block) -> Option<&TaggedDataPayload> {
+    match block.payload()? {
+        Payload::TaggedData(tagged_data) => Some(tagged_data),
+        Payload::Transaction(transaction) => {
+            let TransactionEssence::Regular(essence) = transaction.essence();
+            match essence.payload()? {
+                Payload::TaggedData(tagged_data) => Some(tagged_data),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// A listener started with [`Client::listen_tagged_data()`], it stops and removes its MQTT subscription when it's
+/// dropped.
+#[must_use]
+pub struct TaggedDataListener {
+    #[cfg(feature = "mqtt")]
+    client: Client,
+    // The MQTT topics with the handler of the listener, `None` if the blocks are polled
+    #[cfg(feature = "mqtt")]
+    subscription: Option<(Vec<Topic>, Arc<TopicHandler>)>,
+    task: JoinHandle<()>,
+}
+
+impl core::fmt::Debug for TaggedDataListener {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_struct("TaggedDataListener");
+        #[cfg(feature = "mqtt")]
+        debug.field("topics", &self.subscription.as_ref().map(|(topics, _)| topics));
+        debug.field("task", &self.task).finish()
+    }
+}
+
+impl TaggedDataListener {
+    /// Returns whether the blocks are received over MQTT, or polled from the confirmed milestones.
+    pub fn uses_mqtt(&self) -> bool {
+        #[cfg(feature = "mqtt")]
+        return self.subscription.is_some();
+        #[cfg(not(feature = "mqtt"))]
+        false
+    }
+
+    /// Stops the listener and removes its MQTT subscription, the topics stay subscribed for other handlers.
+    pub async fn stop(mut self) -> Result<()> {
+        self.task.abort();
+        #[cfg(feature = "mqtt")]
+        if let Some((topics, handler)) = self.subscription.take() {
+            self.client
+                .subscriber()
+                .with_topics(topics)
+                .unsubscribe_handler(&handler)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for TaggedDataListener {
+    fn drop(&mut self) {
+        self.task.abort();
+        // The subscription can only be removed asynchronously, it's kept if the runtime is already gone
+        #[cfg(feature = "mqtt")]
+        if let (Some((topics, handler)), Ok(runtime)) =
+            (self.subscription.take(), tokio::runtime::Handle::try_current())
+        {
+            let client = self.client.clone();
+            runtime.spawn(async move {
+                if let Err(e) = client
+                    .subscriber()
+                    .with_topics(topics)
+                    .unsubscribe_handler(&handler)
+                    .await
+                {
+                    log::warn!("[listen_tagged_data] removing the MQTT subscription failed: {e}");
+                }
+            });
+        }
+    }
+}
+
+impl Client {
+    /// Listens to new blocks with a tagged data payload with the tag, directly in the block or in its transaction,
+    /// and passes the decoded payloads to the handler.
+    ///
+    /// The blocks are received over MQTT if the `mqtt` feature is enabled and the node accepts the subscription. The
+    /// handler is then called once when a block is received, with [`TaggedDataStatus::Pending`], and once more when
+    /// the block got referenced by a milestone. Otherwise the blocks are polled from every new confirmed milestone,
+    /// the handler is only called for referenced blocks then.
+    pub async fn listen_tagged_data<F>(&self, tag: impl AsRef<[u8]> + Send, handler: F) -> Result<TaggedDataListener>
+    where
+        F: Fn(&TaggedDataEvent) + Send + Sync + 'static,
+    {
+        let tag = tag.as_ref().to_vec();
+        let handler: TaggedDataHandler = Arc::new(handler);
+
+        #[cfg(feature = "mqtt")]
+        {
+            let tag_hex = prefix_hex::encode(&tag);
+            // Topics can't be created for an empty tag, those blocks are polled
+            if let Ok(topics) = [
+                format!("blocks/tagged-data/{tag_hex}"),
+                format!("blocks/transaction/tagged-data/{tag_hex}"),
+            ]
+            .into_iter()
+            .map(Topic::new)
+            .collect::<core::result::Result<Vec<_>, _>>()
+            {
+                let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+                let topic_handler: Arc<TopicHandler> = Arc::new(Box::new(move |event: &TopicEvent| {
+                    if let MqttPayload::Block(block) = &event.payload {
+                        match <Block as crate::types::TryFromDto>::try_from_dto(block.clone()) {
+                            Ok(block) => {
+                                sender.send(block).ok();
+                            }
+                            Err(e) => log::warn!("[listen_tagged_data] invalid block: {e}"),
+                        }
+                    }
+                }));
+                let subscription = self
+                    .subscriber()
+                    .with_topics(topics.clone())
+                    .subscribe_handler(topic_handler.clone())
+                    .await;
+
+                match subscription {
+                    Ok(()) => {
+                        let task = self.background_tasks.spawn(
+                            "tagged-data-listener",
+                            receive_blocks(self.clone(), tag, receiver, handler),
+                        );
+                        return Ok(TaggedDataListener {
+                            client: self.clone(),
+                            subscription: Some((topics, topic_handler)),
+                            task,
+                        });
+                    }
+                    Err(e) => log::warn!("[listen_tagged_data] MQTT subscription failed, polling instead: {e}"),
+                }
+            }
+        }
+
+        let task = self
+            .background_tasks
+            .spawn("tagged-data-listener", poll_milestones(self.clone(), tag, handler));
+
+        Ok(TaggedDataListener {
+            #[cfg(feature = "mqtt")]
+            client: self.clone(),
+            #[cfg(feature = "mqtt")]
+            subscription: None,
+            task,
+        })
+    }
+}
+
+// Passes the blocks received over MQTT to the handler, and once more when they got referenced by a milestone
+#[cfg(feature = "mqtt")]
+async fn receive_blocks(
+    client: Client,
+    tag: Vec<u8>,
+    mut receiver: tokio::sync::mpsc::UnboundedReceiver<Block>,
+    handler: TaggedDataHandler,
+) {
+    let mut confirmations = FuturesUnordered::new();
+
+    loop {
+        tokio::select! {
+            block = receiver.recv() => {
+                let Some(block) = block else {
+                    break;
+                };
+                if let Some(event) = TaggedDataEvent::from_block(&block, &tag, TaggedDataStatus::Pending) {
+                    handler(&event);
+                    confirmations.push(wait_for_confirmation(&client, event));
+                }
+            }
+            Some(event) = confirmations.next(), if !confirmations.is_empty() => {
+                if let Some(event) = event {
+                    handler(&event);
+                }
+            }
+        }
+    }
+}
+
+// Returns the event with the status of the block once it got referenced by a milestone, `None` if it didn't in time
+#[cfg(feature = "mqtt")]
+async fn wait_for_confirmation(client: &Client, mut event: TaggedDataEvent) -> Option<TaggedDataEvent> {
+    for _ in 0..DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT {
+        tokio::time::sleep(Duration::from_secs(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL)).await;
+
+        match client.get_block_metadata(&event.block_id).await {
+            Ok(metadata) => {
+                event.status = TaggedDataStatus::from(&metadata);
+                if event.status != TaggedDataStatus::Pending {
+                    return Some(event);
+                }
+            }
+            Err(e) => log::debug!("[listen_tagged_data] block metadata request failed: {e}"),
+        }
+    }
+
+    None
+}
+
+// Passes the blocks with the tag that got referenced by every new confirmed milestone to the handler
+async fn poll_milestones(client: Client, tag: Vec<u8>, handler: TaggedDataHandler) {
+    // The first milestone that wasn't searched yet, starting after the currently confirmed one
+    let mut next_milestone_index = None;
+
+    loop {
+        match client.get_info().await {
+            Ok(info) => {
+                let confirmed_milestone_index = info.node_info.status.confirmed_milestone.index;
+                let start = *next_milestone_index.get_or_insert(confirmed_milestone_index + 1);
+
+                for milestone_index in start..=confirmed_milestone_index {
+                    match search_milestone_cone(&client, milestone_index, &tag).await {
+                        Ok(events) => events.iter().for_each(|event| handler(event)),
+                        Err(e) => {
+                            // The milestone is searched again in the next round
+                            log::warn!("[listen_tagged_data] searching milestone {milestone_index} failed: {e}");
+                            break;
+                        }
+                    }
+                    next_milestone_index = Some(milestone_index + 1);
+                }
+            }
+            Err(e) => log::warn!("[listen_tagged_data] node info request failed: {e}"),
+        }
+
+        tokio::time::sleep(TAGGED_DATA_POLL_INTERVAL).await;
+    }
+}
+
+// Returns the events of the blocks with the tag referenced by the milestone, only once the whole cone was searched, so
+// a failed search can be repeated without passing events twice to the handler
+async fn search_milestone_cone(client: &Client, milestone_index: u32, tag: &[u8]) -> Result<Vec<TaggedDataEvent>> {
+    let mut cone = Box::pin(client.get_milestone_cone(milestone_index));
+    let mut events = Vec::new();
+
+    while let Some(block) = cone.next().await {
+        let block = block?;
+        // Only the metadata of blocks with the tag is requested
+        if tagged_data_payload(&block).map_or(true, |tagged_data| tagged_data.tag() != tag) {
+            continue;
+        }
+        let status = match block.payload() {
+            // Only transactions can be conflicting
+            Some(Payload::Transaction(_)) => TaggedDataStatus::from(&client.get_block_metadata(&block.id()).await?),
+            _ => TaggedDataStatus::Included { milestone_index },
+        };
+        events.extend(TaggedDataEvent::from_block(&block, tag, status));
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{rand::parents::rand_parents, BlockBuilder};
+
+    #[cfg(feature = "mqtt")]
+    async fn add_topic_handler(client: &Client, topic: &Topic) -> Arc<TopicHandler> {
+        let handler: Arc<TopicHandler> = Arc::new(Box::new(|_: &TopicEvent| {}));
+        client
+            .mqtt
+            .topic_handlers
+            .write()
+            .await
+            .entry(topic.clone())
+            .or_default()
+            .push(handler.clone());
+
+        handler
+    }
+
+    #[cfg(feature = "mqtt")]
+    async fn mqtt_listener(client: &Client, topic: &Topic) -> TaggedDataListener {
+        TaggedDataListener {
+            client: client.clone(),
+            subscription: Some((vec![topic.clone()], add_topic_handler(client, topic).await)),
+            task: tokio::spawn(async {}),
+        }
+    }
+
+    #[test]
+    fn event_from_block() {
+        let block = BlockBuilder::new(rand_parents())
+            .with_payload(Payload::from(
+                TaggedDataPayload::new(b"tag".to_vec(), b"data".to_vec()).unwrap(),
+            ))
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            TaggedDataEvent::from_block(&block, b"tag", TaggedDataStatus::Pending),
+            Some(TaggedDataEvent {
+                block_id: block.id(),
+                tag: b"tag".to_vec(),
+                data: b"data".to_vec(),
+                status: TaggedDataStatus::Pending,
+            })
+        );
+        assert_eq!(
+            TaggedDataEvent::from_block(&block, b"other", TaggedDataStatus::Pending),
+            None
+        );
+        assert_eq!(
+            TaggedDataEvent::from_block(
+                &BlockBuilder::new(rand_parents()).finish().unwrap(),
+                b"tag",
+                TaggedDataStatus::Pending
+            ),
+            None
+        );
+    }
+
+    #[cfg(feature = "mqtt")]
+    #[tokio::test]
+    async fn stop_keeps_other_handlers() {
+        let client = Client::builder().finish().await.unwrap();
+        let topic = Topic::new("blocks/tagged-data/0x746167").unwrap();
+        let other_handler = add_topic_handler(&client, &topic).await;
+        let listener = mqtt_listener(&client, &topic).await;
+
+        assert!(listener.uses_mqtt());
+        listener.stop().await.unwrap();
+
+        let topic_handlers = client.mqtt.topic_handlers.read().await;
+        assert_eq!(topic_handlers[&topic].len(), 1);
+        assert!(Arc::ptr_eq(&topic_handlers[&topic][0], &other_handler));
+    }
+
+    #[cfg(feature = "mqtt")]
+    #[tokio::test]
+    async fn drop_removes_handler() {
+        let client = Client::builder().finish().await.unwrap();
+        let topic = Topic::new("blocks/tagged-data/0x746167").unwrap();
+        let listener = mqtt_listener(&client, &topic).await;
+
+        drop(listener);
+        // The handler is removed by a spawned task
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(client.mqtt.topic_handlers.read().await.is_empty());
+    }
+}
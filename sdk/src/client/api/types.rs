@@ -5,18 +5,23 @@ use crypto::keys::bip44::Bip44;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::secret::types::{InputSigningData, InputSigningDataDto},
+    client::{
+        api::{input_selection::InputSelection, transaction::validate_regular_transaction_essence_length},
+        secret::types::{InputSigningData, InputSigningDataDto},
+    },
     types::{
         block::{
             address::{dto::AddressDto, Address},
-            output::{dto::OutputDto, Output},
+            input::{Input, UtxoInput},
+            output::{dto::OutputDto, unlock_condition::ExpirationUnlockCondition, InputsCommitment, Output, OutputId},
             payload::{
                 transaction::{
                     dto::{TransactionEssenceDto, TransactionPayloadDto},
-                    TransactionEssence,
+                    RegularTransactionEssence, TransactionEssence,
                 },
-                TransactionPayload,
+                TaggedDataPayload, TransactionPayload,
             },
+            protocol::ProtocolParameters,
             Error,
         },
         TryFromDto, ValidationParams,
@@ -35,6 +40,133 @@ pub struct PreparedTransactionData {
     pub remainder: Option<RemainderData>,
 }
 
+impl PreparedTransactionData {
+    /// Builds the transaction essence of the inputs and outputs in their canonical order: inputs sorted like the input
+    /// selection sorts them, so alias and NFT owned inputs come after the input unlocking them, and outputs in the
+    /// given order. Every party building the transaction from the same inputs and outputs gets the same essence, which
+    /// multisig coordinators can check by comparing [`PreparedTransactionData::essence_hash()`] before signing.
+    pub fn from_inputs_and_outputs(
+        protocol_parameters: &ProtocolParameters,
+        inputs_data: Vec<InputSigningData>,
+        outputs: impl Into<Vec<Output>>,
+        tagged_data_payload: impl Into<Option<TaggedDataPayload>>,
+        remainder: impl Into<Option<RemainderData>>,
+    ) -> crate::client::Result<Self> {
+        let outputs = outputs.into();
+        let inputs_data = InputSelection::sort_input_signing_data(inputs_data, &outputs, None)?;
+
+        let inputs_commitment = InputsCommitment::new(inputs_data.iter().map(|input| &input.output));
+        let inputs = inputs_data
+            .iter()
+            .map(|input| Input::Utxo(UtxoInput::from(*input.output_id())))
+            .collect::<Vec<_>>();
+        let essence = RegularTransactionEssence::builder(protocol_parameters.network_id(), inputs_commitment)
+            .with_inputs(inputs)
+            .with_outputs(outputs)
+            .with_payload(tagged_data_payload.into())
+            .finish_with_params(protocol_parameters)?;
+
+        validate_regular_transaction_essence_length(&essence)?;
+
+        Ok(Self {
+            essence: TransactionEssence::Regular(essence),
+            inputs_data,
+            remainder: remainder.into(),
+        })
+    }
+
+    /// Returns the hash of the transaction essence, which is what every input signs.
+    pub fn essence_hash(&self) -> [u8; 32] {
+        self.essence.hash()
+    }
+
+    /// Returns the index and the expiration unlock condition of the outputs with one. Expirations are compared against
+    /// the timestamp of the milestone confirming the transaction, so these are the effective expiries of the outputs.
+    pub fn expirations(&self) -> impl Iterator<Item = (usize, &ExpirationUnlockCondition)> + '_ {
+        let TransactionEssence::Regular(essence) = &self.essence;
+
+        essence.outputs().iter().enumerate().filter_map(|(index, output)| {
+            output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.expiration())
+                .map(|expiration| (index, expiration))
+        })
+    }
+
+    /// Returns a summary of the outputs consumed and created by the transaction.
+    pub fn summary(&self) -> TransactionSummary {
+        let TransactionEssence::Regular(essence) = &self.essence;
+        let remainder_index = self.remainder.as_ref().and_then(|remainder| {
+            essence
+                .outputs()
+                .iter()
+                .position(|output| output == &remainder.output)
+                .map(|index| index as u16)
+        });
+
+        TransactionSummary {
+            consumed_output_ids: self.inputs_data.iter().map(|input| *input.output_id()).collect(),
+            consumed_amount: self.inputs_data.iter().map(|input| input.output.amount()).sum(),
+            created_amount: essence.outputs().iter().map(Output::amount).sum(),
+            remainder_index,
+        }
+    }
+}
+
+/// Summary of the outputs consumed and created by a prepared transaction, e.g. to preview it before it's signed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSummary {
+    /// The IDs of the consumed outputs.
+    pub consumed_output_ids: Vec<OutputId>,
+    /// The base coin amount of the consumed outputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub consumed_amount: u64,
+    /// The base coin amount of the created outputs, including the remainder.
+    #[serde(with = "crate::utils::serde::string")]
+    pub created_amount: u64,
+    /// The index of the remainder output in the outputs of the essence, if there is one.
+    pub remainder_index: Option<u16>,
+}
+
+/// A transaction built with input selection, output validation and storage deposit checks, but that is neither signed
+/// nor sent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DryRunTransaction {
+    /// The would-be transaction.
+    pub prepared_transaction_data: PreparedTransactionData,
+    /// Summary of the consumed and created outputs.
+    pub summary: TransactionSummary,
+}
+
+impl From<PreparedTransactionData> for DryRunTransaction {
+    fn from(prepared_transaction_data: PreparedTransactionData) -> Self {
+        Self {
+            summary: prepared_transaction_data.summary(),
+            prepared_transaction_data,
+        }
+    }
+}
+
+/// DryRunTransaction Dto
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunTransactionDto {
+    /// The would-be transaction.
+    pub prepared_transaction_data: PreparedTransactionDataDto,
+    /// Summary of the consumed and created outputs.
+    pub summary: TransactionSummary,
+}
+
+impl From<&DryRunTransaction> for DryRunTransactionDto {
+    fn from(value: &DryRunTransaction) -> Self {
+        Self {
+            prepared_transaction_data: PreparedTransactionDataDto::from(&value.prepared_transaction_data),
+            summary: value.summary.clone(),
+        }
+    }
+}
+
 /// PreparedTransactionData Dto
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -82,6 +214,22 @@ impl TryFromDto for PreparedTransactionData {
     }
 }
 
+impl<'de> Deserialize<'de> for PreparedTransactionData {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        PreparedTransactionDataDto::deserialize(d)
+            .and_then(|dto| Self::try_from_dto(dto).map_err(serde::de::Error::custom))
+    }
+}
+
+impl Serialize for PreparedTransactionData {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        PreparedTransactionDataDto::from(self).serialize(s)
+    }
+}
+
 /// Helper struct for offline signing
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SignedTransactionData {
@@ -128,6 +276,22 @@ impl TryFromDto for SignedTransactionData {
     }
 }
 
+impl<'de> Deserialize<'de> for SignedTransactionData {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        SignedTransactionDataDto::deserialize(d)
+            .and_then(|dto| Self::try_from_dto(dto).map_err(serde::de::Error::custom))
+    }
+}
+
+impl Serialize for SignedTransactionData {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SignedTransactionDataDto::from(self).serialize(s)
+    }
+}
+
 /// Data for a remainder output, used for ledger nano
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RemainderData {
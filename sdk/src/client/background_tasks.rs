@@ -0,0 +1,217 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Named background tasks of the client and the wallet
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::ClientInner;
+
+/// The state of a background task.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BackgroundTaskState {
+    /// The task is running.
+    Running,
+    /// The task finished on its own.
+    Finished,
+    /// The task was aborted or panicked before it finished.
+    Aborted,
+}
+
+/// The status of a background task spawned by the client or the wallet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundTaskStatus {
+    /// The name of the task, also used as name of the tokio task or thread.
+    pub name: String,
+    /// The state of the task.
+    pub state: BackgroundTaskState,
+    /// Unix timestamp in seconds of when the task was started.
+    pub started_at: u64,
+}
+
+/// Registry of the background tasks, only the last started task with a given name is kept.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BackgroundTasks {
+    inner: Arc<Mutex<BackgroundTasksInner>>,
+}
+
+#[derive(Debug, Default)]
+struct BackgroundTasksInner {
+    next_id: u64,
+    tasks: HashMap<&'static str, (u64, BackgroundTaskStatus)>,
+}
+
+/// Marks a registered task as finished once [`BackgroundTaskGuard::finish()`] was called, or as aborted if it's
+/// dropped before, e.g. because the task was aborted or panicked.
+#[derive(Debug)]
+#[must_use]
+pub(crate) struct BackgroundTaskGuard {
+    tasks: BackgroundTasks,
+    name: &'static str,
+    id: u64,
+    finished: bool,
+}
+
+impl BackgroundTasks {
+    /// Registers a running task, replacing a previous task with the same name.
+    pub(crate) fn register(&self, name: &'static str) -> BackgroundTaskGuard {
+        log::debug!("[BACKGROUND TASK] starting {name}");
+        let mut inner = self.inner.lock().expect("background tasks lock poisoned");
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.tasks.insert(
+            name,
+            (
+                id,
+                BackgroundTaskStatus {
+                    name: name.to_string(),
+                    state: BackgroundTaskState::Running,
+                    started_at: crate::utils::unix_timestamp_now().as_secs(),
+                },
+            ),
+        );
+
+        BackgroundTaskGuard {
+            tasks: self.clone(),
+            name,
+            id,
+            finished: false,
+        }
+    }
+
+    /// Spawns a named tokio task and registers it, the name is logged when the task starts, finishes or gets aborted.
+    /// It's only visible to tools like tokio-console if the crate is built with `--cfg tokio_unstable`.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn spawn<F>(&self, name: &'static str, future: F) -> tokio::task::JoinHandle<F::Output>
+    where
+        F: futures::Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let guard = self.register(name);
+
+        spawn_named(name, async move {
+            let output = future.await;
+            guard.finish();
+            output
+        })
+    }
+
+    /// Spawns a named thread and registers it, for tasks that run on their own runtime.
+    pub(crate) fn spawn_thread<F>(&self, name: &'static str, f: F) -> std::thread::JoinHandle<()>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let guard = self.register(name);
+
+        std::thread::Builder::new()
+            .name(name.to_string())
+            .spawn(move || {
+                f();
+                guard.finish();
+            })
+            .expect("failed to spawn background thread")
+    }
+
+    pub(crate) fn statuses(&self) -> Vec<BackgroundTaskStatus> {
+        let inner = self.inner.lock().expect("background tasks lock poisoned");
+        let mut statuses = inner
+            .tasks
+            .values()
+            .map(|(_, status)| status.clone())
+            .collect::<Vec<_>>();
+        statuses.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        statuses
+    }
+
+    fn set_state(&self, name: &'static str, id: u64, state: BackgroundTaskState) {
+        // Don't panic in a drop, a poisoned lock only means the status isn't updated
+        if let Ok(mut inner) = self.inner.lock() {
+            // The task could already be replaced by a newer one with the same name
+            if let Some((_, status)) = inner.tasks.get_mut(name).filter(|(task_id, _)| *task_id == id) {
+                status.state = state;
+            }
+        }
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), tokio_unstable))]
+fn spawn_named<F>(name: &'static str, future: F) -> tokio::task::JoinHandle<F::Output>
+where
+    F: futures::Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::task::Builder::new()
+        .name(name)
+        .spawn(future)
+        .expect("failed to spawn background task")
+}
+
+#[cfg(all(not(target_family = "wasm"), not(tokio_unstable)))]
+fn spawn_named<F>(_name: &'static str, future: F) -> tokio::task::JoinHandle<F::Output>
+where
+    F: futures::Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(future)
+}
+
+impl BackgroundTaskGuard {
+    pub(crate) fn finish(mut self) {
+        self.finished = true;
+    }
+}
+
+impl Drop for BackgroundTaskGuard {
+    fn drop(&mut self) {
+        let state = if self.finished {
+            log::debug!("[BACKGROUND TASK] {} finished", self.name);
+            BackgroundTaskState::Finished
+        } else {
+            log::debug!("[BACKGROUND TASK] {} aborted", self.name);
+            BackgroundTaskState::Aborted
+        };
+        self.tasks.set_state(self.name, self.id, state);
+    }
+}
+
+impl ClientInner {
+    /// Returns the status of the background tasks of the client and wallets using it, like the node syncing, the MQTT
+    /// event loop and the background syncing of the wallet. Tasks that stopped are listed until a task with the same
+    /// name is started again.
+    pub fn background_tasks(&self) -> Vec<BackgroundTaskStatus> {
+        self.background_tasks.statuses()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn task_states() {
+        let tasks = BackgroundTasks::default();
+
+        let guard = tasks.register("first");
+        assert_eq!(tasks.statuses()[0].state, BackgroundTaskState::Running);
+        guard.finish();
+        assert_eq!(tasks.statuses()[0].state, BackgroundTaskState::Finished);
+
+        let guard = tasks.register("second");
+        drop(guard);
+        assert_eq!(tasks.statuses()[1].state, BackgroundTaskState::Aborted);
+
+        // A replaced task doesn't change the state of the new one
+        let old_guard = tasks.register("second");
+        let _new_guard = tasks.register("second");
+        drop(old_guard);
+        assert_eq!(tasks.statuses().len(), 2);
+        assert_eq!(tasks.statuses()[1].state, BackgroundTaskState::Running);
+    }
+}
@@ -9,17 +9,25 @@ use serde::{Deserialize, Serialize};
 use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
 #[cfg(feature = "mqtt")]
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
+#[cfg(not(target_family = "wasm"))]
+use crate::client::{api::pow::SharedPowProgressCallback, node_manager::resolver::DnsResolver};
 use crate::{
     client::{
-        constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
-        error::Result,
+        api::pow::{PowProvider, SharedPowProvider},
+        constants::{
+            DEFAULT_API_TIMEOUT, DEFAULT_BLOCK_IDEMPOTENCY_TTL, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL,
+        },
+        error::{Error, Result},
+        network_profile::NetworkProfile,
         node_manager::{
-            builder::validate_url,
+            builder::{validate_proxy_url, validate_url},
+            connection_pool::ConnectionPoolOptions,
             node::{Node, NodeAuth},
+            retry_policy::RetryPolicy,
         },
         Client,
     },
-    types::block::protocol::ProtocolParameters,
+    types::block::{address::Hrp, protocol::ProtocolParameters},
 };
 
 /// Builder to construct client instance with sensible default values
@@ -44,6 +52,12 @@ pub struct ClientBuilder {
     /// Timeout when sending a block that requires remote proof of work
     #[serde(default = "default_remote_pow_timeout")]
     pub remote_pow_timeout: Duration,
+    /// Time in which posting a block for the same transaction again returns the block that was already posted
+    #[serde(default = "default_block_idempotency_ttl")]
+    pub block_idempotency_ttl: Duration,
+    /// Whether the blocks and outputs returned by nodes are validated locally
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    pub validate_responses: bool,
     /// The amount of threads to be used for proof of work
     #[cfg(not(target_family = "wasm"))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -52,6 +66,13 @@ pub struct ClientBuilder {
     #[cfg(not(target_family = "wasm"))]
     #[serde(default = "default_max_parallel_api_requests")]
     pub max_parallel_api_requests: usize,
+    /// Custom provider of the nonces for local PoW
+    #[serde(skip)]
+    pub(crate) pow_provider: Option<SharedPowProvider>,
+    /// Callback receiving the hash rate of the local PoW
+    #[cfg(not(target_family = "wasm"))]
+    #[serde(skip)]
+    pub(crate) pow_progress_callback: Option<SharedPowProgressCallback>,
 }
 
 fn default_api_timeout() -> Duration {
@@ -62,6 +83,10 @@ fn default_remote_pow_timeout() -> Duration {
     DEFAULT_REMOTE_POW_API_TIMEOUT
 }
 
+fn default_block_idempotency_ttl() -> Duration {
+    DEFAULT_BLOCK_IDEMPOTENCY_TTL
+}
+
 #[cfg(not(target_family = "wasm"))]
 fn default_max_parallel_api_requests() -> usize {
     super::constants::MAX_PARALLEL_API_REQUESTS
@@ -76,6 +101,8 @@ impl Default for NetworkInfo {
             fallback_to_local_pow: true,
             tips_interval: DEFAULT_TIPS_INTERVAL,
             latest_milestone_timestamp: None,
+            pinned_protocol_parameters: false,
+            network_profile: None,
         }
     }
 }
@@ -89,10 +116,15 @@ impl Default for ClientBuilder {
             network_info: NetworkInfo::default(),
             api_timeout: DEFAULT_API_TIMEOUT,
             remote_pow_timeout: DEFAULT_REMOTE_POW_API_TIMEOUT,
+            block_idempotency_ttl: DEFAULT_BLOCK_IDEMPOTENCY_TTL,
+            validate_responses: false,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: None,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: super::constants::MAX_PARALLEL_API_REQUESTS,
+            pow_provider: None,
+            #[cfg(not(target_family = "wasm"))]
+            pow_progress_callback: None,
         }
     }
 }
@@ -107,7 +139,11 @@ impl ClientBuilder {
     #[allow(unused_assignments)]
     pub fn from_json(mut self, client_config: &str) -> Result<Self> {
         self = serde_json::from_str::<Self>(client_config)?;
-        // validate URLs
+        self.validate_urls()?;
+        Ok(self)
+    }
+
+    fn validate_urls(&self) -> Result<()> {
         if let Some(node_dto) = &self.node_manager_builder.primary_node {
             let node: Node = node_dto.into();
             validate_url(node.url)?;
@@ -124,7 +160,10 @@ impl ClientBuilder {
             let node: Node = node_dto.into();
             validate_url(node.url)?;
         }
-        Ok(self)
+        if let Some(proxy) = &self.node_manager_builder.proxy {
+            validate_proxy_url(proxy.clone())?;
+        }
+        Ok(())
     }
 
     /// Adds an IOTA node by its URL.
@@ -177,7 +216,9 @@ impl ClientBuilder {
         self
     }
 
-    /// Set if quorum should be used or not
+    /// Set if quorum should be used or not. With quorum, reads like the node info, outputs, output metadata and indexer
+    /// queries are sent to `min_quorum_size` nodes and only accepted if `quorum_threshold`% of them return the same
+    /// response.
     pub fn with_quorum(mut self, quorum: bool) -> Self {
         self.node_manager_builder = self.node_manager_builder.with_quorum(quorum);
         self
@@ -196,6 +237,18 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the amount of nodes a block is posted to at the same time, to improve its propagation during congestion.
+    /// Only used with local PoW, as nodes doing remote PoW would each create a different block. Posting succeeds as
+    /// soon as one of the nodes accepts the block, see
+    /// [`Client::post_block_broadcast()`](crate::client::ClientInner::post_block_broadcast) for the outcome of
+    /// every node. Default: 1
+    pub fn with_broadcast_node_count(mut self, broadcast_node_count: usize) -> Self {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_broadcast_node_count(broadcast_node_count.max(1));
+        self
+    }
+
     /// Sets the MQTT broker options.
     #[cfg(feature = "mqtt")]
     #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
@@ -241,13 +294,130 @@ impl ClientBuilder {
         self
     }
 
-    /// Set User-Agent header for requests
-    /// Default is "iota-client/{version}"
-    pub fn with_user_agent(mut self, user_agent: String) -> Self {
+    /// Sets the time in which posting a block for the same transaction again, e.g. after a timeout, returns the ID of
+    /// the block that was already posted instead of posting the transaction again. Reattachments are always posted.
+    /// The posted blocks are remembered in the wallet storage if the client belongs to a wallet, otherwise in memory.
+    /// A zero TTL disables it. Default: 5 minutes
+    pub fn with_block_idempotency_ttl(mut self, ttl: Duration) -> Self {
+        self.block_idempotency_ttl = ttl;
+        self
+    }
+
+    /// Set User-Agent header for requests, an empty User-Agent isn't sent at all.
+    /// Default is "iota-sdk/{version}"
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
         self.node_manager_builder = self.node_manager_builder.with_user_agent(user_agent);
         self
     }
 
+    /// Sets whether the SDK name and version headers should be sent with every request, so node operators can
+    /// identify the SDK versions in use. Enabled by default.
+    pub fn with_identification_headers(mut self, identification_headers: bool) -> Self {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_identification_headers(identification_headers);
+        self
+    }
+
+    /// Sets how failed requests to nodes are retried, e.g. after a 503 response of a node under load. The policy
+    /// applies to every request sent to a node, before the next node is tried. Disabled by default.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Sets how connections to nodes are kept open and reused, e.g. to keep more idle connections for sync heavy
+    /// workloads. All requests of the client share one connection pool.
+    pub fn with_connection_pool(mut self, connection_pool: ConnectionPoolOptions) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_connection_pool(connection_pool);
+        self
+    }
+
+    /// Sends all requests to nodes through an HTTP or SOCKS5 proxy, e.g. `socks5h://127.0.0.1:9050` for Tor, where
+    /// `socks5h` lets the proxy resolve the host names. Credentials can be part of the URL, they aren't serialized with
+    /// the options, so they need to be set again for stored options. The MQTT connection goes through HTTP proxies and
+    /// through a local tunnel to SOCKS5 proxies, with HTTPS proxies subscribing fails with
+    /// `mqtt::Error::UnsupportedProxy` instead of connecting directly. The WebSocket event API can't be used with a
+    /// proxy and INX, a local connection, isn't proxied. The proxy is ignored on wasm, where the browser sends the
+    /// requests.
+    pub fn with_proxy(mut self, url: &str) -> Result<Self> {
+        self.node_manager_builder = self.node_manager_builder.with_proxy(url)?;
+        Ok(self)
+    }
+
+    /// Disables all client identification, no User-Agent nor SDK name and version headers are sent.
+    pub fn without_identification(self) -> Self {
+        self.with_user_agent(String::new()).with_identification_headers(false)
+    }
+
+    /// Sets a custom DNS resolver for the host names of node URLs, e.g. to use DNS over HTTPS. The addresses are tried
+    /// in the returned order.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_dns_resolver(mut self, dns_resolver: impl DnsResolver + 'static) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_dns_resolver(Arc::new(dns_resolver));
+        self
+    }
+
+    /// Sets a custom provider of the nonces for local PoW, e.g. to use a GPU or FPGA miner or an external PoW service
+    /// instead of the built-in miner.
+    pub fn with_pow_provider(mut self, pow_provider: impl PowProvider + 'static) -> Self {
+        self.pow_provider = Some(SharedPowProvider(Arc::new(pow_provider)));
+        self
+    }
+
+    /// Sets a callback receiving the hash rate of the local PoW in hashes per second, about once per second while the
+    /// built-in miner is mining, or whenever the PoW provider reports it.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_pow_progress_callback(mut self, progress_callback: impl Fn(f64) + Send + Sync + 'static) -> Self {
+        self.pow_progress_callback = Some(SharedPowProgressCallback(Arc::new(progress_callback)));
+        self
+    }
+
+    /// Sets the protocol parameters, so the client can pack and validate blocks without nodes. They are verified
+    /// against the ones of the nodes instead of being replaced by them, building the client fails if they don't match.
+    pub fn with_protocol_parameters(mut self, protocol_parameters: ProtocolParameters) -> Self {
+        self.network_info.protocol_parameters = protocol_parameters;
+        self.network_info.pinned_protocol_parameters = true;
+        self
+    }
+
+    /// Validates every block and output returned by a node locally, failing with [`Error::NodeResponseInvalid`] if
+    /// a block doesn't have the requested ID or transaction, enough PoW, valid signatures or storage deposits, or an
+    /// output isn't the one created by the transaction of its ID, in the block the node returns as the one including
+    /// that transaction. This protects against buggy or malicious nodes at the cost of packing and hashing every
+    /// response again, and of requesting the including block of every output. Outputs that weren't created by a
+    /// transaction, like the ones of the genesis snapshot, can't be validated. Raw responses aren't validated.
+    pub fn with_validate_responses(mut self, validate_responses: bool) -> Self {
+        self.validate_responses = validate_responses;
+        self
+    }
+
+    /// Sets the network the client is used for. Its public nodes are used if no node is set, and its protocol
+    /// parameters until the ones of the nodes are known. Nodes and addresses of another network are rejected.
+    pub fn with_network_profile(mut self, network_profile: NetworkProfile) -> Self {
+        self.network_info.network_profile = Some(network_profile);
+        self
+    }
+
+    /// Applies the network profile, if one is set, to the nodes and protocol parameters that aren't set.
+    fn apply_network_profile(mut self) -> Result<Self> {
+        let Some(network_profile) = self.network_info.network_profile else {
+            return Ok(self);
+        };
+
+        if self.node_manager_builder.primary_node.is_none() && self.node_manager_builder.nodes.is_empty() {
+            self = self.with_nodes(network_profile.nodes())?;
+        }
+        if self.network_info.pinned_protocol_parameters {
+            self.network_info
+                .check_network_profile(self.network_info.protocol_parameters.bech32_hrp())?;
+        } else if let Some(protocol_parameters) = network_profile.protocol_parameters() {
+            self.network_info.protocol_parameters = protocol_parameters;
+        }
+
+        Ok(self)
+    }
+
     /// Set maximum parallel API requests.
     #[cfg(not(target_family = "wasm"))]
     pub fn with_max_parallel_api_requests(mut self, max_parallel_api_requests: usize) -> Self {
@@ -257,9 +427,11 @@ impl ClientBuilder {
 
     /// Build the Client instance.
     #[cfg(not(target_family = "wasm"))]
-    pub async fn finish(self) -> Result<Client> {
+    pub async fn finish(mut self) -> Result<Client> {
         use tokio::sync::RwLock;
 
+        self = self.apply_network_profile()?;
+
         let node_sync_interval = self.node_manager_builder.node_sync_interval;
         let ignore_node_health = self.node_manager_builder.ignore_node_health;
         let nodes = self
@@ -278,7 +450,13 @@ impl ClientBuilder {
             network_info: RwLock::new(self.network_info),
             api_timeout: RwLock::new(self.api_timeout),
             remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+            block_idempotency_ttl: RwLock::new(self.block_idempotency_ttl),
+            submitted_blocks: Default::default(),
+            validate_responses: RwLock::new(self.validate_responses),
             pow_worker_count: RwLock::new(self.pow_worker_count),
+            pow_provider: self.pow_provider,
+            pow_progress_callback: self.pow_progress_callback,
+            pow_cancel: Default::default(),
             #[cfg(feature = "mqtt")]
             mqtt: super::MqttInner {
                 client: Default::default(),
@@ -286,14 +464,18 @@ impl ClientBuilder {
                 broker_options: RwLock::new(self.broker_options),
                 sender: RwLock::new(mqtt_event_tx),
                 receiver: RwLock::new(mqtt_event_rx),
+                #[cfg(feature = "inx")]
+                inx_listener: Default::default(),
+                socks5_tunnel: Default::default(),
             },
             request_pool: crate::client::request_pool::RequestPool::new(self.max_parallel_api_requests),
+            background_tasks: Default::default(),
         });
 
         client_inner.sync_nodes(&nodes, ignore_node_health).await?;
         let client_clone = client_inner.clone();
 
-        let sync_handle = tokio::spawn(async move {
+        let sync_handle = client_inner.background_tasks.spawn("node-sync", async move {
             client_clone
                 .start_sync_process(nodes, node_sync_interval, ignore_node_health)
                 .await
@@ -309,9 +491,11 @@ impl ClientBuilder {
 
     /// Build the Client instance.
     #[cfg(target_family = "wasm")]
-    pub async fn finish(self) -> Result<Client> {
+    pub async fn finish(mut self) -> Result<Client> {
         use tokio::sync::RwLock;
 
+        self = self.apply_network_profile()?;
+
         #[cfg(feature = "mqtt")]
         let (mqtt_event_tx, mqtt_event_rx) = tokio::sync::watch::channel(MqttEvent::Connected);
 
@@ -321,6 +505,11 @@ impl ClientBuilder {
                 network_info: RwLock::new(self.network_info),
                 api_timeout: RwLock::new(self.api_timeout),
                 remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+                block_idempotency_ttl: RwLock::new(self.block_idempotency_ttl),
+                submitted_blocks: Default::default(),
+                validate_responses: RwLock::new(self.validate_responses),
+                pow_provider: self.pow_provider,
+                pow_cancel: Default::default(),
                 #[cfg(feature = "mqtt")]
                 mqtt: super::MqttInner {
                     client: Default::default(),
@@ -328,8 +517,12 @@ impl ClientBuilder {
                     broker_options: RwLock::new(self.broker_options),
                     sender: RwLock::new(mqtt_event_tx),
                     receiver: RwLock::new(mqtt_event_rx),
+                    #[cfg(feature = "inx")]
+                    inx_listener: Default::default(),
+                    socks5_tunnel: Default::default(),
                 },
                 last_sync: tokio::sync::Mutex::new(None),
+                background_tasks: Default::default(),
             }),
         };
 
@@ -340,18 +533,65 @@ impl ClientBuilder {
         Self {
             node_manager_builder: NodeManagerBuilder::from(&*client.node_manager.read().await),
             #[cfg(feature = "mqtt")]
-            broker_options: *client.mqtt.broker_options.read().await,
+            broker_options: client.mqtt.broker_options.read().await.clone(),
             network_info: client.network_info.read().await.clone(),
             api_timeout: client.get_timeout().await,
             remote_pow_timeout: client.get_remote_pow_timeout().await,
+            block_idempotency_ttl: client.get_block_idempotency_ttl().await,
+            validate_responses: client.get_validate_responses().await,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: *client.pow_worker_count.read().await,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: client.request_pool.size().await,
+            pow_provider: client.pow_provider.clone(),
+            #[cfg(not(target_family = "wasm"))]
+            pow_progress_callback: client.pow_progress_callback.clone(),
         }
     }
 }
 
+/// Versioned configuration of a [`Client`], which can be stored as JSON or passed through the bindings to create a
+/// client with the same settings again. Custom DNS resolvers and PoW providers aren't part of the configuration.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientConfig {
+    /// The version of the configuration format.
+    pub version: u8,
+    /// The options of the client.
+    #[serde(flatten)]
+    pub options: ClientBuilder,
+}
+
+impl ClientConfig {
+    /// The current version of the configuration format.
+    pub const VERSION: u8 = 1;
+
+    /// Creates a configuration with the current version from client options.
+    pub fn new(options: ClientBuilder) -> Self {
+        Self {
+            version: Self::VERSION,
+            options,
+        }
+    }
+}
+
+impl Client {
+    /// Exports the configuration of the client, with the nodes, timeouts, PoW and quorum settings and the current
+    /// protocol parameters.
+    pub async fn export_config(&self) -> ClientConfig {
+        ClientConfig::new(ClientBuilder::from_client(self).await)
+    }
+
+    /// Creates a client from a configuration exported with [`Client::export_config()`].
+    pub async fn from_config(config: ClientConfig) -> Result<Self> {
+        if config.version != ClientConfig::VERSION {
+            return Err(Error::UnsupportedClientConfigVersion(config.version));
+        }
+        config.options.validate_urls()?;
+        config.options.finish().await
+    }
+}
+
 /// Struct containing network and PoW related information
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -371,6 +611,13 @@ pub struct NetworkInfo {
     /// The latest cached milestone timestamp.
     #[serde(skip)]
     pub latest_milestone_timestamp: Option<u32>,
+    /// Whether the protocol parameters were set by the user, they are then verified against the ones of the nodes
+    /// instead of being replaced by them.
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    pub pinned_protocol_parameters: bool,
+    /// The network the client is used for, nodes and addresses of other networks are rejected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_profile: Option<NetworkProfile>,
 }
 
 impl NetworkInfo {
@@ -398,6 +645,37 @@ impl NetworkInfo {
         self.latest_milestone_timestamp = latest_milestone_timestamp.into();
         self
     }
+
+    /// Updates the protocol parameters with the ones of a node, or verifies that they match if they are pinned.
+    pub(crate) fn update_protocol_parameters(
+        &mut self,
+        protocol_parameters: &ProtocolParameters,
+        node_url: &str,
+    ) -> Result<()> {
+        self.check_network_profile(protocol_parameters.bech32_hrp())?;
+        if !self.pinned_protocol_parameters {
+            self.protocol_parameters = protocol_parameters.clone();
+        } else if &self.protocol_parameters != protocol_parameters {
+            return Err(Error::ProtocolParametersMismatch {
+                url: node_url.to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that a bech32 HRP is the one of the network profile, if one is set.
+    pub(crate) fn check_network_profile(&self, bech32_hrp: &Hrp) -> Result<()> {
+        if let Some(network_profile) = self.network_profile {
+            let expected = network_profile.bech32_hrp();
+            if bech32_hrp != &expected {
+                return Err(Error::Bech32HrpMismatch {
+                    provided: bech32_hrp.to_string(),
+                    expected: expected.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 fn default_local_pow() -> bool {
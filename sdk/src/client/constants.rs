@@ -10,6 +10,10 @@ use crate::types::block::address::Hrp;
 /// Default timeout for all API requests apart from post_block with remote PoW
 pub(crate) const DEFAULT_API_TIMEOUT: Duration = Duration::from_secs(15);
 pub(crate) const DEFAULT_REMOTE_POW_API_TIMEOUT: Duration = Duration::from_secs(100);
+/// Timeout for connecting to a node, a node that can't be reached fails before the API timeout
+pub(crate) const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Default time in which posting a block for the same transaction again returns the block that was already posted
+pub(crate) const DEFAULT_BLOCK_IDEMPOTENCY_TTL: Duration = Duration::from_secs(300);
 pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
 pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
 /// Interval in seconds when new tips will be requested during PoW, so the final block always will be attached to a
@@ -19,7 +23,28 @@ pub(crate) const DEFAULT_TIPS_INTERVAL: u64 = 5;
 pub(crate) const NODE_SYNC_INTERVAL: Duration = Duration::from_secs(60);
 pub(crate) const DEFAULT_MIN_QUORUM_SIZE: usize = 3;
 pub(crate) const DEFAULT_QUORUM_THRESHOLD: usize = 66;
+pub(crate) const DEFAULT_BROADCAST_NODE_COUNT: usize = 1;
+pub(crate) const DEFAULT_RETRY_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+pub(crate) const DEFAULT_RETRY_MAX_BACKOFF: Duration = Duration::from_secs(10);
+/// Too many requests, bad gateway, service unavailable and gateway timeout
+pub(crate) const DEFAULT_RETRY_ON_STATUS: [u16; 4] = [429, 502, 503, 504];
+pub(crate) const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 32;
+pub(crate) const DEFAULT_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+pub(crate) const DEFAULT_TCP_KEEPALIVE: Duration = Duration::from_secs(60);
+pub(crate) const DEFAULT_HTTP2_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30);
+/// Weight of the latest node sync in the moving averages of the node latency and error rate
+pub(crate) const NODE_HEALTH_SMOOTHING: f64 = 0.3;
+/// Score penalty in milliseconds of a node whose node info requests always fail
+pub(crate) const NODE_HEALTH_ERROR_PENALTY_MS: f64 = 10_000.0;
+/// Score penalty in milliseconds per milestone a node is behind the other nodes
+pub(crate) const NODE_HEALTH_MILESTONE_LAG_PENALTY_MS: f64 = 1_000.0;
 pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+/// Header identifying the name of the SDK sending a request
+pub(crate) const CLIENT_NAME_HEADER: &str = "x-iota-client-name";
+/// Header identifying the version of the SDK sending a request
+pub(crate) const CLIENT_VERSION_HEADER: &str = "x-iota-client-version";
+pub(crate) const CLIENT_NAME: &str = env!("CARGO_PKG_NAME");
+pub(crate) const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[cfg(not(target_family = "wasm"))]
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
 /// Max allowed difference between the local time and latest milestone time, 5 minutes in seconds
@@ -8,23 +8,32 @@ use std::{sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 #[cfg(feature = "mqtt")]
 use {
-    crate::client::node_api::mqtt::{BrokerOptions, MqttEvent, TopicHandlerMap},
+    crate::client::node_api::mqtt::{socks5::Socks5Tunnel, BrokerOptions, MqttEvent, TopicHandlerMap},
     rumqttc::AsyncClient as MqttClient,
     tokio::sync::watch::{Receiver as WatchReceiver, Sender as WatchSender},
 };
 
 #[cfg(not(target_family = "wasm"))]
 use super::request_pool::RequestPool;
+#[cfg(not(target_family = "wasm"))]
+use crate::client::api::pow::SharedPowProgressCallback;
 #[cfg(target_family = "wasm")]
 use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
+        api::pow::{PowCancel, SharedPowProvider},
+        background_tasks::BackgroundTasks,
         builder::{ClientBuilder, NetworkInfo},
         error::Result,
         node_manager::NodeManager,
+        submitted_blocks::SubmittedBlocks,
         Error,
     },
-    types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters},
+    types::block::{
+        address::{Bech32Address, Hrp},
+        output::{Output, Rent, RentStructure},
+        protocol::ProtocolParameters,
+    },
 };
 
 /// An IOTA node client.
@@ -51,15 +60,28 @@ pub struct ClientInner {
     pub(crate) api_timeout: RwLock<Duration>,
     /// HTTP request timeout for remote PoW API call.
     pub(crate) remote_pow_timeout: RwLock<Duration>,
+    /// Time in which posting a block for the same transaction again returns the block that was already posted.
+    pub(crate) block_idempotency_ttl: RwLock<Duration>,
+    pub(crate) submitted_blocks: SubmittedBlocks,
+    /// Whether the blocks and outputs returned by nodes are validated locally.
+    pub(crate) validate_responses: RwLock<bool>,
     /// pow_worker_count for local PoW.
     #[cfg(not(target_family = "wasm"))]
     pub(crate) pow_worker_count: RwLock<Option<usize>>,
+    /// Custom provider of the nonces for local PoW.
+    pub(crate) pow_provider: Option<SharedPowProvider>,
+    /// Callback receiving the hash rate of the local PoW.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) pow_progress_callback: Option<SharedPowProgressCallback>,
+    /// Token of the local PoW of blocks that are finished without an own cancel token.
+    pub(crate) pow_cancel: RwLock<PowCancel>,
     #[cfg(feature = "mqtt")]
     pub(crate) mqtt: MqttInner,
     #[cfg(target_family = "wasm")]
     pub(crate) last_sync: tokio::sync::Mutex<Option<u32>>,
     #[cfg(not(target_family = "wasm"))]
     pub(crate) request_pool: RequestPool,
+    pub(crate) background_tasks: BackgroundTasks,
 }
 
 #[derive(Default)]
@@ -82,6 +104,11 @@ pub(crate) struct MqttInner {
     pub(crate) broker_options: RwLock<BrokerOptions>,
     pub(crate) sender: RwLock<WatchSender<MqttEvent>>,
     pub(crate) receiver: RwLock<WatchReceiver<MqttEvent>>,
+    /// The task streaming the topics from the INX interface of a node, if the broker options have an INX address.
+    #[cfg(feature = "inx")]
+    pub(crate) inx_listener: RwLock<Option<tokio::task::JoinHandle<()>>>,
+    /// The local tunnel of the MQTT connection, if the client uses a SOCKS5 proxy.
+    pub(crate) socks5_tunnel: RwLock<Option<Socks5Tunnel>>,
 }
 
 impl std::fmt::Debug for Client {
@@ -119,9 +146,9 @@ impl ClientInner {
                     return Ok(self.network_info.read().await.clone());
                 }
             }
-            let info = self.get_info().await?.node_info;
+            let info = self.get_info().await?;
             let mut client_network_info = self.network_info.write().await;
-            client_network_info.protocol_parameters = info.protocol.clone();
+            client_network_info.update_protocol_parameters(&info.node_info.protocol, &info.url)?;
             *self.last_sync.lock().await = Some(current_time + CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS);
         }
 
@@ -173,6 +200,11 @@ impl ClientInner {
         Ok(self.get_network_info().await?.protocol_parameters.token_supply())
     }
 
+    /// Estimates the storage deposit an output requires with the rent structure of the node we're connecting to.
+    pub async fn estimate_storage_deposit(&self, output: &Output) -> Result<u64> {
+        Ok(output.rent_cost(&self.get_rent_structure().await?))
+    }
+
     /// returns the tips interval
     pub async fn get_tips_interval(&self) -> u64 {
         self.network_info.read().await.tips_interval
@@ -191,6 +223,14 @@ impl ClientInner {
         *self.remote_pow_timeout.read().await
     }
 
+    pub(crate) async fn get_block_idempotency_ttl(&self) -> Duration {
+        *self.block_idempotency_ttl.read().await
+    }
+
+    pub(crate) async fn get_validate_responses(&self) -> bool {
+        *self.validate_responses.read().await
+    }
+
     /// returns the fallback_to_local_pow
     pub async fn get_fallback_to_local_pow(&self) -> bool {
         self.network_info.read().await.fallback_to_local_pow
@@ -207,4 +247,25 @@ impl ClientInner {
         };
         Ok(())
     }
+
+    /// Validates the recipient of an output before any output is built, so that all send paths reject the same invalid
+    /// recipient with the same error. The address and the optional return address need to have the HRP of the
+    /// connected network and the recipient needs to be sent base coins, or native tokens with an amount of 0 that is
+    /// replaced by the minimum storage deposit.
+    pub async fn validate_recipient(
+        &self,
+        address: &Bech32Address,
+        return_address: Option<&Bech32Address>,
+        amount: u64,
+        has_native_tokens: bool,
+    ) -> Result<()> {
+        self.bech32_hrp_matches(address.hrp()).await?;
+        if let Some(return_address) = return_address {
+            self.bech32_hrp_matches(return_address.hrp()).await?;
+        }
+        if amount == 0 && !has_native_tokens {
+            return Err(Error::ZeroAmount(address.to_string()));
+        }
+        Ok(())
+    }
 }
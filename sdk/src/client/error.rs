@@ -13,7 +13,7 @@ use serde::{
 
 use crate::{
     client::{api::input_selection::Error as InputSelectionError, node_api::indexer::QueryParameter},
-    types::block::semantic::ConflictReason,
+    types::block::{payload::transaction::TransactionId, semantic::ConflictReason},
 };
 
 /// Type alias of `Result` in iota-client
@@ -45,6 +45,12 @@ pub enum Error {
     /// Crypto.rs error
     #[error("{0}")]
     Crypto(#[from] crypto::Error),
+    /// A custom secret manager can't be created from its DTO
+    #[error("a custom secret manager can't be created from its config, it needs to be provided again")]
+    CustomSecretManagerConfig,
+    /// The transaction is a dry run
+    #[error("the transaction is a dry run and can't be signed or sent")]
+    DryRun,
     /// Address not found
     #[error("address: {address} not found in range: {range}")]
     InputAddressNotFound {
@@ -53,12 +59,41 @@ pub enum Error {
         /// The range in which the address was not found.
         range: String,
     },
+    /// The fields of an outputs query can't be combined, no output type can have all of them.
+    #[error("the outputs query can't match any output, no output type can have all of its fields")]
+    InvalidOutputsQuery,
     /// Invalid amount in API response
     #[error("invalid amount in API response: {0}")]
     InvalidAmount(String),
+    /// The inclusion of a block couldn't be verified
+    #[error("the inclusion of block {block_id} couldn't be verified: {reason}")]
+    InvalidInclusion {
+        /// The block ID.
+        block_id: String,
+        /// Why the inclusion couldn't be verified.
+        reason: String,
+    },
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
+    /// The foundry of a native token has no valid IRC-30 metadata
+    #[error("the foundry of native token {token_id} has no valid IRC-30 metadata: {reason}")]
+    InvalidNativeTokenMetadata {
+        /// The native token ID.
+        token_id: String,
+        /// Why the metadata is invalid.
+        reason: String,
+    },
+    /// The nonce of a PoW provider doesn't reach the minimum PoW score
+    #[error("the nonce {nonce} of the PoW provider reaches a PoW score of {score}, the minimum is {min_pow_score}")]
+    InvalidPowNonce {
+        /// The nonce of the provider.
+        nonce: u64,
+        /// The PoW score the nonce reaches.
+        score: f64,
+        /// The minimum PoW score.
+        min_pow_score: u32,
+    },
     /// The transaction essence is too large
     #[error("the transaction essence is too large. Its length is {length}, max length is {max_length}")]
     InvalidRegularTransactionEssenceLength {
@@ -67,6 +102,9 @@ pub enum Error {
         /// The max supported length.
         max_length: usize,
     },
+    /// The snapshot file is invalid or has an unsupported format
+    #[error("invalid snapshot: {0}")]
+    InvalidSnapshot(String),
     /// The transaction payload is too large
     #[error("the transaction payload is too large. Its length is {length}, max length is {max_length}")]
     InvalidTransactionPayloadLength {
@@ -75,6 +113,9 @@ pub enum Error {
         /// The max length.
         max_length: usize,
     },
+    /// IO error
+    #[error("`{0}`")]
+    Io(#[from] std::io::Error),
     /// JSON error
     #[error("{0}")]
     Json(#[from] serde_json::Error),
@@ -90,15 +131,32 @@ pub enum Error {
     /// Requested output id not found for this type
     #[error("No output found for {0}")]
     NoOutput(String),
+    /// A block or output returned by a node is invalid
+    #[error("node returned an invalid response for {id}: {reason}")]
+    NodeResponseInvalid {
+        /// The ID of the requested block or output.
+        id: String,
+        /// Why the response is invalid.
+        reason: String,
+    },
     /// PlaceholderSecretManager can't be used for address generation or signing
     #[error("placeholderSecretManager can't be used for address generation or signing")]
     PlaceholderSecretManager,
+    /// The local PoW was cancelled
+    #[error("proof of work was cancelled")]
+    PowCancelled,
     /// Rw lock failed.
     #[error("rw lock failed")]
     PoisonError,
     /// Prefix hex string convert error
     #[error("{0}")]
     PrefixHex(#[from] prefix_hex::Error),
+    /// The protocol parameters set on the client don't match the ones of a node
+    #[error("the protocol parameters of the client don't match the ones of node {url}")]
+    ProtocolParametersMismatch {
+        /// The URL of the node.
+        url: String,
+    },
     /// Error on quorum because not enough nodes are available
     #[error("not enough nodes for quorum: {available_nodes} < {minimum_threshold}")]
     QuorumPoolSizeError {
@@ -144,9 +202,15 @@ pub enum Error {
     /// The semantic validation of a transaction failed.
     #[error("the semantic validation of a transaction failed with conflict reason: {} - {0:?}", *.0 as u8)]
     TransactionSemantic(ConflictReason),
+    /// The transaction was already posted within the block idempotency TTL, but the block isn't known yet.
+    #[error("the transaction {0} was already posted, but its block isn't included yet")]
+    TransactionAlreadyPosted(TransactionId),
     /// An indexer API request contains a query parameter not supported by the endpoint.
     #[error("an indexer API request contains a query parameter not supported by the endpoint: {0}.")]
     UnsupportedQueryParameter(QueryParameter),
+    /// A client configuration has a version that isn't supported.
+    #[error("unsupported client config version {0}")]
+    UnsupportedClientConfigVersion(u8),
     /// Unpack error
     #[error("{0}")]
     Unpack(#[from] packable::error::UnpackError<crate::types::block::Error, UnexpectedEOF>),
@@ -165,10 +229,35 @@ pub enum Error {
     /// Missing BIP32 chain to sign with.
     #[error("missing BIP32 chain to sign with")]
     MissingBip32Chain,
+    /// The secret manager returned another number of signature unlocks than the number of chains to sign with.
+    #[error("the secret manager returned {found} signature unlocks, expected: {expected}")]
+    SignatureUnlockCount {
+        /// The number of chains to sign with.
+        expected: usize,
+        /// The number of returned signature unlocks.
+        found: usize,
+    },
+    /// A partial signature signs another transaction essence.
+    #[error("the partial signature signs essence hash {found}, expected: {expected}")]
+    EssenceHashMismatch {
+        /// The essence hash of the transaction.
+        expected: String,
+        /// The essence hash signed by the partial signature.
+        found: String,
+    },
+    /// A partial signature isn't from an address that has to sign the transaction.
+    #[error("the partial signature of {0} isn't from an address that has to sign the transaction")]
+    UnexpectedSigner(String),
+    /// Signatures of addresses that have to sign the transaction are missing.
+    #[error("signatures of the addresses {0:?} are missing")]
+    MissingSignatures(Vec<String>),
+    /// A recipient is sent neither base coins nor native tokens
+    #[error("nothing is sent to {0}, the amount is 0 and no native tokens are sent")]
+    ZeroAmount(String),
 
     /// Participation error
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "participation_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation_api")))]
     #[error("{0}")]
     Participation(#[from] crate::types::api::plugins::participation::error::Error),
 
@@ -36,27 +36,32 @@ macro_rules! lazy_static {
 }
 
 pub mod api;
+pub mod background_tasks;
 pub mod builder;
 pub mod constants;
 pub mod core;
 pub mod error;
+pub mod network_profile;
 pub mod node_api;
 pub mod node_manager;
 #[cfg(not(target_family = "wasm"))]
 pub(crate) mod request_pool;
 pub mod secret;
+pub mod snapshot;
 pub mod storage;
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
 pub mod stronghold;
+pub(crate) mod submitted_blocks;
 pub mod utils;
 
 #[cfg(feature = "mqtt")]
 pub use self::node_api::mqtt;
 pub use self::{
-    builder::{ClientBuilder, NetworkInfo},
+    builder::{ClientBuilder, ClientConfig, NetworkInfo},
     core::*,
     error::*,
+    network_profile::NetworkProfile,
     node_api::core::routes::NodeInfoWrapper,
     utils::*,
 };
@@ -0,0 +1,89 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Presets of the well-known networks, so a client can't be pointed at nodes or addresses of another network by mistake
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::constants::{
+        IOTA_BECH32_HRP, IOTA_COIN_TYPE, SHIMMER_BECH32_HRP, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP,
+    },
+    types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters, PROTOCOL_VERSION},
+};
+
+/// The bech32 HRP of private networks, like the private tangle of Hornet.
+pub const PRIVATE_BECH32_HRP: Hrp = Hrp::from_str_unchecked("tst");
+
+/// A preset of a network, with its bech32 HRP, coin type, protocol parameters and known public nodes.
+///
+/// The client uses the nodes of the profile if no node is set and the protocol parameters of the profile until it
+/// gets the ones of the nodes. Nodes with another bech32 HRP than the one of the profile are rejected, and so are
+/// addresses converted with [`Client::address_to_bech32()`](crate::client::ClientInner::address_to_bech32).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NetworkProfile {
+    /// The IOTA mainnet.
+    Mainnet,
+    /// The Shimmer mainnet.
+    Shimmer,
+    /// The public Shimmer testnet.
+    Testnet,
+    /// A private network, e.g. a private tangle running on the local machine.
+    Private,
+}
+
+impl NetworkProfile {
+    /// Returns the bech32 HRP of the addresses of the network.
+    pub fn bech32_hrp(&self) -> Hrp {
+        match self {
+            Self::Mainnet => IOTA_BECH32_HRP,
+            Self::Shimmer => SHIMMER_BECH32_HRP,
+            Self::Testnet => SHIMMER_TESTNET_BECH32_HRP,
+            Self::Private => PRIVATE_BECH32_HRP,
+        }
+    }
+
+    /// Returns the BIP44 coin type used to generate the addresses of the network.
+    pub fn coin_type(&self) -> u32 {
+        match self {
+            Self::Mainnet => IOTA_COIN_TYPE,
+            Self::Shimmer | Self::Testnet | Self::Private => SHIMMER_COIN_TYPE,
+        }
+    }
+
+    /// Returns the protocol parameters of the network, `None` for private networks whose parameters can only be
+    /// known from their nodes.
+    pub fn protocol_parameters(&self) -> Option<ProtocolParameters> {
+        let (network_name, rent_structure, token_supply) = match self {
+            Self::Mainnet => ("iota-mainnet", RentStructure::new(250, 10, 1), 4_600_000_000_000_000),
+            Self::Shimmer => ("shimmer", RentStructure::default(), 1_813_620_509_061_365),
+            Self::Testnet => ("testnet", RentStructure::default(), 1_450_896_407_249_092),
+            Self::Private => return None,
+        };
+
+        // PANIC: These values are known to be correct.
+        Some(
+            ProtocolParameters::new(
+                PROTOCOL_VERSION,
+                network_name.to_owned(),
+                self.bech32_hrp(),
+                1500,
+                15,
+                rent_structure,
+                token_supply,
+            )
+            .unwrap(),
+        )
+    }
+
+    /// Returns the URLs of the known public nodes of the network.
+    pub fn nodes(&self) -> &'static [&'static str] {
+        match self {
+            Self::Mainnet => &["https://api.stardust-mainnet.iotaledger.net"],
+            Self::Shimmer => &["https://api.shimmer.network"],
+            Self::Testnet => &["https://api.testnet.shimmer.network"],
+            Self::Private => &["http://localhost:14265"],
+        }
+    }
+}
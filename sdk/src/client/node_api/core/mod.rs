@@ -4,6 +4,9 @@
 //! IOTA node core API
 
 pub mod routes;
+mod validation;
+
+use futures::{StreamExt, TryStreamExt};
 
 use crate::{
     client::{Client, Result},
@@ -11,9 +14,30 @@ use crate::{
 };
 
 impl Client {
-    /// Request outputs by their output ID in parallel
+    /// Request outputs by their output ID in parallel, with at most as many requests in flight as set with
+    /// [`ClientBuilder::with_max_parallel_api_requests()`](crate::client::ClientBuilder::with_max_parallel_api_requests).
+    /// The outputs are returned in the order of the provided IDs.
     pub async fn get_outputs(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
-        futures::future::try_join_all(output_ids.iter().map(|id| self.get_output(id))).await
+        #[cfg(not(target_family = "wasm"))]
+        let concurrency = self.request_pool.size().await;
+        #[cfg(target_family = "wasm")]
+        let concurrency = output_ids.len();
+
+        self.get_outputs_with_concurrency(output_ids, concurrency).await
+    }
+
+    /// Request outputs by their output ID in parallel, with at most `concurrency` requests in flight at the same time.
+    /// The outputs are returned in the order of the provided IDs.
+    pub async fn get_outputs_with_concurrency(
+        &self,
+        output_ids: &[OutputId],
+        concurrency: usize,
+    ) -> Result<Vec<OutputWithMetadata>> {
+        futures::stream::iter(output_ids)
+            .map(|output_id| self.get_output(output_id))
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await
     }
 
     /// Request outputs by their output ID in parallel, ignoring failed requests
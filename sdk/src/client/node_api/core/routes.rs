@@ -3,14 +3,17 @@
 
 //! Node core API routes.
 
+use futures::Future;
 use packable::PackableExt;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use super::validation::{validate_block, validate_included_block, validate_output};
 use crate::{
     client::{
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_USER_AGENT},
         node_manager::node::{Node, NodeAuth},
+        submitted_blocks::SubmittedBlocks,
         Client, ClientInner, Error, Result,
     },
     types::{
@@ -43,6 +46,28 @@ pub struct NodeInfoWrapper {
     pub url: String,
 }
 
+/// The outcome of posting a block to a node, see [`ClientInner::post_block_broadcast()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeBroadcastOutcome {
+    /// The url of the node.
+    pub url: String,
+    /// The BlockId returned by the node, if it accepted the block.
+    pub block_id: Option<BlockId>,
+    /// The error returned for the node, if it didn't accept the block.
+    pub error: Option<String>,
+}
+
+/// The result of [`ClientInner::post_block_broadcast()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockBroadcast {
+    /// The BlockId of the posted block.
+    pub block_id: BlockId,
+    /// The outcome of every node the block was posted to, empty if remote PoW was used.
+    pub outcomes: Vec<NodeBroadcastOutcome>,
+}
+
 impl ClientInner {
     // Node routes.
 
@@ -53,7 +78,15 @@ impl ClientInner {
 
         let mut url = Url::parse(url)?;
         url.set_path(path);
-        let status = crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
+        // Reuse the connections of the client, but don't retry health checks
+        let http_client = self
+            .node_manager
+            .read()
+            .await
+            .http_client
+            .clone()
+            .with_retry_policy(Default::default());
+        let status = http_client
             .get(
                 Node {
                     url,
@@ -82,7 +115,15 @@ impl ClientInner {
     /// Returns general information about the node.
     /// GET /api/core/v2/info
     pub async fn get_info(&self) -> Result<NodeInfoWrapper> {
-        self.get_request(INFO_PATH, None, false, false).await
+        self.get_request(INFO_PATH, None, true, false).await
+    }
+
+    /// Returns general information about the node at the URL, which doesn't have to be one of the nodes of the client.
+    /// The request is sent with the user agent, identification headers, connection pool and proxy of the client.
+    /// GET /api/core/v2/info
+    pub async fn get_node_info_from(&self, url: &str, auth: Option<NodeAuth>) -> Result<InfoResponse> {
+        let http_client = self.node_manager.read().await.http_client.clone();
+        Client::get_node_info_with_http_client(&http_client, url, auth).await
     }
 
     // Tangle routes.
@@ -99,11 +140,29 @@ impl ClientInner {
 
     // Blocks routes.
 
-    /// Returns the BlockId of the submitted block.
+    /// Returns the BlockId of the submitted block. If a block with the same transaction was already posted within the
+    /// [block idempotency TTL](crate::client::ClientBuilder::with_block_idempotency_ttl()), e.g. before a timeout, the
+    /// BlockId of that block is returned instead of posting the transaction again. If that BlockId isn't known, because
+    /// the node does the PoW and the request failed, it's returned once the transaction is included, and
+    /// [`Error::TransactionAlreadyPosted`] before.
     /// POST JSON to /api/core/v2/blocks
     pub async fn post_block(&self, block: &Block) -> Result<BlockId> {
+        self.post_block_idempotent(block, self.post_block_json(block)).await
+    }
+
+    async fn post_block_json(&self, block: &Block) -> Result<BlockId> {
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow().await;
+        if local_pow && self.node_manager.read().await.broadcast_node_count > 1 {
+            let timeout = self.get_timeout().await;
+            let response = self
+                .node_manager
+                .read()
+                .await
+                .broadcast_request_bytes_first::<SubmitBlockResponse>(path, timeout, &block.pack_to_vec())
+                .await?;
+            return Ok(response.block_id);
+        }
         let timeout = if local_pow {
             self.get_timeout().await
         } else {
@@ -154,9 +213,14 @@ impl ClientInner {
         Ok(response.block_id)
     }
 
-    /// Returns the BlockId of the submitted block.
+    /// Returns the BlockId of the submitted block, like [`Self::post_block()`] it returns the BlockId of the block with
+    /// the same transaction if it was already posted.
     /// POST /api/core/v2/blocks
     pub async fn post_block_raw(&self, block: &Block) -> Result<BlockId> {
+        self.post_block_idempotent(block, self.post_block_packed(block)).await
+    }
+
+    async fn post_block_packed(&self, block: &Block) -> Result<BlockId> {
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow().await;
         let timeout = if local_pow {
@@ -206,17 +270,157 @@ impl ClientInner {
         Ok(response.block_id)
     }
 
+    // Posts a block with a transaction only once within the block idempotency TTL, whatever its parents and nonce
+    async fn post_block_idempotent(
+        &self,
+        block: &Block,
+        post: impl Future<Output = Result<BlockId>>,
+    ) -> Result<BlockId> {
+        let ttl = self.get_block_idempotency_ttl().await;
+        let Some(key) = SubmittedBlocks::key(block).filter(|_| !ttl.is_zero()) else {
+            return post.await;
+        };
+
+        if let Some(submitted_block) = self.submitted_blocks.get(&key.0, ttl).await {
+            let block_id = match submitted_block.block_id {
+                Some(block_id) => block_id,
+                // The request timed out before the node returned the BlockId, so it can only be found once included
+                None => {
+                    self.get_included_block_metadata(&submitted_block.transaction_id)
+                        .await
+                        .map_err(|_| Error::TransactionAlreadyPosted(submitted_block.transaction_id))?
+                        .block_id
+                }
+            };
+            log::debug!("[post_block] the transaction was already posted in {block_id}");
+            return Ok(block_id);
+        }
+        // Remembered before posting, so a retry after a timeout doesn't post the transaction again even if the node
+        // received it. With local PoW the BlockId is already known.
+        let block_id = self.get_local_pow().await.then(|| block.id());
+        self.submitted_blocks.insert(key, block_id, ttl).await;
+
+        match post.await {
+            Ok(block_id) => {
+                self.submitted_blocks.insert(key, Some(block_id), ttl).await;
+                Ok(block_id)
+            }
+            Err(e) => {
+                // Only keep the block if it's unknown whether the node received it
+                if !matches!(e, Error::Node(crate::client::node_api::error::Error::Reqwest(_))) {
+                    self.submitted_blocks.remove(&key.0).await;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Posts a reattachment of a block with a transaction, which isn't deduplicated like [`Self::post_block()`], and
+    /// remembers it as the block posted for the transaction.
+    pub(crate) async fn post_reattachment(&self, block: &Block) -> Result<BlockId> {
+        let block_id = self.post_block_packed(block).await?;
+        let ttl = self.get_block_idempotency_ttl().await;
+
+        if let Some(key) = SubmittedBlocks::key(block).filter(|_| !ttl.is_zero()) {
+            self.submitted_blocks.insert(key, Some(block_id), ttl).await;
+        }
+
+        Ok(block_id)
+    }
+
+    /// Returns the BlockId of the submitted block, which is passed through as already serialized bytes, e.g. from
+    /// [`Self::get_block_raw()`], without being unpacked and packed again. Because the block can't be changed, there
+    /// is no fallback to local PoW if remote PoW is unavailable.
+    /// POST /api/core/v2/blocks
+    pub async fn post_block_bytes(&self, block_bytes: &[u8]) -> Result<BlockId> {
+        let path = "api/core/v2/blocks";
+        let local_pow = self.get_local_pow().await;
+        let timeout = if local_pow {
+            self.get_timeout().await
+        } else {
+            self.get_remote_pow_timeout().await
+        };
+
+        let response = self
+            .node_manager
+            .read()
+            .await
+            .post_request_bytes::<SubmitBlockResponse>(path, timeout, block_bytes, local_pow)
+            .await?;
+
+        Ok(response.block_id)
+    }
+
+    /// Posts the block to `broadcast_node_count` nodes at the same time, see
+    /// [`ClientBuilder::with_broadcast_node_count()`](crate::client::ClientBuilder::with_broadcast_node_count), and
+    /// returns the outcome of every node. Succeeds if one of the nodes accepted the block, but waits for all of them,
+    /// unlike [`Client::post_block()`](crate::client::ClientInner::post_block) which returns once the first node
+    /// accepted it. With remote PoW the block is only posted to a single node, as every node would create a different
+    /// block.
+    /// POST /api/core/v2/blocks
+    pub async fn post_block_broadcast(&self, block: &Block) -> Result<BlockBroadcast> {
+        let path = "api/core/v2/blocks";
+        if !self.get_local_pow().await {
+            return Ok(BlockBroadcast {
+                block_id: self.post_block_raw(block).await?,
+                outcomes: Vec::new(),
+            });
+        }
+        let timeout = self.get_timeout().await;
+
+        let results = self
+            .node_manager
+            .read()
+            .await
+            .broadcast_request_bytes::<SubmitBlockResponse>(path, timeout, &block.pack_to_vec())
+            .await?;
+
+        let mut block_id = None;
+        let mut error = None;
+        let mut outcomes = Vec::with_capacity(results.len());
+        for (node, result) in results {
+            match result {
+                Ok(response) => {
+                    block_id.get_or_insert(response.block_id);
+                    outcomes.push(NodeBroadcastOutcome {
+                        url: node.url.to_string(),
+                        block_id: Some(response.block_id),
+                        error: None,
+                    });
+                }
+                Err(e) => {
+                    log::warn!("posting block to {} failed: {e}", node.url);
+                    outcomes.push(NodeBroadcastOutcome {
+                        url: node.url.to_string(),
+                        block_id: None,
+                        error: Some(e.to_string()),
+                    });
+                    error.replace(e);
+                }
+            }
+        }
+
+        // Safe unwrap, there are nodes because we throw on empty nodepool.
+        // Each node will throw an error or return a BlockId
+        block_id
+            .map(|block_id| BlockBroadcast { block_id, outcomes })
+            .ok_or_else(|| error.unwrap())
+    }
+
     /// Finds a block by its BlockId. This method returns the given block object.
     /// GET /api/core/v2/blocks/{BlockId}
     pub async fn get_block(&self, block_id: &BlockId) -> Result<Block> {
         let path = &format!("api/core/v2/blocks/{block_id}");
 
         let dto = self.get_request::<BlockDto>(path, None, false, true).await?;
+        let protocol_parameters = self.get_protocol_parameters().await?;
+        let block = Block::try_from_dto_with_params(dto, &protocol_parameters)?;
 
-        Ok(Block::try_from_dto_with_params(
-            dto,
-            self.get_protocol_parameters().await?,
-        )?)
+        if self.get_validate_responses().await {
+            validate_block(&block, Some(block_id), &protocol_parameters)?;
+        }
+
+        Ok(block)
     }
 
     /// Finds a block by its BlockId. This method returns the given block raw data.
@@ -242,10 +446,16 @@ impl ClientInner {
     pub async fn get_output(&self, output_id: &OutputId) -> Result<OutputWithMetadata> {
         let path = &format!("api/core/v2/outputs/{output_id}");
 
-        let response: OutputWithMetadataResponse = self.get_request(path, None, false, true).await?;
+        let response: OutputWithMetadataResponse = self.get_request(path, None, true, true).await?;
 
-        let token_supply = self.get_token_supply().await?;
-        let output = Output::try_from_dto_with_params(response.output, token_supply)?;
+        let protocol_parameters = self.get_protocol_parameters().await?;
+        let output = Output::try_from_dto_with_params(response.output, protocol_parameters.token_supply())?;
+
+        if self.get_validate_responses().await {
+            // Only the transaction creating the output commits to it
+            let included_block = self.get_included_block(output_id.transaction_id()).await?;
+            validate_output(output_id, &output, &response.metadata, &included_block)?;
+        }
 
         Ok(OutputWithMetadata::new(output, response.metadata))
     }
@@ -263,7 +473,7 @@ impl ClientInner {
     pub async fn get_output_metadata(&self, output_id: &OutputId) -> Result<OutputMetadata> {
         let path = &format!("api/core/v2/outputs/{output_id}/metadata");
 
-        self.get_request::<OutputMetadata>(path, None, false, true).await
+        self.get_request::<OutputMetadata>(path, None, true, true).await
     }
 
     /// Gets all stored receipts.
@@ -301,11 +511,14 @@ impl ClientInner {
         let path = &format!("api/core/v2/transactions/{transaction_id}/included-block");
 
         let dto = self.get_request::<BlockDto>(path, None, true, true).await?;
+        let protocol_parameters = self.get_protocol_parameters().await?;
+        let block = Block::try_from_dto_with_params(dto, &protocol_parameters)?;
 
-        Ok(Block::try_from_dto_with_params(
-            dto,
-            self.get_protocol_parameters().await?,
-        )?)
+        if self.get_validate_responses().await {
+            validate_included_block(&block, transaction_id, &protocol_parameters)?;
+        }
+
+        Ok(block)
     }
 
     /// Returns the block, as raw bytes, that was included in the ledger for a given TransactionId.
@@ -417,8 +630,25 @@ impl ClientInner {
 }
 
 impl Client {
-    /// GET /api/core/v2/info endpoint
+    /// GET /api/core/v2/info endpoint, with the default user agent and without a proxy.
+    #[deprecated(
+        since = "1.0.4",
+        note = "use `ClientInner::get_node_info_from()` to send the request with the settings of a client"
+    )]
     pub async fn get_node_info(url: &str, auth: Option<NodeAuth>) -> Result<InfoResponse> {
+        Self::get_node_info_with_http_client(
+            &crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string(), true),
+            url,
+            auth,
+        )
+        .await
+    }
+
+    pub(crate) async fn get_node_info_with_http_client(
+        http_client: &crate::client::node_manager::http_client::HttpClient,
+        url: &str,
+        auth: Option<NodeAuth>,
+    ) -> Result<InfoResponse> {
         let mut url = crate::client::node_manager::builder::validate_url(Url::parse(url)?)?;
         if let Some(auth) = &auth {
             if let Some((name, password)) = &auth.basic_auth_name_pwd {
@@ -431,19 +661,18 @@ impl Client {
         let path = "api/core/v2/info";
         url.set_path(path);
 
-        let resp: InfoResponse =
-            crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
-                .get(
-                    Node {
-                        url,
-                        auth,
-                        disabled: false,
-                    },
-                    DEFAULT_API_TIMEOUT,
-                )
-                .await?
-                .into_json()
-                .await?;
+        let resp: InfoResponse = http_client
+            .get(
+                Node {
+                    url,
+                    auth,
+                    disabled: false,
+                },
+                DEFAULT_API_TIMEOUT,
+            )
+            .await?
+            .into_json()
+            .await?;
 
         Ok(resp)
     }
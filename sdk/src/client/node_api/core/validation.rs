@@ -0,0 +1,323 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validating the blocks and outputs returned by nodes locally, so a buggy or malicious node can't make the client
+//! accept them, see [`ClientBuilder::with_validate_responses()`](crate::client::ClientBuilder::with_validate_responses)
+
+use packable::PackableExt;
+
+use crate::{
+    client::{Error, Result},
+    pow::score::PowScorer,
+    types::block::{
+        output::{Output, OutputId, OutputMetadata},
+        payload::{
+            transaction::{TransactionEssence, TransactionId},
+            Payload,
+        },
+        protocol::ProtocolParameters,
+        signature::Signature,
+        unlock::Unlock,
+        Block, BlockId,
+    },
+};
+
+fn invalid(id: impl ToString, reason: impl ToString) -> Error {
+    Error::NodeResponseInvalid {
+        id: id.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Validates a block returned by a node: it has to have the requested ID, be valid when it's unpacked again, have
+/// enough PoW unless it's a milestone, and its transaction has to have valid signatures and storage deposits.
+pub(crate) fn validate_block(
+    block: &Block,
+    block_id: Option<&BlockId>,
+    protocol_parameters: &ProtocolParameters,
+) -> Result<()> {
+    let bytes = block.pack_to_vec();
+    let id = block.id();
+
+    if let Some(block_id) = block_id {
+        if block_id != &id {
+            return Err(invalid(block_id, format!("the node returned block {id}")));
+        }
+    }
+
+    Block::unpack_strict(&bytes, protocol_parameters).map_err(|e| invalid(id, e))?;
+
+    // Milestones are issued without PoW
+    if !matches!(block.payload(), Some(Payload::Milestone(_))) {
+        let pow_score = PowScorer::new().score(&bytes);
+        if pow_score < protocol_parameters.min_pow_score() as f64 {
+            return Err(invalid(
+                id,
+                format!(
+                    "PoW score {pow_score} is lower than the minimum of {}",
+                    protocol_parameters.min_pow_score()
+                ),
+            ));
+        }
+    }
+
+    if let Some(Payload::Transaction(transaction)) = block.payload() {
+        let essence_hash = transaction.essence().hash();
+
+        for (index, unlock) in transaction.unlocks().iter().enumerate() {
+            if let Unlock::Signature(unlock) = unlock {
+                let Signature::Ed25519(signature) = unlock.signature();
+                if !signature.verify(&essence_hash) {
+                    return Err(invalid(id, format!("invalid signature in unlock {index}")));
+                }
+            }
+        }
+
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        for (index, output) in essence.outputs().iter().enumerate() {
+            output
+                .verify_storage_deposit(
+                    *protocol_parameters.rent_structure(),
+                    protocol_parameters.token_supply(),
+                )
+                .map_err(|e| invalid(id, format!("output {index}: {e}")))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the block a node returned as the one including a transaction: it has to be a valid block, see
+/// [`validate_block()`], with the requested transaction as payload.
+pub(crate) fn validate_included_block(
+    block: &Block,
+    transaction_id: &TransactionId,
+    protocol_parameters: &ProtocolParameters,
+) -> Result<()> {
+    match block.payload() {
+        Some(Payload::Transaction(transaction)) if &transaction.id() == transaction_id => {}
+        Some(Payload::Transaction(transaction)) => {
+            return Err(invalid(
+                transaction_id,
+                format!("the node returned a block with transaction {}", transaction.id()),
+            ));
+        }
+        _ => {
+            return Err(invalid(
+                transaction_id,
+                "the node returned a block without a transaction",
+            ));
+        }
+    }
+
+    validate_block(block, None, protocol_parameters)
+}
+
+/// Validates an output returned by a node against the block including the transaction that created it, which has to
+/// be validated with [`validate_included_block()`]: the output has to be the one the transaction created at the index
+/// of the output ID, and the metadata has to have the requested ID and the ID of the including block.
+pub(crate) fn validate_output(
+    output_id: &OutputId,
+    output: &Output,
+    metadata: &OutputMetadata,
+    included_block: &Block,
+) -> Result<()> {
+    if metadata.output_id() != output_id {
+        return Err(invalid(
+            output_id,
+            format!("the node returned output {}", metadata.output_id()),
+        ));
+    }
+    if metadata.block_id() != &included_block.id() {
+        return Err(invalid(
+            output_id,
+            format!(
+                "the node returned block {} as the one including the output, expected {}",
+                metadata.block_id(),
+                included_block.id()
+            ),
+        ));
+    }
+
+    let Some(Payload::Transaction(transaction)) = included_block.payload() else {
+        return Err(invalid(output_id, "the including block has no transaction"));
+    };
+    if &transaction.id() != output_id.transaction_id() {
+        return Err(invalid(
+            output_id,
+            format!("the including block has transaction {}", transaction.id()),
+        ));
+    }
+
+    let TransactionEssence::Regular(essence) = transaction.essence();
+    match essence.outputs().get(usize::from(output_id.index())) {
+        Some(created_output) if created_output == output => Ok(()),
+        Some(_) => Err(invalid(
+            output_id,
+            "the output isn't the one created by the transaction",
+        )),
+        None => Err(invalid(
+            output_id,
+            format!("the transaction only created {} outputs", essence.outputs().len()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto::signatures::ed25519::SecretKey;
+
+    use super::*;
+    use crate::types::block::{
+        input::{Input, UtxoInput},
+        output::{BasicOutput, RentStructure},
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionPayload},
+            TaggedDataPayload,
+        },
+        rand::{
+            block::rand_block_id,
+            output::{rand_inputs_commitment, rand_output_id, unlock_condition::rand_address_unlock_condition},
+            parents::rand_parents,
+            transaction::rand_transaction_id,
+        },
+        signature::Ed25519Signature,
+        unlock::{SignatureUnlock, Unlocks},
+        BlockBuilder, PROTOCOL_VERSION,
+    };
+
+    fn protocol_parameters(min_pow_score: u32) -> ProtocolParameters {
+        ProtocolParameters::new(
+            PROTOCOL_VERSION,
+            String::from("testnet"),
+            "rms",
+            min_pow_score,
+            15,
+            RentStructure::default(),
+            1_813_620_509_061_365,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn block_validation() {
+        let block = BlockBuilder::new(rand_parents())
+            .with_payload(TaggedDataPayload::new(b"tag".to_vec(), b"data".to_vec()).unwrap())
+            .with_nonce(0)
+            .finish()
+            .unwrap();
+
+        assert!(validate_block(&block, Some(&block.id()), &protocol_parameters(0)).is_ok());
+        assert!(matches!(
+            validate_block(&block, Some(&rand_block_id()), &protocol_parameters(0)),
+            Err(Error::NodeResponseInvalid { .. })
+        ));
+        assert!(matches!(
+            validate_block(&block, Some(&block.id()), &protocol_parameters(1500)),
+            Err(Error::NodeResponseInvalid { .. })
+        ));
+    }
+
+    fn basic_output(amount: u64, protocol_parameters: &ProtocolParameters) -> Output {
+        Output::Basic(
+            BasicOutput::build_with_amount(amount)
+                .add_unlock_condition(rand_address_unlock_condition())
+                .finish_with_params(protocol_parameters.token_supply())
+                .unwrap(),
+        )
+    }
+
+    fn transaction_block(protocol_parameters: &ProtocolParameters) -> Block {
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+                .with_inputs([Input::Utxo(UtxoInput::from(rand_output_id()))])
+                .add_output(basic_output(1_000_000, protocol_parameters))
+                .finish_with_params(protocol_parameters)
+                .unwrap(),
+        );
+        let secret_key = SecretKey::from_bytes(&[1; 32]);
+        let signature = Ed25519Signature::new(secret_key.public_key(), secret_key.sign(&essence.hash()));
+        let unlocks = Unlocks::new([Unlock::Signature(SignatureUnlock::from(Signature::from(signature)))]).unwrap();
+
+        BlockBuilder::new(rand_parents())
+            .with_payload(TransactionPayload::new(essence, unlocks).unwrap())
+            .with_nonce(0)
+            .finish()
+            .unwrap()
+    }
+
+    #[test]
+    fn included_block_validation() {
+        let protocol_parameters = protocol_parameters(0);
+        let block = transaction_block(&protocol_parameters);
+        let Some(Payload::Transaction(transaction)) = block.payload() else {
+            unreachable!()
+        };
+
+        assert!(validate_included_block(&block, &transaction.id(), &protocol_parameters).is_ok());
+        assert!(matches!(
+            validate_included_block(&block, &rand_transaction_id(), &protocol_parameters),
+            Err(Error::NodeResponseInvalid { .. })
+        ));
+
+        let block = BlockBuilder::new(rand_parents())
+            .with_payload(TaggedDataPayload::new(b"tag".to_vec(), b"data".to_vec()).unwrap())
+            .with_nonce(0)
+            .finish()
+            .unwrap();
+        assert!(matches!(
+            validate_included_block(&block, &transaction.id(), &protocol_parameters),
+            Err(Error::NodeResponseInvalid { .. })
+        ));
+    }
+
+    #[test]
+    fn output_validation() {
+        let protocol_parameters = protocol_parameters(0);
+        let block = transaction_block(&protocol_parameters);
+        let Some(Payload::Transaction(transaction)) = block.payload() else {
+            unreachable!()
+        };
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        let output_id = OutputId::new(transaction.id(), 0).unwrap();
+        let output = essence.outputs()[0].clone();
+        let metadata = |block_id, output_id| OutputMetadata::new(block_id, output_id, false, None, None, None, 1, 1, 1);
+
+        assert!(validate_output(&output_id, &output, &metadata(block.id(), output_id), &block).is_ok());
+        assert!(matches!(
+            validate_output(&output_id, &output, &metadata(block.id(), rand_output_id()), &block),
+            Err(Error::NodeResponseInvalid { .. })
+        ));
+        assert!(matches!(
+            validate_output(&output_id, &output, &metadata(rand_block_id(), output_id), &block),
+            Err(Error::NodeResponseInvalid { .. })
+        ));
+        // Another output than the one created by the transaction
+        assert!(matches!(
+            validate_output(
+                &output_id,
+                &basic_output(2_000_000, &protocol_parameters),
+                &metadata(block.id(), output_id),
+                &block
+            ),
+            Err(Error::NodeResponseInvalid { .. })
+        ));
+        // The transaction didn't create an output with this index
+        let output_id = OutputId::new(transaction.id(), 1).unwrap();
+        assert!(matches!(
+            validate_output(&output_id, &output, &metadata(block.id(), output_id), &block),
+            Err(Error::NodeResponseInvalid { .. })
+        ));
+        // The block doesn't include the transaction that created the output
+        let other_block = transaction_block(&protocol_parameters);
+        assert!(matches!(
+            validate_output(
+                &output_id,
+                &output,
+                &metadata(other_block.id(), output_id),
+                &other_block
+            ),
+            Err(Error::NodeResponseInvalid { .. })
+        ));
+    }
+}
@@ -3,6 +3,7 @@
 
 //! Node indexer API.
 
+pub mod outputs_query;
 pub mod query_parameters;
 pub mod routes;
 
@@ -0,0 +1,946 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A query for outputs, that can be sent to the indexer or applied to outputs that are already known
+
+use std::collections::HashSet;
+
+use futures::{Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        node_api::{
+            error::Error as NodeApiError,
+            indexer::query_parameters::{QueryParameter, QueryParameters},
+        },
+        Client, Error, Result,
+    },
+    types::{
+        api::plugins::indexer::OutputIdsResponse,
+        block::{
+            address::{Address, Bech32Address},
+            output::{AliasId, AliasOutput, BasicOutput, FoundryId, FoundryOutput, NftId, NftOutput, Output, OutputId},
+        },
+    },
+};
+
+/// A query for outputs. The same query can be sent to the indexer with [`Client::outputs_query_ids()`] or be applied
+/// to outputs that are already known, like the ones of a wallet account, with [`OutputsQuery::matches()`]. All set
+/// fields have to match.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputsQuery {
+    /// The address that can unlock the output: the address unlock condition of basic and nft outputs, the state
+    /// controller of alias outputs and the controlling alias of foundry outputs.
+    pub address: Option<Bech32Address>,
+    /// Only return outputs of these types (Basic = 3, Alias = 4, Foundry = 5, NFT = 6).
+    pub output_types: Option<Vec<u8>>,
+    /// Only return alias outputs with these IDs, if any ID is set only outputs with a matching ID are returned.
+    pub alias_ids: Option<HashSet<AliasId>>,
+    /// Only return foundry outputs with these IDs, if any ID is set only outputs with a matching ID are returned.
+    pub foundry_ids: Option<HashSet<FoundryId>>,
+    /// Only return nft outputs with these IDs, if any ID is set only outputs with a matching ID are returned.
+    pub nft_ids: Option<HashSet<NftId>>,
+    /// Outputs with a tag feature containing this tag.
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    pub tag: Option<Vec<u8>>,
+    /// Outputs with an issuer feature containing this address.
+    pub issuer: Option<Bech32Address>,
+    /// Outputs with a sender feature containing this address.
+    pub sender: Option<Bech32Address>,
+    /// Alias outputs with this governor address.
+    pub governor: Option<Bech32Address>,
+    /// Outputs that were created after this unix timestamp.
+    pub created_after: Option<u32>,
+    /// Outputs that were created before this unix timestamp.
+    pub created_before: Option<u32>,
+    /// Outputs that expire after this unix timestamp.
+    pub expires_after: Option<u32>,
+    /// Outputs that expire before this unix timestamp.
+    pub expires_before: Option<u32>,
+    /// Outputs with or without an expiration unlock condition.
+    pub has_expiration: Option<bool>,
+    /// Outputs with an expiration unlock condition returning to this address.
+    pub expiration_return_address: Option<Bech32Address>,
+    /// Outputs with or without a timelock unlock condition.
+    pub has_timelock: Option<bool>,
+    /// Outputs that are timelocked after this unix timestamp.
+    pub timelocked_after: Option<u32>,
+    /// Outputs that are timelocked before this unix timestamp.
+    pub timelocked_before: Option<u32>,
+    /// Outputs with or without a storage deposit return unlock condition.
+    pub has_storage_deposit_return: Option<bool>,
+    /// Outputs with a storage deposit return unlock condition returning to this address.
+    pub storage_deposit_return_address: Option<Bech32Address>,
+    /// Outputs with or without native tokens.
+    pub has_native_tokens: Option<bool>,
+    /// Outputs with at least this many distinct native tokens.
+    pub min_native_token_count: Option<u32>,
+    /// Outputs with at most this many distinct native tokens.
+    pub max_native_token_count: Option<u32>,
+    /// The maximum amount of output IDs the indexer returns per page, ignored for known outputs.
+    pub page_size: Option<usize>,
+    /// Only returns the page of the indexer starting at the cursor, ignored for known outputs. Requires the query to
+    /// target a single output type.
+    pub cursor: Option<String>,
+}
+
+impl OutputsQuery {
+    /// Creates an empty query, matching all outputs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the address that can unlock the outputs.
+    pub fn with_address(mut self, address: impl Into<Option<Bech32Address>>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Sets the output types to query.
+    pub fn with_output_types(mut self, output_types: impl Into<Option<Vec<u8>>>) -> Self {
+        self.output_types = output_types.into();
+        self
+    }
+
+    /// Sets the IDs of the alias outputs to query.
+    pub fn with_alias_ids(mut self, alias_ids: impl Into<Option<HashSet<AliasId>>>) -> Self {
+        self.alias_ids = alias_ids.into();
+        self
+    }
+
+    /// Sets the IDs of the foundry outputs to query.
+    pub fn with_foundry_ids(mut self, foundry_ids: impl Into<Option<HashSet<FoundryId>>>) -> Self {
+        self.foundry_ids = foundry_ids.into();
+        self
+    }
+
+    /// Sets the IDs of the nft outputs to query.
+    pub fn with_nft_ids(mut self, nft_ids: impl Into<Option<HashSet<NftId>>>) -> Self {
+        self.nft_ids = nft_ids.into();
+        self
+    }
+
+    /// Sets the tag of the outputs.
+    pub fn with_tag(mut self, tag: impl Into<Option<Vec<u8>>>) -> Self {
+        self.tag = tag.into();
+        self
+    }
+
+    /// Sets the issuer of the outputs.
+    pub fn with_issuer(mut self, issuer: impl Into<Option<Bech32Address>>) -> Self {
+        self.issuer = issuer.into();
+        self
+    }
+
+    /// Sets the sender of the outputs.
+    pub fn with_sender(mut self, sender: impl Into<Option<Bech32Address>>) -> Self {
+        self.sender = sender.into();
+        self
+    }
+
+    /// Sets the governor of the alias outputs.
+    pub fn with_governor(mut self, governor: impl Into<Option<Bech32Address>>) -> Self {
+        self.governor = governor.into();
+        self
+    }
+
+    /// Sets the range of unix timestamps in which the outputs were created, both bounds are exclusive.
+    pub fn with_created_range(mut self, after: impl Into<Option<u32>>, before: impl Into<Option<u32>>) -> Self {
+        self.created_after = after.into();
+        self.created_before = before.into();
+        self
+    }
+
+    /// Sets the range of unix timestamps in which the outputs expire, both bounds are exclusive.
+    pub fn with_expiration_range(mut self, after: impl Into<Option<u32>>, before: impl Into<Option<u32>>) -> Self {
+        self.expires_after = after.into();
+        self.expires_before = before.into();
+        self
+    }
+
+    /// Sets whether the outputs have an expiration unlock condition.
+    pub fn with_has_expiration(mut self, has_expiration: impl Into<Option<bool>>) -> Self {
+        self.has_expiration = has_expiration.into();
+        self
+    }
+
+    /// Sets the return address of the expiration unlock condition of the outputs.
+    pub fn with_expiration_return_address(mut self, address: impl Into<Option<Bech32Address>>) -> Self {
+        self.expiration_return_address = address.into();
+        self
+    }
+
+    /// Sets whether the outputs have a timelock unlock condition.
+    pub fn with_has_timelock(mut self, has_timelock: impl Into<Option<bool>>) -> Self {
+        self.has_timelock = has_timelock.into();
+        self
+    }
+
+    /// Sets the range of unix timestamps in which the timelocks of the outputs end, both bounds are exclusive.
+    pub fn with_timelock_range(mut self, after: impl Into<Option<u32>>, before: impl Into<Option<u32>>) -> Self {
+        self.timelocked_after = after.into();
+        self.timelocked_before = before.into();
+        self
+    }
+
+    /// Sets whether the outputs have a storage deposit return unlock condition.
+    pub fn with_has_storage_deposit_return(mut self, has_storage_deposit_return: impl Into<Option<bool>>) -> Self {
+        self.has_storage_deposit_return = has_storage_deposit_return.into();
+        self
+    }
+
+    /// Sets the return address of the storage deposit return unlock condition of the outputs.
+    pub fn with_storage_deposit_return_address(mut self, address: impl Into<Option<Bech32Address>>) -> Self {
+        self.storage_deposit_return_address = address.into();
+        self
+    }
+
+    /// Sets whether the outputs hold native tokens.
+    pub fn with_has_native_tokens(mut self, has_native_tokens: impl Into<Option<bool>>) -> Self {
+        self.has_native_tokens = has_native_tokens.into();
+        self
+    }
+
+    /// Sets the range of the number of distinct native tokens the outputs hold, both bounds are inclusive.
+    pub fn with_native_token_count_range(mut self, min: impl Into<Option<u32>>, max: impl Into<Option<u32>>) -> Self {
+        self.min_native_token_count = min.into();
+        self.max_native_token_count = max.into();
+        self
+    }
+
+    /// Sets the page size of the indexer requests.
+    pub fn with_page_size(mut self, page_size: impl Into<Option<usize>>) -> Self {
+        self.page_size = page_size.into();
+        self
+    }
+
+    /// Sets the cursor of the indexer page to return.
+    pub fn with_cursor(mut self, cursor: impl Into<Option<String>>) -> Self {
+        self.cursor = cursor.into();
+        self
+    }
+
+    fn has_ids(&self) -> bool {
+        self.alias_ids.is_some() || self.foundry_ids.is_some() || self.nft_ids.is_some()
+    }
+
+    // Whether the query filters on unlock conditions only basic and nft outputs can have
+    fn has_unlock_condition_filter(&self) -> bool {
+        self.expires_after.is_some()
+            || self.expires_before.is_some()
+            || self.has_expiration.is_some()
+            || self.expiration_return_address.is_some()
+            || self.has_timelock.is_some()
+            || self.timelocked_after.is_some()
+            || self.timelocked_before.is_some()
+            || self.has_storage_deposit_return.is_some()
+            || self.storage_deposit_return_address.is_some()
+    }
+
+    // Outputs of some types can't match some fields, e.g. alias outputs can't have a tag feature
+    fn can_match_output_type(&self, output_type: u8) -> bool {
+        if self
+            .output_types
+            .as_ref()
+            .map_or(false, |output_types| !output_types.contains(&output_type))
+        {
+            return false;
+        }
+
+        let has_unlock_condition_filter = self.has_unlock_condition_filter();
+        match output_type {
+            BasicOutput::KIND => self.issuer.is_none() && self.governor.is_none(),
+            AliasOutput::KIND => self.tag.is_none() && !has_unlock_condition_filter,
+            FoundryOutput::KIND => {
+                self.tag.is_none()
+                    && self.issuer.is_none()
+                    && self.sender.is_none()
+                    && self.governor.is_none()
+                    && !has_unlock_condition_filter
+            }
+            NftOutput::KIND => self.governor.is_none(),
+            _ => false,
+        }
+    }
+
+    // Outputs queried by their ID have to be of the type of the ID
+    fn can_match_ids(&self, output_type: u8) -> bool {
+        if !self.has_ids() {
+            return true;
+        }
+
+        match output_type {
+            AliasOutput::KIND => self.alias_ids.is_some(),
+            FoundryOutput::KIND => self.foundry_ids.is_some(),
+            NftOutput::KIND => self.nft_ids.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Returns [`Error::InvalidOutputsQuery`] if no output type can have all the set fields, e.g. a tag and a governor,
+    /// because the query couldn't match any output.
+    pub fn validate(&self) -> Result<()> {
+        if [
+            BasicOutput::KIND,
+            AliasOutput::KIND,
+            FoundryOutput::KIND,
+            NftOutput::KIND,
+        ]
+        .into_iter()
+        .any(|output_type| self.can_match_output_type(output_type) && self.can_match_ids(output_type))
+        {
+            Ok(())
+        } else {
+            Err(Error::InvalidOutputsQuery)
+        }
+    }
+
+    /// Returns whether a known output matches the query. `created` is the unix timestamp at which the output was
+    /// booked. A query that isn't [valid](Self::validate()) doesn't match any output.
+    pub fn matches(&self, output_id: &OutputId, output: &Output, created: u32) -> bool {
+        if !self.can_match_output_type(output.kind()) {
+            return false;
+        }
+
+        if self.has_ids() {
+            let id_matches = match output {
+                Output::Alias(alias) => self
+                    .alias_ids
+                    .as_ref()
+                    .map_or(false, |ids| ids.contains(&alias.alias_id_non_null(output_id))),
+                Output::Foundry(foundry) => self
+                    .foundry_ids
+                    .as_ref()
+                    .map_or(false, |ids| ids.contains(&foundry.id())),
+                Output::Nft(nft) => self
+                    .nft_ids
+                    .as_ref()
+                    .map_or(false, |ids| ids.contains(&nft.nft_id_non_null(output_id))),
+                _ => false,
+            };
+            if !id_matches {
+                return false;
+            }
+        }
+
+        if let Some(address) = &self.address {
+            let unlock_address = match output {
+                Output::Alias(alias) => Some(*alias.state_controller_address()),
+                Output::Foundry(foundry) => Some(Address::Alias(*foundry.alias_address())),
+                _ => output
+                    .unlock_conditions()
+                    .and_then(|unlock_conditions| unlock_conditions.address())
+                    .map(|uc| *uc.address()),
+            };
+            if unlock_address.as_ref() != Some(address.inner()) {
+                return false;
+            }
+        }
+
+        if let Some(tag) = &self.tag {
+            if output
+                .features()
+                .and_then(|features| features.tag())
+                .map(|tag| tag.tag())
+                != Some(tag.as_slice())
+            {
+                return false;
+            }
+        }
+        if let Some(issuer) = &self.issuer {
+            if output
+                .immutable_features()
+                .and_then(|features| features.issuer())
+                .map(|issuer| issuer.address())
+                != Some(issuer.inner())
+            {
+                return false;
+            }
+        }
+        if let Some(sender) = &self.sender {
+            if output
+                .features()
+                .and_then(|features| features.sender())
+                .map(|sender| sender.address())
+                != Some(sender.inner())
+            {
+                return false;
+            }
+        }
+
+        if let Some(governor) = &self.governor {
+            let Output::Alias(alias) = output else {
+                return false;
+            };
+            if alias.governor_address() != governor.inner() {
+                return false;
+            }
+        }
+
+        if self.created_after.map_or(false, |after| created <= after)
+            || self.created_before.map_or(false, |before| created >= before)
+        {
+            return false;
+        }
+
+        if self.expires_after.is_some() || self.expires_before.is_some() {
+            let Some(expiration) = output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.expiration())
+            else {
+                return false;
+            };
+            if self
+                .expires_after
+                .map_or(false, |after| expiration.timestamp() <= after)
+                || self
+                    .expires_before
+                    .map_or(false, |before| expiration.timestamp() >= before)
+            {
+                return false;
+            }
+        }
+
+        let unlock_conditions = output.unlock_conditions();
+        let expiration = unlock_conditions.and_then(|unlock_conditions| unlock_conditions.expiration());
+        if self.has_expiration.map_or(false, |has| expiration.is_some() != has)
+            || self.expiration_return_address.as_ref().map_or(false, |address| {
+                expiration.map(|expiration| expiration.return_address()) != Some(address.inner())
+            })
+        {
+            return false;
+        }
+
+        let timelock = unlock_conditions.and_then(|unlock_conditions| unlock_conditions.timelock());
+        if self.has_timelock.map_or(false, |has| timelock.is_some() != has) {
+            return false;
+        }
+        if self.timelocked_after.is_some() || self.timelocked_before.is_some() {
+            let Some(timelock) = timelock else {
+                return false;
+            };
+            if self
+                .timelocked_after
+                .map_or(false, |after| timelock.timestamp() <= after)
+                || self
+                    .timelocked_before
+                    .map_or(false, |before| timelock.timestamp() >= before)
+            {
+                return false;
+            }
+        }
+
+        let storage_deposit_return =
+            unlock_conditions.and_then(|unlock_conditions| unlock_conditions.storage_deposit_return());
+        if self
+            .has_storage_deposit_return
+            .map_or(false, |has| storage_deposit_return.is_some() != has)
+            || self.storage_deposit_return_address.as_ref().map_or(false, |address| {
+                storage_deposit_return.map(|sdr| sdr.return_address()) != Some(address.inner())
+            })
+        {
+            return false;
+        }
+
+        let native_token_count = output.native_tokens().map_or(0, |native_tokens| native_tokens.len());
+        if self
+            .has_native_tokens
+            .map_or(false, |has| (native_token_count != 0) != has)
+            || self
+                .min_native_token_count
+                .map_or(false, |min| native_token_count < min as usize)
+            || self
+                .max_native_token_count
+                .map_or(false, |max| native_token_count > max as usize)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Returns the indexer query parameters for outputs of the given type, or `None` if no output of that type can
+    /// match the query.
+    pub fn to_query_parameters(&self, output_type: u8) -> Option<QueryParameters> {
+        if !self.can_match_output_type(output_type) {
+            return None;
+        }
+
+        let mut query_parameters = Vec::new();
+        if let Some(address) = self.address {
+            query_parameters.push(match output_type {
+                AliasOutput::KIND => QueryParameter::StateController(address),
+                FoundryOutput::KIND => QueryParameter::AliasAddress(address),
+                _ => QueryParameter::Address(address),
+            });
+        }
+        if let Some(tag) = &self.tag {
+            query_parameters.push(QueryParameter::Tag(prefix_hex::encode(tag)));
+        }
+        if let Some(issuer) = self.issuer {
+            query_parameters.push(QueryParameter::Issuer(issuer));
+        }
+        if let Some(sender) = self.sender {
+            query_parameters.push(QueryParameter::Sender(sender));
+        }
+        if let Some(governor) = self.governor {
+            query_parameters.push(QueryParameter::Governor(governor));
+        }
+        if let Some(created_after) = self.created_after {
+            query_parameters.push(QueryParameter::CreatedAfter(created_after));
+        }
+        if let Some(created_before) = self.created_before {
+            query_parameters.push(QueryParameter::CreatedBefore(created_before));
+        }
+        if let Some(expires_after) = self.expires_after {
+            query_parameters.push(QueryParameter::ExpiresAfter(expires_after));
+        }
+        if let Some(expires_before) = self.expires_before {
+            query_parameters.push(QueryParameter::ExpiresBefore(expires_before));
+        }
+        if let Some(has_expiration) = self.has_expiration {
+            query_parameters.push(QueryParameter::HasExpiration(has_expiration));
+        }
+        if let Some(address) = self.expiration_return_address {
+            query_parameters.push(QueryParameter::ExpirationReturnAddress(address));
+        }
+        if let Some(has_timelock) = self.has_timelock {
+            query_parameters.push(QueryParameter::HasTimelock(has_timelock));
+        }
+        if let Some(timelocked_after) = self.timelocked_after {
+            query_parameters.push(QueryParameter::TimelockedAfter(timelocked_after));
+        }
+        if let Some(timelocked_before) = self.timelocked_before {
+            query_parameters.push(QueryParameter::TimelockedBefore(timelocked_before));
+        }
+        if let Some(has_storage_deposit_return) = self.has_storage_deposit_return {
+            query_parameters.push(QueryParameter::HasStorageDepositReturn(has_storage_deposit_return));
+        }
+        if let Some(address) = self.storage_deposit_return_address {
+            query_parameters.push(QueryParameter::StorageDepositReturnAddress(address));
+        }
+        if let Some(has_native_tokens) = self.has_native_tokens {
+            query_parameters.push(QueryParameter::HasNativeTokens(has_native_tokens));
+        }
+        if let Some(min_native_token_count) = self.min_native_token_count {
+            query_parameters.push(QueryParameter::MinNativeTokenCount(min_native_token_count));
+        }
+        if let Some(max_native_token_count) = self.max_native_token_count {
+            query_parameters.push(QueryParameter::MaxNativeTokenCount(max_native_token_count));
+        }
+        if let Some(page_size) = self.page_size {
+            query_parameters.push(QueryParameter::PageSize(page_size));
+        }
+        if let Some(cursor) = &self.cursor {
+            query_parameters.push(QueryParameter::Cursor(cursor.clone()));
+        }
+
+        Some(QueryParameters::new(query_parameters))
+    }
+
+    // Returns the indexer routes with their query parameters for the output types that can match the query
+    fn indexer_routes(&self) -> Result<Vec<(&'static str, QueryParameters)>> {
+        self.validate()?;
+
+        let routes = [
+            (BasicOutput::KIND, "api/indexer/v1/outputs/basic"),
+            (AliasOutput::KIND, "api/indexer/v1/outputs/alias"),
+            (FoundryOutput::KIND, "api/indexer/v1/outputs/foundry"),
+            (NftOutput::KIND, "api/indexer/v1/outputs/nft"),
+        ]
+        .into_iter()
+        .filter_map(|(output_type, route)| {
+            self.to_query_parameters(output_type)
+                .map(|query_parameters| (route, query_parameters))
+        })
+        .collect::<Vec<_>>();
+
+        // A cursor belongs to the results of a single indexer route
+        if let (Some(cursor), true) = (&self.cursor, routes.len() != 1) {
+            return Err(Error::UnsupportedQueryParameter(QueryParameter::Cursor(cursor.clone())));
+        }
+
+        Ok(routes)
+    }
+}
+
+// The state of [`Client::output_ids_stream()`]
+enum OutputIdPages {
+    Query(OutputsQuery),
+    // The routes that still have pages, in reverse order, with the cursor of their next page
+    Routes(Vec<(&'static str, QueryParameters)>),
+}
+
+impl Client {
+    /// Returns the IDs of the unspent outputs matching the query. Outputs queried by their ID are requested to check
+    /// the remaining fields of the query. Fails with [`Error::InvalidOutputsQuery`] if the query can't match any
+    /// output.
+    pub async fn outputs_query_ids(&self, query: &OutputsQuery) -> Result<Vec<OutputId>> {
+        query.validate()?;
+
+        if query.has_ids() {
+            let mut output_ids = Vec::new();
+            for alias_id in query.alias_ids.iter().flatten() {
+                output_ids.extend(ignore_not_found(self.alias_output_id(*alias_id).await)?);
+            }
+            for foundry_id in query.foundry_ids.iter().flatten() {
+                output_ids.extend(ignore_not_found(self.foundry_output_id(*foundry_id).await)?);
+            }
+            for nft_id in query.nft_ids.iter().flatten() {
+                output_ids.extend(ignore_not_found(self.nft_output_id(*nft_id).await)?);
+            }
+
+            return Ok(self
+                .get_outputs(&output_ids)
+                .await?
+                .into_iter()
+                .filter(|output| {
+                    query.matches(
+                        output.metadata().output_id(),
+                        output.output(),
+                        output.metadata().milestone_timestamp_booked(),
+                    )
+                })
+                .map(|output| *output.metadata().output_id())
+                .collect());
+        }
+
+        let mut output_ids = Vec::new();
+        for (route, query_parameters) in query.indexer_routes()? {
+            output_ids.extend(self.get_output_ids(route, query_parameters, true, false).await?.items);
+        }
+
+        Ok(output_ids)
+    }
+
+    /// Returns a stream of the IDs of the unspent outputs matching the query. The pages of the indexer are requested
+    /// one after another while the stream is polled, following their cursors, with the page size of the query. The
+    /// stream ends after `max_results` output IDs if set. Outputs queried by their ID are requested at once, like
+    /// with [`Client::outputs_query_ids()`].
+    pub fn output_ids_stream(
+        &self,
+        query: OutputsQuery,
+        max_results: impl Into<Option<usize>>,
+    ) -> impl Stream<Item = Result<OutputId>> + '_ {
+        let max_results = max_results.into().unwrap_or(usize::MAX);
+
+        futures::stream::try_unfold(OutputIdPages::Query(query), move |pages| async move {
+            let mut routes = match pages {
+                OutputIdPages::Query(query) if query.has_ids() => {
+                    let output_ids = self.outputs_query_ids(&query).await?;
+                    return Ok(Some((output_ids, OutputIdPages::Routes(Vec::new()))));
+                }
+                OutputIdPages::Query(query) => {
+                    let mut routes = query.indexer_routes()?;
+                    routes.reverse();
+                    routes
+                }
+                OutputIdPages::Routes(routes) => routes,
+            };
+
+            let Some((route, mut query_parameters)) = routes.pop() else {
+                return Ok(None);
+            };
+            let output_ids_response = self
+                .get_request::<OutputIdsResponse>(route, query_parameters.to_query_string().as_deref(), true, false)
+                .await?;
+            if let Some(cursor) = output_ids_response.cursor {
+                query_parameters.replace(QueryParameter::Cursor(cursor));
+                routes.push((route, query_parameters));
+            }
+
+            Ok(Some((output_ids_response.items, OutputIdPages::Routes(routes))))
+        })
+        .map_ok(|output_ids| futures::stream::iter(output_ids.into_iter().map(Ok)))
+        .try_flatten()
+        .take(max_results)
+    }
+}
+
+// Outputs queried by ID that don't exist (anymore) just don't match
+fn ignore_not_found(result: Result<OutputId>) -> Result<Option<OutputId>> {
+    match result {
+        Ok(output_id) => Ok(Some(output_id)),
+        Err(Error::NoOutput(_)) | Err(Error::Node(NodeApiError::NotFound(_))) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Ed25519Address, ToBech32Ext},
+        output::{
+            feature::{SenderFeature, TagFeature},
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                StateControllerAddressUnlockCondition, TimelockUnlockCondition,
+            },
+            AliasOutputBuilder, BasicOutputBuilder,
+        },
+        payload::transaction::TransactionId,
+    };
+
+    fn ed25519_address(byte: u8) -> Address {
+        Address::Ed25519(Ed25519Address::new([byte; 32]))
+    }
+
+    #[test]
+    fn matches_basic_outputs() {
+        let owner = ed25519_address(1);
+        let sender = ed25519_address(2);
+        let output_id = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(owner))
+            .add_unlock_condition(ExpirationUnlockCondition::new(sender, 200).unwrap())
+            .add_feature(TagFeature::new(b"tag".to_vec()).unwrap())
+            .add_feature(SenderFeature::new(sender))
+            .finish_output(u64::MAX)
+            .unwrap();
+        let matches = |query: OutputsQuery| query.matches(&output_id, &output, 100);
+
+        assert!(matches(OutputsQuery::new()));
+        assert!(matches(
+            OutputsQuery::new()
+                .with_address(owner.to_bech32_unchecked("atoi"))
+                .with_output_types(vec![BasicOutput::KIND])
+                .with_tag(b"tag".to_vec())
+                .with_sender(sender.to_bech32_unchecked("atoi"))
+                .with_created_range(99, 101)
+                .with_expiration_range(199, 201)
+                .with_has_expiration(true)
+                .with_expiration_return_address(sender.to_bech32_unchecked("atoi"))
+                .with_has_timelock(false)
+                .with_has_storage_deposit_return(false)
+                .with_has_native_tokens(false)
+        ));
+
+        // Every set field has to match
+        assert!(!matches(
+            OutputsQuery::new().with_address(sender.to_bech32_unchecked("atoi"))
+        ));
+        assert!(!matches(OutputsQuery::new().with_output_types(vec![NftOutput::KIND])));
+        assert!(!matches(OutputsQuery::new().with_tag(b"other".to_vec())));
+        assert!(!matches(
+            OutputsQuery::new().with_sender(owner.to_bech32_unchecked("atoi"))
+        ));
+        assert!(!matches(
+            OutputsQuery::new().with_issuer(owner.to_bech32_unchecked("atoi"))
+        ));
+        assert!(!matches(OutputsQuery::new().with_created_range(100, None)));
+        assert!(!matches(OutputsQuery::new().with_created_range(None, 100)));
+        assert!(!matches(OutputsQuery::new().with_expiration_range(200, None)));
+        assert!(!matches(OutputsQuery::new().with_has_expiration(false)));
+        assert!(!matches(
+            OutputsQuery::new().with_expiration_return_address(owner.to_bech32_unchecked("atoi"))
+        ));
+        assert!(!matches(OutputsQuery::new().with_has_timelock(true)));
+        assert!(!matches(OutputsQuery::new().with_timelock_range(None, 300)));
+        assert!(!matches(OutputsQuery::new().with_has_storage_deposit_return(true)));
+        assert!(!matches(OutputsQuery::new().with_native_token_count_range(1, None)));
+        // Basic outputs have no ID
+        assert!(!matches(
+            OutputsQuery::new().with_nft_ids(HashSet::from([NftId::from(&output_id)]))
+        ));
+
+        let timelocked = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(owner))
+            .add_unlock_condition(TimelockUnlockCondition::new(300).unwrap())
+            .finish_output(u64::MAX)
+            .unwrap();
+        assert!(
+            OutputsQuery::new()
+                .with_timelock_range(299, 301)
+                .matches(&output_id, &timelocked, 100)
+        );
+        assert!(
+            !OutputsQuery::new()
+                .with_timelock_range(300, None)
+                .matches(&output_id, &timelocked, 100)
+        );
+    }
+
+    #[test]
+    fn matches_alias_outputs() {
+        let state_controller = ed25519_address(1);
+        let governor = ed25519_address(2);
+        let output_id = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        // A new alias output gets its ID from the output ID
+        let output = AliasOutputBuilder::new_with_amount(1_000_000, AliasId::null())
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(state_controller))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(governor))
+            .finish_output(u64::MAX)
+            .unwrap();
+        let matches = |query: OutputsQuery| query.matches(&output_id, &output, 100);
+
+        assert!(matches(
+            OutputsQuery::new()
+                .with_address(state_controller.to_bech32_unchecked("atoi"))
+                .with_governor(governor.to_bech32_unchecked("atoi"))
+                .with_alias_ids(HashSet::from([AliasId::from(&output_id)]))
+        ));
+        // The address of an alias output is its state controller
+        assert!(!matches(
+            OutputsQuery::new().with_address(governor.to_bech32_unchecked("atoi"))
+        ));
+        assert!(!matches(
+            OutputsQuery::new().with_governor(state_controller.to_bech32_unchecked("atoi"))
+        ));
+        assert!(!matches(
+            OutputsQuery::new().with_alias_ids(HashSet::from([AliasId::null()]))
+        ));
+        // Alias outputs can't have a tag or expiration
+        assert!(!matches(OutputsQuery::new().with_tag(b"tag".to_vec())));
+        assert!(!matches(OutputsQuery::new().with_has_expiration(false)));
+    }
+
+    #[test]
+    fn query_parameters_per_output_type() {
+        let address =
+            Bech32Address::try_from_str("atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r").unwrap();
+        let query = OutputsQuery::new()
+            .with_address(address)
+            .with_tag(b"tag".to_vec())
+            .with_created_range(10, None);
+
+        assert_eq!(
+            query.to_query_parameters(BasicOutput::KIND).unwrap().to_query_string(),
+            Some(format!("address={address}&createdAfter=10&tag=0x746167"))
+        );
+        // Alias and foundry outputs can't have a tag
+        assert!(query.to_query_parameters(AliasOutput::KIND).is_none());
+        assert!(query.to_query_parameters(FoundryOutput::KIND).is_none());
+
+        let query = query.with_tag(None);
+        assert_eq!(
+            query.to_query_parameters(AliasOutput::KIND).unwrap().to_query_string(),
+            Some(format!("createdAfter=10&stateController={address}"))
+        );
+        assert_eq!(
+            query
+                .to_query_parameters(FoundryOutput::KIND)
+                .unwrap()
+                .to_query_string(),
+            Some(format!("aliasAddress={address}&createdAfter=10"))
+        );
+        assert!(
+            query
+                .with_output_types(vec![NftOutput::KIND])
+                .to_query_parameters(BasicOutput::KIND)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unlock_condition_and_governor_filters() {
+        let address =
+            Bech32Address::try_from_str("atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r").unwrap();
+        let query = OutputsQuery::new()
+            .with_address(address)
+            .with_has_expiration(false)
+            .with_has_timelock(false)
+            .with_has_storage_deposit_return(false);
+
+        assert_eq!(
+            query.to_query_parameters(BasicOutput::KIND).unwrap().to_query_string(),
+            Some(format!(
+                "address={address}&hasExpiration=false&hasStorageDepositReturn=false&hasTimelock=false"
+            ))
+        );
+        // Only basic and nft outputs can have these unlock conditions
+        assert!(query.to_query_parameters(AliasOutput::KIND).is_none());
+        assert!(query.to_query_parameters(FoundryOutput::KIND).is_none());
+        assert!(query.to_query_parameters(NftOutput::KIND).is_some());
+
+        let query = OutputsQuery::new()
+            .with_governor(address)
+            .with_native_token_count_range(1, 2)
+            .with_page_size(10);
+        assert_eq!(
+            query.to_query_parameters(AliasOutput::KIND).unwrap().to_query_string(),
+            Some(format!(
+                "governor={address}&maxNativeTokenCount=2&minNativeTokenCount=1&pageSize=10"
+            ))
+        );
+        // Only alias outputs have a governor
+        assert_eq!(
+            query
+                .indexer_routes()
+                .unwrap()
+                .iter()
+                .map(|(route, _)| *route)
+                .collect::<Vec<_>>(),
+            ["api/indexer/v1/outputs/alias"]
+        );
+    }
+
+    #[test]
+    fn indexer_routes() {
+        let query = OutputsQuery::new().with_tag(b"tag".to_vec());
+        let routes = query.indexer_routes().unwrap();
+        assert_eq!(
+            routes.iter().map(|(route, _)| *route).collect::<Vec<_>>(),
+            ["api/indexer/v1/outputs/basic", "api/indexer/v1/outputs/nft"]
+        );
+
+        // A cursor can only be followed on a single route
+        assert!(
+            query
+                .clone()
+                .with_cursor("cursor".to_string())
+                .indexer_routes()
+                .is_err()
+        );
+        assert!(
+            query
+                .with_output_types(vec![NftOutput::KIND])
+                .with_cursor("cursor".to_string())
+                .indexer_routes()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn invalid_combinations() {
+        let address = ed25519_address(1).to_bech32_unchecked("atoi");
+
+        assert!(OutputsQuery::new().validate().is_ok());
+        assert!(
+            OutputsQuery::new()
+                .with_tag(b"tag".to_vec())
+                .with_issuer(address)
+                .validate()
+                .is_ok()
+        );
+        // No output type has both a tag and a governor
+        let query = OutputsQuery::new().with_tag(b"tag".to_vec()).with_governor(address);
+        assert!(matches!(query.validate(), Err(Error::InvalidOutputsQuery)));
+        assert!(matches!(query.indexer_routes(), Err(Error::InvalidOutputsQuery)));
+        // Alias outputs can't have a timelock
+        assert!(matches!(
+            OutputsQuery::new()
+                .with_output_types(vec![AliasOutput::KIND])
+                .with_has_timelock(true)
+                .validate(),
+            Err(Error::InvalidOutputsQuery)
+        ));
+        // Nft outputs don't have a governor
+        assert!(matches!(
+            OutputsQuery::new()
+                .with_nft_ids(HashSet::from([NftId::null()]))
+                .with_governor(address)
+                .validate(),
+            Err(Error::InvalidOutputsQuery)
+        ));
+        assert!(
+            OutputsQuery::new()
+                .with_alias_ids(HashSet::from([AliasId::null()]))
+                .with_governor(address)
+                .validate()
+                .is_ok()
+        );
+    }
+}
@@ -9,7 +9,7 @@ pub mod indexer;
 #[cfg(feature = "mqtt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
 pub mod mqtt;
-#[cfg(feature = "participation")]
-#[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+#[cfg(feature = "participation_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "participation_api")))]
 pub mod participation;
 pub mod plugin;
@@ -13,7 +13,17 @@ pub enum Error {
     /// Crypto error.
     #[error("crypto error {0}")]
     Crypto(#[from] crypto::Error),
+    /// INX error.
+    #[cfg(feature = "inx")]
+    #[error("INX error: {0}")]
+    Inx(String),
     /// Invalid topic.
     #[error("invalid topic {0}")]
     InvalidTopic(String),
+    /// The local tunnel to the SOCKS5 proxy couldn't be started.
+    #[error("couldn't start the MQTT SOCKS5 tunnel: {0}")]
+    Socks5Tunnel(std::io::Error),
+    /// The proxy can't be used for MQTT connections.
+    #[error("MQTT connections can't use the {0} proxy, only HTTP and SOCKS5 proxies are supported")]
+    UnsupportedProxy(String),
 }
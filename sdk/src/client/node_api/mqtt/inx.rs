@@ -0,0 +1,530 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Streaming the MQTT topics from the INX interface of a node
+
+use inx::{
+    client::InxClient,
+    proto::{
+        block_metadata::LedgerInclusionState, ledger_update::Op, BlockWithMetadata, LedgerOutput,
+        MilestoneRangeRequest, MilestoneRequest,
+    },
+    tonic::transport::Channel,
+};
+use log::warn;
+use packable::PackableExt;
+
+use super::{Error, MqttEvent, MqttPayload, Topic, TopicEvent};
+use crate::{
+    client::Client,
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::{Address, ToBech32Ext},
+            output::{Output, OutputId, OutputMetadata, OutputWithMetadata, UnlockCondition},
+            payload::{
+                transaction::{TransactionEssence, TransactionId},
+                Payload,
+            },
+            protocol::ProtocolParameters,
+            Block, BlockId,
+        },
+    },
+};
+
+/// Connects to the INX interface of a node and streams its events to the topic handlers, unless it's already done.
+pub(super) async fn set_inx_listener(client: &Client, inx_address: String) -> Result<(), Error> {
+    let mut inx_listener = client.mqtt.inx_listener.write().await;
+
+    if inx_listener.as_ref().map_or(false, |listener| !listener.is_finished()) {
+        return Ok(());
+    }
+
+    let inx = InxClient::connect(inx_address).await.map_err(inx_error)?;
+    let _ = client.mqtt.sender.read().await.send(MqttEvent::Connected);
+
+    let client = client.clone();
+    let background_tasks = client.background_tasks.clone();
+    inx_listener.replace(background_tasks.spawn("inx-listener", async move {
+        if let Err(err) = tokio::try_join!(
+            listen_to_milestones(&client, inx.clone()),
+            listen_to_ledger_updates(&client, inx)
+        ) {
+            warn!("INX connection error: {err}");
+        }
+        let _ = client.mqtt.sender.read().await.send(MqttEvent::Disconnected);
+    }));
+
+    Ok(())
+}
+
+async fn listen_to_milestones(client: &Client, mut inx: InxClient<Channel>) -> Result<(), Error> {
+    let mut milestones = inx
+        .listen_to_confirmed_milestones(MilestoneRangeRequest {
+            start_milestone_index: 0,
+            end_milestone_index: 0,
+        })
+        .await
+        .map_err(inx_error)?
+        .into_inner();
+
+    while let Some(milestone) = milestones.message().await.map_err(inx_error)? {
+        let milestone = milestone.milestone.ok_or_else(|| missing_field("milestone"))?;
+        let info = milestone
+            .milestone_info
+            .ok_or_else(|| missing_field("milestone_info"))?;
+        let protocol_parameters = client.network_info.read().await.protocol_parameters.clone();
+
+        dispatch(client, ["milestone-info/confirmed".to_owned()], || {
+            let milestone_id = info.milestone_id.as_ref().map(|id| prefix_hex::encode(&id.id));
+            Some(MqttPayload::Json(serde_json::json!({
+                "index": info.milestone_index,
+                "timestamp": info.milestone_timestamp,
+                "milestoneId": milestone_id,
+            })))
+        })
+        .await;
+
+        if let Some(raw_milestone) = milestone.milestone {
+            dispatch(client, ["milestones".to_owned()], || {
+                match Payload::unpack_verified(raw_milestone.data, &protocol_parameters) {
+                    Ok(Payload::Milestone(milestone)) => Some(MqttPayload::MilestonePayload(milestone.as_ref().into())),
+                    Ok(p) => {
+                        warn!("INX returned a non-milestone payload, kind: {:?}", p.kind());
+                        None
+                    }
+                    Err(e) => {
+                        warn!("MilestonePayload unpacking failed: {:?}", e);
+                        None
+                    }
+                }
+            })
+            .await;
+        }
+
+        // Only stream the blocks of the milestone cone if they are listened to
+        let has_block_topics = client
+            .mqtt
+            .topic_handlers
+            .read()
+            .await
+            .keys()
+            .any(|topic| topic.as_str().starts_with("blocks") || topic.as_str().starts_with("transactions/"));
+
+        if has_block_topics {
+            let mut cone = inx
+                .read_milestone_cone(MilestoneRequest {
+                    milestone_index: info.milestone_index,
+                    milestone_id: None,
+                })
+                .await
+                .map_err(inx_error)?
+                .into_inner();
+
+            while let Some(block) = cone.message().await.map_err(inx_error)? {
+                dispatch_block(client, block, &protocol_parameters).await;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn dispatch_block(
+    client: &Client,
+    block_with_metadata: BlockWithMetadata,
+    protocol_parameters: &ProtocolParameters,
+) {
+    let Some(raw_block) = block_with_metadata.block else {
+        return;
+    };
+    let block = match Block::unpack_verified(raw_block.data, protocol_parameters) {
+        Ok(block) => block,
+        Err(e) => {
+            warn!("Block unpacking failed: {:?}", e);
+            return;
+        }
+    };
+    let is_included = block_with_metadata.metadata.map_or(false, |metadata| {
+        metadata.ledger_inclusion_state == LedgerInclusionState::Included as i32
+    });
+
+    let mut topics = vec!["blocks".to_owned()];
+
+    match block.payload() {
+        Some(Payload::Transaction(transaction)) => {
+            topics.push("blocks/transaction".to_owned());
+
+            let TransactionEssence::Regular(essence) = transaction.essence();
+            if let Some(Payload::TaggedData(tagged_data)) = essence.payload() {
+                topics.push("blocks/transaction/tagged-data".to_owned());
+                if !tagged_data.tag().is_empty() {
+                    topics.push(format!(
+                        "blocks/transaction/tagged-data/{}",
+                        prefix_hex::encode(tagged_data.tag())
+                    ));
+                }
+            }
+            if is_included {
+                topics.push(format!("transactions/{}/included-block", transaction.id()));
+            }
+        }
+        Some(Payload::TaggedData(tagged_data)) => {
+            topics.push("blocks/tagged-data".to_owned());
+            if !tagged_data.tag().is_empty() {
+                topics.push(format!("blocks/tagged-data/{}", prefix_hex::encode(tagged_data.tag())));
+            }
+        }
+        _ => {}
+    }
+
+    dispatch(client, topics, || Some(MqttPayload::Block((&block).into()))).await;
+}
+
+async fn listen_to_ledger_updates(client: &Client, mut inx: InxClient<Channel>) -> Result<(), Error> {
+    let mut ledger_updates = inx
+        .listen_to_ledger_updates(MilestoneRangeRequest {
+            start_milestone_index: 0,
+            end_milestone_index: 0,
+        })
+        .await
+        .map_err(inx_error)?
+        .into_inner();
+
+    while let Some(ledger_update) = ledger_updates.message().await.map_err(inx_error)? {
+        match ledger_update.op {
+            Some(Op::Created(output)) => dispatch_output(client, output, None).await?,
+            Some(Op::Consumed(spent)) => {
+                let output = spent.output.ok_or_else(|| missing_field("output"))?;
+                let transaction_id_spent = raw_id(spent.transaction_id_spent.map(|id| id.id))?;
+
+                dispatch_output(
+                    client,
+                    output,
+                    Some((
+                        spent.milestone_index_spent,
+                        spent.milestone_timestamp_spent,
+                        transaction_id_spent,
+                    )),
+                )
+                .await?
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+async fn dispatch_output(
+    client: &Client,
+    output: LedgerOutput,
+    spent: Option<(u32, u32, [u8; 32])>,
+) -> Result<(), Error> {
+    let output_id =
+        OutputId::try_from(raw_id::<{ OutputId::LENGTH }>(output.output_id.map(|id| id.id))?).map_err(inx_error)?;
+    let block_id = BlockId::new(raw_id(output.block_id.map(|id| id.id))?);
+    let raw_output = output.output.ok_or_else(|| missing_field("output"))?;
+    let protocol_parameters = client.network_info.read().await.protocol_parameters.clone();
+    let hrp = *protocol_parameters.bech32_hrp();
+    let unpacked_output = match Output::unpack_verified(raw_output.data, &protocol_parameters) {
+        Ok(output) => output,
+        Err(e) => {
+            warn!("Output unpacking failed: {:?}", e);
+            return Ok(());
+        }
+    };
+
+    let spent_suffix = if spent.is_some() { "/spent" } else { "" };
+    let mut topics = vec![format!("outputs/{output_id}")];
+
+    match &unpacked_output {
+        Output::Alias(alias) => topics.push(format!("outputs/alias/{}", alias.alias_id_non_null(&output_id))),
+        Output::Nft(nft) => topics.push(format!("outputs/nft/{}", nft.nft_id_non_null(&output_id))),
+        Output::Foundry(foundry) => topics.push(format!("outputs/foundry/{}", foundry.id())),
+        _ => {}
+    }
+
+    for unlock_condition in unpacked_output.unlock_conditions().into_iter().flat_map(|u| u.iter()) {
+        let (kind, address): (&str, &Address) = match unlock_condition {
+            UnlockCondition::Address(uc) => ("address", uc.address()),
+            UnlockCondition::StorageDepositReturn(uc) => ("storage-return", uc.return_address()),
+            UnlockCondition::Expiration(uc) => ("expiration", uc.return_address()),
+            UnlockCondition::StateControllerAddress(uc) => ("state-controller", uc.address()),
+            UnlockCondition::GovernorAddress(uc) => ("governor", uc.address()),
+            UnlockCondition::ImmutableAliasAddress(uc) => ("immutable-alias", uc.address()),
+            UnlockCondition::Timelock(_) => continue,
+        };
+        let bech32_address = (*address).to_bech32(hrp);
+
+        topics.push(format!("outputs/unlock/{kind}/{bech32_address}{spent_suffix}"));
+        topics.push(format!("outputs/unlock/+/{bech32_address}{spent_suffix}"));
+    }
+
+    // The "+" topic is the same for all unlock conditions with the same address
+    topics.sort();
+    topics.dedup();
+
+    dispatch(client, topics, || {
+        let metadata = OutputMetadata::new(
+            block_id,
+            output_id,
+            spent.is_some(),
+            spent.map(|(index, ..)| index),
+            spent.map(|(_, timestamp, _)| timestamp),
+            spent.map(|(.., transaction_id)| TransactionId::new(transaction_id)),
+            output.milestone_index_booked,
+            output.milestone_timestamp_booked,
+            spent.map_or(output.milestone_index_booked, |(index, ..)| index),
+        );
+        let response = OutputWithMetadataResponse::from(OutputWithMetadata::new(unpacked_output, metadata));
+
+        match serde_json::to_value(response) {
+            Ok(value) => Some(MqttPayload::Json(value)),
+            Err(e) => {
+                warn!("Cannot serialize output: {:?}", e);
+                None
+            }
+        }
+    })
+    .await;
+
+    Ok(())
+}
+
+/// Passes the payload to the handlers of the topics, the payload is only created if any of the topics has a handler.
+async fn dispatch(
+    client: &Client,
+    topics: impl IntoIterator<Item = String>,
+    payload: impl FnOnce() -> Option<MqttPayload>,
+) {
+    let topic_handlers = client.mqtt.topic_handlers.read().await;
+    let topics = topics
+        .into_iter()
+        .filter_map(|topic| {
+            topic_handlers
+                .get(&Topic::new_unchecked(&topic))
+                .map(|handlers| (topic, handlers))
+        })
+        .collect::<Vec<_>>();
+
+    if topics.is_empty() {
+        return;
+    }
+
+    if let Some(payload) = payload() {
+        for (topic, handlers) in topics {
+            let event = TopicEvent {
+                topic,
+                payload: payload.clone(),
+            };
+            for handler in handlers {
+                handler(&event);
+            }
+        }
+    }
+}
+
+fn raw_id<const N: usize>(id: Option<Vec<u8>>) -> Result<[u8; N], Error> {
+    id.ok_or_else(|| missing_field("id"))?
+        .try_into()
+        .map_err(|id: Vec<u8>| Error::Inx(format!("invalid id length {}", id.len())))
+}
+
+fn missing_field(field: &str) -> Error {
+    Error::Inx(format!("missing field {field}"))
+}
+
+fn inx_error(err: impl core::fmt::Display) -> Error {
+    Error::Inx(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use inx::proto::{self, RawBlock, RawOutput};
+
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        payload::TaggedDataPayload,
+        rand::parents::rand_parents,
+        BlockBuilder, BlockDto,
+    };
+
+    async fn client_with_handlers(topics: &[&str]) -> (Client, Arc<Mutex<Vec<TopicEvent>>>) {
+        let client = Client::builder().finish().await.unwrap();
+        let events = Arc::new(Mutex::new(Vec::new()));
+
+        let mut topic_handlers = client.mqtt.topic_handlers.write().await;
+        for topic in topics {
+            let events = events.clone();
+            topic_handlers.insert(
+                Topic::new(*topic).unwrap(),
+                vec![Arc::new(
+                    Box::new(move |event: &TopicEvent| events.lock().unwrap().push(event.clone()))
+                        as Box<dyn Fn(&TopicEvent) + Send + Sync>,
+                )],
+            );
+        }
+        drop(topic_handlers);
+
+        (client, events)
+    }
+
+    fn topics(events: &Mutex<Vec<TopicEvent>>) -> Vec<String> {
+        events.lock().unwrap().iter().map(|event| event.topic.clone()).collect()
+    }
+
+    #[test]
+    fn raw_ids() {
+        assert_eq!(raw_id::<2>(Some(vec![1, 2])).unwrap(), [1, 2]);
+        assert!(matches!(raw_id::<2>(None), Err(Error::Inx(_))));
+        assert!(matches!(raw_id::<2>(Some(vec![1, 2, 3])), Err(Error::Inx(_))));
+    }
+
+    #[tokio::test]
+    async fn dispatch_to_handlers() {
+        let (client, events) = client_with_handlers(&["milestone-info/confirmed"]).await;
+
+        dispatch(
+            &client,
+            ["milestone-info/confirmed".to_owned(), "milestones".to_owned()],
+            || Some(MqttPayload::Json(serde_json::json!({ "index": 1 }))),
+        )
+        .await;
+        // The payload isn't created if no topic has a handler
+        dispatch(&client, ["milestones".to_owned()], || panic!("payload created")).await;
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [TopicEvent {
+                topic: "milestone-info/confirmed".to_owned(),
+                payload: MqttPayload::Json(serde_json::json!({ "index": 1 })),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn dispatch_tagged_data_block() {
+        let (client, events) = client_with_handlers(&[
+            "blocks",
+            "blocks/tagged-data",
+            "blocks/tagged-data/0x746167",
+            "blocks/transaction",
+        ])
+        .await;
+        let protocol_parameters = client.network_info.read().await.protocol_parameters.clone();
+        let block = BlockBuilder::new(rand_parents())
+            .with_payload(Payload::from(
+                TaggedDataPayload::new(b"tag".to_vec(), b"data".to_vec()).unwrap(),
+            ))
+            .finish()
+            .unwrap();
+
+        dispatch_block(
+            &client,
+            BlockWithMetadata {
+                block: Some(RawBlock {
+                    data: block.pack_to_vec(),
+                }),
+                ..Default::default()
+            },
+            &protocol_parameters,
+        )
+        .await;
+        // Blocks that don't unpack are dropped
+        dispatch_block(
+            &client,
+            BlockWithMetadata {
+                block: Some(RawBlock { data: vec![0] }),
+                ..Default::default()
+            },
+            &protocol_parameters,
+        )
+        .await;
+
+        assert_eq!(
+            topics(&events),
+            ["blocks", "blocks/tagged-data", "blocks/tagged-data/0x746167"]
+        );
+        assert!(
+            events
+                .lock()
+                .unwrap()
+                .iter()
+                .all(|event| event.payload == MqttPayload::Block(BlockDto::from(&block)))
+        );
+    }
+
+    #[tokio::test]
+    async fn dispatch_created_and_spent_output() {
+        let address = Address::from(Ed25519Address::new([1; 32]));
+        let bech32_address = address.to_bech32(*ProtocolParameters::default().bech32_hrp());
+        let output_id = OutputId::new(TransactionId::new([2; 32]), 0).unwrap();
+        let (client, events) = client_with_handlers(&[
+            format!("outputs/{output_id}").as_str(),
+            format!("outputs/unlock/address/{bech32_address}").as_str(),
+            format!("outputs/unlock/+/{bech32_address}/spent").as_str(),
+        ])
+        .await;
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(u64::MAX)
+            .unwrap();
+        let ledger_output = LedgerOutput {
+            output_id: Some(proto::OutputId {
+                id: output_id.pack_to_vec(),
+            }),
+            block_id: Some(proto::BlockId { id: vec![3; 32] }),
+            milestone_index_booked: 4,
+            milestone_timestamp_booked: 5,
+            output: Some(RawOutput {
+                data: output.pack_to_vec(),
+            }),
+        };
+
+        dispatch_output(&client, ledger_output.clone(), None).await.unwrap();
+        dispatch_output(&client, ledger_output, Some((6, 7, [8; 32])))
+            .await
+            .unwrap();
+
+        // Only the topics with handlers receive the output, the spent one on the topics with the "/spent" suffix
+        assert_eq!(
+            topics(&events),
+            [
+                format!("outputs/{output_id}"),
+                format!("outputs/unlock/address/{bech32_address}"),
+                format!("outputs/{output_id}"),
+                format!("outputs/unlock/+/{bech32_address}/spent"),
+            ]
+        );
+        let events = events.lock().unwrap();
+        let MqttPayload::Json(created) = &events[0].payload else {
+            panic!("expected a JSON payload")
+        };
+        assert_eq!(created["metadata"]["isSpent"], false);
+        assert_eq!(created["metadata"]["milestoneIndexBooked"], 4);
+        let MqttPayload::Json(spent) = &events[2].payload else {
+            panic!("expected a JSON payload")
+        };
+        assert_eq!(spent["metadata"]["isSpent"], true);
+        assert_eq!(spent["metadata"]["milestoneIndexSpent"], 6);
+        assert_eq!(
+            spent["metadata"]["transactionIdSpent"],
+            TransactionId::new([8; 32]).to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn dispatch_output_without_id() {
+        let (client, _) = client_with_handlers(&[]).await;
+
+        assert!(matches!(
+            dispatch_output(&client, LedgerOutput::default(), None).await,
+            Err(Error::Inx(_))
+        ));
+    }
+}
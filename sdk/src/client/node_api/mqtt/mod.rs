@@ -4,21 +4,29 @@
 //! IOTA node MQTT API
 
 mod error;
+#[cfg(feature = "inx")]
+mod inx;
+pub(crate) mod socks5;
 pub mod types;
 
-use std::{sync::Arc, time::Instant};
+use std::sync::Arc;
 
 use crypto::utils;
 use log::warn;
 use packable::PackableExt;
-use rumqttc::{AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, QoS, SubscribeFilter, Transport};
+use rumqttc::{
+    AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, Proxy, ProxyAuth, ProxyType, QoS,
+    SubscribeFilter, TlsConfiguration, Transport,
+};
 use tokio::sync::watch::Receiver as WatchReceiver;
+use url::Url;
 
 pub use self::{error::Error, types::*};
 use crate::{
-    client::{Client, ClientInner},
+    client::{node_manager::node::Node, Client, ClientInner},
     types::block::{
         payload::{milestone::ReceiptMilestoneOption, Payload},
+        protocol::ProtocolParameters,
         Block,
     },
 };
@@ -52,6 +60,11 @@ impl ClientInner {
 }
 
 async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
+    #[cfg(feature = "inx")]
+    if let Some(inx_address) = client.mqtt.broker_options.read().await.inx_address.clone() {
+        return inx::set_inx_listener(client, inx_address).await;
+    }
+
     // if the client was disconnected, we clear it so we can start over
     if *client.mqtt_event_receiver().await.borrow() == MqttEvent::Disconnected {
         *client.mqtt.client.write().await = None;
@@ -59,41 +72,37 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
     let exists = client.mqtt.client.read().await.is_some();
 
     if !exists {
-        let node_manager = client.node_manager.read().await;
-        let nodes = if !node_manager.ignore_node_health {
-            #[cfg(not(target_family = "wasm"))]
-            {
-                node_manager
-                    .healthy_nodes
-                    .read()
-                    .map_or(node_manager.nodes.clone(), |healthy_nodes| {
-                        healthy_nodes.iter().map(|(node, _)| node.clone()).collect()
-                    })
-            }
-            #[cfg(target_family = "wasm")]
-            {
-                client.node_manager.nodes.clone()
-            }
-        } else {
-            node_manager.nodes.clone()
+        let proxy = client.node_manager.read().await.http_client.proxy.clone();
+        let proxy = match proxy {
+            Some(proxy) => Some(mqtt_proxy(client, &proxy).await?),
+            None => None,
         };
-        for node in &nodes {
+
+        for node in &broker_nodes(client).await {
             let host = node.url.host_str().expect("can't get host from URL");
             let mut entropy = [0u8; 8];
             utils::rand::fill(&mut entropy)?;
             let id = format!("iotasdk{}", prefix_hex::encode(entropy));
             let broker_options = client.mqtt.broker_options.read().await;
             let port = broker_options.port;
-            let secure = node.url.scheme() == "https";
-            let mqtt_options = if broker_options.use_ws {
+            let secure = broker_options.use_tls.unwrap_or(node.url.scheme() == "https");
+            let tls_configuration = broker_options.tls.as_ref().map(tls_configuration);
+            let mut mqtt_options = if broker_options.use_ws {
                 let uri = format!(
-                    "{}://{host}:{}/api/mqtt/v1",
+                    "{}://{host}:{}{}",
                     if secure { "wss" } else { "ws" },
-                    node.url.port_or_known_default().unwrap_or(port)
+                    broker_options
+                        .ws_port
+                        .or_else(|| node.url.port_or_known_default())
+                        .unwrap_or(port),
+                    broker_options.ws_path
                 );
                 let mut mqtt_options = MqttOptions::new(id, uri, port);
                 if secure {
-                    mqtt_options.set_transport(Transport::wss_with_default_config());
+                    mqtt_options.set_transport(match tls_configuration {
+                        Some(tls_configuration) => Transport::wss_with_config(tls_configuration),
+                        None => Transport::wss_with_default_config(),
+                    });
                 } else {
                     mqtt_options.set_transport(Transport::ws());
                 }
@@ -102,10 +111,16 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
                 let uri = host.to_string();
                 let mut mqtt_options = MqttOptions::new(id, uri, port);
                 if secure {
-                    mqtt_options.set_transport(Transport::tls_with_default_config());
+                    mqtt_options.set_transport(match tls_configuration {
+                        Some(tls_configuration) => Transport::tls_with_config(tls_configuration),
+                        None => Transport::tls_with_default_config(),
+                    });
                 }
                 mqtt_options
             };
+            if let Some(proxy) = &proxy {
+                mqtt_options.set_proxy(proxy.clone());
+            }
             let (_, mut connection) = AsyncClient::new(mqtt_options.clone(), 10);
             connection
                 .set_network_options(*NetworkOptions::new().set_connection_timeout(broker_options.timeout.as_secs()));
@@ -131,9 +146,69 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
     Ok(())
 }
 
+/// Converts the proxy of the client to a proxy of the MQTT connection, which only supports HTTP proxies. SOCKS5 proxies
+/// are used through a local tunnel.
+async fn mqtt_proxy(client: &Client, proxy: &Url) -> Result<Proxy, Error> {
+    if matches!(proxy.scheme(), "socks5" | "socks5h") {
+        return socks5::socks5_tunnel(client, proxy).await;
+    }
+    let (Some(addr), "http") = (proxy.host_str(), proxy.scheme()) else {
+        return Err(Error::UnsupportedProxy(proxy.scheme().to_string()));
+    };
+    let auth = match proxy.password() {
+        Some(password) => ProxyAuth::Basic {
+            username: proxy.username().to_string(),
+            password: password.to_string(),
+        },
+        None => ProxyAuth::None,
+    };
+
+    Ok(Proxy {
+        ty: ProxyType::Http,
+        auth,
+        addr: addr.to_string(),
+        port: proxy.port_or_known_default().unwrap_or(80),
+    })
+}
+
+/// Returns the nodes whose broker can be connected to, the healthy ones unless the node health is ignored.
+async fn broker_nodes(client: &Client) -> Vec<Node> {
+    let node_manager = client.node_manager.read().await;
+    if !node_manager.ignore_node_health {
+        #[cfg(not(target_family = "wasm"))]
+        {
+            node_manager
+                .healthy_nodes
+                .read()
+                .map_or(node_manager.nodes.clone(), |healthy_nodes| {
+                    healthy_nodes.iter().map(|(node, _)| node.clone()).collect()
+                })
+        }
+        #[cfg(target_family = "wasm")]
+        {
+            client.node_manager.nodes.clone()
+        }
+    } else {
+        node_manager.nodes.clone()
+    }
+}
+
+fn tls_configuration(tls: &BrokerTlsOptions) -> TlsConfiguration {
+    TlsConfiguration::Simple {
+        ca: tls.ca_certificates.as_bytes().to_vec(),
+        alpn: None,
+        client_auth: tls
+            .client_certificate
+            .as_ref()
+            .zip(tls.client_key.as_ref())
+            .map(|(certificate, key)| (certificate.as_bytes().to_vec(), key.as_bytes().to_vec())),
+    }
+}
+
 fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
     let client = client.clone();
-    std::thread::spawn(move || {
+    let background_tasks = client.background_tasks.clone();
+    background_tasks.spawn_thread("mqtt-event-loop", move || {
         let runtime = tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()
@@ -145,7 +220,6 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
             // can perform the re-subscriptions and reset `is_subscribed` to true.
             // we need the flag since the first ConnAck must be ignored.
             let mut is_subscribed = true;
-            let mut error_instant = Instant::now();
             let mut connection_failure_count = 0;
 
             loop {
@@ -153,6 +227,7 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
 
                 match event {
                     Ok(Event::Incoming(Incoming::ConnAck(_))) => {
+                        connection_failure_count = 0;
                         let _ = client.mqtt.sender.read().await.send(MqttEvent::Connected);
                         if !is_subscribed {
                             is_subscribed = true;
@@ -178,6 +253,7 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
                                     .subscribe_many(topics)
                                     .await;
                             }
+                            let _ = client.mqtt.sender.read().await.send(MqttEvent::Reconnected);
                         }
                     }
                     Ok(Event::Incoming(Incoming::Publish(p))) => {
@@ -187,90 +263,43 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
                             let mqtt_topic_handlers = client.mqtt.topic_handlers.read().await;
 
                             if let Some(handlers) = mqtt_topic_handlers.get(&Topic::new_unchecked(&p.topic)) {
-                                let event = {
-                                    if p.topic.contains("blocks") || p.topic.contains("included-block") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match Block::unpack_verified(payload, protocol_parameters) {
-                                            Ok(block) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Block((&block).into()),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Block unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else if p.topic.contains("milestones") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match Payload::unpack_verified(payload, protocol_parameters) {
-                                            Ok(Payload::Milestone(milestone)) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::MilestonePayload(milestone.as_ref().into()),
-                                            }),
-                                            Ok(p) => {
-                                                warn!(
-                                                    "'milestone' topic returned non-milestone payload, kind: {:?}",
-                                                    p.kind()
-                                                );
-                                                Err(())
-                                            }
-                                            Err(e) => {
-                                                warn!("MilestonePayload unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else if p.topic.contains("receipts") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match ReceiptMilestoneOption::unpack_verified(payload, protocol_parameters) {
-                                            Ok(receipt) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Receipt((&receipt).into()),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Receipt unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else {
-                                        match serde_json::from_slice(&p.payload) {
-                                            Ok(value) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Json(value),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Cannot parse JSON: {:?}", e);
-                                                Err(())
-                                            }
+                                let payload = if is_packed_topic(&p.topic) {
+                                    let protocol_parameters = &client.network_info.read().await.protocol_parameters;
+                                    unpack_payload(&p.topic, &p.payload, protocol_parameters)
+                                } else {
+                                    match serde_json::from_slice(&p.payload) {
+                                        Ok(value) => Some(MqttPayload::Json(value)),
+                                        Err(e) => {
+                                            warn!("Cannot parse JSON: {:?}", e);
+                                            None
                                         }
                                     }
                                 };
-                                if let Ok(event) = event {
+                                if let Some(payload) = payload {
+                                    let event = TopicEvent {
+                                        topic: p.topic.clone(),
+                                        payload,
+                                    };
                                     for handler in handlers {
                                         handler(&event);
                                     }
-                                };
+                                }
                             }
                         });
                     }
-                    Err(_) => {
-                        if error_instant.elapsed().as_secs() < 5 {
-                            connection_failure_count += 1;
-                        } else {
-                            connection_failure_count = 1;
-                        }
-                        if connection_failure_count == client.mqtt.broker_options.read().await.max_reconnection_attempts
-                        {
+                    Err(err) => {
+                        connection_failure_count += 1;
+                        let broker_options = client.mqtt.broker_options.read().await.clone();
+                        if connection_failure_count == broker_options.max_reconnection_attempts {
                             let _ = client.mqtt.sender.read().await.send(MqttEvent::Disconnected);
                             break;
                         }
-                        error_instant = Instant::now();
                         is_subscribed = false;
+
+                        // Wait before reconnecting, so an unreachable broker isn't hammered with connection attempts
+                        let delay = broker_options.reconnection_delay_for_attempt(connection_failure_count);
+                        warn!("MQTT connection error: {err}, reconnecting in {delay:?}");
+                        tokio::time::sleep(delay).await;
                     }
                     _ => {}
                 }
@@ -279,6 +308,47 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
     });
 }
 
+/// Returns whether the topic publishes packed blocks, milestones or receipts instead of JSON.
+fn is_packed_topic(topic: &str) -> bool {
+    topic.contains("blocks")
+        || topic.contains("included-block")
+        || topic.contains("milestones")
+        || topic.contains("receipts")
+}
+
+/// Unpacks the payload of a topic publishing packed blocks, milestones or receipts.
+fn unpack_payload(topic: &str, payload: &[u8], protocol_parameters: &ProtocolParameters) -> Option<MqttPayload> {
+    if topic.contains("blocks") || topic.contains("included-block") {
+        match Block::unpack_verified(payload, protocol_parameters) {
+            Ok(block) => Some(MqttPayload::Block((&block).into())),
+            Err(e) => {
+                warn!("Block unpacking failed: {:?}", e);
+                None
+            }
+        }
+    } else if topic.contains("milestones") {
+        match Payload::unpack_verified(payload, protocol_parameters) {
+            Ok(Payload::Milestone(milestone)) => Some(MqttPayload::MilestonePayload(milestone.as_ref().into())),
+            Ok(p) => {
+                warn!("'milestone' topic returned non-milestone payload, kind: {:?}", p.kind());
+                None
+            }
+            Err(e) => {
+                warn!("MilestonePayload unpacking failed: {:?}", e);
+                None
+            }
+        }
+    } else {
+        match ReceiptMilestoneOption::unpack_verified(payload, protocol_parameters) {
+            Ok(receipt) => Some(MqttPayload::Receipt((&receipt).into())),
+            Err(e) => {
+                warn!("Receipt unpacking failed: {:?}", e);
+                None
+            }
+        }
+    }
+}
+
 /// MQTT subscriber.
 pub struct MqttManager<'a> {
     client: &'a Client,
@@ -314,6 +384,13 @@ impl<'a> MqttManager<'a> {
         }
 
         *self.client.mqtt.client.write().await = None;
+        self.client.mqtt.socks5_tunnel.write().await.take();
+
+        #[cfg(feature = "inx")]
+        if let Some(inx_listener) = self.client.mqtt.inx_listener.write().await.take() {
+            inx_listener.abort();
+            self.client.mqtt.topic_handlers.write().await.clear();
+        }
 
         Ok(())
     }
@@ -352,26 +429,34 @@ impl<'a> MqttTopicManager<'a> {
         self,
         callback: C,
     ) -> Result<(), Error> {
-        let cb =
-            Arc::new(Box::new(callback)
-                as Box<
-                    dyn Fn(&crate::client::node_api::mqtt::TopicEvent) + Send + Sync + 'static,
-                >);
+        self.subscribe_handler(Arc::new(Box::new(callback))).await
+    }
+
+    /// Subscribe to the given topics with a handler, which can be removed again with
+    /// [`MqttTopicManager::unsubscribe_handler()`].
+    pub(crate) async fn subscribe_handler(self, cb: Arc<TopicHandler>) -> Result<(), Error> {
         set_mqtt_client(self.client).await?;
-        self.client
-            .inner
-            .mqtt
-            .client
-            .write()
-            .await
-            .as_ref()
-            .ok_or(Error::ConnectionNotFound)?
-            .subscribe_many(
-                self.topics
-                    .iter()
-                    .map(|t| SubscribeFilter::new(t.as_str().to_owned(), QoS::AtLeastOnce)),
-            )
-            .await?;
+        // INX streams the events of all topics, which are then only passed to the handlers of subscribed topics
+        #[cfg(feature = "inx")]
+        let uses_inx = self.client.mqtt.broker_options.read().await.inx_address.is_some();
+        #[cfg(not(feature = "inx"))]
+        let uses_inx = false;
+        if !uses_inx {
+            self.client
+                .inner
+                .mqtt
+                .client
+                .write()
+                .await
+                .as_ref()
+                .ok_or(Error::ConnectionNotFound)?
+                .subscribe_many(
+                    self.topics
+                        .iter()
+                        .map(|t| SubscribeFilter::new(t.as_str().to_owned(), QoS::AtLeastOnce)),
+                )
+                .await?;
+        }
         {
             let mut mqtt_topic_handlers = self.client.mqtt.topic_handlers.write().await;
             for topic in self.topics {
@@ -413,4 +498,38 @@ impl<'a> MqttTopicManager<'a> {
 
         Ok(())
     }
+
+    /// Removes the handler from the given topics, and only unsubscribes from the topics without other handlers.
+    pub(crate) async fn unsubscribe_handler(self, handler: &Arc<TopicHandler>) -> Result<(), Error> {
+        let (unused_topics, empty_topic_handlers) = {
+            let mut mqtt_topic_handlers = self.client.mqtt.topic_handlers.write().await;
+            let mut unused_topics = Vec::new();
+            for topic in self.topics {
+                if let Some(handlers) = mqtt_topic_handlers.get_mut(&topic) {
+                    handlers.retain(|h| !Arc::ptr_eq(h, handler));
+                    if handlers.is_empty() {
+                        mqtt_topic_handlers.remove(&topic);
+                        unused_topics.push(topic);
+                    }
+                }
+            }
+            (unused_topics, mqtt_topic_handlers.is_empty())
+        };
+
+        if unused_topics.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(client) = &*self.client.mqtt.client.write().await {
+            for topic in &unused_topics {
+                client.unsubscribe(topic.as_str()).await?;
+            }
+        }
+
+        if self.client.mqtt.broker_options.read().await.automatic_disconnect && empty_topic_handlers {
+            MqttManager::new(self.client).disconnect().await?;
+        }
+
+        Ok(())
+    }
 }
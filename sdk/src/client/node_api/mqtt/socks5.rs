@@ -0,0 +1,258 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tunneling the MQTT connection through a SOCKS5 proxy
+//!
+//! rumqttc only connects through HTTP proxies, so a local HTTP proxy is started that opens the requested connections
+//! through the SOCKS5 proxy. The broker host name is passed to the SOCKS5 proxy, which resolves it, and TLS and
+//! WebSocket connections still use it.
+
+use std::{
+    io,
+    net::{Ipv4Addr, SocketAddr},
+};
+
+use log::warn;
+use rumqttc::{Proxy, ProxyAuth, ProxyType};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+use tokio_socks::tcp::Socks5Stream;
+use url::Url;
+
+use super::Error;
+use crate::client::Client;
+
+const DEFAULT_SOCKS5_PORT: u16 = 1080;
+
+/// The local HTTP proxy that tunnels the MQTT connection through a SOCKS5 proxy. It's stopped when dropped.
+#[derive(Debug)]
+pub(crate) struct Socks5Tunnel {
+    socks5_proxy: Url,
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl Drop for Socks5Tunnel {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Starts the tunnel to the SOCKS5 proxy of the client, unless it's already running, and returns it as proxy of the
+/// MQTT connection.
+pub(super) async fn socks5_tunnel(client: &Client, socks5_proxy: &Url) -> Result<Proxy, Error> {
+    let mut tunnel = client.mqtt.socks5_tunnel.write().await;
+
+    if let Some(tunnel) = tunnel.as_ref() {
+        if &tunnel.socks5_proxy == socks5_proxy && !tunnel.task.is_finished() {
+            return Ok(http_proxy(tunnel.address));
+        }
+    }
+
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .await
+        .map_err(Error::Socks5Tunnel)?;
+    let address = listener.local_addr().map_err(Error::Socks5Tunnel)?;
+    let proxy_address = (
+        socks5_proxy.host_str().unwrap_or_default().to_owned(),
+        socks5_proxy.port().unwrap_or(DEFAULT_SOCKS5_PORT),
+    );
+    let credentials = socks5_proxy
+        .password()
+        .map(|password| (socks5_proxy.username().to_owned(), password.to_owned()));
+
+    let task = client.background_tasks.spawn("mqtt-socks5-tunnel", async move {
+        loop {
+            match listener.accept().await {
+                Ok((connection, _)) => {
+                    tokio::spawn(tunnel_connection(
+                        connection,
+                        proxy_address.clone(),
+                        credentials.clone(),
+                    ));
+                }
+                Err(err) => {
+                    warn!("MQTT SOCKS5 tunnel error: {err}");
+                    break;
+                }
+            }
+        }
+    });
+    tunnel.replace(Socks5Tunnel {
+        socks5_proxy: socks5_proxy.clone(),
+        address,
+        task,
+    });
+
+    Ok(http_proxy(address))
+}
+
+fn http_proxy(address: SocketAddr) -> Proxy {
+    Proxy {
+        ty: ProxyType::Http,
+        auth: ProxyAuth::None,
+        addr: address.ip().to_string(),
+        port: address.port(),
+    }
+}
+
+// Reads the HTTP CONNECT request of the MQTT client, connects to the requested broker through the SOCKS5 proxy and
+// forwards the connection.
+async fn tunnel_connection(connection: TcpStream, proxy_address: (String, u16), credentials: Option<(String, String)>) {
+    // The client waits for the response before it sends anything else, so nothing is lost in the buffer
+    let mut connection = BufReader::new(connection);
+
+    let mut broker = match connect_to_broker(&mut connection, &proxy_address, credentials.as_ref()).await {
+        Ok(broker) => broker,
+        Err(err) => {
+            warn!("MQTT SOCKS5 tunnel error: {err}");
+            connection.write_all(b"HTTP/1.1 502 Bad Gateway\r\n\r\n").await.ok();
+            return;
+        }
+    };
+
+    if connection
+        .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+        .await
+        .is_ok()
+    {
+        tokio::io::copy_bidirectional(&mut connection, &mut broker).await.ok();
+    }
+}
+
+async fn connect_to_broker(
+    connection: &mut BufReader<TcpStream>,
+    proxy_address: &(String, u16),
+    credentials: Option<&(String, String)>,
+) -> io::Result<Socks5Stream<TcpStream>> {
+    let mut request_line = String::new();
+    connection.read_line(&mut request_line).await?;
+    let (host, port) = connect_target(&request_line)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid CONNECT request"))?;
+
+    // Skip the headers
+    let mut header = String::new();
+    while connection.read_line(&mut header).await? > 2 {
+        header.clear();
+    }
+
+    let proxy_address = (proxy_address.0.as_str(), proxy_address.1);
+    match credentials {
+        Some((username, password)) => {
+            Socks5Stream::connect_with_password(proxy_address, (host.as_str(), port), username, password).await
+        }
+        None => Socks5Stream::connect(proxy_address, (host.as_str(), port)).await,
+    }
+    .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+}
+
+// Returns the host and port of a `CONNECT host:port HTTP/1.1` request line.
+fn connect_target(request_line: &str) -> Option<(String, u16)> {
+    let mut parts = request_line.split_whitespace();
+    if parts.next()? != "CONNECT" {
+        return None;
+    }
+    let (host, port) = parts.next()?.rsplit_once(':')?;
+
+    Some((
+        host.trim_start_matches('[').trim_end_matches(']').to_owned(),
+        port.parse().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+
+    #[test]
+    fn connect_request() {
+        assert_eq!(
+            connect_target("CONNECT api.testnet.shimmer.network:1883 HTTP/1.1\r\n"),
+            Some(("api.testnet.shimmer.network".to_owned(), 1883))
+        );
+        assert_eq!(
+            connect_target("CONNECT [::1]:443 HTTP/1.1\r\n"),
+            Some(("::1".to_owned(), 443))
+        );
+        assert_eq!(connect_target("GET / HTTP/1.1\r\n"), None);
+        assert_eq!(connect_target("CONNECT localhost HTTP/1.1\r\n"), None);
+    }
+
+    // A SOCKS5 proxy without authentication that only supports IPv4 and domain targets
+    async fn socks5_proxy() -> SocketAddr {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut connection, _) = listener.accept().await.unwrap();
+            let mut greeting = [0; 2];
+            connection.read_exact(&mut greeting).await.unwrap();
+            let mut methods = vec![0; greeting[1] as usize];
+            connection.read_exact(&mut methods).await.unwrap();
+            connection.write_all(&[5, 0]).await.unwrap();
+
+            let mut request = [0; 4];
+            connection.read_exact(&mut request).await.unwrap();
+            let host = if request[3] == 1 {
+                let mut ip = [0; 4];
+                connection.read_exact(&mut ip).await.unwrap();
+                Ipv4Addr::from(ip).to_string()
+            } else {
+                let mut host = vec![0; connection.read_u8().await.unwrap() as usize];
+                connection.read_exact(&mut host).await.unwrap();
+                String::from_utf8(host).unwrap()
+            };
+            let port = connection.read_u16().await.unwrap();
+
+            let mut target = TcpStream::connect((host.as_str(), port)).await.unwrap();
+            connection.write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0]).await.unwrap();
+            tokio::io::copy_bidirectional(&mut connection, &mut target).await.ok();
+        });
+
+        address
+    }
+
+    #[tokio::test]
+    async fn tunnel_through_socks5_proxy() {
+        let broker = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let broker_port = broker.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (mut connection, _) = broker.accept().await.unwrap();
+            let mut message = [0; 4];
+            connection.read_exact(&mut message).await.unwrap();
+            connection.write_all(&message).await.unwrap();
+        });
+        let proxy_address = socks5_proxy().await;
+
+        let tunnel = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let tunnel_address = tunnel.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (connection, _) = tunnel.accept().await.unwrap();
+            tunnel_connection(connection, ("127.0.0.1".to_owned(), proxy_address.port()), None).await;
+        });
+
+        let mut connection = TcpStream::connect(tunnel_address).await.unwrap();
+        connection
+            .write_all(
+                format!("CONNECT localhost:{broker_port} HTTP/1.1\r\nHost: localhost:{broker_port}\r\n\r\n").as_bytes(),
+            )
+            .await
+            .unwrap();
+        let mut response = BufReader::new(connection);
+        let mut status_line = String::new();
+        response.read_line(&mut status_line).await.unwrap();
+        assert_eq!(status_line, "HTTP/1.1 200 Connection established\r\n");
+        let mut empty_line = String::new();
+        response.read_line(&mut empty_line).await.unwrap();
+
+        response.write_all(b"ping").await.unwrap();
+        let mut message = [0; 4];
+        response.read_exact(&mut message).await.unwrap();
+        assert_eq!(&message, b"ping");
+    }
+}
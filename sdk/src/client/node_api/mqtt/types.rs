@@ -15,7 +15,7 @@ use crate::types::block::{
     BlockDto,
 };
 
-type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
+pub(crate) type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
 
 pub(crate) type TopicHandlerMap = HashMap<Topic, Vec<Arc<TopicHandler>>>;
 
@@ -52,11 +52,13 @@ pub enum MqttEvent {
     Connected,
     /// Client was disconnected.
     Disconnected,
+    /// Client was reconnected after a connection error and resubscribed to the topics, emitted after `Connected`.
+    Reconnected,
 }
 
 /// The MQTT broker options.
 
-#[derive(Copy, Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[must_use]
 pub struct BrokerOptions {
@@ -70,6 +72,62 @@ pub struct BrokerOptions {
     pub(crate) port: u16,
     #[serde(default = "default_max_reconnection_attempts")]
     pub(crate) max_reconnection_attempts: usize,
+    #[serde(default = "default_broker_ws_path")]
+    pub(crate) ws_path: String,
+    #[serde(default)]
+    pub(crate) ws_port: Option<u16>,
+    #[serde(default)]
+    pub(crate) use_tls: Option<bool>,
+    #[serde(default)]
+    pub(crate) tls: Option<BrokerTlsOptions>,
+    #[serde(default = "default_reconnection_delay")]
+    pub(crate) reconnection_delay: Duration,
+    #[serde(default = "default_max_reconnection_delay")]
+    pub(crate) max_reconnection_delay: Duration,
+    #[cfg(feature = "inx")]
+    #[serde(default)]
+    pub(crate) inx_address: Option<String>,
+}
+
+/// The TLS options used to connect to a MQTT broker.
+#[derive(Clone, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[must_use]
+pub struct BrokerTlsOptions {
+    /// The PEM encoded certificate authorities the broker certificate is verified against.
+    pub ca_certificates: String,
+    /// The PEM encoded client certificate, for brokers requiring client authentication.
+    pub client_certificate: Option<String>,
+    /// The PEM encoded private key of the client certificate.
+    pub client_key: Option<String>,
+}
+
+impl std::fmt::Debug for BrokerTlsOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BrokerTlsOptions")
+            .field("ca_certificates", &self.ca_certificates)
+            .field("client_certificate", &self.client_certificate)
+            .field("client_key", &self.client_key.as_ref().map(|_| "<omitted>"))
+            .finish()
+    }
+}
+
+impl BrokerTlsOptions {
+    /// Creates TLS options verifying the broker against the given PEM encoded certificate authorities.
+    pub fn new(ca_certificates: impl Into<String>) -> Self {
+        Self {
+            ca_certificates: ca_certificates.into(),
+            client_certificate: None,
+            client_key: None,
+        }
+    }
+
+    /// Sets the PEM encoded client certificate and private key used to authenticate to the broker.
+    pub fn with_client_auth(mut self, certificate: impl Into<String>, key: impl Into<String>) -> Self {
+        self.client_certificate = Some(certificate.into());
+        self.client_key = Some(key.into());
+        self
+    }
 }
 
 fn default_broker_automatic_disconnect() -> bool {
@@ -92,6 +150,18 @@ fn default_max_reconnection_attempts() -> usize {
     0
 }
 
+fn default_broker_ws_path() -> String {
+    "/api/mqtt/v1".to_string()
+}
+
+fn default_reconnection_delay() -> Duration {
+    Duration::from_secs(1)
+}
+
+fn default_max_reconnection_delay() -> Duration {
+    Duration::from_secs(60)
+}
+
 impl Default for BrokerOptions {
     fn default() -> Self {
         Self {
@@ -100,6 +170,14 @@ impl Default for BrokerOptions {
             use_ws: default_broker_use_ws(),
             port: default_broker_port(),
             max_reconnection_attempts: default_max_reconnection_attempts(),
+            ws_path: default_broker_ws_path(),
+            ws_port: None,
+            use_tls: None,
+            tls: None,
+            reconnection_delay: default_reconnection_delay(),
+            max_reconnection_delay: default_max_reconnection_delay(),
+            #[cfg(feature = "inx")]
+            inx_address: None,
         }
     }
 }
@@ -139,6 +217,60 @@ impl BrokerOptions {
         self.max_reconnection_attempts = max_reconnection_attempts;
         self
     }
+
+    /// Sets the path of the MQTT over WebSocket endpoint, `/api/mqtt/v1` by default.
+    pub fn ws_path(mut self, ws_path: impl Into<String>) -> Self {
+        self.ws_path = ws_path.into();
+        self
+    }
+
+    /// Sets the port of the MQTT over WebSocket endpoint. Defaults to the port of the node URL.
+    pub fn ws_port(mut self, ws_port: impl Into<Option<u16>>) -> Self {
+        self.ws_port = ws_port.into();
+        self
+    }
+
+    /// Sets whether TLS should be used to connect to the broker. Defaults to using it for `https` node URLs.
+    pub fn use_tls(mut self, use_tls: impl Into<Option<bool>>) -> Self {
+        self.use_tls = use_tls.into();
+        self
+    }
+
+    /// Sets the TLS options, to use custom certificate authorities or client authentication.
+    pub fn tls(mut self, tls: impl Into<Option<BrokerTlsOptions>>) -> Self {
+        self.tls = tls.into();
+        self
+    }
+
+    /// Sets the delay before the first reconnection attempt after a connection error, 1 second by default. The delay
+    /// doubles with every failed attempt, up to `max_reconnection_delay`, 60 seconds by default.
+    pub fn reconnection_delay(mut self, reconnection_delay: Duration, max_reconnection_delay: Duration) -> Self {
+        self.reconnection_delay = reconnection_delay;
+        self.max_reconnection_delay = max_reconnection_delay;
+        self
+    }
+
+    /// Sets the address of the INX interface of a HORNET node, e.g. `http://localhost:9029`. If set, the topics are
+    /// streamed from INX instead of a MQTT broker, which only works for nodes the client can reach directly. Block
+    /// topics then receive the blocks once they are confirmed by a milestone, and only the confirmed milestone info is
+    /// published.
+    #[cfg(feature = "inx")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "inx")))]
+    pub fn inx_address(mut self, inx_address: impl Into<Option<String>>) -> Self {
+        self.inx_address = inx_address.into();
+        self
+    }
+
+    /// Returns the delay before a reconnection attempt, starting at 1 for the first attempt.
+    pub(crate) fn reconnection_delay_for_attempt(&self, attempt: usize) -> Duration {
+        let exponent = u32::try_from(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        let delay = self
+            .reconnection_delay
+            .checked_mul(2u32.saturating_pow(exponent))
+            .unwrap_or(Duration::MAX);
+
+        delay.min(self.max_reconnection_delay)
+    }
 }
 
 /// A MQTT topic.
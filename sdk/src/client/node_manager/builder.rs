@@ -3,22 +3,31 @@
 
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
+#[cfg(not(target_family = "wasm"))]
+use std::sync::Arc;
 use std::{
     collections::{HashMap, HashSet},
     sync::RwLock,
     time::Duration,
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use url::Url;
 
+#[cfg(not(target_family = "wasm"))]
+use crate::client::node_manager::resolver::{DnsResolver, SharedDnsResolver};
 use crate::{
     client::{
-        constants::{DEFAULT_MIN_QUORUM_SIZE, DEFAULT_QUORUM_THRESHOLD, DEFAULT_USER_AGENT, NODE_SYNC_INTERVAL},
+        constants::{
+            DEFAULT_BROADCAST_NODE_COUNT, DEFAULT_MIN_QUORUM_SIZE, DEFAULT_QUORUM_THRESHOLD, DEFAULT_USER_AGENT,
+            NODE_SYNC_INTERVAL,
+        },
         error::{Error, Result},
         node_manager::{
+            connection_pool::ConnectionPoolOptions,
             http_client::HttpClient,
             node::{Node, NodeAuth, NodeDto},
+            retry_policy::RetryPolicy,
             NodeManager,
         },
     },
@@ -58,15 +67,59 @@ pub struct NodeManagerBuilder {
     /// % of nodes that have to return the same response so it gets accepted
     #[serde(default = "default_quorum_threshold")]
     pub quorum_threshold: usize,
-    /// The User-Agent header for requests
+    /// Amount of nodes a block is posted to at the same time if local PoW is used
+    #[serde(default = "default_broadcast_node_count")]
+    pub broadcast_node_count: usize,
+    /// The User-Agent header for requests, no User-Agent header is sent if empty
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// If the client name and version headers should be sent with every request
+    #[serde(default = "default_identification_headers")]
+    pub identification_headers: bool,
+    /// How failed requests are retried
+    #[serde(default)]
+    pub retry_policy: RetryPolicy,
+    /// How connections to nodes are kept open and reused
+    #[serde(default)]
+    pub connection_pool: ConnectionPoolOptions,
+    /// HTTP or SOCKS5 proxy all requests to nodes are sent through. Its credentials aren't serialized, so they don't
+    /// end up in stored options.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_proxy_without_credentials"
+    )]
+    pub proxy: Option<Url>,
+    /// Custom DNS resolver for the host names of node URLs
+    #[cfg(not(target_family = "wasm"))]
+    #[serde(skip)]
+    pub(crate) dns_resolver: Option<SharedDnsResolver>,
 }
 
 fn default_user_agent() -> String {
     DEFAULT_USER_AGENT.to_string()
 }
 
+fn default_identification_headers() -> bool {
+    true
+}
+
+fn serialize_proxy_without_credentials<S: Serializer>(
+    proxy: &Option<Url>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    proxy
+        .as_ref()
+        .map(|proxy| {
+            let mut proxy = proxy.clone();
+            // Only fails for URLs without a host, which aren't valid proxy URLs
+            proxy.set_username("").ok();
+            proxy.set_password(None).ok();
+            proxy
+        })
+        .serialize(serializer)
+}
+
 fn default_node_sync_interval() -> Duration {
     NODE_SYNC_INTERVAL
 }
@@ -79,6 +132,10 @@ fn default_quorum_threshold() -> usize {
     DEFAULT_QUORUM_THRESHOLD
 }
 
+fn default_broadcast_node_count() -> usize {
+    DEFAULT_BROADCAST_NODE_COUNT
+}
+
 impl NodeManagerBuilder {
     pub(crate) fn new() -> Self {
         Default::default()
@@ -206,12 +263,50 @@ impl NodeManagerBuilder {
         self
     }
 
-    pub(crate) fn with_user_agent(mut self, user_agent: String) -> Self {
-        self.user_agent = user_agent;
+    pub(crate) fn with_broadcast_node_count(mut self, broadcast_node_count: usize) -> Self {
+        self.broadcast_node_count = broadcast_node_count;
+        self
+    }
+
+    pub(crate) fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    pub(crate) fn with_identification_headers(mut self, identification_headers: bool) -> Self {
+        self.identification_headers = identification_headers;
+        self
+    }
+
+    pub(crate) fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub(crate) fn with_connection_pool(mut self, connection_pool: ConnectionPoolOptions) -> Self {
+        self.connection_pool = connection_pool;
+        self
+    }
+
+    pub(crate) fn with_proxy(mut self, url: &str) -> Result<Self> {
+        self.proxy = Some(validate_proxy_url(Url::parse(url)?)?);
+        Ok(self)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn with_dns_resolver(mut self, dns_resolver: Arc<dyn DnsResolver>) -> Self {
+        self.dns_resolver = Some(SharedDnsResolver(dns_resolver));
         self
     }
 
     pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> NodeManager {
+        let http_client = HttpClient::new(self.user_agent, self.identification_headers)
+            .with_retry_policy(self.retry_policy)
+            .with_connection_pool(self.connection_pool)
+            .with_proxy(self.proxy);
+        #[cfg(not(target_family = "wasm"))]
+        let http_client = http_client.with_dns_resolver(self.dns_resolver);
+
         NodeManager {
             primary_node: self.primary_node.map(Into::into),
             primary_pow_node: self.primary_pow_node.map(Into::into),
@@ -220,10 +315,12 @@ impl NodeManagerBuilder {
             ignore_node_health: self.ignore_node_health,
             node_sync_interval: self.node_sync_interval,
             healthy_nodes: RwLock::new(healthy_nodes),
+            node_health: RwLock::new(HashMap::new()),
             quorum: self.quorum,
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
-            http_client: HttpClient::new(self.user_agent),
+            broadcast_node_count: self.broadcast_node_count,
+            http_client,
         }
     }
 }
@@ -240,7 +337,14 @@ impl Default for NodeManagerBuilder {
             quorum: false,
             min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
             quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
+            broadcast_node_count: DEFAULT_BROADCAST_NODE_COUNT,
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            identification_headers: true,
+            retry_policy: RetryPolicy::default(),
+            connection_pool: ConnectionPoolOptions::default(),
+            proxy: None,
+            #[cfg(not(target_family = "wasm"))]
+            dns_resolver: None,
         }
     }
 }
@@ -253,6 +357,14 @@ pub fn validate_url(url: Url) -> Result<Url> {
     Ok(url)
 }
 
+/// Validates if the proxy url starts with http, https, socks5 or socks5h
+pub fn validate_proxy_url(url: Url) -> Result<Url> {
+    if !matches!(url.scheme(), "http" | "https" | "socks5" | "socks5h") {
+        return Err(Error::UrlValidation(format!("invalid proxy scheme: {}", url.scheme())));
+    }
+    Ok(url)
+}
+
 impl From<&NodeManager> for NodeManagerBuilder {
     fn from(value: &NodeManager) -> Self {
         Self {
@@ -265,7 +377,14 @@ impl From<&NodeManager> for NodeManagerBuilder {
             quorum: value.quorum,
             min_quorum_size: value.min_quorum_size,
             quorum_threshold: value.quorum_threshold,
+            broadcast_node_count: value.broadcast_node_count,
             user_agent: value.http_client.user_agent.clone(),
+            identification_headers: value.http_client.identification_headers,
+            retry_policy: value.http_client.retry_policy.clone(),
+            connection_pool: value.http_client.connection_pool.clone(),
+            proxy: value.http_client.proxy.clone(),
+            #[cfg(not(target_family = "wasm"))]
+            dns_resolver: value.http_client.dns_resolver.clone(),
         }
     }
 }
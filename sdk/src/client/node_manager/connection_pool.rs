@@ -0,0 +1,64 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::constants::{
+    DEFAULT_HTTP2_KEEP_ALIVE_INTERVAL, DEFAULT_POOL_IDLE_TIMEOUT, DEFAULT_POOL_MAX_IDLE_PER_HOST, DEFAULT_TCP_KEEPALIVE,
+};
+
+/// How connections to nodes are kept open and reused. All requests of a client, including health checks and node
+/// syncing, share one connection pool. HTTP/2 is negotiated with nodes that support it, so concurrent requests to a
+/// node are multiplexed over a single connection. The options are ignored on wasm, where the browser manages the
+/// connections.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConnectionPoolOptions {
+    /// The maximum amount of idle connections that are kept open per node.
+    pub max_idle_per_host: usize,
+    /// How long an idle connection is kept open.
+    pub idle_timeout: Duration,
+    /// The interval of TCP keep-alive probes, none are sent if `None`.
+    pub tcp_keepalive: Option<Duration>,
+    /// The interval of HTTP/2 pings that keep idle connections alive, none are sent if `None`.
+    pub http2_keep_alive_interval: Option<Duration>,
+}
+
+impl Default for ConnectionPoolOptions {
+    fn default() -> Self {
+        Self {
+            max_idle_per_host: DEFAULT_POOL_MAX_IDLE_PER_HOST,
+            idle_timeout: DEFAULT_POOL_IDLE_TIMEOUT,
+            tcp_keepalive: Some(DEFAULT_TCP_KEEPALIVE),
+            http2_keep_alive_interval: Some(DEFAULT_HTTP2_KEEP_ALIVE_INTERVAL),
+        }
+    }
+}
+
+impl ConnectionPoolOptions {
+    /// Sets the maximum amount of idle connections that are kept open per node.
+    pub fn with_max_idle_per_host(mut self, max_idle_per_host: usize) -> Self {
+        self.max_idle_per_host = max_idle_per_host;
+        self
+    }
+
+    /// Sets how long an idle connection is kept open.
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Sets the interval of TCP keep-alive probes, `None` disables them.
+    pub fn with_tcp_keepalive(mut self, tcp_keepalive: impl Into<Option<Duration>>) -> Self {
+        self.tcp_keepalive = tcp_keepalive.into();
+        self
+    }
+
+    /// Sets the interval of HTTP/2 pings that keep idle connections alive, `None` disables them.
+    pub fn with_http2_keep_alive_interval(mut self, http2_keep_alive_interval: impl Into<Option<Duration>>) -> Self {
+        self.http2_keep_alive_interval = http2_keep_alive_interval.into();
+        self
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::node::Node;
+use crate::client::constants::{
+    NODE_HEALTH_ERROR_PENALTY_MS, NODE_HEALTH_MILESTONE_LAG_PENALTY_MS, NODE_HEALTH_SMOOTHING,
+};
+
+/// The health of a node, measured by the node syncing.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeHealth {
+    /// The moving average of the latency of the node info requests, `None` if the node never responded.
+    pub latency: Option<Duration>,
+    /// The moving average of the rate of failed node info requests, between 0 and 1.
+    pub error_rate: f64,
+    /// The amount of milestones the confirmed milestone of the node is behind the most recent confirmed milestone of
+    /// the synced nodes, `None` if the node didn't respond to the last sync.
+    pub milestone_lag: Option<u32>,
+    /// The confirmed milestone index of the node at the last sync it responded to.
+    pub confirmed_milestone_index: Option<u32>,
+}
+
+impl NodeHealth {
+    /// Returns the score of the node, lower is better. It's the latency in milliseconds, plus penalties for failed
+    /// requests and for lagging behind the other nodes. Nodes that never responded have an infinite score.
+    pub fn score(&self) -> f64 {
+        self.latency.map_or(f64::INFINITY, |latency| {
+            latency.as_secs_f64() * 1000.0
+                + self.error_rate * NODE_HEALTH_ERROR_PENALTY_MS
+                + self.milestone_lag.map_or(NODE_HEALTH_ERROR_PENALTY_MS, |lag| {
+                    lag as f64 * NODE_HEALTH_MILESTONE_LAG_PENALTY_MS
+                })
+        })
+    }
+
+    pub(crate) fn record_success(&mut self, latency: Duration, confirmed_milestone_index: u32) {
+        self.latency = Some(match self.latency {
+            Some(average) => average.mul_f64(1.0 - NODE_HEALTH_SMOOTHING) + latency.mul_f64(NODE_HEALTH_SMOOTHING),
+            None => latency,
+        });
+        self.error_rate *= 1.0 - NODE_HEALTH_SMOOTHING;
+        self.confirmed_milestone_index = Some(confirmed_milestone_index);
+    }
+
+    pub(crate) fn record_error(&mut self) {
+        self.error_rate = self.error_rate * (1.0 - NODE_HEALTH_SMOOTHING) + NODE_HEALTH_SMOOTHING;
+        self.confirmed_milestone_index = None;
+        self.milestone_lag = None;
+    }
+}
+
+/// The health of a node, returned by `Client::node_health_report()`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeHealthReport {
+    /// The node.
+    pub node: Node,
+    /// The health of the node.
+    pub health: NodeHealth,
+    /// The score of the node, lower is better.
+    pub score: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score() {
+        let mut fast = NodeHealth::default();
+        let mut slow = NodeHealth::default();
+        assert_eq!(fast.score(), f64::INFINITY);
+
+        fast.record_success(Duration::from_millis(50), 10);
+        fast.milestone_lag = Some(0);
+        slow.record_success(Duration::from_millis(500), 10);
+        slow.milestone_lag = Some(0);
+        assert!(fast.score() < slow.score());
+
+        // Lagging behind is worse than being slow
+        fast.milestone_lag = Some(2);
+        assert!(fast.score() > slow.score());
+
+        // Failed requests make the node worse, until it recovers
+        slow.record_error();
+        assert!(slow.milestone_lag.is_none());
+        let failed = slow.score();
+        slow.record_success(Duration::from_millis(500), 10);
+        slow.milestone_lag = Some(0);
+        assert!(slow.score() < failed);
+        assert!(slow.error_rate > 0.0);
+    }
+}
@@ -8,11 +8,16 @@ use std::time::Duration;
 use reqwest::RequestBuilder;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use url::Url;
 
+#[cfg(not(target_family = "wasm"))]
+use crate::client::node_manager::resolver::SharedDnsResolver;
 use crate::client::{
+    constants::{CLIENT_NAME, CLIENT_NAME_HEADER, CLIENT_VERSION, CLIENT_VERSION_HEADER, DEFAULT_CONNECT_TIMEOUT},
     node_api::error::{Error, Result},
-    node_manager::node::Node,
+    node_manager::{connection_pool::ConnectionPoolOptions, node::Node, retry_policy::RetryPolicy},
 };
+
 pub(crate) struct Response(reqwest::Response);
 
 impl Response {
@@ -24,7 +29,6 @@ impl Response {
         self.0.json().await.map_err(Into::into)
     }
 
-    #[cfg(not(target_family = "wasm"))]
     pub(crate) async fn into_text(self) -> Result<String> {
         self.0.text().await.map_err(Into::into)
     }
@@ -34,18 +38,97 @@ impl Response {
     }
 }
 
+// Clones share the connection pool of the underlying client
 #[derive(Clone)]
 pub(crate) struct HttpClient {
     client: reqwest::Client,
     pub(crate) user_agent: String,
+    pub(crate) identification_headers: bool,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) connection_pool: ConnectionPoolOptions,
+    pub(crate) proxy: Option<Url>,
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) dns_resolver: Option<SharedDnsResolver>,
 }
 
 impl HttpClient {
-    pub(crate) fn new(user_agent: String) -> Self {
+    pub(crate) fn new(user_agent: String, identification_headers: bool) -> Self {
+        let connection_pool = ConnectionPoolOptions::default();
+
         Self {
+            #[cfg(not(target_family = "wasm"))]
+            client: Self::build_client(None, &connection_pool, None),
+            #[cfg(target_family = "wasm")]
             client: reqwest::Client::new(),
             user_agent,
+            identification_headers,
+            retry_policy: RetryPolicy::default(),
+            connection_pool,
+            proxy: None,
+            #[cfg(not(target_family = "wasm"))]
+            dns_resolver: None,
+        }
+    }
+
+    /// Repeats failed requests according to the given policy.
+    pub(crate) fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Keeps connections open and reuses them according to the given options.
+    pub(crate) fn with_connection_pool(mut self, connection_pool: ConnectionPoolOptions) -> Self {
+        #[cfg(not(target_family = "wasm"))]
+        {
+            self.client = Self::build_client(self.dns_resolver.clone(), &connection_pool, self.proxy.as_ref());
+        }
+        self.connection_pool = connection_pool;
+        self
+    }
+
+    /// Sends all requests through the given proxy, the URL was validated by the builder.
+    pub(crate) fn with_proxy(mut self, proxy: Option<Url>) -> Self {
+        #[cfg(not(target_family = "wasm"))]
+        {
+            self.client = Self::build_client(self.dns_resolver.clone(), &self.connection_pool, proxy.as_ref());
+        }
+        self.proxy = proxy;
+        self
+    }
+
+    /// Resolves the host names of node URLs with the given resolver instead of the system resolver.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn with_dns_resolver(mut self, dns_resolver: Option<SharedDnsResolver>) -> Self {
+        self.client = Self::build_client(dns_resolver.clone(), &self.connection_pool, self.proxy.as_ref());
+        self.dns_resolver = dns_resolver;
+        self
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn build_client(
+        dns_resolver: Option<SharedDnsResolver>,
+        connection_pool: &ConnectionPoolOptions,
+        proxy: Option<&Url>,
+    ) -> reqwest::Client {
+        let mut builder = reqwest::Client::builder();
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy.as_str()).expect("the proxy URL is validated"));
         }
+        if let Some(dns_resolver) = dns_resolver {
+            builder = builder.dns_resolver(std::sync::Arc::new(dns_resolver));
+        }
+
+        // The connector already races IPv6 and IPv4 addresses (happy eyeballs, the other family is tried after
+        // 300 ms), the connect timeout makes an unreachable node fail fast instead of taking the whole request timeout
+        builder
+            .connect_timeout(DEFAULT_CONNECT_TIMEOUT)
+            .pool_max_idle_per_host(connection_pool.max_idle_per_host)
+            .pool_idle_timeout(connection_pool.idle_timeout)
+            .tcp_keepalive(connection_pool.tcp_keepalive)
+            .http2_keep_alive_interval(connection_pool.http2_keep_alive_interval)
+            .http2_keep_alive_while_idle(connection_pool.http2_keep_alive_interval.is_some())
+            .build()
+            .expect("failed to build the HTTP client")
     }
 
     async fn parse_response(response: reqwest::Response, url: &url::Url) -> Result<Response> {
@@ -73,8 +156,55 @@ impl HttpClient {
         }
     }
 
-    fn build_request(&self, request_builder: RequestBuilder, node: &Node, _timeout: Duration) -> RequestBuilder {
-        let mut request_builder = request_builder.header(reqwest::header::USER_AGENT, &self.user_agent);
+    // Sends the request and repeats it while the retry policy of its route allows it
+    async fn send(&self, request_builder: RequestBuilder, url: &url::Url) -> Result<Response> {
+        let retry_policy = self.retry_policy.for_path(url.path());
+        let mut retry = 0;
+
+        loop {
+            // Request bodies are never streams, so the request can always be cloned
+            let request_builder = request_builder.try_clone().expect("request body is not a stream");
+            let start_time = instant::Instant::now();
+            // Connection errors are passed to the retry policy like error responses
+            let result = match request_builder.send().await {
+                Ok(resp) => {
+                    log::debug!(
+                        "{:?} ms for {} {}",
+                        start_time.elapsed().as_millis(),
+                        resp.status(),
+                        url
+                    );
+                    Self::parse_response(resp, url).await
+                }
+                Err(err) => Err(err.into()),
+            };
+
+            match result {
+                Err(err) => match retry_policy.backoff(retry, &err) {
+                    Some(backoff) => {
+                        log::debug!("retrying {url} in {backoff:?} after: {err}");
+                        #[cfg(target_family = "wasm")]
+                        gloo_timers::future::TimeoutFuture::new(backoff.as_millis() as u32).await;
+                        #[cfg(not(target_family = "wasm"))]
+                        tokio::time::sleep(backoff).await;
+                        retry += 1;
+                    }
+                    None => return Err(err),
+                },
+                resp => return resp,
+            }
+        }
+    }
+
+    fn build_request(&self, mut request_builder: RequestBuilder, node: &Node, _timeout: Duration) -> RequestBuilder {
+        if !self.user_agent.is_empty() {
+            request_builder = request_builder.header(reqwest::header::USER_AGENT, &self.user_agent);
+        }
+        if self.identification_headers {
+            request_builder = request_builder
+                .header(CLIENT_NAME_HEADER, CLIENT_NAME)
+                .header(CLIENT_VERSION_HEADER, CLIENT_VERSION);
+        }
 
         if let Some(node_auth) = &node.auth {
             if let Some(jwt) = &node_auth.jwt {
@@ -91,15 +221,7 @@ impl HttpClient {
     pub(crate) async fn get(&self, node: Node, timeout: Duration) -> Result<Response> {
         let mut request_builder = self.client.get(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
-        let start_time = instant::Instant::now();
-        let resp = request_builder.send().await?;
-        log::debug!(
-            "GET: {:?} ms for {} {}",
-            start_time.elapsed().as_millis(),
-            resp.status(),
-            node.url
-        );
-        Self::parse_response(resp, &node.url).await
+        self.send(request_builder, &node.url).await
     }
 
     // Get with header: "accept", "application/vnd.iota.serializer-v1"
@@ -107,20 +229,36 @@ impl HttpClient {
         let mut request_builder = self.client.get(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         request_builder = request_builder.header("accept", "application/vnd.iota.serializer-v1");
-        let resp = request_builder.send().await?;
-        Self::parse_response(resp, &node.url).await
+        self.send(request_builder, &node.url).await
     }
 
     pub(crate) async fn post_json(&self, node: Node, timeout: Duration, json: Value) -> Result<Response> {
         let mut request_builder = self.client.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
-        Self::parse_response(request_builder.json(&json).send().await?, &node.url).await
+        self.send(request_builder.json(&json), &node.url).await
     }
 
     pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: &[u8]) -> Result<Response> {
         let mut request_builder = self.client.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         request_builder = request_builder.header("Content-Type", "application/vnd.iota.serializer-v1");
-        Self::parse_response(request_builder.body(body.to_vec()).send().await?, &node.url).await
+        self.send(request_builder.body(body.to_vec()), &node.url).await
+    }
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use crate::client::constants::DEFAULT_API_TIMEOUT;
+
+    #[tokio::test]
+    async fn connect_timeout() {
+        let http_client = HttpClient::new(String::new(), false);
+        // Not routable, so connecting doesn't fail but hangs
+        let node = Node::from(Url::parse("http://10.255.255.1:14265/api/core/v2/info").unwrap());
+
+        let start_time = instant::Instant::now();
+        assert!(http_client.get(node, DEFAULT_API_TIMEOUT).await.is_err());
+        assert!(start_time.elapsed() < DEFAULT_API_TIMEOUT);
     }
 }
@@ -4,9 +4,18 @@
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
 pub mod builder;
+/// Reusing connections to nodes
+pub mod connection_pool;
+/// Scoring the health of nodes
+pub mod health;
 pub(crate) mod http_client;
 /// Structs for nodes
 pub mod node;
+/// Custom DNS resolution for node URLs
+#[cfg(not(target_family = "wasm"))]
+pub mod resolver;
+/// Retrying failed requests
+pub mod retry_policy;
 pub(crate) mod syncing;
 
 use std::{
@@ -19,18 +28,22 @@ use std::{
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
-use self::{http_client::HttpClient, node::Node};
+use self::{health::NodeHealth, http_client::HttpClient, node::Node};
 use super::ClientInner;
 #[cfg(not(target_family = "wasm"))]
 use crate::client::request_pool::RateLimitExt;
 use crate::{
     client::{
         error::{Error, Result},
+        node_api::core::routes::{NodeInfoWrapper, INFO_PATH},
         node_manager::builder::NodeManagerBuilder,
     },
     types::api::core::response::InfoResponse,
 };
 
+// Indexer queries are about the current ledger state, so quorum is also used for them
+const INDEXER_PATH_PREFIX: &str = "api/indexer/";
+
 // The node manager takes care of selecting node(s) for requests until a result is returned or if quorum is enabled it
 // will send the requests for some endpoints to multiple nodes and compares the results.
 pub struct NodeManager {
@@ -41,9 +54,11 @@ pub struct NodeManager {
     pub(crate) ignore_node_health: bool,
     node_sync_interval: Duration,
     pub(crate) healthy_nodes: RwLock<HashMap<Node, InfoResponse>>,
+    pub(crate) node_health: RwLock<HashMap<Node, NodeHealth>>,
     quorum: bool,
     min_quorum_size: usize,
     quorum_threshold: usize,
+    pub(crate) broadcast_node_count: usize,
     pub(crate) http_client: HttpClient,
 }
 
@@ -57,9 +72,11 @@ impl Debug for NodeManager {
         d.field("ignore_node_health", &self.ignore_node_health);
         d.field("node_sync_interval", &self.node_sync_interval);
         d.field("healthy_nodes", &self.healthy_nodes);
+        d.field("node_health", &self.node_health);
         d.field("quorum", &self.quorum);
         d.field("min_quorum_size", &self.min_quorum_size);
-        d.field("quorum_threshold", &self.quorum_threshold).finish()
+        d.field("quorum_threshold", &self.quorum_threshold);
+        d.field("broadcast_node_count", &self.broadcast_node_count).finish()
     }
 }
 
@@ -214,11 +231,16 @@ impl NodeManager {
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<T> {
-        let mut result: HashMap<String, usize> = HashMap::new();
+        // Responses by their quorum key, with the amount of nodes that returned them
+        let mut result: HashMap<String, (usize, String)> = HashMap::new();
+        let is_info = path == INFO_PATH;
         // primary_pow_node should only be used for post request with remote PoW
         // Get node urls and set path
         let nodes = self.get_nodes(path, query, false, prefer_permanode)?;
-        if self.quorum && need_quorum && nodes.len() < self.min_quorum_size {
+        // With a query we ignore quorum because the nodes can store a different amount of history, except for indexer
+        // queries, which are about the current ledger state
+        let quorum = self.quorum && need_quorum && (query.is_none() || path.starts_with(INDEXER_PATH_PREFIX));
+        if quorum && nodes.len() < self.min_quorum_size {
             return Err(Error::QuorumPoolSizeError {
                 available_nodes: nodes.len(),
                 minimum_threshold: self.min_quorum_size,
@@ -233,30 +255,39 @@ impl NodeManager {
         let wasm = true;
         #[cfg(not(target_family = "wasm"))]
         let wasm = false;
-        if !wasm && self.quorum && need_quorum && query.is_none() {
+        if !wasm && quorum {
             #[cfg(not(target_family = "wasm"))]
             {
                 let mut tasks = Vec::new();
-                for (index, node) in nodes.into_iter().enumerate() {
-                    if index < self.min_quorum_size {
-                        let client_ = self.http_client.clone();
-                        tasks.push(async move { tokio::spawn(async move { client_.get(node, timeout).await }).await });
-                    }
+                for node in nodes.into_iter().take(self.min_quorum_size) {
+                    let client_ = self.http_client.clone();
+                    tasks.push(async move {
+                        tokio::spawn(async move {
+                            let res_text = match client_.get(node.clone(), timeout).await {
+                                Ok(res) => res.into_text().await,
+                                Err(err) => Err(err),
+                            };
+                            (node, res_text)
+                        })
+                        .await
+                    });
                 }
-                for res in futures::future::try_join_all(tasks).await? {
-                    match res {
-                        Ok(res) => (res.into_text().await).map_or_else(
-                            |_| {
-                                log::warn!("couldn't convert node response to text");
-                            },
-                            |res_text| {
-                                let counters = result.entry(res_text).or_insert(0);
-                                *counters += 1;
-                                result_counter += 1;
-                            },
-                        ),
+                for (node, res_text) in futures::future::try_join_all(tasks).await? {
+                    let res_text = res_text.map_err(Error::from).and_then(|res_text| {
+                        if is_info {
+                            node_info_text(serde_json::from_str(&res_text)?, &node)
+                        } else {
+                            Ok(res_text)
+                        }
+                    });
+                    match res_text.and_then(|res_text| Ok((quorum_key(is_info, &res_text)?, res_text))) {
+                        Ok((key, res_text)) => {
+                            result.entry(key).or_insert((0, res_text)).0 += 1;
+                            result_counter += 1;
+                        }
                         Err(err) => {
-                            error.replace(err.into());
+                            log::warn!("quorum request to {} failed: {err}", node.url);
+                            error.replace(err);
                         }
                     }
                 }
@@ -264,41 +295,29 @@ impl NodeManager {
         } else {
             // Send requests
             for node in nodes {
-                match self.http_client.get(node.clone(), timeout).await {
-                    Ok(res) => {
-                        // Handle node_info extra because we also want to return the url
-                        if path == crate::client::node_api::core::routes::INFO_PATH {
-                            let node_info: InfoResponse = res.into_json().await?;
-                            let wrapper = crate::client::node_api::core::routes::NodeInfoWrapper {
-                                node_info,
-                                url: format!("{}://{}", node.url.scheme(), node.url.host_str().unwrap_or("")),
-                            };
-                            let serde_res = serde_json::to_string(&wrapper)?;
-                            return Ok(serde_json::from_str(&serde_res)?);
-                        }
-
-                        match res.into_json::<T>().await {
-                            Ok(result_data) => {
-                                let counters = result.entry(serde_json::to_string(&result_data)?).or_insert(0);
-                                *counters += 1;
-                                result_counter += 1;
-                                // Without quorum it's enough if we got one response
-                                if !self.quorum
-                                    || result_counter >= self.min_quorum_size
-                                    || !need_quorum
-                                    // with query we ignore quorum because the nodes can store a different amount of history
-                                    || query.is_some()
-                                {
-                                    break;
-                                }
-                            }
-                            Err(e) => {
-                                error.replace(e.into());
-                            }
+                let res_text = match self.http_client.get(node.clone(), timeout).await {
+                    // Handle node_info extra because we also want to return the url
+                    Ok(res) if is_info => match res.into_json::<InfoResponse>().await {
+                        Ok(node_info) => node_info_text(node_info, &node),
+                        Err(err) => Err(err.into()),
+                    },
+                    Ok(res) => match res.into_json::<T>().await {
+                        Ok(result_data) => serde_json::to_string(&result_data).map_err(Into::into),
+                        Err(err) => Err(err.into()),
+                    },
+                    Err(err) => Err(err.into()),
+                };
+                match res_text.and_then(|res_text| Ok((quorum_key(is_info, &res_text)?, res_text))) {
+                    Ok((key, res_text)) => {
+                        result.entry(key).or_insert((0, res_text)).0 += 1;
+                        result_counter += 1;
+                        // Without quorum it's enough if we got one response
+                        if !quorum || result_counter >= self.min_quorum_size {
+                            break;
                         }
                     }
                     Err(err) => {
-                        error.replace(err.into());
+                        error.replace(err);
                     }
                 }
             }
@@ -306,19 +325,17 @@ impl NodeManager {
 
         // Safe unwrap, there are nodes because we throw on empty nodepool.
         // Each node will throw an error or return Ok()
-        let res = result.into_iter().max_by_key(|v| v.1).ok_or_else(|| error.unwrap())?;
+        let (quorum_size, res_text) = result
+            .into_values()
+            .max_by_key(|(quorum_size, _)| *quorum_size)
+            .ok_or_else(|| error.unwrap())?;
 
         // Return if quorum is false or check if quorum was reached
-        if !self.quorum
-            || res.1 as f64 >= self.min_quorum_size as f64 * (self.quorum_threshold as f64 / 100.0)
-            || !need_quorum
-            // with query we ignore quorum because the nodes can store a different amount of history
-            || query.is_some()
-        {
-            Ok(serde_json::from_str(&res.0)?)
+        if !quorum || quorum_size as f64 >= self.min_quorum_size as f64 * (self.quorum_threshold as f64 / 100.0) {
+            Ok(serde_json::from_str(&res_text)?)
         } else {
             Err(Error::QuorumThresholdError {
-                quorum_size: res.1,
+                quorum_size,
                 minimum_threshold: self.min_quorum_size,
             })
         }
@@ -383,6 +400,59 @@ impl NodeManager {
         Err(error.unwrap())
     }
 
+    // Posts the body to `broadcast_node_count` nodes at the same time and returns the result of every node. Only used
+    // for blocks with local PoW, as nodes doing remote PoW would each create a different block.
+    pub(crate) async fn broadcast_request_bytes<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        timeout: Duration,
+        body: &[u8],
+    ) -> Result<Vec<(Node, Result<T>)>> {
+        let nodes = self.get_nodes(path, None, false, false)?;
+        let requests = nodes
+            .into_iter()
+            .take(self.broadcast_node_count)
+            .map(|node| async move {
+                let result = match self.http_client.post_bytes(node.clone(), timeout, body).await {
+                    Ok(res) => res.into_json::<T>().await.map_err(Into::into),
+                    Err(e) => Err(Error::Node(e)),
+                };
+                (node, result)
+            });
+
+        Ok(futures::future::join_all(requests).await)
+    }
+
+    // Posts the body to `broadcast_node_count` nodes at the same time and returns the first successful response, or the
+    // last error if no node succeeded. The requests to the other nodes are dropped once a node succeeded, the block is
+    // gossiped by that node anyway.
+    pub(crate) async fn broadcast_request_bytes_first<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        timeout: Duration,
+        body: &[u8],
+    ) -> Result<T> {
+        let nodes = self.get_nodes(path, None, false, false)?;
+        let requests = nodes.into_iter().take(self.broadcast_node_count).map(|node| {
+            Box::pin(async move {
+                let url = node.url.clone();
+                let result = match self.http_client.post_bytes(node, timeout, body).await {
+                    Ok(res) => res.into_json::<T>().await.map_err(Into::into),
+                    Err(e) => Err(Error::Node(e)),
+                };
+                if let Err(e) = &result {
+                    log::warn!("posting block to {url} failed: {e}");
+                }
+                result
+            })
+        });
+
+        // Safe, select_ok panics on no futures, but there are nodes because we throw on empty nodepool
+        futures::future::select_ok(requests)
+            .await
+            .map(|(response, _remaining)| response)
+    }
+
     pub(crate) async fn post_request_json<T: DeserializeOwned>(
         &self,
         path: &str,
@@ -412,3 +482,58 @@ impl NodeManager {
         Err(error.unwrap())
     }
 }
+
+// Wraps the node info with the url of the node that returned it
+fn node_info_text(node_info: InfoResponse, node: &Node) -> Result<String> {
+    let wrapper = NodeInfoWrapper {
+        node_info,
+        url: format!("{}://{}", node.url.scheme(), node.url.host_str().unwrap_or("")),
+    };
+    Ok(serde_json::to_string(&wrapper)?)
+}
+
+// Returns the part of a response that needs to be equal on the nodes to count towards the quorum. The ledger index
+// is left out, as it can differ by a milestone between synced nodes, and of the node info only the protocol
+// parameters and the base token are compared.
+fn quorum_key(is_info: bool, res_text: &str) -> Result<String> {
+    let mut value: Value = serde_json::from_str(res_text)?;
+
+    if is_info {
+        let node_info = &value["nodeInfo"];
+        value = serde_json::json!({
+            "protocol": node_info["protocol"],
+            "pendingProtocolParameters": node_info["pendingProtocolParameters"],
+            "baseToken": node_info["baseToken"],
+        });
+    } else {
+        remove_ledger_index(&mut value);
+    }
+
+    Ok(value.to_string())
+}
+
+fn remove_ledger_index(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("ledgerIndex");
+            map.values_mut().for_each(remove_ledger_index);
+        }
+        Value::Array(values) => values.iter_mut().for_each(remove_ledger_index),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quorum_key_ignores_ledger_index() {
+        let first = r#"{"ledgerIndex":10,"cursor":null,"items":["0x01"],"metadata":{"ledgerIndex":10}}"#;
+        let second = r#"{"ledgerIndex":11,"cursor":null,"items":["0x01"],"metadata":{"ledgerIndex":11}}"#;
+        let other = r#"{"ledgerIndex":11,"cursor":null,"items":["0x02"],"metadata":{"ledgerIndex":11}}"#;
+
+        assert_eq!(quorum_key(false, first).unwrap(), quorum_key(false, second).unwrap());
+        assert_ne!(quorum_key(false, first).unwrap(), quorum_key(false, other).unwrap());
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolution of the host names of node URLs
+
+use std::{
+    fmt::Debug,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+/// A custom DNS resolver for the host names of node URLs, e.g. to use DNS over HTTPS.
+#[async_trait]
+pub trait DnsResolver: Debug + Send + Sync {
+    /// Resolves a host name to its IP addresses, in order of preference.
+    async fn resolve(&self, host: &str) -> Result<Vec<IpAddr>, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// A shareable [`DnsResolver`], resolvers are equal if they are the same instance.
+#[derive(Clone, Debug)]
+pub(crate) struct SharedDnsResolver(pub(crate) Arc<dyn DnsResolver>);
+
+impl PartialEq for SharedDnsResolver {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SharedDnsResolver {}
+
+impl Resolve for SharedDnsResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let dns_resolver = self.0.clone();
+
+        Box::pin(async move {
+            let addresses = dns_resolver.resolve(name.as_str()).await?;
+            log::debug!("resolved {} to {:?}", name.as_str(), addresses);
+
+            // The HTTP connector tries the addresses in this order, racing the address families (happy eyeballs)
+            let addresses: Addrs = Box::new(addresses.into_iter().map(|ip| SocketAddr::new(ip, 0)));
+            Ok(addresses)
+        })
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{
+    constants::{DEFAULT_RETRY_INITIAL_BACKOFF, DEFAULT_RETRY_MAX_BACKOFF, DEFAULT_RETRY_ON_STATUS},
+    node_api::error::Error,
+};
+
+/// How often and when a request to a node is repeated after it failed, before the next node is tried or the error is
+/// returned. Retries wait with an exponential backoff, starting at `initial_backoff` and doubling up to `max_backoff`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RetryPolicy {
+    /// The amount of times a failed request is repeated, 0 disables retries.
+    pub max_retries: u32,
+    /// The time to wait before the first retry.
+    pub initial_backoff: Duration,
+    /// The longest time to wait between retries.
+    pub max_backoff: Duration,
+    /// The HTTP status codes of responses that are retried.
+    pub retry_on_status: Vec<u16>,
+    /// Whether requests that failed to connect to the node or timed out are retried.
+    pub retry_on_connection_error: bool,
+    /// Policies for routes starting with the given path, e.g. `api/core/v2/blocks`, which replace this one for
+    /// requests to them. The longest matching path is used and overrides of overrides are ignored.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub route_overrides: BTreeMap<String, RetryPolicy>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: DEFAULT_RETRY_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_RETRY_MAX_BACKOFF,
+            retry_on_status: DEFAULT_RETRY_ON_STATUS.to_vec(),
+            retry_on_connection_error: true,
+            route_overrides: BTreeMap::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a [`RetryPolicy`] that repeats failed requests up to `max_retries` times.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the backoff before the first retry and the longest backoff between retries.
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the HTTP status codes of responses that are retried.
+    pub fn with_retry_on_status(mut self, retry_on_status: impl Into<Vec<u16>>) -> Self {
+        self.retry_on_status = retry_on_status.into();
+        self
+    }
+
+    /// Sets whether requests that failed to connect to the node or timed out are retried.
+    pub fn with_retry_on_connection_error(mut self, retry_on_connection_error: bool) -> Self {
+        self.retry_on_connection_error = retry_on_connection_error;
+        self
+    }
+
+    /// Uses another policy for routes starting with the given path, e.g. `api/core/v2/blocks`.
+    pub fn with_route_override(mut self, path: impl Into<String>, policy: Self) -> Self {
+        self.route_overrides
+            .insert(path.into().trim_start_matches('/').to_string(), policy);
+        self
+    }
+
+    /// Returns the policy for a request to the given URL path.
+    pub(crate) fn for_path(&self, path: &str) -> &Self {
+        let path = path.trim_start_matches('/');
+
+        self.route_overrides
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self, |(_, policy)| policy)
+    }
+
+    /// Returns the time to wait before the retry with the given index, starting at 0, if the request should be
+    /// retried after the error.
+    pub(crate) fn backoff(&self, retry: u32, error: &Error) -> Option<Duration> {
+        let retryable = match error {
+            Error::ResponseError { code, .. } => self.retry_on_status.contains(code),
+            Error::Reqwest(error) => self.retry_on_connection_error && is_connection_error(error),
+            _ => false,
+        };
+
+        (retryable && retry < self.max_retries).then(|| {
+            self.initial_backoff
+                .saturating_mul(2u32.saturating_pow(retry))
+                .min(self.max_backoff)
+        })
+    }
+}
+
+// The request didn't reach the node or its response didn't arrive in time.
+fn is_connection_error(error: &reqwest::Error) -> bool {
+    #[cfg(not(target_family = "wasm"))]
+    if error.is_connect() {
+        return true;
+    }
+
+    error.is_timeout()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response_error(code: u16) -> Error {
+        Error::ResponseError {
+            code,
+            text: String::new(),
+            url: "http://localhost:14265/api/core/v2/info".to_string(),
+        }
+    }
+
+    #[test]
+    fn backoff() {
+        let policy = RetryPolicy::new(3).with_backoff(Duration::from_millis(100), Duration::from_millis(300));
+
+        assert_eq!(
+            policy.backoff(0, &response_error(503)),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.backoff(1, &response_error(502)),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.backoff(2, &response_error(503)),
+            Some(Duration::from_millis(300))
+        );
+        assert_eq!(policy.backoff(3, &response_error(503)), None);
+        assert_eq!(policy.backoff(0, &response_error(400)), None);
+        assert_eq!(policy.backoff(0, &Error::NotFound(String::new())), None);
+        assert_eq!(RetryPolicy::default().backoff(0, &response_error(503)), None);
+    }
+
+    #[tokio::test]
+    async fn connection_errors() {
+        // Nothing listens on port 1, so the connection is refused
+        let error = Error::Reqwest(reqwest::get("http://127.0.0.1:1").await.unwrap_err());
+        let policy = RetryPolicy::new(1).with_backoff(Duration::from_millis(100), Duration::from_millis(100));
+
+        assert_eq!(policy.backoff(0, &error), Some(Duration::from_millis(100)));
+        assert_eq!(policy.backoff(1, &error), None);
+        assert_eq!(
+            policy.clone().with_retry_on_connection_error(false).backoff(0, &error),
+            None
+        );
+        assert_eq!(RetryPolicy::default().backoff(0, &error), None);
+    }
+
+    #[test]
+    fn route_overrides() {
+        let policy = RetryPolicy::new(3)
+            .with_route_override("/api/indexer/", RetryPolicy::new(0))
+            .with_route_override("api/indexer/v1/outputs/foundry", RetryPolicy::new(1));
+
+        assert_eq!(policy.for_path("/api/core/v2/info").max_retries, 3);
+        assert_eq!(policy.for_path("/api/indexer/v1/outputs/basic").max_retries, 0);
+        assert_eq!(policy.for_path("/api/indexer/v1/outputs/foundry/0x08").max_retries, 1);
+    }
+}
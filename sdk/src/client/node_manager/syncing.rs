@@ -4,23 +4,57 @@
 #[cfg(not(target_family = "wasm"))]
 use {
     crate::types::api::core::response::InfoResponse,
-    std::{collections::HashSet, time::Duration},
+    std::{
+        collections::HashSet,
+        time::{Duration, Instant},
+    },
     tokio::time::sleep,
 };
 
-use super::{Node, NodeManager};
+use super::{
+    health::{NodeHealth, NodeHealthReport},
+    Node, NodeManager,
+};
 use crate::client::{Client, ClientInner, Error, Result};
 
 impl ClientInner {
-    /// Get a node candidate from the healthy node pool.
+    /// Get a node candidate from the healthy node pool, the one with the best health score if the nodes were synced.
     pub async fn get_node(&self) -> Result<Node> {
-        if let Some(primary_node) = &self.node_manager.read().await.primary_node {
+        let node_manager = self.node_manager.read().await;
+
+        if let Some(primary_node) = &node_manager.primary_node {
             return Ok(primary_node.clone());
         }
 
-        let pool = self.node_manager.read().await.nodes.clone();
+        let node_health = node_manager.node_health.read().map_err(|_| Error::PoisonError)?;
+        let score = |node: &Node| node_health.get(node).map_or(f64::INFINITY, NodeHealth::score);
+
+        node_manager
+            .nodes
+            .iter()
+            .min_by(|a, b| score(a).total_cmp(&score(b)))
+            .cloned()
+            .ok_or(Error::HealthyNodePoolEmpty)
+    }
+
+    /// Returns the health of the nodes measured by the node syncing, ordered by their score, best first.
+    pub async fn node_health_report(&self) -> Result<Vec<NodeHealthReport>> {
+        let node_manager = self.node_manager.read().await;
+        let mut report = node_manager
+            .node_health
+            .read()
+            .map_err(|_| Error::PoisonError)?
+            .iter()
+            .map(|(node, health)| NodeHealthReport {
+                node: node.clone(),
+                health: health.clone(),
+                score: health.score(),
+            })
+            .collect::<Vec<_>>();
+
+        report.sort_by(|a, b| a.score.total_cmp(&b.score));
 
-        pool.into_iter().next().ok_or(Error::HealthyNodePoolEmpty)
+        Ok(report)
     }
 
     /// returns the unhealthy nodes.
@@ -67,12 +101,27 @@ impl ClientInner {
         log::debug!("sync_nodes");
         let mut healthy_nodes = HashMap::new();
         let mut network_nodes: HashMap<String, Vec<(InfoResponse, Node)>> = HashMap::new();
+        // The latency and confirmed milestone index of the nodes that responded
+        let mut measurements: HashMap<Node, (Duration, u32)> = HashMap::new();
+        // Reuse the connections of the client, but don't retry requests of unhealthy nodes
+        let http_client = self
+            .node_manager
+            .read()
+            .await
+            .http_client
+            .clone()
+            .with_retry_policy(Default::default());
+        let network_info = self.network_info.read().await.clone();
 
         for node in nodes {
             // Put the healthy node url into the network_nodes
-            match crate::client::Client::get_node_info(node.url.as_ref(), node.auth.clone()).await {
+            let start = Instant::now();
+            match Client::get_node_info_with_http_client(&http_client, node.url.as_ref(), node.auth.clone()).await {
                 Ok(info) => {
-                    if info.status.is_healthy || ignore_node_health {
+                    measurements.insert(node.clone(), (start.elapsed(), info.status.confirmed_milestone.index));
+                    if let Err(err) = network_info.check_network_profile(info.protocol.bech32_hrp()) {
+                        log::warn!("{} is rejected: {err}", node.url);
+                    } else if info.status.is_healthy || ignore_node_health {
                         match network_nodes.get_mut(info.protocol.network_name()) {
                             Some(network_node_entry) => {
                                 network_node_entry.push((info, node.clone()));
@@ -102,11 +151,11 @@ impl ClientInner {
         }
 
         if let Some(nodes) = network_nodes.get(most_nodes.0) {
-            if let Some((info, _node_url)) = nodes.first() {
+            if let Some((info, node)) = nodes.first() {
                 let mut network_info = self.network_info.write().await;
 
                 network_info.latest_milestone_timestamp = info.status.latest_milestone.timestamp;
-                network_info.protocol_parameters = info.protocol.clone();
+                network_info.update_protocol_parameters(&info.protocol, node.url.as_str())?;
             }
 
             for (info, node_url) in nodes {
@@ -114,11 +163,35 @@ impl ClientInner {
             }
         }
 
+        // The lag of the nodes is relative to the most recent confirmed milestone of the healthy nodes, nodes of other
+        // networks or that aren't healthy don't have one.
+        let latest_confirmed_milestone_index = healthy_nodes
+            .values()
+            .map(|info| info.status.confirmed_milestone.index)
+            .max();
+        let node_manager = self.node_manager.read().await;
+        let mut node_health = node_manager
+            .node_health
+            .write()
+            .map_err(|_| crate::client::Error::PoisonError)?;
+
+        for node in nodes {
+            let health = node_health.entry(node.clone()).or_default();
+
+            match measurements.get(node) {
+                Some((latency, confirmed_milestone_index)) => {
+                    health.record_success(*latency, *confirmed_milestone_index);
+                    health.milestone_lag = latest_confirmed_milestone_index
+                        .filter(|_| healthy_nodes.contains_key(node))
+                        .map(|index| index.saturating_sub(*confirmed_milestone_index));
+                }
+                None => health.record_error(),
+            }
+        }
+        drop(node_health);
+
         // Update the sync list.
-        *self
-            .node_manager
-            .read()
-            .await
+        *node_manager
             .healthy_nodes
             .write()
             .map_err(|_| crate::client::Error::PoisonError)? = healthy_nodes;
@@ -144,7 +217,7 @@ impl Client {
         self.sync_nodes(&nodes, ignore_node_health).await?;
         let client = self.clone();
 
-        let sync_handle = tokio::spawn(async move {
+        let sync_handle = self.background_tasks.spawn("node-sync", async move {
             client
                 .start_sync_process(nodes, node_sync_interval, ignore_node_health)
                 .await
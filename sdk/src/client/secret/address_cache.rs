@@ -0,0 +1,161 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A least recently used cache of derived addresses, so secret managers don't need to derive the same paths again.
+
+use std::{collections::HashMap, ops::Range};
+
+use crate::types::block::address::Ed25519Address;
+
+/// The default amount of addresses kept in an [`AddressCache`].
+pub(crate) const DEFAULT_ADDRESS_CACHE_CAPACITY: usize = 1000;
+
+/// Coin type, account index, internal flag and address index of a derived address.
+pub(crate) type AddressCacheKey = (u32, u32, bool, u32);
+
+/// Derived addresses by their path. When the capacity is reached, the least recently used address is evicted.
+#[derive(Debug)]
+pub(crate) struct AddressCache {
+    capacity: usize,
+    // The address with the tick of its last use
+    addresses: HashMap<AddressCacheKey, (Ed25519Address, u64)>,
+    tick: u64,
+}
+
+impl AddressCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            addresses: HashMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Returns the cached address of the path and marks it as recently used.
+    pub(crate) fn get(&mut self, key: &AddressCacheKey) -> Option<Ed25519Address> {
+        self.tick += 1;
+        let tick = self.tick;
+
+        self.addresses.get_mut(key).map(|(address, last_used)| {
+            *last_used = tick;
+            *address
+        })
+    }
+
+    /// Caches the address of the path, evicting the least recently used address if the cache is full. Returns the
+    /// previously cached address of the path.
+    pub(crate) fn insert(&mut self, key: AddressCacheKey, address: Ed25519Address) -> Option<Ed25519Address> {
+        self.tick += 1;
+
+        if !self.addresses.contains_key(&key) && self.addresses.len() >= self.capacity {
+            if let Some(least_recently_used) = self
+                .addresses
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| *key)
+            {
+                self.addresses.remove(&least_recently_used);
+            }
+        }
+
+        self.addresses
+            .insert(key, (address, self.tick))
+            .map(|(previous, _)| previous)
+    }
+
+    /// Returns the cached addresses of the whole range, or `None` if any of them is missing.
+    pub(crate) fn get_range(
+        &mut self,
+        coin_type: u32,
+        account_index: u32,
+        internal: bool,
+        address_indexes: Range<u32>,
+    ) -> Option<Vec<Ed25519Address>> {
+        address_indexes
+            .map(|address_index| self.get(&(coin_type, account_index, internal, address_index)))
+            .collect()
+    }
+
+    /// Caches the addresses of the range. If any of them differs from an already cached one, the seed changed, so all
+    /// previously cached addresses are invalid and removed. Returns whether that was the case.
+    pub(crate) fn insert_range(
+        &mut self,
+        coin_type: u32,
+        account_index: u32,
+        internal: bool,
+        address_indexes: Range<u32>,
+        addresses: &[Ed25519Address],
+    ) -> bool {
+        let seed_changed = address_indexes.clone().zip(addresses).any(|(address_index, address)| {
+            self.get(&(coin_type, account_index, internal, address_index))
+                .is_some_and(|cached| &cached != address)
+        });
+        if seed_changed {
+            self.clear();
+        }
+
+        for (address_index, address) in address_indexes.zip(addresses) {
+            self.insert((coin_type, account_index, internal, address_index), *address);
+        }
+
+        seed_changed
+    }
+
+    /// Removes all cached addresses.
+    pub(crate) fn clear(&mut self) {
+        self.addresses.clear();
+    }
+}
+
+impl Default for AddressCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_ADDRESS_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = AddressCache::new(2);
+        let address = |byte| Ed25519Address::new([byte; Ed25519Address::LENGTH]);
+
+        cache.insert((4218, 0, false, 0), address(0));
+        cache.insert((4218, 0, false, 1), address(1));
+        // Using the first address makes the second one the least recently used
+        assert_eq!(cache.get(&(4218, 0, false, 0)), Some(address(0)));
+
+        cache.insert((4218, 0, false, 2), address(2));
+        assert_eq!(cache.get(&(4218, 0, false, 1)), None);
+        assert_eq!(cache.get(&(4218, 0, false, 0)), Some(address(0)));
+        assert_eq!(cache.get(&(4218, 0, false, 2)), Some(address(2)));
+
+        // Replacing a cached address doesn't evict another one
+        assert_eq!(cache.insert((4218, 0, false, 2), address(3)), Some(address(2)));
+        assert_eq!(cache.get(&(4218, 0, false, 0)), Some(address(0)));
+
+        cache.clear();
+        assert_eq!(cache.get(&(4218, 0, false, 0)), None);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut cache = AddressCache::default();
+        let address = |byte| Ed25519Address::new([byte; Ed25519Address::LENGTH]);
+
+        assert!(!cache.insert_range(4218, 0, false, 0..2, &[address(0), address(1)]));
+        assert_eq!(
+            cache.get_range(4218, 0, false, 0..2),
+            Some(vec![address(0), address(1)])
+        );
+        assert_eq!(cache.get_range(4218, 0, false, 0..3), None);
+        assert_eq!(cache.get_range(4218, 0, true, 0..1), None);
+
+        // A different address for a cached index invalidates the whole cache
+        assert!(cache.insert_range(4218, 0, false, 1..2, &[address(2)]));
+        assert_eq!(cache.get_range(4218, 0, false, 0..1), None);
+        assert_eq!(cache.get_range(4218, 0, false, 1..2), Some(vec![address(2)]));
+    }
+}
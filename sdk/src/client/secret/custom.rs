@@ -0,0 +1,205 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`CustomSecretManager`].
+
+use std::{fmt::Debug, ops::Range};
+
+use async_trait::async_trait;
+use crypto::{
+    keys::bip44::Bip44,
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+
+use super::{GenerateAddressOptions, SecretManage};
+use crate::{
+    client::{api::PreparedTransactionData, Error},
+    types::block::{
+        address::Ed25519Address,
+        payload::transaction::TransactionPayload,
+        signature::Ed25519Signature,
+        unlock::{Unlock, Unlocks},
+    },
+};
+
+/// An object safe version of [`SecretManage`], implemented for every secret manager with the client [`Error`].
+#[async_trait]
+trait DynSecretManage: Send + Sync {
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<Ed25519Address>, Error>;
+
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<EvmAddress>, Error>;
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Error>;
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        msg: &[u8],
+        chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Error>;
+
+    async fn signature_unlock(&self, essence_hash: &[u8; 32], chain: Bip44) -> Result<Unlock, Error>;
+
+    async fn signature_unlocks(&self, essence_hash: &[u8; 32], chains: &[Bip44]) -> Result<Vec<Unlock>, Error>;
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Error>;
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Error>;
+}
+
+#[async_trait]
+impl<S: SecretManage<Error = Error>> DynSecretManage for S {
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<Ed25519Address>, Error> {
+        SecretManage::generate_ed25519_addresses(self, coin_type, account_index, address_indexes, options).await
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<EvmAddress>, Error> {
+        SecretManage::generate_evm_addresses(self, coin_type, account_index, address_indexes, options).await
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Error> {
+        SecretManage::sign_ed25519(self, msg, chain).await
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        msg: &[u8],
+        chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Error> {
+        SecretManage::sign_secp256k1_ecdsa(self, msg, chain).await
+    }
+
+    async fn signature_unlock(&self, essence_hash: &[u8; 32], chain: Bip44) -> Result<Unlock, Error> {
+        SecretManage::signature_unlock(self, essence_hash, chain).await
+    }
+
+    async fn signature_unlocks(&self, essence_hash: &[u8; 32], chains: &[Bip44]) -> Result<Vec<Unlock>, Error> {
+        SecretManage::signature_unlocks(self, essence_hash, chains).await
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Error> {
+        SecretManage::sign_transaction_essence(self, prepared_transaction_data, time).await
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Error> {
+        SecretManage::sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+/// Secret manager wrapping a custom [`SecretManage`] implementation, e.g. backed by an HSM, a KMS or a remote signer,
+/// so it can be used as [`SecretManager::Custom`](super::SecretManager::Custom).
+pub struct CustomSecretManager(Box<dyn DynSecretManage>);
+
+impl CustomSecretManager {
+    /// Wraps a custom secret manager.
+    pub fn new(secret_manager: impl SecretManage<Error = Error> + 'static) -> Self {
+        Self(Box::new(secret_manager))
+    }
+}
+
+impl Debug for CustomSecretManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CustomSecretManager").field(&"...").finish()
+    }
+}
+
+#[async_trait]
+impl SecretManage for CustomSecretManager {
+    type Error = Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        self.0
+            .generate_ed25519_addresses(coin_type, account_index, address_indexes, options.into())
+            .await
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        self.0
+            .generate_evm_addresses(coin_type, account_index, address_indexes, options.into())
+            .await
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        self.0.sign_ed25519(msg, chain).await
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        msg: &[u8],
+        chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        self.0.sign_secp256k1_ecdsa(msg, chain).await
+    }
+
+    async fn signature_unlock(&self, essence_hash: &[u8; 32], chain: Bip44) -> Result<Unlock, Self::Error> {
+        self.0.signature_unlock(essence_hash, chain).await
+    }
+
+    async fn signature_unlocks(&self, essence_hash: &[u8; 32], chains: &[Bip44]) -> Result<Vec<Unlock>, Self::Error> {
+        self.0.signature_unlocks(essence_hash, chains).await
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        self.0.sign_transaction_essence(prepared_transaction_data, time).await
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        self.0.sign_transaction(prepared_transaction_data).await
+    }
+}
@@ -22,8 +22,9 @@ use tokio::sync::Mutex;
 use super::{GenerateAddressOptions, SecretManage, SecretManagerConfig};
 use crate::{
     client::secret::{
+        address_cache::AddressCache,
         is_alias_transition,
-        types::{LedgerApp, LedgerDeviceType},
+        types::{AccountAddress, LedgerApp, LedgerDeviceType, LedgerNanoDto},
         LedgerNanoStatus, PreparedTransactionData,
     },
     types::block::{
@@ -75,6 +76,9 @@ pub enum Error {
     /// No available inputs provided
     #[error("No available inputs provided")]
     NoAvailableInputsProvided,
+    /// Blind signing is needed but not allowed
+    #[error("transaction can't be shown on the device and blind signing is not allowed")]
+    BlindSigningNotAllowed,
 }
 
 impl From<crate::types::block::Error> for Error {
@@ -110,8 +114,20 @@ pub struct LedgerSecretManager {
     pub is_simulator: bool,
     /// Specifies whether the wallet should be in non-interactive mode.
     pub non_interactive: bool,
+    /// Specifies whether addresses retrieved from the device should be cached per account, so they can still be
+    /// generated without prompt while the device is disconnected. A wallet also caches the addresses of its stored
+    /// accounts when it's loaded. The addresses are always retrieved from the device while it's connected, so a
+    /// changed seed is detected. The least recently used addresses are evicted when the cache is full. Signing
+    /// always requires the device.
+    pub cache_addresses: bool,
+    /// Specifies whether transactions that can't be shown on the device, because the essence is too large for its
+    /// buffer or contains outputs the app can't display, may be signed blindly by only showing the essence hash.
+    /// Blind signing also has to be enabled in the settings of the app on the device.
+    pub allow_blind_signing: bool,
     /// Mutex to prevent multiple simultaneous requests to a ledger.
     mutex: Mutex<()>,
+    /// Addresses retrieved from the device, by coin type, account index, internal flag and address index.
+    address_cache: Mutex<AddressCache>,
 }
 
 impl TryFrom<u8> for LedgerDeviceType {
@@ -151,21 +167,40 @@ impl SecretManage for LedgerSecretManager {
         // lock the mutex to prevent multiple simultaneous requests to a ledger
         let lock = self.mutex.lock().await;
 
-        // get ledger
-        let ledger = get_ledger(coin_type, bip32_account, self.is_simulator).map_err(Error::from)?;
-        if ledger.is_debug_app() {
-            ledger
-                .set_non_interactive_mode(self.non_interactive)
-                .map_err(Error::from)?;
-        }
+        let addresses = (|| -> Result<_, Error> {
+            // get ledger
+            let ledger = get_ledger(coin_type, bip32_account, self.is_simulator)?;
+            if ledger.is_debug_app() {
+                ledger.set_non_interactive_mode(self.non_interactive)?;
+            }
 
-        let addresses = ledger
-            .get_addresses(options.ledger_nano_prompt, bip32, address_indexes.len())
-            .map_err(Error::from)?;
+            Ok(ledger.get_addresses(options.ledger_nano_prompt, bip32, address_indexes.len())?)
+        })();
 
         drop(lock);
 
-        Ok(addresses.into_iter().map(Ed25519Address::new).collect())
+        match addresses {
+            Ok(addresses) => {
+                let addresses = addresses.into_iter().map(Ed25519Address::new).collect::<Vec<_>>();
+                if self.cache_addresses {
+                    self.insert_cached_addresses(
+                        coin_type,
+                        account_index,
+                        options.internal,
+                        address_indexes,
+                        &addresses,
+                    )
+                    .await;
+                }
+                Ok(addresses)
+            }
+            // Fall back to the cached addresses if the device is disconnected and no prompt is required
+            Err(Error::DeviceNotFound) if self.cache_addresses && !options.ledger_nano_prompt => self
+                .get_cached_addresses(coin_type, account_index, options.internal, address_indexes)
+                .await
+                .ok_or_else(|| Error::DeviceNotFound.into()),
+            Err(e) => Err(e.into()),
+        }
     }
 
     async fn generate_evm_addresses(
@@ -283,6 +318,9 @@ impl SecretManage for LedgerSecretManager {
                 .map_err(Error::from)?;
         }
         let blind_signing = needs_blind_signing(prepared_transaction, ledger.get_buffer_size());
+        if blind_signing && !self.allow_blind_signing {
+            return Err(Error::BlindSigningNotAllowed.into());
+        }
 
         // if essence + bip32 input indices are larger than the buffer size or the essence contains
         // features / types that are not supported blind signing will be needed
@@ -411,14 +449,22 @@ impl SecretManage for LedgerSecretManager {
 }
 
 impl SecretManagerConfig for LedgerSecretManager {
-    type Config = bool;
+    type Config = LedgerNanoDto;
 
     fn to_config(&self) -> Option<Self::Config> {
-        Some(self.is_simulator)
+        Some(LedgerNanoDto {
+            is_simulator: self.is_simulator,
+            allow_blind_signing: self.allow_blind_signing,
+            cache_addresses: self.cache_addresses,
+        })
     }
 
     fn from_config(config: &Self::Config) -> Result<Self, Self::Error> {
-        Ok(Self::new(*config))
+        let mut secret_manager = Self::new(config.is_simulator);
+        secret_manager.allow_blind_signing = config.allow_blind_signing;
+        secret_manager.cache_addresses = config.cache_addresses;
+
+        Ok(secret_manager)
     }
 }
 
@@ -453,7 +499,75 @@ impl LedgerSecretManager {
         Self {
             is_simulator,
             non_interactive: false,
+            cache_addresses: false,
+            allow_blind_signing: false,
             mutex: Mutex::new(()),
+            address_cache: Mutex::new(AddressCache::default()),
+        }
+    }
+
+    /// Clears all addresses cached from the device.
+    pub async fn clear_address_cache(&self) {
+        self.address_cache.lock().await.clear();
+    }
+
+    /// Caches known addresses of an account, e.g. the ones stored by a wallet, so they can be generated while the
+    /// device is disconnected. Does nothing if [`cache_addresses`](Self::cache_addresses) isn't set. Non Ed25519
+    /// addresses are ignored.
+    pub async fn cache_account_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        addresses: impl IntoIterator<Item = AccountAddress> + Send,
+    ) {
+        if !self.cache_addresses {
+            return;
+        }
+        let mut address_cache = self.address_cache.lock().await;
+
+        for address in addresses {
+            if let Address::Ed25519(ed25519_address) = address.address {
+                address_cache.insert(
+                    (coin_type, account_index, address.internal, address.key_index),
+                    ed25519_address,
+                );
+            }
+        }
+    }
+
+    // Returns the cached addresses for the whole range, or `None` if any of them is missing.
+    async fn get_cached_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        internal: bool,
+        address_indexes: Range<u32>,
+    ) -> Option<Vec<Ed25519Address>> {
+        self.address_cache
+            .lock()
+            .await
+            .get_range(coin_type, account_index, internal, address_indexes)
+    }
+
+    // Caches addresses retrieved from the device. If any of them differs from an already cached one, the seed on the
+    // device changed, so all previously cached addresses are invalid.
+    async fn insert_cached_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        internal: bool,
+        address_indexes: Range<u32>,
+        addresses: &[Ed25519Address],
+    ) {
+        let seed_changed = self.address_cache.lock().await.insert_range(
+            coin_type,
+            account_index,
+            internal,
+            address_indexes,
+            addresses,
+        );
+        if seed_changed {
+            log::debug!("[LEDGER] device seed changed, cleared address cache");
         }
     }
 
@@ -596,6 +710,117 @@ mod tests {
         types::block::address::ToBech32Ext,
     };
 
+    #[tokio::test]
+    async fn address_cache_invalidation() {
+        let secret_manager = LedgerSecretManager::new(true);
+        let address = Ed25519Address::new([1; Ed25519Address::LENGTH]);
+        let other_address = Ed25519Address::new([2; Ed25519Address::LENGTH]);
+
+        secret_manager
+            .insert_cached_addresses(IOTA_COIN_TYPE, 0, false, 0..2, &[address, address])
+            .await;
+        assert_eq!(
+            secret_manager
+                .get_cached_addresses(IOTA_COIN_TYPE, 0, false, 0..2)
+                .await,
+            Some(vec![address, address])
+        );
+        assert_eq!(
+            secret_manager
+                .get_cached_addresses(IOTA_COIN_TYPE, 0, false, 0..3)
+                .await,
+            None
+        );
+
+        // A different address for a cached index invalidates the whole cache
+        secret_manager
+            .insert_cached_addresses(IOTA_COIN_TYPE, 0, false, 1..2, &[other_address])
+            .await;
+        assert_eq!(
+            secret_manager
+                .get_cached_addresses(IOTA_COIN_TYPE, 0, false, 0..1)
+                .await,
+            None
+        );
+        assert_eq!(
+            secret_manager
+                .get_cached_addresses(IOTA_COIN_TYPE, 0, false, 1..2)
+                .await,
+            Some(vec![other_address])
+        );
+
+        secret_manager.clear_address_cache().await;
+        assert_eq!(
+            secret_manager
+                .get_cached_addresses(IOTA_COIN_TYPE, 0, false, 1..2)
+                .await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn cache_account_addresses() {
+        let mut secret_manager = LedgerSecretManager::new(true);
+        let address = Ed25519Address::new([1; Ed25519Address::LENGTH]);
+        let account_address = |key_index, internal| AccountAddress {
+            address: Address::Ed25519(address),
+            key_index,
+            internal,
+        };
+
+        secret_manager
+            .cache_account_addresses(IOTA_COIN_TYPE, 0, [account_address(0, false)])
+            .await;
+        assert_eq!(
+            secret_manager
+                .get_cached_addresses(IOTA_COIN_TYPE, 0, false, 0..1)
+                .await,
+            None
+        );
+
+        secret_manager.cache_addresses = true;
+        secret_manager
+            .cache_account_addresses(
+                IOTA_COIN_TYPE,
+                1,
+                [
+                    account_address(0, false),
+                    account_address(1, false),
+                    account_address(0, true),
+                ],
+            )
+            .await;
+        assert_eq!(
+            secret_manager
+                .get_cached_addresses(IOTA_COIN_TYPE, 1, false, 0..2)
+                .await,
+            Some(vec![address, address])
+        );
+        assert_eq!(
+            secret_manager.get_cached_addresses(IOTA_COIN_TYPE, 1, true, 0..1).await,
+            Some(vec![address])
+        );
+        assert_eq!(
+            secret_manager
+                .get_cached_addresses(IOTA_COIN_TYPE, 0, false, 0..1)
+                .await,
+            None
+        );
+    }
+
+    #[test]
+    fn config() {
+        let mut secret_manager = LedgerSecretManager::new(true);
+        secret_manager.allow_blind_signing = true;
+        secret_manager.cache_addresses = true;
+
+        let config = secret_manager.to_config().unwrap();
+        let restored = LedgerSecretManager::from_config(&config).unwrap();
+        assert!(restored.is_simulator);
+        assert!(restored.allow_blind_signing);
+        assert!(restored.cache_addresses);
+    }
+
     #[tokio::test]
     #[ignore = "requires ledger nano instance"]
     async fn ed25519_address() {
@@ -8,26 +8,43 @@ use std::ops::Range;
 use async_trait::async_trait;
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
-    keys::{bip39::Mnemonic, bip44::Bip44, slip10::Seed},
+    keys::{
+        bip39::Mnemonic,
+        bip44::Bip44,
+        slip10::{Seed, Slip10},
+    },
     signatures::{
         ed25519,
         secp256k1_ecdsa::{self, EvmAddress},
     },
 };
+use tokio::sync::Mutex;
 use zeroize::Zeroizing;
 
-use super::{GenerateAddressOptions, SecretManage};
+use super::{address_cache::AddressCache, GenerateAddressOptions, SecretManage};
 use crate::{
     client::{api::PreparedTransactionData, Client, Error},
     types::block::{
-        address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
+        address::Ed25519Address,
+        payload::transaction::TransactionPayload,
+        signature::{Ed25519Signature, Signature},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
     },
 };
 
+/// The minimum number of signatures to sign them in parallel.
+#[cfg(not(target_family = "wasm"))]
+const PARALLEL_SIGNING_THRESHOLD: usize = 8;
+
 /// Secret manager that uses only a mnemonic.
 ///
 /// Computation are done in-memory. A mnemonic needs to be supplied upon the creation of [`MnemonicSecretManager`].
-pub struct MnemonicSecretManager(Seed);
+/// Generated addresses are cached, so the same paths aren't derived again.
+pub struct MnemonicSecretManager {
+    seed: Seed,
+    /// Generated addresses, by coin type, account index, internal flag and address index.
+    address_cache: Mutex<AddressCache>,
+}
 
 impl std::fmt::Debug for MnemonicSecretManager {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -48,7 +65,17 @@ impl SecretManage for MnemonicSecretManager {
     ) -> Result<Vec<Ed25519Address>, Self::Error> {
         let internal = options.into().map(|o| o.internal).unwrap_or_default();
 
-        Ok(address_indexes
+        if let Some(addresses) =
+            self.address_cache
+                .lock()
+                .await
+                .get_range(coin_type, account_index, internal, address_indexes.clone())
+        {
+            return Ok(addresses);
+        }
+
+        let addresses = address_indexes
+            .clone()
             .map(|address_index| {
                 let chain = Bip44::new(coin_type)
                     .with_account(account_index)
@@ -56,7 +83,7 @@ impl SecretManage for MnemonicSecretManager {
                     .with_address_index(address_index);
 
                 let public_key = chain
-                    .derive(&self.0.to_master_key::<ed25519::SecretKey>())
+                    .derive(&self.seed.to_master_key::<ed25519::SecretKey>())
                     .secret_key()
                     .public_key()
                     .to_bytes();
@@ -68,7 +95,14 @@ impl SecretManage for MnemonicSecretManager {
 
                 crate::client::Result::Ok(Ed25519Address::new(result))
             })
-            .collect::<Result<_, _>>()?)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.address_cache
+            .lock()
+            .await
+            .insert_range(coin_type, account_index, internal, address_indexes, &addresses);
+
+        Ok(addresses)
     }
 
     async fn generate_evm_addresses(
@@ -88,7 +122,7 @@ impl SecretManage for MnemonicSecretManager {
                     .with_address_index(address_index);
 
                 let public_key = chain
-                    .derive(&self.0.to_master_key::<secp256k1_ecdsa::SecretKey>())
+                    .derive(&self.seed.to_master_key::<secp256k1_ecdsa::SecretKey>())
                     .secret_key()
                     .public_key();
 
@@ -99,7 +133,9 @@ impl SecretManage for MnemonicSecretManager {
 
     async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
         // Get the private and public key for this Ed25519 address
-        let private_key = chain.derive(&self.0.to_master_key::<ed25519::SecretKey>()).secret_key();
+        let private_key = chain
+            .derive(&self.seed.to_master_key::<ed25519::SecretKey>())
+            .secret_key();
         let public_key = private_key.public_key();
         let signature = private_key.sign(msg);
 
@@ -113,7 +149,7 @@ impl SecretManage for MnemonicSecretManager {
     ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
         // Get the private and public key for this secp256k1_ecdsa key
         let private_key = chain
-            .derive(&self.0.to_master_key::<secp256k1_ecdsa::SecretKey>())
+            .derive(&self.seed.to_master_key::<secp256k1_ecdsa::SecretKey>())
             .secret_key();
         let public_key = private_key.public_key();
         let signature = private_key.try_sign_keccak256(msg)?;
@@ -121,6 +157,51 @@ impl SecretManage for MnemonicSecretManager {
         Ok((public_key, signature))
     }
 
+    async fn signature_unlocks(&self, essence_hash: &[u8; 32], chains: &[Bip44]) -> Result<Vec<Unlock>, Self::Error> {
+        // Derive the master key only once for all chains
+        let master_key = self.seed.to_master_key::<ed25519::SecretKey>();
+
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let threads = std::thread::available_parallelism().map_or(1, usize::from);
+
+            // Spawning blocking tasks is only worth it for many signatures
+            if threads > 1 && chains.len() >= PARALLEL_SIGNING_THRESHOLD {
+                let chunk_size = chains.len().div_ceil(threads);
+                let essence_hash = *essence_hash;
+
+                // Sign on the blocking thread pool, so the async worker threads aren't blocked
+                let tasks = chains
+                    .chunks(chunk_size)
+                    .map(|chunk| {
+                        let master_key = master_key.clone();
+                        let chunk = chunk.to_vec();
+
+                        tokio::task::spawn_blocking(move || {
+                            chunk
+                                .iter()
+                                .map(|chain| sign_unlock(&master_key, &essence_hash, chain))
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut unlocks = Vec::with_capacity(chains.len());
+
+                for task in tasks {
+                    unlocks.extend(task.await?);
+                }
+
+                return Ok(unlocks);
+            }
+        }
+
+        Ok(chains
+            .iter()
+            .map(|chain| sign_unlock(&master_key, essence_hash, chain))
+            .collect())
+    }
+
     async fn sign_transaction_essence(
         &self,
         prepared_transaction_data: &PreparedTransactionData,
@@ -137,12 +218,20 @@ impl SecretManage for MnemonicSecretManager {
     }
 }
 
+/// Signs `essence_hash` with the key of `chain` derived from `master_key`.
+fn sign_unlock(master_key: &Slip10<ed25519::SecretKey>, essence_hash: &[u8; 32], chain: &Bip44) -> Unlock {
+    let private_key = chain.derive(master_key).secret_key();
+    let signature = Ed25519Signature::new(private_key.public_key(), private_key.sign(essence_hash));
+
+    Unlock::Signature(SignatureUnlock::new(Signature::from(signature)))
+}
+
 impl MnemonicSecretManager {
     /// Create a new [`MnemonicSecretManager`] from a BIP-39 mnemonic in English.
     ///
     /// For more information, see <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki>.
     pub fn try_from_mnemonic(mnemonic: impl Into<Mnemonic>) -> Result<Self, Error> {
-        Ok(Self(Client::mnemonic_to_seed(mnemonic.into())?.into()))
+        Ok(Self::from_seed(Client::mnemonic_to_seed(mnemonic.into())?.into()))
     }
 
     /// Create a new [`MnemonicSecretManager`] from a hex-encoded raw seed string.
@@ -150,7 +239,19 @@ impl MnemonicSecretManager {
         let hex = hex.into();
         let bytes = Zeroizing::new(prefix_hex::decode::<Vec<u8>>(hex.as_str())?);
         let seed = Seed::from_bytes(bytes.as_ref());
-        Ok(Self(seed))
+        Ok(Self::from_seed(seed))
+    }
+
+    fn from_seed(seed: Seed) -> Self {
+        Self {
+            seed,
+            address_cache: Mutex::new(AddressCache::default()),
+        }
+    }
+
+    /// Clears all cached addresses.
+    pub async fn clear_address_cache(&self) {
+        self.address_cache.lock().await.clear();
     }
 }
 
@@ -177,6 +278,45 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn cached_addresses() {
+        use crate::client::constants::IOTA_COIN_TYPE;
+
+        let seed = "0x256a818b2aac458941f7274985a410e57fb750f3a3a67969ece5bd9ae7eef5b2".to_owned();
+        let secret_manager = MnemonicSecretManager::try_from_hex_seed(seed).unwrap();
+
+        let addresses = secret_manager
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..2, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            secret_manager
+                .address_cache
+                .lock()
+                .await
+                .get_range(IOTA_COIN_TYPE, 0, false, 0..2),
+            Some(addresses.clone())
+        );
+        // Served from the cache
+        assert_eq!(
+            secret_manager
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 1..2, None)
+                .await
+                .unwrap(),
+            addresses[1..]
+        );
+
+        secret_manager.clear_address_cache().await;
+        assert_eq!(
+            secret_manager
+                .address_cache
+                .lock()
+                .await
+                .get_range(IOTA_COIN_TYPE, 0, false, 0..1),
+            None
+        );
+    }
+
     #[tokio::test]
     async fn seed_address() {
         use crate::client::constants::IOTA_COIN_TYPE;
@@ -3,6 +3,10 @@
 
 //! Secret manager module enabling address generation and transaction essence signing.
 
+/// Module for caching derived addresses.
+pub(crate) mod address_cache;
+/// Module for custom secret managers.
+pub mod custom;
 /// Module for ledger nano based secret management.
 #[cfg(feature = "ledger_nano")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
@@ -39,7 +43,12 @@ use self::mnemonic::MnemonicSecretManager;
 use self::private_key::PrivateKeySecretManager;
 #[cfg(feature = "stronghold")]
 use self::stronghold::StrongholdSecretManager;
-pub use self::types::{GenerateAddressOptions, LedgerNanoStatus};
+pub use self::{
+    custom::CustomSecretManager,
+    types::{GenerateAddressOptions, LedgerNanoStatus},
+};
+#[cfg(feature = "ledger_nano")]
+use crate::client::secret::types::LedgerNanoDto;
 #[cfg(feature = "stronghold")]
 use crate::client::secret::types::StrongholdDto;
 use crate::{
@@ -63,6 +72,10 @@ use crate::{
 };
 
 /// The secret manager interface.
+///
+/// Implementations backed by an HSM, a KMS or a remote signer can be used through [`SecretManager::Custom`]. Such
+/// implementations usually only need to sign, and can use [`default_sign_transaction_essence()`] and
+/// [`default_sign_transaction()`] for the transaction methods.
 #[async_trait]
 pub trait SecretManage: Send + Sync {
     type Error: std::error::Error + Send + Sync;
@@ -103,6 +116,19 @@ pub trait SecretManage: Send + Sync {
         ))))
     }
 
+    /// Signs `essence_hash` using each of the given `chains`, returning the [`Unlock`]s in the same order.
+    ///
+    /// Signs sequentially by default, secret managers that can sign concurrently override it.
+    async fn signature_unlocks(&self, essence_hash: &[u8; 32], chains: &[Bip44]) -> Result<Vec<Unlock>, Self::Error> {
+        let mut unlocks = Vec::with_capacity(chains.len());
+
+        for chain in chains {
+            unlocks.push(self.signature_unlock(essence_hash, *chain).await?);
+        }
+
+        Ok(unlocks)
+    }
+
     /// Signs a transaction essence.
     async fn sign_transaction_essence(
         &self,
@@ -149,6 +175,10 @@ pub enum SecretManager {
     #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
     PrivateKey(Box<PrivateKeySecretManager>),
 
+    /// Secret manager with a custom implementation, e.g. backed by an HSM or a remote signer. It can't be restored
+    /// from its config, so it has to be provided again when a wallet is loaded.
+    Custom(CustomSecretManager),
+
     /// Secret manager that's just a placeholder, so it can be provided to an online wallet, but can't be used for
     /// signing.
     Placeholder,
@@ -174,6 +204,12 @@ impl From<MnemonicSecretManager> for SecretManager {
     }
 }
 
+impl From<CustomSecretManager> for SecretManager {
+    fn from(secret_manager: CustomSecretManager) -> Self {
+        Self::Custom(secret_manager)
+    }
+}
+
 #[cfg(feature = "private_key_secret_manager")]
 impl From<PrivateKeySecretManager> for SecretManager {
     fn from(secret_manager: PrivateKeySecretManager) -> Self {
@@ -191,6 +227,7 @@ impl Debug for SecretManager {
             Self::Mnemonic(_) => f.debug_tuple("Mnemonic").field(&"...").finish(),
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(_) => f.debug_tuple("PrivateKey").field(&"...").finish(),
+            Self::Custom(_) => f.debug_tuple("Custom").field(&"...").finish(),
             Self::Placeholder => f.debug_struct("Placeholder").finish(),
         }
     }
@@ -213,11 +250,11 @@ pub enum SecretManagerDto {
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
     #[serde(alias = "stronghold")]
     Stronghold(StrongholdDto),
-    /// Ledger Device, bool specifies if it's a simulator or not
+    /// Ledger Device, also deserialized from a bool that specifies if it's a simulator or not
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     #[serde(alias = "ledgerNano")]
-    LedgerNano(bool),
+    LedgerNano(LedgerNanoDto),
     /// Mnemonic
     #[serde(alias = "mnemonic")]
     Mnemonic(Zeroizing<String>),
@@ -229,6 +266,9 @@ pub enum SecretManagerDto {
     /// Hex seed
     #[serde(alias = "hexSeed")]
     HexSeed(Zeroizing<String>),
+    /// Custom, only describes the type since a custom secret manager can't be created from a DTO
+    #[serde(alias = "custom")]
+    Custom,
     /// Placeholder
     #[serde(alias = "placeholder")]
     Placeholder,
@@ -255,7 +295,13 @@ impl TryFrom<SecretManagerDto> for SecretManager {
             }
 
             #[cfg(feature = "ledger_nano")]
-            SecretManagerDto::LedgerNano(is_simulator) => Self::LedgerNano(LedgerSecretManager::new(is_simulator)),
+            SecretManagerDto::LedgerNano(ledger_nano_dto) => {
+                let mut secret_manager = LedgerSecretManager::new(ledger_nano_dto.is_simulator);
+                secret_manager.allow_blind_signing = ledger_nano_dto.allow_blind_signing;
+                secret_manager.cache_addresses = ledger_nano_dto.cache_addresses;
+
+                Self::LedgerNano(secret_manager)
+            }
 
             SecretManagerDto::Mnemonic(mnemonic) => {
                 Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.as_str().to_owned())?)
@@ -271,6 +317,8 @@ impl TryFrom<SecretManagerDto> for SecretManager {
                 Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(hex_seed)?)
             }
 
+            SecretManagerDto::Custom => return Err(Error::CustomSecretManagerConfig),
+
             SecretManagerDto::Placeholder => Self::Placeholder,
         })
     }
@@ -292,7 +340,11 @@ impl From<&SecretManager> for SecretManagerDto {
             }),
 
             #[cfg(feature = "ledger_nano")]
-            SecretManager::LedgerNano(ledger_nano) => Self::LedgerNano(ledger_nano.is_simulator),
+            SecretManager::LedgerNano(ledger_nano) => Self::LedgerNano(LedgerNanoDto {
+                is_simulator: ledger_nano.is_simulator,
+                allow_blind_signing: ledger_nano.allow_blind_signing,
+                cache_addresses: ledger_nano.cache_addresses,
+            }),
 
             // `MnemonicSecretManager(Seed)` doesn't have Debug or Display implemented and in the current use cases of
             // the client/wallet we also don't need to convert it in this direction with the mnemonic/seed, we only need
@@ -302,6 +354,8 @@ impl From<&SecretManager> for SecretManagerDto {
             #[cfg(feature = "private_key_secret_manager")]
             SecretManager::PrivateKey(_private_key) => Self::PrivateKey("...".to_string().into()),
 
+            SecretManager::Custom(_) => Self::Custom,
+
             SecretManager::Placeholder => Self::Placeholder,
         }
     }
@@ -338,6 +392,11 @@ impl SecretManage for SecretManager {
                     .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            Self::Custom(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -369,6 +428,11 @@ impl SecretManage for SecretManager {
                     .generate_evm_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            Self::Custom(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -382,6 +446,7 @@ impl SecretManage for SecretManager {
             Self::Mnemonic(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            Self::Custom(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -399,6 +464,21 @@ impl SecretManage for SecretManager {
             Self::Mnemonic(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
+            Self::Custom(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
+            Self::Placeholder => Err(Error::PlaceholderSecretManager),
+        }
+    }
+
+    async fn signature_unlocks(&self, essence_hash: &[u8; 32], chains: &[Bip44]) -> Result<Vec<Unlock>, Self::Error> {
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => Ok(secret_manager.signature_unlocks(essence_hash, chains).await?),
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(secret_manager) => Ok(secret_manager.signature_unlocks(essence_hash, chains).await?),
+            Self::Mnemonic(secret_manager) => secret_manager.signature_unlocks(essence_hash, chains).await,
+            #[cfg(feature = "private_key_secret_manager")]
+            Self::PrivateKey(secret_manager) => secret_manager.signature_unlocks(essence_hash, chains).await,
+            Self::Custom(secret_manager) => secret_manager.signature_unlocks(essence_hash, chains).await,
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -428,6 +508,11 @@ impl SecretManage for SecretManager {
                     .sign_transaction_essence(prepared_transaction_data, time)
                     .await
             }
+            Self::Custom(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -444,6 +529,7 @@ impl SecretManage for SecretManager {
             Self::Mnemonic(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
+            Self::Custom(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -471,6 +557,7 @@ impl SecretManagerConfig for SecretManager {
             Self::Mnemonic(_) => None,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(_) => None,
+            Self::Custom(_) => None,
             Self::Placeholder => None,
         }
     }
@@ -491,6 +578,7 @@ impl SecretManagerConfig for SecretManager {
             SecretManagerDto::PrivateKey(private_key) => {
                 Self::PrivateKey(Box::new(PrivateKeySecretManager::try_from_hex(private_key.to_owned())?))
             }
+            SecretManagerDto::Custom => return Err(Error::CustomSecretManagerConfig),
             SecretManagerDto::Placeholder => Self::Placeholder,
         })
     }
@@ -508,52 +596,52 @@ impl SecretManager {
     }
 }
 
-pub(crate) async fn default_sign_transaction_essence<M: SecretManage>(
-    secret_manager: &M,
+/// The unlock of an input of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum InputUnlock {
+    /// A signature unlock that still needs to be signed by the address.
+    Signature(Ed25519Address),
+    /// An unlock referencing the unlock of a previous input.
+    Unlock(Unlock),
+}
+
+/// Returns the unlocks of the inputs of a transaction essence: the first input of each Ed25519 address needs a
+/// signature, the other inputs reference the first unlock of their address, or the unlock of the alias or nft output
+/// that owns them.
+pub(crate) fn input_unlocks(
     prepared_transaction_data: &PreparedTransactionData,
-    time: Option<u32>,
-) -> crate::client::Result<Unlocks>
-where
-    crate::client::Error: From<M::Error>,
-{
-    // The hashed_essence gets signed
-    let hashed_essence = prepared_transaction_data.essence.hash();
-    let mut blocks = Vec::new();
+    time: u32,
+) -> crate::client::Result<Vec<InputUnlock>> {
+    let TransactionEssence::Regular(regular) = &prepared_transaction_data.essence;
+    let mut unlocks = Vec::new();
     let mut block_indexes = HashMap::<Address, usize>::new();
 
     // Assuming inputs_data is ordered by address type
     for (current_block_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
         // Get the address that is required to unlock the input
-        let TransactionEssence::Regular(regular) = &prepared_transaction_data.essence;
         let alias_transition = is_alias_transition(&input.output, *input.output_id(), regular.outputs(), None);
-        let (input_address, _) = input.output.required_and_unlocked_address(
-            time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
-            input.output_metadata.output_id(),
-            alias_transition,
-        )?;
+        let (input_address, _) =
+            input
+                .output
+                .required_and_unlocked_address(time, input.output_metadata.output_id(), alias_transition)?;
 
         // Check if we already added an [Unlock] for this address
         match block_indexes.get(&input_address) {
             // If we already have an [Unlock] for this address, add a [Unlock] based on the address type
-            Some(block_index) => match input_address {
-                Address::Alias(_alias) => blocks.push(Unlock::Alias(AliasUnlock::new(*block_index as u16)?)),
-                Address::Ed25519(_ed25519) => {
-                    blocks.push(Unlock::Reference(ReferenceUnlock::new(*block_index as u16)?));
-                }
-                Address::Nft(_nft) => blocks.push(Unlock::Nft(NftUnlock::new(*block_index as u16)?)),
-            },
+            Some(block_index) => unlocks.push(InputUnlock::Unlock(match input_address {
+                Address::Alias(_alias) => Unlock::Alias(AliasUnlock::new(*block_index as u16)?),
+                Address::Ed25519(_ed25519) => Unlock::Reference(ReferenceUnlock::new(*block_index as u16)?),
+                Address::Nft(_nft) => Unlock::Nft(NftUnlock::new(*block_index as u16)?),
+            })),
             None => {
                 // We can only sign ed25519 addresses and block_indexes needs to contain the alias or nft
                 // address already at this point, because the reference index needs to be lower
                 // than the current block index
-                if !input_address.is_ed25519() {
-                    Err(InputSelectionError::MissingInputWithEd25519Address)?;
-                }
-
-                let chain = input.chain.ok_or(Error::MissingBip32Chain)?;
+                let Address::Ed25519(ed25519) = input_address else {
+                    return Err(InputSelectionError::MissingInputWithEd25519Address.into());
+                };
 
-                let block = secret_manager.signature_unlock(&hashed_essence, chain).await?;
-                blocks.push(block);
+                unlocks.push(InputUnlock::Signature(ed25519));
 
                 // Add the ed25519 address to the block_indexes, so it gets referenced if further inputs have
                 // the same address in their unlock condition
@@ -577,10 +665,57 @@ where
         };
     }
 
+    Ok(unlocks)
+}
+
+/// Creates the unlocks of a transaction essence, signing the inputs with [`SecretManage::signature_unlocks()`] and
+/// referencing the first unlock of each address for the other inputs.
+pub async fn default_sign_transaction_essence<M: SecretManage>(
+    secret_manager: &M,
+    prepared_transaction_data: &PreparedTransactionData,
+    time: Option<u32>,
+) -> crate::client::Result<Unlocks>
+where
+    crate::client::Error: From<M::Error>,
+{
+    // The hashed_essence gets signed
+    let hashed_essence = prepared_transaction_data.essence.hash();
+    let input_unlocks = input_unlocks(
+        prepared_transaction_data,
+        time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
+    )?;
+
+    // The signature unlocks get signed in a single batch
+    let chains = input_unlocks
+        .iter()
+        .zip(&prepared_transaction_data.inputs_data)
+        .filter(|(unlock, _)| matches!(unlock, InputUnlock::Signature(_)))
+        .map(|(_, input)| input.chain.ok_or(Error::MissingBip32Chain))
+        .collect::<crate::client::Result<Vec<_>>>()?;
+    let signature_unlocks = secret_manager.signature_unlocks(&hashed_essence, &chains).await?;
+    // Otherwise unlocks would be missing or assigned to other inputs
+    if signature_unlocks.len() != chains.len() {
+        return Err(Error::SignatureUnlockCount {
+            expected: chains.len(),
+            found: signature_unlocks.len(),
+        });
+    }
+    let mut signature_unlocks = signature_unlocks.into_iter();
+    let blocks = input_unlocks
+        .into_iter()
+        .map(|unlock| match unlock {
+            InputUnlock::Unlock(unlock) => unlock,
+            InputUnlock::Signature(_) => signature_unlocks
+                .next()
+                .expect("the number of signature unlocks is checked"),
+        })
+        .collect::<Vec<_>>();
+
     Ok(Unlocks::new(blocks)?)
 }
 
-pub(crate) async fn default_sign_transaction<M: SecretManage>(
+/// Signs a transaction with [`SecretManage::sign_transaction_essence()`] and checks it's semantically valid.
+pub async fn default_sign_transaction<M: SecretManage>(
     secret_manager: &M,
     prepared_transaction_data: PreparedTransactionData,
 ) -> crate::client::Result<TransactionPayload>
@@ -41,6 +41,57 @@ impl core::fmt::Debug for StrongholdDto {
     }
 }
 
+/// Ledger Nano DTO to allow the creation of a Ledger Nano secret manager from bindings, also deserialized from a bool
+/// that specifies if it's a simulator.
+#[cfg(feature = "ledger_nano")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", from = "LedgerNanoDtoRepr")]
+pub struct LedgerNanoDto {
+    /// Whether a simulator is used instead of a device
+    pub is_simulator: bool,
+    /// Whether transactions that can't be shown on the device may be signed blindly
+    pub allow_blind_signing: bool,
+    /// Whether addresses are cached per account, so they can still be generated while the device is disconnected
+    pub cache_addresses: bool,
+}
+
+#[cfg(feature = "ledger_nano")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LedgerNanoDtoRepr {
+    IsSimulator(bool),
+    #[serde(rename_all = "camelCase")]
+    Options {
+        is_simulator: bool,
+        #[serde(default)]
+        allow_blind_signing: bool,
+        #[serde(default)]
+        cache_addresses: bool,
+    },
+}
+
+#[cfg(feature = "ledger_nano")]
+impl From<LedgerNanoDtoRepr> for LedgerNanoDto {
+    fn from(value: LedgerNanoDtoRepr) -> Self {
+        match value {
+            LedgerNanoDtoRepr::IsSimulator(is_simulator) => Self {
+                is_simulator,
+                ..Default::default()
+            },
+            LedgerNanoDtoRepr::Options {
+                is_simulator,
+                allow_blind_signing,
+                cache_addresses,
+            } => Self {
+                is_simulator,
+                allow_blind_signing,
+                cache_addresses,
+            },
+        }
+    }
+}
+
 /// An account address.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AccountAddress {
@@ -203,3 +254,45 @@ impl From<&InputSigningData> for InputSigningDataDto {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ledger_nano")]
+    #[test]
+    fn ledger_nano_dto() {
+        use super::LedgerNanoDto;
+
+        assert_eq!(
+            serde_json::from_str::<LedgerNanoDto>("true").unwrap(),
+            LedgerNanoDto {
+                is_simulator: true,
+                allow_blind_signing: false,
+                cache_addresses: false,
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<LedgerNanoDto>(r#"{"isSimulator":false,"allowBlindSigning":true}"#).unwrap(),
+            LedgerNanoDto {
+                is_simulator: false,
+                allow_blind_signing: true,
+                cache_addresses: false,
+            }
+        );
+        let dto = serde_json::from_str::<LedgerNanoDto>(
+            r#"{"isSimulator":false,"allowBlindSigning":true,"cacheAddresses":true}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            dto,
+            LedgerNanoDto {
+                is_simulator: false,
+                allow_blind_signing: true,
+                cache_addresses: true,
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<LedgerNanoDto>(&serde_json::to_string(&dto).unwrap()).unwrap(),
+            dto
+        );
+    }
+}
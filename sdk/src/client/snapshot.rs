@@ -0,0 +1,409 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reading the unspent outputs of a full ledger snapshot file in the Hornet format, to compute the balances of
+//! addresses without a node.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use packable::PackableExt;
+
+use crate::{
+    client::{Error, Result},
+    types::block::{
+        address::Address,
+        output::{NativeToken, NativeTokensBuilder, Output, OutputId},
+        payload::milestone::{option::ParametersMilestoneOption, MilestoneId},
+        protocol::ProtocolParameters,
+        Block, BlockId,
+    },
+};
+
+/// The version of the snapshot format of the Stardust protocol.
+pub const SNAPSHOT_VERSION: u8 = 2;
+/// The snapshot type of a full snapshot, delta snapshots don't contain the ledger.
+const FULL_SNAPSHOT_TYPE: u8 = 0;
+
+/// The header of a full snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotHeader {
+    /// The index of the genesis milestone of the network.
+    pub genesis_milestone_index: u32,
+    /// The index of the milestone the snapshot was taken at.
+    pub target_milestone_index: u32,
+    /// The timestamp of the milestone the snapshot was taken at.
+    pub target_milestone_timestamp: u32,
+    /// The ID of the milestone the snapshot was taken at.
+    pub target_milestone_id: MilestoneId,
+    /// The index of the milestone the unspent outputs of the snapshot belong to.
+    pub ledger_milestone_index: u32,
+    /// The ID of the milestone that created the treasury output.
+    pub treasury_output_milestone_id: MilestoneId,
+    /// The amount of the treasury output.
+    pub treasury_output_amount: u64,
+    /// The protocol parameters of the network.
+    pub protocol_parameters: ProtocolParameters,
+    /// The amount of unspent outputs in the snapshot.
+    pub output_count: u64,
+    /// The amount of milestone diffs following the outputs.
+    pub milestone_diff_count: u32,
+    /// The amount of solid entry points following the milestone diffs.
+    pub solid_entry_point_count: u16,
+}
+
+/// An unspent output of a snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotOutput {
+    /// The ID of the output.
+    pub output_id: OutputId,
+    /// The ID of the block that created the output.
+    pub block_id: BlockId,
+    /// The index of the milestone that booked the output.
+    pub milestone_index_booked: u32,
+    /// The timestamp of the milestone that booked the output.
+    pub milestone_timestamp_booked: u32,
+    /// The output.
+    pub output: Output,
+}
+
+/// The funds an address owns in the ledger of a snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressBalance {
+    /// The address.
+    pub address: Address,
+    /// The amount of base coins of the outputs.
+    pub base_coin: u64,
+    /// The native tokens of the outputs.
+    pub native_tokens: Vec<NativeToken>,
+    /// The outputs owned by the address.
+    pub output_ids: Vec<OutputId>,
+}
+
+/// Reads a full snapshot in the Hornet format. The header is read when the reader is created, the unspent outputs
+/// are then read one by one, so the snapshot doesn't need to fit into memory.
+#[derive(Debug)]
+pub struct SnapshotReader<R: Read> {
+    reader: R,
+    header: SnapshotHeader,
+    remaining_outputs: u64,
+}
+
+impl SnapshotReader<BufReader<File>> {
+    /// Opens the snapshot file at the path and reads its header.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> SnapshotReader<R> {
+    /// Reads the header of the snapshot.
+    pub fn new(mut reader: R) -> Result<Self> {
+        let version = read_u8(&mut reader)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(Error::InvalidSnapshot(format!(
+                "unsupported version {version}, expected {SNAPSHOT_VERSION}"
+            )));
+        }
+        let snapshot_type = read_u8(&mut reader)?;
+        if snapshot_type != FULL_SNAPSHOT_TYPE {
+            return Err(Error::InvalidSnapshot(
+                "only full snapshots contain the ledger".to_string(),
+            ));
+        }
+
+        let genesis_milestone_index = read_u32(&mut reader)?;
+        let target_milestone_index = read_u32(&mut reader)?;
+        let target_milestone_timestamp = read_u32(&mut reader)?;
+        let target_milestone_id = MilestoneId::new(read_array(&mut reader)?);
+        let ledger_milestone_index = read_u32(&mut reader)?;
+        let treasury_output_milestone_id = MilestoneId::new(read_array(&mut reader)?);
+        let treasury_output_amount = read_u64(&mut reader)?;
+
+        // The protocol parameters are stored as serialized milestone option
+        let milestone_option = read_bytes(&mut reader, read_u16(&mut reader)? as usize, Block::LENGTH_MAX)?;
+        let Some((&ParametersMilestoneOption::KIND, milestone_option)) = milestone_option.split_first() else {
+            return Err(Error::InvalidSnapshot(
+                "missing protocol parameters milestone option".to_string(),
+            ));
+        };
+        let parameters_milestone_option = ParametersMilestoneOption::unpack_verified(milestone_option, &())?;
+        let protocol_parameters =
+            ProtocolParameters::unpack_verified(parameters_milestone_option.binary_parameters(), &())?;
+
+        let header = SnapshotHeader {
+            genesis_milestone_index,
+            target_milestone_index,
+            target_milestone_timestamp,
+            target_milestone_id,
+            ledger_milestone_index,
+            treasury_output_milestone_id,
+            treasury_output_amount,
+            protocol_parameters,
+            output_count: read_u64(&mut reader)?,
+            milestone_diff_count: read_u32(&mut reader)?,
+            solid_entry_point_count: read_u16(&mut reader)?,
+        };
+
+        Ok(Self {
+            reader,
+            remaining_outputs: header.output_count,
+            header,
+        })
+    }
+
+    /// Returns the header of the snapshot.
+    pub fn header(&self) -> &SnapshotHeader {
+        &self.header
+    }
+
+    /// Reads the next unspent output, returns `None` once all outputs were read.
+    pub fn next_output(&mut self) -> Result<Option<SnapshotOutput>> {
+        if self.remaining_outputs == 0 {
+            return Ok(None);
+        }
+        self.remaining_outputs -= 1;
+
+        let output_id = OutputId::try_from(read_array::<{ OutputId::LENGTH }>(&mut self.reader)?)?;
+        let block_id = BlockId::new(read_array(&mut self.reader)?);
+        let milestone_index_booked = read_u32(&mut self.reader)?;
+        let milestone_timestamp_booked = read_u32(&mut self.reader)?;
+        let output_length = read_u32(&mut self.reader)? as usize;
+        let output = Output::unpack_verified(
+            // An output can't be larger than the block that created it
+            read_bytes(&mut self.reader, output_length, Block::LENGTH_MAX)?,
+            &self.header.protocol_parameters,
+        )?;
+
+        Ok(Some(SnapshotOutput {
+            output_id,
+            block_id,
+            milestone_index_booked,
+            milestone_timestamp_booked,
+            output,
+        }))
+    }
+
+    /// Reads all unspent outputs and sums up the funds owned by the addresses, in the order of the addresses.
+    ///
+    /// The balances are the ones of the ledger at [`SnapshotHeader::ledger_milestone_index`], milestone diffs aren't
+    /// applied. An output is owned by the address that can unlock it at the target milestone timestamp, i.e. the
+    /// return address of an expired output, or the state controller of an alias output. Timelocked outputs are
+    /// included.
+    pub fn balances_for_addresses(
+        mut self,
+        addresses: impl IntoIterator<Item = Address>,
+    ) -> Result<Vec<AddressBalance>> {
+        let addresses = addresses.into_iter().collect::<Vec<_>>();
+        let mut funds = addresses
+            .iter()
+            .map(|address| (*address, (0, NativeTokensBuilder::new(), Vec::new())))
+            .collect::<HashMap<_, _>>();
+        let milestone_timestamp = self.header.target_milestone_timestamp;
+
+        while let Some(snapshot_output) = self.next_output()? {
+            let output = &snapshot_output.output;
+            let owner = match output {
+                Output::Alias(alias) => Some(alias.state_controller_address()),
+                _ => output.locked_address(milestone_timestamp),
+            };
+
+            if let Some((base_coin, native_tokens, output_ids)) = owner.and_then(|owner| funds.get_mut(owner)) {
+                *base_coin += output.amount();
+                if let Some(output_native_tokens) = output.native_tokens() {
+                    native_tokens.add_native_tokens(output_native_tokens.clone())?;
+                }
+                output_ids.push(snapshot_output.output_id);
+            }
+        }
+
+        addresses
+            .into_iter()
+            .map(|address| {
+                // The funds of an address that was passed more than once are only returned for the first one
+                let (base_coin, native_tokens, output_ids) = funds.remove(&address).unwrap_or_default();
+                Ok(AddressBalance {
+                    address,
+                    base_coin,
+                    native_tokens: native_tokens.finish_vec()?,
+                    output_ids,
+                })
+            })
+            .collect()
+    }
+}
+
+impl<R: Read> Iterator for SnapshotReader<R> {
+    type Item = Result<SnapshotOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_output().transpose()
+    }
+}
+
+// The length is read from the file, so it's checked before allocating the bytes
+fn read_bytes(reader: &mut impl Read, length: usize, max_length: usize) -> Result<Vec<u8>> {
+    if length > max_length {
+        return Err(Error::InvalidSnapshot(format!(
+            "length {length} exceeds the maximum length {max_length}"
+        )));
+    }
+    let mut bytes = vec![0; length];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_u8(reader: &mut impl Read) -> Result<u8> {
+    Ok(u8::from_le_bytes(read_array(reader)?))
+}
+
+fn read_u16(reader: &mut impl Read) -> Result<u16> {
+    Ok(u16::from_le_bytes(read_array(reader)?))
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<u32> {
+    Ok(u32::from_le_bytes(read_array(reader)?))
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<u64> {
+    Ok(u64::from_le_bytes(read_array(reader)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address,
+        output::{
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
+            BasicOutputBuilder,
+        },
+        payload::transaction::TransactionId,
+    };
+
+    fn snapshot(protocol_parameters: &ProtocolParameters, outputs: &[(OutputId, Output)]) -> Vec<u8> {
+        let mut snapshot = vec![SNAPSHOT_VERSION, FULL_SNAPSHOT_TYPE];
+        snapshot.extend(0u32.to_le_bytes());
+        snapshot.extend(10u32.to_le_bytes());
+        snapshot.extend(1_000u32.to_le_bytes());
+        snapshot.extend([0; MilestoneId::LENGTH]);
+        snapshot.extend(10u32.to_le_bytes());
+        snapshot.extend([0; MilestoneId::LENGTH]);
+        snapshot.extend(0u64.to_le_bytes());
+
+        let mut milestone_option = vec![ParametersMilestoneOption::KIND];
+        milestone_option.extend(
+            ParametersMilestoneOption::new(0.into(), 2, protocol_parameters.pack_to_vec())
+                .unwrap()
+                .pack_to_vec(),
+        );
+        snapshot.extend((milestone_option.len() as u16).to_le_bytes());
+        snapshot.extend(milestone_option);
+
+        snapshot.extend((outputs.len() as u64).to_le_bytes());
+        snapshot.extend(0u32.to_le_bytes());
+        snapshot.extend(0u16.to_le_bytes());
+
+        for (output_id, output) in outputs {
+            let output = output.pack_to_vec();
+            snapshot.extend(output_id.pack_to_vec());
+            snapshot.extend([0; BlockId::LENGTH]);
+            snapshot.extend(1u32.to_le_bytes());
+            snapshot.extend(100u32.to_le_bytes());
+            snapshot.extend((output.len() as u32).to_le_bytes());
+            snapshot.extend(output);
+        }
+
+        snapshot
+    }
+
+    #[test]
+    fn balances_for_addresses() {
+        let protocol_parameters = ProtocolParameters::default();
+        let token_supply = protocol_parameters.token_supply();
+        let address = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let return_address = Address::from(Ed25519Address::new([2; Ed25519Address::LENGTH]));
+        let other_address = Address::from(Ed25519Address::new([3; Ed25519Address::LENGTH]));
+        let output_id = |index| OutputId::new(TransactionId::new([4; TransactionId::LENGTH]), index).unwrap();
+
+        let outputs = [
+            (
+                output_id(0),
+                BasicOutputBuilder::new_with_amount(1_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish_output(token_supply)
+                    .unwrap(),
+            ),
+            // Expired at the target milestone timestamp, so it's owned by the return address
+            (
+                output_id(1),
+                BasicOutputBuilder::new_with_amount(2_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .add_unlock_condition(ExpirationUnlockCondition::new(return_address, 500).unwrap())
+                    .finish_output(token_supply)
+                    .unwrap(),
+            ),
+            (
+                output_id(2),
+                BasicOutputBuilder::new_with_amount(3_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(other_address))
+                    .finish_output(token_supply)
+                    .unwrap(),
+            ),
+        ];
+
+        let reader = SnapshotReader::new(snapshot(&protocol_parameters, &outputs).as_slice()).unwrap();
+        assert_eq!(reader.header().target_milestone_timestamp, 1_000);
+        assert_eq!(reader.header().output_count, 3);
+        assert_eq!(reader.header().protocol_parameters, protocol_parameters);
+
+        let balances = reader.balances_for_addresses([address, return_address]).unwrap();
+        assert_eq!(
+            balances,
+            vec![
+                AddressBalance {
+                    address,
+                    base_coin: 1_000_000,
+                    native_tokens: Vec::new(),
+                    output_ids: vec![output_id(0)],
+                },
+                AddressBalance {
+                    address: return_address,
+                    base_coin: 2_000_000,
+                    native_tokens: Vec::new(),
+                    output_ids: vec![output_id(1)],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn output_length_exceeding_block_length() {
+        let protocol_parameters = ProtocolParameters::default();
+        let output_id = OutputId::new(TransactionId::new([4; TransactionId::LENGTH]), 0).unwrap();
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(Ed25519Address::new(
+                [1; Ed25519Address::LENGTH],
+            )))
+            .finish_output(protocol_parameters.token_supply())
+            .unwrap();
+        let mut snapshot = snapshot(&protocol_parameters, &[(output_id, output.clone())]);
+        // Replace the output length, which precedes the output, with one that's too large to be allocated
+        let output_length_position = snapshot.len() - output.pack_to_vec().len() - core::mem::size_of::<u32>();
+        snapshot[output_length_position..output_length_position + core::mem::size_of::<u32>()]
+            .copy_from_slice(&u32::MAX.to_le_bytes());
+
+        let mut reader = SnapshotReader::new(snapshot.as_slice()).unwrap();
+        assert!(matches!(reader.next_output(), Err(Error::InvalidSnapshot(_))));
+    }
+}
@@ -34,6 +34,9 @@ pub enum Error {
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
+    /// Invalid snapshot file
+    #[error("invalid snapshot file")]
+    InvalidSnapshotFile,
     /// Unsupported snapshot version
     #[error("unsupported snapshot version, expected {expected}, found {found}, migration required")]
     UnsupportedSnapshotVersion {
@@ -51,6 +51,7 @@ mod error;
 mod migration;
 mod secret;
 mod storage;
+mod verification;
 
 use std::{
     path::{Path, PathBuf},
@@ -68,8 +69,8 @@ use tokio::{
 use zeroize::Zeroizing;
 
 pub(crate) use self::common::PRIVATE_DATA_CLIENT_PATH;
-pub use self::error::Error;
-use super::{storage::StorageAdapter, utils::Password};
+pub use self::{error::Error, verification::SnapshotVerification};
+use super::{secret::address_cache::AddressCache, storage::StorageAdapter, utils::Password};
 
 /// A wrapper on [Stronghold].
 ///
@@ -111,6 +112,10 @@ pub struct StrongholdAdapter {
     /// The path to a Stronghold snapshot file.
     #[builder(setter(skip))]
     pub(crate) snapshot_path: PathBuf,
+
+    /// Generated addresses, by coin type, account index, internal flag and address index.
+    #[builder(setter(skip))]
+    pub(crate) address_cache: Arc<Mutex<AddressCache>>,
 }
 
 fn check_or_create_snapshot(
@@ -186,6 +191,7 @@ impl StrongholdAdapterBuilder {
         let has_key_provider = self.key_provider.is_some();
         let key_provider = Arc::new(Mutex::new(self.key_provider));
         let stronghold = Arc::new(Mutex::new(stronghold));
+        let address_cache = Arc::new(Mutex::new(AddressCache::default()));
 
         // If both `key` and `timeout` are set, then we spawn the task and keep its join handle.
         if let (true, Some(Some(timeout))) = (has_key_provider, self.timeout) {
@@ -200,10 +206,12 @@ impl StrongholdAdapterBuilder {
             // itself is a `fn`. There is also a small delay from the return of this function to the task actually being
             // spawned and set in the `struct`.
             let stronghold_clone = stronghold.clone();
+            let address_cache_clone = address_cache.clone();
             tokio::spawn(async move {
                 *task_self.lock().await = Some(tokio::spawn(task_key_clear(
                     task_self.clone(), // LHS moves task_self
                     stronghold_clone,
+                    address_cache_clone,
                     key_provider,
                     timeout,
                 )));
@@ -220,6 +228,7 @@ impl StrongholdAdapterBuilder {
             timeout: self.timeout.unwrap_or(None),
             timeout_task: self.timeout_task.unwrap_or_else(|| Arc::new(Mutex::new(None))),
             snapshot_path: snapshot_path.as_ref().to_path_buf(),
+            address_cache,
         })
     }
 }
@@ -276,6 +285,7 @@ impl StrongholdAdapter {
             *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
                 task_self,
                 self.stronghold.clone(),
+                self.address_cache.clone(),
                 key_provider,
                 timeout,
             )));
@@ -288,11 +298,9 @@ impl StrongholdAdapter {
     ///
     /// If a snapshot path has been set, then it'll be rewritten with the newly set password.
     ///
-    /// The secrets (e.g. mnemonic) stored in the Stronghold vault will be preserved, but the data saved via the
-    /// [`StorageAdapter`] interface won't - they'll stay encrypted with the old password. To re-encrypt these
-    /// data, provide a list of keys in `keys_to_re_encrypt`, as we have no way to list and iterate over every
-    /// key-value in the Stronghold store - we'll attempt on the ones provided instead. Set it to `None` to skip
-    /// re-encryption.
+    /// The secrets (e.g. mnemonic) stored in the Stronghold vault will be preserved and the data saved via the
+    /// [`StorageAdapter`] interface will be re-encrypted with the new password. The password has to be set before,
+    /// see [`rotate_password()`](Self::rotate_password) to also provide the current password.
     pub async fn change_password(&self, new_password: impl Into<Password> + Send) -> Result<(), Error> {
         let new_password = new_password.into();
 
@@ -336,6 +344,7 @@ impl StrongholdAdapter {
                         *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
                             task_self,
                             self.stronghold.clone(),
+                            self.address_cache.clone(),
                             key_provider,
                             timeout,
                         )));
@@ -379,6 +388,7 @@ impl StrongholdAdapter {
                     *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
                         task_self,
                         self.stronghold.clone(),
+                        self.address_cache.clone(),
                         key_provider,
                         timeout,
                     )));
@@ -400,6 +410,7 @@ impl StrongholdAdapter {
             *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
                 task_self,
                 self.stronghold.clone(),
+                self.address_cache.clone(),
                 key_provider,
                 timeout,
             )));
@@ -408,6 +419,31 @@ impl StrongholdAdapter {
         Ok(())
     }
 
+    /// Change the password from `current_password` to `new_password`, e.g. to rotate a compromised password without
+    /// recreating the snapshot from the mnemonic.
+    ///
+    /// The current password is verified first, so it doesn't need to be set before. After the snapshot file has been
+    /// rewritten with the new password, it's loaded into a separate Stronghold instance to make sure it can be opened
+    /// with the new password.
+    pub async fn rotate_password(
+        &self,
+        current_password: impl Into<Password> + Send,
+        new_password: impl Into<Password> + Send,
+    ) -> Result<(), Error> {
+        self.set_password(current_password).await?;
+        self.change_password(new_password).await?;
+
+        let locked_key_provider = self.key_provider.lock().await;
+        let key_provider = locked_key_provider.as_ref().ok_or(Error::KeyCleared)?;
+        Stronghold::default().load_client_from_snapshot(
+            PRIVATE_DATA_CLIENT_PATH,
+            key_provider,
+            &SnapshotPath::from_path(&self.snapshot_path),
+        )?;
+
+        Ok(())
+    }
+
     /// Immediately clear ([zeroize]) the stored key.
     ///
     /// If a key clearing thread has been spawned, then it'll be stopped too.
@@ -460,6 +496,7 @@ impl StrongholdAdapter {
             *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
                 task_self,
                 self.stronghold.clone(),
+                self.address_cache.clone(),
                 key_provider,
                 timeout,
             )));
@@ -488,6 +525,8 @@ impl StrongholdAdapter {
             key_provider,
             &SnapshotPath::from_path(&self.snapshot_path),
         )?;
+        // The snapshot may contain another seed
+        self.clear_address_cache().await;
 
         Ok(())
     }
@@ -530,20 +569,27 @@ impl StrongholdAdapter {
         self.write_stronghold_snapshot(None).await?;
 
         self.stronghold.lock().await.clear()?;
+        self.clear_address_cache().await;
 
         Ok(())
     }
 
+    /// Clears all cached addresses. Needs to be called if the seed is changed through [`Self::inner()`].
+    pub async fn clear_address_cache(&self) {
+        self.address_cache.lock().await.clear();
+    }
+
     /// Acquire the stronghold lock.
     pub async fn inner(&self) -> MutexGuard<'_, Stronghold> {
         self.stronghold.lock().await
     }
 }
 
-/// The asynchronous key clearing task purging `key` after `timeout` spent in Tokio.
+/// The asynchronous key clearing task purging `key` and the cached addresses after `timeout` spent in Tokio.
 async fn task_key_clear(
     task_self: Arc<Mutex<Option<JoinHandle<()>>>>,
     stronghold: Arc<Mutex<Stronghold>>,
+    address_cache: Arc<Mutex<AddressCache>>,
     key_provider: Arc<Mutex<Option<KeyProvider>>>,
     timeout: Duration,
 ) {
@@ -554,6 +600,8 @@ async fn task_key_clear(
 
     // TODO handle error
     stronghold.lock().await.clear().unwrap();
+    // The addresses can't be derived without the key either
+    address_cache.lock().await.clear();
 
     // Take self, but do nothing (we're exiting anyways).
     task_self.lock().await.take();
@@ -564,6 +612,7 @@ mod tests {
     use std::fs;
 
     use super::*;
+    use crate::types::block::address::Ed25519Address;
 
     #[tokio::test]
     async fn test_clear_key() {
@@ -584,11 +633,19 @@ mod tests {
         assert_eq!(adapter.get_timeout(), Some(timeout));
         assert!(adapter.timeout_task.lock().await.is_some());
 
-        // After the timeout, the key should be purged.
+        let address_cache_key = (4218, 0, false, 0);
+        adapter
+            .address_cache
+            .lock()
+            .await
+            .insert(address_cache_key, Ed25519Address::new([1; 32]));
+
+        // After the timeout, the key and the cached addresses should be purged.
         tokio::time::sleep(Duration::from_millis(200)).await;
         assert!(adapter.key_provider.lock().await.is_none());
         assert_eq!(adapter.get_timeout(), Some(timeout));
         assert!(adapter.timeout_task.lock().await.is_none());
+        assert!(adapter.address_cache.lock().await.get(&address_cache_key).is_none());
 
         // Set the key again, but this time we manually purge the key.
         let timeout = None;
@@ -628,4 +685,45 @@ mod tests {
 
         fs::remove_file(stronghold_path).unwrap();
     }
+
+    #[tokio::test]
+    async fn stronghold_rotate_password() {
+        let stronghold_path = "stronghold_rotate_password.stronghold";
+        let adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+        adapter.set_bytes("key", b"value").await.unwrap();
+
+        // A wrong current password is rejected
+        assert!(matches!(
+            adapter
+                .rotate_password("other_password".to_owned(), "new_password".to_owned())
+                .await,
+            Err(Error::InvalidPassword)
+        ));
+
+        adapter.clear_key().await;
+        adapter
+            .rotate_password("drowssap".to_owned(), "new_password".to_owned())
+            .await
+            .unwrap();
+        assert_eq!(adapter.get_bytes("key").await.unwrap().as_deref(), Some(&b"value"[..]));
+
+        // The snapshot file can only be opened with the new password
+        drop(adapter);
+        assert!(matches!(
+            StrongholdAdapter::builder()
+                .password("drowssap".to_owned())
+                .build(stronghold_path),
+            Err(Error::InvalidPassword)
+        ));
+        let adapter = StrongholdAdapter::builder()
+            .password("new_password".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+        assert_eq!(adapter.get_bytes("key").await.unwrap().as_deref(), Some(&b"value"[..]));
+
+        fs::remove_file(stronghold_path).unwrap();
+    }
 }
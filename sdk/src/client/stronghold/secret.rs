@@ -67,7 +67,17 @@ impl SecretManage for StrongholdAdapter {
         let mut addresses = Vec::new();
         let internal = options.into().map(|o| o.internal).unwrap_or_default();
 
-        for address_index in address_indexes {
+        // Deriving addresses in the vault is slow, so they're cached
+        if let Some(addresses) =
+            self.address_cache
+                .lock()
+                .await
+                .get_range(coin_type, account_index, internal, address_indexes.clone())
+        {
+            return Ok(addresses);
+        }
+
+        for address_index in address_indexes.clone() {
             let chain = Bip44::new(coin_type)
                 .with_account(account_index)
                 .with_change(internal as _)
@@ -113,6 +123,11 @@ impl SecretManage for StrongholdAdapter {
             addresses.push(address);
         }
 
+        self.address_cache
+            .lock()
+            .await
+            .insert_range(coin_type, account_index, internal, address_indexes, &addresses);
+
         Ok(addresses)
     }
 
@@ -487,6 +502,7 @@ impl StrongholdAdapter {
 
         // Persist Stronghold to the disk
         self.write_stronghold_snapshot(None).await?;
+        self.clear_address_cache().await;
 
         Ok(())
     }
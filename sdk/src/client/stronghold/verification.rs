@@ -0,0 +1,160 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs::File, io::Read, path::Path};
+
+use crypto::ciphers::{chacha::XChaCha20Poly1305, traits::Aead};
+use iota_stronghold::{
+    engine::snapshot::{MAGIC, VERSION},
+    procedures::{self, AeadCipher},
+    Client, KeyProvider, Location, SnapshotPath, Stronghold,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    common::{
+        key_provider_from_password, PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH, SEED_RECORD_PATH,
+        USERDATA_STORE_KEY_RECORD_PATH,
+    },
+    Error, StrongholdAdapter,
+};
+use crate::client::utils::Password;
+
+/// The result of verifying a Stronghold snapshot.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotVerification {
+    /// Whether the vault contains a seed record, i.e. a mnemonic has been stored.
+    pub has_mnemonic: bool,
+    /// Keys of the store records that could be decrypted.
+    pub valid_store_records: Vec<String>,
+    /// Keys of the store records that couldn't be decrypted.
+    pub corrupted_store_records: Vec<String>,
+}
+
+impl SnapshotVerification {
+    /// Returns whether no corrupted record was found.
+    pub fn is_intact(&self) -> bool {
+        self.corrupted_store_records.is_empty()
+    }
+}
+
+impl StrongholdAdapter {
+    /// Verifies a snapshot without loading it into a wallet.
+    ///
+    /// Checks the header and version, the integrity of the encrypted content, and that every record of the store can
+    /// be decrypted. A failed integrity check of the content can't be distinguished from a wrong password, both result
+    /// in [`Error::InvalidPassword`].
+    pub fn verify_snapshot<P: AsRef<Path>>(path: P, password: Password) -> Result<SnapshotVerification, Error> {
+        log::debug!("verify_snapshot");
+
+        let (_, client) = load_snapshot(path.as_ref(), &key_provider_from_password(password))?;
+
+        verify_client(&client)
+    }
+
+    /// Writes the readable parts of a snapshot to `recovered_path`, dropping the store records that couldn't be
+    /// decrypted, e.g. to extract the seed when other records are corrupted.
+    ///
+    /// Returns the verification of the original snapshot.
+    pub fn repair_snapshot<P: AsRef<Path>>(
+        path: P,
+        password: Password,
+        recovered_path: P,
+    ) -> Result<SnapshotVerification, Error> {
+        log::debug!("repair_snapshot");
+
+        if recovered_path.as_ref().exists() {
+            return Err(Error::PathAlreadyExists(recovered_path.as_ref().to_path_buf()));
+        }
+
+        let key_provider = key_provider_from_password(password);
+        let (stronghold, client) = load_snapshot(path.as_ref(), &key_provider)?;
+        let verification = verify_client(&client)?;
+
+        for key in &verification.corrupted_store_records {
+            client.store().delete(key.as_bytes())?;
+        }
+
+        stronghold.commit_with_keyprovider(&SnapshotPath::from_path(recovered_path), &key_provider)?;
+
+        Ok(verification)
+    }
+}
+
+// Checks the magic bytes and the version of a snapshot file.
+fn check_snapshot_header(path: &Path) -> Result<(), Error> {
+    let mut header = [0u8; MAGIC.len() + VERSION.len()];
+    File::open(path)?
+        .read_exact(&mut header)
+        .map_err(|_| Error::InvalidSnapshotFile)?;
+
+    if header[..MAGIC.len()] != MAGIC {
+        return Err(Error::InvalidSnapshotFile);
+    }
+    if header[MAGIC.len()..] != VERSION {
+        return Err(Error::UnsupportedSnapshotVersion {
+            found: header[MAGIC.len()] as u16,
+            expected: VERSION[0] as u16,
+        });
+    }
+
+    Ok(())
+}
+
+fn load_snapshot(path: &Path, key_provider: &KeyProvider) -> Result<(Stronghold, Client), Error> {
+    check_snapshot_header(path)?;
+
+    let stronghold = Stronghold::default();
+    let client = stronghold
+        .load_client_from_snapshot(PRIVATE_DATA_CLIENT_PATH, key_provider, &SnapshotPath::from_path(path))
+        .map_err(|err| match err {
+            // Matching the error string is not ideal but stronghold doesn't wrap the error types at the moment.
+            iota_stronghold::ClientError::Inner(ref err_msg)
+                if err_msg.contains("XCHACHA20-POLY1305") || err_msg.contains("BadFileKey") =>
+            {
+                Error::InvalidPassword
+            }
+            err => err.into(),
+        })?;
+
+    Ok((stronghold, client))
+}
+
+fn verify_client(client: &Client) -> Result<SnapshotVerification, Error> {
+    let mut verification = SnapshotVerification {
+        has_mnemonic: client.record_exists(&Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH))?,
+        ..Default::default()
+    };
+    let store_key_location = Location::generic(SECRET_VAULT_PATH, USERDATA_STORE_KEY_RECORD_PATH);
+
+    for key in client.store().keys()? {
+        let key = String::from_utf8_lossy(&key).into_owned();
+        let decrypted = match client.store().get(key.as_bytes())? {
+            Some(data) if data.len() >= XChaCha20Poly1305::NONCE_LENGTH + XChaCha20Poly1305::TAG_LENGTH => {
+                let (nonce, data) = data.split_at(XChaCha20Poly1305::NONCE_LENGTH);
+                let (tag, ciphertext) = data.split_at(XChaCha20Poly1305::TAG_LENGTH);
+
+                client
+                    .execute_procedure(procedures::AeadDecrypt {
+                        cipher: AeadCipher::XChaCha20Poly1305,
+                        associated_data: Vec::new(),
+                        nonce: nonce.to_vec(),
+                        tag: tag.to_vec(),
+                        ciphertext: ciphertext.to_vec(),
+                        key: store_key_location.clone(),
+                    })
+                    .is_ok()
+            }
+            _ => false,
+        };
+
+        if decrypted {
+            verification.valid_store_records.push(key);
+        } else {
+            verification.corrupted_store_records.push(key);
+        }
+    }
+
+    Ok(verification)
+}
@@ -0,0 +1,250 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Remembering the blocks posted for transactions, so posting a block again doesn't create another one
+
+use std::{collections::HashMap, time::Duration};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::{
+    types::block::{
+        payload::{transaction::TransactionId, Payload},
+        Block, BlockId,
+    },
+    utils::unix_timestamp_now,
+};
+
+/// A block posted for a transaction, identified by the hash of the transaction essence.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubmittedBlock {
+    #[serde(with = "crate::utils::serde::prefix_hex_bytes")]
+    pub(crate) essence_hash: [u8; 32],
+    pub(crate) transaction_id: TransactionId,
+    /// `None` while it's unknown, e.g. if the node does the PoW and the request timed out.
+    pub(crate) block_id: Option<BlockId>,
+    /// The UNIX timestamp in milliseconds at which the block was posted.
+    pub(crate) posted_at: u64,
+}
+
+impl SubmittedBlock {
+    fn is_expired(&self, now: u64, ttl: Duration) -> bool {
+        u128::from(now.saturating_sub(self.posted_at)) >= ttl.as_millis()
+    }
+}
+
+/// Persists the blocks posted for transactions, e.g. in the wallet storage, so they're also remembered after a restart.
+#[async_trait]
+pub(crate) trait SubmittedBlocksStore: std::fmt::Debug + Send + Sync {
+    /// Loads the persisted blocks.
+    async fn load(&self) -> Vec<SubmittedBlock>;
+
+    /// Replaces the persisted blocks.
+    async fn save(&self, blocks: Vec<SubmittedBlock>);
+}
+
+/// The blocks posted for transactions, by the hash of their transaction essence.
+///
+/// Posting a block again after a timeout would otherwise post the transaction again in another block, e.g. with
+/// another nonce if the node does the PoW. The essence hash is recorded before the block is posted, so a retry is
+/// recognized even if the ID of the block isn't known. Reattachments are posted with
+/// [`ClientInner::post_reattachment()`](crate::client::ClientInner::post_reattachment).
+#[derive(Debug, Default)]
+pub(crate) struct SubmittedBlocks {
+    blocks: Mutex<HashMap<[u8; 32], SubmittedBlock>>,
+    store: Mutex<Option<Box<dyn SubmittedBlocksStore>>>,
+}
+
+impl SubmittedBlocks {
+    /// Returns the hash of the transaction essence and the transaction ID, `None` if the block has no transaction.
+    /// Other payloads, like tagged data, may be posted more than once on purpose.
+    pub(crate) fn key(block: &Block) -> Option<([u8; 32], TransactionId)> {
+        match block.payload()? {
+            Payload::Transaction(transaction) => Some((transaction.essence().hash(), transaction.id())),
+            _ => None,
+        }
+    }
+
+    /// Persists the posted blocks in the store, and loads the ones it already has.
+    pub(crate) async fn set_store(&self, store: Box<dyn SubmittedBlocksStore>) {
+        let mut blocks = self.blocks.lock().await;
+
+        for block in store.load().await {
+            blocks.entry(block.essence_hash).or_insert(block);
+        }
+        *self.store.lock().await = Some(store);
+    }
+
+    /// Returns the block posted for the essence hash, if it was posted within the TTL.
+    pub(crate) async fn get(&self, essence_hash: &[u8; 32], ttl: Duration) -> Option<SubmittedBlock> {
+        let now = unix_timestamp_now().as_millis() as u64;
+
+        self.blocks
+            .lock()
+            .await
+            .get(essence_hash)
+            .filter(|block| !block.is_expired(now, ttl))
+            .cloned()
+    }
+
+    /// Remembers the block posted for the essence hash, and forgets the ones posted before the TTL.
+    pub(crate) async fn insert(
+        &self,
+        (essence_hash, transaction_id): ([u8; 32], TransactionId),
+        block_id: Option<BlockId>,
+        ttl: Duration,
+    ) {
+        let now = unix_timestamp_now().as_millis() as u64;
+        let mut blocks = self.blocks.lock().await;
+
+        blocks.retain(|_, block| !block.is_expired(now, ttl));
+        blocks.insert(
+            essence_hash,
+            SubmittedBlock {
+                essence_hash,
+                transaction_id,
+                block_id,
+                posted_at: now,
+            },
+        );
+        self.save(&blocks).await;
+    }
+
+    /// Forgets the block posted for the essence hash, e.g. because the node rejected it.
+    pub(crate) async fn remove(&self, essence_hash: &[u8; 32]) {
+        let mut blocks = self.blocks.lock().await;
+
+        if blocks.remove(essence_hash).is_some() {
+            self.save(&blocks).await;
+        }
+    }
+
+    // Called with the blocks locked, so the store is updated in the same order
+    async fn save(&self, blocks: &HashMap<[u8; 32], SubmittedBlock>) {
+        if let Some(store) = self.store.lock().await.as_ref() {
+            store.save(blocks.values().cloned().collect()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::types::block::{
+        input::{Input, UtxoInput},
+        output::Output,
+        payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionPayload},
+        protocol::protocol_parameters,
+        rand::{
+            block::rand_block_with_parents,
+            output::{rand_basic_output, rand_inputs_commitment, rand_output_id},
+            parents::rand_parents,
+            signature::rand_signature,
+        },
+        unlock::{SignatureUnlock, Unlock, Unlocks},
+        BlockBuilder,
+    };
+
+    #[derive(Debug, Default)]
+    struct MemoryStore(Arc<Mutex<Vec<SubmittedBlock>>>);
+
+    #[async_trait]
+    impl SubmittedBlocksStore for MemoryStore {
+        async fn load(&self) -> Vec<SubmittedBlock> {
+            self.0.lock().await.clone()
+        }
+
+        async fn save(&self, blocks: Vec<SubmittedBlock>) {
+            *self.0.lock().await = blocks;
+        }
+    }
+
+    fn transaction_block(nonce: u64) -> Block {
+        let protocol_parameters = protocol_parameters();
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+                .with_inputs([Input::Utxo(UtxoInput::from(rand_output_id()))])
+                .add_output(Output::Basic(rand_basic_output(protocol_parameters.token_supply())))
+                .finish_with_params(&protocol_parameters)
+                .unwrap(),
+        );
+        let unlocks = Unlocks::new([Unlock::Signature(SignatureUnlock::from(rand_signature()))]).unwrap();
+
+        BlockBuilder::new(rand_parents())
+            .with_payload(TransactionPayload::new(essence, unlocks).unwrap())
+            .with_nonce(nonce)
+            .finish()
+            .unwrap()
+    }
+
+    #[test]
+    fn key() {
+        assert!(SubmittedBlocks::key(&rand_block_with_parents(rand_parents())).is_none());
+
+        let block = transaction_block(0);
+        let rebuilt = BlockBuilder::new(rand_parents())
+            .with_payload(block.payload().cloned().unwrap())
+            .with_nonce(1)
+            .finish()
+            .unwrap();
+
+        // A block rebuilt for the same transaction, with other parents and nonce, is recognized
+        assert_eq!(SubmittedBlocks::key(&block), SubmittedBlocks::key(&rebuilt));
+        assert_ne!(
+            SubmittedBlocks::key(&block),
+            SubmittedBlocks::key(&transaction_block(0))
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_after_timeout() {
+        let submitted_blocks = SubmittedBlocks::default();
+        let ttl = Duration::from_millis(100);
+        let block = transaction_block(0);
+        let key = SubmittedBlocks::key(&block).unwrap();
+
+        assert_eq!(submitted_blocks.get(&key.0, ttl).await, None);
+        // Recorded before posting, while the block ID isn't known yet
+        submitted_blocks.insert(key, None, ttl).await;
+        assert_eq!(submitted_blocks.get(&key.0, ttl).await.unwrap().block_id, None);
+        submitted_blocks.insert(key, Some(block.id()), ttl).await;
+        // A retry within the TTL gets the posted block back
+        let submitted_block = submitted_blocks.get(&key.0, ttl).await.unwrap();
+        assert_eq!(submitted_block.block_id, Some(block.id()));
+        assert_eq!(submitted_block.transaction_id, key.1);
+
+        tokio::time::sleep(ttl).await;
+        assert_eq!(submitted_blocks.get(&key.0, ttl).await, None);
+
+        submitted_blocks.insert(key, Some(block.id()), ttl).await;
+        submitted_blocks.remove(&key.0).await;
+        assert_eq!(submitted_blocks.get(&key.0, ttl).await, None);
+    }
+
+    #[tokio::test]
+    async fn persisted() {
+        let store = MemoryStore::default();
+        let persisted = store.0.clone();
+        let ttl = Duration::from_secs(60);
+        let block = transaction_block(0);
+        let key = SubmittedBlocks::key(&block).unwrap();
+
+        let submitted_blocks = SubmittedBlocks::default();
+        submitted_blocks.set_store(Box::new(store)).await;
+        submitted_blocks.insert(key, None, ttl).await;
+        assert_eq!(persisted.lock().await.len(), 1);
+
+        // Another client, e.g. after a restart, knows that the transaction was already posted
+        let restarted = SubmittedBlocks::default();
+        restarted.set_store(Box::new(MemoryStore(persisted.clone()))).await;
+        assert_eq!(restarted.get(&key.0, ttl).await.unwrap().transaction_id, key.1);
+
+        restarted.remove(&key.0).await;
+        assert!(persisted.lock().await.is_empty());
+    }
+}
@@ -12,11 +12,12 @@ use crypto::{
     utils,
 };
 use serde::{Deserialize, Serialize};
+use url::Url;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::{Client, ClientInner};
 use crate::{
-    client::{Error, Result},
+    client::{constants::DEFAULT_API_TIMEOUT, node_manager::node::Node, Error, Result},
     types::block::{
         address::{Address, Bech32Address, Ed25519Address, Hrp, ToBech32Ext},
         output::{AliasId, NftId},
@@ -51,10 +52,43 @@ pub fn hex_public_key_to_bech32_address(hex: &str, bech32_hrp: impl ConvertTo<Hr
     Ok(Address::Ed25519(address).try_to_bech32(bech32_hrp)?)
 }
 
+#[cfg(feature = "seeded_rng")]
+static SEEDED_RNG: std::sync::Mutex<Option<rand::rngs::StdRng>> = std::sync::Mutex::new(None);
+
+/// Derives the randomness used by the SDK, like the entropy of generated mnemonics, from the given seed, so that test
+/// suites can produce reproducible results. Only meant for tests, never use it for wallets holding real funds.
+#[cfg(feature = "seeded_rng")]
+#[cfg_attr(docsrs, doc(cfg(feature = "seeded_rng")))]
+pub fn set_rng_seed(seed: u64) -> Result<()> {
+    use rand::SeedableRng;
+
+    *SEEDED_RNG.lock().map_err(|_| Error::PoisonError)? = Some(rand::rngs::StdRng::seed_from_u64(seed));
+    Ok(())
+}
+
+/// Removes the seed set with [`set_rng_seed()`], so that the operating system randomness is used again.
+#[cfg(feature = "seeded_rng")]
+#[cfg_attr(docsrs, doc(cfg(feature = "seeded_rng")))]
+pub fn clear_rng_seed() -> Result<()> {
+    *SEEDED_RNG.lock().map_err(|_| Error::PoisonError)? = None;
+    Ok(())
+}
+
+/// Fills the buffer with random bytes, taken from the seeded generator if one was set.
+pub(crate) fn fill_random(dest: &mut [u8]) -> Result<()> {
+    #[cfg(feature = "seeded_rng")]
+    if let Some(rng) = SEEDED_RNG.lock().map_err(|_| Error::PoisonError)?.as_mut() {
+        rand::RngCore::fill_bytes(rng, dest);
+        return Ok(());
+    }
+    utils::rand::fill(dest)?;
+    Ok(())
+}
+
 /// Generates a new mnemonic.
 pub fn generate_mnemonic() -> Result<Mnemonic> {
     let mut entropy = [0u8; 32];
-    utils::rand::fill(&mut entropy)?;
+    fill_random(&mut entropy)?;
     let mnemonic = wordlist::encode(&entropy, &crypto::keys::bip39::wordlist::ENGLISH)
         .map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")))?;
     entropy.zeroize();
@@ -83,7 +117,8 @@ pub fn verify_mnemonic(mnemonic: impl Borrow<MnemonicRef>) -> Result<()> {
     Ok(())
 }
 
-/// Requests funds from a faucet
+/// Requests funds from a faucet, without a proxy, see [`ClientInner::request_funds_from_faucet()`] to send the request
+/// through the proxy of a client.
 pub async fn request_funds_from_faucet(url: &str, bech32_address: &Bech32Address) -> Result<String> {
     let mut map = HashMap::new();
     map.insert("address", bech32_address.to_string());
@@ -102,16 +137,35 @@ pub async fn request_funds_from_faucet(url: &str, bech32_address: &Bech32Address
 }
 
 impl ClientInner {
+    /// Returns the provided bech32 HRP, which has to be the one of the network profile if the client has one, or the
+    /// one of the connected network.
+    async fn bech32_hrp_or_network(&self, bech32_hrp: Option<impl ConvertTo<Hrp>>) -> Result<Hrp> {
+        match bech32_hrp {
+            Some(bech32_hrp) => {
+                let bech32_hrp = bech32_hrp.convert()?;
+                self.network_info.read().await.check_network_profile(&bech32_hrp)?;
+                Ok(bech32_hrp)
+            }
+            None => self.get_bech32_hrp().await,
+        }
+    }
+
+    /// Transforms an address to a bech32 encoded address
+    pub async fn address_to_bech32(
+        &self,
+        address: impl Into<Address>,
+        bech32_hrp: Option<impl ConvertTo<Hrp>>,
+    ) -> crate::client::Result<Bech32Address> {
+        Ok(address.into().to_bech32(self.bech32_hrp_or_network(bech32_hrp).await?))
+    }
+
     /// Transforms a hex encoded address to a bech32 encoded address
     pub async fn hex_to_bech32(
         &self,
         hex: &str,
         bech32_hrp: Option<impl ConvertTo<Hrp>>,
     ) -> crate::client::Result<Bech32Address> {
-        match bech32_hrp {
-            Some(hrp) => Ok(hex_to_bech32(hex, hrp)?),
-            None => Ok(hex_to_bech32(hex, self.get_bech32_hrp().await?)?),
-        }
+        hex_to_bech32(hex, self.bech32_hrp_or_network(bech32_hrp).await?)
     }
 
     /// Transforms an alias id to a bech32 encoded address
@@ -120,10 +174,7 @@ impl ClientInner {
         alias_id: AliasId,
         bech32_hrp: Option<impl ConvertTo<Hrp>>,
     ) -> crate::client::Result<Bech32Address> {
-        match bech32_hrp {
-            Some(hrp) => Ok(alias_id.to_bech32(hrp.convert()?)),
-            None => Ok(alias_id.to_bech32(self.get_bech32_hrp().await?)),
-        }
+        Ok(alias_id.to_bech32(self.bech32_hrp_or_network(bech32_hrp).await?))
     }
 
     /// Transforms an nft id to a bech32 encoded address
@@ -132,10 +183,7 @@ impl ClientInner {
         nft_id: NftId,
         bech32_hrp: Option<impl ConvertTo<Hrp>>,
     ) -> crate::client::Result<Bech32Address> {
-        match bech32_hrp {
-            Some(hrp) => Ok(nft_id.to_bech32(hrp.convert()?)),
-            None => Ok(nft_id.to_bech32(self.get_bech32_hrp().await?)),
-        }
+        Ok(nft_id.to_bech32(self.bech32_hrp_or_network(bech32_hrp).await?))
     }
 
     /// Transforms a hex encoded public key to a bech32 encoded address
@@ -144,10 +192,27 @@ impl ClientInner {
         hex: &str,
         bech32_hrp: Option<impl ConvertTo<Hrp>>,
     ) -> crate::client::Result<Bech32Address> {
-        match bech32_hrp {
-            Some(hrp) => Ok(hex_public_key_to_bech32_address(hex, hrp)?),
-            None => Ok(hex_public_key_to_bech32_address(hex, self.get_bech32_hrp().await?)?),
-        }
+        hex_public_key_to_bech32_address(hex, self.bech32_hrp_or_network(bech32_hrp).await?)
+    }
+
+    /// Requests funds from a faucet, the request is sent through the proxy of the client.
+    pub async fn request_funds_from_faucet(&self, url: &str, bech32_address: &Bech32Address) -> Result<String> {
+        let http_client = self.node_manager.read().await.http_client.clone();
+        let faucet_response = http_client
+            .post_json(
+                Node {
+                    url: Url::parse(url)?,
+                    auth: None,
+                    disabled: false,
+                },
+                DEFAULT_API_TIMEOUT,
+                serde_json::json!({ "address": bech32_address }),
+            )
+            .await?
+            .into_text()
+            .await?;
+
+        Ok(faucet_response)
     }
 }
 
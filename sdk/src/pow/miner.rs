@@ -5,10 +5,11 @@
 
 use std::{
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
     },
     thread,
+    time::{Duration, Instant},
 };
 
 use crypto::{
@@ -25,6 +26,14 @@ use crypto::{
 
 use crate::pow::{score::count_trailing_zeros, LN_3};
 
+// How often the hash rate is reported to the progress callback.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+// How often the progress reporter checks if the mining ended.
+const PROGRESS_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A callback receiving the hash rate of a [`Miner`] in hashes per second, about once per second while it's mining.
+pub type MinerProgressCallback = Arc<dyn Fn(f64) + Send + Sync>;
+
 /// A type to cancel a [`Miner`] to abort operations.
 #[derive(Default, Clone)]
 pub struct MinerCancel(Arc<AtomicBool>);
@@ -57,6 +66,7 @@ impl MinerCancel {
 pub struct MinerBuilder {
     num_workers: Option<usize>,
     cancel: Option<MinerCancel>,
+    progress_callback: Option<MinerProgressCallback>,
 }
 
 impl MinerBuilder {
@@ -77,23 +87,37 @@ impl MinerBuilder {
         self
     }
 
+    /// Sets a callback receiving the hash rate of the [`Miner`] while it's mining.
+    pub fn with_progress_callback(mut self, progress_callback: impl Into<Option<MinerProgressCallback>>) -> Self {
+        self.progress_callback = progress_callback.into();
+        self
+    }
+
     /// Builds the [`Miner`].
     pub fn finish(self) -> Miner {
         Miner {
             num_workers: self.num_workers.unwrap_or_else(num_cpus::get),
             cancel: self.cancel.unwrap_or_default(),
+            progress_callback: self.progress_callback,
         }
     }
 }
 
-/// A multi-threaded pow nonce miner.
+/// A multi-threaded pow nonce miner, the workers search separate ranges of nonces in parallel.
 pub struct Miner {
     num_workers: usize,
     cancel: MinerCancel,
+    progress_callback: Option<MinerProgressCallback>,
 }
 
 impl Miner {
-    fn worker(cancel: MinerCancel, pow_digest: TritBuf<T1B1Buf>, start_nonce: u64, target_zeros: usize) -> Option<u64> {
+    fn worker(
+        cancel: MinerCancel,
+        hashes: Arc<AtomicU64>,
+        pow_digest: TritBuf<T1B1Buf>,
+        start_nonce: u64,
+        target_zeros: usize,
+    ) -> Option<u64> {
         let mut nonce = start_nonce;
         let mut hasher = CurlPBatchHasher::<T1B1Buf>::new(HASH_LENGTH);
         let mut buffers = Vec::<TritBuf<T1B1Buf>>::with_capacity(BATCH_SIZE);
@@ -119,11 +143,30 @@ impl Miner {
             }
 
             nonce += BATCH_SIZE as u64;
+            hashes.fetch_add(BATCH_SIZE as u64, Ordering::Relaxed);
         }
 
         None
     }
 
+    // Reports the hash rate of the workers until the mining ends, by finding a nonce or being cancelled.
+    fn progress_reporter(cancel: MinerCancel, hashes: Arc<AtomicU64>, progress_callback: MinerProgressCallback) {
+        let mut last_report = Instant::now();
+        let mut last_hashes = 0;
+
+        while !cancel.is_cancelled() {
+            thread::sleep(PROGRESS_CHECK_INTERVAL);
+
+            let elapsed = last_report.elapsed();
+            if elapsed >= PROGRESS_INTERVAL && !cancel.is_cancelled() {
+                let total_hashes = hashes.load(Ordering::Relaxed);
+                progress_callback((total_hashes - last_hashes) as f64 / elapsed.as_secs_f64());
+                last_report = Instant::now();
+                last_hashes = total_hashes;
+            }
+        }
+    }
+
     /// Mines a nonce for provided bytes.
     pub fn nonce(&self, bytes: &[u8], target_score: u32) -> Option<u64> {
         self.cancel.reset();
@@ -138,6 +181,7 @@ impl Miner {
 
         let worker_width = u64::MAX / self.num_workers as u64;
         let mut workers = Vec::with_capacity(self.num_workers);
+        let hashes = Arc::new(AtomicU64::new(0));
         let hash = Blake2b256::digest(bytes);
 
         b1t6::encode::<T1B1Buf>(&hash).iter().for_each(|t| pow_digest.push(t));
@@ -145,18 +189,28 @@ impl Miner {
         for i in 0..self.num_workers {
             let start_nonce = i as u64 * worker_width;
             let _cancel = self.cancel.clone();
+            let _hashes = hashes.clone();
             let _pow_digest = pow_digest.clone();
 
             workers.push(thread::spawn(move || {
-                Self::worker(_cancel, _pow_digest, start_nonce, target_zeros)
+                Self::worker(_cancel, _hashes, _pow_digest, start_nonce, target_zeros)
             }));
         }
 
+        let progress_reporter = self.progress_callback.clone().map(|progress_callback| {
+            let cancel = self.cancel.clone();
+            thread::spawn(move || Self::progress_reporter(cancel, hashes, progress_callback))
+        });
+
         for worker in workers {
             if let Some(mined_nonce) = worker.join().unwrap() {
                 nonce.replace(mined_nonce);
             }
         }
+        // The workers only stop once the miner is cancelled, which also stops the reporter
+        if let Some(progress_reporter) = progress_reporter {
+            progress_reporter.join().unwrap();
+        }
 
         nonce
     }
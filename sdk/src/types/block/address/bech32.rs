@@ -1,22 +1,21 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use alloc::{
-    string::{String, ToString},
-    vec::Vec,
-};
+use alloc::string::{String, ToString};
 use core::str::FromStr;
 
-use bech32::{FromBase32, ToBase32, Variant};
 use derive_more::{AsRef, Deref};
 use packable::{
     error::{UnpackError, UnpackErrorExt},
     packer::Packer,
     unpacker::Unpacker,
-    Packable, PackableExt,
+    Packable,
 };
 
-use crate::types::block::{address::Address, ConvertTo, Error};
+use crate::types::block::{
+    address::{address_codec, Address},
+    ConvertTo, Error,
+};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Hrp {
@@ -149,16 +148,9 @@ impl FromStr for Bech32Address {
     type Err = Error;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
-        match ::bech32::decode(address) {
-            Ok((hrp, data, _)) => {
-                let hrp = hrp.parse()?;
-                let bytes = Vec::<u8>::from_base32(&data).map_err(|_| Error::InvalidAddress)?;
-                Address::unpack_verified(bytes.as_slice(), &())
-                    .map_err(|_| Error::InvalidAddress)
-                    .map(|address| Self { hrp, inner: address })
-            }
-            Err(_) => Err(Error::InvalidAddress),
-        }
+        let (hrp, inner) = address_codec().decode(address)?;
+
+        Ok(Self { hrp, inner })
     }
 }
 
@@ -202,16 +194,7 @@ impl Bech32Address {
 
 impl core::fmt::Display for Bech32Address {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "{}",
-            ::bech32::encode(
-                &self.hrp.to_string(),
-                self.inner.pack_to_vec().to_base32(),
-                Variant::Bech32
-            )
-            .unwrap()
-        )
+        f.write_str(&address_codec().encode(&self.hrp, &self.inner))
     }
 }
 
@@ -0,0 +1,71 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use bech32::{FromBase32, ToBase32, Variant};
+use packable::PackableExt;
+
+use crate::types::block::{
+    address::{Address, Hrp},
+    Error,
+};
+
+/// Encodes addresses to, and decodes them from, their human readable representation.
+///
+/// Networks sharing the object model but using a different human readable encoding can implement this trait and
+/// register it with [`set_address_codec`], so that every [`Bech32Address`](super::Bech32Address) uses it.
+pub trait AddressCodec: core::fmt::Debug + Send + Sync {
+    /// Encodes an address with the given human readable part.
+    fn encode(&self, hrp: &Hrp, address: &Address) -> String;
+
+    /// Decodes an encoded address into its human readable part and address.
+    fn decode(&self, address: &str) -> Result<(Hrp, Address), Error>;
+}
+
+/// The default [`AddressCodec`], encoding addresses as bech32 strings.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bech32Codec;
+
+impl AddressCodec for Bech32Codec {
+    fn encode(&self, hrp: &Hrp, address: &Address) -> String {
+        // PANIC: unwrap is fine as the hrp is ASCII and at most 83 characters long.
+        ::bech32::encode(&hrp.to_string(), address.pack_to_vec().to_base32(), Variant::Bech32).unwrap()
+    }
+
+    fn decode(&self, address: &str) -> Result<(Hrp, Address), Error> {
+        let (hrp, data, _) = ::bech32::decode(address).map_err(|_| Error::InvalidAddress)?;
+        let hrp = hrp.parse()?;
+        let bytes = Vec::<u8>::from_base32(&data).map_err(|_| Error::InvalidAddress)?;
+        let address = Address::unpack_verified(bytes.as_slice(), &()).map_err(|_| Error::InvalidAddress)?;
+
+        Ok((hrp, address))
+    }
+}
+
+#[cfg(feature = "std")]
+static ADDRESS_CODEC: std::sync::OnceLock<Box<dyn AddressCodec>> = std::sync::OnceLock::new();
+
+/// Registers the [`AddressCodec`] used to encode and decode all addresses, instead of [`Bech32Codec`].
+/// It can only be set once, before any address was encoded or decoded.
+#[cfg(feature = "std")]
+pub fn set_address_codec(codec: impl AddressCodec + 'static) -> Result<(), Error> {
+    ADDRESS_CODEC
+        .set(Box::new(codec))
+        .map_err(|_| Error::AddressCodecAlreadySet)
+}
+
+/// Returns the [`AddressCodec`] currently in use.
+pub fn address_codec() -> &'static dyn AddressCodec {
+    #[cfg(feature = "std")]
+    {
+        ADDRESS_CODEC.get_or_init(|| Box::new(Bech32Codec)).as_ref()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        &Bech32Codec
+    }
+}
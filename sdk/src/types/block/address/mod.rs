@@ -3,14 +3,18 @@
 
 mod alias;
 mod bech32;
+mod codec;
 mod ed25519;
 mod nft;
 
 use derive_more::From;
 
+#[cfg(feature = "std")]
+pub use self::codec::set_address_codec;
 pub use self::{
     alias::AliasAddress,
     bech32::{Bech32Address, Hrp},
+    codec::{address_codec, AddressCodec, Bech32Codec},
     ed25519::Ed25519Address,
     nft::NftAddress,
 };
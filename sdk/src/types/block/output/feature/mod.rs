@@ -94,6 +94,28 @@ impl Feature {
         }
     }
 
+    /// Tries to get the feature as an actual [`SenderFeature`].
+    /// Returns an error instead of panicking if the feature is not a [`SenderFeature`].
+    pub fn try_as_sender(&self) -> Result<&SenderFeature, Error> {
+        if let Self::Sender(feature) = self {
+            Ok(feature)
+        } else {
+            Err(Error::InvalidFeatureKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the feature into an actual [`SenderFeature`].
+    /// Returns an error instead of panicking if the feature is not a [`SenderFeature`].
+    pub fn try_into_sender(self) -> Result<SenderFeature, Error> {
+        let kind = self.kind();
+
+        if let Self::Sender(feature) = self {
+            Ok(feature)
+        } else {
+            Err(Error::InvalidFeatureKind(kind))
+        }
+    }
+
     /// Checks whether the feature is an [`IssuerFeature`].
     pub fn is_issuer(&self) -> bool {
         matches!(self, Self::Issuer(_))
@@ -109,6 +131,28 @@ impl Feature {
         }
     }
 
+    /// Tries to get the feature as an actual [`IssuerFeature`].
+    /// Returns an error instead of panicking if the feature is not an [`IssuerFeature`].
+    pub fn try_as_issuer(&self) -> Result<&IssuerFeature, Error> {
+        if let Self::Issuer(feature) = self {
+            Ok(feature)
+        } else {
+            Err(Error::InvalidFeatureKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the feature into an actual [`IssuerFeature`].
+    /// Returns an error instead of panicking if the feature is not an [`IssuerFeature`].
+    pub fn try_into_issuer(self) -> Result<IssuerFeature, Error> {
+        let kind = self.kind();
+
+        if let Self::Issuer(feature) = self {
+            Ok(feature)
+        } else {
+            Err(Error::InvalidFeatureKind(kind))
+        }
+    }
+
     /// Checks whether the feature is a [`MetadataFeature`].
     pub fn is_metadata(&self) -> bool {
         matches!(self, Self::Metadata(_))
@@ -124,6 +168,28 @@ impl Feature {
         }
     }
 
+    /// Tries to get the feature as an actual [`MetadataFeature`].
+    /// Returns an error instead of panicking if the feature is not a [`MetadataFeature`].
+    pub fn try_as_metadata(&self) -> Result<&MetadataFeature, Error> {
+        if let Self::Metadata(feature) = self {
+            Ok(feature)
+        } else {
+            Err(Error::InvalidFeatureKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the feature into an actual [`MetadataFeature`].
+    /// Returns an error instead of panicking if the feature is not a [`MetadataFeature`].
+    pub fn try_into_metadata(self) -> Result<MetadataFeature, Error> {
+        let kind = self.kind();
+
+        if let Self::Metadata(feature) = self {
+            Ok(feature)
+        } else {
+            Err(Error::InvalidFeatureKind(kind))
+        }
+    }
+
     /// Checks whether the feature is a [`TagFeature`].
     pub fn is_tag(&self) -> bool {
         matches!(self, Self::Tag(_))
@@ -138,6 +204,28 @@ impl Feature {
             panic!("invalid downcast of non-TagFeature");
         }
     }
+
+    /// Tries to get the feature as an actual [`TagFeature`].
+    /// Returns an error instead of panicking if the feature is not a [`TagFeature`].
+    pub fn try_as_tag(&self) -> Result<&TagFeature, Error> {
+        if let Self::Tag(feature) = self {
+            Ok(feature)
+        } else {
+            Err(Error::InvalidFeatureKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the feature into an actual [`TagFeature`].
+    /// Returns an error instead of panicking if the feature is not a [`TagFeature`].
+    pub fn try_into_tag(self) -> Result<TagFeature, Error> {
+        let kind = self.kind();
+
+        if let Self::Tag(feature) = self {
+            Ok(feature)
+        } else {
+            Err(Error::InvalidFeatureKind(kind))
+        }
+    }
 }
 
 create_bitflags!(
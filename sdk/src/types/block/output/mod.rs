@@ -193,6 +193,17 @@ impl Output {
         }
     }
 
+    /// Returns the address that can unlock an [`Output`] with an
+    /// [`AddressUnlockCondition`](unlock_condition::AddressUnlockCondition) at the given milestone timestamp, taking
+    /// an expired [`ExpirationUnlockCondition`](unlock_condition::ExpirationUnlockCondition) into account.
+    pub fn locked_address(&self, milestone_timestamp: u32) -> Option<&Address> {
+        let unlock_conditions = self.unlock_conditions()?;
+
+        unlock_conditions
+            .address()
+            .map(|unlock_condition| unlock_conditions.locked_address(unlock_condition.address(), milestone_timestamp))
+    }
+
     /// Returns the features of an [`Output`], if any.
     pub fn features(&self) -> Option<&Features> {
         match self {
@@ -241,6 +252,28 @@ impl Output {
         }
     }
 
+    /// Tries to get the output as an actual [`TreasuryOutput`].
+    /// Returns an error instead of panicking if the output is not a [`TreasuryOutput`].
+    pub fn try_as_treasury(&self) -> Result<&TreasuryOutput, Error> {
+        if let Self::Treasury(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the output into an actual [`TreasuryOutput`].
+    /// Returns an error instead of panicking if the output is not a [`TreasuryOutput`].
+    pub fn try_into_treasury(self) -> Result<TreasuryOutput, Error> {
+        let kind = self.kind();
+
+        if let Self::Treasury(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(kind))
+        }
+    }
+
     /// Checks whether the output is a [`BasicOutput`].
     pub fn is_basic(&self) -> bool {
         matches!(self, Self::Basic(_))
@@ -256,6 +289,28 @@ impl Output {
         }
     }
 
+    /// Tries to get the output as an actual [`BasicOutput`].
+    /// Returns an error instead of panicking if the output is not a [`BasicOutput`].
+    pub fn try_as_basic(&self) -> Result<&BasicOutput, Error> {
+        if let Self::Basic(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the output into an actual [`BasicOutput`].
+    /// Returns an error instead of panicking if the output is not a [`BasicOutput`].
+    pub fn try_into_basic(self) -> Result<BasicOutput, Error> {
+        let kind = self.kind();
+
+        if let Self::Basic(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(kind))
+        }
+    }
+
     /// Checks whether the output is an [`AliasOutput`].
     pub fn is_alias(&self) -> bool {
         matches!(self, Self::Alias(_))
@@ -271,6 +326,28 @@ impl Output {
         }
     }
 
+    /// Tries to get the output as an actual [`AliasOutput`].
+    /// Returns an error instead of panicking if the output is not an [`AliasOutput`].
+    pub fn try_as_alias(&self) -> Result<&AliasOutput, Error> {
+        if let Self::Alias(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the output into an actual [`AliasOutput`].
+    /// Returns an error instead of panicking if the output is not an [`AliasOutput`].
+    pub fn try_into_alias(self) -> Result<AliasOutput, Error> {
+        let kind = self.kind();
+
+        if let Self::Alias(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(kind))
+        }
+    }
+
     /// Checks whether the output is a [`FoundryOutput`].
     pub fn is_foundry(&self) -> bool {
         matches!(self, Self::Foundry(_))
@@ -286,6 +363,28 @@ impl Output {
         }
     }
 
+    /// Tries to get the output as an actual [`FoundryOutput`].
+    /// Returns an error instead of panicking if the output is not a [`FoundryOutput`].
+    pub fn try_as_foundry(&self) -> Result<&FoundryOutput, Error> {
+        if let Self::Foundry(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the output into an actual [`FoundryOutput`].
+    /// Returns an error instead of panicking if the output is not a [`FoundryOutput`].
+    pub fn try_into_foundry(self) -> Result<FoundryOutput, Error> {
+        let kind = self.kind();
+
+        if let Self::Foundry(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(kind))
+        }
+    }
+
     /// Checks whether the output is an [`NftOutput`].
     pub fn is_nft(&self) -> bool {
         matches!(self, Self::Nft(_))
@@ -301,6 +400,28 @@ impl Output {
         }
     }
 
+    /// Tries to get the output as an actual [`NftOutput`].
+    /// Returns an error instead of panicking if the output is not a [`NftOutput`].
+    pub fn try_as_nft(&self) -> Result<&NftOutput, Error> {
+        if let Self::Nft(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the output into an actual [`NftOutput`].
+    /// Returns an error instead of panicking if the output is not a [`NftOutput`].
+    pub fn try_into_nft(self) -> Result<NftOutput, Error> {
+        let kind = self.kind();
+
+        if let Self::Nft(output) = self {
+            Ok(output)
+        } else {
+            Err(Error::InvalidOutputKind(kind))
+        }
+    }
+
     /// Returns the address that is required to unlock this [`Output`] and the alias or nft address that gets
     /// unlocked by it, if it's an alias or nft.
     /// If no `alias_transition` has been provided, assumes a state transition.
@@ -29,7 +29,13 @@ pub use self::{
     state_controller_address::StateControllerAddressUnlockCondition,
     storage_deposit_return::StorageDepositReturnUnlockCondition, timelock::TimelockUnlockCondition,
 };
-use crate::types::block::{address::Address, create_bitflags, protocol::ProtocolParameters, Error};
+use crate::types::block::{
+    address::Address,
+    create_bitflags,
+    output::{AliasOutput, BasicOutput, FoundryOutput, NftOutput},
+    protocol::ProtocolParameters,
+    Error,
+};
 
 ///
 #[derive(Clone, Eq, PartialEq, Hash, From)]
@@ -117,6 +123,28 @@ impl UnlockCondition {
         }
     }
 
+    /// Tries to get the unlock condition as an actual [`AddressUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not an [`AddressUnlockCondition`].
+    pub fn try_as_address(&self) -> Result<&AddressUnlockCondition, Error> {
+        if let Self::Address(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the unlock condition into an actual [`AddressUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not an [`AddressUnlockCondition`].
+    pub fn try_into_address(self) -> Result<AddressUnlockCondition, Error> {
+        let kind = self.kind();
+
+        if let Self::Address(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(kind))
+        }
+    }
+
     /// Checks whether the unlock condition is a [`StorageDepositReturnUnlockCondition`].
     pub fn is_storage_deposit_return(&self) -> bool {
         matches!(self, Self::StorageDepositReturn(_))
@@ -132,6 +160,28 @@ impl UnlockCondition {
         }
     }
 
+    /// Tries to get the unlock condition as an actual [`StorageDepositReturnUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not a [`StorageDepositReturnUnlockCondition`].
+    pub fn try_as_storage_deposit_return(&self) -> Result<&StorageDepositReturnUnlockCondition, Error> {
+        if let Self::StorageDepositReturn(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the unlock condition into an actual [`StorageDepositReturnUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not a [`StorageDepositReturnUnlockCondition`].
+    pub fn try_into_storage_deposit_return(self) -> Result<StorageDepositReturnUnlockCondition, Error> {
+        let kind = self.kind();
+
+        if let Self::StorageDepositReturn(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(kind))
+        }
+    }
+
     /// Checks whether the unlock condition is a [`TimelockUnlockCondition`].
     pub fn is_timelock(&self) -> bool {
         matches!(self, Self::Timelock(_))
@@ -147,6 +197,28 @@ impl UnlockCondition {
         }
     }
 
+    /// Tries to get the unlock condition as an actual [`TimelockUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not a [`TimelockUnlockCondition`].
+    pub fn try_as_timelock(&self) -> Result<&TimelockUnlockCondition, Error> {
+        if let Self::Timelock(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the unlock condition into an actual [`TimelockUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not a [`TimelockUnlockCondition`].
+    pub fn try_into_timelock(self) -> Result<TimelockUnlockCondition, Error> {
+        let kind = self.kind();
+
+        if let Self::Timelock(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(kind))
+        }
+    }
+
     /// Checks whether the unlock condition is an [`ExpirationUnlockCondition`].
     pub fn is_expiration(&self) -> bool {
         matches!(self, Self::Expiration(_))
@@ -162,6 +234,28 @@ impl UnlockCondition {
         }
     }
 
+    /// Tries to get the unlock condition as an actual [`ExpirationUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not an [`ExpirationUnlockCondition`].
+    pub fn try_as_expiration(&self) -> Result<&ExpirationUnlockCondition, Error> {
+        if let Self::Expiration(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the unlock condition into an actual [`ExpirationUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not an [`ExpirationUnlockCondition`].
+    pub fn try_into_expiration(self) -> Result<ExpirationUnlockCondition, Error> {
+        let kind = self.kind();
+
+        if let Self::Expiration(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(kind))
+        }
+    }
+
     /// Checks whether the unlock condition is a [`StateControllerAddressUnlockCondition`].
     pub fn is_state_controller_address(&self) -> bool {
         matches!(self, Self::StateControllerAddress(_))
@@ -177,6 +271,30 @@ impl UnlockCondition {
         }
     }
 
+    /// Tries to get the unlock condition as an actual [`StateControllerAddressUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not a
+    /// [`StateControllerAddressUnlockCondition`].
+    pub fn try_as_state_controller_address(&self) -> Result<&StateControllerAddressUnlockCondition, Error> {
+        if let Self::StateControllerAddress(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the unlock condition into an actual [`StateControllerAddressUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not a
+    /// [`StateControllerAddressUnlockCondition`].
+    pub fn try_into_state_controller_address(self) -> Result<StateControllerAddressUnlockCondition, Error> {
+        let kind = self.kind();
+
+        if let Self::StateControllerAddress(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(kind))
+        }
+    }
+
     /// Checks whether the unlock condition is a [`GovernorAddressUnlockCondition`].
     pub fn is_governor_address(&self) -> bool {
         matches!(self, Self::GovernorAddress(_))
@@ -192,6 +310,28 @@ impl UnlockCondition {
         }
     }
 
+    /// Tries to get the unlock condition as an actual [`GovernorAddressUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not a [`GovernorAddressUnlockCondition`].
+    pub fn try_as_governor_address(&self) -> Result<&GovernorAddressUnlockCondition, Error> {
+        if let Self::GovernorAddress(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the unlock condition into an actual [`GovernorAddressUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not a [`GovernorAddressUnlockCondition`].
+    pub fn try_into_governor_address(self) -> Result<GovernorAddressUnlockCondition, Error> {
+        let kind = self.kind();
+
+        if let Self::GovernorAddress(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(kind))
+        }
+    }
+
     /// Checks whether the unlock condition is an [`ImmutableAliasAddressUnlockCondition`].
     pub fn is_immutable_alias_address(&self) -> bool {
         matches!(self, Self::ImmutableAliasAddress(_))
@@ -206,6 +346,30 @@ impl UnlockCondition {
             panic!("invalid downcast of non-ImmutableAliasAddressUnlockCondition");
         }
     }
+
+    /// Tries to get the unlock condition as an actual [`ImmutableAliasAddressUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not an
+    /// [`ImmutableAliasAddressUnlockCondition`].
+    pub fn try_as_immutable_alias_address(&self) -> Result<&ImmutableAliasAddressUnlockCondition, Error> {
+        if let Self::ImmutableAliasAddress(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(self.kind()))
+        }
+    }
+
+    /// Tries to convert the unlock condition into an actual [`ImmutableAliasAddressUnlockCondition`].
+    /// Returns an error instead of panicking if the unlock condition is not an
+    /// [`ImmutableAliasAddressUnlockCondition`].
+    pub fn try_into_immutable_alias_address(self) -> Result<ImmutableAliasAddressUnlockCondition, Error> {
+        let kind = self.kind();
+
+        if let Self::ImmutableAliasAddress(unlock_condition) = self {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::InvalidUnlockConditionKind(kind))
+        }
+    }
 }
 
 create_bitflags!(
@@ -437,6 +601,175 @@ impl UnlockConditions {
         self.expiration()
             .map_or(false, |expiration| milestone_timestamp >= expiration.timestamp())
     }
+
+    /// Verifies whether the given address can unlock an output with these unlock conditions at the given milestone
+    /// timestamp, returning the reason why it can't on its own otherwise.
+    pub fn verify_unlockable(&self, address: &Address, milestone_timestamp: u32) -> Result<(), Locked> {
+        if let Some(timelock) = self.timelock() {
+            if milestone_timestamp < timelock.timestamp() {
+                return Err(Locked::Timelock(timelock.timestamp()));
+            }
+        }
+
+        let is_owner = [
+            self.address().map(AddressUnlockCondition::address),
+            self.state_controller_address()
+                .map(StateControllerAddressUnlockCondition::address),
+            self.governor_address().map(GovernorAddressUnlockCondition::address),
+            self.immutable_alias_address()
+                .map(ImmutableAliasAddressUnlockCondition::address),
+        ]
+        .contains(&Some(address));
+
+        if let Some(expiration) = self.expiration() {
+            if milestone_timestamp >= expiration.timestamp() {
+                // Once expired, only the return address can unlock the output and the storage deposit return doesn't
+                // apply anymore.
+                return if address == expiration.return_address() {
+                    Ok(())
+                } else if is_owner {
+                    Err(Locked::Expired)
+                } else {
+                    Err(Locked::NotOwner)
+                };
+            } else if !is_owner && address == expiration.return_address() {
+                return Err(Locked::NotExpired(expiration.timestamp()));
+            }
+        }
+
+        if !is_owner {
+            return Err(Locked::NotOwner);
+        }
+
+        match self.storage_deposit_return() {
+            Some(storage_deposit_return) => Err(Locked::StorageDepositReturn(storage_deposit_return.amount())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The reason why an address can't unlock an output on its own, returned by [`UnlockConditions::verify_unlockable`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Locked {
+    /// The output is time locked until the given milestone timestamp.
+    Timelock(u32),
+    /// The output is expired and can only be unlocked by the return address of the expiration.
+    Expired,
+    /// The output can only be unlocked by the return address of the expiration from the given milestone timestamp.
+    NotExpired(u32),
+    /// The address isn't one the output is locked to.
+    NotOwner,
+    /// The output can only be unlocked if the given amount is returned to the return address of the storage deposit
+    /// return.
+    StorageDepositReturn(u64),
+}
+
+/// Builder of [`UnlockConditions`] for a given output kind.
+///
+/// Unlock conditions that aren't allowed for the output kind, like a [`TimelockUnlockCondition`] on an alias output,
+/// are rejected when they are added, and missing mandatory unlock conditions when the builder is finished, instead of
+/// only when the whole output is built.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct UnlockConditionsBuilder {
+    output_kind: u8,
+    allowed_unlock_conditions: UnlockConditionFlags,
+    unlock_conditions: BTreeSet<UnlockCondition>,
+}
+
+impl UnlockConditionsBuilder {
+    /// Creates an empty [`UnlockConditionsBuilder`] for outputs of the given kind.
+    pub fn new(output_kind: u8) -> Result<Self, Error> {
+        let allowed_unlock_conditions = match output_kind {
+            BasicOutput::KIND => BasicOutput::ALLOWED_UNLOCK_CONDITIONS,
+            AliasOutput::KIND => AliasOutput::ALLOWED_UNLOCK_CONDITIONS,
+            FoundryOutput::KIND => FoundryOutput::ALLOWED_UNLOCK_CONDITIONS,
+            NftOutput::KIND => NftOutput::ALLOWED_UNLOCK_CONDITIONS,
+            kind => return Err(Error::InvalidOutputKind(kind)),
+        };
+
+        Ok(Self {
+            output_kind,
+            allowed_unlock_conditions,
+            unlock_conditions: BTreeSet::new(),
+        })
+    }
+
+    /// Creates an [`UnlockConditionsBuilder`] for outputs of the given kind from existing [`UnlockConditions`], e.g.
+    /// to change the unlock conditions of an output.
+    pub fn from_unlock_conditions(output_kind: u8, unlock_conditions: UnlockConditions) -> Result<Self, Error> {
+        unlock_conditions
+            .into_iter()
+            .try_fold(Self::new(output_kind)?, |builder, unlock_condition| {
+                builder.add_unlock_condition(unlock_condition)
+            })
+    }
+
+    /// Adds an [`UnlockCondition`], fails if it isn't allowed for the output kind or if one of the same kind was
+    /// already added.
+    pub fn add_unlock_condition(mut self, unlock_condition: impl Into<UnlockCondition>) -> Result<Self, Error> {
+        let unlock_condition = self.verify_allowed(unlock_condition.into())?;
+
+        if self.unlock_conditions.contains(&unlock_condition) {
+            return Err(Error::UnlockConditionsNotUniqueSorted);
+        }
+        self.unlock_conditions.insert(unlock_condition);
+
+        Ok(self)
+    }
+
+    /// Adds an [`UnlockCondition`] or replaces the one of the same kind, fails if it isn't allowed for the output
+    /// kind.
+    pub fn replace_unlock_condition(mut self, unlock_condition: impl Into<UnlockCondition>) -> Result<Self, Error> {
+        let unlock_condition = self.verify_allowed(unlock_condition.into())?;
+        self.unlock_conditions.replace(unlock_condition);
+
+        Ok(self)
+    }
+
+    /// Removes the [`UnlockCondition`] of the given kind, if any.
+    pub fn remove_unlock_condition(mut self, kind: u8) -> Self {
+        self.unlock_conditions
+            .retain(|unlock_condition| unlock_condition.kind() != kind);
+        self
+    }
+
+    /// Finishes the [`UnlockConditionsBuilder`] into [`UnlockConditions`], fails if an unlock condition that is
+    /// mandatory for the output kind is missing.
+    pub fn finish(self) -> Result<UnlockConditions, Error> {
+        let unlock_conditions = UnlockConditions::from_set(self.unlock_conditions)?;
+
+        match self.output_kind {
+            BasicOutput::KIND | NftOutput::KIND if unlock_conditions.address().is_none() => {
+                Err(Error::MissingAddressUnlockCondition)
+            }
+            AliasOutput::KIND if unlock_conditions.state_controller_address().is_none() => {
+                Err(Error::MissingStateControllerUnlockCondition)
+            }
+            AliasOutput::KIND if unlock_conditions.governor_address().is_none() => {
+                Err(Error::MissingGovernorUnlockCondition)
+            }
+            FoundryOutput::KIND if unlock_conditions.immutable_alias_address().is_none() => {
+                Err(Error::MissingAddressUnlockCondition)
+            }
+            _ => Ok(unlock_conditions),
+        }
+    }
+
+    fn verify_allowed(&self, unlock_condition: UnlockCondition) -> Result<UnlockCondition, Error> {
+        if self.allowed_unlock_conditions.contains(unlock_condition.flag()) {
+            Ok(unlock_condition)
+        } else {
+            Err(Error::UnallowedUnlockCondition {
+                index: self
+                    .unlock_conditions
+                    .iter()
+                    .filter(|existing| existing.kind() < unlock_condition.kind())
+                    .count(),
+                kind: unlock_condition.kind(),
+            })
+        }
+    }
 }
 
 #[inline]
@@ -491,6 +824,134 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn builder() {
+        use crate::types::block::{
+            address::{AliasAddress, Ed25519Address},
+            output::AliasId,
+        };
+
+        let address = Address::from(Ed25519Address::new([0; 32]));
+
+        // A timelock isn't allowed on an alias output
+        assert!(matches!(
+            UnlockConditionsBuilder::new(AliasOutput::KIND)
+                .unwrap()
+                .add_unlock_condition(TimelockUnlockCondition::new(1).unwrap()),
+            Err(Error::UnallowedUnlockCondition {
+                kind: TimelockUnlockCondition::KIND,
+                ..
+            })
+        ));
+        assert!(matches!(
+            UnlockConditionsBuilder::new(AliasOutput::KIND)
+                .unwrap()
+                .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+                .unwrap()
+                .finish(),
+            Err(Error::MissingGovernorUnlockCondition)
+        ));
+
+        let unlock_conditions = UnlockConditionsBuilder::new(BasicOutput::KIND)
+            .unwrap()
+            .add_unlock_condition(TimelockUnlockCondition::new(1).unwrap())
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .unwrap()
+            .finish()
+            .unwrap();
+        assert_eq!(unlock_conditions.len(), 2);
+
+        let alias_address = Address::from(AliasAddress::new(AliasId::null()));
+        let unlock_conditions = UnlockConditionsBuilder::from_unlock_conditions(BasicOutput::KIND, unlock_conditions)
+            .unwrap()
+            .replace_unlock_condition(AddressUnlockCondition::new(alias_address))
+            .unwrap()
+            .remove_unlock_condition(TimelockUnlockCondition::KIND)
+            .finish()
+            .unwrap();
+        assert_eq!(unlock_conditions.len(), 1);
+        assert_eq!(unlock_conditions.address().unwrap().address(), &alias_address);
+
+        assert!(matches!(
+            UnlockConditionsBuilder::new(BasicOutput::KIND)
+                .unwrap()
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .unwrap()
+                .add_unlock_condition(AddressUnlockCondition::new(alias_address)),
+            Err(Error::UnlockConditionsNotUniqueSorted)
+        ));
+    }
+
+    #[test]
+    fn try_as() {
+        use crate::types::block::address::Ed25519Address;
+
+        let unlock_condition = UnlockCondition::from(TimelockUnlockCondition::new(1).unwrap());
+
+        assert_eq!(unlock_condition.try_as_timelock().unwrap().timestamp(), 1);
+        assert!(matches!(
+            unlock_condition.try_as_expiration(),
+            Err(Error::InvalidUnlockConditionKind(TimelockUnlockCondition::KIND))
+        ));
+        assert!(unlock_condition.clone().try_into_timelock().is_ok());
+        assert!(matches!(
+            unlock_condition.try_into_address(),
+            Err(Error::InvalidUnlockConditionKind(TimelockUnlockCondition::KIND))
+        ));
+
+        let unlock_condition = UnlockCondition::from(AddressUnlockCondition::new(Ed25519Address::new([0; 32])));
+        assert!(unlock_condition.try_as_address().is_ok());
+        assert!(unlock_condition.try_as_governor_address().is_err());
+    }
+
+    #[test]
+    fn verify_unlockable() {
+        use crate::types::block::address::Ed25519Address;
+
+        let owner = Address::from(Ed25519Address::new([0; 32]));
+        let return_address = Address::from(Ed25519Address::new([1; 32]));
+        let other = Address::from(Ed25519Address::new([2; 32]));
+
+        let unlock_conditions = UnlockConditionsBuilder::new(BasicOutput::KIND)
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(owner))
+            .unwrap()
+            .add_unlock_condition(TimelockUnlockCondition::new(10).unwrap())
+            .unwrap()
+            .add_unlock_condition(ExpirationUnlockCondition::new(return_address, 20).unwrap())
+            .unwrap()
+            .add_unlock_condition(StorageDepositReturnUnlockCondition::new(return_address, 100, u64::MAX).unwrap())
+            .unwrap()
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            unlock_conditions.verify_unlockable(&owner, 5),
+            Err(Locked::Timelock(10))
+        );
+        assert_eq!(
+            unlock_conditions.verify_unlockable(&owner, 15),
+            Err(Locked::StorageDepositReturn(100))
+        );
+        assert_eq!(
+            unlock_conditions.verify_unlockable(&return_address, 15),
+            Err(Locked::NotExpired(20))
+        );
+        assert_eq!(unlock_conditions.verify_unlockable(&other, 15), Err(Locked::NotOwner));
+        assert_eq!(unlock_conditions.verify_unlockable(&owner, 20), Err(Locked::Expired));
+        assert_eq!(unlock_conditions.verify_unlockable(&return_address, 20), Ok(()));
+        assert_eq!(unlock_conditions.verify_unlockable(&other, 20), Err(Locked::NotOwner));
+
+        let unlock_conditions = UnlockConditionsBuilder::new(BasicOutput::KIND)
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(owner))
+            .unwrap()
+            .finish()
+            .unwrap();
+        assert_eq!(unlock_conditions.verify_unlockable(&owner, 0), Ok(()));
+    }
 }
 
 #[cfg(feature = "serde")]
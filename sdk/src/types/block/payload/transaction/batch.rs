@@ -0,0 +1,117 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{format, string::ToString, vec::Vec};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use ed25519_zebra::{batch, Signature as ZebraSignature, VerificationKeyBytes};
+
+use super::{TransactionEssence, TransactionPayload};
+use crate::types::block::{
+    address::{Address, Ed25519Address},
+    output::{AliasTransition, Output, OutputId},
+    signature::{Ed25519Signature, Signature},
+    unlock::Unlock,
+    Error,
+};
+
+impl TransactionPayload {
+    /// Verifies all signature unlocks of the transaction in one batch, which is considerably faster than verifying the
+    /// signatures one by one. `inputs` are the outputs consumed by the transaction, in the order of the inputs of its
+    /// essence. `current_time` is the milestone timestamp the expiration unlock conditions are checked against.
+    ///
+    /// Only the signatures and whether they belong to the address required to unlock the consumed outputs are checked,
+    /// the transaction isn't semantically validated.
+    pub fn verify_signatures_batch(&self, inputs: &[(&OutputId, &Output)], current_time: u32) -> Result<(), Error> {
+        Self::verify_signatures_batch_many(&[(self, inputs)], current_time)
+    }
+
+    /// Verifies the signature unlocks of multiple transactions, each given with the outputs it consumes, in one batch.
+    /// See [`TransactionPayload::verify_signatures_batch()`].
+    pub fn verify_signatures_batch_many(
+        transactions: &[(&Self, &[(&OutputId, &Output)])],
+        current_time: u32,
+    ) -> Result<(), Error> {
+        let essence_hashes = transactions
+            .iter()
+            .map(|(transaction, _)| transaction.essence().hash())
+            .collect::<Vec<_>>();
+        let mut verifier = batch::Verifier::new();
+
+        for ((transaction, inputs), essence_hash) in transactions.iter().zip(&essence_hashes) {
+            let unlocks = transaction.unlocks();
+            let TransactionEssence::Regular(essence) = transaction.essence();
+
+            if inputs.len() != unlocks.len() {
+                return Err(Error::InputUnlockCountMismatch {
+                    input_count: inputs.len(),
+                    unlock_count: unlocks.len(),
+                });
+            }
+
+            for ((output_id, output), unlock) in inputs.iter().zip(unlocks.iter()) {
+                // A reference unlock reuses a queued signature, but it still needs to belong to the required address
+                let signature_unlock = match unlock {
+                    Unlock::Signature(signature_unlock) => signature_unlock,
+                    Unlock::Reference(reference_unlock) => match unlocks.get(reference_unlock.index() as usize) {
+                        Some(Unlock::Signature(signature_unlock)) => signature_unlock,
+                        _ => return Err(Error::InvalidUnlockReference(reference_unlock.index())),
+                    },
+                    // Alias and NFT unlocks don't carry a signature, the chain they reference is unlocked by one
+                    _ => continue,
+                };
+                let Signature::Ed25519(signature) = signature_unlock.signature();
+
+                let (required_address, _) = output.required_and_unlocked_address(
+                    current_time,
+                    output_id,
+                    alias_transition(output, output_id, essence.outputs()),
+                )?;
+                verify_signature_address(signature, &required_address)?;
+
+                if let Unlock::Signature(_) = unlock {
+                    verifier.queue((
+                        VerificationKeyBytes::from(signature.public_key().to_bytes()),
+                        ZebraSignature::from_bytes(&signature.signature().to_bytes()),
+                        essence_hash,
+                    ));
+                }
+            }
+        }
+
+        verifier.verify(rand::thread_rng()).map_err(|_| Error::InvalidSignature)
+    }
+}
+
+// A state transition if the alias is created again with an increased state index, a governance transition otherwise
+fn alias_transition(output: &Output, output_id: &OutputId, outputs: &[Output]) -> Option<AliasTransition> {
+    let Output::Alias(alias_output) = output else {
+        return None;
+    };
+    let alias_id = alias_output.alias_id_non_null(output_id);
+
+    let is_state_transition = outputs.iter().any(|output| {
+        matches!(output, Output::Alias(next_state)
+            if next_state.alias_id() == &alias_id && next_state.state_index() != alias_output.state_index())
+    });
+
+    Some(if is_state_transition {
+        AliasTransition::State
+    } else {
+        AliasTransition::Governance
+    })
+}
+
+// Checks that the address of the signature's public key is the one required to unlock the output
+fn verify_signature_address(signature: &Ed25519Signature, required_address: &Address) -> Result<(), Error> {
+    let signature_address = Ed25519Address::new(Blake2b256::digest(signature.public_key()).into());
+
+    if *required_address == Address::from(signature_address) {
+        Ok(())
+    } else {
+        Err(Error::SignaturePublicKeyMismatch {
+            expected: format!("{required_address:?}"),
+            actual: signature_address.to_string(),
+        })
+    }
+}
@@ -3,6 +3,8 @@
 
 //! Module describing the transaction payload.
 
+#[cfg(feature = "batch_verification")]
+mod batch;
 mod essence;
 mod transaction_id;
 
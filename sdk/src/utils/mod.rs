@@ -3,6 +3,7 @@
 
 #[cfg(feature = "serde")]
 pub mod serde;
+pub mod units;
 
 #[cfg(feature = "instant")]
 pub fn unix_timestamp_now() -> core::time::Duration {
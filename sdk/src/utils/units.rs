@@ -0,0 +1,216 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversion and formatting of base token amounts. Only integer arithmetic is used, so amounts are never rounded:
+//! parse them into the smallest unit, calculate with the checked `u64` operations and format the result.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt;
+
+/// The metric prefixes used by [`UnitFormat::format()`], one for every power of 1000.
+const METRIC_PREFIXES: [&str; 6] = ["", "K", "M", "G", "T", "P"];
+
+/// Errors of the unit conversions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnitError {
+    /// The amount is empty.
+    Empty,
+    /// The amount contains a character that isn't a digit or a separator.
+    InvalidCharacter(char),
+    /// The amount has more fraction digits than the unit has decimals.
+    TooManyFractionDigits { max: u8 },
+    /// The amount in the smallest unit doesn't fit into a `u64`.
+    Overflow,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitError {}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty amount"),
+            Self::InvalidCharacter(c) => write!(f, "invalid character in amount: {c}"),
+            Self::TooManyFractionDigits { max } => write!(f, "amount has more than {max} fraction digits"),
+            Self::Overflow => write!(f, "amount overflows"),
+        }
+    }
+}
+
+/// Converts an amount with up to `decimals` fraction digits, separated by a `.`, into the smallest unit.
+pub fn to_smallest_unit(amount: &str, decimals: u8) -> Result<u64, UnitError> {
+    parse_decimal(amount, decimals, '.', None)
+}
+
+/// Converts an amount in the smallest unit into an amount with up to `decimals` fraction digits, separated by a `.`.
+/// Trailing zeros of the fraction are omitted.
+pub fn from_smallest_unit(amount: u64, decimals: u8) -> String {
+    format_decimal(amount, decimals as usize, '.', None)
+}
+
+/// The unit of the base token of a network and how its amounts are formatted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct UnitFormat {
+    unit: String,
+    decimals: u8,
+    use_metric_prefix: bool,
+    decimal_separator: char,
+    group_separator: Option<char>,
+}
+
+impl UnitFormat {
+    /// Creates the format of a unit with the given number of decimals, using `.` as decimal separator and no group
+    /// separator.
+    pub fn new(unit: impl Into<String>, decimals: u8) -> Self {
+        Self {
+            unit: unit.into(),
+            decimals,
+            use_metric_prefix: false,
+            decimal_separator: '.',
+            group_separator: None,
+        }
+    }
+
+    /// The format of the IOTA base token.
+    pub fn iota() -> Self {
+        Self::new("IOTA", 6)
+    }
+
+    /// The format of the Shimmer base token.
+    pub fn shimmer() -> Self {
+        Self::new("SMR", 6)
+    }
+
+    /// Sets whether formatted amounts are scaled with metric prefixes, e.g. `1.5 Gi` instead of `1500000000 i`.
+    pub fn with_metric_prefix(mut self, use_metric_prefix: bool) -> Self {
+        self.use_metric_prefix = use_metric_prefix;
+        self
+    }
+
+    /// Sets the separators of the fraction and of the groups of three integer digits, e.g. `,` and `.` to format
+    /// `1.234,5`.
+    pub fn with_separators(mut self, decimal_separator: char, group_separator: impl Into<Option<char>>) -> Self {
+        self.decimal_separator = decimal_separator;
+        self.group_separator = group_separator.into();
+        self
+    }
+
+    /// Returns the unit.
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Returns the number of decimals of the unit.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Converts an amount in the unit, formatted with the separators of this format, into the smallest unit.
+    pub fn to_smallest_unit(&self, amount: &str) -> Result<u64, UnitError> {
+        parse_decimal(amount, self.decimals, self.decimal_separator, self.group_separator)
+    }
+
+    /// Converts an amount in the smallest unit into the unit, formatted with the separators of this format.
+    pub fn from_smallest_unit(&self, amount: u64) -> String {
+        format_decimal(
+            amount,
+            self.decimals as usize,
+            self.decimal_separator,
+            self.group_separator,
+        )
+    }
+
+    /// Formats an amount in the smallest unit with the unit, scaled with the largest metric prefix that keeps the
+    /// integer part non-zero if metric prefixes are used.
+    pub fn format(&self, amount: u64) -> String {
+        if self.use_metric_prefix {
+            let digits = amount.to_string().len();
+            let integer_digits = digits.saturating_sub(self.decimals as usize);
+            let exponent = (integer_digits.saturating_sub(1) / 3).min(METRIC_PREFIXES.len() - 1);
+            let value = format_decimal(
+                amount,
+                self.decimals as usize + 3 * exponent,
+                self.decimal_separator,
+                self.group_separator,
+            );
+
+            format!("{value} {}{}", METRIC_PREFIXES[exponent], self.unit)
+        } else {
+            format!("{} {}", self.from_smallest_unit(amount), self.unit)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<&crate::types::api::core::response::BaseTokenResponse> for UnitFormat {
+    fn from(value: &crate::types::api::core::response::BaseTokenResponse) -> Self {
+        Self::new(value.unit.clone(), value.decimals).with_metric_prefix(value.use_metric_prefix)
+    }
+}
+
+fn parse_decimal(
+    amount: &str,
+    decimals: u8,
+    decimal_separator: char,
+    group_separator: Option<char>,
+) -> Result<u64, UnitError> {
+    fn push_digit(amount: u64, c: char) -> Result<u64, UnitError> {
+        let digit = c.to_digit(10).ok_or(UnitError::InvalidCharacter(c))?;
+        amount
+            .checked_mul(10)
+            .and_then(|amount| amount.checked_add(u64::from(digit)))
+            .ok_or(UnitError::Overflow)
+    }
+
+    let amount = amount.trim();
+    let (integer, fraction) = amount.split_once(decimal_separator).unwrap_or((amount, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(UnitError::Empty);
+    }
+
+    let mut smallest_unit_amount = 0;
+    for c in integer.chars().filter(|c| Some(*c) != group_separator) {
+        smallest_unit_amount = push_digit(smallest_unit_amount, c)?;
+    }
+
+    let mut fraction_digits = 0;
+    for c in fraction.trim_end_matches('0').chars() {
+        if fraction_digits == decimals {
+            return Err(UnitError::TooManyFractionDigits { max: decimals });
+        }
+        smallest_unit_amount = push_digit(smallest_unit_amount, c)?;
+        fraction_digits += 1;
+    }
+    for _ in fraction_digits..decimals {
+        smallest_unit_amount = smallest_unit_amount.checked_mul(10).ok_or(UnitError::Overflow)?;
+    }
+
+    Ok(smallest_unit_amount)
+}
+
+fn format_decimal(amount: u64, decimals: usize, decimal_separator: char, group_separator: Option<char>) -> String {
+    // Padded so there's at least one integer digit
+    let digits = format!("{amount:0>width$}", width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    let mut formatted = String::with_capacity(digits.len() + integer.len() / 3 + 1);
+    for (i, c) in integer.chars().enumerate() {
+        if let Some(group_separator) = group_separator {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                formatted.push(group_separator);
+            }
+        }
+        formatted.push(c);
+    }
+    if !fraction.is_empty() {
+        formatted.push(decimal_separator);
+        formatted.push_str(fraction);
+    }
+
+    formatted
+}
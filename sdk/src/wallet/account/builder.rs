@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use tokio::sync::RwLock;
 
@@ -140,6 +140,7 @@ where
             index: account_index,
             coin_type,
             alias: account_alias,
+            metadata: BTreeMap::new(),
             public_addresses: addresses,
             internal_addresses: Vec::new(),
             addresses_with_unspent_outputs: Vec::new(),
@@ -11,9 +11,19 @@ pub(crate) const DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 15;
 /// Amount of API request that can be sent in parallel during syncing
 pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 
+/// Amount of accounts that are synced in parallel when syncing the wallet
+pub(crate) const DEFAULT_ACCOUNT_SYNC_CONCURRENCY: usize = 8;
+
 /// ms before an account actually syncs with the network, before it just returns the previous syncing result
 /// this is done to prevent unnecessary simultaneous synchronizations
 pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
+
+/// Default time in seconds an expiration needs to be later than, to leave time for the transaction to get confirmed
+pub(crate) const DEFAULT_EXPIRATION_SLACK: u32 = 60;
+
+/// The maximum amount of balance snapshots that are kept per account
+#[cfg(feature = "storage")]
+pub(crate) const MAX_BALANCE_SNAPSHOTS: usize = 1000;
@@ -13,7 +13,7 @@ pub mod types;
 pub(crate) mod update;
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     ops::Deref,
     sync::Arc,
 };
@@ -22,16 +22,24 @@ use getset::{Getters, Setters};
 use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, RwLock};
 
+#[cfg(feature = "storage")]
+pub use self::operations::balance_history::{BalanceDiff, BalanceSnapshot};
 #[cfg(feature = "participation")]
 pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
-use self::types::{
-    address::{AccountAddress, AddressWithUnspentOutputs},
-    Balance, OutputData, Transaction, TransactionDto,
+use self::{
+    operations::address_rotation::ReceiveAddressState,
+    types::{
+        address::{AccountAddress, AddressWithUnspentOutputs},
+        Balance, OutputData, Transaction, TransactionDto, TransactionReceipt,
+    },
 };
 pub use self::{
     operations::{
+        address_rotation::AddressRotationPolicy,
+        expiring_outputs::{ExpirationDirection, ExpiringOutput},
         output_claiming::OutputsToClaim,
-        output_consolidation::ConsolidationParams,
+        output_consolidation::{ConsolidationParams, ConsolidationPolicy},
+        proof_of_reserves::{AddressReserves, ProofOfReserves},
         syncing::{
             options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
             SyncOptions,
@@ -56,6 +64,7 @@ pub use self::{
 use super::core::WalletInner;
 use crate::{
     client::{
+        node_api::indexer::outputs_query::OutputsQuery,
         secret::{SecretManage, SecretManager},
         Client,
     },
@@ -73,7 +82,7 @@ use crate::{
     wallet::{account::types::InclusionState, Result},
 };
 
-/// Options to filter outputs
+/// Options to filter outputs, applied as the [`OutputsQuery`] they convert into, so all set fields have to match.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct FilterOptions {
@@ -91,6 +100,25 @@ pub struct FilterOptions {
     pub nft_ids: Option<HashSet<NftId>>,
 }
 
+impl From<FilterOptions> for OutputsQuery {
+    fn from(filter: FilterOptions) -> Self {
+        Self::new()
+            .with_output_types(filter.output_types)
+            .with_alias_ids(filter.alias_ids)
+            .with_foundry_ids(filter.foundry_ids)
+            .with_nft_ids(filter.nft_ids)
+            .with_created_range(
+                // The bounds of the booked timestamp are inclusive, the ones of the query exclusive
+                filter
+                    .lower_bound_booked_timestamp
+                    .and_then(|lower_bound| lower_bound.checked_sub(1)),
+                filter
+                    .upper_bound_booked_timestamp
+                    .and_then(|upper_bound| upper_bound.checked_add(1)),
+            )
+    }
+}
+
 /// Details of an account.
 #[derive(Clone, Debug, Eq, PartialEq, Getters, Setters)]
 #[getset(get = "pub")]
@@ -101,6 +129,8 @@ pub struct AccountDetails {
     coin_type: u32,
     /// The account alias.
     alias: String,
+    /// User-defined key-value metadata of the account.
+    metadata: BTreeMap<String, String>,
     /// Public addresses
     pub(crate) public_addresses: Vec<AccountAddress>,
     /// Internal addresses
@@ -166,7 +196,16 @@ pub struct AccountInner {
     // if the last synced time was < `MIN_SYNC_INTERVAL` second ago, we don't sync, but only calculate the balance
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
+    // the latest milestone timestamp known during the last sync, used to detect outputs whose locked address changed
+    #[cfg(feature = "events")]
+    pub(crate) last_synced_milestone_timestamp: Mutex<Option<u32>>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    // the current receive address and the policy deciding when it's rotated
+    pub(crate) receive_address: Mutex<ReceiveAddressState>,
+    // the policy deciding when the basic outputs are consolidated while syncing
+    pub(crate) consolidation_policy: Mutex<Option<ConsolidationPolicy>>,
+    // mutex to prevent consolidations from selecting the same outputs before their inputs got locked
+    pub(crate) consolidation: Mutex<()>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -194,13 +233,37 @@ where
             .unwrap_or_default();
         #[cfg(not(feature = "storage"))]
         let default_sync_options = Default::default();
+        #[cfg(feature = "storage")]
+        let consolidation_policy = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_consolidation_policy(*details.index())
+            .await?;
+        #[cfg(not(feature = "storage"))]
+        let consolidation_policy = None;
+        #[cfg(feature = "storage")]
+        let receive_address = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_receive_address_state(*details.index())
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let receive_address = Default::default();
 
         Ok(Self {
             wallet,
             inner: Arc::new(AccountInner {
                 details: RwLock::new(details),
                 last_synced: Default::default(),
+                #[cfg(feature = "events")]
+                last_synced_milestone_timestamp: Default::default(),
                 default_sync_options: Mutex::new(default_sync_options),
+                receive_address: Mutex::new(receive_address),
+                consolidation_policy: Mutex::new(consolidation_policy),
+                consolidation: Default::default(),
             }),
         })
     }
@@ -271,6 +334,11 @@ impl AccountInner {
         self.details().await.alias.clone()
     }
 
+    /// Get the value of a metadata key of the account.
+    pub async fn get_metadata(&self, key: &str) -> Option<String> {
+        self.details().await.metadata.get(key).cloned()
+    }
+
     /// Get the [`OutputData`] of an output stored in the account
     pub async fn get_output(&self, output_id: &OutputId) -> Option<OutputData> {
         self.details().await.outputs().get(output_id).cloned()
@@ -281,6 +349,21 @@ impl AccountInner {
         self.details().await.transactions().get(transaction_id).cloned()
     }
 
+    /// Get the [`TransactionReceipt`] of a sent or incoming transaction stored in the account, with the outputs it
+    /// consumed and created and the balance changes of the addresses owning them.
+    pub async fn get_transaction_receipt(&self, transaction_id: &TransactionId) -> Result<Option<TransactionReceipt>> {
+        let transaction = match self.get_transaction(transaction_id).await {
+            Some(transaction) => Some(transaction),
+            None => self.get_incoming_transaction(transaction_id).await,
+        };
+        let Some(transaction) = transaction else {
+            return Ok(None);
+        };
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+
+        Ok(Some(transaction.receipt(bech32_hrp)?))
+    }
+
     /// Get the transaction with inputs of an incoming transaction stored in the account
     /// List might not be complete, if the node pruned the data already
     pub async fn get_incoming_transaction(&self, transaction_id: &TransactionId) -> Option<Transaction> {
@@ -314,69 +397,9 @@ impl AccountInner {
         outputs: impl Iterator<Item = &'a OutputData>,
         filter: impl Into<Option<FilterOptions>>,
     ) -> Result<Vec<OutputData>> {
-        let filter = filter.into();
-
-        if let Some(filter) = filter {
-            let mut filtered_outputs = Vec::new();
-
-            for output in outputs {
-                match &output.output {
-                    Output::Alias(alias) => {
-                        if let Some(alias_ids) = &filter.alias_ids {
-                            let alias_id = alias.alias_id_non_null(&output.output_id);
-                            if alias_ids.contains(&alias_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Foundry(foundry) => {
-                        if let Some(foundry_ids) = &filter.foundry_ids {
-                            let foundry_id = foundry.id();
-                            if foundry_ids.contains(&foundry_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Nft(nft) => {
-                        if let Some(nft_ids) = &filter.nft_ids {
-                            let nft_id = nft.nft_id_non_null(&output.output_id);
-                            if nft_ids.contains(&nft_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-
-                if let Some(lower_bound_booked_timestamp) = filter.lower_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() < lower_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-                if let Some(upper_bound_booked_timestamp) = filter.upper_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() > upper_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-
-                if let Some(output_types) = &filter.output_types {
-                    if !output_types.contains(&output.output.kind()) {
-                        continue;
-                    }
-                }
-
-                // If ids are provided, only return them and no other outputs.
-                if filter.alias_ids.is_none() && filter.foundry_ids.is_none() && filter.nft_ids.is_none() {
-                    filtered_outputs.push(output.clone());
-                }
-            }
-
-            Ok(filtered_outputs)
-        } else {
-            Ok(outputs.cloned().collect())
+        match filter.into() {
+            Some(filter) => Self::query_known_outputs(outputs, &OutputsQuery::from(filter)),
+            None => Ok(outputs.cloned().collect()),
         }
     }
 
@@ -390,6 +413,36 @@ impl AccountInner {
         self.filter_outputs(self.details().await.unspent_outputs.values(), filter)
     }
 
+    /// Returns the outputs of the account matching the query, the same query can be sent to the indexer with
+    /// [`Client::outputs_query_ids()`].
+    pub async fn query_outputs(&self, query: &OutputsQuery) -> Result<Vec<OutputData>> {
+        Self::query_known_outputs(self.details().await.outputs.values(), query)
+    }
+
+    /// Returns the unspent outputs of the account matching the query, the same query can be sent to the indexer with
+    /// [`Client::outputs_query_ids()`].
+    pub async fn query_unspent_outputs(&self, query: &OutputsQuery) -> Result<Vec<OutputData>> {
+        Self::query_known_outputs(self.details().await.unspent_outputs.values(), query)
+    }
+
+    fn query_known_outputs<'a>(
+        outputs: impl Iterator<Item = &'a OutputData>,
+        query: &OutputsQuery,
+    ) -> Result<Vec<OutputData>> {
+        query.validate()?;
+
+        Ok(outputs
+            .filter(|output| {
+                query.matches(
+                    &output.output_id,
+                    &output.output,
+                    output.metadata.milestone_timestamp_booked(),
+                )
+            })
+            .cloned()
+            .collect())
+    }
+
     /// Gets the unspent alias output matching the given ID.
     pub async fn unspent_alias_output(&self, alias_id: &AliasId) -> Result<Option<OutputData>> {
         self.unspent_outputs(FilterOptions {
@@ -454,6 +507,7 @@ pub(crate) fn build_transaction_from_payload_and_inputs(
     Ok(Transaction {
         payload: tx_payload.clone(),
         block_id: inputs.first().map(|i| *i.metadata.block_id()),
+        attachments: Vec::new(),
         inclusion_state: InclusionState::Confirmed,
         timestamp: inputs
             .first()
@@ -464,6 +518,7 @@ pub(crate) fn build_transaction_from_payload_and_inputs(
         incoming: true,
         note: None,
         inputs,
+        build_data: None,
     })
 }
 
@@ -477,6 +532,9 @@ pub struct AccountDetailsDto {
     pub coin_type: u32,
     /// The account alias.
     pub alias: String,
+    /// User-defined key-value metadata of the account.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
     /// Public addresses
     pub public_addresses: Vec<AccountAddress>,
     /// Internal addresses
@@ -512,6 +570,7 @@ impl TryFromDto for AccountDetails {
             index: dto.index,
             coin_type: dto.coin_type,
             alias: dto.alias,
+            metadata: dto.metadata,
             public_addresses: dto.public_addresses,
             internal_addresses: dto.internal_addresses,
             addresses_with_unspent_outputs: dto.addresses_with_unspent_outputs,
@@ -553,6 +612,7 @@ impl From<&AccountDetails> for AccountDetailsDto {
             index: *value.index(),
             coin_type: *value.coin_type(),
             alias: value.alias().clone(),
+            metadata: value.metadata().clone(),
             public_addresses: value.public_addresses().clone(),
             internal_addresses: value.internal_addresses().clone(),
             addresses_with_unspent_outputs: value.addresses_with_unspent_outputs().clone(),
@@ -587,6 +647,45 @@ impl From<&AccountDetails> for AccountDetailsDto {
     }
 }
 
+#[test]
+fn filter_options_query() {
+    let alias_id = AliasId::new([1; 32]);
+    let query = OutputsQuery::from(FilterOptions {
+        lower_bound_booked_timestamp: Some(10),
+        upper_bound_booked_timestamp: Some(20),
+        output_types: Some(vec![crate::types::block::output::AliasOutput::KIND]),
+        alias_ids: Some([alias_id].into()),
+        ..Default::default()
+    });
+
+    // The booked timestamp bounds are inclusive
+    assert_eq!(
+        query,
+        OutputsQuery::new()
+            .with_output_types(vec![crate::types::block::output::AliasOutput::KIND])
+            .with_alias_ids(HashSet::from([alias_id]))
+            .with_created_range(9, 21)
+    );
+    assert_eq!(
+        OutputsQuery::from(FilterOptions {
+            lower_bound_booked_timestamp: Some(0),
+            upper_bound_booked_timestamp: Some(u32::MAX),
+            ..Default::default()
+        }),
+        OutputsQuery::new()
+    );
+    // Nft IDs can't match outputs of the alias type
+    assert!(
+        OutputsQuery::from(FilterOptions {
+            output_types: Some(vec![crate::types::block::output::AliasOutput::KIND]),
+            nft_ids: Some([NftId::null()].into()),
+            ..Default::default()
+        })
+        .validate()
+        .is_err()
+    );
+}
+
 #[test]
 fn serialize() {
     use core::str::FromStr;
@@ -602,6 +701,7 @@ fn serialize() {
         protocol::ProtocolParameters,
         signature::{Ed25519Signature, Signature},
         unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        BlockId,
     };
 
     const TRANSACTION_ID: &str = "0x24a1f46bdb6b2bf38f1c59f73cdd4ae5b418804bb231d76d06fbf246498d5883";
@@ -653,15 +753,27 @@ fn serialize() {
         transaction_id: TransactionId::from_str("0x131fc4cb8f315ae36ae3bf6a4e4b3486d5f17581288f1217410da3e0700d195a")
             .unwrap(),
         payload: tx_payload,
-        block_id: None,
+        // Included in the reattachment
+        block_id: Some(BlockId::new([2; 32])),
+        attachments: vec![BlockId::new([1; 32]), BlockId::new([2; 32])],
         network_id: 0,
         timestamp: 0,
-        inclusion_state: InclusionState::Pending,
+        inclusion_state: InclusionState::Confirmed,
         incoming: false,
         note: None,
         inputs: Vec::new(),
+        build_data: None,
     };
 
+    // Transactions stored before attachments were tracked have none
+    let mut transaction_json = serde_json::to_value(TransactionDto::from(&incoming_transaction)).unwrap();
+    assert_eq!(transaction_json["attachments"].as_array().unwrap().len(), 2);
+    transaction_json.as_object_mut().unwrap().remove("attachments");
+    let transaction =
+        Transaction::try_from_dto(serde_json::from_value::<TransactionDto>(transaction_json).unwrap()).unwrap();
+    assert!(transaction.attachments.is_empty());
+    assert_eq!(transaction.block_id, incoming_transaction.block_id);
+
     let mut incoming_transactions = HashMap::new();
     incoming_transactions.insert(
         TransactionId::from_str("0x131fc4cb8f315ae36ae3bf6a4e4b3486d5f17581288f1217410da3e0700d195a").unwrap(),
@@ -672,6 +784,7 @@ fn serialize() {
         index: 0,
         coin_type: 4218,
         alias: "0".to_string(),
+        metadata: [("purpose".to_string(), "savings".to_string())].into(),
         public_addresses: Vec::new(),
         internal_addresses: Vec::new(),
         addresses_with_unspent_outputs: Vec::new(),
@@ -707,6 +820,7 @@ impl AccountDetails {
             index: 0,
             coin_type: 4218,
             alias: "Alice".to_string(),
+            metadata: BTreeMap::new(),
             public_addresses: vec![AccountAddress {
                 address: crate::types::block::address::Bech32Address::from_str(
                     "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
@@ -3,16 +3,15 @@
 
 #[cfg(feature = "ledger_nano")]
 use crate::client::secret::{ledger_nano::LedgerSecretManager, DowncastSecretManager};
+#[cfg(feature = "ledger_nano")]
+use crate::types::block::address::ToBech32Ext;
+#[cfg(all(feature = "events", feature = "ledger_nano"))]
+use crate::wallet::events::types::{AddressData, LedgerAddressMismatchEvent, WalletEvent};
 use crate::{
     client::secret::{GenerateAddressOptions, SecretManage},
     types::block::address::Bech32Address,
     wallet::account::{types::address::AccountAddress, Account},
 };
-#[cfg(all(feature = "events", feature = "ledger_nano"))]
-use crate::{
-    types::block::address::ToBech32Ext,
-    wallet::events::types::{AddressData, WalletEvent},
-};
 
 impl<S: 'static + SecretManage> Account<S>
 where
@@ -70,21 +69,7 @@ where
         // prompt first
         #[cfg(feature = "ledger_nano")]
         let addresses = {
-            use crate::wallet::account::SecretManager;
-            let secret_manager = self.wallet.secret_manager.read().await;
-            if secret_manager
-                .downcast::<LedgerSecretManager>()
-                .or_else(|| {
-                    secret_manager.downcast::<SecretManager>().and_then(|s| {
-                        if let SecretManager::LedgerNano(n) = s {
-                            Some(n)
-                        } else {
-                            None
-                        }
-                    })
-                })
-                .is_some()
-            {
+            if self.uses_ledger_nano().await {
                 #[cfg(feature = "events")]
                 let changed_options = {
                     // Change options so ledger will not show the prompt the first time
@@ -182,6 +167,90 @@ where
         Ok(generate_addresses)
     }
 
+    /// Displays the public address with the key index on the Ledger Nano device, so the user can verify that it's the
+    /// address shown by the application, and returns whether the device derived the same address. A
+    /// [`WalletEvent::LedgerAddressGeneration`] event with the address of the account is emitted before the prompt,
+    /// and a [`WalletEvent::LedgerAddressMismatch`] event if the derived address differs.
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    pub async fn verify_address_on_device(&self, key_index: u32) -> crate::wallet::Result<bool> {
+        if !self.uses_ledger_nano().await {
+            return Err(crate::wallet::Error::Client(Box::new(
+                crate::client::Error::SecretManagerMismatch,
+            )));
+        }
+
+        let (coin_type, account_index, expected) = {
+            let account_details = self.details().await;
+            let address = account_details
+                .public_addresses
+                .iter()
+                .find(|address| address.key_index == key_index)
+                .ok_or(crate::wallet::Error::KeyIndexNotFoundInAccount(key_index))?;
+            (account_details.coin_type, account_details.index, address.address)
+        };
+
+        #[cfg(feature = "events")]
+        self.emit(
+            account_index,
+            WalletEvent::LedgerAddressGeneration(AddressData { address: expected }),
+        )
+        .await;
+
+        let displayed = self
+            .wallet
+            .secret_manager
+            .read()
+            .await
+            .generate_ed25519_addresses(
+                coin_type,
+                account_index,
+                key_index..key_index + 1,
+                Some(GenerateAddressOptions {
+                    internal: false,
+                    ledger_nano_prompt: true,
+                }),
+            )
+            .await?[0]
+            .to_bech32(expected.hrp);
+
+        if displayed != expected {
+            log::warn!("[verify_address_on_device] device displayed {displayed} instead of {expected}");
+            #[cfg(feature = "events")]
+            self.emit(
+                account_index,
+                WalletEvent::LedgerAddressMismatch(Box::new(LedgerAddressMismatchEvent {
+                    key_index,
+                    expected,
+                    displayed,
+                })),
+            )
+            .await;
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Returns whether the secret manager of the wallet is a Ledger Nano.
+    #[cfg(feature = "ledger_nano")]
+    async fn uses_ledger_nano(&self) -> bool {
+        use crate::wallet::account::SecretManager;
+        let secret_manager = self.wallet.secret_manager.read().await;
+        secret_manager
+            .downcast::<LedgerSecretManager>()
+            .or_else(|| {
+                secret_manager.downcast::<SecretManager>().and_then(|s| {
+                    if let SecretManager::LedgerNano(n) = s {
+                        Some(n)
+                    } else {
+                        None
+                    }
+                })
+            })
+            .is_some()
+    }
+
     /// Generate an internal address and store in the account, internal addresses are used for remainder outputs
     pub(crate) async fn generate_remainder_address(&self) -> crate::wallet::Result<AccountAddress> {
         let result = self
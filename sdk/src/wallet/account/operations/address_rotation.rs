@@ -0,0 +1,242 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{AddressRotatedEvent, WalletEvent};
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::Bech32Address,
+    wallet::account::{types::address::AccountAddress, Account},
+};
+
+/// Defines when the current receive address of an account is replaced by a fresh one.
+///
+/// The default policy never rotates the address.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressRotationPolicy {
+    /// Rotate once funds were received on the current receive address.
+    #[serde(default)]
+    pub after_receiving: bool,
+    /// Rotate after the current receive address was handed out this many times.
+    #[serde(default)]
+    pub max_uses: Option<u32>,
+    /// Rotate after the current receive address was in use for this long.
+    #[serde(default)]
+    pub max_age: Option<Duration>,
+}
+
+impl AddressRotationPolicy {
+    /// Rotates the address once funds were received on it.
+    pub fn with_after_receiving(mut self, after_receiving: bool) -> Self {
+        self.after_receiving = after_receiving;
+        self
+    }
+
+    /// Rotates the address after it was handed out `max_uses` times.
+    pub fn with_max_uses(mut self, max_uses: impl Into<Option<u32>>) -> Self {
+        self.max_uses = max_uses.into();
+        self
+    }
+
+    /// Rotates the address after it was in use for `max_age`.
+    pub fn with_max_age(mut self, max_age: impl Into<Option<Duration>>) -> Self {
+        self.max_age = max_age.into();
+        self
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.after_receiving || self.max_uses.is_some() || self.max_age.is_some()
+    }
+}
+
+/// The receive address handed out by [`Account::current_receive_address()`] and how it was used so far, stored with
+/// the account so it doesn't reset on restart.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ReceiveAddressState {
+    #[serde(default)]
+    pub(crate) policy: AddressRotationPolicy,
+    #[serde(default)]
+    current: Option<CurrentReceiveAddress>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CurrentReceiveAddress {
+    address: AccountAddress,
+    // unix timestamp in milliseconds of when the address became the current one
+    #[serde(with = "crate::utils::serde::string")]
+    since: u128,
+    uses: u32,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sets the policy that decides when the current receive address is rotated.
+    /// If storage is enabled, will persist during restarts.
+    pub async fn set_address_rotation_policy(&self, policy: AddressRotationPolicy) -> crate::wallet::Result<()> {
+        let mut state = self.receive_address.lock().await;
+        state.policy = policy;
+        self.save_receive_address_state(&state).await
+    }
+
+    /// Returns the policy that decides when the current receive address is rotated.
+    pub async fn address_rotation_policy(&self) -> AddressRotationPolicy {
+        self.receive_address.lock().await.policy.clone()
+    }
+
+    /// Returns the address funds should currently be received on, rotating it first if the
+    /// [`AddressRotationPolicy`] requires it. Every call counts as a use of the returned address.
+    pub async fn current_receive_address(&self) -> crate::wallet::Result<Bech32Address> {
+        let mut state = self.receive_address.lock().await;
+
+        if state.current.is_none() {
+            let address = self.initial_receive_address(&state.policy).await?;
+            state.current = Some(CurrentReceiveAddress {
+                address,
+                since: crate::utils::unix_timestamp_now().as_millis(),
+                uses: 0,
+            });
+        } else if self.receive_address_expired(&state).await {
+            self.rotate_receive_address_internal(&mut state).await?;
+        }
+
+        // Safe to unwrap, the current address was set above
+        let current = state.current.as_mut().unwrap();
+        current.uses += 1;
+        let address = current.address.address;
+        self.save_receive_address_state(&state).await?;
+
+        Ok(address)
+    }
+
+    /// Replaces the current receive address by a fresh one, regardless of the [`AddressRotationPolicy`].
+    pub async fn rotate_receive_address(&self) -> crate::wallet::Result<Bech32Address> {
+        let mut state = self.receive_address.lock().await;
+        let address = self.rotate_receive_address_internal(&mut state).await?;
+        self.save_receive_address_state(&state).await?;
+
+        Ok(address)
+    }
+
+    /// Rotates the current receive address during syncing if it received funds or got too old.
+    pub(crate) async fn apply_address_rotation_policy(&self) -> crate::wallet::Result<()> {
+        let mut state = self.receive_address.lock().await;
+        if state.current.is_some() && self.receive_address_expired(&state).await {
+            self.rotate_receive_address_internal(&mut state).await?;
+            self.save_receive_address_state(&state).await?;
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(not(feature = "storage"), allow(unused_variables))]
+    async fn save_receive_address_state(&self, state: &ReceiveAddressState) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_receive_address_state(index, state).await?;
+        }
+
+        Ok(())
+    }
+
+    // The first public address that didn't receive funds yet
+    async fn initial_receive_address(&self, policy: &AddressRotationPolicy) -> crate::wallet::Result<AccountAddress> {
+        let account_details = self.details().await;
+        let unused = account_details.public_addresses.iter().find(|address| !address.used);
+        match (unused, account_details.public_addresses.last()) {
+            (Some(address), _) => Ok(address.clone()),
+            (None, Some(address)) if !policy.is_enabled() => Ok(address.clone()),
+            _ => {
+                drop(account_details);
+                self.next_receive_address(None).await
+            }
+        }
+    }
+
+    async fn receive_address_expired(&self, state: &ReceiveAddressState) -> bool {
+        let (policy, Some(current)) = (&state.policy, &state.current) else {
+            return false;
+        };
+
+        if policy.max_uses.map_or(false, |max_uses| current.uses >= max_uses) {
+            return true;
+        }
+        if policy.max_age.map_or(false, |max_age| {
+            crate::utils::unix_timestamp_now()
+                .as_millis()
+                .saturating_sub(current.since)
+                >= max_age.as_millis()
+        }) {
+            return true;
+        }
+
+        policy.after_receiving
+            && self
+                .details()
+                .await
+                .public_addresses
+                .iter()
+                .any(|address| address.key_index == current.address.key_index && address.used)
+    }
+
+    async fn rotate_receive_address_internal(
+        &self,
+        state: &mut ReceiveAddressState,
+    ) -> crate::wallet::Result<Bech32Address> {
+        let previous = state.current.take().map(|current| current.address);
+        let address = self.next_receive_address(previous.as_ref()).await?;
+        log::debug!(
+            "[ADDRESS ROTATION] rotated receive address to {} (key index {})",
+            address.address,
+            address.key_index
+        );
+
+        #[cfg(feature = "events")]
+        {
+            let account_index = *self.details().await.index();
+            self.emit(
+                account_index,
+                WalletEvent::AddressRotated(Box::new(AddressRotatedEvent {
+                    previous_address: previous.map(|previous| previous.address),
+                    address: address.address,
+                })),
+            )
+            .await;
+        }
+
+        let bech32_address = address.address;
+        state.current = Some(CurrentReceiveAddress {
+            address,
+            since: crate::utils::unix_timestamp_now().as_millis(),
+            uses: 0,
+        });
+
+        Ok(bech32_address)
+    }
+
+    // The first unused public address after the previous one, generating a new address if there is none
+    async fn next_receive_address(&self, previous: Option<&AccountAddress>) -> crate::wallet::Result<AccountAddress> {
+        let account_details = self.details().await;
+        let next = account_details
+            .public_addresses
+            .iter()
+            .find(|address| !address.used && previous.map_or(true, |previous| address.key_index > previous.key_index))
+            .cloned();
+        drop(account_details);
+
+        match next {
+            Some(address) => Ok(address),
+            // Safe to unwrap, exactly one address was generated
+            None => Ok(self.generate_ed25519_addresses(1, None).await?.pop().unwrap()),
+        }
+    }
+}
@@ -1,19 +1,27 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use primitive_types::U256;
 
 use crate::{
     client::secret::SecretManage,
     types::block::{
-        address::Bech32Address,
+        address::{Address, Bech32Address, ToBech32Ext},
         output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
         ConvertTo,
     },
     wallet::{
         account::{
-            operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
-            types::{AddressWithUnspentOutputs, Balance, NativeTokensBalance},
+            operations::helpers::time::{
+                can_output_be_unlocked_forever_from_now_on, can_output_be_unlocked_now,
+                controlled_alias_and_nft_addresses,
+            },
+            types::{
+                AddressBalance, AddressWithUnspentOutputs, Balance, DetailedBalance, NativeTokensBalance,
+                NotSpendableBalance,
+            },
             Account, AccountDetails, OutputsToClaim,
         },
         Error, Result,
@@ -56,6 +64,183 @@ where
             .await
     }
 
+    /// Get the balance of the account with the base coin amount and output count per address and the amounts that
+    /// can't be spent currently, because they're timelocked, need to be returned to the sender or are required as
+    /// storage deposit.
+    pub async fn detailed_balance(&self) -> Result<DetailedBalance> {
+        log::debug!("[BALANCE] detailed_balance");
+
+        let balance = self.balance().await?;
+
+        let network_id = self.client().get_network_id().await?;
+        let rent_structure = self.client().get_rent_structure().await?;
+        let local_time = self.client().get_time_checked().await?;
+        let account_details = self.details().await;
+
+        let alias_and_nft_addresses = controlled_alias_and_nft_addresses(
+            &account_details.addresses_with_unspent_outputs,
+            account_details.unspent_outputs.values(),
+            local_time,
+        )?;
+        let is_account_address = |address: &Address| {
+            account_details
+                .public_addresses
+                .iter()
+                .chain(&account_details.internal_addresses)
+                .any(|a| a.address.inner == *address)
+                || alias_and_nft_addresses.contains(address)
+        };
+
+        let mut addresses = Vec::new();
+        let mut output_count = 0;
+        let mut not_spendable = NotSpendableBalance::default();
+
+        for address_with_unspent_outputs in &account_details.addresses_with_unspent_outputs {
+            let mut address_balance = AddressBalance {
+                address: address_with_unspent_outputs.address,
+                amount: 0,
+                output_count: 0,
+            };
+
+            for output_id in &address_with_unspent_outputs.output_ids {
+                let Some(output_data) = account_details.unspent_outputs.get(output_id) else {
+                    continue;
+                };
+                // Check if output is from the network we're currently connected to
+                if output_data.network_id != network_id {
+                    continue;
+                }
+                let output = &output_data.output;
+
+                address_balance.amount += output.amount();
+                address_balance.output_count += 1;
+
+                if let Some(unlock_conditions) = output.unlock_conditions() {
+                    if unlock_conditions
+                        .timelock()
+                        .map_or(false, |timelock| local_time < timelock.timestamp())
+                    {
+                        not_spendable.timelocked += output.amount();
+                    }
+                    let expired = unlock_conditions.expiration().map_or(false, |expiration| {
+                        expiration.return_address_expired(local_time).is_some()
+                    });
+                    if let Some(sdr) = unlock_conditions.storage_deposit_return() {
+                        if !expired && !is_account_address(sdr.return_address()) {
+                            not_spendable.storage_deposit_return += sdr.amount();
+                        }
+                    }
+                }
+
+                let holds_native_tokens = output
+                    .native_tokens()
+                    .map_or(false, |native_tokens| !native_tokens.is_empty());
+                if holds_native_tokens || !output.is_basic() {
+                    not_spendable.storage_deposit += output.rent_cost(&rent_structure);
+                }
+            }
+
+            output_count += address_balance.output_count;
+            addresses.push(address_balance);
+        }
+
+        Ok(DetailedBalance {
+            balance,
+            addresses,
+            output_count,
+            not_spendable,
+        })
+    }
+
+    /// Get the base coin amount of the unspent outputs of the account per address that can unlock them at the given
+    /// milestone timestamp. Outputs with an expired expiration unlock condition are attributed to their return address
+    /// instead of their nominal recipient. Only outputs with an address unlock condition are considered.
+    pub async fn locked_address_balances(&self, milestone_timestamp: u32) -> Result<HashMap<Bech32Address, u64>> {
+        log::debug!("[BALANCE] locked_address_balances");
+
+        let network_id = self.client().get_network_id().await?;
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let mut balances = HashMap::new();
+
+        for output_data in self.details().await.unspent_outputs.values() {
+            // Check if output is from the network we're currently connected to
+            if output_data.network_id != network_id {
+                continue;
+            }
+
+            if let Some(address) = output_data.output.locked_address(milestone_timestamp) {
+                *balances.entry(address.to_bech32(bech32_hrp)).or_default() += output_data.output.amount();
+            }
+        }
+
+        Ok(balances)
+    }
+
+    /// Projects the base coin amount the account can spend at the given unix timestamp in seconds, based on the
+    /// current unspent outputs. Timelocks that end and expirations that are reached until then are taken into account,
+    /// so outputs can become spendable or go back to their sender. Amounts of storage deposit return unlock conditions
+    /// that need to be sent back are subtracted. Outputs used by pending transactions and alias and foundry outputs
+    /// aren't included.
+    pub async fn spendable_balance_at(&self, timestamp: u32) -> Result<u64> {
+        log::debug!("[BALANCE] spendable_balance_at");
+
+        let network_id = self.client().get_network_id().await?;
+        let account_details = self.details().await;
+
+        let alias_and_nft_addresses = controlled_alias_and_nft_addresses(
+            &account_details.addresses_with_unspent_outputs,
+            account_details.unspent_outputs.values(),
+            timestamp,
+        )?;
+        let is_account_address = |address: &Address| {
+            account_details
+                .public_addresses
+                .iter()
+                .chain(&account_details.internal_addresses)
+                .any(|a| a.address.inner == *address)
+                || alias_and_nft_addresses.contains(address)
+        };
+
+        let mut spendable = 0;
+        for (output_id, output_data) in &account_details.unspent_outputs {
+            // Check if output is from the network we're currently connected to
+            if output_data.network_id != network_id || account_details.locked_outputs.contains(output_id) {
+                continue;
+            }
+            if !(output_data.output.is_basic() || output_data.output.is_nft()) {
+                continue;
+            }
+            if !can_output_be_unlocked_now(
+                &account_details.addresses_with_unspent_outputs,
+                &alias_and_nft_addresses,
+                output_data,
+                timestamp,
+                None,
+            )? {
+                continue;
+            }
+
+            let unlock_conditions = output_data
+                .output
+                .unlock_conditions()
+                .expect("output needs to have unlock conditions");
+            let expired = unlock_conditions.expiration().map_or(false, |expiration| {
+                expiration.return_address_expired(timestamp).is_some()
+            });
+
+            // The storage deposit only needs to be returned if the output is unlocked by its recipient
+            let amount = match unlock_conditions.storage_deposit_return() {
+                Some(sdr) if !expired && !is_account_address(sdr.return_address()) => {
+                    output_data.output.amount() - sdr.amount()
+                }
+                _ => output_data.output.amount(),
+            };
+            spendable += amount;
+        }
+
+        Ok(spendable)
+    }
+
     async fn balance_inner(
         &self,
         addresses_with_unspent_outputs: impl Iterator<Item = &AddressWithUnspentOutputs> + Send,
@@ -63,6 +248,14 @@ where
     ) -> Result<Balance> {
         let network_id = self.client().get_network_id().await?;
         let rent_structure = self.client().get_rent_structure().await?;
+        let local_time = self.client().get_time_checked().await?;
+        // Outputs can also be locked to, or need to be returned to, the address of an alias or nft output the account
+        // controls
+        let alias_and_nft_addresses = controlled_alias_and_nft_addresses(
+            &account_details.addresses_with_unspent_outputs,
+            account_details.unspent_outputs.values(),
+            local_time,
+        )?;
         let mut balance = Balance::default();
         let mut total_rent_amount = 0;
         let mut total_native_tokens = NativeTokensBuilder::default();
@@ -164,7 +357,6 @@ where
                                 // spend the balance at the moment or in the future
 
                                 let account_addresses = self.addresses().await?;
-                                let local_time = self.client().get_time_checked().await?;
                                 let is_claimable =
                                     self.claimable_outputs(OutputsToClaim::All).await?.contains(output_id);
 
@@ -181,6 +373,7 @@ where
                                             // the account without unspent
                                             // outputs can't be related to this output
                                             &account_details.addresses_with_unspent_outputs,
+                                            &alias_and_nft_addresses,
                                             output,
                                             local_time,
                                         );
@@ -198,6 +391,7 @@ where
                                                     if account_addresses
                                                         .iter()
                                                         .any(|a| a.address.inner == *sdr.return_address())
+                                                        || alias_and_nft_addresses.contains(sdr.return_address())
                                                     {
                                                         // sending to ourself, we get the full amount
                                                         output.amount()
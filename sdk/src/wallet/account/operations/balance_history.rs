@@ -0,0 +1,156 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeSet, ops::RangeBounds};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::output::OutputId,
+    wallet::account::{types::Balance, Account},
+};
+
+/// The balance and the unspent outputs of an account after a sync, recorded in the wallet storage.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceSnapshot {
+    /// The time of the sync, in milliseconds since the Unix epoch.
+    #[serde(with = "crate::utils::serde::string")]
+    pub timestamp: u128,
+    /// The balance after the sync.
+    pub balance: Balance,
+    /// The unspent outputs after the sync, sorted.
+    pub output_ids: Vec<OutputId>,
+}
+
+/// The changes between two [`BalanceSnapshot`]s, see [`BalanceSnapshot::diff()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceDiff {
+    /// The time of the earlier snapshot.
+    #[serde(with = "crate::utils::serde::string")]
+    pub from_timestamp: u128,
+    /// The time of the later snapshot.
+    #[serde(with = "crate::utils::serde::string")]
+    pub to_timestamp: u128,
+    /// The outputs that are unspent in the later snapshot, but weren't in the earlier one.
+    pub new_outputs: Vec<OutputId>,
+    /// The outputs that were unspent in the earlier snapshot, but aren't anymore in the later one.
+    pub spent_outputs: Vec<OutputId>,
+    /// The change of the total base coin balance.
+    #[serde(with = "crate::utils::serde::string")]
+    pub base_coin_change: i128,
+}
+
+impl BalanceSnapshot {
+    /// Returns which outputs appeared and disappeared between this snapshot and a later one.
+    pub fn diff(&self, later: &Self) -> BalanceDiff {
+        let output_ids = self.output_ids.iter().collect::<BTreeSet<_>>();
+        let later_output_ids = later.output_ids.iter().collect::<BTreeSet<_>>();
+
+        BalanceDiff {
+            from_timestamp: self.timestamp,
+            to_timestamp: later.timestamp,
+            new_outputs: later_output_ids.difference(&output_ids).map(|id| **id).collect(),
+            spent_outputs: output_ids.difference(&later_output_ids).map(|id| **id).collect(),
+            base_coin_change: later.balance.base_coin.total as i128 - self.balance.base_coin.total as i128,
+        }
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the balance snapshots of the account with a timestamp in the range, oldest first. A snapshot is
+    /// recorded after every full sync that changed the unspent outputs, only the last 1000 snapshots are kept.
+    pub async fn balance_history(
+        &self,
+        range: impl RangeBounds<u128> + Send,
+    ) -> crate::wallet::Result<Vec<BalanceSnapshot>> {
+        let account_index = *self.details().await.index();
+        let snapshots = self
+            .wallet
+            .storage_manager
+            .read()
+            .await
+            .get_balance_snapshots(account_index)
+            .await?;
+
+        Ok(snapshots
+            .into_iter()
+            .filter(|snapshot| range.contains(&snapshot.timestamp))
+            .collect())
+    }
+
+    /// Returns the changes between the consecutive balance snapshots with a timestamp in the range, to find out which
+    /// outputs appeared or disappeared between syncs.
+    pub async fn balance_diffs(&self, range: impl RangeBounds<u128> + Send) -> crate::wallet::Result<Vec<BalanceDiff>> {
+        Ok(self
+            .balance_history(range)
+            .await?
+            .windows(2)
+            .map(|snapshots| snapshots[0].diff(&snapshots[1]))
+            .collect())
+    }
+
+    // Records a snapshot of the balance after a sync, if the unspent outputs changed since the last one
+    pub(crate) async fn record_balance_snapshot(&self, balance: &Balance) -> crate::wallet::Result<()> {
+        let (account_index, output_ids) = {
+            let account_details = self.details().await;
+            let mut output_ids = account_details.unspent_outputs().keys().copied().collect::<Vec<_>>();
+            output_ids.sort();
+            (*account_details.index(), output_ids)
+        };
+
+        let snapshot = BalanceSnapshot {
+            timestamp: crate::utils::unix_timestamp_now().as_millis(),
+            balance: balance.clone(),
+            output_ids,
+        };
+
+        self.wallet
+            .storage_manager
+            .read()
+            .await
+            .append_balance_snapshot(account_index, snapshot)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::payload::transaction::TransactionId;
+
+    fn snapshot(timestamp: u128, total: u64, output_indexes: &[u16]) -> BalanceSnapshot {
+        let mut balance = Balance::default();
+        balance.base_coin.total = total;
+
+        BalanceSnapshot {
+            timestamp,
+            balance,
+            output_ids: output_indexes
+                .iter()
+                .map(|index| OutputId::new(TransactionId::new([1; 32]), *index).unwrap())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn balance_diff() {
+        let diff = snapshot(1, 3_000_000, &[0, 1]).diff(&snapshot(2, 1_000_000, &[1, 2]));
+
+        assert_eq!(
+            diff,
+            BalanceDiff {
+                from_timestamp: 1,
+                to_timestamp: 2,
+                new_outputs: vec![OutputId::new(TransactionId::new([1; 32]), 2).unwrap()],
+                spent_outputs: vec![OutputId::new(TransactionId::new([1; 32]), 0).unwrap()],
+                base_coin_change: -2_000_000,
+            }
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{address::Address, output::OutputId},
+    wallet::account::{operations::helpers::time::controlled_alias_and_nft_addresses, Account},
+};
+
+/// What happens to the funds of an output when its expiration is reached.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExpirationDirection {
+    /// The output was sent to the account and can be claimed until it expires, then it goes back to the sender.
+    WillLeave,
+    /// The output was sent by the account and can be claimed by the recipient until it expires, then it returns to
+    /// the account.
+    WillReturn,
+}
+
+/// An output with an expiration unlock condition that expires soon.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiringOutput {
+    /// The output ID.
+    pub output_id: OutputId,
+    /// The base coin amount of the output.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The unix timestamp in seconds at which the output expires.
+    pub expires_at: u32,
+    /// Whether the funds leave or return to the account when the output expires.
+    pub direction: ExpirationDirection,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the unspent outputs of the account that expire within the given duration from now, ordered by their
+    /// expiration time. This includes outputs the account can claim before they go back to the sender and outputs sent
+    /// by the account that return to it if they don't get claimed.
+    pub async fn expiring_outputs(&self, within: Duration) -> crate::wallet::Result<Vec<ExpiringOutput>> {
+        log::debug!("[EXPIRING_OUTPUTS] expiring_outputs");
+
+        let network_id = self.client().get_network_id().await?;
+        let local_time = self.client().get_time_checked().await?;
+        let window_end = local_time.saturating_add(within.as_secs().try_into().unwrap_or(u32::MAX));
+
+        let account_details = self.details().await;
+
+        // Outputs can also be locked to the addresses of alias and nft outputs controlled by the account
+        let alias_and_nft_addresses = controlled_alias_and_nft_addresses(
+            &account_details.addresses_with_unspent_outputs,
+            account_details.unspent_outputs.values(),
+            local_time,
+        )?;
+        let is_account_address = |address: &Address| {
+            account_details
+                .public_addresses
+                .iter()
+                .chain(&account_details.internal_addresses)
+                .any(|a| a.address.inner == *address)
+                || alias_and_nft_addresses.contains(address)
+        };
+
+        let mut expiring_outputs = Vec::new();
+        for output_data in account_details.unspent_outputs.values() {
+            // Check if output is from the network we're currently connected to
+            if output_data.network_id != network_id {
+                continue;
+            }
+
+            let Some(unlock_conditions) = output_data.output.unlock_conditions() else {
+                continue;
+            };
+            let (Some(address), Some(expiration)) = (unlock_conditions.address(), unlock_conditions.expiration())
+            else {
+                continue;
+            };
+            if expiration.timestamp() <= local_time || expiration.timestamp() > window_end {
+                continue;
+            }
+
+            let direction = match (
+                is_account_address(address.address()),
+                is_account_address(expiration.return_address()),
+            ) {
+                (true, false) => ExpirationDirection::WillLeave,
+                (false, true) => ExpirationDirection::WillReturn,
+                // The funds stay in or out of the account either way
+                _ => continue,
+            };
+
+            expiring_outputs.push(ExpiringOutput {
+                output_id: output_data.output_id,
+                amount: output_data.output.amount(),
+                expires_at: expiration.timestamp(),
+                direction,
+            });
+        }
+
+        expiring_outputs.sort_unstable_by_key(|output| (output.expires_at, output.output_id));
+
+        Ok(expiring_outputs)
+    }
+}
@@ -3,7 +3,7 @@
 
 use crate::{
     types::block::{
-        address::Address,
+        address::{Address, AliasAddress, NftAddress},
         output::{AliasTransition, Output},
     },
     wallet::account::types::{AddressWithUnspentOutputs, OutputData},
@@ -36,12 +36,55 @@ pub(crate) fn can_output_be_unlocked_now(
         || alias_and_nft_addresses.iter().any(|a| *a == required_unlock_address))
 }
 
+// Get the alias and nft addresses of the alias and nft outputs that can be unlocked by the account at the current
+// time, also following ownership chains, like an nft owned by an alias owned by the account
+pub(crate) fn controlled_alias_and_nft_addresses<'a>(
+    account_addresses: &[AddressWithUnspentOutputs],
+    unspent_outputs: impl Iterator<Item = &'a OutputData> + Clone,
+    current_time: u32,
+) -> crate::wallet::Result<Vec<Address>> {
+    let mut alias_and_nft_addresses = Vec::new();
+
+    loop {
+        let mut new_addresses = Vec::new();
+
+        for output_data in unspent_outputs.clone() {
+            let address = match &output_data.output {
+                Output::Alias(alias) => {
+                    Address::from(AliasAddress::from(alias.alias_id_non_null(&output_data.output_id)))
+                }
+                Output::Nft(nft) => Address::from(NftAddress::from(nft.nft_id_non_null(&output_data.output_id))),
+                _ => continue,
+            };
+
+            if !alias_and_nft_addresses.contains(&address)
+                && can_output_be_unlocked_now(
+                    account_addresses,
+                    &alias_and_nft_addresses,
+                    output_data,
+                    current_time,
+                    // Only a state transition allows to unlock outputs locked to the alias address
+                    Some(AliasTransition::State),
+                )?
+            {
+                new_addresses.push(address);
+            }
+        }
+
+        if new_addresses.is_empty() {
+            return Ok(alias_and_nft_addresses);
+        }
+        alias_and_nft_addresses.extend(new_addresses);
+    }
+}
+
 // Check if an output can be unlocked by one of the account addresses at the current time and at any
 // point in the future
 pub(crate) fn can_output_be_unlocked_forever_from_now_on(
     // We use the addresses with unspent outputs, because other addresses of the account without unspent outputs can't
     // be related to this output
     account_addresses: &[AddressWithUnspentOutputs],
+    alias_and_nft_addresses: &[Address],
     output: &Output,
     current_time: u32,
 ) -> bool {
@@ -51,10 +94,12 @@ pub(crate) fn can_output_be_unlocked_forever_from_now_on(
         }
 
         // If there is an expiration unlock condition, we can only unlock it forever from now on, if it's expired and
-        // the return address belongs to the account
+        // the return address belongs to the account, also if it's the address of an alias or nft output it controls
         if let Some(expiration) = unlock_conditions.expiration() {
             if let Some(return_address) = expiration.return_address_expired(current_time) {
-                if !account_addresses.iter().any(|a| a.address.inner == *return_address) {
+                if !account_addresses.iter().any(|a| a.address.inner == *return_address)
+                    && !alias_and_nft_addresses.contains(return_address)
+                {
                     return false;
                 };
             } else {
@@ -67,3 +112,132 @@ pub(crate) fn can_output_be_unlocked_forever_from_now_on(
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Bech32Address, Ed25519Address, Hrp},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                StateControllerAddressUnlockCondition, TimelockUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, OutputId, OutputMetadata,
+        },
+        payload::transaction::TransactionId,
+        BlockId,
+    };
+
+    fn account_address() -> Address {
+        Address::from(Ed25519Address::new([1; 32]))
+    }
+
+    fn foreign_address() -> Address {
+        Address::from(Ed25519Address::new([2; 32]))
+    }
+
+    fn account_addresses() -> Vec<AddressWithUnspentOutputs> {
+        vec![AddressWithUnspentOutputs {
+            address: Bech32Address::new(Hrp::from_str_unchecked("rms"), account_address()),
+            key_index: 0,
+            internal: false,
+            output_ids: Vec::new(),
+        }]
+    }
+
+    fn output_data(index: u16, output: Output) -> OutputData {
+        let output_id = OutputId::new(TransactionId::new([1; 32]), index).unwrap();
+
+        OutputData {
+            output_id,
+            metadata: OutputMetadata::new(BlockId::new([2; 32]), output_id, false, None, None, None, 0, 0, 0),
+            output,
+            is_spent: false,
+            address: account_address(),
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        }
+    }
+
+    fn alias_output(alias_id: AliasId, state_controller: Address) -> Output {
+        AliasOutputBuilder::new_with_amount(1_000_000, alias_id)
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(state_controller))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(account_address()))
+            .finish_output(u64::MAX)
+            .unwrap()
+    }
+
+    #[test]
+    fn controlled_alias_and_nft_addresses_follow_ownership() {
+        let alias_id = AliasId::new([3; 32]);
+        let foreign_alias_id = AliasId::new([4; 32]);
+        let nft_id = NftId::new([5; 32]);
+        let timelocked_nft_id = NftId::new([6; 32]);
+        let alias_address = Address::from(AliasAddress::new(alias_id));
+
+        let unspent_outputs = [
+            // Owned by the alias, which is only known to be controlled after the first round
+            output_data(
+                0,
+                NftOutputBuilder::new_with_amount(1_000_000, nft_id)
+                    .add_unlock_condition(AddressUnlockCondition::new(alias_address))
+                    .finish_output(u64::MAX)
+                    .unwrap(),
+            ),
+            output_data(1, alias_output(alias_id, account_address())),
+            // Only the governor address belongs to the account
+            output_data(2, alias_output(foreign_alias_id, foreign_address())),
+            output_data(
+                3,
+                NftOutputBuilder::new_with_amount(1_000_000, timelocked_nft_id)
+                    .add_unlock_condition(AddressUnlockCondition::new(account_address()))
+                    .add_unlock_condition(TimelockUnlockCondition::new(200).unwrap())
+                    .finish_output(u64::MAX)
+                    .unwrap(),
+            ),
+        ];
+
+        assert_eq!(
+            controlled_alias_and_nft_addresses(&account_addresses(), unspent_outputs.iter(), 100).unwrap(),
+            [alias_address, Address::from(NftAddress::new(nft_id))]
+        );
+        // Once the timelock expired, the nft is controlled too
+        assert!(
+            controlled_alias_and_nft_addresses(&account_addresses(), unspent_outputs.iter(), 200)
+                .unwrap()
+                .contains(&Address::from(NftAddress::new(timelocked_nft_id)))
+        );
+    }
+
+    #[test]
+    fn expired_output_returned_to_controlled_alias() {
+        let alias_address = Address::from(AliasAddress::new(AliasId::new([3; 32])));
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(foreign_address()))
+            .add_unlock_condition(ExpirationUnlockCondition::new(alias_address, 50).unwrap())
+            .finish_output(u64::MAX)
+            .unwrap();
+
+        assert!(!can_output_be_unlocked_forever_from_now_on(
+            &account_addresses(),
+            &[],
+            &output,
+            100
+        ));
+        assert!(can_output_be_unlocked_forever_from_now_on(
+            &account_addresses(),
+            &[alias_address],
+            &output,
+            100
+        ));
+        // Not expired yet, so the recipient can still unlock it
+        assert!(!can_output_be_unlocked_forever_from_now_on(
+            &account_addresses(),
+            &[alias_address],
+            &output,
+            10
+        ));
+    }
+}
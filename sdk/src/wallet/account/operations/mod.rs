@@ -3,8 +3,15 @@
 
 /// The module for the address generation
 pub(crate) mod address_generation;
+/// The module for the rotation of the receive address
+pub(crate) mod address_rotation;
 /// The module to get the accounts balance
 pub(crate) mod balance;
+/// The module for the balance history
+#[cfg(feature = "storage")]
+pub(crate) mod balance_history;
+/// The module to get outputs that expire soon
+pub(crate) mod expiring_outputs;
 /// Helper functions
 pub(crate) mod helpers;
 /// The module for claiming of outputs with
@@ -18,6 +25,8 @@ pub(crate) mod output_finder;
 /// The module for participation
 #[cfg(feature = "participation")]
 pub(crate) mod participation;
+/// The module for proofs of reserves
+pub(crate) mod proof_of_reserves;
 /// The module for retrying blocks or transactions
 pub(crate) mod retry;
 /// The module for synchronization of an account
@@ -12,12 +12,13 @@ use crate::{
         output::{
             unlock_condition::{AddressUnlockCondition, StorageDepositReturnUnlockCondition},
             BasicOutputBuilder, MinimumStorageDepositBasicOutput, NativeTokens, NativeTokensBuilder, NftOutputBuilder,
-            Output, OutputId,
+            Output, OutputId, RentStructure,
         },
     },
     wallet::account::{
-        operations::helpers::time::can_output_be_unlocked_now, types::Transaction, Account, OutputData,
-        TransactionOptions,
+        operations::helpers::time::{can_output_be_unlocked_now, controlled_alias_and_nft_addresses},
+        types::Transaction,
+        Account, OutputData, TransactionOptions,
     },
 };
 
@@ -29,9 +30,16 @@ pub enum OutputsToClaim {
     NativeTokens,
     Nfts,
     Amount,
+    /// Outputs where the amount kept after returning the storage deposit covers the storage deposit of the claimed
+    /// funds, so claiming them doesn't require additional funds of the account.
+    Profitable,
     All,
 }
 
+/// The amount of outputs claimed in a single transaction by [`Account::claim_profitable_outputs()`]. Claiming can
+/// require twice as many outputs because of storage deposit returns, and there might be a remainder output.
+pub(crate) const CLAIM_OUTPUTS_CHUNK_SIZE: usize = 60;
+
 impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
@@ -47,6 +55,15 @@ where
         let account_details = self.details().await;
 
         let local_time = self.client().get_time_checked().await?;
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        // Outputs can also be locked to the addresses of alias and nft outputs controlled by the account
+        let alias_and_nft_addresses = controlled_alias_and_nft_addresses(
+            &account_details.addresses_with_unspent_outputs,
+            account_details.unspent_outputs.values(),
+            local_time,
+        )?;
 
         // Get outputs for the claim
         let mut output_ids_to_claim: HashSet<OutputId> = HashSet::new();
@@ -66,11 +83,10 @@ where
                             // We use the addresses with unspent outputs, because other addresses of the
                             // account without unspent outputs can't be related to this output
                             &account_details.addresses_with_unspent_outputs,
-                            // outputs controlled by an alias or nft are currently not considered
-                            &[],
+                            &alias_and_nft_addresses,
                             output_data,
                             local_time,
-                            // Not relevant without alias addresses
+                            // Only relevant for alias outputs
                             None,
                         )?
                     {
@@ -109,6 +125,11 @@ where
                                     output_ids_to_claim.insert(output_data.output_id);
                                 }
                             }
+                            OutputsToClaim::Profitable => {
+                                if is_claim_profitable(output_data, local_time, rent_structure, token_supply)? {
+                                    output_ids_to_claim.insert(output_data.output_id);
+                                }
+                            }
                             OutputsToClaim::All => {
                                 output_ids_to_claim.insert(output_data.output_id);
                             }
@@ -190,6 +211,32 @@ where
             })
     }
 
+    /// Claim all outputs from [`Account::claimable_outputs()`] with [`OutputsToClaim::Profitable`], in as many
+    /// transactions as needed. If a transaction fails after others were sent,
+    /// [`Error::PartiallySent`](crate::wallet::Error::PartiallySent) with the IDs of the sent transactions is returned.
+    pub async fn claim_profitable_outputs(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[OUTPUT_CLAIMING] claim_profitable_outputs");
+        let output_ids = self.claimable_outputs(OutputsToClaim::Profitable).await?;
+
+        let mut transactions = Vec::new();
+        for output_ids_chunk in output_ids.chunks(CLAIM_OUTPUTS_CHUNK_SIZE) {
+            match self.claim_outputs(output_ids_chunk.to_vec()).await {
+                Ok(transaction) => transactions.push(transaction),
+                Err(error) => {
+                    return Err(crate::wallet::Error::partially_sent(
+                        transactions
+                            .iter()
+                            .map(|transaction| transaction.transaction_id)
+                            .collect(),
+                        error,
+                    ));
+                }
+            }
+        }
+
+        Ok(transactions)
+    }
+
     /// Try to claim basic outputs that have additional unlock conditions to their [AddressUnlockCondition].
     pub(crate) async fn claim_outputs_internal<I: IntoIterator<Item = OutputId> + Send>(
         &self,
@@ -420,6 +467,32 @@ pub(crate) fn sdr_not_expired(output: &Output, current_time: u32) -> Option<&Sto
     })
 }
 
+/// Checks if the amount kept from claiming an output, after returning a not expired storage deposit, covers the
+/// storage deposit of the output holding the claimed funds, so no additional funds are required to claim it.
+pub(crate) fn is_claim_profitable(
+    output_data: &OutputData,
+    current_time: u32,
+    rent_structure: RentStructure,
+    token_supply: u64,
+) -> crate::wallet::Result<bool> {
+    let output = &output_data.output;
+    let kept_amount = output.amount() - sdr_not_expired(output, current_time).map_or(0, |sdr| sdr.amount());
+
+    let required_amount = match output {
+        Output::Nft(nft_output) => NftOutputBuilder::from(nft_output)
+            .with_minimum_storage_deposit(rent_structure)
+            .with_nft_id(nft_output.nft_id_non_null(&output_data.output_id))
+            .with_unlock_conditions([AddressUnlockCondition::new(*nft_output.address())])
+            .finish_output(token_supply)?
+            .amount(),
+        _ => MinimumStorageDepositBasicOutput::new(rent_structure, token_supply)
+            .with_native_tokens(output.native_tokens().filter(|n| !n.is_empty()).cloned())
+            .finish()?,
+    };
+
+    Ok(kept_amount > 0 && kept_amount >= required_amount)
+}
+
 // Helper function to calculate the native token count without duplicates, when new native tokens are added
 // Might be possible to refactor the sections where it's used to remove the clones
 pub(crate) fn get_new_native_token_count(
@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "ledger_nano")]
 use crate::client::secret::{ledger_nano::LedgerSecretManager, DowncastSecretManager};
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{AutoConsolidationEvent, WalletEvent};
 use crate::{
     client::{api::PreparedTransactionData, secret::SecretManage},
     types::block::{
@@ -37,7 +39,8 @@ use crate::wallet::{
     Result,
 };
 
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ConsolidationParams {
     /// Ignores the output_threshold if set to `true`.
@@ -45,7 +48,27 @@ pub struct ConsolidationParams {
     /// Consolidates if the output number is >= the output_threshold.
     output_threshold: Option<usize>,
     /// Address to which the consolidated output should be sent.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     target_address: Option<Bech32Address>,
+    /// Also consolidates outputs with other unlock conditions than the address unlock condition, like an expired
+    /// expiration or a timelock in the past.
+    #[serde(default = "default_unlock_conditions_allowed")]
+    unlock_conditions_allowed: bool,
+}
+
+fn default_unlock_conditions_allowed() -> bool {
+    true
+}
+
+impl Default for ConsolidationParams {
+    fn default() -> Self {
+        Self {
+            force: false,
+            output_threshold: None,
+            target_address: None,
+            unlock_conditions_allowed: default_unlock_conditions_allowed(),
+        }
+    }
 }
 
 impl ConsolidationParams {
@@ -67,13 +90,87 @@ impl ConsolidationParams {
         self.target_address = target_address.into();
         self
     }
+
+    pub fn with_unlock_conditions_allowed(mut self, unlock_conditions_allowed: bool) -> Self {
+        self.unlock_conditions_allowed = unlock_conditions_allowed;
+        self
+    }
+}
+
+/// Defines when the basic outputs of an account are consolidated automatically while syncing.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsolidationPolicy {
+    /// Consolidates once the number of spendable basic outputs is >= the output_threshold.
+    pub output_threshold: usize,
+    /// Also consolidates outputs with other unlock conditions than the address unlock condition, like an expired
+    /// expiration or a timelock in the past.
+    pub unlock_conditions_allowed: bool,
 }
 
 impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    fn should_consolidate_output(
+    /// Returns whether the account uses a Ledger Nano, which asks to confirm every transaction on the device.
+    async fn uses_ledger_nano(&self) -> bool {
+        #[cfg(feature = "ledger_nano")]
+        {
+            use crate::wallet::account::SecretManager;
+            let secret_manager = self.wallet.secret_manager.read().await;
+            secret_manager.downcast::<LedgerSecretManager>().is_some()
+                || matches!(
+                    secret_manager.downcast::<SecretManager>(),
+                    Some(SecretManager::LedgerNano(_))
+                )
+        }
+        #[cfg(not(feature = "ledger_nano"))]
+        false
+    }
+
+    /// Returns the maximum amount of inputs a transaction of the account with `output_count` outputs can have, which is
+    /// lower than the protocol limit for a ledger nano without blind signing.
+    #[cfg_attr(not(feature = "ledger_nano"), allow(unused_variables))]
+    pub(crate) async fn max_transaction_inputs(&self, output_count: usize) -> u16 {
+        #[cfg(feature = "ledger_nano")]
+        {
+            use crate::wallet::account::SecretManager;
+            let secret_manager = self.wallet.secret_manager.read().await;
+            if let Some(ledger) = secret_manager.downcast::<LedgerSecretManager>().or_else(|| {
+                secret_manager.downcast::<SecretManager>().and_then(|s| {
+                    if let SecretManager::LedgerNano(n) = s {
+                        Some(n)
+                    } else {
+                        None
+                    }
+                })
+            }) {
+                let ledger_nano_status = ledger.get_ledger_nano_status().await;
+                // With blind signing we are only limited by the protocol
+                if ledger_nano_status.blind_signing_enabled() {
+                    INPUT_COUNT_MAX
+                } else {
+                    ledger_nano_status
+                        .buffer_size()
+                        .map(|buffer_size| {
+                            // Calculate how many inputs we can have with this ledger, buffer size is different for
+                            // different ledger types
+                            let available_buffer_size_for_inputs = buffer_size
+                                - ESSENCE_SIZE_WITHOUT_IN_AND_OUTPUTS
+                                - output_count * MIN_OUTPUT_SIZE_IN_ESSENCE;
+                            (available_buffer_size_for_inputs / INPUT_SIZE) as u16
+                        })
+                        .unwrap_or(INPUT_COUNT_MAX)
+                }
+            } else {
+                INPUT_COUNT_MAX
+            }
+        }
+        #[cfg(not(feature = "ledger_nano"))]
+        INPUT_COUNT_MAX
+    }
+
+    pub(crate) fn should_consolidate_output(
         &self,
         output_data: &OutputData,
         current_time: u32,
@@ -102,6 +199,104 @@ where
         })
     }
 
+    /// Sets the policy that consolidates the basic outputs automatically while syncing, once there are
+    /// `output_threshold` or more of them. Every consolidation transaction is reported with a
+    /// [`WalletEvent::AutoConsolidation`](crate::wallet::events::types::WalletEvent::AutoConsolidation) event.
+    /// If storage is enabled, will persist during restarts. The policy isn't applied with a Ledger Nano, which would
+    /// ask to confirm every consolidation during background syncs.
+    pub async fn set_consolidation_policy(
+        &self,
+        output_threshold: usize,
+        unlock_conditions_allowed: bool,
+    ) -> Result<()> {
+        self.update_consolidation_policy(Some(ConsolidationPolicy {
+            output_threshold,
+            unlock_conditions_allowed,
+        }))
+        .await
+    }
+
+    /// Stops consolidating the basic outputs automatically while syncing.
+    pub async fn clear_consolidation_policy(&self) -> Result<()> {
+        self.update_consolidation_policy(None).await
+    }
+
+    async fn update_consolidation_policy(&self, policy: Option<ConsolidationPolicy>) -> Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_consolidation_policy(index, policy.as_ref()).await?;
+        }
+
+        *self.consolidation_policy.lock().await = policy;
+        Ok(())
+    }
+
+    /// Returns the policy that consolidates the basic outputs automatically while syncing, if one is set.
+    pub async fn consolidation_policy(&self) -> Option<ConsolidationPolicy> {
+        *self.consolidation_policy.lock().await
+    }
+
+    /// Consolidates the basic outputs during syncing as long as the [`ConsolidationPolicy`] requires it, so more than
+    /// one transaction is sent if the outputs don't fit into a single one. A failed consolidation doesn't fail the
+    /// sync, it's only reported.
+    pub(crate) async fn apply_consolidation_policy(&self) {
+        let Some(policy) = self.consolidation_policy().await else {
+            return;
+        };
+        if self.uses_ledger_nano().await {
+            log::debug!("[OUTPUT_CONSOLIDATION] skipping automatic consolidation with a Ledger Nano");
+            return;
+        }
+        let params = ConsolidationParams::new()
+            .with_output_threshold(policy.output_threshold)
+            .with_unlock_conditions_allowed(policy.unlock_conditions_allowed);
+
+        loop {
+            // The inputs of previous consolidation transactions are locked, so they aren't selected again
+            let (consolidated_outputs, result) = match self.prepare_consolidate_outputs(params.clone()).await {
+                Ok(prepared_transaction) => (
+                    prepared_transaction.inputs_data.len(),
+                    self.sign_and_submit_transaction(prepared_transaction, None).await,
+                ),
+                Err(crate::wallet::Error::NoOutputsToConsolidate { .. }) => break,
+                Err(e) => (0, Err(e)),
+            };
+
+            match &result {
+                Ok(transaction) => log::debug!(
+                    "[OUTPUT_CONSOLIDATION] automatic consolidation of {consolidated_outputs} outputs: tx_id: {:?}",
+                    transaction.transaction_id
+                ),
+                Err(e) => log::warn!("[OUTPUT_CONSOLIDATION] automatic consolidation failed: {e}"),
+            }
+
+            #[cfg(feature = "events")]
+            {
+                let account_index = *self.details().await.index();
+                let event = match &result {
+                    Ok(transaction) => AutoConsolidationEvent {
+                        consolidated_outputs,
+                        transaction_id: Some(transaction.transaction_id),
+                        error: None,
+                    },
+                    Err(e) => AutoConsolidationEvent {
+                        consolidated_outputs,
+                        transaction_id: None,
+                        error: Some(e.to_string()),
+                    },
+                };
+                self.emit(account_index, WalletEvent::AutoConsolidation(Box::new(event)))
+                    .await;
+            }
+
+            if result.is_err() {
+                break;
+            }
+        }
+    }
+
     /// Consolidates basic outputs with only an [AddressUnlockCondition] from an account by sending them to a provided
     /// address or to an own address again if the output amount is >= the output_threshold. When `force`
     /// is set to `true`, the threshold is ignored. Only consolidates the amount of outputs that fit into a single
@@ -123,6 +318,8 @@ where
     /// [Account::consolidate_outputs()](crate::wallet::Account::consolidate_outputs).
     pub async fn prepare_consolidate_outputs(&self, params: ConsolidationParams) -> Result<PreparedTransactionData> {
         log::debug!("[OUTPUT_CONSOLIDATION] prepare consolidating outputs if needed");
+        // Held until the selected inputs are locked, so concurrent consolidations don't select the same outputs
+        let _consolidation = self.consolidation.lock().await;
         #[cfg(feature = "participation")]
         let voting_output = self.get_voting_output().await?;
         let current_time = self.client().get_time_checked().await?;
@@ -140,6 +337,13 @@ where
                 }
             }
             let is_locked_output = account_details.locked_outputs.contains(output_id);
+            let has_other_unlock_conditions = output_data
+                .output
+                .unlock_conditions()
+                .map_or(false, |unlock_conditions| unlock_conditions.len() > 1);
+            if !params.unlock_conditions_allowed && has_other_unlock_conditions {
+                continue;
+            }
             let should_consolidate_output =
                 self.should_consolidate_output(output_data, current_time, account_addresses)?;
             if !is_locked_output && should_consolidate_output {
@@ -153,26 +357,10 @@ where
             Some(t) => t,
             None => {
                 #[cfg(feature = "ledger_nano")]
-                {
-                    use crate::wallet::account::SecretManager;
-                    let secret_manager = self.wallet.secret_manager.read().await;
-                    if secret_manager
-                        .downcast::<LedgerSecretManager>()
-                        .or_else(|| {
-                            secret_manager.downcast::<SecretManager>().and_then(|s| {
-                                if let SecretManager::LedgerNano(n) = s {
-                                    Some(n)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .is_some()
-                    {
-                        DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD
-                    } else {
-                        DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD
-                    }
+                if self.uses_ledger_nano().await {
+                    DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD
+                } else {
+                    DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD
                 }
                 #[cfg(not(feature = "ledger_nano"))]
                 DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD
@@ -192,41 +380,8 @@ where
             });
         }
 
-        #[cfg(feature = "ledger_nano")]
-        let max_inputs = {
-            use crate::wallet::account::SecretManager;
-            let secret_manager = self.wallet.secret_manager.read().await;
-            if let Some(ledger) = secret_manager.downcast::<LedgerSecretManager>().or_else(|| {
-                secret_manager.downcast::<SecretManager>().and_then(|s| {
-                    if let SecretManager::LedgerNano(n) = s {
-                        Some(n)
-                    } else {
-                        None
-                    }
-                })
-            }) {
-                let ledger_nano_status = ledger.get_ledger_nano_status().await;
-                // With blind signing we are only limited by the protocol
-                if ledger_nano_status.blind_signing_enabled() {
-                    INPUT_COUNT_MAX
-                } else {
-                    ledger_nano_status
-                        .buffer_size()
-                        .map(|buffer_size| {
-                            // Calculate how many inputs we can have with this ledger, buffer size is different for
-                            // different ledger types
-                            let available_buffer_size_for_inputs =
-                                buffer_size - ESSENCE_SIZE_WITHOUT_IN_AND_OUTPUTS - MIN_OUTPUT_SIZE_IN_ESSENCE;
-                            (available_buffer_size_for_inputs / INPUT_SIZE) as u16
-                        })
-                        .unwrap_or(INPUT_COUNT_MAX)
-                }
-            } else {
-                INPUT_COUNT_MAX
-            }
-        };
-        #[cfg(not(feature = "ledger_nano"))]
-        let max_inputs = INPUT_COUNT_MAX;
+        // The consolidation creates a single output
+        let max_inputs = self.max_transaction_inputs(1).await;
 
         let mut total_amount = 0;
         let mut custom_inputs = Vec::with_capacity(max_inputs.into());
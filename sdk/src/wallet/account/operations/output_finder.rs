@@ -13,12 +13,15 @@ where
     crate::wallet::Error: From<S::Error>,
 {
     /// Search addresses with unspent outputs
-    /// `address_gap_limit`: The number of addresses to search for, after the last address with unspent outputs
+    /// `address_gap_limit`: The number of public addresses to search for, after the last address with unspent outputs
+    /// `address_gap_limit_internal`: The number of internal addresses to search for, after the last internal address
+    /// with unspent outputs
     /// Addresses that got crated during this operation and have a higher key_index than the latest one with outputs,
     /// will be removed again, to keep the account size smaller
     pub(crate) async fn search_addresses_with_outputs(
         &self,
         mut address_gap_limit: u32,
+        mut address_gap_limit_internal: u32,
         sync_options: Option<SyncOptions>,
     ) -> crate::wallet::Result<usize> {
         log::debug!("[search_addresses_with_outputs]");
@@ -71,8 +74,6 @@ where
                 .await?;
         }
 
-        let mut address_gap_limit_internal = address_gap_limit;
-
         let mut latest_outputs_count = 0;
         loop {
             // Also needs to be in the loop so it gets updated every round for internal use without modifying the values
@@ -0,0 +1,186 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip44::Bip44,
+};
+use packable::PackableExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Address, Bech32Address},
+        output::OutputId,
+        signature::Ed25519Signature,
+        Error,
+    },
+    wallet::account::Account,
+};
+
+/// An address of a [`ProofOfReserves`] with the amount it holds and a signature proving its ownership.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressReserves {
+    /// The address.
+    pub address: Bech32Address,
+    /// The amount of base coins of the outputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The unspent outputs the address can unlock, so the amount can be checked against the ledger.
+    pub output_ids: Vec<OutputId>,
+    /// The signature over the nonce, the ledger index, the address and the amount, see
+    /// [`ProofOfReserves::signed_message()`].
+    #[serde(with = "ed25519_signature")]
+    pub signature: Ed25519Signature,
+}
+
+/// A signed statement of the addresses of an account and the amounts they hold, for auditors. Created with
+/// [`Account::proof_of_reserves()`] and checked with [`ProofOfReserves::verify()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofOfReserves {
+    /// The nonce supplied by the auditor, so that a proof can't be reused.
+    #[serde(with = "crate::utils::serde::prefix_hex_bytes")]
+    pub nonce: Vec<u8>,
+    /// The highest ledger index the outputs were returned with by the node. Every output was unspent at the ledger
+    /// index of its response, which isn't higher than this one.
+    pub ledger_index: u32,
+    /// The addresses with their amounts and signatures.
+    pub addresses: Vec<AddressReserves>,
+}
+
+impl ProofOfReserves {
+    /// Returns the amount of base coins of all addresses.
+    pub fn total_amount(&self) -> u64 {
+        self.addresses.iter().map(|address| address.amount).sum()
+    }
+
+    /// Returns the message that is signed for an address: the Blake2b256 hash of the nonce, the ledger index, the
+    /// packed address and the amount, with integers in little endian.
+    pub fn signed_message(&self, address: &Bech32Address, amount: u64) -> [u8; 32] {
+        let mut hasher = Blake2b256::new();
+        hasher.update(&self.nonce);
+        hasher.update(self.ledger_index.to_le_bytes());
+        hasher.update(address.inner().pack_to_vec());
+        hasher.update(amount.to_le_bytes());
+        hasher.finalize().into()
+    }
+
+    /// Verifies that every address signed its amount for the nonce and the ledger index. This doesn't need a node, the
+    /// amounts can be compared with the ledger by requesting the output IDs.
+    pub fn verify(&self) -> Result<(), Error> {
+        for address_reserves in &self.addresses {
+            let Address::Ed25519(address) = address_reserves.address.inner() else {
+                return Err(Error::InvalidAddressKind(address_reserves.address.inner().kind()));
+            };
+            address_reserves.signature.is_valid(
+                &self.signed_message(&address_reserves.address, address_reserves.amount),
+                address,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Creates a [`ProofOfReserves`] for the addresses of the account with unspent outputs, signed over the nonce
+    /// supplied by an auditor. The account is synced first and the outputs are requested from the node again, so the
+    /// amounts are the ones of the ledger; only outputs an address can unlock now are counted. With a Ledger Nano every
+    /// address signature needs to be confirmed on the device.
+    pub async fn proof_of_reserves(&self, nonce: impl Into<Vec<u8>> + Send) -> crate::wallet::Result<ProofOfReserves> {
+        log::debug!("[PROOF_OF_RESERVES] proof_of_reserves");
+        // Sync so outputs received since the last sync are included
+        self.sync(None).await?;
+        let local_time = self.client().get_time_checked().await?;
+        let (coin_type, account_index, addresses_with_unspent_outputs) = {
+            let account_details = self.details().await;
+            (
+                account_details.coin_type,
+                account_details.index,
+                account_details.addresses_with_unspent_outputs.clone(),
+            )
+        };
+
+        let mut proof = ProofOfReserves {
+            nonce: nonce.into(),
+            ledger_index: 0,
+            addresses: Vec::new(),
+        };
+        let mut unsigned_addresses = Vec::new();
+
+        for address_with_unspent_outputs in addresses_with_unspent_outputs {
+            let mut amount = 0;
+            let mut output_ids = Vec::new();
+
+            for output_with_metadata in self
+                .client()
+                .get_outputs(&address_with_unspent_outputs.output_ids)
+                .await?
+            {
+                let metadata = output_with_metadata.metadata();
+                proof.ledger_index = proof.ledger_index.max(metadata.ledger_index());
+                if metadata.is_spent() {
+                    continue;
+                }
+                let (required_address, _) = output_with_metadata.output().required_and_unlocked_address(
+                    local_time,
+                    metadata.output_id(),
+                    None,
+                )?;
+                if required_address == address_with_unspent_outputs.address.inner {
+                    amount += output_with_metadata.output().amount();
+                    output_ids.push(*metadata.output_id());
+                }
+            }
+
+            if !output_ids.is_empty() {
+                unsigned_addresses.push((address_with_unspent_outputs, amount, output_ids));
+            }
+        }
+
+        for (address_with_unspent_outputs, amount, output_ids) in unsigned_addresses {
+            let chain = Bip44::new(coin_type)
+                .with_account(account_index)
+                .with_change(address_with_unspent_outputs.internal as _)
+                .with_address_index(address_with_unspent_outputs.key_index);
+            let message = proof.signed_message(&address_with_unspent_outputs.address, amount);
+            let signature = self
+                .wallet
+                .secret_manager
+                .read()
+                .await
+                .sign_ed25519(&message, chain)
+                .await?;
+
+            proof.addresses.push(AddressReserves {
+                address: address_with_unspent_outputs.address,
+                amount,
+                output_ids,
+                signature,
+            });
+        }
+
+        Ok(proof)
+    }
+}
+
+mod ed25519_signature {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::types::block::signature::{dto::Ed25519SignatureDto, Ed25519Signature};
+
+    pub fn serialize<S: Serializer>(signature: &Ed25519Signature, serializer: S) -> Result<S::Ok, S::Error> {
+        Ed25519SignatureDto::from(signature).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ed25519Signature, D::Error> {
+        Ed25519SignatureDto::deserialize(deserializer)
+            .and_then(|dto| Ed25519Signature::try_from(dto).map_err(serde::de::Error::custom))
+    }
+}
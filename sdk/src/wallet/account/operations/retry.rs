@@ -67,12 +67,16 @@ where
 
             let block_id = match transaction.block_id {
                 Some(block_id) => block_id,
-                None => self
-                    .client()
-                    .build_block()
-                    .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
-                    .await?
-                    .id(),
+                None => {
+                    let block_id = self
+                        .client()
+                        .build_block()
+                        .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
+                        .await?
+                        .id();
+                    self.add_transaction_attachment(transaction_id, block_id).await?;
+                    block_id
+                }
             };
 
             // Attachments of the Block to check inclusion state
@@ -110,10 +114,15 @@ where
                         } else if block_metadata.should_reattach.unwrap_or(false) {
                             let reattached_block = self
                                 .client()
-                                .build_block()
-                                .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
+                                .finish_block_builder(
+                                    None,
+                                    Some(Payload::Transaction(Box::new(transaction.payload.clone()))),
+                                )
+                                .await?;
+                            let reattached_block_id = self.client().post_reattachment(&reattached_block).await?;
+                            block_ids.push(reattached_block_id);
+                            self.add_transaction_attachment(transaction_id, reattached_block_id)
                                 .await?;
-                            block_ids.push(reattached_block.id());
                         }
                     }
                 }
@@ -136,4 +145,21 @@ where
             Err(Error::TransactionNotFound(*transaction_id))
         }
     }
+
+    // Stores a new attachment of a transaction, so it's known in which blocks the transaction was sent
+    async fn add_transaction_attachment(
+        &self,
+        transaction_id: &TransactionId,
+        block_id: BlockId,
+    ) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        if let Some(transaction) = account_details.transactions.get_mut(transaction_id) {
+            transaction.block_id.replace(block_id);
+            transaction.attachments.push(block_id);
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
 }
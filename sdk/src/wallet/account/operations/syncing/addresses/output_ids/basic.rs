@@ -10,8 +10,15 @@ use futures::FutureExt;
 #[cfg(not(target_family = "wasm"))]
 use crate::types::api::plugins::indexer::OutputIdsResponse;
 use crate::{
-    client::{node_api::indexer::query_parameters::QueryParameter, secret::SecretManage},
-    types::block::{address::Bech32Address, output::OutputId, ConvertTo},
+    client::{
+        node_api::indexer::{outputs_query::OutputsQuery, query_parameters::QueryParameter},
+        secret::SecretManage,
+    },
+    types::block::{
+        address::Bech32Address,
+        output::{BasicOutput, OutputId},
+        ConvertTo,
+    },
     wallet::Account,
 };
 
@@ -26,16 +33,16 @@ where
     ) -> crate::client::Result<Vec<OutputId>> {
         let bech32_address = bech32_address.convert()?;
         // Only request basic outputs with `AddressUnlockCondition` only
-        Ok(self
-            .client()
-            .basic_output_ids([
-                QueryParameter::Address(bech32_address),
-                QueryParameter::HasExpiration(false),
-                QueryParameter::HasTimelock(false),
-                QueryParameter::HasStorageDepositReturn(false),
-            ])
-            .await?
-            .items)
+        self.client()
+            .outputs_query_ids(
+                &OutputsQuery::new()
+                    .with_output_types(vec![BasicOutput::KIND])
+                    .with_address(bech32_address)
+                    .with_has_expiration(false)
+                    .with_has_timelock(false)
+                    .with_has_storage_deposit_return(false),
+            )
+            .await
     }
 
     /// Returns output ids of basic outputs that have the address in the `AddressUnlockCondition`,
@@ -11,16 +11,15 @@ use std::collections::HashSet;
 use futures::FutureExt;
 use instant::Instant;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::SyncStage;
 use crate::{
     client::secret::SecretManage,
     types::block::{
         address::{Address, Bech32Address},
         output::OutputId,
     },
-    wallet::account::{
-        constants::PARALLEL_REQUESTS_AMOUNT, operations::syncing::SyncOptions,
-        types::address::AddressWithUnspentOutputs, Account,
-    },
+    wallet::account::{operations::syncing::SyncOptions, types::address::AddressWithUnspentOutputs, Account},
 };
 
 impl<S: 'static + SecretManage> Account<S>
@@ -157,6 +156,7 @@ where
         &self,
         options: &SyncOptions,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+        parallel_requests: usize,
     ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputId>)> {
         log::debug!("[SYNC] start get_output_ids_for_addresses");
         let address_output_ids_start_time = Instant::now();
@@ -164,9 +164,16 @@ where
         let mut addresses_with_outputs = Vec::new();
         // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
         let mut spent_or_not_anymore_synced_outputs = Vec::new();
+
+        #[cfg(feature = "events")]
+        let (total, mut processed) = (addresses_with_unspent_outputs.len(), 0);
+        #[cfg(feature = "events")]
+        self.emit_sync_progress(SyncStage::AddressDiscovery, processed, total)
+            .await;
+
         // We split the addresses into chunks so we don't get timeouts if we have thousands
         for addresses_chunk in &mut addresses_with_unspent_outputs
-            .chunks(PARALLEL_REQUESTS_AMOUNT)
+            .chunks(parallel_requests)
             .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
         {
             let results;
@@ -200,6 +207,13 @@ where
                 results = futures::future::try_join_all(tasks).await?;
             }
 
+            #[cfg(feature = "events")]
+            {
+                processed += results.len();
+                self.emit_sync_progress(SyncStage::AddressDiscovery, processed, total)
+                    .await;
+            }
+
             for res in results {
                 let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
                 // only return addresses with outputs
@@ -3,12 +3,12 @@
 
 use instant::Instant;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::SyncStage;
 use crate::{
     client::secret::SecretManage,
     wallet::{
-        account::{
-            constants::PARALLEL_REQUESTS_AMOUNT, types::address::AddressWithUnspentOutputs, Account, OutputData,
-        },
+        account::{types::address::AddressWithUnspentOutputs, Account, OutputData},
         task,
     },
 };
@@ -21,6 +21,7 @@ where
     pub(crate) async fn get_outputs_from_address_output_ids(
         &self,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+        parallel_requests: usize,
     ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputData>)> {
         log::debug!("[SYNC] start get_outputs_from_address_output_ids");
         let address_outputs_start_time = Instant::now();
@@ -28,9 +29,21 @@ where
         let mut addresses_with_outputs = Vec::new();
         let mut outputs_data = Vec::new();
 
+        #[cfg(feature = "events")]
+        let (total, mut processed) = (
+            addresses_with_unspent_outputs
+                .iter()
+                .map(|address| address.output_ids.len())
+                .sum::<usize>(),
+            0,
+        );
+        #[cfg(feature = "events")]
+        self.emit_sync_progress(SyncStage::OutputFetching, processed, total)
+            .await;
+
         // We split the addresses into chunks so we don't get timeouts if we have thousands
         for addresses_chunk in &mut addresses_with_unspent_outputs
-            .chunks(PARALLEL_REQUESTS_AMOUNT)
+            .chunks(parallel_requests)
             .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
         {
             let mut tasks = Vec::new();
@@ -51,9 +64,17 @@ where
             let results = futures::future::try_join_all(tasks).await?;
             for res in results {
                 let (address, outputs): (AddressWithUnspentOutputs, Vec<OutputData>) = res?;
+                #[cfg(feature = "events")]
+                {
+                    processed += address.output_ids.len();
+                }
                 addresses_with_outputs.push(address);
                 outputs_data.extend(outputs);
             }
+
+            #[cfg(feature = "events")]
+            self.emit_sync_progress(SyncStage::OutputFetching, processed, total)
+                .await;
         }
         log::debug!(
             "[SYNC] finished get_outputs_from_address_output_ids in {:.2?}",
@@ -10,6 +10,8 @@ pub(crate) mod transactions;
 use std::collections::{HashMap, HashSet};
 
 pub use self::options::SyncOptions;
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{LockedAddressChangedEvent, SyncProgressEvent, SyncStage, WalletEvent};
 use crate::{
     client::secret::SecretManage,
     types::block::{
@@ -17,7 +19,8 @@ use crate::{
         output::{FoundryId, Output, OutputId, OutputMetadata},
     },
     wallet::account::{
-        constants::MIN_SYNC_INTERVAL,
+        constants::{MIN_SYNC_INTERVAL, PARALLEL_REQUESTS_AMOUNT},
+        operations::helpers::time::controlled_alias_and_nft_addresses,
         types::{AddressWithUnspentOutputs, OutputData},
         Account, Balance,
     },
@@ -49,6 +52,17 @@ where
     /// Sync the account by fetching new information from the nodes. Will also retry pending transactions
     /// if necessary. A custom default can be set using set_default_sync_options.
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
+        self.sync_with_parallel_requests(options, PARALLEL_REQUESTS_AMOUNT)
+            .await
+    }
+
+    /// Sync the account like [`Account::sync()`], with at most `parallel_requests` requests for the outputs of the
+    /// addresses in parallel, so accounts that are synced concurrently can share a bound on the requests.
+    pub(crate) async fn sync_with_parallel_requests(
+        &self,
+        options: Option<SyncOptions>,
+        parallel_requests: usize,
+    ) -> crate::wallet::Result<Balance> {
         let options = match options {
             Some(opt) => opt,
             None => self.default_sync_options().await,
@@ -57,11 +71,15 @@ where
         log::debug!("[SYNC] start syncing with {:?}", options);
         let syc_start_time = instant::Instant::now();
 
+        // Syncing specific addresses only refreshes a part of the account, so it's neither skipped nor counted as the
+        // last sync
+        let partial_sync = !options.addresses.is_empty();
+
         // Prevent syncing the account multiple times simultaneously
         let time_now = crate::utils::unix_timestamp_now().as_millis();
         let mut last_synced = self.last_synced.lock().await;
         log::debug!("[SYNC] last time synced before {}ms", time_now - *last_synced);
-        if !options.force_syncing && time_now - *last_synced < MIN_SYNC_INTERVAL {
+        if !options.force_syncing && !partial_sync && time_now - *last_synced < MIN_SYNC_INTERVAL {
             log::debug!(
                 "[SYNC] synced within the latest {} ms, only calculating balance",
                 MIN_SYNC_INTERVAL
@@ -71,7 +89,7 @@ where
             return self.balance().await;
         }
 
-        self.sync_internal(&options).await?;
+        self.sync_internal(&options, parallel_requests).await?;
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
         // status
@@ -80,19 +98,105 @@ where
             // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
             if confirmed_tx_with_unknown_output {
                 log::debug!("[SYNC] a transaction for which no output is known got confirmed, syncing outputs again");
-                self.sync_internal(&options).await?;
+                self.sync_internal(&options, parallel_requests).await?;
             }
         };
 
+        // Request the status of new voting outputs, the status of spent ones is cached in the storage. The outputs are
+        // already synced, so an unavailable participation plugin doesn't fail the sync.
+        #[cfg(feature = "participation")]
+        if options.sync_participation {
+            if let Err(e) = self.get_participation_overview(None).await {
+                log::warn!("[SYNC] syncing the participations failed: {e}");
+            }
+        }
+
+        #[cfg(feature = "events")]
+        self.emit_locked_address_changes().await?;
+
+        self.apply_address_rotation_policy().await?;
+
+        if !partial_sync {
+            self.apply_consolidation_policy().await;
+        }
+
         let balance = self.balance().await?;
+        #[cfg(feature = "storage")]
+        if !partial_sync {
+            self.record_balance_snapshot(&balance).await?;
+        }
         // Update last_synced mutex
-        let time_now = crate::utils::unix_timestamp_now().as_millis();
-        *last_synced = time_now;
+        if !partial_sync {
+            let time_now = crate::utils::unix_timestamp_now().as_millis();
+            *last_synced = time_now;
+        }
         log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
         Ok(balance)
     }
 
-    async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
+    // Emits an event for every unspent output whose expiration expired since the last sync, because it's now attributed
+    // to its return address instead of its nominal recipient
+    #[cfg(feature = "events")]
+    async fn emit_locked_address_changes(&self) -> crate::wallet::Result<()> {
+        let milestone_timestamp = self.client().get_latest_milestone_timestamp().await?;
+        let previous_milestone_timestamp = self
+            .last_synced_milestone_timestamp
+            .lock()
+            .await
+            .replace(milestone_timestamp);
+
+        let Some(previous_milestone_timestamp) = previous_milestone_timestamp else {
+            return Ok(());
+        };
+        if previous_milestone_timestamp >= milestone_timestamp {
+            return Ok(());
+        }
+
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let account_details = self.details().await;
+        let account_index = *account_details.index();
+        let events = account_details
+            .unspent_outputs
+            .values()
+            .filter_map(|output_data| {
+                let unlock_conditions = output_data.output.unlock_conditions()?;
+                let address = unlock_conditions.address()?.address();
+                let expiration = unlock_conditions.expiration()?;
+
+                (previous_milestone_timestamp < expiration.timestamp() && expiration.timestamp() <= milestone_timestamp)
+                    .then(|| LockedAddressChangedEvent {
+                        output_id: output_data.output_id,
+                        previous_address: address.to_bech32(bech32_hrp),
+                        address: expiration.return_address().to_bech32(bech32_hrp),
+                    })
+            })
+            .collect::<Vec<_>>();
+        drop(account_details);
+
+        for event in events {
+            self.emit(account_index, WalletEvent::LockedAddressChanged(Box::new(event)))
+                .await;
+        }
+
+        Ok(())
+    }
+
+    // Emits the progress of a sync stage, so a UI can show how far the sync is
+    #[cfg(feature = "events")]
+    pub(crate) async fn emit_sync_progress(&self, stage: SyncStage, processed: usize, total: usize) {
+        let account_index = *self.details().await.index();
+        self.emit(
+            account_index,
+            WalletEvent::SyncProgress(SyncProgressEvent {
+                stage,
+                processed,
+                total,
+            }),
+        )
+        .await;
+    }
+
+    async fn sync_internal(&self, options: &SyncOptions, parallel_requests: usize) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] sync_internal");
 
         let addresses_to_sync = self.get_addresses_to_sync(options).await?;
@@ -102,7 +206,9 @@ where
             Vec<OutputId>,
             Vec<AddressWithUnspentOutputs>,
             Vec<OutputData>,
-        ) = self.request_outputs_recursively(addresses_to_sync, options).await?;
+        ) = self
+            .request_outputs_recursively(addresses_to_sync, options, parallel_requests)
+            .await?;
 
         // Request possible spent outputs
         log::debug!("[SYNC] spent_or_not_synced_outputs: {spent_or_not_synced_output_ids:?}");
@@ -154,17 +260,20 @@ where
         .await
     }
 
-    // First request all outputs directly related to the ed25519 addresses, then for each nft and alias output we got,
-    // request all outputs that are related to their alias/nft addresses in a loop until no new alias or nft outputs is
-    // found
+    // First request all outputs directly related to the ed25519 addresses, then for each nft and alias output we got
+    // and can unlock, request all outputs that are related to their alias/nft addresses in a loop until no new alias or
+    // nft outputs is found
     async fn request_outputs_recursively(
         &self,
         addresses_to_sync: Vec<AddressWithUnspentOutputs>,
         options: &SyncOptions,
+        parallel_requests: usize,
     ) -> crate::wallet::Result<(Vec<OutputId>, Vec<AddressWithUnspentOutputs>, Vec<OutputData>)> {
         // Cache the alias and nft address with the related ed2559 address, so we can update the account address with
         // the new output ids
         let mut new_alias_and_nft_addresses = HashMap::new();
+        let mut synced_alias_and_nft_addresses = HashSet::new();
+        let current_time = self.client().get_time_checked().await?;
         let (mut spent_or_not_synced_output_ids, mut addresses_with_unspent_outputs, mut outputs_data) =
             (Vec::new(), Vec::new(), Vec::new());
 
@@ -172,12 +281,12 @@ where
             let new_outputs_data = if new_alias_and_nft_addresses.is_empty() {
                 // Get outputs for addresses and add them also the the addresses_with_unspent_outputs
                 let (addresses_with_output_ids, spent_or_not_synced_output_ids_inner) = self
-                    .get_output_ids_for_addresses(options, addresses_to_sync.clone())
+                    .get_output_ids_for_addresses(options, addresses_to_sync.clone(), parallel_requests)
                     .await?;
                 spent_or_not_synced_output_ids = spent_or_not_synced_output_ids_inner;
                 // Get outputs for addresses and add them also the the addresses_with_unspent_outputs
                 let (addresses_with_unspent_outputs_inner, outputs_data_inner) = self
-                    .get_outputs_from_address_output_ids(addresses_with_output_ids)
+                    .get_outputs_from_address_output_ids(addresses_with_output_ids, parallel_requests)
                     .await?;
                 addresses_with_unspent_outputs = addresses_with_unspent_outputs_inner;
                 outputs_data.extend(outputs_data_inner.clone());
@@ -211,20 +320,24 @@ where
 
             // Clear, so we only get new addresses
             new_alias_and_nft_addresses = HashMap::new();
+            // Only outputs locked to the addresses of alias and nft outputs the account controls can be unlocked, so
+            // other alias and nft outputs, e.g. with a foreign state controller, aren't followed
+            let controlled_addresses =
+                controlled_alias_and_nft_addresses(&addresses_with_unspent_outputs, outputs_data.iter(), current_time)?;
             // Add new alias and nft addresses
             for output_data in new_outputs_data.iter() {
-                match &output_data.output {
-                    Output::Alias(alias_output) => {
-                        let alias_address = AliasAddress::from(alias_output.alias_id_non_null(&output_data.output_id));
-
-                        new_alias_and_nft_addresses.insert(Address::Alias(alias_address), output_data.address);
-                    }
+                let address = match &output_data.output {
+                    Output::Alias(alias_output) => Address::Alias(AliasAddress::from(
+                        alias_output.alias_id_non_null(&output_data.output_id),
+                    )),
                     Output::Nft(nft_output) => {
-                        let nft_address = NftAddress::from(nft_output.nft_id_non_null(&output_data.output_id));
-
-                        new_alias_and_nft_addresses.insert(Address::Nft(nft_address), output_data.address);
+                        Address::Nft(NftAddress::from(nft_output.nft_id_non_null(&output_data.output_id)))
                     }
-                    _ => {}
+                    _ => continue,
+                };
+
+                if controlled_addresses.contains(&address) && synced_alias_and_nft_addresses.insert(address) {
+                    new_alias_and_nft_addresses.insert(address, output_data.address);
                 }
             }
 
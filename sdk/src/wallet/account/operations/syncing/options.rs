@@ -11,6 +11,8 @@ const DEFAULT_SYNC_INCOMING_TRANSACTIONS: bool = false;
 const DEFAULT_SYNC_ONLY_MOST_BASIC_OUTPUTS: bool = false;
 const DEFAULT_SYNC_PENDING_TRANSACTIONS: bool = true;
 const DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES: bool = false;
+#[cfg(feature = "participation")]
+const DEFAULT_SYNC_PARTICIPATION: bool = false;
 
 /// The synchronization options
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -20,6 +22,10 @@ pub struct SyncOptions {
     /// will be ignored
     #[serde(default)]
     pub addresses: Vec<Bech32Address>,
+    /// Indexes of the accounts to sync when syncing the wallet, all accounts are synced if empty. If addresses are
+    /// provided, only the accounts containing them are synced.
+    #[serde(default)]
+    pub account_indexes: Vec<u32>,
     /// Address index from which to start syncing addresses. 0 by default, using a higher index will be faster because
     /// addresses with a lower index will be skipped, but could result in a wrong balance for that reason
     #[serde(default = "default_address_start_index")]
@@ -56,6 +62,11 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Request the participation status of the voting outputs and cache it for spent ones, so the participations are
+    /// still known after the outputs got pruned by the node.
+    #[cfg(feature = "participation")]
+    #[serde(default = "default_sync_participation")]
+    pub sync_participation: bool,
 }
 
 fn default_address_start_index() -> u32 {
@@ -82,10 +93,16 @@ fn default_sync_native_token_foundries() -> bool {
     DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES
 }
 
+#[cfg(feature = "participation")]
+fn default_sync_participation() -> bool {
+    DEFAULT_SYNC_PARTICIPATION
+}
+
 impl Default for SyncOptions {
     fn default() -> Self {
         Self {
             addresses: Vec::new(),
+            account_indexes: Vec::new(),
             address_start_index: default_address_start_index(),
             address_start_index_internal: default_address_start_index(),
             sync_incoming_transactions: default_sync_incoming_transactions(),
@@ -95,6 +112,8 @@ impl Default for SyncOptions {
             nft: NftSyncOptions::default(),
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
+            #[cfg(feature = "participation")]
+            sync_participation: default_sync_participation(),
             force_syncing: default_force_syncing(),
         }
     }
@@ -4,6 +4,8 @@
 use crypto::keys::bip44::Bip44;
 use instant::Instant;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::SyncStage;
 use crate::{
     client::{secret::SecretManage, Client},
     types::{
@@ -134,12 +136,20 @@ where
         });
         drop(account_details);
 
+        #[cfg(feature = "events")]
+        let (total, processed) = (transaction_ids.len(), std::sync::atomic::AtomicUsize::new(0));
+        #[cfg(feature = "events")]
+        self.emit_sync_progress(SyncStage::TransactionResolution, 0, total)
+            .await;
+
         // Limit parallel requests to 100, to avoid timeouts
         let results =
             futures::future::try_join_all(transaction_ids.chunks(100).map(|x| x.to_vec()).map(|transaction_ids| {
                 let client = self.client().clone();
+                #[cfg(feature = "events")]
+                let (processed, chunk_len) = (&processed, transaction_ids.len());
                 async move {
-                    task::spawn(async move {
+                    let results = task::spawn(async move {
                         futures::future::try_join_all(transaction_ids.iter().map(|transaction_id| async {
                             let transaction_id = *transaction_id;
                             match client.get_included_block(&transaction_id).await {
@@ -171,7 +181,18 @@ where
                         }))
                         .await
                     })
-                    .await?
+                    .await??;
+
+                    // Report the progress after every chunk, as they can take a while
+                    #[cfg(feature = "events")]
+                    {
+                        let processed =
+                            processed.fetch_add(chunk_len, std::sync::atomic::Ordering::Relaxed) + chunk_len;
+                        self.emit_sync_progress(SyncStage::TransactionResolution, processed, total)
+                            .await;
+                    }
+
+                    crate::wallet::Result::Ok(results)
                 }
             }))
             .await?;
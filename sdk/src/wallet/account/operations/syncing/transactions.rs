@@ -203,8 +203,9 @@ where
 
         for mut transaction in transactions_to_reattach {
             log::debug!("[SYNC] reattach transaction");
-            let reattached_block = self.submit_transaction_payload(transaction.payload.clone()).await?;
+            let reattached_block = self.reattach_transaction_payload(transaction.payload.clone()).await?;
             transaction.block_id.replace(reattached_block);
+            transaction.attachments.push(reattached_block);
             updated_transactions.push(transaction);
         }
 
@@ -224,6 +225,12 @@ fn updated_transaction_and_outputs(
     updated_transactions: &mut Vec<Transaction>,
     spent_output_ids: &mut Vec<OutputId>,
 ) {
+    if let Some(block_id) = block_id {
+        // The transaction can also be included in an attachment that wasn't sent by the wallet
+        if !transaction.attachments.contains(&block_id) {
+            transaction.attachments.push(block_id);
+        }
+    }
     transaction.block_id = block_id;
     transaction.inclusion_state = inclusion_state;
     // get spent inputs
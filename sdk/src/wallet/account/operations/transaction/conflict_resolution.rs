@@ -0,0 +1,205 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{TransactionRebuiltEvent, WalletEvent};
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        input::Input,
+        output::{Output, OutputId},
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::{
+        account::{
+            types::{InclusionState, Transaction},
+            Account, TransactionOptions, TransactionOptionsDto,
+        },
+        Error,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Rebuilds a conflicting transaction of the account whose inputs were partly spent by another transaction and
+    /// sends it again.
+    ///
+    /// The spent inputs are marked as spent and input selection runs again without them, with the options the
+    /// transaction was built with. Its outputs are kept, also the ones sent to the account itself, only the remainder
+    /// is left out, as it's created again if needed. Transactions that weren't built by the account, e.g. signed
+    /// elsewhere and sent with [`Account::submit_and_store_transaction()`], can't be rebuilt. The
+    /// [`WalletEvent::TransactionRebuilt`](crate::wallet::events::types::WalletEvent) event links the new
+    /// transaction to the conflicting one.
+    pub async fn rebuild_conflicting_transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] rebuild_conflicting_transaction {transaction_id}");
+
+        let transaction = self
+            .details()
+            .await
+            .transactions
+            .get(transaction_id)
+            .cloned()
+            .ok_or(Error::TransactionNotFound(*transaction_id))?;
+
+        if transaction.inclusion_state != InclusionState::Conflicting {
+            return Err(Error::TransactionNotRebuildable(
+                *transaction_id,
+                "the transaction isn't conflicting",
+            ));
+        }
+        let Some(build_data) = transaction.build_data.clone() else {
+            return Err(Error::TransactionNotRebuildable(
+                *transaction_id,
+                "it wasn't built by the account, so its remainder and options aren't known",
+            ));
+        };
+
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let input_ids = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => Some(*input.output_id()),
+                Input::Treasury(_) => None,
+            })
+            .collect::<Vec<OutputId>>();
+        let spent_input_ids = self
+            .client()
+            .get_outputs_metadata_ignore_errors(&input_ids)
+            .await?
+            .into_iter()
+            .filter(|metadata| metadata.is_spent())
+            .map(|metadata| *metadata.output_id())
+            .collect::<Vec<_>>();
+
+        if spent_input_ids.is_empty() {
+            return Err(Error::TransactionNotRebuildable(
+                *transaction_id,
+                "none of its inputs was spent by another transaction",
+            ));
+        }
+        log::debug!("[TRANSACTION] inputs spent by another transaction: {spent_input_ids:?}");
+
+        // Mark the inputs as spent already, so input selection doesn't use them again before the next sync
+        {
+            let mut account_details = self.details_mut().await;
+            for output_id in &spent_input_ids {
+                account_details.unspent_outputs.remove(output_id);
+                account_details.locked_outputs.remove(output_id);
+                if let Some(output_data) = account_details.outputs.get_mut(output_id) {
+                    output_data.is_spent = true;
+                }
+            }
+            #[cfg(feature = "storage")]
+            self.save(Some(&account_details)).await?;
+        }
+
+        let outputs = outputs_without_remainder(essence.outputs(), build_data.remainder_index);
+        let options = rebuild_options(build_data.options, &spent_input_ids)?;
+
+        let rebuilt_transaction = self.send_outputs(outputs, options).await?;
+        log::debug!(
+            "[TRANSACTION] rebuilt conflicting transaction {transaction_id} as {}",
+            rebuilt_transaction.transaction_id
+        );
+
+        #[cfg(feature = "events")]
+        {
+            let account_index = *self.details().await.index();
+            self.emit(
+                account_index,
+                WalletEvent::TransactionRebuilt(Box::new(TransactionRebuiltEvent {
+                    previous_transaction_id: *transaction_id,
+                    transaction_id: rebuilt_transaction.transaction_id,
+                })),
+            )
+            .await;
+        }
+
+        Ok(rebuilt_transaction)
+    }
+}
+
+fn outputs_without_remainder(outputs: &[Output], remainder_index: Option<u16>) -> Vec<Output> {
+    outputs
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| remainder_index != Some(*index as u16))
+        .map(|(_, output)| output.clone())
+        .collect()
+}
+
+// The original options, without the inputs that were spent by another transaction
+fn rebuild_options(
+    mut options: TransactionOptionsDto,
+    spent_input_ids: &[OutputId],
+) -> crate::wallet::Result<TransactionOptions> {
+    for inputs in [&mut options.custom_inputs, &mut options.mandatory_inputs]
+        .into_iter()
+        .flatten()
+    {
+        inputs.retain(|output_id| !spent_input_ids.contains(output_id));
+    }
+
+    Ok(TransactionOptions::try_from_dto(options)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+    };
+
+    fn basic_output(amount: u64, address: Address) -> Output {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(u64::MAX)
+            .unwrap()
+    }
+
+    #[test]
+    fn remainder_left_out() {
+        let account_address = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let other_address = Address::Ed25519(Ed25519Address::new([2; 32]));
+
+        let recipient = basic_output(1_000_000, other_address);
+        let self_send = basic_output(2_000_000, account_address);
+        let remainder = basic_output(3_000_000, account_address);
+
+        assert_eq!(
+            outputs_without_remainder(&[recipient.clone(), self_send.clone(), remainder], Some(2)),
+            [recipient.clone(), self_send.clone()]
+        );
+        // A plain self send as last output isn't mistaken for a remainder
+        assert_eq!(
+            outputs_without_remainder(&[recipient.clone(), self_send.clone()], None),
+            [recipient, self_send]
+        );
+    }
+
+    #[test]
+    fn spent_inputs_removed_from_options() {
+        let spent = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let unspent = OutputId::new(TransactionId::new([2; 32]), 0).unwrap();
+        let options = TransactionOptionsDto {
+            custom_inputs: Some(vec![spent, unspent]),
+            mandatory_inputs: Some(vec![spent]),
+            note: Some("note".to_string()),
+            allow_micro_amount: true,
+            ..Default::default()
+        };
+
+        let options = rebuild_options(options, &[spent]).unwrap();
+        assert_eq!(options.custom_inputs, Some(vec![unspent]));
+        assert_eq!(options.mandatory_inputs, Some(Vec::new()));
+        assert_eq!(options.note.as_deref(), Some("note"));
+        assert!(options.allow_micro_amount);
+    }
+}
@@ -22,7 +22,8 @@ where
     ///
     /// This happens with the foundry output which minted them, by increasing it's
     /// `melted_tokens` field. This should be preferred over burning, because after burning, the foundry can never be
-    /// destroyed anymore.
+    /// destroyed anymore. The amount can't exceed the circulating supply of the token or the amount available in the
+    /// account.
     pub async fn melt_native_token(
         &self,
         token_id: TokenId,
@@ -47,6 +48,7 @@ where
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_melt_native_token");
 
+        let melt_amount = melt_amount.into();
         let foundry_id = FoundryId::from(token_id);
         let alias_id = *foundry_id.alias_address().alias_id();
         let token_supply = self.client().get_token_supply().await?;
@@ -59,6 +61,22 @@ where
                 _ => unreachable!("We already checked it's a foundry output"),
             })?;
 
+        let TokenScheme::Simple(token_scheme) = existing_foundry_output.token_scheme();
+        check_melt_amount(token_scheme, melt_amount)?;
+        // The melted tokens have to be inputs of the transaction
+        let available_amount = self
+            .balance()
+            .await?
+            .native_tokens()
+            .iter()
+            .find(|native_token| native_token.token_id() == &token_id)
+            .map_or_else(U256::zero, |native_token| native_token.available());
+        if available_amount < melt_amount {
+            return Err(Error::BurningOrMeltingFailed(format!(
+                "melting {melt_amount} tokens requires more than the {available_amount} available tokens"
+            )));
+        }
+
         if let Output::Alias(alias_output) = &existing_alias_output_data.output {
             // Create the new alias output with updated amount and state_index
             let alias_output = AliasOutputBuilder::from(alias_output)
@@ -66,7 +84,6 @@ where
                 .with_state_index(alias_output.state_index() + 1)
                 .finish_output(token_supply)?;
 
-            let TokenScheme::Simple(token_scheme) = existing_foundry_output.token_scheme();
             let outputs = [
                 alias_output,
                 FoundryOutputBuilder::from(&existing_foundry_output)
@@ -123,3 +140,42 @@ where
         Ok((existing_alias_output_data, existing_foundry_output_data))
     }
 }
+
+/// Checks that the amount is not zero and can be melted without exceeding the circulating supply of the token scheme.
+fn check_melt_amount(token_scheme: &SimpleTokenScheme, melt_amount: U256) -> crate::wallet::Result<()> {
+    if melt_amount.is_zero() {
+        return Err(Error::BurningOrMeltingFailed(
+            "melt amount must be greater than zero".to_string(),
+        ));
+    }
+    if token_scheme.circulating_supply() < melt_amount {
+        return Err(Error::BurningOrMeltingFailed(format!(
+            "melting {melt_amount} tokens would exceed the circulating supply: {}",
+            token_scheme.circulating_supply()
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn melt_amount() {
+        // Circulating supply of 50 with a maximum supply of 100
+        let token_scheme = SimpleTokenScheme::new(60, 10, 100).unwrap();
+
+        assert!(check_melt_amount(&token_scheme, U256::from(1)).is_ok());
+        assert!(check_melt_amount(&token_scheme, U256::from(50)).is_ok());
+        assert!(matches!(
+            check_melt_amount(&token_scheme, U256::zero()),
+            Err(Error::BurningOrMeltingFailed(_))
+        ));
+        assert!(matches!(
+            check_melt_amount(&token_scheme, U256::from(51)),
+            Err(Error::BurningOrMeltingFailed(_))
+        ));
+    }
+}
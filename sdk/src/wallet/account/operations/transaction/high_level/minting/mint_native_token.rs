@@ -16,7 +16,7 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Mints additional native tokens.
+    /// Mints additional native tokens, increasing their circulating supply.
     ///
     /// The max supply must not be reached yet. The foundry needs to be
     /// controlled by this account. Address needs to be Bech32 encoded. This will not change the max supply.
@@ -73,13 +73,7 @@ where
 
         let existing_alias_output = if let Output::Foundry(foundry_output) = &existing_foundry_output.output {
             let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
-            // Check if we can mint the provided amount without exceeding the maximum_supply
-            if token_scheme.maximum_supply() - token_scheme.circulating_supply() < mint_amount {
-                return Err(Error::MintingFailed(format!(
-                    "minting additional {mint_amount} tokens would exceed the maximum supply: {}",
-                    token_scheme.maximum_supply()
-                )));
-            }
+            check_mint_amount(token_scheme, mint_amount)?;
 
             // Get the alias output that controls the foundry output
             let existing_alias_output = account_details.unspent_outputs().values().find(|output_data| {
@@ -135,3 +129,42 @@ where
         self.prepare_transaction(outputs, options).await
     }
 }
+
+/// Checks that the amount is not zero and can be minted without exceeding the maximum supply of the token scheme.
+fn check_mint_amount(token_scheme: &SimpleTokenScheme, mint_amount: U256) -> crate::wallet::Result<()> {
+    if mint_amount.is_zero() {
+        return Err(Error::MintingFailed(
+            "mint amount must be greater than zero".to_string(),
+        ));
+    }
+    if token_scheme.maximum_supply() - token_scheme.circulating_supply() < mint_amount {
+        return Err(Error::MintingFailed(format!(
+            "minting additional {mint_amount} tokens would exceed the maximum supply: {}",
+            token_scheme.maximum_supply()
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mint_amount() {
+        // Circulating supply of 50 with a maximum supply of 100
+        let token_scheme = SimpleTokenScheme::new(60, 10, 100).unwrap();
+
+        assert!(check_mint_amount(&token_scheme, U256::from(1)).is_ok());
+        assert!(check_mint_amount(&token_scheme, U256::from(50)).is_ok());
+        assert!(matches!(
+            check_mint_amount(&token_scheme, U256::zero()),
+            Err(Error::MintingFailed(_))
+        ));
+        assert!(matches!(
+            check_mint_amount(&token_scheme, U256::from(51)),
+            Err(Error::MintingFailed(_))
+        ));
+    }
+}
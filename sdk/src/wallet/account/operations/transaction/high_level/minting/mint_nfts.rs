@@ -11,7 +11,7 @@ use crate::{
         output::{
             feature::{IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
             unlock_condition::AddressUnlockCondition,
-            NftId, NftOutputBuilder,
+            NftId, NftOutputBuilder, OUTPUT_COUNT_MAX,
         },
         ConvertTo,
     },
@@ -149,6 +149,99 @@ where
         self.sign_and_submit_transaction(prepared_transaction, options).await
     }
 
+    /// Mints NFTs in as many transactions as needed, for collections that don't fit in a single transaction.
+    ///
+    /// Every transaction mints as many NFTs as the output count and the transaction length allow. Each transaction is
+    /// waited for and the account is synced before the next one is sent, so it can use the remainder of the previous
+    /// one. Returns the transactions in the order they were sent. If a batch fails after others were sent,
+    /// [`Error::PartiallySent`](crate::wallet::Error::PartiallySent) with the IDs of the sent transactions is returned.
+    /// The NFTs of these transactions are the first NFTs of the params, so minting can be continued with the rest.
+    /// ```ignore
+    /// let params = (0..300)
+    ///     .map(|index| MintNftParams::new().with_immutable_metadata(format!("NFT #{index}").into_bytes()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let transactions = account.mint_nfts_in_batches(params, None).await?;
+    /// for transaction in transactions {
+    ///     println!("Transaction sent: {}", transaction.transaction_id);
+    /// }
+    /// ```
+    pub async fn mint_nfts_in_batches<I: IntoIterator<Item = MintNftParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>>
+    where
+        I::IntoIter: Send,
+    {
+        log::debug!("[TRANSACTION] mint_nfts_in_batches");
+        let mut transactions = Vec::new();
+        let result = self
+            .mint_nfts_in_batches_internal(params, options, &mut transactions)
+            .await;
+        result.map_err(|error| {
+            WalletError::partially_sent(
+                transactions
+                    .iter()
+                    .map(|transaction| transaction.transaction_id)
+                    .collect(),
+                error,
+            )
+        })?;
+
+        Ok(transactions)
+    }
+
+    async fn mint_nfts_in_batches_internal<I: IntoIterator<Item = MintNftParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        transactions: &mut Vec<Transaction>,
+    ) -> crate::wallet::Result<()>
+    where
+        I::IntoIter: Send,
+    {
+        let options = options.into();
+        let params = params.into_iter().collect::<Vec<_>>();
+        let mut remaining_params = params.as_slice();
+        // One output is kept free for the remainder
+        let mut batch_size = OUTPUT_COUNT_MAX as usize - 1;
+
+        while !remaining_params.is_empty() {
+            let batch = &remaining_params[..batch_size.min(remaining_params.len())];
+            let prepared_transaction = match self.prepare_mint_nfts(batch.to_vec(), options.clone()).await {
+                Ok(prepared_transaction) => prepared_transaction,
+                // Retry with fewer NFTs if the transaction would be too large
+                Err(WalletError::Client(error))
+                    if batch.len() > 1
+                        && matches!(
+                            *error,
+                            crate::client::Error::InvalidRegularTransactionEssenceLength { .. }
+                        ) =>
+                {
+                    batch_size = batch.len() / 2;
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
+            let transaction = self
+                .sign_and_submit_transaction(prepared_transaction, options.clone())
+                .await?;
+            remaining_params = &remaining_params[batch.len()..];
+            let transaction_id = transaction.transaction_id;
+            transactions.push(transaction);
+
+            if !remaining_params.is_empty() {
+                // The outputs of the transaction are only available after it got confirmed and the account is synced
+                self.retry_transaction_until_included(&transaction_id, None, None)
+                    .await?;
+                self.sync(None).await?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Prepares the transaction for
     /// [Account::mint_nfts()](crate::wallet::Account::mint_nfts).
     pub async fn prepare_mint_nfts<I: IntoIterator<Item = MintNftParams> + Send>(
@@ -5,5 +5,7 @@ pub(crate) mod burning_melting;
 pub(crate) mod create_alias;
 pub(crate) mod minting;
 pub(crate) mod send;
+pub(crate) mod send_all;
 pub(crate) mod send_native_tokens;
 pub(crate) mod send_nft;
+pub(crate) mod update_alias_state;
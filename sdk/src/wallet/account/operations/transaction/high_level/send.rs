@@ -1,7 +1,7 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use getset::Getters;
+use getset::{CopyGetters, Getters};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -12,13 +12,16 @@ use crate::{
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder, MinimumStorageDepositBasicOutput,
+            BasicOutputBuilder, MinimumStorageDepositBasicOutput, Rent, RentStructure,
         },
         ConvertTo,
     },
     wallet::{
         account::{
-            constants::DEFAULT_EXPIRATION_TIME, operations::transaction::Transaction, Account, TransactionOptions,
+            constants::{DEFAULT_EXPIRATION_SLACK, DEFAULT_EXPIRATION_TIME},
+            operations::transaction::Transaction,
+            types::TransactionReceipt,
+            Account, TransactionOptions,
         },
         Error,
     },
@@ -41,7 +44,8 @@ pub struct SendParams {
     return_address: Option<Bech32Address>,
     /// Expiration in seconds, after which the output will be available for the sender again, if not spent by the
     /// receiver already. The expiration will only be used if one is necessary given the provided amount. If an
-    /// expiration is needed but not provided, it will default to one day.
+    /// expiration is needed but not provided, it will default to one day. It needs to be later than the
+    /// [`TransactionOptions::expiration_slack`].
     #[getset(get = "pub")]
     expiration: Option<u32>,
 }
@@ -75,6 +79,41 @@ impl SendParams {
     }
 }
 
+/// The costs of sending base coins, estimated before anything is signed or submitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get_copy = "pub")]
+pub struct SendEstimate {
+    /// Minimum storage deposit required by the outputs to the recipients
+    #[serde(with = "crate::utils::serde::string")]
+    storage_deposit: u64,
+    /// Amount of the inputs that are consumed by the transaction
+    #[serde(with = "crate::utils::serde::string")]
+    required_input_amount: u64,
+    /// Amount that is sent back to the account with the remainder output
+    #[serde(with = "crate::utils::serde::string")]
+    remainder: u64,
+}
+
+impl SendEstimate {
+    fn new(prepared_transaction: &PreparedTransactionData, rent_structure: &RentStructure) -> Self {
+        let summary = prepared_transaction.summary();
+        let outputs = prepared_transaction.essence.as_regular().outputs();
+        let remainder_index = summary.remainder_index.map(usize::from);
+
+        Self {
+            storage_deposit: outputs
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| Some(*index) != remainder_index)
+                .map(|(_, output)| output.rent_cost(rent_structure))
+                .sum(),
+            required_input_amount: summary.consumed_amount,
+            remainder: remainder_index.map_or(0, |index| outputs[index].amount()),
+        }
+    }
+}
+
 impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
@@ -94,6 +133,31 @@ where
         self.send_with_params(params, options).await
     }
 
+    /// Sends a certain amount of base coins to the address of a contact from the address book of the wallet.
+    ///
+    /// Calls [Account::send()](crate::wallet::Account::send) internally.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn send_to_contact(
+        &self,
+        amount: u64,
+        contact_name: &str,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let contact = self
+            .wallet
+            .storage_manager
+            .read()
+            .await
+            .get_contacts()
+            .await?
+            .into_iter()
+            .find(|contact| contact.name() == contact_name)
+            .ok_or_else(|| Error::ContactNotFound(contact_name.to_string()))?;
+
+        self.send(amount, *contact.address(), options).await
+    }
+
     /// Sends a certain amount of base coins with full customizability of the transaction.
     ///
     /// Calls [Account::send_outputs()](crate::wallet::Account::send_outputs) internally.
@@ -125,8 +189,27 @@ where
         self.sign_and_submit_transaction(prepared_transaction, options).await
     }
 
+    /// Sends base coins like [Account::send_with_params()](crate::wallet::Account::send_with_params) and returns the
+    /// [`TransactionReceipt`] of the sent transaction, with the outputs it consumed and created and the balance changes
+    /// of the addresses owning them.
+    pub async fn send_with_receipt<I: IntoIterator<Item = SendParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<TransactionReceipt>
+    where
+        I::IntoIter: Send,
+    {
+        let transaction = self.send_with_params(params, options).await?;
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+
+        Ok(transaction.receipt(bech32_hrp)?)
+    }
+
     /// Prepares the transaction for
     /// [Account::send()](crate::wallet::Account::send).
+    /// All params are validated before any output is built and params of the same recipient with the same return
+    /// address and expiration are merged into one output.
     pub async fn prepare_send<I: IntoIterator<Item = SendParams> + Send>(
         &self,
         params: I,
@@ -144,6 +227,30 @@ where
         let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
 
         let local_time = self.client().get_time_checked().await?;
+        // Expirations are compared against milestone timestamps, so they're computed from the latest one if the local
+        // clock is behind it
+        let milestone_time = self
+            .client()
+            .get_network_info()
+            .await?
+            .latest_milestone_timestamp
+            .map_or(local_time, |milestone_timestamp| milestone_timestamp.max(local_time));
+        let expiration_slack = options
+            .as_ref()
+            .and_then(|options| options.expiration_slack)
+            .unwrap_or(DEFAULT_EXPIRATION_SLACK);
+
+        let params = merge_send_params(params)?;
+        for send_params in &params {
+            self.client()
+                .validate_recipient(
+                    &send_params.address,
+                    send_params.return_address.as_ref(),
+                    send_params.amount,
+                    false,
+                )
+                .await?;
+        }
 
         let mut outputs = Vec::new();
         for SendParams {
@@ -153,19 +260,7 @@ where
             expiration,
         } in params
         {
-            self.client().bech32_hrp_matches(address.hrp()).await?;
-            let return_address = return_address
-                .map(|return_address| {
-                    if return_address.hrp() != address.hrp() {
-                        Err(crate::client::Error::Bech32HrpMismatch {
-                            provided: return_address.hrp().to_string(),
-                            expected: address.hrp().to_string(),
-                        })?;
-                    }
-                    Ok::<_, Error>(return_address)
-                })
-                .transpose()?
-                .unwrap_or(default_return_address.address);
+            let return_address = return_address.unwrap_or(default_return_address.address);
 
             // Get the minimum required amount for an output assuming it does not need a storage deposit.
             let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
@@ -179,9 +274,16 @@ where
                         .finish_output(token_supply)?,
                 )
             } else {
-                let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
-                    local_time + expiration_time
-                });
+                let expiration = expiration.unwrap_or(DEFAULT_EXPIRATION_TIME);
+                // The output could otherwise already be expired when the transaction gets confirmed
+                if expiration <= expiration_slack {
+                    return Err(Error::UnsafeExpiration {
+                        expiration,
+                        slack: expiration_slack,
+                    });
+                }
+                let expiration_time = milestone_time + expiration;
+                log::debug!("[TRANSACTION] prepare_send output to {address} expires at {expiration_time}");
 
                 // Since it does need a storage deposit, calculate how much that should be
                 let storage_deposit_amount = MinimumStorageDepositBasicOutput::new(rent_structure, token_supply)
@@ -218,4 +320,89 @@ where
 
         self.prepare_transaction(outputs, options).await
     }
+
+    /// Estimates the storage deposit, the required input amount and the remainder of
+    /// [Account::send_with_params()](crate::wallet::Account::send_with_params) with the currently available outputs,
+    /// by preparing the transaction as a dry run.
+    pub async fn estimate_send<I: IntoIterator<Item = SendParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<SendEstimate>
+    where
+        I::IntoIter: Send,
+    {
+        log::debug!("[TRANSACTION] estimate_send");
+        let options = TransactionOptions {
+            dry_run: true,
+            ..options.into().unwrap_or_default()
+        };
+        let prepared_transaction = self.prepare_send(params, options).await?;
+
+        let rent_structure = self.client().get_rent_structure().await?;
+
+        Ok(SendEstimate::new(&prepared_transaction, &rent_structure))
+    }
+}
+
+// Merges the params of the same recipient with the same return address and expiration into one, so that a recipient
+// listed multiple times gets a single output with the sum of the amounts.
+fn merge_send_params(params: impl IntoIterator<Item = SendParams>) -> crate::wallet::Result<Vec<SendParams>> {
+    let mut merged = Vec::<SendParams>::new();
+
+    for params in params {
+        match merged.iter_mut().find(|merged| {
+            merged.address == params.address
+                && merged.return_address == params.return_address
+                && merged.expiration == params.expiration
+        }) {
+            Some(merged) => {
+                merged.amount = merged
+                    .amount
+                    .checked_add(params.amount)
+                    .ok_or(crate::types::block::Error::CreatedAmountOverflow)?
+            }
+            None => merged.push(params),
+        }
+    }
+
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_duplicate_recipients() {
+        let address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+        let other_address = "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu";
+
+        let merged = merge_send_params([
+            SendParams::new(1_000_000, address).unwrap(),
+            SendParams::new(2_000_000, other_address).unwrap(),
+            SendParams::new(3_000_000, address).unwrap(),
+            SendParams::new(4_000_000, address).unwrap().with_expiration(3600),
+        ])
+        .unwrap();
+
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[0].amount, 4_000_000);
+        assert_eq!(merged[1].amount, 2_000_000);
+        assert_eq!(merged[2].amount, 4_000_000);
+        assert_eq!(merged[2].expiration, Some(3600));
+    }
+
+    #[test]
+    fn merge_overflowing_amounts() {
+        let address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+
+        assert!(matches!(
+            merge_send_params([
+                SendParams::new(u64::MAX, address).unwrap(),
+                SendParams::new(1, address).unwrap(),
+            ]),
+            Err(Error::Block(error)) if *error == crate::types::block::Error::CreatedAmountOverflow
+        ));
+    }
 }
@@ -0,0 +1,199 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::Bech32Address,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder},
+        ConvertTo,
+    },
+    wallet::{
+        account::{
+            operations::{output_claiming::get_new_native_token_count, transaction::Transaction},
+            types::OutputData,
+            Account, RemainderValueStrategy, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sends the full available base coin balance of the account to a single address.
+    ///
+    /// Only basic outputs that can be unlocked now and don't need to return a storage deposit are sent. Native tokens
+    /// of these outputs stay in the account, in a remainder output that keeps the minimum required storage deposit,
+    /// nfts, aliases and foundries aren't touched. If the outputs don't fit into a single transaction, because of the
+    /// input count or native token count limits, multiple transactions are sent, each one creating an output for the
+    /// recipient.
+    ///
+    /// The remainder value strategy of the options only decides where the native tokens remain, custom and mandatory
+    /// inputs are ignored. If a transaction fails after others were sent, [`Error::PartiallySent`] with the IDs of the
+    /// sent transactions is returned.
+    pub async fn send_all(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] send_all");
+        let mut transactions = Vec::new();
+        let result = self.send_all_internal(address, options, &mut transactions).await;
+        result.map_err(|error| {
+            Error::partially_sent(
+                transactions
+                    .iter()
+                    .map(|transaction| transaction.transaction_id)
+                    .collect(),
+                error,
+            )
+        })?;
+
+        Ok(transactions)
+    }
+
+    async fn send_all_internal(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        transactions: &mut Vec<Transaction>,
+    ) -> crate::wallet::Result<()> {
+        let address = address.convert()?;
+        self.client().bech32_hrp_matches(address.hrp()).await?;
+        let options = options.into().unwrap_or_default();
+
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+        let batches = self.send_all_batches().await?;
+
+        // The storage deposit the output for the recipient needs at least
+        let recipient_minimum_amount = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?
+            .amount();
+
+        let mut available = 0;
+        let mut required = recipient_minimum_amount;
+
+        for batch in batches {
+            let mut total_amount = 0;
+            let mut native_tokens = NativeTokensBuilder::new();
+            for output_data in &batch {
+                total_amount += output_data.output.amount();
+                if let Some(output_native_tokens) = output_data.output.native_tokens() {
+                    native_tokens.add_native_tokens(output_native_tokens.clone())?;
+                }
+            }
+            available += total_amount;
+
+            let mut outputs = Vec::new();
+            let mut remainder_amount = 0;
+            if !native_tokens.is_empty() {
+                let remainder_address = match &options.remainder_value_strategy {
+                    RemainderValueStrategy::CustomAddress(address) => address.address.inner,
+                    _ => batch[0].address,
+                };
+                // The native tokens stay in the account with the minimum storage deposit
+                let remainder = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+                    .add_unlock_condition(AddressUnlockCondition::new(remainder_address))
+                    .with_native_tokens(native_tokens.finish()?)
+                    .finish_output(token_supply)?;
+                remainder_amount = remainder.amount();
+                outputs.push(remainder);
+            }
+
+            if total_amount < remainder_amount + recipient_minimum_amount {
+                log::debug!(
+                    "[TRANSACTION] send_all: skipping {} outputs, {total_amount} isn't enough for the storage deposits",
+                    batch.len()
+                );
+                required = required.max(remainder_amount + recipient_minimum_amount);
+                continue;
+            }
+            outputs.insert(
+                0,
+                BasicOutputBuilder::new_with_amount(total_amount - remainder_amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish_output(token_supply)?,
+            );
+
+            let options = TransactionOptions {
+                custom_inputs: Some(batch.iter().map(|output_data| output_data.output_id).collect()),
+                mandatory_inputs: None,
+                ..options.clone()
+            };
+            let prepared_transaction = self.prepare_transaction(outputs, options.clone()).await?;
+            transactions.push(self.sign_and_submit_transaction(prepared_transaction, options).await?);
+        }
+
+        if transactions.is_empty() {
+            return Err(Error::InsufficientFunds { available, required });
+        }
+
+        Ok(())
+    }
+
+    // Groups the outputs that can be sent into batches that fit into a single transaction, largest outputs first
+    async fn send_all_batches(&self) -> crate::wallet::Result<Vec<Vec<OutputData>>> {
+        #[cfg(feature = "participation")]
+        let voting_output = self.get_voting_output().await?;
+        let current_time = self.client().get_time_checked().await?;
+        let network_id = self.client().get_network_id().await?;
+        // Each transaction creates the output for the recipient and a remainder output for the native tokens
+        let max_inputs = usize::from(self.max_transaction_inputs(2).await);
+
+        let account_details = self.details().await;
+        let mut outputs = Vec::new();
+        for (output_id, output_data) in account_details.unspent_outputs() {
+            #[cfg(feature = "participation")]
+            if let Some(ref voting_output) = voting_output {
+                // The voting output keeps its features and isn't sent
+                if output_data.output_id == voting_output.output_id {
+                    continue;
+                }
+            }
+            if output_data.network_id != network_id || account_details.locked_outputs.contains(output_id) {
+                continue;
+            }
+            if self.should_consolidate_output(
+                output_data,
+                current_time,
+                &account_details.addresses_with_unspent_outputs,
+            )? {
+                outputs.push(output_data.clone());
+            }
+        }
+        drop(account_details);
+
+        outputs.sort_unstable_by_key(|output_data| {
+            (std::cmp::Reverse(output_data.output.amount()), output_data.output_id)
+        });
+
+        let mut batches = Vec::new();
+        let mut batch: Vec<OutputData> = Vec::new();
+        let mut batch_native_tokens = NativeTokensBuilder::new();
+        for output_data in outputs {
+            let exceeds_native_token_count = match output_data.output.native_tokens() {
+                Some(native_tokens) => {
+                    get_new_native_token_count(&batch_native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into()
+                }
+                None => false,
+            };
+            if batch.len() == max_inputs || exceeds_native_token_count {
+                batches.push(std::mem::take(&mut batch));
+                batch_native_tokens = NativeTokensBuilder::new();
+            }
+            if let Some(native_tokens) = output_data.output.native_tokens() {
+                batch_native_tokens.add_native_tokens(native_tokens.clone())?;
+            }
+            batch.push(output_data);
+        }
+        if !batch.is_empty() {
+            batches.push(batch);
+        }
+
+        Ok(batches)
+    }
+}
@@ -144,19 +144,10 @@ where
             expiration,
         } in params
         {
-            self.client().bech32_hrp_matches(address.hrp()).await?;
-            let return_address = return_address
-                .map(|addr| {
-                    if address.hrp() != addr.hrp() {
-                        Err(crate::client::Error::Bech32HrpMismatch {
-                            provided: addr.hrp().to_string(),
-                            expected: address.hrp().to_string(),
-                        })?;
-                    }
-                    Ok::<_, Error>(addr)
-                })
-                .transpose()?
-                .unwrap_or(default_return_address.address);
+            self.client()
+                .validate_recipient(&address, return_address.as_ref(), 0, !native_tokens.is_empty())
+                .await?;
+            let return_address = return_address.unwrap_or(default_return_address.address);
 
             let native_tokens = NativeTokens::from_vec(
                 native_tokens
@@ -0,0 +1,95 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    types::block::output::{AliasId, AliasOutputBuilder, Output, Rent},
+    wallet::{
+        account::{types::Transaction, Account, TransactionOptions},
+        Error,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Updates the state metadata of an alias with a state transition, signed by the state controller.
+    ///
+    /// The state index is incremented and all other fields, including the immutable features, are kept. The amount is
+    /// only raised if the new state metadata requires a higher storage deposit. The state controller address of the
+    /// alias needs to be an address of this account.
+    /// ```ignore
+    /// let tx = account
+    ///     .update_alias_state(
+    ///         AliasId::from_str("0x09c6ea1ed1e6f9d5b4d8dcfc1b9b5ea0dbe4fd2ff0ac92e5d39b6cbc5a8a0d3e")?,
+    ///         b"new state".to_vec(),
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// println!("Transaction created: {}", tx.transaction_id);
+    /// ```
+    pub async fn update_alias_state(
+        &self,
+        alias_id: AliasId,
+        state_metadata: impl Into<Vec<u8>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let options = options.into();
+        let prepared = self
+            .prepare_update_alias_state(alias_id, state_metadata, options.clone())
+            .await?;
+
+        self.sign_and_submit_transaction(prepared, options).await
+    }
+
+    /// Prepares the transaction for
+    /// [Account::update_alias_state()](crate::wallet::Account::update_alias_state).
+    pub async fn prepare_update_alias_state(
+        &self,
+        alias_id: AliasId,
+        state_metadata: impl Into<Vec<u8>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_update_alias_state");
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        let alias_output = self
+            .details()
+            .await
+            .unspent_outputs()
+            .iter()
+            .find_map(|(output_id, output_data)| match &output_data.output {
+                Output::Alias(alias_output) if alias_output.alias_id_non_null(output_id) == alias_id => {
+                    Some(alias_output.clone())
+                }
+                _ => None,
+            })
+            .ok_or(Error::AliasNotFoundInUnspentOutputs(alias_id))?;
+
+        let state_controller_address = alias_output.state_controller_address();
+        if !self
+            .addresses()
+            .await?
+            .iter()
+            .any(|address| address.address().inner() == state_controller_address)
+        {
+            return Err(Error::AliasStateControllerNotInAccount(alias_id));
+        }
+
+        // Only the state can be changed in a state transition, so everything else is kept
+        let alias_output_builder = AliasOutputBuilder::from(&alias_output)
+            .with_alias_id(alias_id)
+            .with_state_index(alias_output.state_index() + 1)
+            .with_state_metadata(state_metadata);
+        let mut alias_output = alias_output_builder.clone().finish_output(token_supply)?;
+        if alias_output.amount() < alias_output.rent_cost(&rent_structure) {
+            alias_output = alias_output_builder
+                .with_minimum_storage_deposit(rent_structure)
+                .finish_output(token_supply)?;
+        }
+
+        self.prepare_transaction([alias_output], options).await
+    }
+}
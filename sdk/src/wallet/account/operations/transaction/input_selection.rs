@@ -15,7 +15,10 @@ use crate::{
         output::{Output, OutputId},
     },
     wallet::account::{
-        operations::helpers::time::can_output_be_unlocked_forever_from_now_on, Account, AccountDetails, OutputData,
+        operations::helpers::time::{
+            can_output_be_unlocked_forever_from_now_on, can_output_be_unlocked_now, controlled_alias_and_nft_addresses,
+        },
+        Account, AccountDetails, OutputData,
     },
 };
 
@@ -23,14 +26,17 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Selects inputs for a transaction and locks them in the account, so they don't get used again
+    /// Selects inputs for a transaction and locks them in the account, so they don't get used again, unless it's a
+    /// dry run
     pub(crate) async fn select_inputs(
         &self,
         outputs: Vec<Output>,
         custom_inputs: Option<HashSet<OutputId>>,
         mandatory_inputs: Option<HashSet<OutputId>>,
+        forbidden_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
+        dry_run: bool,
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
         // Voting output needs to be requested before to prevent a deadlock
@@ -41,15 +47,18 @@ where
         let protocol_parameters = self.client().get_protocol_parameters().await?;
 
         #[cfg(feature = "events")]
-        self.emit(
-            account_details.index,
-            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
-        )
-        .await;
+        if !dry_run {
+            self.emit(
+                account_details.index,
+                WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+            )
+            .await;
+        }
 
         let current_time = self.client().get_time_checked().await?;
-        #[allow(unused_mut)]
-        let mut forbidden_inputs = account_details.locked_outputs.clone();
+        // Locked outputs are already used in other transactions
+        let mut forbidden_inputs = forbidden_inputs.unwrap_or_default();
+        forbidden_inputs.extend(account_details.locked_outputs.iter().copied());
 
         let addresses = account_details
             .public_addresses()
@@ -113,8 +122,10 @@ where
             let selected_transaction_data = input_selection.select()?;
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if !dry_run {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             return Ok(selected_transaction_data);
@@ -148,13 +159,17 @@ where
             let selected_transaction_data = input_selection.select()?;
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if !dry_run {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if !dry_run {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             return Ok(selected_transaction_data);
@@ -194,9 +209,11 @@ where
         };
 
         // lock outputs so they don't get used by another transaction
-        for output in &selected_transaction_data.inputs {
-            log::debug!("[TRANSACTION] locking: {}", output.output_id());
-            account_details.locked_outputs.insert(*output.output_id());
+        if !dry_run {
+            for output in &selected_transaction_data.inputs {
+                log::debug!("[TRANSACTION] locking: {}", output.output_id());
+                account_details.locked_outputs.insert(*output.output_id());
+            }
         }
 
         Ok(selected_transaction_data)
@@ -205,6 +222,7 @@ where
 
 /// Filter available outputs to only include outputs that don't have unlock conditions, that could create
 /// conflicting transactions or need a new output for the storage deposit return
+/// Outputs locked to the address of an alias or nft output are only included if the account controls that output
 /// Also only include Alias, Nft and Foundry outputs, if a corresponding output with the same id exists in the output,
 /// so they don't get burned
 ///
@@ -232,8 +250,16 @@ fn filter_inputs(
     mandatory_inputs: Option<&HashSet<OutputId>>,
 ) -> crate::wallet::Result<Vec<InputSigningData>> {
     let mut available_outputs_signing_data = Vec::new();
+    let alias_and_nft_addresses = controlled_alias_and_nft_addresses(
+        &account.addresses_with_unspent_outputs,
+        available_outputs.clone(),
+        current_time,
+    )?;
 
     for output_data in available_outputs {
+        // Defaults to state transition if it is not explicitly a governance transition or a burn.
+        let alias_state_transition = is_alias_transition(&output_data.output, output_data.output_id, outputs, burn);
+
         if !custom_inputs
             .map(|inputs| inputs.contains(&output_data.output_id))
             .unwrap_or(false)
@@ -245,6 +271,7 @@ fn filter_inputs(
                 // We use the addresses with unspent outputs, because other addresses of the
                 // account without unspent outputs can't be related to this output
                 &account.addresses_with_unspent_outputs,
+                &alias_and_nft_addresses,
                 &output_data.output,
                 current_time,
             );
@@ -253,10 +280,19 @@ fn filter_inputs(
             if !output_can_be_unlocked_now_and_in_future {
                 continue;
             }
-        }
 
-        // Defaults to state transition if it is not explicitly a governance transition or a burn.
-        let alias_state_transition = is_alias_transition(&output_data.output, output_data.output_id, outputs, burn);
+            // Outputs locked to the address of an alias or nft output that isn't controlled by the account can't be
+            // unlocked
+            if !can_output_be_unlocked_now(
+                &account.addresses_with_unspent_outputs,
+                &alias_and_nft_addresses,
+                output_data,
+                current_time,
+                alias_state_transition,
+            )? {
+                continue;
+            }
+        }
 
         if let Some(available_input) = output_data.input_signing_data(account, current_time, alias_state_transition)? {
             available_outputs_signing_data.push(available_input);
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod build_transaction;
+mod conflict_resolution;
 pub(crate) mod high_level;
 mod input_selection;
 mod options;
@@ -21,12 +22,12 @@ use crate::{
         api::core::response::OutputWithMetadataResponse,
         block::{
             output::{dto::OutputDto, Output},
-            payload::transaction::TransactionPayload,
+            payload::transaction::{TransactionEssence, TransactionPayload},
             semantic::ConflictReason,
         },
     },
     wallet::account::{
-        types::{InclusionState, Transaction},
+        types::{InclusionState, Transaction, TransactionBuildData},
         Account,
     },
 };
@@ -105,6 +106,10 @@ where
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         log::debug!("[TRANSACTION] sign_and_submit_transaction");
+        let options = options.into();
+        if options.as_ref().map_or(false, |options| options.dry_run) {
+            return Err(crate::client::Error::DryRun.into());
+        }
 
         let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
             Ok(res) => res,
@@ -115,7 +120,21 @@ where
             }
         };
 
-        self.submit_and_store_transaction(signed_transaction_data, options)
+        // Input selection adds the remainder after the other outputs
+        let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+        let remainder_index = prepared_transaction_data.remainder.as_ref().and_then(|remainder| {
+            essence
+                .outputs()
+                .iter()
+                .rposition(|output| *output == remainder.output)
+                .map(|index| index as u16)
+        });
+        let build_data = TransactionBuildData {
+            options: options.as_ref().map(TransactionOptionsDto::from).unwrap_or_default(),
+            remainder_index,
+        };
+
+        self.submit_and_store_transaction_internal(signed_transaction_data, options, Some(build_data))
             .await
     }
 
@@ -124,12 +143,22 @@ where
         &self,
         signed_transaction_data: SignedTransactionData,
         options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        // The remainder isn't known from the signed transaction, so it can't be rebuilt if it's conflicting
+        self.submit_and_store_transaction_internal(signed_transaction_data, options.into(), None)
+            .await
+    }
+
+    async fn submit_and_store_transaction_internal(
+        &self,
+        signed_transaction_data: SignedTransactionData,
+        options: Option<TransactionOptions>,
+        build_data: Option<TransactionBuildData>,
     ) -> crate::wallet::Result<Transaction> {
         log::debug!(
             "[TRANSACTION] submit_and_store_transaction {}",
             signed_transaction_data.transaction_payload.id()
         );
-        let options = options.into();
 
         // Validate transaction before sending and storing it
         let local_time = self.client().get_time_checked().await?;
@@ -152,7 +181,10 @@ where
 
         // Ignore errors from sending, we will try to send it again during [`sync_pending_transactions`]
         let block_id = match self
-            .submit_transaction_payload(signed_transaction_data.transaction_payload.clone())
+            .submit_transaction_payload(
+                signed_transaction_data.transaction_payload.clone(),
+                options.as_ref().and_then(|options| options.pow_cancel.clone()),
+            )
             .await
         {
             Ok(block_id) => Some(block_id),
@@ -180,12 +212,14 @@ where
             transaction_id,
             payload: signed_transaction_data.transaction_payload,
             block_id,
+            attachments: block_id.into_iter().collect(),
             network_id,
             timestamp: crate::utils::unix_timestamp_now().as_millis(),
             inclusion_state: InclusionState::Pending,
             incoming: false,
             note: options.and_then(|o| o.note),
             inputs,
+            build_data,
         };
 
         let mut account_details = self.details_mut().await;
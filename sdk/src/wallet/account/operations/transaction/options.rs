@@ -4,7 +4,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::input_selection::{Burn, BurnDto},
+    client::api::{
+        input_selection::{Burn, BurnDto},
+        pow::PowCancel,
+    },
     types::block::{
         output::OutputId,
         payload::{dto::TaggedDataPayloadDto, tagged_data::TaggedDataPayload},
@@ -22,9 +25,20 @@ pub struct TransactionOptions {
     // `mandatory_inputs` should be used instead.
     pub custom_inputs: Option<Vec<OutputId>>,
     pub mandatory_inputs: Option<Vec<OutputId>>,
+    /// Outputs that must not be used as inputs, e.g. outputs of a dust attack.
+    pub forbidden_inputs: Option<Vec<OutputId>>,
     pub burn: Option<Burn>,
     pub note: Option<String>,
     pub allow_micro_amount: bool,
+    /// Time in seconds that the expiration of conditional payments needs to be later than, to leave time for the
+    /// transaction to get confirmed. Defaults to one minute.
+    pub expiration_slack: Option<u32>,
+    /// Token to stop the local PoW of the block with the transaction, instead of the one cancelled by
+    /// `Client::cancel_pow()`. The transaction is then stored without a block and sent again when the account syncs.
+    pub pow_cancel: Option<PowCancel>,
+    /// Only prepare the transaction, to preview it: its inputs aren't locked, no events are emitted and no remainder
+    /// address is generated. Signing or sending it fails.
+    pub dry_run: bool,
 }
 
 impl TransactionOptions {
@@ -35,15 +49,36 @@ impl TransactionOptions {
             tagged_data_payload: value.tagged_data_payload.map(TaggedDataPayload::try_from).transpose()?,
             custom_inputs: value.custom_inputs,
             mandatory_inputs: value.mandatory_inputs,
+            forbidden_inputs: value.forbidden_inputs,
             burn: value.burn.map(Burn::try_from).transpose()?,
             note: value.note,
             allow_micro_amount: value.allow_micro_amount,
+            expiration_slack: value.expiration_slack,
+            pow_cancel: None,
+            dry_run: value.dry_run,
         })
     }
 }
 
+impl From<&TransactionOptions> for TransactionOptionsDto {
+    fn from(value: &TransactionOptions) -> Self {
+        Self {
+            remainder_value_strategy: value.remainder_value_strategy.clone(),
+            tagged_data_payload: value.tagged_data_payload.as_ref().map(TaggedDataPayloadDto::from),
+            custom_inputs: value.custom_inputs.clone(),
+            mandatory_inputs: value.mandatory_inputs.clone(),
+            forbidden_inputs: value.forbidden_inputs.clone(),
+            burn: value.burn.as_ref().map(BurnDto::from),
+            note: value.note.clone(),
+            allow_micro_amount: value.allow_micro_amount,
+            expiration_slack: value.expiration_slack,
+            dry_run: value.dry_run,
+        }
+    }
+}
+
 /// Dto for transaction options
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOptionsDto {
     #[serde(default)]
@@ -56,15 +91,21 @@ pub struct TransactionOptionsDto {
     pub custom_inputs: Option<Vec<OutputId>>,
     #[serde(default)]
     pub mandatory_inputs: Option<Vec<OutputId>>,
+    #[serde(default)]
+    pub forbidden_inputs: Option<Vec<OutputId>>,
     pub burn: Option<BurnDto>,
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub expiration_slack: Option<u32>,
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 #[allow(clippy::enum_variant_names)]
 /// The strategy to use for the remainder value management when sending funds.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "strategy", content = "value")]
 pub enum RemainderValueStrategy {
     /// Keep the remainder value on the source address.
@@ -9,7 +9,10 @@ use packable::bounded::TryIntoBoundedU16Error;
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{AddressData, TransactionProgressEvent, WalletEvent};
 use crate::{
-    client::{api::PreparedTransactionData, secret::SecretManage},
+    client::{
+        api::{DryRunTransaction, PreparedTransactionData},
+        secret::SecretManage,
+    },
     types::block::{
         input::INPUT_COUNT_RANGE,
         output::{Output, OUTPUT_COUNT_RANGE},
@@ -33,6 +36,7 @@ where
         log::debug!("[TRANSACTION] prepare_transaction");
         let options = options.into();
         let outputs = outputs.into();
+        let dry_run = options.as_ref().map_or(false, |options| options.dry_run);
         let prepare_transaction_start_time = Instant::now();
         let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
@@ -77,6 +81,8 @@ where
                         // select_inputs will select an address from the inputs if it's none
                         None
                     }
+                    // A dry run doesn't generate a new address, the remainder is previewed like with `ReuseAddress`
+                    RemainderValueStrategy::ChangeAddress if dry_run => None,
                     RemainderValueStrategy::ChangeAddress => {
                         let remainder_address = self.generate_remainder_address().await?;
                         #[cfg(feature = "events")]
@@ -111,8 +117,13 @@ where
                     .as_ref()
                     .and_then(|options| options.mandatory_inputs.as_ref())
                     .map(|inputs| HashSet::from_iter(inputs.clone())),
+                options
+                    .as_ref()
+                    .and_then(|options| options.forbidden_inputs.as_ref())
+                    .map(|inputs| HashSet::from_iter(inputs.clone())),
                 remainder_address,
                 options.as_ref().and_then(|options| options.burn.as_ref()),
+                dry_run,
             )
             .await?;
 
@@ -123,7 +134,9 @@ where
             Ok(res) => res,
             Err(err) => {
                 // unlock outputs so they are available for a new transaction
-                self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                if !dry_run {
+                    self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                }
                 return Err(err);
             }
         };
@@ -134,4 +147,22 @@ where
         );
         Ok(prepared_transaction_data)
     }
+
+    /// Builds the transaction like [`Account::prepare_transaction()`], with input selection, output validation and
+    /// storage deposit checks, as a dry run (see [`TransactionOptions::dry_run`]), so the result can be used to
+    /// preview the transaction. Without changes to the account, dry runs with the same outputs and options return the
+    /// same transaction.
+    pub async fn dry_run_transaction(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<DryRunTransaction> {
+        log::debug!("[TRANSACTION] dry_run_transaction");
+        let options = TransactionOptions {
+            dry_run: true,
+            ..options.into().unwrap_or_default()
+        };
+
+        Ok(self.prepare_transaction(outputs, options).await?.into())
+    }
 }
@@ -4,7 +4,7 @@
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
-    client::secret::SecretManage,
+    client::{api::pow::PowCancel, secret::SecretManage},
     types::block::{payload::Payload, BlockId},
     wallet::account::{operations::transaction::TransactionPayload, Account},
 };
@@ -13,10 +13,29 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Submits a payload in a block
+    /// Submits a payload in a block, the local PoW is stopped once the `pow_cancel` token is cancelled.
     pub(crate) async fn submit_transaction_payload(
         &self,
         transaction_payload: TransactionPayload,
+        pow_cancel: Option<PowCancel>,
+    ) -> crate::wallet::Result<BlockId> {
+        self.post_transaction_payload(transaction_payload, pow_cancel, false)
+            .await
+    }
+
+    /// Submits a payload that was already submitted in a new block, which isn't deduplicated like a first submission.
+    pub(crate) async fn reattach_transaction_payload(
+        &self,
+        transaction_payload: TransactionPayload,
+    ) -> crate::wallet::Result<BlockId> {
+        self.post_transaction_payload(transaction_payload, None, true).await
+    }
+
+    async fn post_transaction_payload(
+        &self,
+        transaction_payload: TransactionPayload,
+        pow_cancel: Option<PowCancel>,
+        reattachment: bool,
     ) -> crate::wallet::Result<BlockId> {
         log::debug!("[TRANSACTION] send_payload");
         #[cfg(feature = "events")]
@@ -32,9 +51,13 @@ where
             )
             .await;
         }
+        let pow_cancel = match pow_cancel {
+            Some(pow_cancel) => pow_cancel,
+            None => self.client().pow_cancel().await,
+        };
         let block = self
             .client()
-            .finish_block_builder(None, Some(Payload::from(transaction_payload)))
+            .finish_block_builder_with_cancel(None, Some(Payload::from(transaction_payload)), pow_cancel)
             .await?;
 
         #[cfg(feature = "events")]
@@ -43,7 +66,11 @@ where
             WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting),
         )
         .await;
-        let block_id = self.client().post_block(&block).await?;
+        let block_id = if reattachment {
+            self.client().post_reattachment(&block).await?
+        } else {
+            self.client().post_block(&block).await?
+        };
         log::debug!("[TRANSACTION] submitted block {}", block_id);
         Ok(block_id)
     }
@@ -7,7 +7,10 @@ use getset::{CopyGetters, Getters};
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::output::{feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId};
+use crate::types::block::{
+    address::Bech32Address,
+    output::{feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId},
+};
 
 /// The balance of an account, returned from [`crate::wallet::account::Account::sync()`] and
 /// [`crate::wallet::account::Account::balance()`].
@@ -147,6 +150,66 @@ impl std::ops::AddAssign for NativeTokensBalance {
     }
 }
 
+/// The balance of an account with a breakdown per address and of the funds that can't be spent currently, returned
+/// from [`crate::wallet::account::Account::detailed_balance()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct DetailedBalance {
+    /// The balance of the account
+    #[getset(get = "pub")]
+    pub(crate) balance: Balance,
+    /// The balance of every address with unspent outputs
+    #[getset(get = "pub")]
+    pub(crate) addresses: Vec<AddressBalance>,
+    /// Amount of unspent outputs
+    #[getset(get_copy = "pub")]
+    pub(crate) output_count: usize,
+    /// Base coin amounts that can't be spent currently
+    #[getset(get = "pub")]
+    pub(crate) not_spendable: NotSpendableBalance,
+}
+
+impl DetailedBalance {
+    /// Total amount of the base coin
+    pub fn total(&self) -> u64 {
+        self.balance.base_coin.total
+    }
+}
+
+/// The base coin balance of a single address for [`DetailedBalance`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBalance {
+    /// The address
+    #[getset(get = "pub")]
+    pub(crate) address: Bech32Address,
+    /// Amount of the base coin in the unspent outputs of the address
+    #[getset(get_copy = "pub")]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) amount: u64,
+    /// Amount of unspent outputs of the address
+    #[getset(get_copy = "pub")]
+    pub(crate) output_count: usize,
+}
+
+/// Base coin amounts that can't be spent currently for [`DetailedBalance`], an output can be counted for multiple
+/// reasons
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get_copy = "pub")]
+pub struct NotSpendableBalance {
+    /// Amount of outputs with a timelock that isn't reached yet
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) timelocked: u64,
+    /// Amount that needs to be returned to the sender of outputs with a storage deposit return unlock condition
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) storage_deposit_return: u64,
+    /// Amount required as storage deposit by outputs that hold native tokens or are alias, foundry or nft outputs,
+    /// which can't be sent without also sending or burning what they hold
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) storage_deposit: u64,
+}
+
 #[cfg(feature = "rand")]
 impl Balance {
     pub fn rand_mock() -> Self {
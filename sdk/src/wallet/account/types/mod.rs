@@ -6,6 +6,7 @@ pub(crate) mod address;
 pub(crate) mod balance;
 #[cfg(feature = "participation")]
 pub mod participation;
+pub(crate) mod receipt;
 
 use std::str::FromStr;
 
@@ -14,7 +15,11 @@ use serde::{Deserialize, Deserializer, Serialize};
 
 pub use self::{
     address::{AccountAddress, AddressWithUnspentOutputs},
-    balance::{Balance, BaseCoinBalance, NativeTokensBalance, RequiredStorageDeposit},
+    balance::{
+        AddressBalance, Balance, BaseCoinBalance, DetailedBalance, NativeTokensBalance, NotSpendableBalance,
+        RequiredStorageDeposit,
+    },
+    receipt::{AddressBalanceChange, NativeTokenChange, TransactionReceipt},
 };
 use crate::{
     client::secret::types::InputSigningData,
@@ -29,7 +34,7 @@ use crate::{
         TryFromDto,
     },
     utils::serde::bip44::option_bip44,
-    wallet::account::AccountDetails,
+    wallet::account::{AccountDetails, TransactionOptionsDto},
 };
 
 /// An output with metadata
@@ -159,6 +164,9 @@ impl TryFromDto for OutputData {
 pub struct Transaction {
     pub payload: TransactionPayload,
     pub block_id: Option<BlockId>,
+    /// The IDs of all blocks the transaction was sent in, starting with the first attachment and followed by its
+    /// reattachments.
+    pub attachments: Vec<BlockId>,
     pub inclusion_state: InclusionState,
     // Transaction creation time
     pub timestamp: u128,
@@ -172,6 +180,19 @@ pub struct Transaction {
     /// from the node.
     // serde(default) is needed so it doesn't break with old dbs
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// How the transaction was built, if it was sent by the account, to rebuild it if it's conflicting.
+    pub build_data: Option<TransactionBuildData>,
+}
+
+/// How a transaction sent by the account was built.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBuildData {
+    /// The options the transaction was built with.
+    pub options: TransactionOptionsDto,
+    /// The index of the remainder output, if the transaction has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remainder_index: Option<u16>,
 }
 
 /// Dto for a transaction with metadata
@@ -183,6 +204,9 @@ pub struct TransactionDto {
     /// BlockId when it got sent to the Tangle
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub block_id: Option<BlockId>,
+    /// BlockIds of all attachments of the transaction, in the order they were sent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<BlockId>,
     /// Inclusion state of the transaction
     pub inclusion_state: InclusionState,
     /// Timestamp
@@ -195,6 +219,8 @@ pub struct TransactionDto {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
     pub inputs: Vec<OutputWithMetadataResponse>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_data: Option<TransactionBuildData>,
 }
 
 impl From<&Transaction> for TransactionDto {
@@ -202,6 +228,7 @@ impl From<&Transaction> for TransactionDto {
         Self {
             payload: TransactionPayloadDto::from(&value.payload),
             block_id: value.block_id,
+            attachments: value.attachments.clone(),
             inclusion_state: value.inclusion_state,
             timestamp: value.timestamp.to_string(),
             transaction_id: value.transaction_id,
@@ -209,6 +236,7 @@ impl From<&Transaction> for TransactionDto {
             incoming: value.incoming,
             note: value.note.clone(),
             inputs: value.inputs.clone(),
+            build_data: value.build_data.clone(),
         }
     }
 }
@@ -224,6 +252,7 @@ impl TryFromDto for Transaction {
         Ok(Self {
             payload: TransactionPayload::try_from_dto_with_params(dto.payload, params)?,
             block_id: dto.block_id,
+            attachments: dto.attachments,
             inclusion_state: dto.inclusion_state,
             timestamp: dto
                 .timestamp
@@ -237,6 +266,7 @@ impl TryFromDto for Transaction {
             incoming: dto.incoming,
             note: dto.note,
             inputs: dto.inputs,
+            build_data: dto.build_data,
         })
     }
 }
@@ -0,0 +1,346 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use super::Transaction;
+use crate::types::{
+    block::{
+        address::{Address, Bech32Address, Hrp, ToBech32Ext},
+        output::{Output, OutputId, TokenId},
+        payload::transaction::{TransactionEssence, TransactionId},
+        BlockId, Error as BlockError,
+    },
+    TryFromDto,
+};
+
+/// The effects of a transaction: the outputs it consumed and created and how it changed the balances of the addresses
+/// owning them.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    /// The ID of the transaction.
+    pub transaction_id: TransactionId,
+    /// The block the transaction was sent in, if it was sent by the account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<BlockId>,
+    /// The outputs the transaction consumed.
+    pub consumed_outputs: Vec<OutputId>,
+    /// The outputs the transaction created.
+    pub created_outputs: Vec<OutputId>,
+    /// The balance changes of the addresses owning the consumed or created outputs, sorted by address.
+    pub address_changes: Vec<AddressBalanceChange>,
+    /// The net changes of the native tokens over all addresses, which aren't zero if tokens were minted, melted or
+    /// burned.
+    pub native_token_changes: Vec<NativeTokenChange>,
+}
+
+/// How a transaction changed the balance of an address.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBalanceChange {
+    /// The address, the state controller for alias outputs and the alias for foundry outputs.
+    pub address: Bech32Address,
+    /// The change of the base coin balance.
+    #[serde(with = "crate::utils::serde::string")]
+    pub base_coin_change: i128,
+    /// The changes of the native token balances, unchanged tokens are left out.
+    pub native_token_changes: Vec<NativeTokenChange>,
+}
+
+/// How a transaction changed the balance of a native token, only one of the amounts isn't zero.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTokenChange {
+    /// The ID of the native token.
+    pub token_id: TokenId,
+    /// The amount the balance increased by.
+    pub increase: U256,
+    /// The amount the balance decreased by.
+    pub decrease: U256,
+}
+
+// The base coin change and the consumed and created amounts of every native token
+#[derive(Default)]
+struct Change {
+    base_coin: i128,
+    native_tokens: BTreeMap<TokenId, (U256, U256)>,
+}
+
+impl Change {
+    fn add(&mut self, output: &Output, consumed: bool) {
+        let amount = output.amount() as i128;
+        self.base_coin += if consumed { -amount } else { amount };
+
+        if let Some(native_tokens) = output.native_tokens() {
+            for native_token in native_tokens.iter() {
+                let (consumed_amount, created_amount) = self.native_tokens.entry(*native_token.token_id()).or_default();
+                let total = if consumed { consumed_amount } else { created_amount };
+                *total = total.saturating_add(native_token.amount());
+            }
+        }
+    }
+
+    fn native_token_changes(&self) -> Vec<NativeTokenChange> {
+        self.native_tokens
+            .iter()
+            .filter(|(_, (consumed, created))| consumed != created)
+            .map(|(token_id, (consumed, created))| NativeTokenChange {
+                token_id: *token_id,
+                increase: created.saturating_sub(*consumed),
+                decrease: consumed.saturating_sub(*created),
+            })
+            .collect()
+    }
+}
+
+// The address whose balance an output counts towards
+fn owner_address(output: &Output) -> Option<Address> {
+    match output {
+        Output::Alias(alias) => Some(*alias.state_controller_address()),
+        Output::Foundry(foundry) => Some(Address::Alias(*foundry.alias_address())),
+        _ => output.unlock_conditions()?.address().map(|unlock| *unlock.address()),
+    }
+}
+
+impl Transaction {
+    /// Returns the effects of the transaction. The balance changes only include the consumed outputs that are known,
+    /// which are all of them for transactions sent by the account, but may not be for incoming transactions whose
+    /// inputs were pruned.
+    pub fn receipt(&self, bech32_hrp: Hrp) -> Result<TransactionReceipt, BlockError> {
+        let TransactionEssence::Regular(essence) = self.payload.essence();
+
+        let consumed_outputs = essence
+            .inputs()
+            .iter()
+            .map(|input| *input.as_utxo().output_id())
+            .collect();
+        let created_outputs = (0..essence.outputs().len() as u16)
+            .map(|index| OutputId::new(self.transaction_id, index))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut address_changes = BTreeMap::<Address, Change>::new();
+        let mut total_change = Change::default();
+
+        for input in &self.inputs {
+            let output = Output::try_from_dto(input.output.clone())?;
+            if let Some(address) = owner_address(&output) {
+                address_changes.entry(address).or_default().add(&output, true);
+            }
+            total_change.add(&output, true);
+        }
+        for output in essence.outputs() {
+            if let Some(address) = owner_address(output) {
+                address_changes.entry(address).or_default().add(output, false);
+            }
+            total_change.add(output, false);
+        }
+
+        Ok(TransactionReceipt {
+            transaction_id: self.transaction_id,
+            block_id: self.block_id,
+            consumed_outputs,
+            created_outputs,
+            address_changes: address_changes
+                .into_iter()
+                .map(|(address, change)| AddressBalanceChange {
+                    address: address.to_bech32(bech32_hrp),
+                    base_coin_change: change.base_coin,
+                    native_token_changes: change.native_token_changes(),
+                })
+                .collect(),
+            native_token_changes: total_change.native_token_changes(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::InclusionState, *};
+    use crate::types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::{AliasAddress, Ed25519Address},
+            input::{Input, UtxoInput},
+            output::{
+                dto::OutputDto,
+                unlock_condition::{
+                    AddressUnlockCondition, GovernorAddressUnlockCondition, ImmutableAliasAddressUnlockCondition,
+                    StateControllerAddressUnlockCondition,
+                },
+                AliasId, AliasOutputBuilder, BasicOutputBuilder, FoundryOutputBuilder, InputsCommitment, NativeToken,
+                OutputMetadata, SimpleTokenScheme, TokenScheme,
+            },
+            payload::transaction::{RegularTransactionEssence, TransactionPayload},
+            protocol::ProtocolParameters,
+            signature::{Ed25519Signature, Signature},
+            unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        },
+    };
+
+    const ED25519_PUBLIC_KEY: &str = "0x1da5ddd11ba3f961acab68fafee3177d039875eaa94ac5fdbff8b53f0c50bfb9";
+    const ED25519_SIGNATURE: &str = "0xc6a40edf9a089f42c18f4ebccb35fe4b578d93b879e99b87f63573324a710d3456b03fb6d1fcc027e6401cbd9581f790ee3ed7a3f68e9c225fcb9f1cd7b7110d";
+
+    fn input(output: &Output, index: u16) -> OutputWithMetadataResponse {
+        let output_id = OutputId::new(TransactionId::new([1; 32]), index).unwrap();
+
+        OutputWithMetadataResponse {
+            metadata: OutputMetadata::new(BlockId::new([2; 32]), output_id, false, None, None, None, 0, 0, 0),
+            output: OutputDto::from(output),
+        }
+    }
+
+    #[test]
+    fn receipt_with_alias_foundry_and_native_tokens() {
+        let protocol_parameters = ProtocolParameters::default();
+        let token_supply = protocol_parameters.token_supply();
+        let hrp = Hrp::from_str_unchecked("rms");
+
+        let sender = Address::from(Ed25519Address::new([3; 32]));
+        let recipient = Address::from(Ed25519Address::new([4; 32]));
+        let alias_id = AliasId::new([5; 32]);
+        let alias_address = AliasAddress::new(alias_id);
+        let token_id = TokenId::new([6; 38]);
+
+        let alias = |state_index| {
+            AliasOutputBuilder::new_with_amount(1_000_000, alias_id)
+                .with_state_index(state_index)
+                .with_foundry_counter(state_index)
+                .add_unlock_condition(StateControllerAddressUnlockCondition::new(sender))
+                .add_unlock_condition(GovernorAddressUnlockCondition::new(sender))
+                .finish_output(token_supply)
+                .unwrap()
+        };
+        let foundry_output = FoundryOutputBuilder::new_with_amount(
+            100_000,
+            1,
+            TokenScheme::Simple(SimpleTokenScheme::new(50, 0, 100).unwrap()),
+        )
+        .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(alias_address))
+        .finish_output(token_supply)
+        .unwrap();
+        let foundry_token_id = TokenId::new(*foundry_output.as_foundry().id());
+
+        let inputs = [
+            alias(0),
+            BasicOutputBuilder::new_with_amount(500_000)
+                .add_native_token(NativeToken::new(token_id, 100).unwrap())
+                .add_unlock_condition(AddressUnlockCondition::new(sender))
+                .finish_output(token_supply)
+                .unwrap(),
+        ];
+        let outputs = [
+            alias(1),
+            foundry_output,
+            BasicOutputBuilder::new_with_amount(300_000)
+                .add_native_token(NativeToken::new(token_id, 100).unwrap())
+                .add_native_token(NativeToken::new(foundry_token_id, 50).unwrap())
+                .add_unlock_condition(AddressUnlockCondition::new(recipient))
+                .finish_output(token_supply)
+                .unwrap(),
+            BasicOutputBuilder::new_with_amount(100_000)
+                .add_unlock_condition(AddressUnlockCondition::new(sender))
+                .finish_output(token_supply)
+                .unwrap(),
+        ];
+
+        let essence =
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), InputsCommitment::new(inputs.iter()))
+                .with_inputs([
+                    Input::Utxo(UtxoInput::new(TransactionId::new([1; 32]), 0).unwrap()),
+                    Input::Utxo(UtxoInput::new(TransactionId::new([1; 32]), 1).unwrap()),
+                ])
+                .with_outputs(outputs)
+                .finish_with_params(&protocol_parameters)
+                .unwrap();
+        let signature = Ed25519Signature::try_from_bytes(
+            prefix_hex::decode(ED25519_PUBLIC_KEY).unwrap(),
+            prefix_hex::decode(ED25519_SIGNATURE).unwrap(),
+        )
+        .unwrap();
+        let unlocks = Unlocks::new([
+            Unlock::Signature(SignatureUnlock::from(Signature::from(signature))),
+            Unlock::Reference(ReferenceUnlock::new(0).unwrap()),
+        ])
+        .unwrap();
+        let payload = TransactionPayload::new(TransactionEssence::Regular(essence), unlocks).unwrap();
+
+        let transaction = Transaction {
+            transaction_id: payload.id(),
+            payload,
+            block_id: None,
+            attachments: Vec::new(),
+            inclusion_state: InclusionState::Pending,
+            timestamp: 0,
+            network_id: protocol_parameters.network_id(),
+            incoming: false,
+            note: None,
+            inputs: vec![input(&inputs[0], 0), input(&inputs[1], 1)],
+            build_data: None,
+        };
+
+        let receipt = transaction.receipt(hrp).unwrap();
+
+        assert_eq!(receipt.transaction_id, transaction.transaction_id);
+        assert_eq!(
+            receipt.consumed_outputs,
+            vec![
+                OutputId::new(TransactionId::new([1; 32]), 0).unwrap(),
+                OutputId::new(TransactionId::new([1; 32]), 1).unwrap(),
+            ]
+        );
+        assert_eq!(
+            receipt.created_outputs,
+            (0..4)
+                .map(|index| OutputId::new(transaction.transaction_id, index).unwrap())
+                .collect::<Vec<_>>()
+        );
+        // Only the minted foundry tokens change the total, the other tokens were just moved
+        assert_eq!(
+            receipt.native_token_changes,
+            vec![NativeTokenChange {
+                token_id: foundry_token_id,
+                increase: U256::from(50),
+                decrease: U256::zero(),
+            }]
+        );
+        assert_eq!(
+            receipt.address_changes,
+            vec![
+                AddressBalanceChange {
+                    address: sender.to_bech32(hrp),
+                    base_coin_change: -400_000,
+                    native_token_changes: vec![NativeTokenChange {
+                        token_id,
+                        increase: U256::zero(),
+                        decrease: U256::from(100),
+                    }],
+                },
+                AddressBalanceChange {
+                    address: recipient.to_bech32(hrp),
+                    base_coin_change: 300_000,
+                    native_token_changes: vec![
+                        NativeTokenChange {
+                            token_id,
+                            increase: U256::from(100),
+                            decrease: U256::zero(),
+                        },
+                        NativeTokenChange {
+                            token_id: foundry_token_id,
+                            increase: U256::from(50),
+                            decrease: U256::zero(),
+                        },
+                    ],
+                },
+                AddressBalanceChange {
+                    address: Address::from(alias_address).to_bech32(hrp),
+                    base_coin_change: 100_000,
+                    native_token_changes: Vec::new(),
+                },
+            ]
+        );
+    }
+}
@@ -17,7 +17,9 @@ use crate::{
     types::{api::core::response::OutputWithMetadataResponse, block::payload::transaction::dto::TransactionPayloadDto},
     wallet::{
         account::types::OutputDataDto,
-        events::types::{NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, WalletEvent},
+        events::types::{
+            AccountRenamedEvent, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, WalletEvent,
+        },
     },
 };
 
@@ -25,15 +27,57 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Set the alias for the account
+    /// Set the alias for the account, without checking that it isn't used by another account of the wallet, use
+    /// [`Wallet::rename_account()`](crate::wallet::Wallet::rename_account) for that.
     pub async fn set_alias(&self, alias: &str) -> crate::wallet::Result<()> {
         let mut account_details = self.details_mut().await;
+        #[cfg(feature = "events")]
+        let previous_alias = account_details.alias.clone();
         account_details.alias = alias.to_string();
         #[cfg(feature = "storage")]
         self.save(Some(&account_details)).await?;
+
+        #[cfg(feature = "events")]
+        if previous_alias != alias {
+            let account_index = account_details.index;
+            drop(account_details);
+            self.emit(
+                account_index,
+                WalletEvent::AccountRenamed(Box::new(AccountRenamedEvent {
+                    previous_alias,
+                    alias: alias.to_string(),
+                })),
+            )
+            .await;
+        }
+
         Ok(())
     }
 
+    /// Set a metadata key of the account to the value, returns the previous value of the key.
+    pub async fn set_metadata(
+        &self,
+        key: impl Into<String> + Send,
+        value: impl Into<String> + Send,
+    ) -> crate::wallet::Result<Option<String>> {
+        let mut account_details = self.details_mut().await;
+        let previous_value = account_details.metadata.insert(key.into(), value.into());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(previous_value)
+    }
+
+    /// Remove a metadata key of the account, returns its value.
+    pub async fn remove_metadata(&self, key: &str) -> crate::wallet::Result<Option<String>> {
+        let mut account_details = self.details_mut().await;
+        let value = account_details.metadata.remove(key);
+        #[cfg(feature = "storage")]
+        if value.is_some() {
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(value)
+    }
+
     /// Update account with newly synced data and emit events for outputs
     pub(crate) async fn update_account(
         &self,
@@ -83,9 +127,12 @@ where
         }
 
         // Update addresses_with_unspent_outputs
-        // only keep addresses below the address start index, because we synced the addresses above and will update them
+        // only keep addresses that weren't synced, which are the ones not provided in the options or, if no addresses
+        // were provided, the ones below the address start index, because we will update the synced addresses
         account_details.addresses_with_unspent_outputs.retain(|a| {
-            if a.internal {
+            if !options.addresses.is_empty() {
+                !options.addresses.contains(&a.address)
+            } else if a.internal {
                 a.key_index < options.address_start_index_internal
             } else {
                 a.key_index < options.address_start_index
@@ -212,6 +259,7 @@ where
                             WalletEvent::TransactionInclusion(TransactionInclusionEvent {
                                 transaction_id,
                                 inclusion_state: transaction.inclusion_state,
+                                block_id: transaction.block_id,
                             }),
                         )
                         .await;
@@ -13,14 +13,16 @@ use serde::Serialize;
 use tokio::sync::RwLock;
 
 use super::operations::storage::SaveLoadWallet;
+#[cfg(all(feature = "storage", feature = "ledger_nano"))]
+use crate::client::secret::{ledger_nano::LedgerSecretManager, types::AccountAddress, DowncastSecretManager};
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
-#[cfg(all(feature = "storage", not(feature = "rocksdb")))]
-use crate::wallet::storage::adapter::memory::Memory;
 #[cfg(feature = "storage")]
 use crate::wallet::{
     account::AccountDetails,
-    storage::{StorageManager, StorageOptions},
+    storage::{
+        adapter::memory::MemoryStorageAdapter, StorageKind, StorageManager, StorageOptions, WalletSubmittedBlocksStore,
+    },
 };
 use crate::{
     client::secret::{SecretManage, SecretManager},
@@ -121,8 +123,17 @@ where
         let storage_options = self.storage_options.clone().unwrap_or_default();
         // Check if the db exists and if not, return an error if one parameter is missing, because otherwise the db
         // would be created with an empty parameter which just leads to errors later
-        #[cfg(feature = "storage")]
-        if !storage_options.path.is_dir() {
+        #[cfg(all(feature = "storage", not(target_family = "wasm")))]
+        let storage_exists = match storage_options.kind {
+            // SQLite stores everything in a single file instead of a directory
+            #[cfg(feature = "storage-sqlite")]
+            StorageKind::Sqlite => storage_options.path.is_file(),
+            // A memory storage adapter may hold previously exported records
+            StorageKind::Memory => storage_options.memory_storage.is_some(),
+            _ => storage_options.path.is_dir(),
+        };
+        #[cfg(all(feature = "storage", not(target_family = "wasm")))]
+        if !storage_exists {
             if self.client_options.is_none() {
                 return Err(crate::wallet::Error::MissingParameter("client_options"));
             }
@@ -134,14 +145,39 @@ where
             }
         }
 
-        #[cfg(all(feature = "rocksdb", feature = "storage"))]
-        let storage =
-            crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter::new(storage_options.path.clone())?;
-        #[cfg(all(not(feature = "rocksdb"), feature = "storage"))]
-        let storage = Memory::default();
-
         #[cfg(feature = "storage")]
-        let mut storage_manager = StorageManager::new(storage, storage_options.encryption_key.clone()).await?;
+        let encryption_keys = (
+            storage_options.encryption_key.clone(),
+            storage_options.previous_encryption_key.clone(),
+        );
+        #[cfg(feature = "storage")]
+        let mut storage_manager = match storage_options.kind {
+            #[cfg(feature = "rocksdb")]
+            StorageKind::Rocksdb => {
+                let storage =
+                    crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter::new(storage_options.path.clone())?;
+                StorageManager::open(storage, encryption_keys.0, encryption_keys.1).await?
+            }
+            #[cfg(feature = "storage-sqlite")]
+            StorageKind::Sqlite => {
+                let storage =
+                    crate::wallet::storage::adapter::sqlite::SqliteStorageAdapter::new(storage_options.path.clone())?;
+                StorageManager::open(storage, encryption_keys.0, encryption_keys.1).await?
+            }
+            StorageKind::Memory => {
+                let storage = storage_options.memory_storage.clone().unwrap_or_default();
+                StorageManager::open(storage, encryption_keys.0, encryption_keys.1).await?
+            }
+            #[cfg(target_family = "wasm")]
+            StorageKind::Wasm => match crate::wallet::storage::adapter::wasm::WasmAdapter::new(&storage_options.path) {
+                Ok(storage) => StorageManager::open(storage, encryption_keys.0, encryption_keys.1).await?,
+                // Outside of browsers the wallet is kept in memory
+                Err(error) => {
+                    log::warn!("[WalletBuilder] {error}, falling back to memory storage");
+                    StorageManager::open(MemoryStorageAdapter::default(), encryption_keys.0, encryption_keys.1).await?
+                }
+            },
+        };
 
         #[cfg(feature = "storage")]
         let read_manager_builder = Self::load(&storage_manager).await?;
@@ -225,6 +261,17 @@ where
             #[cfg(feature = "storage")]
             storage_manager: tokio::sync::RwLock::new(storage_manager),
         });
+        // The blocks posted for transactions are remembered in the storage, so a retry after a restart isn't posted
+        // again
+        #[cfg(feature = "storage")]
+        wallet_inner
+            .client
+            .submitted_blocks
+            .set_store(Box::new(WalletSubmittedBlocksStore(Arc::downgrade(&wallet_inner))))
+            .await;
+        // A Ledger Nano can then still generate the addresses of the accounts while the device is disconnected
+        #[cfg(all(feature = "storage", feature = "ledger_nano"))]
+        cache_ledger_nano_addresses(&*wallet_inner.secret_manager.read().await, &accounts).await;
 
         let mut accounts: Vec<Account<S>> = try_join_all(
             accounts
@@ -242,10 +289,18 @@ where
             }
         }
 
-        Ok(Wallet {
+        let wallet = Wallet {
             inner: wallet_inner,
             accounts: Arc::new(RwLock::new(accounts)),
-        })
+        };
+
+        // Continue a storage key rotation that was interrupted
+        #[cfg(feature = "storage")]
+        if wallet.storage_key_rotation_remaining().await.is_some() {
+            wallet.spawn_storage_key_rotation();
+        }
+
+        Ok(wallet)
     }
 
     #[cfg(feature = "storage")]
@@ -259,6 +314,37 @@ where
     }
 }
 
+// Cache the stored addresses of the accounts in a Ledger Nano secret manager that caches addresses
+#[cfg(all(feature = "storage", feature = "ledger_nano"))]
+async fn cache_ledger_nano_addresses<S: 'static + SecretManage>(secret_manager: &S, accounts: &[AccountDetails]) {
+    let ledger_nano = secret_manager.downcast::<LedgerSecretManager>().or_else(|| {
+        secret_manager.downcast::<SecretManager>().and_then(|s| {
+            if let SecretManager::LedgerNano(n) = s {
+                Some(n)
+            } else {
+                None
+            }
+        })
+    });
+
+    if let Some(ledger_nano) = ledger_nano {
+        for account in accounts {
+            let addresses = account
+                .public_addresses
+                .iter()
+                .chain(&account.internal_addresses)
+                .map(|address| AccountAddress {
+                    address: address.address.inner,
+                    key_index: address.key_index,
+                    internal: address.internal,
+                });
+            ledger_nano
+                .cache_account_addresses(*account.coin_type(), *account.index(), addresses)
+                .await;
+        }
+    }
+}
+
 // Check if any of the locked inputs is not used in a transaction and unlock them, so they get available for new
 // transactions
 #[cfg(feature = "storage")]
@@ -10,9 +10,21 @@ use std::sync::{
 };
 
 use crypto::keys::bip39::{Mnemonic, MnemonicRef};
+use futures::StreamExt;
 use tokio::sync::RwLock;
 
-pub use self::builder::WalletBuilder;
+#[cfg(feature = "storage")]
+pub use self::operations::contacts::Contact;
+pub use self::{
+    builder::WalletBuilder,
+    operations::{
+        account_recovery::{RecoverAccountsOptions, RecoveredAccount},
+        recovery_report::{
+            RecoveredAddress, RecoveryReport, RecoveryReportOptions, RecoveryScanError, RecoveryScanErrorKind,
+            RecoverySuggestion,
+        },
+    },
+};
 #[cfg(feature = "events")]
 use crate::wallet::events::{
     types::{Event, WalletEventType},
@@ -25,7 +37,13 @@ use crate::{
         secret::{SecretManage, SecretManager},
         verify_mnemonic, Client,
     },
-    wallet::account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::Balance, Account},
+    wallet::account::{
+        builder::AccountBuilder,
+        constants::{DEFAULT_ACCOUNT_SYNC_CONCURRENCY, PARALLEL_REQUESTS_AMOUNT},
+        operations::syncing::SyncOptions,
+        types::Balance,
+        Account,
+    },
 };
 
 /// The wallet, used to create and get accounts. One wallet can hold many accounts, but they should
@@ -154,16 +172,89 @@ where
         Ok(balance)
     }
 
-    /// Sync all accounts
+    /// Sync all accounts, or only the accounts with the `account_indexes` of the options. If `addresses` are
+    /// provided, only these addresses are synced in the accounts that contain them. Returns the balance of the synced
+    /// accounts.
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
         let mut balance = Balance::default();
-
-        for account in self.accounts.read().await.iter() {
-            balance += account.sync(options.clone()).await?;
+        for (_, account_balance) in self.sync_all(options, None).await? {
+            balance += account_balance?;
         }
 
         Ok(balance)
     }
+
+    /// Sync the accounts like [`Wallet::sync()`], but with at most `concurrency` accounts syncing at the same time, 8
+    /// by default.
+    /// The requests for the outputs of the addresses are split between the accounts that sync at the same time, so
+    /// the wallet doesn't send more of them in parallel than a single account. Returns the account index with the
+    /// result of every synced account, so an account that failed to sync doesn't prevent the others from being synced.
+    pub async fn sync_all(
+        &self,
+        options: Option<SyncOptions>,
+        concurrency: impl Into<Option<usize>> + Send,
+    ) -> crate::wallet::Result<Vec<(u32, crate::wallet::Result<Balance>)>> {
+        let accounts = self.accounts.read().await;
+        let mut accounts_to_sync = Vec::new();
+
+        match &options {
+            Some(options) if !options.account_indexes.is_empty() || !options.addresses.is_empty() => {
+                let mut account_indexes = Vec::with_capacity(accounts.len());
+                for account in accounts.iter() {
+                    account_indexes.push(*account.details().await.index());
+                }
+                if let Some(account_index) = options
+                    .account_indexes
+                    .iter()
+                    .find(|account_index| !account_indexes.contains(account_index))
+                {
+                    return Err(crate::wallet::Error::AccountNotFound(account_index.to_string()));
+                }
+                let mut unknown_addresses = options.addresses.clone();
+
+                for (account, account_index) in accounts.iter().zip(account_indexes) {
+                    if !options.account_indexes.is_empty() && !options.account_indexes.contains(&account_index) {
+                        continue;
+                    }
+                    let mut account_options = options.clone();
+                    if !options.addresses.is_empty() {
+                        let account_addresses = account.addresses().await?;
+                        account_options
+                            .addresses
+                            .retain(|address| account_addresses.iter().any(|a| &a.address == address));
+                        if account_options.addresses.is_empty() {
+                            continue;
+                        }
+                        unknown_addresses.retain(|address| !account_options.addresses.contains(address));
+                    }
+                    accounts_to_sync.push((account, Some(account_options)));
+                }
+
+                if let Some(address) = unknown_addresses.first() {
+                    return Err(crate::wallet::Error::AddressNotFoundInAccount(*address));
+                }
+            }
+            _ => accounts_to_sync.extend(accounts.iter().map(|account| (account, options.clone()))),
+        }
+
+        let concurrency = concurrency
+            .into()
+            .unwrap_or(DEFAULT_ACCOUNT_SYNC_CONCURRENCY)
+            .clamp(1, PARALLEL_REQUESTS_AMOUNT);
+        let parallel_requests = PARALLEL_REQUESTS_AMOUNT / concurrency;
+
+        Ok(futures::stream::iter(accounts_to_sync)
+            .map(|(account, options)| async move {
+                let account_index = *account.details().await.index();
+                (
+                    account_index,
+                    account.sync_with_parallel_requests(options, parallel_requests).await,
+                )
+            })
+            .buffered(concurrency)
+            .collect()
+            .await)
+    }
 }
 
 impl<S: SecretManage> WalletInner<S> {
@@ -184,6 +275,21 @@ impl<S: SecretManage> WalletInner<S> {
         emitter.on(events, handler);
     }
 
+    /// Listen to the events of the given accounts, empty vecs will listen to all events or all accounts
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn listen_to_accounts<F, A, I>(&self, account_indexes: A, events: I, handler: F)
+    where
+        A: IntoIterator<Item = u32> + Send,
+        A::IntoIter: Send,
+        I: IntoIterator<Item = WalletEventType> + Send,
+        I::IntoIter: Send,
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        let mut emitter = self.event_emitter.write().await;
+        emitter.on_accounts(account_indexes, events, handler);
+    }
+
     /// Remove wallet event listeners, empty vec will remove all listeners
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
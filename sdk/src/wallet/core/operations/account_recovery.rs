@@ -2,12 +2,56 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use instant::Instant;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     client::secret::SecretManage,
-    wallet::{account::SyncOptions, task, Account, Wallet},
+    wallet::{
+        account::{types::Balance, SyncOptions},
+        task, Account, Wallet,
+    },
 };
 
+/// Options for [`Wallet::recover_accounts_with_options()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RecoverAccountsOptions {
+    /// The index of the first account to search for.
+    pub account_start_index: u32,
+    /// The number of accounts to search for, after the last account with unspent outputs.
+    pub account_gap_limit: u32,
+    /// The number of public addresses to search for, after the last public address with unspent outputs, in each
+    /// account.
+    pub address_gap_limit: u32,
+    /// The number of internal addresses to search for, after the last internal address with unspent outputs, in each
+    /// account. Uses `address_gap_limit` if not set.
+    pub address_gap_limit_internal: Option<u32>,
+    /// The sync options. The `address_start_index` and `force_syncing` fields will be overwritten to skip existing
+    /// addresses.
+    pub sync_options: Option<SyncOptions>,
+}
+
+impl Default for RecoverAccountsOptions {
+    fn default() -> Self {
+        Self {
+            account_start_index: 0,
+            account_gap_limit: 10,
+            address_gap_limit: 20,
+            address_gap_limit_internal: None,
+            sync_options: None,
+        }
+    }
+}
+
+/// An account found by [`Wallet::recover_accounts_with_options()`], with its balance after the recovery.
+#[derive(Clone, Debug)]
+pub struct RecoveredAccount<S: SecretManage> {
+    /// The account.
+    pub account: Account<S>,
+    /// The balance of the account.
+    pub balance: Balance,
+}
+
 impl<S: 'static + SecretManage> Wallet<S>
 where
     crate::wallet::Error: From<S::Error>,
@@ -33,16 +77,45 @@ where
         address_gap_limit: u32,
         sync_options: Option<SyncOptions>,
     ) -> crate::wallet::Result<Vec<Account<S>>> {
+        Ok(self
+            .recover_accounts_with_options(RecoverAccountsOptions {
+                account_start_index,
+                account_gap_limit,
+                address_gap_limit,
+                address_gap_limit_internal: None,
+                sync_options,
+            })
+            .await?
+            .into_iter()
+            .map(|recovered_account| recovered_account.account)
+            .collect())
+    }
+
+    /// Find accounts with unspent outputs, like [`Wallet::recover_accounts()`], with separate gap limits for public
+    /// and internal addresses. Returns all accounts of the wallet after the recovery with their balances, so the
+    /// recovered funds can be shown without another sync.
+    pub async fn recover_accounts_with_options(
+        &self,
+        options: RecoverAccountsOptions,
+    ) -> crate::wallet::Result<Vec<RecoveredAccount<S>>> {
         log::debug!("[recover_accounts]");
+        let RecoverAccountsOptions {
+            account_start_index,
+            account_gap_limit,
+            address_gap_limit,
+            address_gap_limit_internal,
+            sync_options,
+        } = options;
+        let address_gap_limit_internal = address_gap_limit_internal.unwrap_or(address_gap_limit);
         let start_time = Instant::now();
         let mut max_account_index_to_keep = None;
 
         // Search for addresses in current accounts
         for account in self.accounts.read().await.iter() {
-            // If the gap limit is 0, there is no need to search for funds
-            if address_gap_limit > 0 {
+            // If the gap limits are 0, there is no need to search for funds
+            if address_gap_limit > 0 || address_gap_limit_internal > 0 {
                 account
-                    .search_addresses_with_outputs(address_gap_limit, sync_options.clone())
+                    .search_addresses_with_outputs(address_gap_limit, address_gap_limit_internal, sync_options.clone())
                     .await?;
             }
             let account_index = *account.details().await.index();
@@ -68,6 +141,7 @@ where
             .search_new_accounts(
                 account_gap_limit,
                 address_gap_limit,
+                address_gap_limit_internal,
                 &mut max_account_index_to_keep,
                 sync_options.clone(),
             )
@@ -104,8 +178,16 @@ where
         // Handle result after cleaning up the empty accounts
         new_accounts_discovery_result?;
 
+        let mut recovered_accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            recovered_accounts.push(RecoveredAccount {
+                balance: account.balance().await?,
+                account: account.clone(),
+            });
+        }
+
         log::debug!("[recover_accounts] finished in {:?}", start_time.elapsed());
-        Ok(self.accounts.read().await.clone())
+        Ok(recovered_accounts)
     }
 
     /// Generate new accounts and search for unspent outputs
@@ -113,6 +195,7 @@ where
         &self,
         account_gap_limit: u32,
         address_gap_limit: u32,
+        address_gap_limit_internal: u32,
         max_account_index_to_keep: &mut Option<u32>,
         sync_options: Option<SyncOptions>,
     ) -> crate::wallet::Result<()> {
@@ -128,7 +211,7 @@ where
                     task::spawn(async move {
                         let new_account = new_account.finish().await?;
                         let account_outputs_count = new_account
-                            .search_addresses_with_outputs(address_gap_limit, sync_options_)
+                            .search_addresses_with_outputs(address_gap_limit, address_gap_limit_internal, sync_options_)
                             .await?;
                         let account_index = *new_account.details().await.index();
                         crate::wallet::Result::Ok((account_index, account_outputs_count))
@@ -35,7 +35,8 @@ where
 
         self.background_syncing_status.store(1, Ordering::Relaxed);
         let wallet = self.clone();
-        let _background_syncing = std::thread::spawn(move || {
+        let background_tasks = &self.client.background_tasks;
+        let _background_syncing = background_tasks.spawn_thread("wallet-background-sync", move || {
             #[cfg(not(target_family = "wasm"))]
             let runtime = tokio::runtime::Builder::new_multi_thread()
                 .enable_all()
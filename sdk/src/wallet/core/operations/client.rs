@@ -40,10 +40,16 @@ where
             network_info,
             api_timeout,
             remote_pow_timeout,
+            block_idempotency_ttl,
+            validate_responses,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests,
+            // The PoW provider and progress callback are kept, they can't be passed through the bindings
+            #[cfg(not(target_family = "wasm"))]
+                pow_progress_callback: _,
+            pow_provider: _,
         } = client_options;
         self.client
             .update_node_manager(node_manager_builder.build(HashMap::new()))
@@ -51,6 +57,8 @@ where
         *self.client.network_info.write().await = network_info;
         *self.client.api_timeout.write().await = api_timeout;
         *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
+        *self.client.block_idempotency_ttl.write().await = block_idempotency_ttl;
+        *self.client.validate_responses.write().await = validate_responses;
         #[cfg(not(target_family = "wasm"))]
         self.client.request_pool.resize(max_parallel_api_requests).await;
         #[cfg(not(target_family = "wasm"))]
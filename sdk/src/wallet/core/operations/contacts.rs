@@ -0,0 +1,96 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::Bech32Address,
+    wallet::{Error, Wallet},
+};
+
+/// A named address of the address book of the wallet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct Contact {
+    /// Name of the contact, unique in the address book
+    name: String,
+    /// Bech32 encoded address of the contact
+    address: Bech32Address,
+    /// Optional note about the contact
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+impl Contact {
+    pub fn new(name: impl Into<String>, address: Bech32Address) -> Self {
+        Self {
+            name: name.into(),
+            address,
+            note: None,
+        }
+    }
+
+    pub fn with_note(mut self, note: impl Into<Option<String>>) -> Self {
+        self.note = note.into();
+        self
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Adds a contact to the address book, replacing the contact with the same name. The address needs to have the
+    /// bech32 HRP of the wallet.
+    pub async fn add_contact(&self, contact: Contact) -> crate::wallet::Result<()> {
+        if contact.name.trim().is_empty() {
+            return Err(Error::MissingParameter("contact name"));
+        }
+
+        let bech32_hrp = self.get_bech32_hrp().await?;
+        if contact.address.hrp() != &bech32_hrp {
+            return Err(crate::client::Error::Bech32HrpMismatch {
+                provided: contact.address.hrp().to_string(),
+                expected: bech32_hrp.to_string(),
+            }
+            .into());
+        }
+
+        let storage_manager = self.storage_manager.write().await;
+        let mut contacts = storage_manager.get_contacts().await?;
+        match contacts.iter_mut().find(|c| c.name == contact.name) {
+            Some(existing) => *existing = contact,
+            None => contacts.push(contact),
+        }
+        storage_manager.save_contacts(&contacts).await
+    }
+
+    /// Returns the contacts of the address book, in the order they were added.
+    pub async fn list_contacts(&self) -> crate::wallet::Result<Vec<Contact>> {
+        self.storage_manager.read().await.get_contacts().await
+    }
+
+    /// Returns the contact with the name, if it's in the address book.
+    pub async fn get_contact(&self, name: &str) -> crate::wallet::Result<Option<Contact>> {
+        Ok(self
+            .list_contacts()
+            .await?
+            .into_iter()
+            .find(|contact| contact.name == name))
+    }
+
+    /// Removes the contact with the name from the address book.
+    pub async fn remove_contact(&self, name: &str) -> crate::wallet::Result<()> {
+        let storage_manager = self.storage_manager.write().await;
+        let mut contacts = storage_manager.get_contacts().await?;
+        let len = contacts.len();
+        contacts.retain(|contact| contact.name != name);
+        if contacts.len() == len {
+            return Err(Error::ContactNotFound(name.to_string()));
+        }
+        storage_manager.save_contacts(&contacts).await
+    }
+}
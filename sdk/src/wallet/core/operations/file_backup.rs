@@ -0,0 +1,178 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Backups of the wallet data in a file encrypted with a password, which can be restored with any storage backend
+
+use std::{fs, num::NonZeroU32, path::Path, sync::atomic::Ordering};
+
+use crypto::ciphers::chacha;
+use futures::{future::try_join_all, FutureExt};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use super::storage::SaveLoadWallet;
+use crate::{
+    client::{secret::SecretManage, utils::Password, ClientBuilder},
+    types::TryFromDto,
+    wallet::{
+        account::{AccountDetails, AccountDetailsDto},
+        Account, Error, Result, Wallet, WalletBuilder,
+    },
+};
+
+/// The bytes a backup file starts with.
+const BACKUP_FILE_MAGIC: &[u8; 8] = b"IOTAWBAK";
+/// The length of the salt the encryption key is derived with.
+const SALT_LENGTH: usize = 32;
+/// The PBKDF2 rounds the encryption key is derived with, stored in the file so they can be increased later.
+const KEY_DERIVATION_ROUNDS: u32 = 100_000;
+/// The most PBKDF2 rounds a backup file can use, so a crafted file can't make the restore hang.
+const KEY_DERIVATION_ROUNDS_MAX: u32 = 10_000_000;
+
+/// The version of the backup data, increased whenever its format changes.
+const BACKUP_FILE_VERSION: u32 = 1;
+
+/// Migrations of the backup data from the version at their index + 1 to the next one. To change the format, increase
+/// [`BACKUP_FILE_VERSION`] and add a migration at the bottom of this list that converts the data of the previous
+/// version, so older backups can still be restored.
+const BACKUP_FILE_MIGRATIONS: [fn(&mut serde_json::Value) -> Result<()>; BACKUP_FILE_VERSION as usize - 1] = [];
+
+/// The wallet data stored in a backup file. The secrets of the secret manager aren't part of it.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupData {
+    version: u32,
+    coin_type: u32,
+    client_options: ClientBuilder,
+    accounts: Vec<AccountDetailsDto>,
+}
+
+fn derive_encryption_key(password: &Password, salt: &[u8], rounds: u32) -> Result<Zeroizing<[u8; 32]>> {
+    let rounds = NonZeroU32::new(rounds)
+        .filter(|rounds| rounds.get() <= KEY_DERIVATION_ROUNDS_MAX)
+        .ok_or(Error::Backup("invalid key derivation rounds"))?;
+    let mut encryption_key = Zeroizing::new([0; 32]);
+    crypto::keys::pbkdf::PBKDF2_HMAC_SHA512(password.as_bytes(), salt, rounds, encryption_key.as_mut());
+
+    Ok(encryption_key)
+}
+
+/// Migrates the backup data to the current version.
+fn migrate_backup_data(mut data: serde_json::Value) -> Result<BackupData> {
+    let version = data
+        .get("version")
+        .and_then(serde_json::Value::as_u64)
+        .ok_or(Error::Backup("missing backup version"))? as u32;
+    if version == 0 || version > BACKUP_FILE_VERSION {
+        return Err(Error::Backup("unsupported backup version"));
+    }
+
+    for migration in &BACKUP_FILE_MIGRATIONS[version as usize - 1..] {
+        migration(&mut data)?;
+    }
+    data["version"] = BACKUP_FILE_VERSION.into();
+
+    Ok(serde_json::from_value(data)?)
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+    WalletBuilder<S>: SaveLoadWallet,
+{
+    /// Writes the accounts, with their addresses, outputs and pending transactions, the client options and the coin
+    /// type to a file encrypted with a key derived from the password. Unlike Stronghold backups, the file doesn't
+    /// depend on the storage or secret manager, but it also doesn't contain the secrets of the secret manager, so
+    /// the mnemonic or seed has to be backed up separately.
+    pub async fn backup_to_file(
+        &self,
+        backup_path: impl AsRef<Path>,
+        password: impl Into<Password> + Send,
+    ) -> Result<()> {
+        log::debug!("[backup_to_file]");
+        let password = password.into();
+
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            accounts.push(AccountDetailsDto::from(&*account.details().await));
+        }
+        let data = Zeroizing::new(serde_json::to_vec(&BackupData {
+            version: BACKUP_FILE_VERSION,
+            coin_type: self.coin_type.load(Ordering::Relaxed),
+            client_options: self.client_options().await,
+            accounts,
+        })?);
+
+        let mut salt = [0; SALT_LENGTH];
+        crypto::utils::rand::fill(&mut salt)?;
+        let encryption_key = derive_encryption_key(&password, &salt, KEY_DERIVATION_ROUNDS)?;
+
+        let mut file = Vec::new();
+        file.extend_from_slice(BACKUP_FILE_MAGIC);
+        file.extend_from_slice(&KEY_DERIVATION_ROUNDS.to_le_bytes());
+        file.extend_from_slice(&salt);
+        file.extend_from_slice(&chacha::aead_encrypt(&encryption_key, &data)?);
+
+        fs::write(backup_path, file)?;
+
+        Ok(())
+    }
+
+    /// Restores the accounts, client options and coin type of a file written with [`Wallet::backup_to_file()`],
+    /// migrating the data of backups written by older versions. Returns an error if accounts were already created.
+    pub async fn restore_backup_from_file(
+        &self,
+        backup_path: impl AsRef<Path>,
+        password: impl Into<Password> + Send,
+    ) -> Result<()> {
+        log::debug!("[restore_backup_from_file]");
+        let password = password.into();
+
+        let file = fs::read(backup_path)?;
+        let header_length = BACKUP_FILE_MAGIC.len() + core::mem::size_of::<u32>() + SALT_LENGTH;
+        if file.len() < header_length || !file.starts_with(BACKUP_FILE_MAGIC) {
+            return Err(Error::Backup("not a backup file"));
+        }
+        let (header, encrypted_data) = file.split_at(header_length);
+        let (rounds, salt) = header[BACKUP_FILE_MAGIC.len()..].split_at(core::mem::size_of::<u32>());
+        // Safe to unwrap, the length was checked
+        let rounds = u32::from_le_bytes(rounds.try_into().unwrap());
+
+        let encryption_key = derive_encryption_key(&password, salt, rounds)?;
+        let data = Zeroizing::new(
+            chacha::aead_decrypt(&encryption_key, encrypted_data)
+                .map_err(|_| Error::Backup("invalid password or corrupted backup file"))?,
+        );
+        let data = migrate_backup_data(serde_json::from_slice(&data)?)?;
+
+        let mut accounts = self.accounts.write().await;
+        // We don't want to overwrite possible existing accounts
+        if !accounts.is_empty() {
+            return Err(Error::Backup("can't restore backup when there are already accounts"));
+        }
+
+        let account_details = data
+            .accounts
+            .into_iter()
+            .map(AccountDetails::try_from_dto)
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+
+        self.coin_type.store(data.coin_type, Ordering::Relaxed);
+        // Also stores the coin type
+        self.set_client_options(data.client_options).await?;
+
+        *accounts = try_join_all(
+            account_details
+                .into_iter()
+                .map(|details| Account::new(details, self.inner.clone()).boxed()),
+        )
+        .await?;
+
+        #[cfg(feature = "storage")]
+        for account in accounts.iter() {
+            account.save(None).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -44,3 +44,40 @@ impl<S: SecretManage> Wallet<S> {
         )?))
     }
 }
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Change the alias of an account, which can't be used by another account of the wallet.
+    pub async fn rename_account<I: Into<AccountIdentifier> + Send>(
+        &self,
+        identifier: I,
+        alias: impl Into<String> + Send,
+    ) -> crate::wallet::Result<()> {
+        let account = self.get_account(identifier).await?;
+        let alias = alias.into();
+        let account_index = *account.details().await.index();
+
+        // Same check as when creating an account
+        for other_account in self.accounts.read().await.iter() {
+            let other_account = other_account.details().await;
+            if *other_account.index() != account_index && other_account.alias().to_lowercase() == alias.to_lowercase() {
+                return Err(crate::wallet::Error::AccountAliasAlreadyExists(alias));
+            }
+        }
+
+        account.set_alias(&alias).await
+    }
+
+    /// Get the accounts which have the metadata key set to the value.
+    pub async fn get_accounts_by_metadata(&self, key: &str, value: &str) -> Vec<Account<S>> {
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            if account.details().await.metadata().get(key).map(String::as_str) == Some(value) {
+                accounts.push(account.clone());
+            }
+        }
+        accounts
+    }
+}
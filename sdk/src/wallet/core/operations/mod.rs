@@ -5,10 +5,17 @@ pub(crate) mod account_recovery;
 pub(crate) mod address_generation;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
+#[cfg(feature = "storage")]
+pub(crate) mod contacts;
+#[cfg(not(target_family = "wasm"))]
+pub(crate) mod file_backup;
 pub(crate) mod get_account;
 #[cfg(feature = "ledger_nano")]
 pub(crate) mod ledger_nano;
+pub(crate) mod recovery_report;
 pub(crate) mod storage;
+#[cfg(feature = "storage")]
+pub(crate) mod storage_key_rotation;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold;
 #[cfg(feature = "stronghold")]
@@ -0,0 +1,468 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeMap, fmt, ops::Range, sync::atomic::Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
+        node_api::indexer::query_parameters::QueryParameter,
+        secret::{GenerateAddressOptions, SecretManage},
+    },
+    types::block::address::{Address, Bech32Address, Hrp},
+    wallet::Wallet,
+};
+
+/// Options for [`Wallet::recovery_report()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RecoveryReportOptions {
+    /// The coin types to scan.
+    pub coin_types: Vec<u32>,
+    /// The account indexes to scan.
+    pub account_indexes: Range<u32>,
+    /// The address indexes to scan, for public and internal addresses.
+    pub address_range: Range<u32>,
+}
+
+impl Default for RecoveryReportOptions {
+    fn default() -> Self {
+        Self {
+            coin_types: vec![IOTA_COIN_TYPE, SHIMMER_COIN_TYPE],
+            account_indexes: 0..10,
+            address_range: 0..20,
+        }
+    }
+}
+
+/// An address with outputs found by [`Wallet::recovery_report()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveredAddress {
+    /// The address.
+    pub address: Bech32Address,
+    /// The coin type the address was derived with.
+    pub coin_type: u32,
+    /// The account index the address was derived with.
+    pub account_index: u32,
+    /// Whether the address is an internal address.
+    pub internal: bool,
+    /// The address index.
+    pub address_index: u32,
+    /// The amount of basic and nft outputs owned by the address and alias outputs it's the state controller of.
+    pub output_count: usize,
+    /// The amount of base coins of these outputs.
+    pub amount: u64,
+    /// The amount of alias outputs the address is only the governor of. Their base coins can only be moved by their
+    /// state controller, so they aren't part of the amount.
+    pub governed_alias_count: usize,
+}
+
+/// The step of the scan that failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecoveryScanErrorKind {
+    /// The addresses couldn't be generated, e.g. because the coin type isn't supported by the app opened on a Ledger
+    /// Nano.
+    AddressGeneration,
+    /// The outputs of an address couldn't be requested from the node.
+    NodeRequest,
+}
+
+/// A part of the scan that failed, e.g. a coin type that isn't supported by the app opened on a Ledger Nano.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryScanError {
+    /// The step of the scan that failed.
+    pub kind: RecoveryScanErrorKind,
+    /// The coin type that couldn't be scanned.
+    pub coin_type: u32,
+    /// The account index that couldn't be scanned.
+    pub account_index: u32,
+    /// Whether the internal or the public addresses couldn't be scanned.
+    pub internal: bool,
+    /// The address index that couldn't be scanned, if the scan failed for a single address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_index: Option<u32>,
+    /// The error message.
+    pub error: String,
+}
+
+/// The settings to recover the funds of a coin type with, see [`Wallet::recover_accounts()`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoverySuggestion {
+    /// The coin type the wallet has to be created with.
+    pub coin_type: u32,
+    /// Whether the coin type is the one of the wallet.
+    pub matches_wallet_coin_type: bool,
+    /// The account gap limit for [`Wallet::recover_accounts()`] with an account start index of 0.
+    pub account_gap_limit: u32,
+    /// The address gap limit for [`Wallet::recover_accounts()`].
+    pub address_gap_limit: u32,
+    /// The amount of base coins found with this coin type.
+    pub amount: u64,
+}
+
+/// The result of [`Wallet::recovery_report()`], serializable for tools and printable for humans.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryReport {
+    /// The coin type of the wallet.
+    pub wallet_coin_type: u32,
+    /// The scanned options.
+    pub options: RecoveryReportOptions,
+    /// The addresses with outputs.
+    pub addresses: Vec<RecoveredAddress>,
+    /// The parts of the scan that failed.
+    pub errors: Vec<RecoveryScanError>,
+    /// The settings to recover the found funds with, one per coin type with funds.
+    pub suggestions: Vec<RecoverySuggestion>,
+}
+
+impl RecoveryReport {
+    /// Returns the amount of base coins found on all addresses.
+    pub fn total_amount(&self) -> u64 {
+        self.addresses.iter().map(|address| address.amount).sum()
+    }
+
+    fn suggest(&mut self) {
+        let mut per_coin_type = BTreeMap::<u32, RecoverySuggestion>::new();
+
+        for address in &self.addresses {
+            let suggestion = per_coin_type
+                .entry(address.coin_type)
+                .or_insert_with(|| RecoverySuggestion {
+                    coin_type: address.coin_type,
+                    matches_wallet_coin_type: address.coin_type == self.wallet_coin_type,
+                    account_gap_limit: 0,
+                    address_gap_limit: 0,
+                    amount: 0,
+                });
+            // The gap limits need to reach the last account and address with funds
+            suggestion.account_gap_limit = suggestion.account_gap_limit.max(address.account_index + 1);
+            suggestion.address_gap_limit = suggestion.address_gap_limit.max(address.address_index + 1);
+            suggestion.amount += address.amount;
+        }
+
+        self.suggestions = per_coin_type.into_values().collect();
+    }
+}
+
+impl fmt::Display for RecoveryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Scanned coin types {:?}, accounts {:?} and addresses {:?}, the wallet uses coin type {}",
+            self.options.coin_types, self.options.account_indexes, self.options.address_range, self.wallet_coin_type
+        )?;
+
+        if self.addresses.is_empty() {
+            writeln!(f, "No funds found")?;
+        }
+        for address in &self.addresses {
+            writeln!(
+                f,
+                "Found {} in {} outputs on {} (coin type {}, account {}, {} address {})",
+                address.amount,
+                address.output_count,
+                address.address,
+                address.coin_type,
+                address.account_index,
+                if address.internal { "internal" } else { "public" },
+                address.address_index
+            )?;
+            if address.governed_alias_count > 0 {
+                writeln!(
+                    f,
+                    "{} is the governor of {} aliases, their funds can only be moved by their state controller",
+                    address.address, address.governed_alias_count
+                )?;
+            }
+        }
+        for error in &self.errors {
+            let addresses = if error.internal { "internal" } else { "public" };
+            match (error.kind, error.address_index) {
+                (RecoveryScanErrorKind::NodeRequest, Some(address_index)) => writeln!(
+                    f,
+                    "Couldn't request the outputs of coin type {} account {} {addresses} address {address_index}: {}",
+                    error.coin_type, error.account_index, error.error
+                )?,
+                _ => writeln!(
+                    f,
+                    "Couldn't generate the {addresses} addresses of coin type {} account {}: {}",
+                    error.coin_type, error.account_index, error.error
+                )?,
+            }
+        }
+        for suggestion in &self.suggestions {
+            if !suggestion.matches_wallet_coin_type {
+                writeln!(
+                    f,
+                    "Funds were found with coin type {}, create a wallet with this coin type to recover them",
+                    suggestion.coin_type
+                )?;
+            }
+            writeln!(
+                f,
+                "Recover {} with coin type {} using an account start index of 0, an account gap limit of {} and an \
+                 address gap limit of {}",
+                suggestion.amount, suggestion.coin_type, suggestion.account_gap_limit, suggestion.address_gap_limit
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Scans the addresses of a secret manager over several coin types, accounts and address indexes and reports where
+    /// funds were found and which settings recover them with [`Wallet::recover_accounts()`]. The secret manager
+    /// doesn't need to be the one of the wallet, so different mnemonics or a Ledger Nano can be checked. Failures to
+    /// generate the addresses of an account, e.g. because the opened Ledger Nano app doesn't support the coin type, and
+    /// failed node requests for single addresses are reported instead of returned. Requires a node with the indexer
+    /// plugin enabled.
+    pub async fn recovery_report<T: SecretManage>(
+        &self,
+        secret_manager: &T,
+        options: RecoveryReportOptions,
+    ) -> crate::wallet::Result<RecoveryReport>
+    where
+        crate::wallet::Error: From<T::Error>,
+    {
+        log::debug!("[recovery_report]");
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let mut report = RecoveryReport {
+            wallet_coin_type: self.coin_type.load(Ordering::Relaxed),
+            options,
+            addresses: Vec::new(),
+            errors: Vec::new(),
+            suggestions: Vec::new(),
+        };
+
+        for coin_type in report.options.coin_types.clone() {
+            for account_index in report.options.account_indexes.clone() {
+                for internal in [false, true] {
+                    let scan_error = |kind, address_index, error: String| RecoveryScanError {
+                        kind,
+                        coin_type,
+                        account_index,
+                        internal,
+                        address_index,
+                        error,
+                    };
+
+                    let generated_addresses = match secret_manager
+                        .generate_ed25519_addresses(
+                            coin_type,
+                            account_index,
+                            report.options.address_range.clone(),
+                            GenerateAddressOptions {
+                                internal,
+                                ..Default::default()
+                            },
+                        )
+                        .await
+                    {
+                        Ok(generated_addresses) => generated_addresses,
+                        Err(error) => {
+                            log::debug!(
+                                "[recovery_report] couldn't generate addresses of coin type {coin_type} account \
+                                 {account_index}"
+                            );
+                            report.errors.push(scan_error(
+                                RecoveryScanErrorKind::AddressGeneration,
+                                None,
+                                crate::wallet::Error::from(error).to_string(),
+                            ));
+                            continue;
+                        }
+                    };
+
+                    for (address, address_index) in generated_addresses
+                        .into_iter()
+                        .zip(report.options.address_range.clone())
+                    {
+                        let address = Bech32Address::new(bech32_hrp, Address::from(address));
+                        match self.scan_recovery_address(address).await {
+                            Ok((0, _, 0)) => {}
+                            Ok((output_count, amount, governed_alias_count)) => {
+                                log::debug!("[recovery_report] found {amount} on {address}");
+                                report.addresses.push(RecoveredAddress {
+                                    address,
+                                    coin_type,
+                                    account_index,
+                                    internal,
+                                    address_index,
+                                    output_count,
+                                    amount,
+                                    governed_alias_count,
+                                });
+                            }
+                            Err(error) => {
+                                log::debug!("[recovery_report] couldn't request the outputs of {address}");
+                                report.errors.push(scan_error(
+                                    RecoveryScanErrorKind::NodeRequest,
+                                    Some(address_index),
+                                    error.to_string(),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        report.suggest();
+
+        Ok(report)
+    }
+
+    // Returns the amount of outputs owned by the address, their amount and the amount of aliases the address is only
+    // the governor of.
+    async fn scan_recovery_address(&self, address: Bech32Address) -> crate::wallet::Result<(usize, u64, usize)> {
+        let mut output_ids = self
+            .client()
+            .basic_output_ids([QueryParameter::Address(address)])
+            .await?
+            .items;
+        output_ids.extend(
+            self.client()
+                .nft_output_ids([QueryParameter::Address(address)])
+                .await?
+                .items,
+        );
+        // The state controller can move the base coins of an alias, the governor can't
+        output_ids.extend(
+            self.client()
+                .alias_output_ids([QueryParameter::StateController(address)])
+                .await?
+                .items,
+        );
+        let governed_alias_count = self
+            .client()
+            .alias_output_ids([QueryParameter::Governor(address)])
+            .await?
+            .items
+            .into_iter()
+            .filter(|output_id| !output_ids.contains(output_id))
+            .count();
+
+        let amount = if output_ids.is_empty() {
+            0
+        } else {
+            self.client()
+                .get_outputs(&output_ids)
+                .await?
+                .iter()
+                .map(|output_with_metadata| output_with_metadata.output().amount())
+                .sum()
+        };
+
+        Ok((output_ids.len(), amount, governed_alias_count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recovered_address(coin_type: u32, account_index: u32, address_index: u32, amount: u64) -> RecoveredAddress {
+        RecoveredAddress {
+            address: Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")
+                .unwrap(),
+            coin_type,
+            account_index,
+            internal: false,
+            address_index,
+            output_count: 1,
+            amount,
+            governed_alias_count: 0,
+        }
+    }
+
+    #[test]
+    fn suggestions() {
+        let mut report = RecoveryReport {
+            wallet_coin_type: SHIMMER_COIN_TYPE,
+            options: RecoveryReportOptions::default(),
+            addresses: vec![
+                recovered_address(SHIMMER_COIN_TYPE, 0, 3, 1_000),
+                recovered_address(SHIMMER_COIN_TYPE, 2, 0, 2_000),
+                recovered_address(IOTA_COIN_TYPE, 1, 5, 3_000),
+            ],
+            errors: Vec::new(),
+            suggestions: Vec::new(),
+        };
+        report.suggest();
+
+        assert_eq!(report.total_amount(), 6_000);
+        assert_eq!(
+            report.suggestions,
+            [
+                RecoverySuggestion {
+                    coin_type: IOTA_COIN_TYPE,
+                    matches_wallet_coin_type: false,
+                    account_gap_limit: 2,
+                    address_gap_limit: 6,
+                    amount: 3_000,
+                },
+                RecoverySuggestion {
+                    coin_type: SHIMMER_COIN_TYPE,
+                    matches_wallet_coin_type: true,
+                    account_gap_limit: 3,
+                    address_gap_limit: 4,
+                    amount: 3_000,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn scan_errors() {
+        let report = RecoveryReport {
+            wallet_coin_type: SHIMMER_COIN_TYPE,
+            options: RecoveryReportOptions::default(),
+            addresses: Vec::new(),
+            errors: vec![
+                RecoveryScanError {
+                    kind: RecoveryScanErrorKind::AddressGeneration,
+                    coin_type: IOTA_COIN_TYPE,
+                    account_index: 0,
+                    internal: false,
+                    address_index: None,
+                    error: "unsupported coin type".to_string(),
+                },
+                RecoveryScanError {
+                    kind: RecoveryScanErrorKind::NodeRequest,
+                    coin_type: SHIMMER_COIN_TYPE,
+                    account_index: 1,
+                    internal: true,
+                    address_index: Some(4),
+                    error: "timeout".to_string(),
+                },
+            ],
+            suggestions: Vec::new(),
+        };
+
+        let display = report.to_string();
+        assert!(
+            display
+                .contains("Couldn't generate the public addresses of coin type 4218 account 0: unsupported coin type")
+        );
+        assert!(
+            display.contains("Couldn't request the outputs of coin type 4219 account 1 internal address 4: timeout")
+        );
+
+        let json = serde_json::to_value(&report.errors).unwrap();
+        assert_eq!(json[0]["kind"], "addressGeneration");
+        assert!(json[0].get("addressIndex").is_none());
+        assert_eq!(json[1]["kind"], "nodeRequest");
+        assert_eq!(json[1]["addressIndex"], 4);
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use zeroize::Zeroizing;
+
+use crate::{client::secret::SecretManage, wallet::Wallet};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Replaces the storage encryption key. The records are re-encrypted with the new key one by one in the background,
+    /// while the wallet can be used as usual. If the wallet is closed before all records are re-encrypted, the
+    /// rotation continues when the wallet is opened again with the new key and the previous key set with
+    /// [`StorageOptions::with_previous_encryption_key()`](crate::wallet::storage::StorageOptions::with_previous_encryption_key).
+    pub async fn rotate_storage_key(&self, new_encryption_key: [u8; 32]) -> crate::wallet::Result<()> {
+        log::debug!("[STORAGE KEY ROTATION] rotate_storage_key");
+
+        self.storage_manager
+            .write()
+            .await
+            .start_key_rotation(Zeroizing::new(new_encryption_key))
+            .await?;
+        self.spawn_storage_key_rotation();
+
+        Ok(())
+    }
+
+    /// Returns the amount of records that still need to be re-encrypted, or `None` if no storage key rotation is in
+    /// progress.
+    pub async fn storage_key_rotation_remaining(&self) -> Option<usize> {
+        self.storage_manager.read().await.key_rotation_remaining()
+    }
+
+    pub(crate) fn spawn_storage_key_rotation(&self) {
+        let wallet = self.clone();
+        let background_tasks = &self.client.background_tasks;
+        background_tasks.spawn("wallet-storage-key-rotation", async move {
+            loop {
+                // The lock is only held for a single record, so the wallet isn't blocked during the rotation
+                match wallet.storage_manager.write().await.rotate_next_record().await {
+                    Ok(true) => break,
+                    Ok(false) => tokio::task::yield_now().await,
+                    Err(err) => {
+                        log::error!("[STORAGE KEY ROTATION] interrupted: {err}");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+}
@@ -21,18 +21,17 @@ impl Wallet {
         Ok(())
     }
 
-    /// Change the Stronghold password to another one and also re-encrypt the values in the loaded snapshot with it.
+    /// Change the Stronghold password to another one and re-encrypt the snapshot file with it, see
+    /// [`StrongholdAdapter::rotate_password()`].
     pub async fn change_stronghold_password(
         &self,
         current_password: impl Into<Password> + Send,
         new_password: impl Into<Password> + Send,
     ) -> crate::wallet::Result<()> {
-        let current_password = current_password.into();
-        let new_password = new_password.into();
-
-        if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
-            stronghold.set_password(current_password).await?;
-            stronghold.change_password(new_password).await?;
+        log::debug!("[change_stronghold_password]");
+        match &*self.secret_manager.read().await {
+            SecretManager::Stronghold(stronghold) => stronghold.rotate_password(current_password, new_password).await?,
+            _ => return Err(crate::client::Error::SecretManagerMismatch.into()),
         }
         Ok(())
     }
@@ -81,16 +80,20 @@ impl Wallet<StrongholdAdapter> {
         Ok(self.secret_manager.write().await.set_password(password).await?)
     }
 
-    /// Change the Stronghold password to another one and also re-encrypt the values in the loaded snapshot with it.
+    /// Change the Stronghold password to another one and re-encrypt the snapshot file with it, see
+    /// [`StrongholdAdapter::rotate_password()`].
     pub async fn change_stronghold_password(
         &self,
         current_password: impl Into<Password> + Send,
         new_password: impl Into<Password> + Send,
     ) -> crate::wallet::Result<()> {
-        let stronghold = &mut *self.secret_manager.write().await;
-        stronghold.set_password(current_password).await?;
-        stronghold.change_password(new_password).await?;
-        Ok(())
+        log::debug!("[change_stronghold_password]");
+        Ok(self
+            .secret_manager
+            .read()
+            .await
+            .rotate_password(current_password, new_password)
+            .await?)
     }
 
     /// Sets the Stronghold password clear interval
@@ -8,7 +8,7 @@ use serde::{
     Serialize,
 };
 
-use crate::types::block::{address::Bech32Address, payload::transaction::TransactionId};
+use crate::types::block::{address::Bech32Address, output::AliasId, payload::transaction::TransactionId};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -22,6 +22,12 @@ pub enum Error {
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(Bech32Address),
+    /// Alias not found in unspent outputs
+    #[error("alias {0} not found in unspent outputs")]
+    AliasNotFoundInUnspentOutputs(AliasId),
+    /// The state controller of an alias isn't an address of the account
+    #[error("the state controller address of alias {0} is not an address of the account")]
+    AliasStateControllerNotInAccount(AliasId),
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
@@ -37,6 +43,9 @@ pub enum Error {
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {output_count}/{output_count_max}, consolidation required")]
     ConsolidationRequired { output_count: usize, output_count_max: u16 },
+    /// Contact not found in the address book
+    #[error("contact `{0}` not found")]
+    ContactNotFound(String),
     /// Crypto.rs error
     #[error("{0}")]
     Crypto(#[from] crypto::Error),
@@ -67,6 +76,9 @@ pub enum Error {
     /// serde_json error.
     #[error("`{0}`")]
     Json(#[from] serde_json::error::Error),
+    /// No public address with the key index in account
+    #[error("no public address with key index {0} in account")]
+    KeyIndexNotFoundInAccount(u32),
     /// Error migrating storage or backup
     #[error("migration failed {0}")]
     Migration(String),
@@ -92,6 +104,14 @@ pub enum Error {
     /// Errors not covered by other variants.
     #[error(transparent)]
     Other(#[from] Box<dyn std::error::Error + Send + Sync>),
+    /// An operation sending multiple transactions failed after some of them were already sent
+    #[error("{error}, after sending transactions {transaction_ids:?}")]
+    PartiallySent {
+        /// The IDs of the transactions sent before the error, in the order they were sent.
+        transaction_ids: Vec<TransactionId>,
+        /// The error that stopped the operation.
+        error: Box<Self>,
+    },
     /// Participation error
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
@@ -109,6 +129,17 @@ pub enum Error {
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// Transaction can't be rebuilt
+    #[error("transaction {0} can't be rebuilt: {1}")]
+    TransactionNotRebuildable(TransactionId, &'static str),
+    /// The expiration could pass before the transaction gets confirmed
+    #[error("expiration in {expiration} seconds is unsafe, it needs to be later than the slack of {slack} seconds")]
+    UnsafeExpiration {
+        /// The requested expiration in seconds.
+        expiration: u32,
+        /// The slack in seconds.
+        slack: u32,
+    },
     // TODO more precise error
     /// Voting error
     #[cfg(feature = "participation")]
@@ -121,6 +152,20 @@ pub enum Error {
     InvalidVotingPower,
 }
 
+impl Error {
+    /// Returns [`Error::PartiallySent`] with the already sent transactions, or the error itself if none were sent.
+    pub(crate) fn partially_sent(transaction_ids: Vec<TransactionId>, error: Self) -> Self {
+        if transaction_ids.is_empty() {
+            error
+        } else {
+            Self::PartiallySent {
+                transaction_ids,
+                error: Box::new(error),
+            }
+        }
+    }
+}
+
 // Serialize type with Display error
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -189,3 +234,32 @@ impl From<rocksdb::Error> for Error {
         Self::Storage(error.to_string())
     }
 }
+
+#[cfg(feature = "storage-sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Self::Storage(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partially_sent() {
+        let error = Error::partially_sent(Vec::new(), Error::MissingParameter("amount"));
+        assert!(matches!(error, Error::MissingParameter("amount")));
+
+        let transaction_id = TransactionId::new([1; 32]);
+        let error = Error::partially_sent(vec![transaction_id], Error::MissingParameter("amount"));
+        match &error {
+            Error::PartiallySent { transaction_ids, error } => {
+                assert_eq!(transaction_ids, &[transaction_id]);
+                assert!(matches!(**error, Error::MissingParameter("amount")));
+            }
+            _ => panic!("expected Error::PartiallySent"),
+        }
+        assert_eq!(serde_json::to_value(&error).unwrap()["type"], "partiallySent");
+    }
+}
@@ -5,7 +5,7 @@ pub mod types;
 
 use alloc::sync::Arc;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{Debug, Formatter, Result},
 };
 
@@ -41,9 +41,17 @@ impl EventEmitter {
                 WalletEventType::SpentOutput,
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
+                WalletEventType::LockedAddressChanged,
+                WalletEventType::AddressRotated,
+                WalletEventType::TransactionRebuilt,
+                WalletEventType::AccountRenamed,
+                WalletEventType::SyncProgress,
+                WalletEventType::AutoConsolidation,
                 WalletEventType::ConsolidationRequired,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
+                #[cfg(feature = "ledger_nano")]
+                WalletEventType::LedgerAddressMismatch,
             ] {
                 self.handlers.entry(event_type).or_default().push(handler.clone());
             }
@@ -53,6 +61,24 @@ impl EventEmitter {
         }
     }
 
+    /// Registers function `handler` as a listener for a `WalletEventType` of the given accounts, so that the events of
+    /// other accounts aren't passed to it. If no account index is provided the handler is registered for all accounts.
+    pub fn on_accounts<F>(
+        &mut self,
+        account_indexes: impl IntoIterator<Item = u32>,
+        events: impl IntoIterator<Item = WalletEventType>,
+        handler: F,
+    ) where
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        let account_indexes = account_indexes.into_iter().collect::<HashSet<_>>();
+        self.on(events, move |event| {
+            if account_indexes.is_empty() || account_indexes.contains(&event.account_index) {
+                handler(event);
+            }
+        });
+    }
+
     /// Removes handlers for each given `WalletEventType`.
     /// If no `WalletEventType` is given, handlers will be removed for all event types.
     pub fn clear(&mut self, events: impl IntoIterator<Item = WalletEventType>) {
@@ -74,9 +100,17 @@ impl EventEmitter {
             WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
             WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
             WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
+            WalletEvent::LockedAddressChanged(_) => WalletEventType::LockedAddressChanged,
+            WalletEvent::AddressRotated(_) => WalletEventType::AddressRotated,
+            WalletEvent::TransactionRebuilt(_) => WalletEventType::TransactionRebuilt,
+            WalletEvent::AccountRenamed(_) => WalletEventType::AccountRenamed,
+            WalletEvent::SyncProgress(_) => WalletEventType::SyncProgress,
+            WalletEvent::AutoConsolidation(_) => WalletEventType::AutoConsolidation,
             WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
             #[cfg(feature = "ledger_nano")]
             WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
+            #[cfg(feature = "ledger_nano")]
+            WalletEvent::LedgerAddressMismatch(_) => WalletEventType::LedgerAddressMismatch,
         };
         let event = Event { account_index, event };
         if let Some(handlers) = self.handlers.get(&event_type) {
@@ -161,6 +195,7 @@ mod tests {
                 )
                 .expect("invalid tx id"),
                 inclusion_state: InclusionState::Confirmed,
+                block_id: None,
             }),
         );
 
@@ -188,6 +223,7 @@ mod tests {
                 )
                 .expect("invalid tx id"),
                 inclusion_state: InclusionState::Confirmed,
+                block_id: None,
             }),
         );
         assert_eq!(3, event_counter.load(Ordering::SeqCst));
@@ -204,4 +240,26 @@ mod tests {
         }
         assert_eq!(1_000_003, event_counter.load(Ordering::SeqCst));
     }
+
+    #[test]
+    fn events_of_accounts() {
+        let mut emitter = EventEmitter::new();
+        let event_counter = Arc::new(AtomicUsize::new(0));
+
+        let event_counter_clone = Arc::clone(&event_counter);
+        emitter.on_accounts([1, 2], [WalletEventType::ConsolidationRequired], move |event| {
+            assert_ne!(event.account_index, 0);
+            event_counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        emitter.emit(0, WalletEvent::ConsolidationRequired);
+        emitter.emit(1, WalletEvent::ConsolidationRequired);
+        emitter.emit(2, WalletEvent::ConsolidationRequired);
+        emitter.emit(
+            2,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+        );
+
+        assert_eq!(2, event_counter.load(Ordering::SeqCst));
+    }
 }
@@ -10,7 +10,9 @@ use crate::{
         api::core::response::OutputWithMetadataResponse,
         block::{
             address::Bech32Address,
+            output::OutputId,
             payload::transaction::{dto::TransactionPayloadDto, TransactionId},
+            BlockId,
         },
     },
     wallet::account::types::{InclusionState, OutputDataDto},
@@ -36,6 +38,15 @@ pub enum WalletEvent {
     SpentOutput(Box<SpentOutputEvent>),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
+    LockedAddressChanged(Box<LockedAddressChangedEvent>),
+    AddressRotated(Box<AddressRotatedEvent>),
+    TransactionRebuilt(Box<TransactionRebuiltEvent>),
+    AccountRenamed(Box<AccountRenamedEvent>),
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    LedgerAddressMismatch(Box<LedgerAddressMismatchEvent>),
+    SyncProgress(SyncProgressEvent),
+    AutoConsolidation(Box<AutoConsolidationEvent>),
 }
 
 impl Serialize for WalletEvent {
@@ -58,6 +69,14 @@ impl Serialize for WalletEvent {
             T3(&'a SpentOutputEvent),
             T4(&'a TransactionInclusionEvent),
             T5(TransactionProgressEvent_<'a>),
+            T6(&'a LockedAddressChangedEvent),
+            T7(&'a AddressRotatedEvent),
+            T8(&'a TransactionRebuiltEvent),
+            T9(&'a AccountRenamedEvent),
+            #[cfg(feature = "ledger_nano")]
+            T10(&'a LedgerAddressMismatchEvent),
+            T11(&'a SyncProgressEvent),
+            T12(&'a AutoConsolidationEvent),
         }
         #[derive(Serialize)]
         struct TypedWalletEvent_<'a> {
@@ -92,6 +111,35 @@ impl Serialize for WalletEvent {
                 kind: WalletEventType::TransactionProgress as u8,
                 event: WalletEvent_::T5(TransactionProgressEvent_ { progress: e }),
             },
+            Self::LockedAddressChanged(e) => TypedWalletEvent_ {
+                kind: WalletEventType::LockedAddressChanged as u8,
+                event: WalletEvent_::T6(e),
+            },
+            Self::AddressRotated(e) => TypedWalletEvent_ {
+                kind: WalletEventType::AddressRotated as u8,
+                event: WalletEvent_::T7(e),
+            },
+            Self::TransactionRebuilt(e) => TypedWalletEvent_ {
+                kind: WalletEventType::TransactionRebuilt as u8,
+                event: WalletEvent_::T8(e),
+            },
+            Self::AccountRenamed(e) => TypedWalletEvent_ {
+                kind: WalletEventType::AccountRenamed as u8,
+                event: WalletEvent_::T9(e),
+            },
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerAddressMismatch(e) => TypedWalletEvent_ {
+                kind: WalletEventType::LedgerAddressMismatch as u8,
+                event: WalletEvent_::T10(e),
+            },
+            Self::SyncProgress(e) => TypedWalletEvent_ {
+                kind: WalletEventType::SyncProgress as u8,
+                event: WalletEvent_::T11(e),
+            },
+            Self::AutoConsolidation(e) => TypedWalletEvent_ {
+                kind: WalletEventType::AutoConsolidation as u8,
+                event: WalletEvent_::T12(e),
+            },
         };
         event.serialize(serializer)
     }
@@ -143,6 +191,41 @@ impl<'de> Deserialize<'de> for WalletEvent {
                         })?
                         .progress,
                 ),
+                WalletEventType::LockedAddressChanged => {
+                    Self::LockedAddressChanged(Box::new(LockedAddressChangedEvent::deserialize(value).map_err(
+                        |e| serde::de::Error::custom(format!("cannot deserialize LockedAddressChanged: {e}")),
+                    )?))
+                }
+                WalletEventType::AddressRotated => {
+                    Self::AddressRotated(Box::new(AddressRotatedEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize AddressRotated: {e}"))
+                    })?))
+                }
+                WalletEventType::TransactionRebuilt => {
+                    Self::TransactionRebuilt(Box::new(TransactionRebuiltEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize TransactionRebuilt: {e}"))
+                    })?))
+                }
+                WalletEventType::AccountRenamed => {
+                    Self::AccountRenamed(Box::new(AccountRenamedEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize AccountRenamed: {e}"))
+                    })?))
+                }
+                #[cfg(feature = "ledger_nano")]
+                WalletEventType::LedgerAddressMismatch => {
+                    Self::LedgerAddressMismatch(Box::new(LedgerAddressMismatchEvent::deserialize(value).map_err(
+                        |e| serde::de::Error::custom(format!("cannot deserialize LedgerAddressMismatch: {e}")),
+                    )?))
+                }
+                WalletEventType::SyncProgress => Self::SyncProgress(
+                    SyncProgressEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize SyncProgress: {e}")))?,
+                ),
+                WalletEventType::AutoConsolidation => {
+                    Self::AutoConsolidation(Box::new(AutoConsolidationEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize AutoConsolidation: {e}"))
+                    })?))
+                }
             },
         )
     }
@@ -160,6 +243,15 @@ pub enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    LockedAddressChanged = 6,
+    AddressRotated = 7,
+    TransactionRebuilt = 8,
+    AccountRenamed = 9,
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    LedgerAddressMismatch = 10,
+    SyncProgress = 11,
+    AutoConsolidation = 12,
 }
 
 impl TryFrom<u8> for WalletEventType {
@@ -174,6 +266,14 @@ impl TryFrom<u8> for WalletEventType {
             3 => Self::SpentOutput,
             4 => Self::TransactionInclusion,
             5 => Self::TransactionProgress,
+            6 => Self::LockedAddressChanged,
+            7 => Self::AddressRotated,
+            8 => Self::TransactionRebuilt,
+            9 => Self::AccountRenamed,
+            #[cfg(feature = "ledger_nano")]
+            10 => Self::LedgerAddressMismatch,
+            11 => Self::SyncProgress,
+            12 => Self::AutoConsolidation,
             _ => return Err(format!("invalid event type {value}")),
         };
         Ok(event_type)
@@ -199,11 +299,110 @@ pub struct SpentOutputEvent {
     pub output: OutputDataDto,
 }
 
+/// The address that can unlock an output changed, because its expiration expired with a new milestone.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedAddressChangedEvent {
+    /// The output ID.
+    pub output_id: OutputId,
+    /// The address that could unlock the output before, its nominal recipient.
+    pub previous_address: Bech32Address,
+    /// The address that can unlock the output now, the return address of the expiration.
+    pub address: Bech32Address,
+}
+
+/// The current receive address of an account was rotated.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressRotatedEvent {
+    /// The previous receive address, if there was one.
+    pub previous_address: Option<Bech32Address>,
+    /// The new receive address.
+    pub address: Bech32Address,
+}
+
+/// A conflicting transaction was rebuilt with other inputs and sent again.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRebuiltEvent {
+    /// The ID of the conflicting transaction.
+    pub previous_transaction_id: TransactionId,
+    /// The ID of the transaction that replaces it.
+    pub transaction_id: TransactionId,
+}
+
+/// The alias of an account was changed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRenamedEvent {
+    /// The previous alias of the account.
+    pub previous_alias: String,
+    /// The new alias of the account.
+    pub alias: String,
+}
+
+/// The address displayed by a Ledger Nano device doesn't match the address of the account.
+#[cfg(feature = "ledger_nano")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerAddressMismatchEvent {
+    /// The key index of the address.
+    pub key_index: u32,
+    /// The address of the account.
+    pub expected: Bech32Address,
+    /// The address displayed by the device.
+    pub displayed: Bech32Address,
+}
+
+/// Basic outputs were consolidated automatically because of the consolidation policy of the account, or the attempt
+/// failed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoConsolidationEvent {
+    /// The number of outputs that were consolidated, or failed to be consolidated.
+    pub consolidated_outputs: usize,
+    /// The ID of the consolidation transaction, if it was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<TransactionId>,
+    /// Why the consolidation failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The progress of an account sync, emitted when a stage starts, while it advances and when it's done.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncProgressEvent {
+    /// The stage of the sync.
+    pub stage: SyncStage,
+    /// The number of items of the stage that were processed so far.
+    pub processed: usize,
+    /// The number of items of the stage, the stage is done when all of them were processed.
+    pub total: usize,
+}
+
+/// The stages of an account sync, in the order they're run. Output fetching and transaction resolution can be
+/// repeated, when new addresses or transactions are found.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SyncStage {
+    /// Requesting the output IDs of the addresses, the items are addresses.
+    AddressDiscovery,
+    /// Requesting the outputs and their metadata, the items are outputs.
+    OutputFetching,
+    /// Requesting the transactions that created the new outputs, the items are transactions.
+    TransactionResolution,
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionInclusionEvent {
     pub transaction_id: TransactionId,
     pub inclusion_state: InclusionState,
+    /// The block that included the transaction, which can be any of its attachments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<BlockId>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
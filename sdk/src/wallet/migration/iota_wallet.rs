@@ -0,0 +1,238 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+
+use crypto::ciphers::chacha;
+use serde_json::Value;
+use zeroize::Zeroizing;
+
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{
+        migration::MIGRATION_VERSION_KEY,
+        storage::{
+            adapter::rocksdb::RocksdbStorageAdapter,
+            constants::{ACCOUNTS_INDEXATION_KEY, ACCOUNT_INDEXATION_KEY},
+            StorageManager,
+        },
+        Error, Result,
+    },
+};
+
+/// Fields of an account that didn't exist in every `iota-wallet` release, with the value they get if missing.
+const ACCOUNT_DEFAULT_FIELDS: [(&str, fn() -> Value); 8] = [
+    ("addressesWithUnspentOutputs", || Value::Array(Vec::new())),
+    ("outputs", || Value::Object(Default::default())),
+    ("lockedOutputs", || Value::Array(Vec::new())),
+    ("unspentOutputs", || Value::Object(Default::default())),
+    ("transactions", || Value::Object(Default::default())),
+    ("pendingTransactions", || Value::Array(Vec::new())),
+    ("incomingTransactions", || Value::Object(Default::default())),
+    ("nativeTokenFoundries", || Value::Object(Default::default())),
+];
+
+/// Records of an `iota-wallet` storage that were left out by [`migrate_db_iota_wallet_to_sdk()`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IotaWalletMigrationReport {
+    /// Pending transactions without their transaction data, which can't be tracked anymore, by account index.
+    pub dropped_pending_transactions: Vec<(u32, String)>,
+}
+
+/// Imports the storage of the pre-unification `iota-wallet` crate into a new iota-sdk wallet storage.
+///
+/// All records are copied, re-encrypted with `new_db_encryption_key`, and accounts get the fields that didn't exist in
+/// every `iota-wallet` release. The regular storage migrations then convert the data, as if the old storage was opened
+/// by the wallet, so the wallet can be loaded from `new_storage_path` without restoring it from the mnemonic. Records
+/// that can't be imported are returned in the report. The old storage is left untouched.
+pub async fn migrate_db_iota_wallet_to_sdk(
+    old_storage_path: impl AsRef<Path> + Send,
+    old_db_encryption_key: impl Into<Option<Zeroizing<[u8; 32]>>> + Send,
+    new_storage_path: impl AsRef<Path> + Send,
+    new_db_encryption_key: impl Into<Option<Zeroizing<[u8; 32]>>> + Send,
+) -> Result<IotaWalletMigrationReport> {
+    let old_storage_path = old_storage_path.as_ref();
+    let new_storage_path = new_storage_path.as_ref();
+
+    if !old_storage_path.is_dir() {
+        return Err(Error::Migration("no iota-wallet data to migrate".to_string()));
+    }
+    if new_storage_path.exists() {
+        return Err(Error::Migration(format!(
+            "new storage path {} already exists",
+            new_storage_path.display()
+        )));
+    }
+
+    let old_db = RawStorage {
+        adapter: RocksdbStorageAdapter::new(old_storage_path)?,
+        encryption_key: old_db_encryption_key.into(),
+    };
+
+    if old_db.get(MIGRATION_VERSION_KEY).await?.is_some() {
+        return Err(Error::Migration(
+            "storage was already created by iota-sdk, no import needed".to_string(),
+        ));
+    }
+    let account_indexes = old_db
+        .get(ACCOUNTS_INDEXATION_KEY)
+        .await?
+        .ok_or_else(|| Error::Migration("no iota-wallet data to migrate".to_string()))?;
+    let account_indexes = serde_json::from_value::<Vec<u32>>(account_indexes)?;
+
+    let new_db = RawStorage {
+        adapter: RocksdbStorageAdapter::new(new_storage_path)?,
+        encryption_key: new_db_encryption_key.into(),
+    };
+
+    // The RocksDB adapter always lists its records
+    for key in old_db.adapter.keys().await?.into_iter().flatten() {
+        if let Some(record) = old_db.get_bytes(&key).await? {
+            new_db.set_bytes(&key, &record).await?;
+        }
+    }
+
+    let mut report = IotaWalletMigrationReport::default();
+    for account_index in account_indexes {
+        let account_key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}");
+        let mut account = new_db
+            .get(&account_key)
+            .await?
+            .ok_or_else(|| Error::Migration(format!("missing data for account {account_index}")))?;
+        for transaction_id in migrate_account(&mut account)? {
+            log::warn!("Skip migrating pending transaction without data: {transaction_id}");
+            report
+                .dropped_pending_transactions
+                .push((account_index, transaction_id));
+        }
+        new_db.set(&account_key, &account).await?;
+    }
+
+    let RawStorage {
+        adapter,
+        encryption_key,
+    } = new_db;
+    // Opening the storage applies all storage migrations to the imported data.
+    StorageManager::new(adapter, encryption_key).await?;
+
+    Ok(report)
+}
+
+/// Access to the raw records of a wallet database, without running storage migrations.
+struct RawStorage {
+    adapter: RocksdbStorageAdapter,
+    encryption_key: Option<Zeroizing<[u8; 32]>>,
+}
+
+impl RawStorage {
+    async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match self.adapter.get_bytes(key).await? {
+            Some(record) => match &self.encryption_key {
+                Some(encryption_key) => Ok(Some(chacha::aead_decrypt(encryption_key.as_ref(), &record)?)),
+                None => Ok(Some(record)),
+            },
+            None => Ok(None),
+        }
+    }
+
+    async fn set_bytes(&self, key: &str, record: &[u8]) -> Result<()> {
+        match &self.encryption_key {
+            Some(encryption_key) => {
+                self.adapter
+                    .set_bytes(key, &chacha::aead_encrypt(encryption_key.as_ref(), record)?)
+                    .await
+            }
+            None => self.adapter.set_bytes(key, record).await,
+        }
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Value>> {
+        match self.get_bytes(key).await? {
+            Some(record) => Ok(Some(serde_json::from_slice(&record)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, key: &str, value: &Value) -> Result<()> {
+        self.set_bytes(key, &serde_json::to_vec(value)?).await
+    }
+}
+
+// Fills the fields the storage migrations expect, returns the dropped pending transactions
+fn migrate_account(account: &mut Value) -> Result<Vec<String>> {
+    let account = account
+        .as_object_mut()
+        .ok_or_else(|| Error::Migration("malformatted account".to_string()))?;
+
+    for (field, default) in ACCOUNT_DEFAULT_FIELDS {
+        if account.get(field).map_or(true, Value::is_null) {
+            account.insert(field.to_string(), default());
+        }
+    }
+
+    // Pending transactions without their transaction data can't be tracked anymore.
+    let transactions = account["transactions"]
+        .as_object()
+        .ok_or_else(|| Error::Migration("malformatted transactions".to_string()))?
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut dropped = Vec::new();
+    account["pendingTransactions"]
+        .as_array_mut()
+        .ok_or_else(|| Error::Migration("malformatted pending transactions".to_string()))?
+        .retain(|transaction_id| {
+            let tracked = transaction_id
+                .as_str()
+                .map_or(false, |id| transactions.iter().any(|t| t == id));
+            if !tracked {
+                dropped.push(
+                    transaction_id
+                        .as_str()
+                        .map_or_else(|| transaction_id.to_string(), ToString::to_string),
+                );
+            }
+            tracked
+        });
+
+    Ok(dropped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrate_account_fills_missing_fields() {
+        let mut account = serde_json::json!({
+            "index": 0,
+            "coinType": 4218,
+            "alias": "Alice",
+            "publicAddresses": [],
+            "internalAddresses": [],
+            "transactions": {
+                "0x97e2d7e3cb6a5e8c13a0dcb44b2b0e6a2e9c2e9f43b3bf3a0b94d0bffb0a1d1e": {}
+            },
+            "pendingTransactions": [
+                "0x97e2d7e3cb6a5e8c13a0dcb44b2b0e6a2e9c2e9f43b3bf3a0b94d0bffb0a1d1e",
+                "0x0000000000000000000000000000000000000000000000000000000000000000"
+            ],
+            "incomingTransactions": null
+        });
+
+        assert_eq!(
+            migrate_account(&mut account).unwrap(),
+            ["0x0000000000000000000000000000000000000000000000000000000000000000"]
+        );
+
+        for (field, default) in ACCOUNT_DEFAULT_FIELDS {
+            if field != "transactions" && field != "pendingTransactions" {
+                assert_eq!(account[field], default());
+            }
+        }
+        assert_eq!(
+            account["pendingTransactions"],
+            serde_json::json!(["0x97e2d7e3cb6a5e8c13a0dcb44b2b0e6a2e9c2e9f43b3bf3a0b94d0bffb0a1d1e"])
+        );
+    }
+}
@@ -3,6 +3,8 @@
 
 #[cfg(not(target_family = "wasm"))]
 pub(crate) mod chrysalis;
+#[cfg(feature = "rocksdb")]
+mod iota_wallet;
 mod migrate_0;
 mod migrate_1;
 mod migrate_2;
@@ -15,6 +17,8 @@ use anymap::Map;
 use async_trait::async_trait;
 #[cfg(not(target_family = "wasm"))]
 pub use chrysalis::migrate_db_chrysalis_to_stardust;
+#[cfg(feature = "rocksdb")]
+pub use iota_wallet::{migrate_db_iota_wallet_to_sdk, IotaWalletMigrationReport};
 use once_cell::sync::Lazy;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
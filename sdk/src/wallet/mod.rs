@@ -27,17 +27,20 @@ pub mod storage;
 /// The module for spawning tasks on a thread
 pub(crate) mod task;
 
+#[cfg(feature = "storage")]
+#[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+pub use self::core::Contact;
 pub use self::{
     account::{
         operations::transaction::high_level::{
             minting::{create_native_token::CreateNativeTokenParams, mint_nfts::MintNftParams},
-            send::SendParams,
+            send::{SendEstimate, SendParams},
             send_native_tokens::SendNativeTokensParams,
             send_nft::SendNftParams,
         },
         Account,
     },
-    core::{Wallet, WalletBuilder},
+    core::{RecoverAccountsOptions, RecoveredAccount, RecoveryReport, RecoveryReportOptions, Wallet, WalletBuilder},
     error::Error,
 };
 
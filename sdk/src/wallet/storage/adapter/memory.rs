@@ -6,14 +6,50 @@ use std::collections::HashMap;
 
 use tokio::sync::RwLock;
 
-use crate::client::storage::StorageAdapter;
+use crate::{client::storage::StorageAdapter, wallet::Error};
 
 /// A storage adapter that stores data in memory.
-#[derive(Debug, Default)]
-pub struct Memory(Arc<RwLock<HashMap<String, Vec<u8>>>>);
+///
+/// Clones share the same records, so the records of a wallet built with
+/// [`StorageOptions::with_memory_storage()`](crate::wallet::storage::StorageOptions::with_memory_storage) can be
+/// exported at any time with [`MemoryStorageAdapter::to_bytes()`] to persist them on demand.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorageAdapter(Arc<RwLock<HashMap<String, Vec<u8>>>>);
+
+/// The previous name of [`MemoryStorageAdapter`].
+#[deprecated(since = "1.0.4", note = "use `MemoryStorageAdapter` instead")]
+pub type Memory = MemoryStorageAdapter;
+
+impl MemoryStorageAdapter {
+    /// Serializes all records, which are encrypted if the wallet uses an encryption key.
+    pub async fn to_bytes(&self) -> crate::wallet::Result<Vec<u8>> {
+        let records = self
+            .0
+            .read()
+            .await
+            .iter()
+            .map(|(key, record)| (key.clone(), prefix_hex::encode(record.as_slice())))
+            .collect::<HashMap<_, _>>();
+
+        Ok(serde_json::to_vec(&records)?)
+    }
+
+    /// Creates a storage adapter with the records serialized with [`MemoryStorageAdapter::to_bytes()`].
+    pub fn from_bytes(bytes: &[u8]) -> crate::wallet::Result<Self> {
+        let records = serde_json::from_slice::<HashMap<String, String>>(bytes)?
+            .into_iter()
+            .map(|(key, record)| {
+                let record: Vec<u8> = prefix_hex::decode(record).map_err(|e| Error::Storage(e.to_string()))?;
+                Ok((key, record))
+            })
+            .collect::<crate::wallet::Result<_>>()?;
+
+        Ok(Self(Arc::new(RwLock::new(records))))
+    }
+}
 
 #[async_trait::async_trait]
-impl StorageAdapter for Memory {
+impl StorageAdapter for MemoryStorageAdapter {
     type Error = crate::wallet::Error;
 
     async fn get_bytes(&self, key: &str) -> crate::wallet::Result<Option<Vec<u8>>> {
@@ -29,4 +65,28 @@ impl StorageAdapter for Memory {
         self.0.write().await.remove(key);
         Ok(())
     }
+
+    async fn keys(&self) -> crate::wallet::Result<Option<Vec<String>>> {
+        Ok(Some(self.0.read().await.keys().cloned().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn to_from_bytes() {
+        let storage = MemoryStorageAdapter::default();
+        storage.set_bytes("key", b"record").await.unwrap();
+        storage.set_bytes("other key", &[0, 255]).await.unwrap();
+
+        let restored = MemoryStorageAdapter::from_bytes(&storage.to_bytes().await.unwrap()).unwrap();
+        assert_eq!(restored.get_bytes("key").await.unwrap(), Some(b"record".to_vec()));
+        assert_eq!(restored.get_bytes("other key").await.unwrap(), Some(vec![0, 255]));
+
+        // The restored records are independent of the exported ones
+        restored.delete("key").await.unwrap();
+        assert_eq!(storage.get_bytes("key").await.unwrap(), Some(b"record".to_vec()));
+    }
 }
@@ -6,6 +6,13 @@ pub mod memory;
 #[cfg(feature = "rocksdb")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rocksdb")))]
 pub mod rocksdb;
+/// SQLite storage adapter.
+#[cfg(feature = "storage-sqlite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "storage-sqlite")))]
+pub mod sqlite;
+/// Browser local storage adapter.
+#[cfg(target_family = "wasm")]
+pub mod wasm;
 
 use async_trait::async_trait;
 
@@ -19,6 +26,9 @@ pub(crate) trait DynStorageAdapter: std::fmt::Debug + Send + Sync {
 
     /// Removes a record from the storage.
     async fn dyn_delete(&self, key: &str) -> crate::wallet::Result<()>;
+
+    /// Returns the keys of all records, `None` if the storage can't list them.
+    async fn dyn_keys(&self) -> crate::wallet::Result<Option<Vec<String>>>;
 }
 
 #[async_trait]
@@ -37,6 +47,10 @@ where
     async fn dyn_delete(&self, key: &str) -> crate::wallet::Result<()> {
         Ok(self.delete(key).await?)
     }
+
+    async fn dyn_keys(&self) -> crate::wallet::Result<Option<Vec<String>>> {
+        Ok(self.keys().await?)
+    }
 }
 
 #[async_trait]
@@ -54,4 +68,8 @@ impl StorageAdapter for dyn DynStorageAdapter {
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
         self.dyn_delete(key).await
     }
+
+    async fn keys(&self) -> Result<Option<Vec<String>>, Self::Error> {
+        self.dyn_keys().await
+    }
 }
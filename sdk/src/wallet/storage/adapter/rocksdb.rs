@@ -3,7 +3,7 @@
 
 use std::{path::Path, sync::Arc};
 
-use rocksdb::{DBCompressionType, Options, DB};
+use rocksdb::{DBCompressionType, IteratorMode, Options, DB};
 use tokio::sync::Mutex;
 
 use crate::client::storage::StorageAdapter;
@@ -45,4 +45,17 @@ impl StorageAdapter for RocksdbStorageAdapter {
         self.db.lock().await.delete(key)?;
         Ok(())
     }
+
+    async fn keys(&self) -> crate::wallet::Result<Option<Vec<String>>> {
+        self.db
+            .lock()
+            .await
+            .iterator(IteratorMode::Start)
+            .map(|item| {
+                let (key, _) = item?;
+                String::from_utf8(key.into_vec()).map_err(|_| crate::wallet::Error::Storage("invalid key".to_string()))
+            })
+            .collect::<crate::wallet::Result<_>>()
+            .map(Some)
+    }
 }
@@ -0,0 +1,112 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{path::Path, sync::Arc};
+
+use rusqlite::{params, Connection, OptionalExtension};
+use tokio::sync::Mutex;
+
+use crate::client::storage::StorageAdapter;
+
+/// Key value storage adapter, storing the records in a table of a single SQLite database file.
+#[derive(Clone, Debug)]
+pub struct SqliteStorageAdapter {
+    pub(crate) connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteStorageAdapter {
+    /// Initialises the storage adapter, creating the database file if it doesn't exist.
+    pub fn new(path: impl AsRef<Path>) -> crate::wallet::Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let connection = Connection::open(path)?;
+        connection.execute(
+            "CREATE TABLE IF NOT EXISTS records (key TEXT PRIMARY KEY NOT NULL, value BLOB NOT NULL)",
+            [],
+        )?;
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageAdapter for SqliteStorageAdapter {
+    type Error = crate::wallet::Error;
+
+    async fn get_bytes(&self, key: &str) -> crate::wallet::Result<Option<Vec<u8>>> {
+        Ok(self
+            .connection
+            .lock()
+            .await
+            .query_row("SELECT value FROM records WHERE key = ?1", params![key], |row| {
+                row.get(0)
+            })
+            .optional()?)
+    }
+
+    async fn set_bytes(&self, key: &str, record: &[u8]) -> crate::wallet::Result<()> {
+        self.connection.lock().await.execute(
+            "INSERT INTO records (key, value) VALUES (?1, ?2) ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            params![key, record],
+        )?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> crate::wallet::Result<()> {
+        self.connection
+            .lock()
+            .await
+            .execute("DELETE FROM records WHERE key = ?1", params![key])?;
+        Ok(())
+    }
+
+    async fn keys(&self) -> crate::wallet::Result<Option<Vec<String>>> {
+        let connection = self.connection.lock().await;
+        let mut statement = connection.prepare("SELECT key FROM records")?;
+        let keys = statement.query_map([], |row| row.get(0))?.collect::<Result<_, _>>()?;
+
+        Ok(Some(keys))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn get_set_delete() {
+        let path = std::env::temp_dir().join("iota-sdk-sqlite-storage-adapter.db");
+        std::fs::remove_file(&path).ok();
+
+        let storage = SqliteStorageAdapter::new(&path).unwrap();
+        assert_eq!(storage.get_bytes("key").await.unwrap(), None);
+
+        storage.set_bytes("key", b"record").await.unwrap();
+        storage.set_bytes("key", b"updated record").await.unwrap();
+        assert_eq!(
+            storage.get_bytes("key").await.unwrap(),
+            Some(b"updated record".to_vec())
+        );
+
+        // The records are persisted in the file
+        drop(storage);
+        let storage = SqliteStorageAdapter::new(&path).unwrap();
+        assert_eq!(
+            storage.get_bytes("key").await.unwrap(),
+            Some(b"updated record".to_vec())
+        );
+
+        storage.set_bytes("other key", b"record").await.unwrap();
+        let mut keys = storage.keys().await.unwrap().unwrap();
+        keys.sort();
+        assert_eq!(keys, ["key", "other key"]);
+
+        storage.delete("key").await.unwrap();
+        assert_eq!(storage.get_bytes("key").await.unwrap(), None);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -1,44 +1,81 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use gloo_storage::LocalStorage;
+use std::path::Path;
 
-use super::StorageAdapter;
+use crate::{client::storage::StorageAdapter, wallet::Error};
 
-/// The storage id.
-pub const STORAGE_ID: &str = "Wasm";
-
-/// Wasm storage adapter using the browser local storage
-#[derive(Debug)]
-pub struct WasmAdapter(LocalStorage);
+/// A storage adapter that stores data in the local storage of the browser, so that it persists across page loads.
+/// Keys are prefixed with the storage path, so that several wallets can be stored for the same origin.
+#[derive(Clone, Debug)]
+pub struct WasmAdapter {
+    prefix: String,
+}
 
 impl WasmAdapter {
-    /// Initialises the storage adapter.
-    pub fn new() -> crate::wallet::Result<Self> {
-        Ok(Self(LocalStorage::new()))
+    /// Creates a new [`WasmAdapter`] for the given storage path, returns an error if there is no local storage, like
+    /// in Node.js.
+    pub fn new(path: impl AsRef<Path>) -> crate::wallet::Result<Self> {
+        local_storage()?;
+
+        Ok(Self {
+            prefix: path.as_ref().to_string_lossy().into_owned(),
+        })
     }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}/{key}", self.prefix)
+    }
+}
+
+// The storage handle can't be sent between threads, so it's requested for every access
+fn local_storage() -> crate::wallet::Result<web_sys::Storage> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .ok_or_else(|| Error::Storage("the local storage of the browser is not available".to_string()))
+}
+
+fn storage_error(error: wasm_bindgen::JsValue) -> Error {
+    Error::Storage(format!("{error:?}"))
 }
 
 #[async_trait::async_trait]
 impl StorageAdapter for WasmAdapter {
-    /// Gets the record associated with the given key from the storage.
-    async fn get(&self, key: &str) -> crate::wallet::Result<String> {
-        self.0.get(key)
+    type Error = Error;
+
+    async fn get_bytes(&self, key: &str) -> crate::wallet::Result<Option<Vec<u8>>> {
+        // The local storage only holds strings, so records are stored hex encoded
+        local_storage()?
+            .get_item(&self.key(key))
+            .map_err(storage_error)?
+            .map(|record| prefix_hex::decode::<Vec<u8>>(record).map_err(|error| Error::Storage(error.to_string())))
+            .transpose()
     }
 
-    /// Saves or updates a record on the storage.
-    async fn set(&mut self, key: &str, record: String) -> crate::wallet::Result<()> {
-        self.0.set(key, record)
+    async fn set_bytes(&self, key: &str, record: &[u8]) -> crate::wallet::Result<()> {
+        local_storage()?
+            .set_item(&self.key(key), &prefix_hex::encode(record))
+            .map_err(storage_error)
     }
 
-    /// Batch writes records to the storage.
-    async fn batch_set(&mut self, records: HashMap<String, String>) -> crate::wallet::Result<()> {
-        records.into_iter().map(|s| self.set(s.0, s.1));
-        Ok(())
+    async fn delete(&self, key: &str) -> crate::wallet::Result<()> {
+        local_storage()?.remove_item(&self.key(key)).map_err(storage_error)
     }
 
-    /// Removes a record from the storage.
-    async fn remove(&mut self, key: &str) -> crate::wallet::Result<()> {
-        self.0.delete(key)
+    async fn keys(&self) -> crate::wallet::Result<Option<Vec<String>>> {
+        let local_storage = local_storage()?;
+        let prefix = self.key("");
+        let mut keys = Vec::new();
+
+        // The local storage is shared with other wallets and the page, so only keys with the prefix are records
+        for index in 0..local_storage.length().map_err(storage_error)? {
+            if let Some(key) = local_storage.key(index).map_err(storage_error)? {
+                if let Some(key) = key.strip_prefix(&prefix) {
+                    keys.push(key.to_owned());
+                }
+            }
+        }
+
+        Ok(Some(keys))
     }
 }
@@ -0,0 +1,239 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use super::manager::StorageManager;
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{
+        account::{constants::MAX_BALANCE_SNAPSHOTS, operations::balance_history::BalanceSnapshot},
+        storage::constants::BALANCE_HISTORY,
+    },
+};
+
+/// The amount of balance snapshots stored per record, so appending a snapshot doesn't rewrite the whole history.
+const BALANCE_SNAPSHOTS_PER_CHUNK: usize = 100;
+
+/// The chunks the balance history of an account is stored in, the snapshots of a chunk are stored under
+/// [`balance_history_chunk_key()`].
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceHistoryChunks {
+    first_chunk: u32,
+    chunk_count: u32,
+}
+
+impl BalanceHistoryChunks {
+    fn indexes(&self) -> core::ops::Range<u32> {
+        self.first_chunk..self.first_chunk + self.chunk_count
+    }
+}
+
+fn balance_history_key(account_index: u32) -> String {
+    format!("{BALANCE_HISTORY}{account_index}")
+}
+
+fn balance_history_chunk_key(account_index: u32, chunk_index: u32) -> String {
+    format!("{BALANCE_HISTORY}{account_index}-{chunk_index}")
+}
+
+impl StorageManager {
+    /// Appends the snapshot to the balance history of the account, unless the unspent outputs didn't change since the
+    /// last snapshot. Only the last chunk is written, and the oldest chunks are removed once more than
+    /// [`MAX_BALANCE_SNAPSHOTS`] are stored.
+    pub(crate) async fn append_balance_snapshot(
+        &self,
+        account_index: u32,
+        snapshot: BalanceSnapshot,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("append_balance_snapshot");
+
+        let mut chunks = self
+            .storage
+            .get::<BalanceHistoryChunks>(&balance_history_key(account_index))
+            .await?
+            .unwrap_or_default();
+
+        let mut last_chunk = match chunks.indexes().last() {
+            Some(chunk_index) => self
+                .storage
+                .get::<Vec<BalanceSnapshot>>(&balance_history_chunk_key(account_index, chunk_index))
+                .await?
+                .unwrap_or_default(),
+            None => Vec::new(),
+        };
+        if last_chunk
+            .last()
+            .map_or(false, |last| last.output_ids == snapshot.output_ids)
+        {
+            return Ok(());
+        }
+
+        if chunks.chunk_count == 0 || last_chunk.len() >= BALANCE_SNAPSHOTS_PER_CHUNK {
+            chunks.chunk_count += 1;
+            last_chunk.clear();
+        }
+        last_chunk.push(snapshot);
+        // Safe to unwrap, there is at least one chunk
+        let last_chunk_index = chunks.indexes().last().unwrap();
+        self.storage
+            .set(&balance_history_chunk_key(account_index, last_chunk_index), &last_chunk)
+            .await?;
+
+        // Remove the oldest chunk while the other chunks still hold the maximum amount of snapshots
+        while chunks.chunk_count > 1
+            && (chunks.chunk_count as usize - 2) * BALANCE_SNAPSHOTS_PER_CHUNK + last_chunk.len()
+                >= MAX_BALANCE_SNAPSHOTS
+        {
+            self.storage
+                .delete(&balance_history_chunk_key(account_index, chunks.first_chunk))
+                .await?;
+            chunks.first_chunk += 1;
+            chunks.chunk_count -= 1;
+        }
+
+        self.storage.set(&balance_history_key(account_index), &chunks).await
+    }
+
+    /// Returns the last [`MAX_BALANCE_SNAPSHOTS`] balance snapshots of the account, oldest first.
+    pub(crate) async fn get_balance_snapshots(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Vec<BalanceSnapshot>> {
+        log::debug!("get_balance_snapshots");
+
+        let mut snapshots = Vec::new();
+        for chunk_index in self.balance_history_chunk_indexes(account_index).await? {
+            if let Some(chunk) = self
+                .storage
+                .get::<Vec<BalanceSnapshot>>(&balance_history_chunk_key(account_index, chunk_index))
+                .await?
+            {
+                snapshots.extend(chunk);
+            }
+        }
+        if snapshots.len() > MAX_BALANCE_SNAPSHOTS {
+            snapshots.drain(..snapshots.len() - MAX_BALANCE_SNAPSHOTS);
+        }
+
+        Ok(snapshots)
+    }
+
+    /// Returns the keys of all records of the balance history of the account.
+    pub(crate) async fn balance_history_keys(&self, account_index: u32) -> crate::wallet::Result<Vec<String>> {
+        let mut keys = vec![balance_history_key(account_index)];
+        keys.extend(
+            self.balance_history_chunk_indexes(account_index)
+                .await?
+                .map(|chunk_index| balance_history_chunk_key(account_index, chunk_index)),
+        );
+
+        Ok(keys)
+    }
+
+    /// Deletes the balance history of the account.
+    pub(crate) async fn delete_balance_history(&self, account_index: u32) -> crate::wallet::Result<()> {
+        for key in self.balance_history_keys(account_index).await? {
+            self.storage.delete(&key).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn balance_history_chunk_indexes(&self, account_index: u32) -> crate::wallet::Result<core::ops::Range<u32>> {
+        Ok(self
+            .storage
+            .get::<BalanceHistoryChunks>(&balance_history_key(account_index))
+            .await?
+            .unwrap_or_default()
+            .indexes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{output::OutputId, payload::transaction::TransactionId},
+        wallet::{account::types::Balance, storage::adapter::memory::MemoryStorageAdapter},
+    };
+
+    fn snapshot(timestamp: u128, output_index: u16) -> BalanceSnapshot {
+        BalanceSnapshot {
+            timestamp,
+            balance: Balance::default(),
+            output_ids: vec![OutputId::new(TransactionId::new([2; 32]), output_index).unwrap()],
+        }
+    }
+
+    #[tokio::test]
+    async fn append_get_balance_snapshots() {
+        let storage_manager = StorageManager::new(MemoryStorageAdapter::default(), None)
+            .await
+            .unwrap();
+        assert!(storage_manager.get_balance_snapshots(0).await.unwrap().is_empty());
+
+        storage_manager
+            .append_balance_snapshot(0, snapshot(1, 0))
+            .await
+            .unwrap();
+        // Same outputs, not recorded again
+        storage_manager
+            .append_balance_snapshot(0, snapshot(2, 0))
+            .await
+            .unwrap();
+        storage_manager
+            .append_balance_snapshot(0, snapshot(3, 1))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage_manager.get_balance_snapshots(0).await.unwrap(),
+            vec![snapshot(1, 0), snapshot(3, 1)]
+        );
+        assert!(storage_manager.get_balance_snapshots(1).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn balance_snapshot_chunks() {
+        let storage_manager = StorageManager::new(MemoryStorageAdapter::default(), None)
+            .await
+            .unwrap();
+
+        let snapshot_count = MAX_BALANCE_SNAPSHOTS + BALANCE_SNAPSHOTS_PER_CHUNK + 1;
+        for index in 0..snapshot_count {
+            storage_manager
+                .append_balance_snapshot(0, snapshot(index as u128, index as u16))
+                .await
+                .unwrap();
+        }
+
+        // Only the last snapshots are returned, and the oldest chunk was deleted
+        let snapshots = storage_manager.get_balance_snapshots(0).await.unwrap();
+        assert_eq!(snapshots.len(), MAX_BALANCE_SNAPSHOTS);
+        assert_eq!(
+            snapshots.last(),
+            Some(&snapshot(snapshot_count as u128 - 1, snapshot_count as u16 - 1))
+        );
+        let first_chunk = storage_manager
+            .storage
+            .get::<Vec<BalanceSnapshot>>(&balance_history_chunk_key(0, 0))
+            .await
+            .unwrap();
+        assert!(first_chunk.is_none());
+        let second_chunk = storage_manager
+            .storage
+            .get::<Vec<BalanceSnapshot>>(&balance_history_chunk_key(0, 1))
+            .await
+            .unwrap();
+        assert_eq!(second_chunk.unwrap().len(), BALANCE_SNAPSHOTS_PER_CHUNK);
+
+        let keys = storage_manager.balance_history_keys(0).await.unwrap();
+        storage_manager.delete_balance_history(0).await.unwrap();
+        for key in keys {
+            assert!(storage_manager.storage.get_bytes(&key).await.unwrap().is_none());
+        }
+        assert!(storage_manager.get_balance_snapshots(0).await.unwrap().is_empty());
+    }
+}
@@ -24,6 +24,16 @@ pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
 
+pub(crate) const ACCOUNT_CONSOLIDATION_POLICY: &str = "consolidation-policy";
+
+pub(crate) const ACCOUNT_RECEIVE_ADDRESS: &str = "receive-address";
+
+pub(crate) const BALANCE_HISTORY: &str = "balance-history-";
+
+pub(crate) const CONTACTS_KEY: &str = "contacts";
+
+pub(crate) const SUBMITTED_BLOCKS_KEY: &str = "submitted-blocks";
+
 pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
 
@@ -33,3 +43,5 @@ pub(crate) const PARTICIPATION_EVENTS: &str = "participation-events";
 pub(crate) const PARTICIPATION_CACHED_OUTPUTS: &str = "participation-cached-outputs";
 
 pub(crate) const CHRYSALIS_STORAGE_KEY: &str = "chrysalis-data";
+
+pub(crate) const STORAGE_KEY_ROTATION_KEY: &str = "storage-key-rotation";
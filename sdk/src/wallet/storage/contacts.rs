@@ -0,0 +1,51 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::manager::StorageManager;
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{core::operations::contacts::Contact, storage::constants::CONTACTS_KEY},
+};
+
+impl StorageManager {
+    pub(crate) async fn save_contacts(&self, contacts: &[Contact]) -> crate::wallet::Result<()> {
+        log::debug!("save_contacts");
+
+        self.storage.set(CONTACTS_KEY, &contacts).await
+    }
+
+    pub(crate) async fn get_contacts(&self) -> crate::wallet::Result<Vec<Contact>> {
+        log::debug!("get_contacts");
+
+        Ok(self.storage.get(CONTACTS_KEY).await?.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{types::block::address::Bech32Address, wallet::storage::adapter::memory::MemoryStorageAdapter};
+
+    #[tokio::test]
+    async fn save_get_contacts() {
+        let storage_manager = StorageManager::new(MemoryStorageAdapter::default(), None)
+            .await
+            .unwrap();
+        assert!(storage_manager.get_contacts().await.unwrap().is_empty());
+
+        let contacts = vec![
+            Contact::new(
+                "alice",
+                Bech32Address::try_from_str("rms1qqqu7qry22f6v7d2d9aesny9vjtf56unpevkfzfudddlcq5ja9clv44sef6").unwrap(),
+            ),
+            Contact::new(
+                "bob",
+                Bech32Address::try_from_str("rms1qz4tac74vympq4hqqz8g9egrkhscn9743svd9xxh2w99qf5cd8vcxrmspmw").unwrap(),
+            )
+            .with_note("exchange deposit address".to_string()),
+        ];
+        storage_manager.save_contacts(&contacts).await.unwrap();
+
+        assert_eq!(storage_manager.get_contacts().await.unwrap(), contacts);
+    }
+}
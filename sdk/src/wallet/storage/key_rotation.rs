@@ -0,0 +1,243 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use zeroize::Zeroizing;
+
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{
+        storage::{constants::*, StorageManager},
+        Error, Result,
+    },
+};
+
+/// The state of a storage encryption key rotation that didn't finish yet.
+#[derive(Debug)]
+pub(crate) struct KeyRotation {
+    // The key of the records that weren't re-encrypted yet, `None` if they aren't encrypted
+    pub(crate) previous_encryption_key: Option<Zeroizing<[u8; 32]>>,
+    pub(crate) progress: KeyRotationProgress,
+}
+
+impl KeyRotation {
+    pub(crate) fn decrypt(&self, record: Vec<u8>) -> core::result::Result<Vec<u8>, crypto::Error> {
+        match &self.previous_encryption_key {
+            Some(previous_encryption_key) => {
+                crypto::ciphers::chacha::aead_decrypt(previous_encryption_key.as_ref(), &record)
+            }
+            None => Ok(record),
+        }
+    }
+}
+
+/// Stored unencrypted, so an interrupted key rotation can be resumed.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct KeyRotationProgress {
+    previous_encrypted: bool,
+    remaining_keys: Vec<String>,
+}
+
+impl StorageManager {
+    /// Loads the state of an interrupted key rotation, records that weren't re-encrypted yet get decrypted with the
+    /// previous encryption key.
+    pub(crate) async fn load_key_rotation(
+        &mut self,
+        previous_encryption_key: Option<Zeroizing<[u8; 32]>>,
+    ) -> Result<()> {
+        let Some(progress) = self
+            .storage
+            .inner
+            .as_ref()
+            .get::<KeyRotationProgress>(STORAGE_KEY_ROTATION_KEY)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        if progress.previous_encrypted && previous_encryption_key.is_none() {
+            return Err(Error::Storage(
+                "storage key rotation didn't finish, the previous encryption key is required".to_string(),
+            ));
+        }
+        log::debug!(
+            "[STORAGE KEY ROTATION] resuming with {} remaining records",
+            progress.remaining_keys.len()
+        );
+
+        self.storage.key_rotation = Some(KeyRotation {
+            previous_encryption_key: previous_encryption_key.filter(|_| progress.previous_encrypted),
+            progress,
+        });
+
+        Ok(())
+    }
+
+    /// Switches to a new encryption key, existing records are re-encrypted with
+    /// [`StorageManager::rotate_next_record()`].
+    pub(crate) async fn start_key_rotation(&mut self, new_encryption_key: Zeroizing<[u8; 32]>) -> Result<()> {
+        if self.storage.key_rotation.is_some() {
+            return Err(Error::Storage("storage key rotation already in progress".to_string()));
+        }
+
+        // All records are re-encrypted, except the progress of a previous rotation, which is stored unencrypted
+        let remaining_keys = self
+            .storage
+            .inner
+            .as_ref()
+            .keys()
+            .await?
+            .ok_or_else(|| Error::Storage("the storage can't list its records to re-encrypt them".to_string()))?
+            .into_iter()
+            .filter(|key| key != STORAGE_KEY_ROTATION_KEY)
+            .collect();
+
+        let progress = KeyRotationProgress {
+            previous_encrypted: self.storage.encryption_key.is_some(),
+            remaining_keys,
+        };
+        // Stored before any record gets re-encrypted, so the rotation can always be resumed
+        self.storage
+            .inner
+            .as_ref()
+            .set(STORAGE_KEY_ROTATION_KEY, &progress)
+            .await?;
+
+        let previous_encryption_key = self.storage.encryption_key.replace(new_encryption_key);
+        self.storage.key_rotation = Some(KeyRotation {
+            previous_encryption_key,
+            progress,
+        });
+
+        Ok(())
+    }
+
+    /// Re-encrypts the next record with the new encryption key. Returns `true` once all records are re-encrypted.
+    pub(crate) async fn rotate_next_record(&mut self) -> Result<bool> {
+        let Some(key) = self
+            .storage
+            .key_rotation
+            .as_ref()
+            .map(|key_rotation| key_rotation.progress.remaining_keys.last().cloned())
+        else {
+            return Ok(true);
+        };
+
+        let Some(key) = key else {
+            self.storage.inner.as_ref().delete(STORAGE_KEY_ROTATION_KEY).await?;
+            self.storage.key_rotation = None;
+            log::debug!("[STORAGE KEY ROTATION] finished");
+            return Ok(true);
+        };
+
+        if let Some(mut record) = self.storage.get_bytes(&key).await? {
+            if key == WALLET_INDEXATION_KEY {
+                record = remove_stored_encryption_key(record)?;
+            }
+            self.storage.set_bytes(&key, &record).await?;
+        }
+
+        // Safe to unwrap, checked above
+        let key_rotation = self.storage.key_rotation.as_mut().unwrap();
+        key_rotation.progress.remaining_keys.pop();
+        self.storage
+            .inner
+            .as_ref()
+            .set(STORAGE_KEY_ROTATION_KEY, &key_rotation.progress)
+            .await?;
+
+        Ok(false)
+    }
+
+    /// Returns the amount of records that weren't re-encrypted yet, if a key rotation is in progress.
+    pub(crate) fn key_rotation_remaining(&self) -> Option<usize> {
+        self.storage
+            .key_rotation
+            .as_ref()
+            .map(|key_rotation| key_rotation.progress.remaining_keys.len())
+    }
+}
+
+// Older wallet data contains the storage options including the previous encryption key, which must not be kept
+fn remove_stored_encryption_key(record: Vec<u8>) -> Result<Vec<u8>> {
+    let mut wallet_data = serde_json::from_slice::<Value>(&record)?;
+    match wallet_data
+        .get_mut("storageOptions")
+        .and_then(Value::as_object_mut)
+        .and_then(|storage_options| storage_options.remove("encryptionKey"))
+    {
+        Some(_) => Ok(serde_json::to_vec(&wallet_data)?),
+        None => Ok(record),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::{
+        account::{operations::balance_history::BalanceSnapshot, types::Balance},
+        storage::adapter::memory::MemoryStorageAdapter,
+    };
+
+    #[tokio::test]
+    async fn rotate_key() {
+        let memory = MemoryStorageAdapter::default();
+        let old_key = Zeroizing::new([1; 32]);
+        let new_key = Zeroizing::new([2; 32]);
+
+        let mut storage_manager = StorageManager::new(memory.clone(), old_key.clone()).await.unwrap();
+        storage_manager
+            .set(
+                WALLET_INDEXATION_KEY,
+                &serde_json::json!({ "storageOptions": { "encryptionKey": [1; 32] } }),
+            )
+            .await
+            .unwrap();
+        storage_manager.set(ACCOUNTS_INDEXATION_KEY, &[0u32]).await.unwrap();
+        storage_manager.account_indexes = vec![0];
+        let snapshot = BalanceSnapshot {
+            timestamp: 1,
+            balance: Balance::default(),
+            output_ids: Vec::new(),
+        };
+        storage_manager
+            .append_balance_snapshot(0, snapshot.clone())
+            .await
+            .unwrap();
+        // Records without a known key are re-encrypted too
+        storage_manager.set("custom-record", &42).await.unwrap();
+
+        storage_manager.start_key_rotation(new_key.clone()).await.unwrap();
+        assert!(storage_manager.start_key_rotation(new_key.clone()).await.is_err());
+        // Records can be read while they are re-encrypted
+        assert!(!storage_manager.rotate_next_record().await.unwrap());
+        assert_eq!(
+            storage_manager.get::<Vec<u32>>(ACCOUNTS_INDEXATION_KEY).await.unwrap(),
+            Some(vec![0])
+        );
+        let remaining = storage_manager.key_rotation_remaining().unwrap();
+
+        // The rotation is resumed with the previous key
+        assert!(StorageManager::new(memory.clone(), new_key.clone()).await.is_err());
+        let mut storage_manager = StorageManager::open(memory.clone(), new_key.clone(), old_key.clone())
+            .await
+            .unwrap();
+        assert_eq!(storage_manager.key_rotation_remaining(), Some(remaining));
+        while !storage_manager.rotate_next_record().await.unwrap() {}
+        assert_eq!(storage_manager.key_rotation_remaining(), None);
+
+        let storage_manager = StorageManager::new(memory, new_key).await.unwrap();
+        assert_eq!(
+            storage_manager.get::<Vec<u32>>(ACCOUNTS_INDEXATION_KEY).await.unwrap(),
+            Some(vec![0])
+        );
+        assert_eq!(storage_manager.get_balance_snapshots(0).await.unwrap(), vec![snapshot]);
+        assert_eq!(storage_manager.get::<u32>("custom-record").await.unwrap(), Some(42));
+        assert_eq!(
+            storage_manager.get::<Value>(WALLET_INDEXATION_KEY).await.unwrap(),
+            Some(serde_json::json!({ "storageOptions": {} }))
+        );
+    }
+}
@@ -10,20 +10,25 @@ pub enum StorageKind {
     /// RocksDB storage.
     #[cfg(feature = "rocksdb")]
     Rocksdb,
+    /// SQLite storage in a single database file.
+    #[cfg(feature = "storage-sqlite")]
+    Sqlite,
     /// Storage backed by a Map in memory.
     Memory,
-    /// Wasm storage.
+    /// Storage backed by the local storage of the browser.
     #[cfg(target_family = "wasm")]
     Wasm,
 }
 
+/// Defaults to RocksDB, or to memory for WebAssembly, the local storage of the browser has to be chosen with
+/// [`StorageKind::Wasm`].
 impl Default for StorageKind {
     fn default() -> Self {
         #[cfg(feature = "rocksdb")]
         return Self::Rocksdb;
-        #[cfg(target_family = "wasm")]
-        return Self::Wasm;
-        #[cfg(not(any(feature = "rocksdb", target_family = "wasm")))]
+        #[cfg(all(feature = "storage-sqlite", not(any(feature = "rocksdb", target_family = "wasm"))))]
+        return Self::Sqlite;
+        #[cfg(not(any(feature = "rocksdb", all(feature = "storage-sqlite", not(target_family = "wasm")))))]
         Self::Memory
     }
 }
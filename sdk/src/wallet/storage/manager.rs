@@ -8,7 +8,10 @@ use crate::{
     client::storage::StorageAdapter,
     types::TryFromDto,
     wallet::{
-        account::{AccountDetails, AccountDetailsDto, SyncOptions},
+        account::{
+            operations::address_rotation::ReceiveAddressState, AccountDetails, AccountDetailsDto, ConsolidationPolicy,
+            SyncOptions,
+        },
         migration::migrate,
         storage::{constants::*, DynStorageAdapter, Storage},
     },
@@ -19,7 +22,7 @@ use crate::{
 pub(crate) struct StorageManager {
     pub(crate) storage: Storage,
     // account indexes for accounts in the database
-    account_indexes: Vec<u32>,
+    pub(crate) account_indexes: Vec<u32>,
 }
 
 impl StorageManager {
@@ -27,11 +30,29 @@ impl StorageManager {
         storage: impl DynStorageAdapter + 'static,
         encryption_key: impl Into<Option<Zeroizing<[u8; 32]>>> + Send,
     ) -> crate::wallet::Result<Self> {
-        let storage = Storage {
-            inner: Box::new(storage) as _,
-            encryption_key: encryption_key.into(),
+        Self::open(storage, encryption_key, None).await
+    }
+
+    /// Opens the storage, `previous_encryption_key` is needed if a rotation of the encryption key didn't finish.
+    pub(crate) async fn open(
+        storage: impl DynStorageAdapter + 'static,
+        encryption_key: impl Into<Option<Zeroizing<[u8; 32]>>> + Send,
+        previous_encryption_key: impl Into<Option<Zeroizing<[u8; 32]>>> + Send,
+    ) -> crate::wallet::Result<Self> {
+        let mut storage_manager = Self {
+            storage: Storage {
+                inner: Box::new(storage) as _,
+                encryption_key: encryption_key.into(),
+                key_rotation: None,
+            },
+            account_indexes: Vec::new(),
         };
-        migrate(&storage).await?;
+        storage_manager
+            .load_key_rotation(previous_encryption_key.into())
+            .await?;
+
+        let storage = &storage_manager.storage;
+        migrate(storage).await?;
 
         // Get the db version or set it
         if let Some(db_schema_version) = storage.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await? {
@@ -46,12 +67,7 @@ impl StorageManager {
                 .await?;
         };
 
-        let account_indexes = storage.get(ACCOUNTS_INDEXATION_KEY).await?.unwrap_or_default();
-
-        let storage_manager = Self {
-            storage,
-            account_indexes,
-        };
+        storage_manager.account_indexes = storage.get(ACCOUNTS_INDEXATION_KEY).await?.unwrap_or_default();
 
         Ok(storage_manager)
     }
@@ -92,6 +108,7 @@ impl StorageManager {
 
     pub(crate) async fn remove_account(&mut self, account_index: u32) -> crate::wallet::Result<()> {
         self.delete(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}")).await?;
+        self.delete_balance_history(account_index).await?;
         self.account_indexes.retain(|a| a != &account_index);
         self.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await
     }
@@ -112,6 +129,43 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.get(&key).await
     }
+
+    pub(crate) async fn set_consolidation_policy(
+        &self,
+        account_index: u32,
+        policy: Option<&ConsolidationPolicy>,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_CONSOLIDATION_POLICY}");
+        match policy {
+            Some(policy) => self.set(&key, policy).await,
+            None => self.delete(&key).await,
+        }
+    }
+
+    pub(crate) async fn get_consolidation_policy(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<ConsolidationPolicy>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_CONSOLIDATION_POLICY}");
+        self.get(&key).await
+    }
+
+    pub(crate) async fn set_receive_address_state(
+        &self,
+        account_index: u32,
+        state: &ReceiveAddressState,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_RECEIVE_ADDRESS}");
+        self.set(&key, state).await
+    }
+
+    pub(crate) async fn get_receive_address_state(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<ReceiveAddressState>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_RECEIVE_ADDRESS}");
+        self.get(&key).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -129,6 +183,10 @@ impl StorageAdapter for StorageManager {
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
         self.storage.delete(key).await
     }
+
+    async fn keys(&self) -> Result<Option<Vec<String>>, Self::Error> {
+        self.storage.keys().await
+    }
 }
 
 #[cfg(test)]
@@ -138,7 +196,9 @@ mod tests {
     use super::*;
     use crate::{
         client::secret::SecretManager,
-        wallet::{core::operations::storage::SaveLoadWallet, storage::adapter::memory::Memory, WalletBuilder},
+        wallet::{
+            core::operations::storage::SaveLoadWallet, storage::adapter::memory::MemoryStorageAdapter, WalletBuilder,
+        },
     };
 
     #[tokio::test]
@@ -155,7 +215,7 @@ mod tests {
             b: 42,
             c: -420,
         };
-        let storage = Memory::default();
+        let storage = MemoryStorageAdapter::default();
         storage.set("key", &rec).await.unwrap();
 
         let storage_manager = StorageManager::new(storage, None).await.unwrap();
@@ -164,7 +224,9 @@ mod tests {
 
     #[tokio::test]
     async fn save_remove_account() {
-        let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        let mut storage_manager = StorageManager::new(MemoryStorageAdapter::default(), None)
+            .await
+            .unwrap();
         assert!(storage_manager.get_accounts().await.unwrap().is_empty());
 
         let account_details = AccountDetails::mock();
@@ -180,7 +242,9 @@ mod tests {
 
     #[tokio::test]
     async fn save_get_wallet_data() {
-        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        let storage_manager = StorageManager::new(MemoryStorageAdapter::default(), None)
+            .await
+            .unwrap();
         assert!(
             WalletBuilder::<SecretManager>::load(&storage_manager)
                 .await
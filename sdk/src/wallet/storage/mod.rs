@@ -3,8 +3,14 @@
 
 /// Storage adapter.
 pub mod adapter;
+/// Storage functions related to the balance history.
+mod balance_history;
 /// Storage constants.
 pub mod constants;
+/// Storage functions related to contacts.
+mod contacts;
+/// Storage encryption key rotation.
+mod key_rotation;
 /// Storage kind.
 mod kind;
 /// Storage manager.
@@ -15,20 +21,24 @@ mod options;
 #[cfg(feature = "participation")]
 #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
 mod participation;
+/// Storage functions related to the blocks posted for transactions.
+mod submitted_blocks;
 
 use async_trait::async_trait;
 use crypto::ciphers::chacha;
 use zeroize::Zeroizing;
 
-use self::adapter::DynStorageAdapter;
-pub(crate) use self::manager::StorageManager;
+use self::{adapter::DynStorageAdapter, key_rotation::KeyRotation};
 pub use self::{kind::StorageKind, options::StorageOptions};
+pub(crate) use self::{manager::StorageManager, submitted_blocks::WalletSubmittedBlocksStore};
 use crate::client::storage::StorageAdapter;
 
 #[derive(Debug)]
 pub struct Storage {
     pub(crate) inner: Box<dyn DynStorageAdapter>,
     encryption_key: Option<Zeroizing<[u8; 32]>>,
+    // Set while the records are re-encrypted with a new encryption key
+    key_rotation: Option<KeyRotation>,
 }
 
 #[async_trait]
@@ -39,7 +49,12 @@ impl StorageAdapter for Storage {
         match self.inner.as_ref().get_bytes(key).await? {
             Some(record) => {
                 if let Some(encryption_key) = &self.encryption_key {
-                    return Ok(Some(chacha::aead_decrypt(encryption_key.as_ref(), &record)?));
+                    let decrypted = chacha::aead_decrypt(encryption_key.as_ref(), &record);
+                    return Ok(Some(match &self.key_rotation {
+                        // The record might not be re-encrypted with the new key yet
+                        Some(key_rotation) => decrypted.or_else(|_| key_rotation.decrypt(record))?,
+                        None => decrypted?,
+                    }));
                 }
 
                 Ok(Some(record))
@@ -61,6 +76,10 @@ impl StorageAdapter for Storage {
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
         self.inner.as_ref().delete(key).await
     }
+
+    async fn keys(&self) -> Result<Option<Vec<String>>, Self::Error> {
+        self.inner.as_ref().keys().await
+    }
 }
 
 #[cfg(test)]
@@ -68,7 +87,7 @@ mod tests {
     use serde::{Deserialize, Serialize};
 
     use super::*;
-    use crate::wallet::storage::adapter::memory::Memory;
+    use crate::wallet::storage::adapter::memory::MemoryStorageAdapter;
 
     #[tokio::test]
     async fn get_set_remove() {
@@ -80,8 +99,9 @@ mod tests {
         }
 
         let storage = Storage {
-            inner: Box::<Memory>::default(),
+            inner: Box::<MemoryStorageAdapter>::default(),
             encryption_key: None,
+            key_rotation: None,
         };
 
         let rec = Record {
@@ -108,8 +128,9 @@ mod tests {
 
         let encryption_key = crate::types::block::rand::bytes::rand_bytes_array::<32>();
         let storage = Storage {
-            inner: Box::<Memory>::default(),
+            inner: Box::<MemoryStorageAdapter>::default(),
             encryption_key: Some(Zeroizing::new(encryption_key)),
+            key_rotation: None,
         };
 
         let rec = Record {
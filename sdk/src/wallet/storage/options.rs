@@ -6,7 +6,7 @@ use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
-use crate::wallet::storage::{constants::default_storage_path, StorageKind};
+use crate::wallet::storage::{adapter::memory::MemoryStorageAdapter, constants::default_storage_path, StorageKind};
 
 #[cfg(feature = "storage")]
 #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
@@ -14,9 +14,13 @@ use crate::wallet::storage::{constants::default_storage_path, StorageKind};
 #[serde(rename_all = "camelCase")]
 pub struct StorageOptions {
     pub(crate) path: PathBuf,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing)]
     pub(crate) encryption_key: Option<Zeroizing<[u8; 32]>>,
+    #[serde(default, skip_serializing)]
+    pub(crate) previous_encryption_key: Option<Zeroizing<[u8; 32]>>,
     pub(crate) kind: StorageKind,
+    #[serde(skip)]
+    pub(crate) memory_storage: Option<MemoryStorageAdapter>,
 }
 
 #[cfg(feature = "storage")]
@@ -25,7 +29,9 @@ impl Default for StorageOptions {
         Self {
             path: default_storage_path().into(),
             encryption_key: None,
+            previous_encryption_key: None,
             kind: StorageKind::default(),
+            memory_storage: None,
         }
     }
 }
@@ -36,7 +42,9 @@ impl StorageOptions {
         Self {
             path,
             encryption_key: None,
+            previous_encryption_key: None,
             kind,
+            memory_storage: None,
         }
     }
 
@@ -46,6 +54,22 @@ impl StorageOptions {
         self
     }
 
+    /// Adds the encryption key that was used before a key rotation that didn't finish, records that weren't
+    /// re-encrypted yet are decrypted with it until the rotation is finished.
+    pub fn with_previous_encryption_key(mut self, previous_encryption_key: [u8; 32]) -> Self {
+        self.previous_encryption_key = Some(Zeroizing::new(previous_encryption_key));
+        self
+    }
+
+    /// Stores the wallet in the given memory storage adapter, which can be empty or created from previously exported
+    /// records. The adapter can be kept to export the records with [`MemoryStorageAdapter::to_bytes()`] whenever they
+    /// should be persisted.
+    pub fn with_memory_storage(mut self, memory_storage: MemoryStorageAdapter) -> Self {
+        self.kind = StorageKind::Memory;
+        self.memory_storage = Some(memory_storage);
+        self
+    }
+
     /// Returns the path of the [`StorageOptions`];
     pub fn path(&self) -> &Path {
         &self.path
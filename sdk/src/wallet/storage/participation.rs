@@ -115,11 +115,15 @@ impl StorageManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{types::block::payload::transaction::TransactionId, wallet::storage::adapter::memory::Memory};
+    use crate::{
+        types::block::payload::transaction::TransactionId, wallet::storage::adapter::memory::MemoryStorageAdapter,
+    };
 
     #[tokio::test]
     async fn insert_get_remove_participation_event() {
-        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        let storage_manager = StorageManager::new(MemoryStorageAdapter::default(), None)
+            .await
+            .unwrap();
         assert!(storage_manager.get_participation_events(0).await.unwrap().is_empty());
 
         let event_with_nodes = ParticipationEventWithNodes::mock();
@@ -145,7 +149,9 @@ mod tests {
 
     #[tokio::test]
     async fn set_get_cached_participation_output_status() {
-        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        let storage_manager = StorageManager::new(MemoryStorageAdapter::default(), None)
+            .await
+            .unwrap();
         assert!(
             storage_manager
                 .get_cached_participation_output_status(0)
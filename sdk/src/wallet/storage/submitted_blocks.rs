@@ -0,0 +1,98 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Weak;
+
+use async_trait::async_trait;
+
+use super::manager::StorageManager;
+use crate::{
+    client::{
+        secret::SecretManage,
+        storage::StorageAdapter,
+        submitted_blocks::{SubmittedBlock, SubmittedBlocksStore},
+    },
+    wallet::{core::WalletInner, storage::constants::SUBMITTED_BLOCKS_KEY},
+};
+
+impl StorageManager {
+    pub(crate) async fn save_submitted_blocks(&self, blocks: &[SubmittedBlock]) -> crate::wallet::Result<()> {
+        log::debug!("save_submitted_blocks");
+
+        self.storage.set(SUBMITTED_BLOCKS_KEY, &blocks).await
+    }
+
+    pub(crate) async fn get_submitted_blocks(&self) -> crate::wallet::Result<Vec<SubmittedBlock>> {
+        log::debug!("get_submitted_blocks");
+
+        Ok(self.storage.get(SUBMITTED_BLOCKS_KEY).await?.unwrap_or_default())
+    }
+}
+
+/// Persists the blocks posted by the client of a wallet in the wallet storage, so a transaction isn't posted again
+/// after a restart within the block idempotency TTL.
+pub(crate) struct WalletSubmittedBlocksStore<S: SecretManage>(pub(crate) Weak<WalletInner<S>>);
+
+impl<S: SecretManage> std::fmt::Debug for WalletSubmittedBlocksStore<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WalletSubmittedBlocksStore").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<S: 'static + SecretManage> SubmittedBlocksStore for WalletSubmittedBlocksStore<S> {
+    async fn load(&self) -> Vec<SubmittedBlock> {
+        let Some(wallet) = self.0.upgrade() else {
+            return Vec::new();
+        };
+        let submitted_blocks = wallet.storage_manager.read().await.get_submitted_blocks().await;
+
+        submitted_blocks.unwrap_or_else(|e| {
+            log::warn!("couldn't load the submitted blocks: {e}");
+            Vec::new()
+        })
+    }
+
+    async fn save(&self, blocks: Vec<SubmittedBlock>) {
+        if let Some(wallet) = self.0.upgrade() {
+            if let Err(e) = wallet.storage_manager.read().await.save_submitted_blocks(&blocks).await {
+                log::warn!("couldn't save the submitted blocks: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{payload::transaction::TransactionId, BlockId},
+        wallet::storage::adapter::memory::MemoryStorageAdapter,
+    };
+
+    #[tokio::test]
+    async fn save_get_submitted_blocks() {
+        let storage_manager = StorageManager::new(MemoryStorageAdapter::default(), None)
+            .await
+            .unwrap();
+        assert!(storage_manager.get_submitted_blocks().await.unwrap().is_empty());
+
+        let submitted_blocks = vec![
+            SubmittedBlock {
+                essence_hash: [1; 32],
+                transaction_id: TransactionId::new([2; 32]),
+                block_id: Some(BlockId::new([3; 32])),
+                posted_at: 1_700_000_000_000,
+            },
+            SubmittedBlock {
+                essence_hash: [4; 32],
+                transaction_id: TransactionId::new([5; 32]),
+                block_id: None,
+                posted_at: 1_700_000_001_000,
+            },
+        ];
+        storage_manager.save_submitted_blocks(&submitted_blocks).await.unwrap();
+
+        assert_eq!(storage_manager.get_submitted_blocks().await.unwrap(), submitted_blocks);
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+// These are E2E test samples, so they are ignored by default.
+
+use iota_sdk::{
+    client::{
+        api::{ChrysalisScanOptions, GetAddressesOptions},
+        constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
+        secret::SecretManager,
+        Client, Result,
+    },
+    types::block::{address::ToBech32Ext, payload::transaction::TransactionEssence},
+};
+
+use crate::client::common::create_client_and_secret_manager_with_funds;
+
+#[test]
+fn chrysalis_scan_options() {
+    let options = ChrysalisScanOptions::default();
+    assert_eq!(options.coin_types, [IOTA_COIN_TYPE]);
+    assert_eq!(options.account_indexes, 0..1);
+    assert_eq!(options.address_range, 0..20);
+
+    let options = options
+        .with_coin_types([IOTA_COIN_TYPE, SHIMMER_COIN_TYPE])
+        .with_account_indexes(0..2)
+        .with_address_range(5..10);
+    assert_eq!(options.coin_types, [IOTA_COIN_TYPE, SHIMMER_COIN_TYPE]);
+    assert_eq!(options.account_indexes, 0..2);
+    assert_eq!(options.address_range, 5..10);
+}
+
+#[ignore]
+#[tokio::test]
+async fn find_and_sweep_chrysalis_funds() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+    let address = secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::from_client(&client)
+                .await?
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(client.get_bech32_hrp().await?);
+
+    // The faucet funds are on the first public address
+    let funds = client
+        .find_chrysalis_funds(
+            &secret_manager,
+            ChrysalisScanOptions::default()
+                .with_coin_types([SHIMMER_COIN_TYPE])
+                .with_address_range(0..3),
+        )
+        .await?;
+    assert_eq!(funds.len(), 1);
+    assert_eq!(funds[0].address, address);
+    assert_eq!(funds[0].coin_type, SHIMMER_COIN_TYPE);
+    assert_eq!(funds[0].account_index, 0);
+    assert!(!funds[0].internal);
+    assert_eq!(funds[0].address_index, 0);
+    assert_eq!(funds[0].output_ids.len(), 1);
+    assert!(funds[0].amount > 0);
+
+    // Nothing is found for other coin types
+    assert!(
+        client
+            .find_chrysalis_funds(&secret_manager, ChrysalisScanOptions::default())
+            .await?
+            .is_empty()
+    );
+
+    // Everything is swept to the address of another seed
+    let recipient = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(0..1))
+        .await?[0]
+        .to_bech32(client.get_bech32_hrp().await?);
+    let prepared_transactions = client
+        .prepare_chrysalis_sweep(&secret_manager, &funds, recipient)
+        .await?;
+    assert_eq!(prepared_transactions.len(), 1);
+    assert_eq!(prepared_transactions[0].inputs_data.len(), 1);
+    let TransactionEssence::Regular(essence) = &prepared_transactions[0].essence;
+    assert_eq!(essence.outputs().len(), 1);
+    assert_eq!(essence.outputs()[0].amount(), funds[0].amount);
+    assert_eq!(
+        essence.outputs()[0]
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.address())
+            .map(|uc| uc.address()),
+        Some(recipient.inner())
+    );
+
+    Ok(())
+}
@@ -83,3 +83,52 @@ async fn consolidate_outputs() -> Result<()> {
 
     Ok(())
 }
+
+#[ignore]
+#[tokio::test]
+async fn consolidate_outputs_of_other_account() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+
+    let options = GetAddressesOptions::from_client(&client)
+        .await?
+        .with_account_index(1)
+        .with_range(0..2);
+    let addresses = secret_manager.generate_ed25519_addresses(options.clone()).await?;
+
+    // Fund two addresses of the account with index 1
+    let token_supply = client.get_token_supply().await?;
+    let block = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs([
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(addresses[0]))
+                .finish_output(token_supply)?,
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(addresses[1]))
+                .finish_output(token_supply)?,
+        ])?
+        .finish()
+        .await?;
+    client.retry_until_included(&block.id(), None, None).await?;
+
+    // The outputs are unlocked with the keys of the account index of the options
+    let address = client.consolidate_funds(&secret_manager, options).await?;
+    assert_eq!(addresses[0], address);
+
+    let bech32_hrp = client.get_bech32_hrp().await?;
+    let output_ids_response = client
+        .basic_output_ids([
+            QueryParameter::Address(address.to_bech32(bech32_hrp)),
+            QueryParameter::HasExpiration(false),
+            QueryParameter::HasTimelock(false),
+            QueryParameter::HasStorageDepositReturn(false),
+        ])
+        .await?;
+    assert_eq!(output_ids_response.items.len(), 1);
+
+    let output = client.get_output(&output_ids_response.items[0]).await?;
+    assert_eq!(output.output().amount(), 2_000_000);
+
+    Ok(())
+}
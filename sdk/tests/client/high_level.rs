@@ -1,9 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::types::block::{
-    address::ToBech32Ext,
-    payload::{transaction::TransactionEssence, Payload},
+use iota_sdk::{
+    client::Error,
+    types::block::{
+        address::ToBech32Ext,
+        payload::{transaction::TransactionEssence, Payload},
+        rand::block::rand_block_id,
+        semantic::ConflictReason,
+    },
 };
 
 use crate::client::{common::setup_client_with_node_health_ignored, node_api::setup_transaction_block};
@@ -29,6 +34,40 @@ async fn test_find_blocks() {
     assert_eq!(blocks[0].id(), block_id);
 }
 
+#[ignore]
+#[tokio::test]
+async fn test_find_blocks_skip_missing() {
+    let client = setup_client_with_node_health_ignored().await;
+    let (block_id, _transaction_id) = setup_transaction_block(&client).await;
+    let missing_block_id = rand_block_id();
+    let found = client
+        .find_blocks_skip_missing([block_id, block_id, missing_block_id], 10)
+        .await
+        .unwrap();
+
+    assert_eq!(found.blocks.len(), 1);
+    assert_eq!(found.blocks[0].id(), block_id);
+    assert_eq!(found.missing, [missing_block_id]);
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_validate_transaction_payload_spent_inputs() {
+    let client = setup_client_with_node_health_ignored().await;
+    let (block_id, _transaction_id) = setup_transaction_block(&client).await;
+    let block = client.get_block(&block_id).await.unwrap();
+
+    if let Some(Payload::Transaction(transaction)) = block.payload() {
+        // The inputs were consumed when the transaction got included.
+        assert!(matches!(
+            client.validate_transaction_payload(transaction).await,
+            Err(Error::TransactionSemantic(ConflictReason::InputUtxoAlreadySpent))
+        ));
+    } else {
+        unreachable!();
+    }
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_find_inputs() {
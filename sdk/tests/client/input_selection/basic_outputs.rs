@@ -4,7 +4,7 @@
 use std::str::FromStr;
 
 use iota_sdk::{
-    client::api::input_selection::{Error, InputSelection, Requirement},
+    client::api::input_selection::{BranchAndBound, Error, InputSelection, LargestFirst, Requirement},
     types::block::{
         address::{Address, AliasAddress, Bech32Address, NftAddress},
         output::{AliasId, NftId},
@@ -1390,3 +1390,178 @@ fn too_many_outputs_with_remainder() {
         iota_sdk::client::api::input_selection::Error::InvalidOutputCount(129)
     )
 }
+
+#[test]
+fn strategies() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(5_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_500_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        3_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters.clone(),
+    )
+    .strategy(LargestFirst)
+    .select()
+    .unwrap();
+
+    assert_eq!(selected.inputs, [inputs[0].clone()]);
+    assert_eq!(selected.outputs.len(), 2);
+    assert!(selected.outputs.contains(&outputs[0]));
+    selected.outputs.iter().for_each(|output| {
+        if !outputs.contains(output) {
+            assert!(is_remainder_or_return(
+                output,
+                2_000_000,
+                BECH32_ADDRESS_ED25519_0,
+                None,
+            ));
+        }
+    });
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .strategy(BranchAndBound::default())
+    .select()
+    .unwrap();
+
+    // No remainder is needed
+    assert!(unsorted_eq(&selected.inputs, &[inputs[1].clone(), inputs[3].clone()]));
+    assert_eq!(selected.outputs, outputs);
+}
+
+#[test]
+fn forbidden_input_not_selected() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .required_inputs(vec![*inputs[2].output_id()])
+    .forbidden_inputs(vec![*inputs[0].output_id()])
+    .select()
+    .unwrap();
+
+    // The required input is completed with the only one that isn't forbidden
+    assert!(unsorted_eq(&selected.inputs, &[inputs[1].clone(), inputs[2].clone()]));
+    assert_eq!(selected.outputs, outputs);
+}
+
+#[test]
+fn required_input_is_forbidden() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .required_inputs(vec![*inputs[0].output_id()])
+    .forbidden_inputs(vec![*inputs[0].output_id()])
+    .select();
+
+    assert!(matches!(selected, Err(Error::RequiredInputIsForbidden(output_id)) if &output_id == inputs[0].output_id()));
+}
+
+#[test]
+fn equal_inputs_selected_independent_of_order() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let select = |inputs: Vec<_>| {
+        InputSelection::new(
+            inputs,
+            outputs.clone(),
+            addresses([BECH32_ADDRESS_ED25519_0]),
+            protocol_parameters.clone(),
+        )
+        .select()
+        .unwrap()
+    };
+    let selected = select(inputs.clone());
+    let mut reversed_inputs = inputs.clone();
+    reversed_inputs.reverse();
+
+    // Of the equally good inputs, the one with the lowest output ID is selected
+    let lowest_input = inputs.iter().min_by_key(|input| *input.output_id()).unwrap();
+    assert_eq!(selected.inputs.len(), 1);
+    assert_eq!(selected.inputs[0].output_id(), lowest_input.output_id());
+    assert_eq!(select(reversed_inputs).inputs, selected.inputs);
+}
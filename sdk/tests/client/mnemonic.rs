@@ -13,3 +13,21 @@ async fn mnemonic() -> Result<()> {
     assert!(Client::mnemonic_to_hex_seed(Mnemonic::from("invalid mnemonic".to_owned())).is_err());
     Ok(())
 }
+
+#[cfg(feature = "seeded_rng")]
+#[test]
+fn seeded_mnemonic() -> Result<()> {
+    use iota_sdk::client::utils::{clear_rng_seed, set_rng_seed};
+
+    set_rng_seed(42)?;
+    let first = Client::generate_mnemonic()?;
+    let second = Client::generate_mnemonic()?;
+    // The same seed produces the same sequence of mnemonics
+    set_rng_seed(42)?;
+    assert_eq!(Client::generate_mnemonic()?, first);
+    assert_eq!(Client::generate_mnemonic()?, second);
+    assert_ne!(first, second);
+    clear_rng_seed()?;
+
+    Ok(())
+}
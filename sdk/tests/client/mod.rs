@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod addresses;
+mod chrysalis_migration;
 mod client_builder;
 mod common;
 mod consolidation;
@@ -13,6 +14,7 @@ mod mnemonic;
 #[cfg(feature = "mqtt")]
 mod mqtt;
 mod node_api;
+mod pow;
 mod secret_manager;
 mod signing;
 mod transactions;
@@ -0,0 +1,48 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::client::mqtt::{BrokerOptions, BrokerTlsOptions};
+
+#[test]
+fn broker_options_serde() {
+    let broker_options: BrokerOptions = serde_json::from_str(
+        r#"{
+            "useWs": true,
+            "wsPath": "/mqtt",
+            "wsPort": 8443,
+            "useTls": true,
+            "tls": {
+                "caCertificates": "ca",
+                "clientCertificate": "certificate",
+                "clientKey": "key"
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        broker_options,
+        BrokerOptions::new()
+            .use_ws(true)
+            .ws_path("/mqtt")
+            .ws_port(8443)
+            .use_tls(true)
+            .tls(BrokerTlsOptions::new("ca").with_client_auth("certificate", "key"))
+    );
+    assert!(!format!("{broker_options:?}").contains("\"key\""));
+
+    let default: BrokerOptions = serde_json::from_str("{}").unwrap();
+    assert_eq!(default, BrokerOptions::new());
+}
+
+#[cfg(feature = "inx")]
+#[test]
+fn broker_options_inx_address() {
+    let broker_options: BrokerOptions = serde_json::from_str(r#"{"inxAddress": "http://localhost:9029"}"#).unwrap();
+
+    assert_eq!(
+        broker_options,
+        BrokerOptions::new().inx_address("http://localhost:9029".to_owned())
+    );
+    assert_eq!(BrokerOptions::new().inx_address(None), BrokerOptions::new());
+}
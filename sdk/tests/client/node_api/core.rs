@@ -3,8 +3,11 @@
 
 // These are E2E test samples, so they are ignored by default.
 
+use std::collections::HashSet;
+
+use futures::TryStreamExt;
 use iota_sdk::{
-    client::{api::GetAddressesOptions, node_api::indexer::query_parameters::QueryParameter, Client, NodeInfoWrapper},
+    client::{api::GetAddressesOptions, node_api::indexer::query_parameters::QueryParameter, NodeInfoWrapper},
     types::block::{
         output::{Output, OutputId},
         payload::Payload,
@@ -30,7 +33,11 @@ async fn test_get_health() {
 #[ignore]
 #[tokio::test]
 async fn test_get_info() {
-    let r = Client::get_node_info(NODE_LOCAL, None).await.unwrap();
+    let r = setup_client_with_node_health_ignored()
+        .await
+        .get_node_info_from(NODE_LOCAL, None)
+        .await
+        .unwrap();
     println!("{r:#?}");
 }
 
@@ -243,6 +250,51 @@ async fn test_get_milestone_by_index_raw() {
     }
 }
 
+#[ignore]
+#[tokio::test]
+async fn test_get_milestone_cone() {
+    let client = setup_client_with_node_health_ignored().await;
+
+    let milestone_index = client
+        .get_info()
+        .await
+        .unwrap()
+        .node_info
+        .status
+        .confirmed_milestone
+        .index;
+
+    let blocks = client
+        .get_milestone_cone(milestone_index)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let mut returned = HashSet::new();
+    for block in &blocks {
+        let block_id = block.id();
+        // Every block is newly confirmed by the milestone and returned once
+        assert!(returned.insert(block_id));
+        assert_eq!(
+            client
+                .get_block_metadata(&block_id)
+                .await
+                .unwrap()
+                .referenced_by_milestone_index,
+            Some(milestone_index)
+        );
+    }
+
+    // Every block follows its parents of the cone
+    let mut preceding = HashSet::new();
+    for block in &blocks {
+        for parent in block.parents().iter() {
+            assert!(!returned.contains(parent) || preceding.contains(parent));
+        }
+        preceding.insert(block.id());
+    }
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_get_utxo_changes_by_id() {
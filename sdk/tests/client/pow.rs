@@ -0,0 +1,216 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use iota_sdk::{
+    client::{
+        api::pow::{PowCancel, PowProgress, PowProvider},
+        Client, ClientBuilder, Error, Result,
+    },
+    types::block::{
+        output::RentStructure,
+        protocol::{protocol_parameters, ProtocolParameters},
+        rand::parents::rand_parents,
+    },
+};
+
+fn local_pow_client_builder(min_pow_score: u32) -> ClientBuilder {
+    let protocol_parameters = protocol_parameters();
+
+    Client::builder().with_local_pow(true).with_protocol_parameters(
+        ProtocolParameters::new(
+            protocol_parameters.protocol_version(),
+            protocol_parameters.network_name().to_owned(),
+            protocol_parameters.bech32_hrp(),
+            min_pow_score,
+            protocol_parameters.below_max_depth(),
+            RentStructure::default(),
+            protocol_parameters.token_supply(),
+        )
+        .unwrap(),
+    )
+}
+
+// A score no nonce reaches in the time of the tests, so the PoW only ends when it's cancelled.
+async fn unreachable_pow_score_client() -> Client {
+    local_pow_client_builder(u32::MAX).finish().await.unwrap()
+}
+
+// Reports a hash rate and returns the same nonce for every block.
+#[derive(Debug)]
+struct FixedNonceProvider(u64);
+
+#[async_trait]
+impl PowProvider for FixedNonceProvider {
+    async fn nonce(
+        &self,
+        _block_bytes: &[u8],
+        _min_pow_score: u32,
+        _cancel: PowCancel,
+        progress: PowProgress,
+    ) -> Result<Option<u64>> {
+        progress.report(1000.0);
+        Ok(Some(self.0))
+    }
+}
+
+// Only returns once the PoW got cancelled.
+#[derive(Debug)]
+struct PendingProvider;
+
+#[async_trait]
+impl PowProvider for PendingProvider {
+    async fn nonce(
+        &self,
+        _block_bytes: &[u8],
+        _min_pow_score: u32,
+        cancel: PowCancel,
+        _progress: PowProgress,
+    ) -> Result<Option<u64>> {
+        while !cancel.is_cancelled() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        Ok(None)
+    }
+}
+
+#[tokio::test]
+async fn finish_block_builder_cancelled() {
+    let client = unreachable_pow_score_client().await;
+    let cancel = PowCancel::new();
+    cancel.cancel();
+
+    assert!(matches!(
+        client
+            .finish_block_builder_with_cancel(Some(rand_parents()), None, cancel)
+            .await,
+        Err(Error::PowCancelled)
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cancel_running_pow() {
+    let client = unreachable_pow_score_client().await;
+
+    let pow = tokio::spawn({
+        let client = client.clone();
+        async move { client.finish_block_builder(Some(rand_parents()), None).await }
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(!pow.is_finished());
+
+    client.cancel_pow().await;
+    let result = tokio::time::timeout(Duration::from_secs(5), pow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(result, Err(Error::PowCancelled)));
+
+    // Only the running PoW is cancelled
+    let pow = tokio::spawn({
+        let client = client.clone();
+        async move { client.finish_block_builder(Some(rand_parents()), None).await }
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(!pow.is_finished());
+    client.cancel_pow().await;
+    pow.await.unwrap().unwrap_err();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn own_cancel_token() {
+    let client = unreachable_pow_score_client().await;
+    let cancel = PowCancel::new();
+
+    let pow = tokio::spawn({
+        let client = client.clone();
+        let cancel = cancel.clone();
+        async move {
+            client
+                .finish_block_builder_with_cancel(Some(rand_parents()), None, cancel)
+                .await
+        }
+    });
+    // The PoW with an own token isn't stopped by the client
+    client.cancel_pow().await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(!pow.is_finished());
+
+    cancel.cancel();
+    let result = tokio::time::timeout(Duration::from_secs(5), pow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(result, Err(Error::PowCancelled)));
+}
+
+#[tokio::test]
+async fn provider_nonce_and_progress() {
+    let hash_rates = Arc::new(Mutex::new(Vec::new()));
+    let client = local_pow_client_builder(0)
+        .with_pow_provider(FixedNonceProvider(42))
+        .with_pow_progress_callback({
+            let hash_rates = hash_rates.clone();
+            move |hash_rate| hash_rates.lock().unwrap().push(hash_rate)
+        })
+        .finish()
+        .await
+        .unwrap();
+
+    let block = client.finish_block_builder(Some(rand_parents()), None).await.unwrap();
+
+    assert_eq!(block.nonce(), 42);
+    assert_eq!(*hash_rates.lock().unwrap(), vec![1000.0]);
+}
+
+#[tokio::test]
+async fn provider_nonce_below_min_pow_score() {
+    let client = local_pow_client_builder(u32::MAX)
+        .with_pow_provider(FixedNonceProvider(42))
+        .finish()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        client.finish_block_builder(Some(rand_parents()), None).await,
+        Err(Error::InvalidPowNonce {
+            nonce: 42,
+            min_pow_score: u32::MAX,
+            ..
+        })
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cancel_provider_pow() {
+    let client = local_pow_client_builder(u32::MAX)
+        .with_pow_provider(PendingProvider)
+        .finish()
+        .await
+        .unwrap();
+    let cancel = PowCancel::new();
+
+    let pow = tokio::spawn({
+        let client = client.clone();
+        let cancel = cancel.clone();
+        async move {
+            client
+                .finish_block_builder_with_cancel(Some(rand_parents()), None, cancel)
+                .await
+        }
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(!pow.is_finished());
+
+    cancel.cancel();
+    let result = tokio::time::timeout(Duration::from_secs(5), pow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(result, Err(Error::PowCancelled)));
+}
@@ -0,0 +1,280 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Range;
+
+use async_trait::async_trait;
+use crypto::{
+    keys::bip44::Bip44,
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+use iota_sdk::{
+    client::{
+        api::{
+            transaction::validate_transaction_payload_length, verify_semantic, GetAddressesOptions,
+            PreparedTransactionData,
+        },
+        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{
+            default_sign_transaction, default_sign_transaction_essence, mnemonic::MnemonicSecretManager,
+            CustomSecretManager, GenerateAddressOptions, SecretManage, SecretManager, SecretManagerConfig,
+            SecretManagerDto,
+        },
+        Client, Error, Result,
+    },
+    types::block::{
+        address::{Ed25519Address, ToBech32Ext},
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence},
+            TransactionPayload,
+        },
+        protocol::protocol_parameters,
+        semantic::ConflictReason,
+        signature::Ed25519Signature,
+        unlock::{Unlock, Unlocks},
+    },
+};
+
+use crate::client::{build_inputs, build_outputs, Build::Basic};
+
+const MNEMONIC: &str = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+
+/// A custom secret manager that only derives keys and signs, like one backed by an HSM would.
+struct Signer {
+    secret_manager: MnemonicSecretManager,
+    /// Returns one signature unlock less than requested, like a faulty signer.
+    drop_unlock: bool,
+}
+
+#[async_trait]
+impl SecretManage for Signer {
+    type Error = Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>> {
+        self.secret_manager
+            .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+            .await
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>> {
+        Err(Error::SecretManagerMismatch)
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature> {
+        self.secret_manager.sign_ed25519(msg, chain).await
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _msg: &[u8],
+        _chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature)> {
+        Err(Error::SecretManagerMismatch)
+    }
+
+    async fn signature_unlocks(&self, essence_hash: &[u8; 32], chains: &[Bip44]) -> Result<Vec<Unlock>> {
+        let mut unlocks = self.secret_manager.signature_unlocks(essence_hash, chains).await?;
+        if self.drop_unlock {
+            unlocks.pop();
+        }
+        Ok(unlocks)
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks> {
+        default_sign_transaction_essence(self, prepared_transaction_data, time).await
+    }
+
+    async fn sign_transaction(&self, prepared_transaction_data: PreparedTransactionData) -> Result<TransactionPayload> {
+        default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+fn custom_secret_manager() -> Result<SecretManager> {
+    Ok(SecretManager::Custom(CustomSecretManager::new(Signer {
+        secret_manager: MnemonicSecretManager::try_from_mnemonic(MNEMONIC.to_owned())?,
+        drop_unlock: false,
+    })))
+}
+
+#[tokio::test]
+async fn custom_secret_manager_addresses() -> Result<()> {
+    let secret_manager = custom_secret_manager()?;
+    let mnemonic_secret_manager = SecretManager::try_from_mnemonic(MNEMONIC.to_owned())?;
+
+    let options = GetAddressesOptions::default()
+        .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+        .with_range(0..3);
+
+    assert_eq!(
+        secret_manager.generate_ed25519_addresses(options.clone()).await?,
+        mnemonic_secret_manager
+            .generate_ed25519_addresses(options.clone())
+            .await?
+    );
+    // The options are passed to the custom secret manager
+    assert_eq!(
+        secret_manager
+            .generate_ed25519_addresses(options.clone().internal())
+            .await?,
+        mnemonic_secret_manager
+            .generate_ed25519_addresses(options.clone().internal())
+            .await?
+    );
+    assert_ne!(
+        secret_manager.generate_ed25519_addresses(options.clone()).await?,
+        secret_manager.generate_ed25519_addresses(options.internal()).await?
+    );
+    assert!(matches!(
+        secret_manager
+            .generate_evm_addresses(GetAddressesOptions::default().with_range(0..1))
+            .await,
+        Err(Error::SecretManagerMismatch)
+    ));
+
+    Ok(())
+}
+
+async fn prepared_transaction_data(secret_manager: &SecretManager) -> Result<PreparedTransactionData> {
+    let bech32_address_0 = &secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish_with_params(protocol_parameters)?,
+    );
+
+    Ok(PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    })
+}
+
+#[tokio::test]
+async fn custom_secret_manager_sign_transaction_essence() -> Result<()> {
+    let secret_manager = custom_secret_manager()?;
+
+    let prepared_transaction_data = prepared_transaction_data(&secret_manager).await?;
+
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(0))
+        .await?;
+
+    assert_eq!(unlocks.len(), 1);
+
+    let tx_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+
+    validate_transaction_payload_length(&tx_payload)?;
+
+    let conflict = verify_semantic(&prepared_transaction_data.inputs_data, &tx_payload, 100)?;
+
+    assert_eq!(conflict, ConflictReason::None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn custom_secret_manager_missing_signature_unlock() -> Result<()> {
+    let secret_manager = custom_secret_manager()?;
+    let prepared_transaction_data = prepared_transaction_data(&secret_manager).await?;
+    let faulty_secret_manager = SecretManager::Custom(CustomSecretManager::new(Signer {
+        secret_manager: MnemonicSecretManager::try_from_mnemonic(MNEMONIC.to_owned())?,
+        drop_unlock: true,
+    }));
+
+    // The input isn't left without an unlock
+    assert!(matches!(
+        faulty_secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(0))
+            .await,
+        Err(Error::SignatureUnlockCount { expected: 1, found: 0 })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn custom_secret_manager_dto() -> Result<()> {
+    let secret_manager = SecretManager::Custom(CustomSecretManager::new(Signer {
+        secret_manager: MnemonicSecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?,
+        drop_unlock: false,
+    }));
+
+    // The DTO describes the type, but the custom secret manager can't be stored or created from it
+    let dto = SecretManagerDto::from(&secret_manager);
+    assert!(matches!(dto, SecretManagerDto::Custom));
+    assert_eq!(serde_json::to_string(&dto)?, r#""Custom""#);
+    assert!(secret_manager.to_config().is_none());
+    assert!(matches!(
+        SecretManager::try_from(dto.clone()),
+        Err(Error::CustomSecretManagerConfig)
+    ));
+    assert!(matches!(
+        SecretManager::from_config(&dto),
+        Err(Error::CustomSecretManagerConfig)
+    ));
+    assert!(matches!(
+        r#""custom""#.parse::<SecretManager>(),
+        Err(Error::CustomSecretManagerConfig)
+    ));
+
+    Ok(())
+}
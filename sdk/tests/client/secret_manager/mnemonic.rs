@@ -1,8 +1,12 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crypto::keys::bip44::Bip44;
 use iota_sdk::client::{
-    api::GetAddressesOptions, constants::SHIMMER_TESTNET_BECH32_HRP, secret::SecretManager, Result,
+    api::GetAddressesOptions,
+    constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+    secret::{SecretManage, SecretManager},
+    Result,
 };
 
 #[tokio::test]
@@ -27,3 +31,22 @@ async fn mnemonic_secret_manager() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn mnemonic_secret_manager_signature_unlocks() -> Result<()> {
+    let dto = r#"{"mnemonic": "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
+    let secret_manager: SecretManager = dto.parse()?;
+    let essence_hash = [1; 32];
+    let chains = (0..20)
+        .map(|address_index| Bip44::new(SHIMMER_COIN_TYPE).with_address_index(address_index))
+        .collect::<Vec<_>>();
+
+    let unlocks = secret_manager.signature_unlocks(&essence_hash, &chains).await?;
+
+    assert_eq!(unlocks.len(), chains.len());
+    for (unlock, chain) in unlocks.iter().zip(chains) {
+        assert_eq!(unlock, &secret_manager.signature_unlock(&essence_hash, chain).await?);
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod custom;
 mod mnemonic;
 #[cfg(feature = "private_key_secret_manager")]
 mod private_key;
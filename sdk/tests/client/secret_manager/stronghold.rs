@@ -80,3 +80,69 @@ async fn stronghold_mnemonic_missing() -> Result<()> {
     std::fs::remove_dir_all("stronghold_mnemonic_missing").ok();
     Ok(())
 }
+
+#[tokio::test]
+async fn stronghold_verify_and_repair_snapshot() -> Result<()> {
+    use iota_sdk::client::{
+        storage::StorageAdapter,
+        stronghold::{Error as StrongholdError, StrongholdAdapter},
+    };
+
+    iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+
+    let folder = "stronghold_verify_and_repair_snapshot";
+    // Cleanup of a possibly failed run
+    std::fs::remove_dir_all(folder).ok();
+    std::fs::create_dir_all(folder).unwrap();
+    let snapshot_path = format!("{folder}/test.stronghold");
+    let recovered_path = format!("{folder}/recovered.stronghold");
+    let password = "some_hopefully_secure_password";
+
+    let stronghold = StrongholdAdapter::builder()
+        .password(password.to_owned())
+        .build(&snapshot_path)?;
+    stronghold
+        .store_mnemonic(crypto::keys::bip39::Mnemonic::from(
+            "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast".to_owned(),
+        ))
+        .await?;
+    stronghold.set("valid", &"value").await?;
+    // Store a record that isn't encrypted with the store key
+    stronghold
+        .inner()
+        .await
+        .get_client(b"iota_seed")
+        .unwrap()
+        .store()
+        .insert(b"corrupted".to_vec(), vec![0; 64], None)
+        .unwrap();
+    stronghold.write_stronghold_snapshot(None).await?;
+
+    let verification = StrongholdAdapter::verify_snapshot(&snapshot_path, password.to_owned().into())?;
+    assert!(verification.has_mnemonic);
+    assert_eq!(verification.valid_store_records, ["valid"]);
+    assert_eq!(verification.corrupted_store_records, ["corrupted"]);
+    assert!(!verification.is_intact());
+
+    assert!(matches!(
+        StrongholdAdapter::verify_snapshot(&snapshot_path, "wrong_password".to_owned().into()),
+        Err(StrongholdError::InvalidPassword)
+    ));
+    std::fs::write(format!("{folder}/invalid.stronghold"), [0; 256]).unwrap();
+    assert!(matches!(
+        StrongholdAdapter::verify_snapshot(format!("{folder}/invalid.stronghold"), password.to_owned().into()),
+        Err(StrongholdError::InvalidSnapshotFile)
+    ));
+
+    let repaired = StrongholdAdapter::repair_snapshot(&snapshot_path, password.to_owned().into(), &recovered_path)?;
+    assert_eq!(repaired, verification);
+
+    let verification = StrongholdAdapter::verify_snapshot(&recovered_path, password.to_owned().into())?;
+    assert!(verification.has_mnemonic);
+    assert_eq!(verification.valid_store_records, ["valid"]);
+    assert!(verification.is_intact());
+
+    // Remove garbage after test, but don't care about the result
+    std::fs::remove_dir_all(folder).ok();
+    Ok(())
+}
@@ -357,3 +357,96 @@ async fn alias_reference_unlocks() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn alias_reference_unlocks_canonical_order() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+    let alias_id = AliasId::from_str(ALIAS_ID_1)?;
+    let alias_bech32_address = &Address::Alias(AliasAddress::new(alias_id)).to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    // The alias owned input is given first, it can only be unlocked after the alias output
+    let inputs = build_inputs([
+        Basic(
+            1_000_000,
+            &alias_bech32_address.to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Alias(
+            1_000_000,
+            alias_id,
+            0,
+            &bech32_address_0.to_string(),
+            &bech32_address_0.to_string(),
+            None,
+            None,
+            None,
+            Some(Bip44::new(SHIMMER_COIN_TYPE)),
+        ),
+    ]);
+
+    let outputs = build_outputs([
+        Alias(
+            1_000_000,
+            alias_id,
+            1,
+            &bech32_address_0.to_string(),
+            &bech32_address_0.to_string(),
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(
+            1_000_000,
+            &bech32_address_0.to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+
+    let prepared_transaction_data =
+        PreparedTransactionData::from_inputs_and_outputs(&protocol_parameters, inputs, outputs, None, None)?;
+
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(0))
+        .await?;
+
+    assert_eq!(unlocks.len(), 2);
+    assert_eq!((*unlocks).get(0).unwrap().kind(), SignatureUnlock::KIND);
+    match (*unlocks).get(1).unwrap() {
+        Unlock::Alias(a) => {
+            assert_eq!(a.index(), 0);
+        }
+        _ => panic!("Invalid unlock"),
+    }
+
+    let tx_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+
+    let conflict = verify_semantic(&prepared_transaction_data.inputs_data, &tx_payload, 100)?;
+
+    if conflict != ConflictReason::None {
+        panic!("{conflict:?}, with {tx_payload:#?}");
+    }
+
+    Ok(())
+}
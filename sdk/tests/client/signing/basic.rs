@@ -6,7 +6,7 @@ use iota_sdk::{
     client::{
         api::{
             transaction::validate_transaction_payload_length, verify_semantic, GetAddressesOptions,
-            PreparedTransactionData,
+            PreparedTransactionData, SignedTransactionData,
         },
         constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
         secret::{SecretManage, SecretManager},
@@ -322,3 +322,168 @@ async fn two_signature_unlocks() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn offline_signing_json_round_trip() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    // Signing doesn't send requests, so the client doesn't need nodes
+    let offline_client = Client::builder().finish().await?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish_with_params(protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    };
+
+    let json = serde_json::to_string(&prepared_transaction_data)?;
+    let prepared_transaction_data_from_json = serde_json::from_str::<PreparedTransactionData>(&json)?;
+    assert_eq!(prepared_transaction_data_from_json, prepared_transaction_data);
+
+    let signed_transaction_data = offline_client
+        .sign_transaction(&secret_manager, prepared_transaction_data_from_json)
+        .await?;
+
+    let json = serde_json::to_string(&signed_transaction_data)?;
+    assert_eq!(
+        serde_json::from_str::<SignedTransactionData>(&json)?,
+        signed_transaction_data
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn canonical_essence() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(
+            1_000_000,
+            &bech32_address_0.to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Bip44::new(SHIMMER_COIN_TYPE)),
+        ),
+        Basic(
+            2_000_000,
+            &bech32_address_0.to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Bip44::new(SHIMMER_COIN_TYPE)),
+        ),
+    ]);
+
+    let outputs = build_outputs([Basic(
+        3_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let prepared_transaction_data = PreparedTransactionData::from_inputs_and_outputs(
+        &protocol_parameters,
+        inputs.clone(),
+        outputs.clone(),
+        None,
+        None,
+    )?;
+    let reversed_prepared_transaction_data = PreparedTransactionData::from_inputs_and_outputs(
+        &protocol_parameters,
+        inputs.into_iter().rev().collect(),
+        outputs,
+        None,
+        None,
+    )?;
+
+    // The order in which the inputs are given doesn't change the essence
+    assert_eq!(
+        prepared_transaction_data.essence_hash(),
+        reversed_prepared_transaction_data.essence_hash()
+    );
+    assert_eq!(
+        prepared_transaction_data.inputs_data,
+        reversed_prepared_transaction_data.inputs_data
+    );
+
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(0))
+        .await?;
+    let tx_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+
+    let conflict = verify_semantic(&prepared_transaction_data.inputs_data, &tx_payload, 100)?;
+
+    if conflict != ConflictReason::None {
+        panic!("{conflict:?}, with {tx_payload:#?}");
+    }
+
+    Ok(())
+}
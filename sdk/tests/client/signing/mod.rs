@@ -3,6 +3,7 @@
 
 mod alias;
 mod basic;
+mod multisig;
 mod nft;
 
 use std::str::FromStr;
@@ -0,0 +1,122 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::{GetAddressesOptions, MultisigCoordinator, PartialSignature, PreparedTransactionData},
+        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{SecretManage, SecretManager},
+        Client, Error, Result,
+    },
+    types::block::{
+        address::{Bech32Address, ToBech32Ext},
+        protocol::protocol_parameters,
+        unlock::SignatureUnlock,
+    },
+};
+
+use crate::client::{build_inputs, build_outputs, Build::Basic};
+
+async fn first_address(secret_manager: &SecretManager) -> Result<Bech32Address> {
+    Ok(secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP))
+}
+
+// A transaction with two inputs of the first address and one of the second
+async fn shared_transaction(
+    secret_manager_0: &SecretManager,
+    secret_manager_1: &SecretManager,
+) -> Result<PreparedTransactionData> {
+    let address_0 = first_address(secret_manager_0).await?.to_string();
+    let address_1 = first_address(secret_manager_1).await?.to_string();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, &address_0, None, None, None, None, None, None),
+        Basic(1_000_000, &address_0, None, None, None, None, None, None),
+        Basic(1_000_000, &address_1, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(3_000_000, &address_1, None, None, None, None, None, None)]);
+
+    PreparedTransactionData::from_inputs_and_outputs(&protocol_parameters(), inputs, outputs, None, None)
+}
+
+#[tokio::test]
+async fn multisig_coordinator() -> Result<()> {
+    let secret_manager_0 = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let secret_manager_1 = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let prepared_transaction_data = shared_transaction(&secret_manager_0, &secret_manager_1).await?;
+
+    let mut coordinator = MultisigCoordinator::new(prepared_transaction_data.clone(), 100)?;
+    assert_eq!(coordinator.signers().count(), 2);
+    assert!(!coordinator.is_complete());
+
+    let chains = [Bip44::new(SHIMMER_COIN_TYPE)];
+    coordinator
+        .add_signatures(PartialSignature::sign(&secret_manager_0, &prepared_transaction_data, &chains).await?)?;
+    assert_eq!(coordinator.missing_signers().count(), 1);
+    assert!(matches!(coordinator.unlocks(), Err(Error::MissingSignatures(missing)) if missing.len() == 1));
+
+    // Partial signatures can be sent as JSON
+    let partial_signatures = PartialSignature::sign(&secret_manager_1, &prepared_transaction_data, &chains).await?;
+    let json = serde_json::to_string(&partial_signatures)?;
+    coordinator.add_signatures(serde_json::from_str::<Vec<PartialSignature>>(&json)?)?;
+    assert!(coordinator.is_complete());
+
+    let signed_transaction_data = coordinator.finish()?;
+    let unlocks = signed_transaction_data.transaction_payload.unlocks();
+    assert_eq!(unlocks.len(), 3);
+    assert_eq!(
+        unlocks
+            .iter()
+            .filter(|unlock| unlock.kind() == SignatureUnlock::KIND)
+            .count(),
+        2
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn multisig_coordinator_rejects_invalid_signatures() -> Result<()> {
+    let secret_manager_0 = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let secret_manager_1 = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let secret_manager_2 = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let prepared_transaction_data = shared_transaction(&secret_manager_0, &secret_manager_1).await?;
+    let other_transaction_data = shared_transaction(&secret_manager_1, &secret_manager_0).await?;
+
+    let mut coordinator = MultisigCoordinator::new(prepared_transaction_data.clone(), 100)?;
+    let chains = [Bip44::new(SHIMMER_COIN_TYPE)];
+
+    // Not an address of the inputs
+    let partial_signatures = PartialSignature::sign(&secret_manager_2, &prepared_transaction_data, &chains).await?;
+    assert!(matches!(
+        coordinator.add_signatures(partial_signatures),
+        Err(Error::UnexpectedSigner(_))
+    ));
+
+    // Another essence
+    let partial_signatures = PartialSignature::sign(&secret_manager_0, &other_transaction_data, &chains).await?;
+    assert!(matches!(
+        coordinator.add_signatures(partial_signatures),
+        Err(Error::EssenceHashMismatch { .. })
+    ));
+
+    // The signature doesn't match the essence hash it claims to sign
+    let mut partial_signatures = PartialSignature::sign(&secret_manager_0, &other_transaction_data, &chains).await?;
+    partial_signatures[0].essence_hash = coordinator.essence_hash();
+    assert!(matches!(
+        coordinator.add_signatures(partial_signatures),
+        Err(Error::Block(_))
+    ));
+
+    assert_eq!(coordinator.missing_signers().count(), 2);
+
+    Ok(())
+}
@@ -4,11 +4,17 @@
 // These are E2E test samples, so they are ignored by default.
 
 use iota_sdk::{
-    client::{api::GetAddressesOptions, node_api::indexer::query_parameters::QueryParameter, Result},
+    client::{
+        api::{input_selection::Burn, GetAddressesOptions},
+        node_api::indexer::query_parameters::QueryParameter,
+        Client, Error, Result,
+    },
     types::block::{
         address::ToBech32Ext,
         input::{Input, UtxoInput},
-        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId},
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NftId, NftOutputBuilder, Output, OutputId,
+        },
         payload::Payload,
     },
 };
@@ -135,3 +141,199 @@ async fn custom_input() -> Result<()> {
 
     Ok(())
 }
+
+#[ignore]
+#[tokio::test]
+async fn dry_run_basic_output() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+
+    let token_supply = client.get_token_supply().await?;
+
+    let second_address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(1..2))
+        .await?[0];
+
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(second_address))
+        .finish_output(token_supply)?;
+
+    let block_builder = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs([output.clone()])?;
+
+    // Dry runs of the same builder return the same transaction
+    let dry_run = block_builder.dry_run().await?;
+    assert_eq!(dry_run, block_builder.dry_run().await?);
+    assert_eq!(dry_run.summary.consumed_output_ids.len(), 1);
+    assert_eq!(dry_run.summary.remainder_index, Some(1));
+    assert_eq!(dry_run.summary.consumed_amount, dry_run.summary.created_amount);
+
+    // The previewed transaction is the one that is sent
+    let block = block_builder.finish().await?;
+    let Some(Payload::Transaction(tx_payload)) = block.payload() else {
+        panic!("missing transaction payload")
+    };
+    assert_eq!(tx_payload.essence(), &dry_run.prepared_transaction_data.essence);
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn required_and_forbidden_inputs() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+
+    let token_supply = client.get_token_supply().await?;
+
+    let second_address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(1..2))
+        .await?[0];
+
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(second_address))
+        .finish_output(token_supply)?;
+
+    // Split the funds into two small outputs and a remainder
+    let block = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs([output.clone(), output.clone()])?
+        .finish()
+        .await?;
+    client.retry_until_included(&block.id(), None, None).await?;
+
+    let Some(Payload::Transaction(tx_payload)) = block.payload() else {
+        panic!("missing transaction payload")
+    };
+    let mut small_output_ids = Vec::new();
+    let mut remainder_output_id = None;
+    for (index, output) in tx_payload.essence().as_regular().outputs().iter().enumerate() {
+        let output_id = OutputId::new(tx_payload.id(), index as u16)?;
+        if output.amount() == 1_000_000 {
+            small_output_ids.push(output_id);
+        } else {
+            remainder_output_id = Some(output_id);
+        }
+    }
+    let remainder_output_id = remainder_output_id.expect("missing remainder");
+
+    // The required input covers the amount on its own
+    let dry_run = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs([output.clone()])?
+        .with_required_inputs([small_output_ids[1]])
+        .dry_run()
+        .await?;
+    assert_eq!(dry_run.summary.consumed_output_ids, [small_output_ids[1]]);
+
+    // Without the remainder, both small outputs are needed
+    let output = BasicOutputBuilder::new_with_amount(1_500_000)
+        .add_unlock_condition(AddressUnlockCondition::new(second_address))
+        .finish_output(token_supply)?;
+    let dry_run = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs([output.clone()])?
+        .with_forbidden_inputs([remainder_output_id])
+        .dry_run()
+        .await?;
+    assert_eq!(dry_run.summary.consumed_output_ids.len(), 2);
+    assert!(
+        dry_run
+            .summary
+            .consumed_output_ids
+            .iter()
+            .all(|output_id| small_output_ids.contains(output_id))
+    );
+
+    // Nothing is left to select
+    assert!(
+        client
+            .build_block()
+            .with_secret_manager(&secret_manager)
+            .with_outputs([output])?
+            .with_forbidden_inputs(small_output_ids.into_iter().chain([remainder_output_id]))
+            .dry_run()
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn burn_nft_without_outputs() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+
+    let token_supply = client.get_token_supply().await?;
+
+    let address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(0..1))
+        .await?[0];
+
+    let output = NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(token_supply)?;
+    let block = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs([output])?
+        .finish()
+        .await?;
+    client.retry_until_included(&block.id(), None, None).await?;
+
+    let Some(Payload::Transaction(tx_payload)) = block.payload() else {
+        panic!("missing transaction payload")
+    };
+    let nft_output_id = OutputId::new(tx_payload.id(), 0)?;
+    let nft_id = NftId::from(&nft_output_id);
+
+    // The NFT input is found from the burn alone and not transitioned to an output
+    let dry_run = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_burn(Burn::new().add_nft(nft_id))
+        .dry_run()
+        .await?;
+    assert!(dry_run.summary.consumed_output_ids.contains(&nft_output_id));
+    assert!(
+        !dry_run
+            .prepared_transaction_data
+            .essence
+            .as_regular()
+            .outputs()
+            .iter()
+            .any(|output| matches!(output, Output::Nft(_)))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn burn_without_outputs_needs_seed() {
+    let client = Client::builder().finish().await.unwrap();
+
+    // A burn is sent in a transaction, even without outputs
+    assert!(matches!(
+        client
+            .build_block()
+            .with_burn(Burn::new().add_nft(NftId::null()))
+            .finish()
+            .await,
+        Err(Error::MissingParameter("seed"))
+    ));
+}
+
+#[tokio::test]
+async fn dry_run_flag_prevents_sending() {
+    let client = Client::builder().finish().await.unwrap();
+
+    // The flag is checked before anything is requested from a node
+    assert!(matches!(
+        client.build_block().with_dry_run(true).finish().await,
+        Err(Error::DryRun)
+    ));
+}
@@ -4,7 +4,7 @@
 use core::str::FromStr;
 
 use iota_sdk::types::block::{
-    address::{Address, Bech32Address, Ed25519Address, Hrp},
+    address::{set_address_codec, Address, AddressCodec, Bech32Address, Bech32Codec, Ed25519Address, Hrp},
     Error,
 };
 use packable::PackableExt;
@@ -68,3 +68,25 @@ fn bech32_into_inner() {
 
     assert_eq!(address, bech32_address.into_inner());
 }
+
+#[test]
+fn bech32_codec() {
+    let (hrp, address) = Bech32Codec.decode(ED25519_BECH32).unwrap();
+
+    assert_eq!(hrp, "rms");
+    assert_eq!(
+        address,
+        Address::Ed25519(ED25519_ADDRESS.parse::<Ed25519Address>().unwrap())
+    );
+    assert_eq!(Bech32Codec.encode(&hrp, &address), ED25519_BECH32);
+    assert_eq!(Bech32Codec.decode("rms1invalid"), Err(Error::InvalidAddress));
+}
+
+#[test]
+fn set_address_codec_after_use() {
+    // The default codec is set on first use, so it can't be replaced afterwards.
+    let bech32_address = Bech32Address::from_str(ED25519_BECH32).unwrap();
+    assert_eq!(bech32_address.to_string(), ED25519_BECH32);
+
+    assert_eq!(set_address_codec(Bech32Codec), Err(Error::AddressCodecAlreadySet));
+}
@@ -106,6 +106,22 @@ fn pack_unpack_valid() {
     );
 }
 
+#[test]
+fn unpack_unverified_skips_protocol_checks() {
+    let protocol_parameters = protocol_parameters();
+    let block = BlockBuilder::new(rand_parents())
+        .with_protocol_version(protocol_parameters.protocol_version() + 1)
+        .finish()
+        .unwrap();
+    let packed_block = block.pack_to_vec();
+
+    assert!(matches!(
+        Block::unpack_verified(packed_block.as_slice(), &protocol_parameters),
+        Err(UnpackError::Packable(Error::ProtocolVersionMismatch { .. }))
+    ));
+    assert_eq!(block, Block::unpack_unverified(packed_block).unwrap());
+}
+
 #[test]
 fn getters() {
     let protocol_parameters = protocol_parameters();
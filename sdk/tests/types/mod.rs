@@ -14,6 +14,7 @@ mod milestone_id;
 mod milestone_index;
 mod milestone_payload;
 mod milestone_payload_essence;
+mod output;
 mod output_id;
 mod parents;
 mod payload;
@@ -0,0 +1,92 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    address::{Address, Ed25519Address},
+    output::{
+        feature::{Feature, SenderFeature, TagFeature},
+        unlock_condition::{AddressUnlockCondition, TimelockUnlockCondition, UnlockCondition},
+        BasicOutput, BasicOutputBuilder, Output, TreasuryOutput,
+    },
+    Error,
+};
+
+fn address() -> Address {
+    Address::Ed25519(Ed25519Address::new([1; 32]))
+}
+
+#[test]
+fn output_downcasts() {
+    let basic_output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(address()))
+        .finish_output(u64::MAX)
+        .unwrap();
+    let treasury_output = Output::Treasury(TreasuryOutput::new(1_000_000, u64::MAX).unwrap());
+
+    assert_eq!(basic_output.try_as_basic().unwrap(), basic_output.as_basic());
+    assert_eq!(
+        treasury_output.try_as_treasury().unwrap(),
+        treasury_output.as_treasury()
+    );
+    // A mismatching kind returns the actual kind
+    assert!(matches!(
+        basic_output.try_as_nft(),
+        Err(Error::InvalidOutputKind(BasicOutput::KIND))
+    ));
+    assert!(matches!(
+        treasury_output.try_as_alias(),
+        Err(Error::InvalidOutputKind(TreasuryOutput::KIND))
+    ));
+
+    assert_eq!(&basic_output.clone().try_into_basic().unwrap(), basic_output.as_basic());
+    assert!(matches!(
+        basic_output.clone().try_into_foundry(),
+        Err(Error::InvalidOutputKind(BasicOutput::KIND))
+    ));
+    assert!(matches!(
+        treasury_output.try_into_nft(),
+        Err(Error::InvalidOutputKind(TreasuryOutput::KIND))
+    ));
+}
+
+#[test]
+fn feature_downcasts() {
+    let sender = Feature::from(SenderFeature::new(address()));
+    let tag = Feature::from(TagFeature::new(b"tag".to_vec()).unwrap());
+
+    assert_eq!(sender.try_as_sender().unwrap().address(), &address());
+    assert_eq!(tag.clone().try_into_tag().unwrap().tag(), b"tag");
+    assert!(matches!(
+        sender.try_as_tag(),
+        Err(Error::InvalidFeatureKind(SenderFeature::KIND))
+    ));
+    assert!(matches!(
+        tag.clone().try_into_issuer(),
+        Err(Error::InvalidFeatureKind(TagFeature::KIND))
+    ));
+    assert!(matches!(
+        tag.try_as_metadata(),
+        Err(Error::InvalidFeatureKind(TagFeature::KIND))
+    ));
+}
+
+#[test]
+fn unlock_condition_downcasts() {
+    let address_unlock_condition = UnlockCondition::from(AddressUnlockCondition::new(address()));
+    let timelock = UnlockCondition::from(TimelockUnlockCondition::new(100).unwrap());
+
+    assert_eq!(address_unlock_condition.try_as_address().unwrap().address(), &address());
+    assert_eq!(timelock.clone().try_into_timelock().unwrap().timestamp(), 100);
+    assert!(matches!(
+        address_unlock_condition.try_as_expiration(),
+        Err(Error::InvalidUnlockConditionKind(AddressUnlockCondition::KIND))
+    ));
+    assert!(matches!(
+        timelock.clone().try_into_address(),
+        Err(Error::InvalidUnlockConditionKind(TimelockUnlockCondition::KIND))
+    ));
+    assert!(matches!(
+        timelock.try_as_governor_address(),
+        Err(Error::InvalidUnlockConditionKind(TimelockUnlockCondition::KIND))
+    ));
+}
@@ -181,3 +181,106 @@ fn getters() {
     assert_eq!(*tx_payload.essence(), essence);
     assert_eq!(*tx_payload.unlocks(), unlocks);
 }
+
+#[test]
+fn verify_signatures_batch() {
+    use crypto::{
+        hashes::{blake2b::Blake2b256, Digest},
+        signatures::ed25519::SecretKey,
+    };
+    use iota_sdk::types::block::output::{unlock_condition::ExpirationUnlockCondition, OutputId};
+
+    let protocol_parameters = protocol_parameters();
+    let transaction_id = TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap());
+    let secret_key = SecretKey::from_bytes(&[1; 32]);
+    let address = Address::from(Ed25519Address::new(Blake2b256::digest(secret_key.public_key()).into()));
+    let output = Output::Basic(
+        BasicOutput::build_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_with_params(protocol_parameters.token_supply())
+            .unwrap(),
+    );
+    let output_ids = [
+        OutputId::new(transaction_id, 0).unwrap(),
+        OutputId::new(transaction_id, 1).unwrap(),
+    ];
+    let inputs = output_ids
+        .iter()
+        .map(|output_id| (output_id, &output))
+        .collect::<Vec<_>>();
+
+    let transaction = |secret_key: &SecretKey, message: Option<&[u8]>| {
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+                .with_inputs(output_ids.map(|output_id| Input::Utxo(UtxoInput::from(output_id))))
+                .add_output(output.clone())
+                .finish_with_params(&protocol_parameters)
+                .unwrap(),
+        );
+        let essence_hash = essence.hash();
+        let signature = Ed25519Signature::new(
+            secret_key.public_key(),
+            secret_key.sign(message.unwrap_or(&essence_hash)),
+        );
+        let unlocks = Unlocks::new([
+            Unlock::Signature(SignatureUnlock::from(Signature::from(signature))),
+            Unlock::Reference(ReferenceUnlock::new(0).unwrap()),
+        ])
+        .unwrap();
+        TransactionPayload::new(essence, unlocks).unwrap()
+    };
+
+    let valid = transaction(&secret_key, None);
+    assert!(valid.verify_signatures_batch(&inputs, 0).is_ok());
+    assert!(TransactionPayload::verify_signatures_batch_many(&[(&valid, &inputs), (&valid, &inputs)], 0).is_ok());
+
+    let wrong_message = transaction(&secret_key, Some(&[0; 32]));
+    assert!(matches!(
+        TransactionPayload::verify_signatures_batch_many(&[(&valid, &inputs), (&wrong_message, &inputs)], 0),
+        Err(Error::InvalidSignature)
+    ));
+
+    let wrong_key = transaction(&SecretKey::from_bytes(&[2; 32]), None);
+    assert!(matches!(
+        wrong_key.verify_signatures_batch(&inputs, 0),
+        Err(Error::SignaturePublicKeyMismatch { .. })
+    ));
+
+    // The input unlocked by the reference unlock is owned by another address
+    let other_address = Address::from(Ed25519Address::new(
+        Blake2b256::digest(SecretKey::from_bytes(&[2; 32]).public_key()).into(),
+    ));
+    let other_output = Output::Basic(
+        BasicOutput::build_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(other_address))
+            .finish_with_params(protocol_parameters.token_supply())
+            .unwrap(),
+    );
+    assert!(matches!(
+        valid.verify_signatures_batch(&[inputs[0], (&output_ids[1], &other_output)], 0),
+        Err(Error::SignaturePublicKeyMismatch { .. })
+    ));
+
+    // Once expired, only the return address can unlock the output
+    let expiring_output = Output::Basic(
+        BasicOutput::build_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(ExpirationUnlockCondition::new(other_address, 100).unwrap())
+            .finish_with_params(protocol_parameters.token_supply())
+            .unwrap(),
+    );
+    let expiring_inputs = [inputs[0], (&output_ids[1], &expiring_output)];
+    assert!(valid.verify_signatures_batch(&expiring_inputs, 99).is_ok());
+    assert!(matches!(
+        valid.verify_signatures_batch(&expiring_inputs, 100),
+        Err(Error::SignaturePublicKeyMismatch { .. })
+    ));
+
+    assert!(matches!(
+        valid.verify_signatures_batch(&inputs[..1], 0),
+        Err(Error::InputUnlockCountMismatch {
+            input_count: 1,
+            unlock_count: 2
+        })
+    ));
+}
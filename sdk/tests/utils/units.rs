@@ -0,0 +1,55 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::utils::units::{from_smallest_unit, to_smallest_unit, UnitError, UnitFormat};
+
+#[test]
+fn smallest_unit_conversion() {
+    assert_eq!(to_smallest_unit("1.234567", 6), Ok(1_234_567));
+    assert_eq!(to_smallest_unit("0.1", 6), Ok(100_000));
+    assert_eq!(to_smallest_unit(".5", 6), Ok(500_000));
+    assert_eq!(to_smallest_unit("42", 0), Ok(42));
+    assert_eq!(to_smallest_unit("1.50000000", 1), Ok(15));
+    assert_eq!(to_smallest_unit("18446744073709.551615", 6), Ok(u64::MAX));
+
+    assert_eq!(from_smallest_unit(1_234_567, 6), "1.234567");
+    assert_eq!(from_smallest_unit(100_000, 6), "0.1");
+    assert_eq!(from_smallest_unit(2_000_000, 6), "2");
+    assert_eq!(from_smallest_unit(0, 6), "0");
+    assert_eq!(from_smallest_unit(u64::MAX, 6), "18446744073709.551615");
+
+    // 0.1 + 0.2 is exact
+    let sum = to_smallest_unit("0.1", 6).unwrap() + to_smallest_unit("0.2", 6).unwrap();
+    assert_eq!(from_smallest_unit(sum, 6), "0.3");
+}
+
+#[test]
+fn smallest_unit_conversion_errors() {
+    assert_eq!(to_smallest_unit("", 6), Err(UnitError::Empty));
+    assert_eq!(to_smallest_unit(".", 6), Err(UnitError::Empty));
+    assert_eq!(to_smallest_unit("-1", 6), Err(UnitError::InvalidCharacter('-')));
+    assert_eq!(to_smallest_unit("1e6", 6), Err(UnitError::InvalidCharacter('e')));
+    assert_eq!(
+        to_smallest_unit("0.1234567", 6),
+        Err(UnitError::TooManyFractionDigits { max: 6 })
+    );
+    assert_eq!(to_smallest_unit("18446744073709.551616", 6), Err(UnitError::Overflow));
+}
+
+#[test]
+fn unit_format() {
+    let format = UnitFormat::shimmer();
+    assert_eq!(format.format(1_500_000), "1.5 SMR");
+    assert_eq!(format.to_smallest_unit("1.5"), Ok(1_500_000));
+
+    let format = UnitFormat::new("i", 0).with_metric_prefix(true);
+    assert_eq!(format.format(999), "999 i");
+    assert_eq!(format.format(1_000), "1 Ki");
+    assert_eq!(format.format(1_500_000_000), "1.5 Gi");
+    assert_eq!(format.format(2_779_530_283_277_761), "2.779530283277761 Pi");
+
+    let format = UnitFormat::iota().with_separators(',', '.');
+    assert_eq!(format.from_smallest_unit(1_234_567_890_000), "1.234.567,89");
+    assert_eq!(format.format(1_000_000), "1 IOTA");
+    assert_eq!(format.to_smallest_unit("1.234.567,89"), Ok(1_234_567_890_000));
+}
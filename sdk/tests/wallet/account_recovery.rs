@@ -12,7 +12,7 @@ use iota_sdk::{
         secret::{mnemonic::MnemonicSecretManager, SecretManager},
         Client,
     },
-    wallet::Result,
+    wallet::{RecoverAccountsOptions, Result},
 };
 
 use crate::wallet::common::{make_wallet, setup, tear_down};
@@ -100,3 +100,56 @@ async fn account_recovery_with_balance_and_empty_addresses() -> Result<()> {
     assert_eq!(3, account_with_balance.public_addresses().len());
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn account_recovery_with_options() -> Result<()> {
+    let storage_path = "test-storage/account_recovery_with_options";
+    setup(storage_path)?;
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let client = Client::builder()
+        .with_node(crate::wallet::common::NODE_LOCAL)?
+        .finish()
+        .await?;
+
+    let secret_manager = SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.clone())?);
+
+    let addresses = secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::from_client(&client)
+                .await?
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_account_index(1)
+                .with_range(1..2),
+        )
+        .await?;
+
+    // Add funds to the address with account index 1 and address key_index 1
+    iota_sdk::client::request_funds_from_faucet(crate::wallet::common::FAUCET_URL, &addresses[0]).await?;
+
+    // Wait for faucet transaction
+    tokio::time::sleep(Duration::new(10, 0)).await;
+
+    let wallet = make_wallet(storage_path, Some(mnemonic), None).await?;
+
+    let recovered_accounts = wallet
+        .recover_accounts_with_options(RecoverAccountsOptions {
+            account_gap_limit: 2,
+            address_gap_limit: 2,
+            address_gap_limit_internal: Some(0),
+            ..Default::default()
+        })
+        .await?;
+
+    // accounts should be 2 because account with index 1 has balance
+    assert_eq!(2, recovered_accounts.len());
+    assert_eq!(recovered_accounts[0].balance.base_coin().total(), 0);
+    assert!(recovered_accounts[1].balance.base_coin().total() > 0);
+
+    let account_with_balance = recovered_accounts[1].account.details().await;
+    assert_eq!(2, account_with_balance.public_addresses().len());
+    // no internal addresses are searched with an internal gap limit of 0
+    assert!(account_with_balance.internal_addresses().is_empty());
+    tear_down(storage_path)
+}
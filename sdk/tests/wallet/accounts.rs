@@ -189,3 +189,37 @@ async fn account_creation_stronghold() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn account_rename_and_metadata() -> Result<()> {
+    let storage_path = "test-storage/account_rename_and_metadata";
+    setup(storage_path)?;
+
+    {
+        let wallet = make_wallet(storage_path, None, None).await?;
+        let _alice = wallet.create_account().with_alias("Alice").finish().await?;
+        let bob = wallet.create_account().with_alias("Bob").finish().await?;
+
+        // The alias is used by another account
+        assert!(wallet.rename_account("Bob", "alice").await.is_err());
+        wallet.rename_account("Bob", "main-savings").await?;
+        assert_eq!(bob.alias().await, "main-savings");
+
+        assert_eq!(bob.set_metadata("purpose", "savings").await?, None);
+        assert_eq!(bob.set_metadata("owner", "Bob").await?, None);
+        assert_eq!(bob.remove_metadata("owner").await?, Some("Bob".to_string()));
+    }
+
+    // The alias and metadata are persisted
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account("main-savings").await?;
+    assert_eq!(account.get_metadata("purpose").await, Some("savings".to_string()));
+    assert_eq!(account.get_metadata("owner").await, None);
+
+    let accounts = wallet.get_accounts_by_metadata("purpose", "savings").await;
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].alias().await, "main-savings");
+
+    tear_down(storage_path)
+}
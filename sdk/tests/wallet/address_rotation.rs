@@ -0,0 +1,73 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::wallet::{account::AddressRotationPolicy, Result};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn receive_address_rotation() -> Result<()> {
+    let storage_path = "test-storage/receive_address_rotation";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+    let first_address = account.addresses().await?[0].clone().into_bech32();
+
+    // Without a policy the receive address is never rotated
+    assert_eq!(account.current_receive_address().await?, first_address);
+    assert_eq!(account.current_receive_address().await?, first_address);
+
+    account
+        .set_address_rotation_policy(AddressRotationPolicy::default().with_max_uses(2))
+        .await?;
+
+    // The first address was already handed out twice
+    let second_address = account.current_receive_address().await?;
+    assert_ne!(second_address, first_address);
+    assert_eq!(account.current_receive_address().await?, second_address);
+
+    let third_address = account.current_receive_address().await?;
+    assert_ne!(third_address, second_address);
+    assert_eq!(account.addresses().await?.len(), 3);
+
+    assert_ne!(account.rotate_receive_address().await?, third_address);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(feature = "rocksdb")]
+async fn receive_address_state_persisted() -> Result<()> {
+    let storage_path = "test-storage/receive_address_state_persisted";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let policy = AddressRotationPolicy::default().with_max_uses(2);
+    account.set_address_rotation_policy(policy.clone()).await?;
+    let first_address = account.current_receive_address().await?;
+
+    drop(account);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account(0).await?;
+
+    // The policy and the uses of the current address survive the restart
+    assert_eq!(account.address_rotation_policy().await, policy);
+    assert_eq!(account.current_receive_address().await?, first_address);
+    let second_address = account.current_receive_address().await?;
+    assert_ne!(second_address, first_address);
+
+    drop(account);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account(0).await?;
+
+    assert_eq!(account.current_receive_address().await?, second_address);
+
+    tear_down(storage_path)
+}
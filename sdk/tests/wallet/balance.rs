@@ -4,7 +4,10 @@
 use iota_sdk::{
     types::block::output::{
         feature::SenderFeature,
-        unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
+        unlock_condition::{
+            AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
+            TimelockUnlockCondition,
+        },
         BasicOutputBuilder, UnlockCondition,
     },
     wallet::{account::types::Balance, Result},
@@ -165,6 +168,112 @@ async fn balance_expiration() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn spendable_balance_at() -> Result<()> {
+    let storage_path = "test-storage/spendable_balance_at";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let account_2 = wallet.create_account().finish().await?;
+
+    let token_supply = account_0.client().get_token_supply().await?;
+    let now = account_0.client().get_time_checked().await?;
+    let unlock_time = now + 3600;
+    let account_1_address = *account_1.addresses().await?[0].address().as_ref();
+    let outputs = [
+        // Spendable by account 1 once the timelock ended
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(account_1_address))
+            .add_unlock_condition(TimelockUnlockCondition::new(unlock_time)?)
+            .finish_output(token_supply)?,
+        // Spendable by account 1 until it expires, then by account 2
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(account_1_address))
+            .add_unlock_condition(ExpirationUnlockCondition::new(
+                *account_2.addresses().await?[0].address().as_ref(),
+                unlock_time,
+            )?)
+            .finish_output(token_supply)?,
+    ];
+
+    let tx = account_0.send_outputs(outputs, None).await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_1.sync(None).await?;
+    account_2.sync(None).await?;
+
+    assert_eq!(account_1.spendable_balance_at(now).await?, 2_000_000);
+    assert_eq!(account_1.spendable_balance_at(unlock_time + 1).await?, 1_000_000);
+    assert_eq!(account_2.spendable_balance_at(now).await?, 0);
+    assert_eq!(account_2.spendable_balance_at(unlock_time + 1).await?, 2_000_000);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn detailed_balance() -> Result<()> {
+    let storage_path = "test-storage/detailed_balance";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let address_0 = account_1.addresses().await?[0].clone().into_bech32();
+    let address_1 = account_1.generate_ed25519_addresses(1, None).await?[0]
+        .clone()
+        .into_bech32();
+
+    let token_supply = account_0.client().get_token_supply().await?;
+    let timelock = account_0.client().get_time_checked().await? + 3600;
+    let outputs = [
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address_0.inner()))
+            .add_unlock_condition(TimelockUnlockCondition::new(timelock)?)
+            .finish_output(token_supply)?,
+        // 500_000 need to be returned to account 0
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address_1.inner()))
+            .add_unlock_condition(StorageDepositReturnUnlockCondition::new(
+                *account_0.addresses().await?[0].address().as_ref(),
+                500_000,
+                token_supply,
+            )?)
+            .finish_output(token_supply)?,
+    ];
+
+    let tx = account_0.send_outputs(outputs, None).await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_1.sync(None).await?;
+
+    let detailed_balance = account_1.detailed_balance().await?;
+    assert_eq!(detailed_balance.output_count(), 2);
+    assert_eq!(detailed_balance.balance(), &account_1.balance().await?);
+    for (address, amount) in [(address_0, 1_000_000), (address_1, 2_000_000)] {
+        let address_balance = detailed_balance
+            .addresses()
+            .iter()
+            .find(|address_balance| address_balance.address() == &address)
+            .unwrap();
+        assert_eq!(address_balance.amount(), amount);
+        assert_eq!(address_balance.output_count(), 1);
+    }
+    assert_eq!(detailed_balance.not_spendable().timelocked(), 1_000_000);
+    assert_eq!(detailed_balance.not_spendable().storage_deposit_return(), 500_000);
+    // Basic outputs without native tokens can be sent entirely
+    assert_eq!(detailed_balance.not_spendable().storage_deposit(), 0);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn addresses_balance() -> Result<()> {
@@ -7,7 +7,7 @@ use iota_sdk::{
         unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
         NativeToken, NftId, NftOutputBuilder, OutputId, UnlockCondition,
     },
-    wallet::{Account, CreateNativeTokenParams, MintNftParams, Result},
+    wallet::{Account, CreateNativeTokenParams, Error, MintNftParams, Result},
     U256,
 };
 
@@ -157,6 +157,14 @@ async fn create_and_melt_native_token() -> Result<()> {
     });
     assert!(search.is_some());
 
+    // Melting more than the circulating supply fails
+    assert!(matches!(
+        account
+            .melt_native_token(create_transaction.token_id, circulating_supply, None)
+            .await,
+        Err(Error::BurningOrMeltingFailed(_))
+    ));
+
     // Then melt the rest of the supply
     let melt_amount = circulating_supply - melt_amount;
     let transaction = account
@@ -1,13 +1,15 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 use iota_sdk::{
     types::block::output::{
         unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
         BasicOutputBuilder, NativeToken, NftId, NftOutputBuilder, UnlockCondition,
     },
     wallet::{
-        account::{OutputsToClaim, TransactionOptions},
+        account::{ExpirationDirection, OutputsToClaim, TransactionOptions},
         CreateNativeTokenParams, Result, SendNativeTokensParams, SendParams,
     },
     U256,
@@ -171,6 +173,57 @@ async fn claim_2_basic_outputs_no_outputs_in_claim_account() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn expiring_outputs() -> Result<()> {
+    let storage_path = "test-storage/expiring_outputs";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let token_supply = account_0.client().get_token_supply().await?;
+    let rent_structure = account_0.client().get_rent_structure().await?;
+    let expiration_time = account_0.client().get_time_checked().await? + 3600; // 1 hour from now
+
+    let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+        .add_unlock_condition(AddressUnlockCondition::new(
+            *account_1.addresses().await?[0].address().as_ref(),
+        ))
+        .add_unlock_condition(ExpirationUnlockCondition::new(
+            *account_0.addresses().await?[0].address().as_ref(),
+            expiration_time,
+        )?)
+        .finish_output(token_supply)?;
+    let amount = output.amount();
+
+    let tx = account_0.send_outputs([output], None).await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+    account_1.sync(None).await?;
+
+    // The output returns to the sender and leaves the recipient when it expires
+    let expiring_outputs = account_0.expiring_outputs(Duration::from_secs(2 * 3600)).await?;
+    assert_eq!(expiring_outputs.len(), 1);
+    assert_eq!(expiring_outputs[0].amount, amount);
+    assert_eq!(expiring_outputs[0].expires_at, expiration_time);
+    assert_eq!(expiring_outputs[0].direction, ExpirationDirection::WillReturn);
+
+    let expiring_outputs = account_1.expiring_outputs(Duration::from_secs(2 * 3600)).await?;
+    assert_eq!(expiring_outputs.len(), 1);
+    assert_eq!(expiring_outputs[0].output_id.transaction_id(), &tx.transaction_id);
+    assert_eq!(expiring_outputs[0].direction, ExpirationDirection::WillLeave);
+
+    // Outputs expiring after the window are left out
+    assert!(account_1.expiring_outputs(Duration::from_secs(60)).await?.is_empty());
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn claim_2_native_tokens() -> Result<()> {
@@ -546,3 +599,56 @@ async fn claim_basic_micro_output_error() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn claim_profitable_outputs_in_chunks() -> Result<()> {
+    let storage_path = "test-storage/claim_profitable_outputs_in_chunks";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let token_supply = account_0.client().get_token_supply().await?;
+    let expiration_time = account_0.client().get_time_checked().await? + 86400; // 1 Day from now
+
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(
+            *account_1.addresses().await?[0].address().as_ref(),
+        ))
+        .add_unlock_condition(ExpirationUnlockCondition::new(
+            *account_0.addresses().await?[0].address().as_ref(),
+            expiration_time,
+        )?)
+        .finish_output(token_supply)?;
+    let amount = output.amount();
+
+    // More outputs than are claimed in a single transaction
+    let outputs = vec![output; 61];
+
+    let tx = account_0.send_outputs(outputs, None).await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    // Claim with account 1
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.potentially_locked_outputs().len(), 61);
+
+    let transactions = account_1.claim_profitable_outputs().await?;
+    assert_eq!(transactions.len(), 2);
+    for tx in transactions {
+        account_1
+            .retry_transaction_until_included(&tx.transaction_id, None, None)
+            .await?;
+    }
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.potentially_locked_outputs().len(), 0);
+    assert_eq!(balance.base_coin().available(), 61 * amount);
+
+    tear_down(storage_path)
+}
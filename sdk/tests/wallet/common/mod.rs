@@ -53,6 +53,7 @@ pub(crate) async fn make_ledger_nano_wallet(storage_path: &str, node: Option<&st
     let client_options = ClientOptions::new().with_node(node.unwrap_or(NODE_LOCAL))?;
     let mut secret_manager = iota_sdk::client::secret::ledger_nano::LedgerSecretManager::new(true);
     secret_manager.non_interactive = true;
+    secret_manager.allow_blind_signing = true;
 
     #[allow(unused_mut)]
     let mut wallet_builder = Wallet::builder()
@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::ConsolidationParams, Result, SendParams};
+use iota_sdk::wallet::{
+    account::{ConsolidationParams, ConsolidationPolicy},
+    Result, SendParams,
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -48,3 +51,85 @@ async fn consolidation() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn consolidation_policy() -> Result<()> {
+    let storage_path = "test-storage/consolidation_policy";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    account_1.set_consolidation_policy(5, false).await?;
+
+    // Send 10 outputs to account_1
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_with_params(
+            vec![SendParams::new(amount, *account_1.addresses().await?[0].address())?; 10],
+            None,
+        )
+        .await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    // Syncing sends the consolidation transaction, its inputs are locked until it's included
+    account_1.sync(None).await?;
+    let pending_transactions = account_1.pending_transactions().await;
+    assert_eq!(pending_transactions.len(), 1);
+
+    account_1.clear_consolidation_policy().await?;
+    account_1
+        .retry_transaction_until_included(&pending_transactions[0].transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await?;
+    // Balance still the same
+    assert_eq!(balance.base_coin().available(), 10 * amount);
+    // Only one unspent output
+    assert_eq!(account_1.unspent_outputs(None).await?.len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(feature = "rocksdb")]
+async fn consolidation_policy_persisted() -> Result<()> {
+    let storage_path = "test-storage/consolidation_policy_persisted";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    assert_eq!(account.consolidation_policy().await, None);
+    account.set_consolidation_policy(5, true).await?;
+
+    drop(account);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account(0).await?;
+
+    assert_eq!(
+        account.consolidation_policy().await,
+        Some(ConsolidationPolicy {
+            output_threshold: 5,
+            unlock_conditions_allowed: true,
+        })
+    );
+    account.clear_consolidation_policy().await?;
+
+    drop(account);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account(0).await?;
+
+    assert_eq!(account.consolidation_policy().await, None);
+
+    tear_down(storage_path)
+}
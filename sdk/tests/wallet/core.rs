@@ -200,3 +200,40 @@ async fn update_node_auth() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn export_memory_storage() -> Result<()> {
+    use iota_sdk::wallet::storage::{adapter::memory::MemoryStorageAdapter, StorageOptions};
+
+    let memory_storage = MemoryStorageAdapter::default();
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC.to_owned(),
+        )?))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_options(StorageOptions::default().with_memory_storage(memory_storage.clone()))
+        .finish()
+        .await?;
+    let account = wallet.create_account().with_alias("Alice").finish().await?;
+
+    // The records are exported on demand and the wallet can be loaded from them, only the mnemonic isn't stored
+    let bytes = memory_storage.to_bytes().await?;
+    let restored_wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC.to_owned(),
+        )?))
+        .with_storage_options(StorageOptions::default().with_memory_storage(MemoryStorageAdapter::from_bytes(&bytes)?))
+        .finish()
+        .await?;
+
+    let restored_account = restored_wallet.get_account("Alice").await?;
+    assert_eq!(account.addresses().await?, restored_account.addresses().await?);
+    assert_eq!(
+        restored_wallet.client_options().await.node_manager_builder.nodes,
+        wallet.client_options().await.node_manager_builder.nodes
+    );
+
+    Ok(())
+}
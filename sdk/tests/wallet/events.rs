@@ -14,8 +14,9 @@ use iota_sdk::{
     wallet::{
         account::types::{InclusionState, OutputData, OutputDataDto},
         events::types::{
-            AddressData, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
-            WalletEvent,
+            AccountRenamedEvent, AddressData, AddressRotatedEvent, AutoConsolidationEvent, LockedAddressChangedEvent,
+            NewOutputEvent, SpentOutputEvent, SyncProgressEvent, SyncStage, TransactionInclusionEvent,
+            TransactionProgressEvent, TransactionRebuiltEvent, WalletEvent,
         },
     },
 };
@@ -40,6 +41,17 @@ fn wallet_events_serde() {
             .unwrap(),
     }));
 
+    #[cfg(feature = "ledger_nano")]
+    assert_serde_eq(WalletEvent::LedgerAddressMismatch(Box::new(
+        iota_sdk::wallet::events::types::LedgerAddressMismatchEvent {
+            key_index: 3,
+            expected: Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")
+                .unwrap(),
+            displayed: Bech32Address::try_from_str("rms1qr47gz3xxjqpjrwd0yu5glhqrth6w0t08npney8000ust2lcw2r92j5a8rt")
+                .unwrap(),
+        },
+    )));
+
     let output_data_dto = OutputDataDto::from(&OutputData {
         output_id: OutputId::null(),
         metadata: rand_output_metadata(),
@@ -64,12 +76,59 @@ fn wallet_events_serde() {
     assert_serde_eq(WalletEvent::TransactionInclusion(TransactionInclusionEvent {
         transaction_id: TransactionId::null(),
         inclusion_state: InclusionState::Conflicting,
+        block_id: None,
     }));
 
     assert_serde_eq(WalletEvent::TransactionProgress(
         TransactionProgressEvent::SelectingInputs,
     ));
 
+    assert_serde_eq(WalletEvent::LockedAddressChanged(Box::new(LockedAddressChangedEvent {
+        output_id: OutputId::null(),
+        previous_address: Bech32Address::try_from_str(
+            "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
+        )
+        .unwrap(),
+        address: Bech32Address::try_from_str("rms1qr47gz3xxjqpjrwd0yu5glhqrth6w0t08npney8000ust2lcw2r92j5a8rt")
+            .unwrap(),
+    })));
+
+    assert_serde_eq(WalletEvent::AddressRotated(Box::new(AddressRotatedEvent {
+        previous_address: Some(
+            Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy").unwrap(),
+        ),
+        address: Bech32Address::try_from_str("rms1qr47gz3xxjqpjrwd0yu5glhqrth6w0t08npney8000ust2lcw2r92j5a8rt")
+            .unwrap(),
+    })));
+
+    assert_serde_eq(WalletEvent::TransactionRebuilt(Box::new(TransactionRebuiltEvent {
+        previous_transaction_id: TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap()),
+        transaction_id: TransactionId::null(),
+    })));
+
+    assert_serde_eq(WalletEvent::AccountRenamed(Box::new(AccountRenamedEvent {
+        previous_alias: "Alice".to_string(),
+        alias: "main-savings".to_string(),
+    })));
+
+    assert_serde_eq(WalletEvent::SyncProgress(SyncProgressEvent {
+        stage: SyncStage::OutputFetching,
+        processed: 100,
+        total: 250,
+    }));
+
+    assert_serde_eq(WalletEvent::AutoConsolidation(Box::new(AutoConsolidationEvent {
+        consolidated_outputs: 12,
+        transaction_id: Some(TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap())),
+        error: None,
+    })));
+
+    assert_serde_eq(WalletEvent::AutoConsolidation(Box::new(AutoConsolidationEvent {
+        consolidated_outputs: 0,
+        transaction_id: None,
+        error: Some("insufficient funds".to_string()),
+    })));
+
     assert_serde_eq(WalletEvent::TransactionProgress(
         TransactionProgressEvent::GeneratingRemainderDepositAddress(AddressData {
             address: Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")
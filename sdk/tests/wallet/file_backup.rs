@@ -0,0 +1,108 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{
+        constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
+        node_manager::node::{Node, NodeDto},
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+    },
+    wallet::{ClientOptions, Error, Result, Wallet},
+    Url,
+};
+
+use crate::wallet::common::{setup, tear_down, NODE_LOCAL, NODE_OTHER};
+
+const MNEMONIC: &str = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+
+#[tokio::test]
+async fn backup_and_restore_file() -> Result<()> {
+    let storage_path = "test-storage/backup_and_restore_file";
+    setup(storage_path)?;
+    std::fs::create_dir_all(storage_path).ok();
+    let backup_path = "test-storage/backup_and_restore_file/wallet.backup";
+    let password = "some_hopefully_secure_password";
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            MNEMONIC.to_owned(),
+        )?))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/backup_and_restore_file/1")
+        .finish()
+        .await?;
+    let account = wallet.create_account().with_alias("Alice").finish().await?;
+
+    wallet.backup_to_file(backup_path, password).await?;
+
+    let restore_wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            MNEMONIC.to_owned(),
+        )?))
+        // Build with a different coin type and node, to check if they get replaced by the ones from the backup
+        .with_client_options(ClientOptions::new().with_node(NODE_OTHER)?)
+        .with_coin_type(IOTA_COIN_TYPE)
+        .with_storage_path("test-storage/backup_and_restore_file/2")
+        .finish()
+        .await?;
+
+    assert!(matches!(
+        restore_wallet
+            .restore_backup_from_file(backup_path, "wrong_password")
+            .await,
+        Err(Error::Backup(_))
+    ));
+
+    restore_wallet.restore_backup_from_file(backup_path, password).await?;
+
+    let new_account = restore_wallet.create_account().finish().await?;
+    assert_eq!(new_account.details().await.coin_type(), &SHIMMER_COIN_TYPE);
+
+    let client_options = restore_wallet.client_options().await;
+    let node_dto = NodeDto::Node(Node::from(Url::parse(NODE_LOCAL).unwrap()));
+    assert!(client_options.node_manager_builder.nodes.contains(&node_dto));
+
+    let restored_account = restore_wallet.get_account("Alice").await?;
+    assert_eq!(account.addresses().await?, restored_account.addresses().await?);
+
+    // Restoring again would overwrite the accounts
+    assert!(matches!(
+        restore_wallet.restore_backup_from_file(backup_path, password).await,
+        Err(Error::Backup(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn restore_backup_file_with_too_many_rounds() -> Result<()> {
+    let storage_path = "test-storage/restore_backup_file_with_too_many_rounds";
+    setup(storage_path)?;
+    std::fs::create_dir_all(storage_path).ok();
+    let backup_path = "test-storage/restore_backup_file_with_too_many_rounds/wallet.backup";
+    let password = "some_hopefully_secure_password";
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            MNEMONIC.to_owned(),
+        )?))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/restore_backup_file_with_too_many_rounds/1")
+        .finish()
+        .await?;
+    wallet.backup_to_file(backup_path, password).await?;
+
+    // The rounds follow the 8 magic bytes
+    let mut file = std::fs::read(backup_path)?;
+    file[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+    std::fs::write(backup_path, file)?;
+
+    assert!(matches!(
+        wallet.restore_backup_from_file(backup_path, password).await,
+        Err(Error::Backup("invalid key derivation rounds"))
+    ));
+
+    tear_down(storage_path)
+}
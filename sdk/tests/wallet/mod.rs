@@ -4,6 +4,7 @@
 mod account_recovery;
 mod accounts;
 mod address_generation;
+mod address_rotation;
 #[cfg(all(feature = "stronghold", feature = "storage"))]
 mod backup_restore;
 mod balance;
@@ -19,10 +20,13 @@ mod core;
 mod error;
 #[cfg(feature = "events")]
 mod events;
+#[cfg(feature = "storage")]
+mod file_backup;
 #[cfg(feature = "stronghold")]
 mod migrate_stronghold_snapshot_v2_to_v3;
 mod native_tokens;
 mod output_preparation;
+mod proof_of_reserves;
 mod syncing;
 mod transactions;
 #[cfg(not(target_os = "windows"))]
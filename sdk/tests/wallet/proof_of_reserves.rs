@@ -0,0 +1,80 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::GetAddressesOptions,
+        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{SecretManage, SecretManager},
+        Client,
+    },
+    types::block::Error,
+    wallet::{
+        account::{AddressReserves, ProofOfReserves},
+        Result,
+    },
+};
+
+#[tokio::test]
+async fn verify_proof_of_reserves() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let addresses = secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+                .with_range(0..2),
+        )
+        .await?;
+
+    let mut proof = ProofOfReserves {
+        nonce: b"auditor nonce".to_vec(),
+        ledger_index: 100,
+        addresses: Vec::new(),
+    };
+    for (address_index, (address, amount)) in addresses.into_iter().zip([1_000_000, 2_000_000]).enumerate() {
+        let signature = secret_manager
+            .sign_ed25519(
+                &proof.signed_message(&address, amount),
+                Bip44::new(SHIMMER_COIN_TYPE).with_address_index(address_index as u32),
+            )
+            .await?;
+        proof.addresses.push(AddressReserves {
+            address,
+            amount,
+            output_ids: Vec::new(),
+            signature,
+        });
+    }
+
+    assert_eq!(proof.total_amount(), 3_000_000);
+    proof.verify()?;
+
+    // The proof can be handed to the auditor as JSON
+    let json = serde_json::to_string(&proof)?;
+    assert_eq!(serde_json::from_str::<ProofOfReserves>(&json)?, proof);
+
+    // Changing the amount, the ledger index or the nonce invalidates the signatures
+    let mut changed_proof = proof.clone();
+    changed_proof.addresses[0].amount += 1;
+    assert!(matches!(changed_proof.verify(), Err(Error::InvalidSignature)));
+
+    let mut changed_proof = proof.clone();
+    changed_proof.ledger_index += 1;
+    assert!(matches!(changed_proof.verify(), Err(Error::InvalidSignature)));
+
+    let mut changed_proof = proof.clone();
+    changed_proof.nonce = b"other nonce".to_vec();
+    assert!(matches!(changed_proof.verify(), Err(Error::InvalidSignature)));
+
+    // The signature of one address doesn't prove the ownership of another one
+    let mut changed_proof = proof;
+    changed_proof.addresses[0].signature = changed_proof.addresses[1].signature.clone();
+    assert!(matches!(
+        changed_proof.verify(),
+        Err(Error::SignaturePublicKeyMismatch { .. })
+    ));
+
+    Ok(())
+}
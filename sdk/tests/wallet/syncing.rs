@@ -9,7 +9,7 @@ use iota_sdk::{
         },
         AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, UnlockCondition,
     },
-    wallet::{account::SyncOptions, Result},
+    wallet::{account::SyncOptions, Error, Result},
 };
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
@@ -45,6 +45,53 @@ async fn updated_default_sync_options() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[test]
+#[cfg(feature = "participation")]
+fn sync_participation_option() -> Result<()> {
+    // Disabled by default, also when not set in the JSON of the bindings
+    assert!(!SyncOptions::default().sync_participation);
+    assert!(!serde_json::from_str::<SyncOptions>("{}")?.sync_participation);
+
+    let options = serde_json::from_str::<SyncOptions>(r#"{"syncParticipation": true}"#)?;
+    assert!(options.sync_participation);
+    assert_eq!(
+        serde_json::from_value::<SyncOptions>(serde_json::to_value(&options)?)?,
+        options
+    );
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+#[cfg(feature = "participation")]
+async fn sync_participation() -> Result<()> {
+    let storage_path = "test-storage/sync_participation";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let voting_power = 1_000_000;
+    let tx = account.increase_voting_power(voting_power).await?;
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    // Syncing doesn't fail if no node with the participation plugin is available
+    let balance = account
+        .sync(Some(SyncOptions {
+            sync_participation: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().voting_power(), voting_power);
+    assert_eq!(account.get_voting_power().await?, voting_power);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn sync_only_most_basic_outputs() -> Result<()> {
@@ -149,6 +196,110 @@ async fn sync_only_most_basic_outputs() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn sync_all() -> Result<()> {
+    let storage_path = "test-storage/sync_all";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    create_accounts_with_funds(&wallet, 2).await?;
+    wallet.create_account().finish().await?;
+
+    // A concurrency of 0 is treated as 1, more than the parallel requests is limited to them
+    for concurrency in [Some(0), Some(1), None, Some(usize::MAX)] {
+        let results = wallet.sync_all(None, concurrency).await?;
+        assert_eq!(results.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [0, 1, 2]);
+
+        let balances = results
+            .into_iter()
+            .map(|(_, balance)| balance)
+            .collect::<Result<Vec<_>>>()?;
+        assert!(balances[0].base_coin().available() > 0);
+        assert!(balances[1].base_coin().available() > 0);
+        assert_eq!(balances[2].base_coin().total(), 0);
+    }
+
+    let balance = wallet.sync(None).await?;
+    assert_eq!(
+        balance.base_coin().total(),
+        wallet.get_accounts().await?[0].balance().await?.base_coin().total()
+            + wallet.get_accounts().await?[1].balance().await?.base_coin().total()
+    );
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn sync_account_indexes_and_addresses() -> Result<()> {
+    let storage_path = "test-storage/sync_account_indexes_and_addresses";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // Only the selected accounts are synced
+    let balance = wallet
+        .sync(Some(SyncOptions {
+            account_indexes: vec![1],
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 0);
+    assert!(matches!(
+        wallet
+            .sync(Some(SyncOptions {
+                account_indexes: vec![2],
+                ..Default::default()
+            }))
+            .await,
+        Err(Error::AccountNotFound(_))
+    ));
+
+    // Send to a second address of account 0
+    let address_0 = *account_0.addresses().await?[0].address();
+    let address_1 = *account_0.generate_ed25519_addresses(1, None).await?[0].address();
+    let amount = 1_000_000;
+    let tx = account_0.send(amount, address_1, None).await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    // Syncing an address only syncs the account that contains it and keeps the other addresses
+    let balance = wallet
+        .sync(Some(SyncOptions {
+            addresses: vec![address_1],
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(
+        balance.base_coin().total(),
+        account_0.balance().await?.base_coin().total()
+    );
+    let addresses_with_unspent_outputs = account_0.addresses_with_unspent_outputs().await?;
+    assert!(addresses_with_unspent_outputs.iter().any(|a| a.address() == &address_0));
+    assert!(addresses_with_unspent_outputs.iter().any(|a| a.address() == &address_1));
+
+    let unknown_address = *account_1.addresses().await?[0].address();
+    assert!(matches!(
+        wallet
+            .sync(Some(SyncOptions {
+                addresses: vec![unknown_address],
+                account_indexes: vec![0],
+                ..Default::default()
+            }))
+            .await,
+        Err(Error::AddressNotFoundInAccount(address)) if address == unknown_address
+    ));
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn sync_incoming_transactions() -> Result<()> {
@@ -196,6 +347,79 @@ async fn sync_incoming_transactions() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+#[cfg(feature = "events")]
+async fn sync_progress_events() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    use iota_sdk::wallet::events::{types::SyncStage, WalletEvent, WalletEventType};
+
+    let storage_path = "test-storage/sync_progress_events";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let tx = account_0
+        .send(1_000_000, *account_1.addresses().await?[0].address(), None)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    wallet
+        .listen_to_accounts(
+            [*account_1.details().await.index()],
+            [WalletEventType::SyncProgress],
+            move |event| {
+                if let WalletEvent::SyncProgress(progress) = &event.event {
+                    events_clone.lock().unwrap().push(*progress);
+                } else {
+                    panic!("expected SyncProgress event")
+                }
+            },
+        )
+        .await;
+
+    account_1
+        .sync(Some(SyncOptions {
+            sync_incoming_transactions: true,
+            ..Default::default()
+        }))
+        .await?;
+
+    let events = events.lock().unwrap().clone();
+    for stage in [
+        SyncStage::AddressDiscovery,
+        SyncStage::OutputFetching,
+        SyncStage::TransactionResolution,
+    ] {
+        let stage_events = events.iter().filter(|event| event.stage == stage).collect::<Vec<_>>();
+        // Every stage starts with nothing processed and is done once everything is processed
+        assert_eq!(stage_events.first().unwrap().processed, 0);
+        let last = stage_events.last().unwrap();
+        assert_eq!(last.processed, last.total);
+        assert!(
+            stage_events
+                .windows(2)
+                .all(|events| events[0].processed <= events[1].processed || events[1].processed == 0)
+        );
+    }
+    // The incoming transaction got resolved
+    assert!(
+        events
+            .iter()
+            .any(|event| event.stage == SyncStage::TransactionResolution && event.processed == 1 && event.total == 1)
+    );
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 #[cfg(feature = "storage")]
@@ -1,7 +1,16 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendNftParams, SendParams};
+use iota_sdk::{
+    types::block::{
+        address::ToBech32Ext,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Rent},
+    },
+    wallet::{
+        account::{RemainderValueStrategy, TransactionOptions},
+        Error, MintNftParams, Result, SendNftParams, SendParams,
+    },
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -34,6 +43,37 @@ async fn send_amount() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_all() -> Result<()> {
+    let storage_path = "test-storage/send_all";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let available = account_0.balance().await?.base_coin().available();
+    let transactions = account_0
+        .send_all(*account_1.addresses().await?[0].address(), None)
+        .await?;
+    assert!(!transactions.is_empty());
+    for tx in &transactions {
+        account_0
+            .retry_transaction_until_included(&tx.transaction_id, None, None)
+            .await?;
+    }
+
+    // Everything was sent to account 1
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 0);
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), available);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_amount_127_outputs() -> Result<()> {
@@ -157,6 +197,66 @@ async fn send_nft() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn mint_nfts_in_batches_partially_sent() -> Result<()> {
+    let storage_path = "test-storage/mint_nfts_in_batches_partially_sent";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let address = *account.addresses().await?[0].address();
+
+    // The last NFT is minted to an address of another network, so only the batch containing it fails
+    let mut nft_params = vec![MintNftParams::new(); 127];
+    nft_params.push(MintNftParams::new().with_address(address.inner().to_bech32_unchecked("iota")));
+
+    match account.mint_nfts_in_batches(nft_params, None).await {
+        Err(Error::PartiallySent { transaction_ids, error }) => {
+            assert!(!transaction_ids.is_empty());
+            assert!(matches!(*error, Error::Client(_)));
+            for transaction_id in transaction_ids {
+                assert!(account.get_transaction(&transaction_id).await.is_some());
+            }
+        }
+        other => panic!("expected Error::PartiallySent, got {other:?}"),
+    }
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn update_alias_state_storage_deposit() -> Result<()> {
+    let storage_path = "test-storage/update_alias_state_storage_deposit";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let transaction = account.create_alias_output(None, None).await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let alias_id = *account.sync(None).await?.aliases().first().unwrap();
+    let amount_before = account.unspent_alias_output(&alias_id).await?.unwrap().output.amount();
+
+    // The larger state metadata requires a higher storage deposit
+    let transaction = account.update_alias_state(alias_id, vec![1; 4000], None).await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let rent_structure = account.client().get_rent_structure().await?;
+    let alias_output = account.unspent_alias_output(&alias_id).await?.unwrap().output;
+    assert_eq!(alias_output.as_alias().state_metadata(), &[1; 4000]);
+    assert!(alias_output.amount() > amount_before);
+    assert_eq!(alias_output.amount(), alias_output.rent_cost(&rent_structure));
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_with_note() -> Result<()> {
@@ -184,6 +284,50 @@ async fn send_with_note() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn transaction_receipt() -> Result<()> {
+    let storage_path = "test-storage/transaction_receipt";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let address_1 = *account_1.addresses().await?[0].address();
+
+    let amount = 1_000_000;
+    let receipt = account_0
+        .send_with_receipt([SendParams::new(amount, address_1)?], None)
+        .await?;
+
+    let tx = account_0.get_transaction(&receipt.transaction_id).await.unwrap();
+    assert_eq!(
+        account_0.get_transaction_receipt(&tx.transaction_id).await?,
+        Some(receipt.clone())
+    );
+    assert_eq!(receipt.block_id, tx.block_id);
+    assert_eq!(receipt.consumed_outputs.len(), tx.inputs.len());
+    assert!(receipt.native_token_changes.is_empty());
+
+    let change_1 = receipt
+        .address_changes
+        .iter()
+        .find(|change| change.address == address_1)
+        .unwrap();
+    assert_eq!(change_1.base_coin_change, amount as i128);
+    // Only the amount sent to the other account leaves the addresses of the sending account
+    let change_0 = receipt
+        .address_changes
+        .iter()
+        .filter(|change| change.address != address_1)
+        .map(|change| change.base_coin_change)
+        .sum::<i128>();
+    assert_eq!(change_0, -(amount as i128));
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn conflicting_transaction() -> Result<()> {
@@ -256,6 +400,245 @@ async fn conflicting_transaction() -> Result<()> {
     tear_down(storage_path_1)
 }
 
+#[ignore]
+#[tokio::test]
+async fn dry_run_transaction() -> Result<()> {
+    let storage_path = "test-storage/dry_run_transaction";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    #[cfg(feature = "events")]
+    wallet
+        .listen([], |event| panic!("dry runs must not emit events: {:?}", event.event))
+        .await;
+
+    let address = *account_1.addresses().await?[0].address();
+    let token_supply = account_0.client().get_token_supply().await?;
+    let outputs = [BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(token_supply)?];
+    let address_count = account_0.addresses().await?.len();
+
+    // Dry runs neither lock the inputs nor generate a remainder address, so they are repeatable
+    let options = TransactionOptions {
+        remainder_value_strategy: RemainderValueStrategy::ChangeAddress,
+        ..Default::default()
+    };
+    let dry_run = account_0.dry_run_transaction(outputs.clone(), options.clone()).await?;
+    assert_eq!(dry_run, account_0.dry_run_transaction(outputs, options).await?);
+    assert!(account_0.details().await.locked_outputs().is_empty());
+    assert_eq!(account_0.addresses().await?.len(), address_count);
+
+    let params = [SendParams::new(1_000_000, address)?];
+
+    // The same flag can be set on the options of the high level operations
+    let estimate = account_0.estimate_send(params.clone(), None).await?;
+    let prepared = account_0
+        .prepare_send(
+            params.clone(),
+            TransactionOptions {
+                dry_run: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert!(account_0.details().await.locked_outputs().is_empty());
+    assert_eq!(estimate.required_input_amount(), prepared.summary().consumed_amount);
+
+    // Dry runs can't be sent
+    assert!(matches!(
+        account_0
+            .send_with_params(
+                params,
+                TransactionOptions {
+                    dry_run: true,
+                    ..Default::default()
+                },
+            )
+            .await,
+        Err(Error::Client(error)) if matches!(*error, iota_sdk::client::Error::DryRun)
+    ));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn estimate_send() -> Result<()> {
+    let storage_path = "test-storage/estimate_send";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let address = *account_1.addresses().await?[0].address();
+    let params = [SendParams::new(amount, address)?];
+    let available = account_0.balance().await?.base_coin().available();
+
+    let estimate = account_0.estimate_send(params.clone(), None).await?;
+    // The estimate doesn't lock the inputs
+    assert!(account_0.details().await.locked_outputs().is_empty());
+
+    // The funds are in a single output, which is consumed and the rest goes to the remainder
+    let output = BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(account_0.client().get_token_supply().await?)?;
+    assert_eq!(
+        estimate.storage_deposit(),
+        account_0.client().estimate_storage_deposit(&output).await?
+    );
+    assert_eq!(estimate.required_input_amount(), available);
+    assert_eq!(estimate.remainder(), available - amount);
+
+    // The sent transaction has the estimated remainder
+    let tx = account_0.send_with_params(params, None).await?;
+    assert!(
+        tx.payload
+            .essence()
+            .as_regular()
+            .outputs()
+            .iter()
+            .any(|output| output.amount() == estimate.remainder())
+    );
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn forbidden_inputs() -> Result<()> {
+    let storage_path = "test-storage/forbidden_inputs";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // Split the funds of the account into two small outputs and a remainder
+    let address_0 = *account_0.addresses().await?[0].address();
+    let address_1 = *account_0.generate_ed25519_addresses(1, None).await?[0].address();
+    let tx = account_0
+        .send_with_params(
+            [
+                SendParams::new(1_000_000, address_0)?,
+                SendParams::new(1_000_000, address_1)?,
+            ],
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    let (small_outputs, remainders): (Vec<_>, Vec<_>) = account_0
+        .unspent_outputs(None)
+        .await?
+        .into_iter()
+        .partition(|output_data| output_data.output.amount() == 1_000_000);
+    let small_output_ids = small_outputs
+        .iter()
+        .map(|output_data| output_data.output_id)
+        .collect::<Vec<_>>();
+    let remainder_output_id = remainders[0].output_id;
+
+    // Without the remainder, both small outputs are needed
+    let params = [SendParams::new(1_500_000, *account_1.addresses().await?[0].address())?];
+    let prepared = account_0
+        .prepare_send(
+            params.clone(),
+            TransactionOptions {
+                forbidden_inputs: Some(vec![remainder_output_id]),
+                dry_run: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    let consumed_output_ids = prepared.summary().consumed_output_ids;
+    assert_eq!(consumed_output_ids.len(), 2);
+    assert!(
+        consumed_output_ids
+            .iter()
+            .all(|output_id| small_output_ids.contains(output_id))
+    );
+
+    // Nothing is left to select
+    assert!(
+        account_0
+            .send_with_params(
+                params,
+                TransactionOptions {
+                    forbidden_inputs: Some(small_output_ids.into_iter().chain([remainder_output_id]).collect()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .is_err()
+    );
+    assert!(account_0.details().await.locked_outputs().is_empty());
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn unsafe_expiration() -> Result<()> {
+    let storage_path = "test-storage/unsafe_expiration";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // A micro amount needs an expiration
+    let params = [SendParams::new(1, *account_1.addresses().await?[0].address())?.with_expiration(30)];
+
+    // The expiration isn't later than the default slack
+    assert!(matches!(
+        account_0
+            .prepare_send(
+                params.clone(),
+                TransactionOptions {
+                    allow_micro_amount: true,
+                    ..Default::default()
+                },
+            )
+            .await,
+        Err(Error::UnsafeExpiration {
+            expiration: 30,
+            slack: 60
+        })
+    ));
+
+    let time = account_0.client().get_time_checked().await?;
+    let prepared_transaction = account_0
+        .prepare_send(
+            params,
+            TransactionOptions {
+                allow_micro_amount: true,
+                expiration_slack: Some(10),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    // Only the output of the micro amount expires, in milestone time
+    let expirations = prepared_transaction.expirations().collect::<Vec<_>>();
+    assert_eq!(expirations.len(), 1);
+    assert!(expirations[0].1.timestamp() >= time + 30);
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 #[cfg(all(feature = "ledger_nano", feature = "events"))]
 #[ignore = "requires ledger nano instance"]
@@ -5,7 +5,10 @@ use std::{fs, io, path::Path};
 
 #[cfg(feature = "stronghold")]
 use iota_sdk::client::stronghold::StrongholdAdapter;
-use iota_sdk::{wallet::Result, Wallet};
+use iota_sdk::{
+    wallet::{migration::migrate_db_iota_wallet_to_sdk, Result},
+    Wallet,
+};
 
 use crate::wallet::common::{setup, tear_down};
 
@@ -351,6 +354,46 @@ async fn check_existing_db_5() -> Result<()> {
     tear_down(storage_path)
 }
 
+// Db created with wallet.rs commit 8dd389ddeed0d95bb493c38f376b41a6a9127148
+#[cfg(feature = "stronghold")]
+#[tokio::test]
+async fn migrate_iota_wallet_db() -> Result<()> {
+    let old_storage_path = "migrate_iota_wallet_db_old";
+    let storage_path = "migrate_iota_wallet_db";
+    setup(old_storage_path)?;
+    setup(storage_path)?;
+    // Copy db so the original doesn't get modified
+    copy_folder("./tests/wallet/fixtures/check_existing_db_test", old_storage_path).unwrap();
+
+    let report = migrate_db_iota_wallet_to_sdk(old_storage_path, None, storage_path, None).await?;
+    assert!(report.dropped_pending_transactions.is_empty());
+    // Importing again into the same storage fails instead of overwriting it
+    assert!(
+        migrate_db_iota_wallet_to_sdk(old_storage_path, None, storage_path, None)
+            .await
+            .is_err()
+    );
+
+    let wallet = Wallet::builder().with_storage_path(storage_path).finish().await?;
+
+    assert_eq!(wallet.get_accounts().await?.len(), 1);
+    let account = wallet.get_account("Alice").await?;
+
+    let addresses = account.addresses().await?;
+    assert_eq!(addresses.len(), 2);
+    assert_eq!(
+        addresses[0].address().to_string(),
+        "rms1qzsw70tha0y4n78s0x0p99ayvz7nl7mzcye7yk8l3s8m6zrfg7slud2ve9f"
+    );
+    assert_eq!(account.transactions().await.len(), 2);
+    assert_eq!(account.pending_transactions().await.len(), 1);
+    assert_eq!(account.incoming_transactions().await.len(), 1);
+    assert_eq!(account.unspent_outputs(None).await?.len(), 9);
+
+    tear_down(old_storage_path)?;
+    tear_down(storage_path)
+}
+
 fn copy_folder(src: impl AsRef<Path>, dest: impl AsRef<Path>) -> io::Result<()> {
     fs::create_dir_all(&dest)?;
     for entry in fs::read_dir(src)? {